@@ -0,0 +1,113 @@
+//! Size and cost of compressing the admin report list, the largest JSON response
+//! the API sends. Fails before measuring when brotli or gzip save less than 70%.
+//!
+//! ```sh
+//! cargo bench --bench response_compression
+//! ```
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Request},
+    routing::get,
+    Json, Router,
+};
+use criterion::{criterion_group, criterion_main, Criterion};
+use serde_json::json;
+use tokio::runtime::Runtime;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use sustainability_tool::common::config::CompressionConfig;
+use sustainability_tool::web::api::models::{AdminReport, AdminReportListResponse};
+use sustainability_tool::web::routes::compression_layer;
+
+const REPORTS: usize = 50;
+const CATEGORIES: [&str; 4] = ["Environmental", "Social", "Governance", "Economic"];
+
+/// `GET /api/admin/reports` as `list_all_reports` renders it, with reports shaped
+/// like the ones `generate_report` stores
+fn report_list() -> serde_json::Value {
+    let reports = (0..REPORTS)
+        .map(|report| {
+            let categories: serde_json::Map<_, _> = CATEGORIES
+                .iter()
+                .map(|category| {
+                    let questions: Vec<_> = (0..5)
+                        .map(|question| {
+                            json!({
+                                "question": format!("{category} question {question}: does the organization track this practice?"),
+                                "answer": { "yesNo": question % 2 == 0, "percentage": (report * question) % 100, "text": "Tracked yearly by the operations team" },
+                            })
+                        })
+                        .collect();
+                    let recommendation = format!("Improve {category} practices");
+                    (
+                        category.to_string(),
+                        json!({
+                            "questions": questions,
+                            "recommendations": [{
+                                "id": Uuid::new_v5(&Uuid::NAMESPACE_DNS, recommendation.as_bytes()),
+                                "text": recommendation,
+                                "status": "todo",
+                            }],
+                        }),
+                    )
+                })
+                .collect();
+            AdminReport {
+                report_id: Uuid::new_v4(),
+                submission_id: Uuid::new_v4(),
+                org_id: format!("org-{}", report % 10),
+                org_name: format!("Organization {}", report % 10),
+                status: "generated".to_string(),
+                generated_at: "2026-01-15T10:30:00Z".to_string(),
+                data: json!([categories]),
+            }
+        })
+        .collect();
+    serde_json::to_value(AdminReportListResponse { reports }).expect("report list serializes")
+}
+
+fn app(reports: serde_json::Value) -> Router {
+    Router::new()
+        .route("/api/admin/reports", get(move || async move { Json(reports) }))
+        .layer(compression_layer(&CompressionConfig::default()))
+}
+
+async fn response_size(app: Router, encoding: &str) -> usize {
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/admin/reports")
+                .header(header::ACCEPT_ENCODING, encoding)
+                .body(Body::empty())
+                .expect("valid request"),
+        )
+        .await
+        .expect("infallible router");
+    to_bytes(response.into_body(), usize::MAX).await.expect("readable body").len()
+}
+
+fn bench_compression(c: &mut Criterion) {
+    let runtime = Runtime::new().expect("tokio runtime");
+    let app = app(report_list());
+
+    let uncompressed = runtime.block_on(response_size(app.clone(), "identity"));
+    for encoding in ["br", "gzip"] {
+        let compressed = runtime.block_on(response_size(app.clone(), encoding));
+        let reduction = 1.0 - compressed as f64 / uncompressed as f64;
+        println!("{encoding}: {uncompressed} -> {compressed} bytes ({:.1}% smaller)", reduction * 100.0);
+        assert!(reduction >= 0.7, "{encoding} saves only {:.1}%", reduction * 100.0);
+    }
+
+    let mut group = c.benchmark_group("admin_report_list");
+    for encoding in ["identity", "br", "gzip"] {
+        group.bench_function(encoding, |b| {
+            b.to_async(&runtime).iter(|| response_size(app.clone(), encoding))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_compression);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+//! Writes `build_info.rs` to `OUT_DIR`, included by `common::build_info`.
+//!
+//! Builds outside a git checkout, like the Docker image, can pass the commit in the
+//! `GIT_COMMIT` environment variable; values that are not a hex commit hash are ignored.
+//! Values that cannot be determined are written as "unknown".
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Trimmed standard output of `program`, if it ran successfully
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let output = String::from_utf8(output.stdout).ok()?;
+    Some(output.trim().to_string()).filter(|output| !output.is_empty())
+}
+
+/// The first 7 characters of `commit` when it is a hexadecimal commit hash
+fn short_commit(commit: &str) -> Option<String> {
+    let commit = commit.trim();
+    (commit.len() >= 7 && commit.bytes().all(|b| b.is_ascii_hexdigit())).then(|| commit[..7].to_string())
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    // Rebuild on a new commit: HEAD moves when switching branches, the branch ref on commit
+    for git_path in ["HEAD"].into_iter().map(str::to_string).chain(command_output("git", &["symbolic-ref", "-q", "HEAD"])) {
+        if let Some(path) = command_output("git", &["rev-parse", "--git-path", &git_path]) {
+            println!("cargo:rerun-if-changed={path}");
+        }
+    }
+
+    println!("cargo:rerun-if-env-changed=GIT_COMMIT");
+    let git_commit = env::var("GIT_COMMIT")
+        .ok()
+        .filter(|commit| !commit.is_empty())
+        .and_then(|commit| {
+            let short = short_commit(&commit);
+            if short.is_none() {
+                println!("cargo:warning=Ignoring GIT_COMMIT {commit:?}, it is not a commit hash");
+            }
+            short
+        })
+        .or_else(|| command_output("git", &["rev-parse", "--short=7", "HEAD"]).as_deref().and_then(short_commit));
+    let build_timestamp = command_output("date", &["-u", "+%Y-%m-%dT%H:%M:%SZ"]);
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rust_version = command_output(&rustc, &["--version"]);
+
+    let constants = [
+        ("GIT_COMMIT", git_commit),
+        ("BUILD_TIMESTAMP", build_timestamp),
+        ("RUST_VERSION", rust_version),
+    ]
+    .map(|(name, value)| format!("pub const {name}: &str = {:?};\n", value.as_deref().unwrap_or("unknown")))
+    .concat();
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("build_info.rs"), constants).expect("Failed to write build_info.rs");
+}
@@ -0,0 +1,34 @@
+use envconfig::Envconfig;
+
+/// Configuration of the DGAT service, read from `DGAT_*` environment variables or `.env`
+#[derive(Debug, Clone, Envconfig)]
+pub struct DgatConfigs {
+    #[envconfig(nested = true)]
+    pub server: DgatServerConfigs,
+    #[envconfig(nested = true)]
+    pub database: DgatDatabaseConfigs,
+}
+
+#[derive(Debug, Clone, Envconfig)]
+pub struct DgatServerConfigs {
+    #[envconfig(from = "DGAT_SERVER_HOST", default = "0.0.0.0")]
+    pub host: String,
+    #[envconfig(from = "DGAT_SERVER_PORT", default = "3002")]
+    pub port: u16,
+}
+
+#[derive(Debug, Clone, Envconfig)]
+pub struct DgatDatabaseConfigs {
+    #[envconfig(from = "DGAT_DATABASE_URL")]
+    pub url: String,
+    /// Postgres schema holding the service's tables, created on startup
+    #[envconfig(from = "DGAT_DATABASE_SCHEMA", default = "dgat")]
+    pub schema: String,
+}
+
+impl DgatConfigs {
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        dotenvy::dotenv().ok();
+        Ok(DgatConfigs::init_from_env()?)
+    }
+}
@@ -0,0 +1,16 @@
+use sea_orm_migration::prelude::*;
+
+/// Migrations of the DGAT service's schema, tracked apart from the sustainability
+/// tool's in `dgat_seaql_migrations`
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migration_table_name() -> DynIden {
+        Alias::new("dgat_seaql_migrations").into_iden()
+    }
+
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![]
+    }
+}
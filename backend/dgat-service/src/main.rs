@@ -0,0 +1,65 @@
+//! DGAT service
+//!
+//! Backend of the Digital Gap Assessment Tool (DGAT), the second of the DGRV digital
+//! tools next to the sustainability tool (see `docs/README.md`). It will own what is
+//! specific to the digital gap assessment: its question catalogue of digitalisation
+//! maturity dimensions, the cooperatives' assessments against it and the gap reports
+//! derived from them. Authentication stays with Keycloak, shared with the
+//! sustainability tool.
+//!
+//! The service keeps its data in its own Postgres schema (`DGAT_DATABASE_SCHEMA`),
+//! migrated by `dgat_migrations`, so it can share a database server with the
+//! sustainability tool without their tables or migration histories mixing. For now it
+//! only serves `GET /health` and `GET /api/v1/status`.
+
+mod config;
+mod dgat_migrations;
+mod routes;
+mod state;
+
+use sustainability_tool::common::logging::init_tracing;
+use sustainability_tool::web::shutdown::{serve_until, shutdown_signal};
+use tracing::Instrument;
+
+use crate::config::DgatConfigs;
+use crate::routes::create_app;
+use crate::state::{initialize_database, DgatAppState};
+
+pub const SERVICE_NAME: &str = "dgat-service";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Initialize tracing, as text or JSON depending on RUST_LOG_FORMAT
+    init_tracing();
+
+    let service_span = tracing::info_span!(
+        "service",
+        service.name = SERVICE_NAME,
+        service.version = env!("CARGO_PKG_VERSION")
+    );
+
+    startup().instrument(service_span).await
+}
+
+async fn startup() -> Result<(), Box<dyn std::error::Error>> {
+    let config = DgatConfigs::new()?;
+
+    tracing::info!("Starting DGAT service");
+
+    let db = initialize_database(&config.database).await?;
+    let app_state = DgatAppState::new(db);
+    let app = create_app(app_state.clone());
+
+    let bind_address = format!("{}:{}", config.server.host, config.server.port);
+    let listener = tokio::net::TcpListener::bind(&bind_address).await?;
+    tracing::info!("Server started and listening");
+
+    // Stop on SIGTERM/SIGINT once in-flight requests have finished
+    serve_until(listener, app, shutdown_signal()).await?;
+
+    tracing::info!("Closing database connections");
+    app_state.db.close_by_ref().await?;
+    tracing::info!("Shutdown complete");
+
+    Ok(())
+}
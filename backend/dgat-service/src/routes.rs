@@ -0,0 +1,80 @@
+use axum::{extract::State, http::StatusCode, routing::get, Json, Router};
+use serde_json::{json, Value};
+use tower_http::trace::TraceLayer;
+
+use crate::state::DgatAppState;
+use crate::SERVICE_NAME;
+
+pub fn create_app(app_state: DgatAppState) -> Router {
+    Router::new()
+        .route("/health", get(health))
+        .route("/api/v1/status", get(status))
+        .layer(TraceLayer::new_for_http())
+        .with_state(app_state)
+}
+
+/// Liveness: the process is up and serving requests
+async fn health() -> Json<Value> {
+    Json(json!({ "status": "ok" }))
+}
+
+/// Version, uptime and whether the database answers
+async fn status(State(app_state): State<DgatAppState>) -> (StatusCode, Json<Value>) {
+    let database_up = app_state.db.ping().await.is_ok();
+    let status = if database_up {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (
+        status,
+        Json(json!({
+            "service": SERVICE_NAME,
+            "version": env!("CARGO_PKG_VERSION"),
+            "uptime_secs": app_state.started_at.elapsed().as_secs(),
+            "database": if database_up { "up" } else { "down" },
+        })),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use sea_orm::Database;
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    async fn get_json(app: Router, uri: &str) -> Result<(StatusCode, Value), Box<dyn std::error::Error>> {
+        let response = app.oneshot(Request::builder().uri(uri).body(Body::empty())?).await?;
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok((status, serde_json::from_slice(&body)?))
+    }
+
+    async fn app() -> Result<Router, Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        Ok(create_app(DgatAppState::new(Arc::new(db))))
+    }
+
+    #[tokio::test]
+    async fn test_health() -> Result<(), Box<dyn std::error::Error>> {
+        let (status, body) = get_json(app().await?, "/health").await?;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!({ "status": "ok" }));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_status_reports_database() -> Result<(), Box<dyn std::error::Error>> {
+        let (status, body) = get_json(app().await?, "/api/v1/status").await?;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["service"], SERVICE_NAME);
+        assert_eq!(body["database"], "up");
+        Ok(())
+    }
+}
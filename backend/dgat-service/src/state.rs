@@ -0,0 +1,45 @@
+use sea_orm::{ConnectOptions, ConnectionTrait, Database, DatabaseConnection, DbErr, Statement};
+use sea_orm_migration::MigratorTrait;
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::config::DgatDatabaseConfigs;
+use crate::dgat_migrations::Migrator;
+
+/// State shared by the DGAT service's handlers, independent of the sustainability
+/// tool's `AppState`
+#[derive(Clone)]
+pub struct DgatAppState {
+    pub db: Arc<DatabaseConnection>,
+    pub started_at: Instant,
+}
+
+impl DgatAppState {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            db,
+            started_at: Instant::now(),
+        }
+    }
+}
+
+/// Connect with `config.schema` as search path, create the schema if needed and
+/// run the DGAT migrations in it
+pub async fn initialize_database(config: &DgatDatabaseConfigs) -> Result<Arc<DatabaseConnection>, DbErr> {
+    let mut options = ConnectOptions::new(config.url.clone());
+    options.set_schema_search_path(config.schema.clone());
+    let conn = Database::connect(options).await?;
+
+    let backend = conn.get_database_backend();
+    conn.execute(Statement::from_string(
+        backend,
+        format!("CREATE SCHEMA IF NOT EXISTS \"{}\"", config.schema.replace('"', "\"\"")),
+    ))
+    .await?;
+
+    tracing::info!(schema = %config.schema, "Running DGAT database migrations");
+    Migrator::up(&conn, None).await?;
+    tracing::info!("Database connection established successfully");
+
+    Ok(Arc::new(conn))
+}
@@ -0,0 +1,40 @@
+//! Version and build metadata, served by `GET /version` and logged at startup.
+//!
+//! The commit, build time and compiler version are written by `build.rs`.
+
+use serde::Serialize;
+use utoipa::ToSchema;
+
+mod generated {
+    include!(concat!(env!("OUT_DIR"), "/build_info.rs"));
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+pub struct BuildInfo {
+    pub version: &'static str,
+    /// Short hash of the commit built, "unknown" outside a git checkout
+    pub git_commit: &'static str,
+    /// UTC, RFC 3339
+    pub build_timestamp: &'static str,
+    pub rust_version: &'static str,
+}
+
+impl BuildInfo {
+    pub const CURRENT: BuildInfo = BuildInfo {
+        version: env!("CARGO_PKG_VERSION"),
+        git_commit: generated::GIT_COMMIT,
+        build_timestamp: generated::BUILD_TIMESTAMP,
+        rust_version: generated::RUST_VERSION,
+    };
+
+    /// One line for shell scripts, e.g. `sustainability-tool 0.0.1 (abc1234, built 2026-01-01T00:00:00Z)`
+    pub fn version_line(&self) -> String {
+        format!(
+            "{} {} ({}, built {})",
+            env!("CARGO_PKG_NAME"),
+            self.version,
+            self.git_commit,
+            self.build_timestamp
+        )
+    }
+}
@@ -1,13 +1,15 @@
 use std::collections::HashMap;
 use std::cell::RefCell;
 use std::sync::{Arc, RwLock};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use jsonwebtoken::DecodingKey;
 use uuid::Uuid;
 use tokio::task_local;
 
 use crate::common::database::entity::assessments::Model as AssessmentModel;
 use crate::common::database::entity::assessments_submission::Model as SubmissionModel;
 use crate::common::database::entity::file::Model as FileModel;
+use crate::common::services::score_engine::SectorPool;
 
 /// Request-level cache for storing frequently accessed data within a single request
 #[derive(Default)]
@@ -200,6 +202,13 @@ impl UserSessionCache {
         self.user_temp_submissions.insert(assessment_id, temp_submission);
     }
 
+    /// Drop everything cached about one assessment of an organization
+    pub fn forget_assessment(&mut self, org_id: &str, assessment_id: &Uuid) {
+        self.user_assessments.remove(org_id);
+        self.user_submissions.remove(assessment_id);
+        self.user_temp_submissions.remove(assessment_id);
+    }
+
     /// Clear all cached data for this user
     pub fn clear(&mut self) {
         self.user_assessments.clear();
@@ -265,6 +274,15 @@ impl SessionCache {
         }
     }
 
+    /// Drop an assessment from every user's cache, for changes made on behalf of its organization
+    pub fn invalidate_assessment(&self, org_id: &str, assessment_id: &Uuid) {
+        if let Ok(mut users) = self.users.write() {
+            for cache in users.values_mut() {
+                cache.forget_assessment(org_id, assessment_id);
+            }
+        }
+    }
+
     /// Clear all caches (useful for testing)
     pub fn clear_all(&self) {
         if let Ok(mut users) = self.users.write() {
@@ -281,6 +299,82 @@ impl Clone for SessionCache {
     }
 }
 
+/// Keycloak's token signing keys by `kid`, fetched from the realm's JWKS endpoint.
+///
+/// Keys are trusted for `ttl` after a fetch. Stale keys are kept so they can still
+/// be used when Keycloak cannot be reached for a refresh.
+pub struct JwksCache {
+    keys: HashMap<String, DecodingKey>,
+    fetched_at: Option<Instant>,
+    ttl: Duration,
+}
+
+impl JwksCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            keys: HashMap::new(),
+            fetched_at: None,
+            ttl,
+        }
+    }
+
+    /// Whether the keys were fetched less than `ttl` ago
+    pub fn is_fresh(&self) -> bool {
+        self.fetched_within(self.ttl)
+    }
+
+    /// Whether the keys were fetched less than `interval` ago
+    pub fn fetched_within(&self, interval: Duration) -> bool {
+        self.fetched_at.is_some_and(|fetched_at| fetched_at.elapsed() < interval)
+    }
+
+    /// Key for `kid`, fresh or not
+    pub fn get(&self, kid: &str) -> Option<&DecodingKey> {
+        self.keys.get(kid)
+    }
+
+    /// Replace all keys with a newly fetched set
+    pub fn replace(&mut self, keys: HashMap<String, DecodingKey>) {
+        self.keys = keys;
+        self.fetched_at = Some(Instant::now());
+    }
+}
+
+/// A pool and when it was built
+type BuiltPool = (Instant, Arc<SectorPool>);
+
+/// Comparison pool for report benchmarks, rebuilt from the database once `ttl` has passed.
+///
+/// Building it scores every report of the last year, so it is shared by all requests.
+#[derive(Clone)]
+pub struct SectorPoolCache {
+    pool: Arc<RwLock<Option<BuiltPool>>>,
+    ttl: Duration,
+}
+
+impl SectorPoolCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            pool: Arc::new(RwLock::new(None)),
+            ttl,
+        }
+    }
+
+    /// The cached pool, unless it is older than `ttl`
+    pub fn get(&self) -> Option<Arc<SectorPool>> {
+        let pool = self.pool.read().ok()?;
+        pool.as_ref()
+            .filter(|(built_at, _)| built_at.elapsed() < self.ttl)
+            .map(|(_, pool)| pool.clone())
+    }
+
+    pub fn replace(&self, pool: Arc<SectorPool>) {
+        if let Ok(mut cached) = self.pool.write() {
+            *cached = Some((Instant::now(), pool));
+        }
+    }
+}
+
 /// Cached wrapper functions for common database operations
 pub mod cached_ops {
     use super::*;
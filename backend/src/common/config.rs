@@ -1,5 +1,9 @@
+use crate::common::services::secrets::{secrets_provider, SECRET_KEYS};
 use envconfig::Envconfig;
 use serde::Deserialize;
+use std::collections::HashMap;
+use std::ops::Deref;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Deserialize, Envconfig)]
 pub struct Configs {
@@ -9,6 +13,30 @@ pub struct Configs {
     pub server: ServerConfigs,
     #[envconfig(nested = true)]
     pub cors: CorsConfigs,
+    #[envconfig(nested = true)]
+    pub rate_limit: RateLimitConfig,
+    #[envconfig(nested = true)]
+    pub upload: UploadConfig,
+    #[envconfig(nested = true)]
+    pub limits: LimitsConfig,
+    #[envconfig(nested = true)]
+    pub storage: StorageConfig,
+    #[envconfig(nested = true)]
+    pub secrets: SecretsConfig,
+    #[envconfig(nested = true)]
+    pub sync: SyncConfig,
+    #[envconfig(nested = true)]
+    pub locale: LocaleConfig,
+    #[envconfig(nested = true)]
+    pub seeder: SeederConfig,
+    #[envconfig(nested = true)]
+    pub email: EmailConfig,
+    #[envconfig(nested = true)]
+    pub database: DatabaseConfig,
+    #[envconfig(nested = true)]
+    pub migrations: MigrationsConfig,
+    #[envconfig(nested = true)]
+    pub encryption: EncryptionConfig,
 }
 
 #[derive(Debug, Clone, Deserialize, Envconfig)]
@@ -19,6 +47,106 @@ pub struct KeycloakConfigs {
     pub realm: String,
     #[envconfig(from = "KEYCLOAK_CLIENT_ID")]
     pub client_id: String,
+    /// Composite realm roles to expand in tokens, see `Claims::expand_composite_roles`
+    #[envconfig(from = "KEYCLOAK_COMPOSITE_ROLES", default = r#"{"org_admin":["org_user"]}"#)]
+    pub composite_roles: CompositeRoles,
+    /// Required `iss` of access tokens; defaults to the realm URL over HTTPS or HTTP
+    #[envconfig(from = "KEYCLOAK_ISSUER")]
+    pub issuer: Option<String>,
+    /// Accepted `aud` values of access tokens, comma separated
+    #[envconfig(from = "KEYCLOAK_AUDIENCES", default = "sustainability-tool,account")]
+    pub audiences: Audiences,
+    /// How often the realm's token signing keys are fetched again, see `JwtValidator::spawn_key_refresh`
+    #[envconfig(from = "KEYCLOAK_JWKS_REFRESH_INTERVAL_SECS", default = "3600")]
+    pub jwks_refresh_interval_secs: u64,
+}
+
+/// The local development realm, as in `.env`
+impl Default for KeycloakConfigs {
+    fn default() -> Self {
+        Self {
+            url: "http://localhost:8080".to_string(),
+            realm: "sustainability-realm".to_string(),
+            client_id: "sustainability-tool".to_string(),
+            composite_roles: Default::default(),
+            issuer: None,
+            audiences: Default::default(),
+            jwks_refresh_interval_secs: 3600,
+        }
+    }
+}
+
+/// Realm role -> roles it includes, given as JSON in `KEYCLOAK_COMPOSITE_ROLES`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CompositeRoles(pub HashMap<String, Vec<String>>);
+
+impl Default for CompositeRoles {
+    fn default() -> Self {
+        Self(HashMap::from([("org_admin".to_string(), vec!["org_user".to_string()])]))
+    }
+}
+
+impl FromStr for CompositeRoles {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map(Self)
+    }
+}
+
+impl Deref for CompositeRoles {
+    type Target = HashMap<String, Vec<String>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Token audiences, given comma separated in `KEYCLOAK_AUDIENCES`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct Audiences(pub Vec<String>);
+
+impl Default for Audiences {
+    fn default() -> Self {
+        Self(vec!["sustainability-tool".to_string(), "account".to_string()])
+    }
+}
+
+impl FromStr for Audiences {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(
+            s.split(',')
+                .map(str::trim)
+                .filter(|audience| !audience.is_empty())
+                .map(str::to_string)
+                .collect(),
+        ))
+    }
+}
+
+impl KeycloakConfigs {
+    /// Issuers tokens are accepted from: the configured one, or else the realm URL
+    /// over HTTPS and, leniently, HTTP
+    pub fn expected_issuers(&self) -> Vec<String> {
+        if let Some(issuer) = &self.issuer {
+            return vec![issuer.clone()];
+        }
+        let https_iss = format!("{}/realms/{}", self.url, self.realm);
+        let http_iss = https_iss.replace("https://", "http://");
+        vec![https_iss, http_iss]
+    }
+
+    /// OpenID Connect authorization endpoint of the realm
+    pub fn authorization_url(&self) -> String {
+        format!("{}/realms/{}/protocol/openid-connect/auth", self.url, self.realm)
+    }
+
+    /// OpenID Connect token endpoint of the realm
+    pub fn token_url(&self) -> String {
+        format!("{}/realms/{}/protocol/openid-connect/token", self.url, self.realm)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Envconfig)]
@@ -27,6 +155,33 @@ pub struct ServerConfigs {
     pub host: String,
     #[envconfig(from = "SERVER_PORT", default = "3001")]
     pub port: u16,
+    /// Public Keycloak client Swagger UI logs in with, see `web::routes::docs_routes`
+    #[envconfig(from = "OAUTH2_CLIENT_ID", default = "swagger-ui")]
+    pub oauth2_client_id: String,
+    #[envconfig(nested = true)]
+    pub compression: CompressionConfig,
+    /// Reject request bodies with fields the endpoint does not know, see `web::api::strict_json`
+    #[envconfig(from = "STRICT_REQUEST_VALIDATION", default = "false")]
+    pub strict_request_validation: bool,
+}
+
+/// Response compression, see `web::routes::compression_layer`
+#[derive(Debug, Clone, Deserialize, Envconfig)]
+pub struct CompressionConfig {
+    #[envconfig(from = "COMPRESSION_ENABLED", default = "true")]
+    pub enabled: bool,
+    /// Smaller responses are sent uncompressed
+    #[envconfig(from = "COMPRESSION_MIN_SIZE_BYTES", default = "1024")]
+    pub min_size_bytes: u64,
+}
+
+impl Default for CompressionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            min_size_bytes: 1024,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Envconfig)]
@@ -35,9 +190,337 @@ pub struct CorsConfigs {
     pub origin: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Envconfig)]
+pub struct RateLimitConfig {
+    #[envconfig(from = "RATE_LIMIT_REQUESTS_PER_MINUTE", default = "60")]
+    pub requests_per_minute: u32,
+    #[envconfig(from = "RATE_LIMIT_BURST_SIZE", default = "60")]
+    pub burst_size: u32,
+}
+
+impl Default for RateLimitConfig {
+    fn default() -> Self {
+        Self {
+            requests_per_minute: 60,
+            burst_size: 60,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Envconfig)]
+pub struct UploadConfig {
+    #[envconfig(from = "MAX_FILE_BYTES", default = "1048576")]
+    pub max_file_bytes: usize,
+}
+
+impl Default for UploadConfig {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: 1024 * 1024,
+        }
+    }
+}
+
+/// Caps on how much data a single organization can accumulate
+#[derive(Debug, Clone, Deserialize, Envconfig)]
+pub struct LimitsConfig {
+    /// Can be raised per organization with its `max_assessments_override` Keycloak attribute
+    #[envconfig(from = "MAX_ASSESSMENTS_PER_ORG", default = "50")]
+    pub max_assessments_per_org: u32,
+    /// Unsubmitted assessments older than this count as overdue on the admin dashboard
+    #[envconfig(from = "ASSESSMENT_OVERDUE_DAYS", default = "30")]
+    pub assessment_overdue_days: u32,
+}
+
+impl Default for LimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_assessments_per_org: 50,
+            assessment_overdue_days: 30,
+        }
+    }
+}
+
+/// Languages tried, in order, when a text is missing in the requested one
+#[derive(Debug, Clone, Deserialize, Envconfig)]
+pub struct LocaleConfig {
+    /// Comma separated, e.g. `fr,en`; any available language is used after these
+    #[envconfig(from = "LANGUAGE_FALLBACK", default = "en")]
+    pub language_fallback: LanguageFallback,
+}
+
+impl Default for LocaleConfig {
+    fn default() -> Self {
+        Self {
+            language_fallback: LanguageFallback(vec!["en".to_string()]),
+        }
+    }
+}
+
+/// Test data endpoints, see `services::seed`
+#[derive(Debug, Clone, Default, Deserialize, Envconfig)]
+pub struct SeederConfig {
+    /// Mounts `/internal/seed`, in debug builds only
+    #[envconfig(from = "ENABLE_TEST_SEEDER", default = "false")]
+    pub enabled: bool,
+}
+
+/// Database connection, see `database::init::initialize_database`
+#[derive(Clone, Default, Deserialize, Envconfig)]
+pub struct DatabaseConfig {
+    /// One of `SECRET_KEYS`, so it can come from the secrets provider
+    #[envconfig(from = "DATABASE_URL")]
+    pub url: String,
+}
+
+impl std::fmt::Debug for DatabaseConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatabaseConfig").field("url", &"<redacted>").finish()
+    }
+}
+
+/// Schema migrations at startup, see `database::init::initialize_database`
+#[derive(Debug, Clone, Deserialize, Envconfig)]
+pub struct MigrationsConfig {
+    /// Run pending migrations at startup; otherwise API requests get 503 until they
+    /// have been applied by hand
+    #[envconfig(from = "MIGRATIONS_AUTO_RUN", default = "true")]
+    pub auto_run: bool,
+}
+
+impl Default for MigrationsConfig {
+    fn default() -> Self {
+        Self { auto_run: true }
+    }
+}
+
+/// Language codes, given comma separated in `LANGUAGE_FALLBACK`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct LanguageFallback(pub Vec<String>);
+
+impl FromStr for LanguageFallback {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(
+            s.split(',')
+                .map(str::trim)
+                .filter(|language| !language.is_empty())
+                .map(str::to_string)
+                .collect(),
+        ))
+    }
+}
+
+impl Deref for LanguageFallback {
+    type Target = Vec<String>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Where uploaded file bytes are kept: `database` (inline, the default) or `s3`
+#[derive(Debug, Clone, Deserialize, Envconfig)]
+pub struct StorageConfig {
+    #[envconfig(from = "STORAGE_BACKEND", default = "database")]
+    pub backend: String,
+    #[envconfig(from = "S3_BUCKET")]
+    pub s3_bucket: Option<String>,
+    #[envconfig(from = "S3_REGION")]
+    pub s3_region: Option<String>,
+    #[envconfig(from = "S3_ENDPOINT")]
+    pub s3_endpoint: Option<String>,
+}
+
+impl Default for StorageConfig {
+    fn default() -> Self {
+        Self {
+            backend: "database".to_string(),
+            s3_bucket: None,
+            s3_region: None,
+            s3_endpoint: None,
+        }
+    }
+}
+
+/// Where sensitive values come from: `env` (the default), `ssm` or `vault`, see `services::secrets`
+#[derive(Debug, Clone, Deserialize, Envconfig)]
+pub struct SecretsConfig {
+    #[envconfig(from = "SECRETS_PROVIDER", default = "env")]
+    pub provider: String,
+    #[envconfig(from = "SECRETS_PREFIX", default = "/dgat/")]
+    pub prefix: String,
+    #[envconfig(from = "SSM_ENDPOINT")]
+    pub ssm_endpoint: Option<String>,
+}
+
+impl Default for SecretsConfig {
+    fn default() -> Self {
+        Self {
+            provider: "env".to_string(),
+            prefix: "/dgat/".to_string(),
+            ssm_endpoint: None,
+        }
+    }
+}
+
+/// Background jobs against Keycloak, see `services::organizations_cache` and `services::invitation_expiry`
+#[derive(Clone, Deserialize, Envconfig)]
+pub struct SyncConfig {
+    /// 0 turns the periodic refresh off
+    #[envconfig(from = "ORG_CACHE_REFRESH_INTERVAL_SECS", default = "300")]
+    pub org_cache_refresh_interval_secs: u64,
+    /// Secret of `KEYCLOAK_CLIENT_ID`, whose service account the refresh runs as
+    #[envconfig(from = "KEYCLOAK_CLIENT_SECRET")]
+    pub keycloak_client_secret: Option<String>,
+    /// How often pending invitations are checked for expiry, see `services::invitation_expiry`; 0 turns it off
+    #[envconfig(from = "INVITATION_EXPIRY_INTERVAL_SECS", default = "3600")]
+    pub invitation_expiry_interval_secs: u64,
+    /// Age after which a pending invitation is deleted, at most `MAX_INVITATION_TTL_SECS`
+    #[envconfig(from = "INVITATION_TTL_SECS", default = "604800")]
+    pub invitation_ttl_secs: u64,
+    /// Also disable the invited user when they never verified their email
+    #[envconfig(from = "INVITATION_EXPIRY_DISABLE_UNVERIFIED", default = "false")]
+    pub disable_unverified_users: bool,
+}
+
+impl std::fmt::Debug for SyncConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncConfig")
+            .field("org_cache_refresh_interval_secs", &self.org_cache_refresh_interval_secs)
+            .field("keycloak_client_secret", &self.keycloak_client_secret.as_ref().map(|_| "<redacted>"))
+            .field("invitation_expiry_interval_secs", &self.invitation_expiry_interval_secs)
+            .field("invitation_ttl_secs", &self.invitation_ttl_secs)
+            .field("disable_unverified_users", &self.disable_unverified_users)
+            .finish()
+    }
+}
+
+/// Longest accepted `INVITATION_TTL_SECS`, one year
+pub const MAX_INVITATION_TTL_SECS: u64 = 365 * 24 * 60 * 60;
+
+impl SyncConfig {
+    /// Refuse values the background jobs cannot turn into a `chrono::Duration`
+    fn validate(&self) -> Result<(), String> {
+        if self.invitation_ttl_secs > MAX_INVITATION_TTL_SECS {
+            return Err(format!(
+                "INVITATION_TTL_SECS must be at most {MAX_INVITATION_TTL_SECS}, got {}",
+                self.invitation_ttl_secs
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for SyncConfig {
+    fn default() -> Self {
+        Self {
+            org_cache_refresh_interval_secs: 300,
+            keycloak_client_secret: None,
+            invitation_expiry_interval_secs: 3600,
+            invitation_ttl_secs: 7 * 24 * 60 * 60,
+            disable_unverified_users: false,
+        }
+    }
+}
+
+/// Outgoing mail, see `services::email`; without `SMTP_HOST` Keycloak sends all emails
+#[derive(Clone, Deserialize, Envconfig)]
+pub struct EmailConfig {
+    #[envconfig(from = "SMTP_HOST")]
+    pub smtp_host: Option<String>,
+    #[envconfig(from = "SMTP_PORT", default = "587")]
+    pub smtp_port: u16,
+    #[envconfig(from = "SMTP_USERNAME")]
+    pub smtp_username: Option<String>,
+    #[envconfig(from = "SMTP_PASSWORD")]
+    pub smtp_password: Option<String>,
+    /// Sender mailbox, e.g. `DGAT <no-reply@example.org>`
+    #[envconfig(from = "EMAIL_FROM", default = "no-reply@localhost")]
+    pub from: String,
+    /// Linked from invitation emails
+    #[envconfig(from = "EMAIL_APP_URL", default = "http://localhost:5173")]
+    pub app_url: String,
+}
+
+impl std::fmt::Debug for EmailConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EmailConfig")
+            .field("smtp_host", &self.smtp_host)
+            .field("smtp_port", &self.smtp_port)
+            .field("smtp_username", &self.smtp_username)
+            .field("smtp_password", &self.smtp_password.as_ref().map(|_| "<redacted>"))
+            .field("from", &self.from)
+            .field("app_url", &self.app_url)
+            .finish()
+    }
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            smtp_host: None,
+            smtp_port: 587,
+            smtp_username: None,
+            smtp_password: None,
+            from: "no-reply@localhost".to_string(),
+            app_url: "http://localhost:5173".to_string(),
+        }
+    }
+}
+
+/// Encryption of answers to sensitive questions, see `services::encryption`
+#[derive(Clone, Default, Deserialize, Envconfig)]
+pub struct EncryptionConfig {
+    /// 32 random bytes, base64 encoded; one of `SECRET_KEYS`, so it can come from the secrets provider
+    #[envconfig(from = "ENCRYPTION_KEY_BASE64")]
+    pub key_base64: Option<String>,
+}
+
+impl std::fmt::Debug for EncryptionConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionConfig")
+            .field("key_base64", &self.key_base64.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
 impl Configs {
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
         dotenvy::dotenv().ok();
-        Ok(Configs::init_from_env()?)
+        let configs = Configs::init_from_env()?;
+        configs.sync.validate()?;
+        Ok(configs)
+    }
+
+    /// Like `new`, but `SECRET_KEYS` from the configured secrets provider take the
+    /// place of the environment's values. The process environment is left untouched.
+    pub async fn load() -> Result<Self, Box<dyn std::error::Error>> {
+        dotenvy::dotenv().ok();
+        let secrets_config = SecretsConfig::init_from_env()?;
+        let provider = secrets_provider(&secrets_config).await?;
+        let mut vars: HashMap<String, String> = std::env::vars().collect();
+        vars.extend(provider.fetch(SECRET_KEYS).await?);
+        let configs = Configs::init_from_hashmap(&vars)?;
+        configs.sync.validate()?;
+        Ok(configs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invitation_ttl_above_a_year_is_refused() {
+        let sync = |ttl: u64| {
+            SyncConfig::init_from_hashmap(&HashMap::from([("INVITATION_TTL_SECS".to_string(), ttl.to_string())]))
+                .unwrap()
+        };
+
+        assert!(sync(MAX_INVITATION_TTL_SECS).validate().is_ok());
+        assert!(sync(MAX_INVITATION_TTL_SECS + 1).validate().is_err());
+        assert!(sync(u64::MAX).validate().is_err());
     }
 }
@@ -0,0 +1,41 @@
+use sea_orm::entity::prelude::*;
+
+/// Question an assessment started from a template was set up with
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "assessment_questions")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub assessment_id: Uuid,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub question_id: Uuid,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::assessments::Entity",
+        from = "Column::AssessmentId",
+        to = "super::assessments::Column::AssessmentId"
+    )]
+    Assessments,
+    #[sea_orm(
+        belongs_to = "super::questions::Entity",
+        from = "Column::QuestionId",
+        to = "super::questions::Column::QuestionId"
+    )]
+    Questions,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Related<super::assessments::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Assessments.def()
+    }
+}
+
+impl Related<super::questions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Questions.def()
+    }
+}
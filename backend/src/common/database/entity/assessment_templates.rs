@@ -0,0 +1,83 @@
+use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
+use crate::impl_database_entity;
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::{QueryOrder, Set};
+use std::sync::Arc;
+
+/// Assessment setup an organization admin can start a draft from
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "assessment_templates")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub template_id: Uuid,
+    pub name: String,
+    pub language: String,
+    pub category_ids: Json, // JSON array of category_catalog ids
+    pub question_ids: Json, // JSON array of question ids
+    pub created_by: String, // Keycloak user id
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl_database_entity!(Entity, Column::TemplateId);
+
+impl Model {
+    pub fn category_ids(&self) -> Vec<Uuid> {
+        serde_json::from_value(self.category_ids.clone()).unwrap_or_default()
+    }
+
+    pub fn question_ids(&self) -> Vec<Uuid> {
+        serde_json::from_value(self.question_ids.clone()).unwrap_or_default()
+    }
+}
+
+#[derive(Clone)]
+pub struct AssessmentTemplatesService {
+    db_service: DatabaseService<Entity>,
+}
+
+impl AssessmentTemplatesService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            db_service: DatabaseService::new(db),
+        }
+    }
+
+    pub async fn create_template(
+        &self,
+        name: String,
+        language: String,
+        category_ids: &[Uuid],
+        question_ids: &[Uuid],
+        created_by: String,
+    ) -> Result<Model, DbErr> {
+        let template = ActiveModel {
+            template_id: Set(Uuid::new_v4()),
+            name: Set(name),
+            language: Set(language),
+            category_ids: Set(serde_json::json!(category_ids)),
+            question_ids: Set(serde_json::json!(question_ids)),
+            created_by: Set(created_by),
+            created_at: Set(Utc::now()),
+        };
+
+        self.db_service.create(template).await
+    }
+
+    pub async fn get_template(&self, template_id: Uuid) -> Result<Option<Model>, DbErr> {
+        self.db_service.find_by_id(template_id).await
+    }
+
+    /// All templates, ordered by name
+    pub async fn list_templates(&self) -> Result<Vec<Model>, DbErr> {
+        Entity::find()
+            .order_by_asc(Column::Name)
+            .all(self.db_service.get_connection())
+            .await
+    }
+}
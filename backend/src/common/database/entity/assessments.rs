@@ -2,8 +2,14 @@ use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
 use crate::impl_database_entity;
 use chrono::{DateTime, Utc};
 use sea_orm::entity::prelude::*;
-use sea_orm::{ActiveModelBehavior, DeleteResult, Set};
+use sea_orm::{
+    ActiveModelBehavior, DeleteResult, JoinType, PaginatorTrait, QueryOrder, QuerySelect, Set,
+    TransactionTrait,
+};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use thiserror::Error;
+use super::assessments_response::AssessmentsResponseService;
 use super::assessments_submission::AssessmentsSubmissionService;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
@@ -48,6 +54,39 @@ impl ActiveModelBehavior for ActiveModel {}
 
 impl_database_entity!(Entity, Column::AssessmentId);
 
+/// One row of an organization's assessment overview
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrgAssessmentSummary {
+    pub assessment_id: Uuid,
+    pub name: String,
+    /// `draft` until submitted, then the submission's review status
+    pub status: String,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub has_report: bool,
+}
+
+#[derive(Error, Debug)]
+pub enum DeleteDraftError {
+    #[error("Assessment not found")]
+    NotFound,
+    #[error("Assessment belongs to another organization")]
+    OtherOrganization,
+    #[error("Assessment has already been submitted")]
+    Submitted,
+    #[error("Database error: {0}")]
+    Database(#[from] DbErr),
+}
+
+#[derive(Error, Debug)]
+pub enum UnlockError {
+    #[error("Assessment not found")]
+    NotFound,
+    #[error("Assessment is not locked")]
+    NotLocked,
+    #[error("Database error: {0}")]
+    Database(#[from] DbErr),
+}
+
 // AssessmentsService implementation
 #[allow(dead_code)]
 #[derive(Clone)]
@@ -82,6 +121,20 @@ impl AssessmentsService {
         language: String,
         name: String,
         category_ids: Vec<Uuid>,
+    ) -> Result<Model, DbErr> {
+        self.create_assessment_with_questions(org_id, language, name, category_ids, Vec::new())
+            .await
+    }
+
+    /// Like `create_assessment`, also recording the questions the assessment is set up
+    /// with, e.g. those of the template it is started from
+    pub async fn create_assessment_with_questions(
+        &self,
+        org_id: String,
+        language: String,
+        name: String,
+        category_ids: Vec<Uuid>,
+        question_ids: Vec<Uuid>,
     ) -> Result<Model, DbErr> {
         let assessment_model = ActiveModel {
             assessment_id: Set(Uuid::new_v4()),
@@ -109,6 +162,21 @@ impl AssessmentsService {
                 .await?;
         }
 
+        if !question_ids.is_empty() {
+            let assessment_questions_models: Vec<super::assessment_questions::ActiveModel> =
+                question_ids
+                    .into_iter()
+                    .map(|question_id| super::assessment_questions::ActiveModel {
+                        assessment_id: Set(created_assessment.assessment_id),
+                        question_id: Set(question_id),
+                    })
+                    .collect();
+
+            super::assessment_questions::Entity::insert_many(assessment_questions_models)
+                .exec(self.db_service.get_connection())
+                .await?;
+        }
+
         Ok(created_assessment)
     }
 
@@ -145,6 +213,346 @@ impl AssessmentsService {
         self.db_service.update(assessment).await
     }
 
+    /// Assessments created before `cutoff` that were never submitted, in one count query
+    pub async fn count_unsubmitted_before(&self, cutoff: DateTime<Utc>) -> Result<u64, DbErr> {
+        Entity::find()
+            .join(JoinType::LeftJoin, Relation::AssessmentsSubmission.def())
+            .filter(super::assessments_submission::Column::SubmissionId.is_null())
+            .filter(Column::CreatedAt.lt(cutoff))
+            .count(self.db_service.get_connection())
+            .await
+    }
+
+    /// Share of the questions in `category_ids` that have at least one response in this
+    /// assessment, as a percentage. Both sides are computed with count queries, unless
+    /// some of the questions have skip rules (see `common::services::skip_logic`):
+    /// questions those rules skip count on neither side, and the rules are applied to
+    /// the answers read through `responses`.
+    pub async fn get_completion_percent(
+        &self,
+        assessment_id: Uuid,
+        category_ids: &[Uuid],
+        responses: &AssessmentsResponseService,
+    ) -> Result<f64, DbErr> {
+        if category_ids.is_empty() {
+            return Ok(0.0);
+        }
+
+        let db = self.db_service.get_connection();
+
+        let total_questions = super::questions::Entity::find()
+            .filter(super::questions::Column::CategoryId.is_in(category_ids.to_vec()))
+            .count(db)
+            .await?;
+
+        // Categories without questions report 0% rather than dividing by zero
+        if total_questions == 0 {
+            return Ok(0.0);
+        }
+
+        let with_rules = super::questions::Entity::find()
+            .filter(super::questions::Column::CategoryId.is_in(category_ids.to_vec()))
+            .filter(super::questions::Column::SkipRules.ne(Json::Array(Vec::new())))
+            .all(db)
+            .await?;
+        if !with_rules.is_empty() {
+            return self
+                .get_completion_percent_with_skips(assessment_id, category_ids, &with_rules, responses)
+                .await;
+        }
+
+        let answered_questions: Option<i64> = super::assessments_response::Entity::find()
+            .select_only()
+            .column_as(
+                Expr::col((
+                    super::questions_revisions::Entity,
+                    super::questions_revisions::Column::QuestionId,
+                ))
+                .count_distinct(),
+                "answered_questions",
+            )
+            .join(
+                JoinType::InnerJoin,
+                super::assessments_response::Relation::QuestionRevision.def(),
+            )
+            .join(
+                JoinType::InnerJoin,
+                super::questions_revisions::Relation::Question.def(),
+            )
+            .filter(super::assessments_response::Column::AssessmentId.eq(assessment_id))
+            .filter(super::questions::Column::CategoryId.is_in(category_ids.to_vec()))
+            .into_tuple()
+            .one(db)
+            .await?;
+
+        let answered_questions = answered_questions.unwrap_or(0).max(0) as u64;
+
+        Ok(answered_questions.min(total_questions) as f64 / total_questions as f64 * 100.0)
+    }
+
+    /// `get_completion_percent` over the questions `with_rules` leave visible
+    async fn get_completion_percent_with_skips(
+        &self,
+        assessment_id: Uuid,
+        category_ids: &[Uuid],
+        with_rules: &[super::questions::Model],
+        responses: &AssessmentsResponseService,
+    ) -> Result<f64, DbErr> {
+        use crate::common::services::skip_logic::{SkipLogicEngine, SkipRule};
+
+        let question_ids: Vec<Uuid> = super::questions::Entity::find()
+            .select_only()
+            .column(super::questions::Column::QuestionId)
+            .filter(super::questions::Column::CategoryId.is_in(category_ids.to_vec()))
+            .into_tuple()
+            .all(self.db_service.get_connection())
+            .await?;
+        let answers = responses.get_latest_answers(assessment_id).await?;
+        let skipped = SkipLogicEngine::evaluate(&SkipRule::from_questions(with_rules), &answers);
+
+        let visible: Vec<&Uuid> = question_ids.iter().filter(|question_id| !skipped.contains(question_id)).collect();
+        if visible.is_empty() {
+            return Ok(0.0);
+        }
+        let answered = visible.iter().filter(|question_id| answers.contains_key(question_id)).count();
+
+        Ok(answered as f64 / visible.len() as f64 * 100.0)
+    }
+
+    /// Every assessment of an organization together with its submission state and
+    /// whether a report has been generated for it.
+    ///
+    /// Submissions whose assessment has since been deleted are still listed, named
+    /// after the `assessment_name` stored in the submission content.
+    pub async fn get_org_assessment_summaries(
+        &self,
+        org_id: &str,
+    ) -> Result<Vec<OrgAssessmentSummary>, DbErr> {
+        let db = self.db_service.get_connection();
+
+        let assessments = Entity::find()
+            .filter(Column::OrgId.eq(org_id))
+            .order_by_asc(Column::CreatedAt)
+            .all(db)
+            .await?;
+
+        let submissions = super::assessments_submission::Entity::find()
+            .filter(super::assessments_submission::Column::OrgId.eq(org_id))
+            .all(db)
+            .await?;
+
+        let reported: HashSet<Uuid> = if submissions.is_empty() {
+            HashSet::new()
+        } else {
+            super::submission_reports::Entity::find()
+                .filter(
+                    super::submission_reports::Column::SubmissionId
+                        .is_in(submissions.iter().map(|s| s.submission_id)),
+                )
+                .all(db)
+                .await?
+                .into_iter()
+                .map(|report| report.submission_id)
+                .collect()
+        };
+
+        let mut submissions: HashMap<Uuid, super::assessments_submission::Model> = submissions
+            .into_iter()
+            .map(|submission| (submission.submission_id, submission))
+            .collect();
+
+        let mut summaries: Vec<OrgAssessmentSummary> = assessments
+            .into_iter()
+            .map(|assessment| {
+                let submission = submissions.remove(&assessment.assessment_id);
+                OrgAssessmentSummary {
+                    assessment_id: assessment.assessment_id,
+                    name: assessment.name,
+                    status: submission
+                        .as_ref()
+                        .map(|s| s.status.to_string())
+                        .unwrap_or_else(|| "draft".to_string()),
+                    submitted_at: submission.as_ref().map(|s| s.submitted_at),
+                    has_report: reported.contains(&assessment.assessment_id),
+                }
+            })
+            .collect();
+
+        let mut orphaned: Vec<_> = submissions.into_values().collect();
+        orphaned.sort_by_key(|s| s.submitted_at);
+        summaries.extend(orphaned.into_iter().map(|submission| OrgAssessmentSummary {
+            assessment_id: submission.submission_id,
+            name: submission
+                .content
+                .get("assessment_name")
+                .and_then(|v| v.as_str())
+                .unwrap_or("Unknown Assessment")
+                .to_string(),
+            status: submission.status.to_string(),
+            submitted_at: Some(submission.submitted_at),
+            has_report: reported.contains(&submission.submission_id),
+        }));
+
+        Ok(summaries)
+    }
+
+    /// Delete a draft assessment together with its responses, category links and any
+    /// files no other response still references, in one transaction.
+    ///
+    /// Refused once the assessment has been submitted for review or finalized. Returns
+    /// the object storage keys of the deleted files so their bytes can be removed too.
+    pub async fn delete_draft_assessment(
+        &self,
+        assessment_id: Uuid,
+        org_id: &str,
+    ) -> Result<Vec<String>, DeleteDraftError> {
+        use super::{assessment_categories, assessments_response, assessments_response_file, file};
+
+        let txn = self.db_service.get_connection().begin().await?;
+
+        let assessment = Entity::find_by_id(assessment_id)
+            .one(&txn)
+            .await?
+            .ok_or(DeleteDraftError::NotFound)?;
+
+        if assessment.org_id != org_id {
+            return Err(DeleteDraftError::OtherOrganization);
+        }
+
+        let submitted = super::temp_submission::Entity::find_by_id(assessment_id)
+            .one(&txn)
+            .await?
+            .is_some()
+            || super::assessments_submission::Entity::find_by_id(assessment_id)
+                .one(&txn)
+                .await?
+                .is_some();
+        if submitted {
+            return Err(DeleteDraftError::Submitted);
+        }
+
+        let response_ids: Vec<Uuid> = assessments_response::Entity::find()
+            .select_only()
+            .column(assessments_response::Column::ResponseId)
+            .filter(assessments_response::Column::AssessmentId.eq(assessment_id))
+            .into_tuple()
+            .all(&txn)
+            .await?;
+
+        let mut deleted_object_keys = Vec::new();
+        if !response_ids.is_empty() {
+            let linked_file_ids: HashSet<Uuid> = assessments_response_file::Entity::find()
+                .filter(assessments_response_file::Column::ResponseId.is_in(response_ids.clone()))
+                .all(&txn)
+                .await?
+                .into_iter()
+                .map(|link| link.file_id)
+                .collect();
+
+            assessments_response_file::Entity::delete_many()
+                .filter(assessments_response_file::Column::ResponseId.is_in(response_ids.clone()))
+                .exec(&txn)
+                .await?;
+
+            assessments_response::Entity::delete_many()
+                .filter(assessments_response::Column::ResponseId.is_in(response_ids))
+                .exec(&txn)
+                .await?;
+
+            if !linked_file_ids.is_empty() {
+                // Files may be shared with responses of other assessments; keep those
+                let still_linked: HashSet<Uuid> = assessments_response_file::Entity::find()
+                    .filter(
+                        assessments_response_file::Column::FileId
+                            .is_in(linked_file_ids.iter().copied()),
+                    )
+                    .all(&txn)
+                    .await?
+                    .into_iter()
+                    .map(|link| link.file_id)
+                    .collect();
+
+                let orphaned: Vec<Uuid> = linked_file_ids
+                    .difference(&still_linked)
+                    .copied()
+                    .collect();
+
+                if !orphaned.is_empty() {
+                    let object_keys: Vec<Option<String>> = file::Entity::find()
+                        .select_only()
+                        .column(file::Column::ObjectKey)
+                        .filter(file::Column::Id.is_in(orphaned.clone()))
+                        .into_tuple()
+                        .all(&txn)
+                        .await?;
+                    deleted_object_keys.extend(object_keys.into_iter().flatten());
+
+                    file::Entity::delete_many()
+                        .filter(file::Column::Id.is_in(orphaned))
+                        .exec(&txn)
+                        .await?;
+                }
+            }
+        }
+
+        assessment_categories::Entity::delete_many()
+            .filter(assessment_categories::Column::AssessmentId.eq(assessment_id))
+            .exec(&txn)
+            .await?;
+
+        Entity::delete_by_id(assessment_id).exec(&txn).await?;
+
+        txn.commit().await?;
+        Ok(deleted_object_keys)
+    }
+
+    /// An assessment is locked once it has a final submission, until an admin reopens
+    /// it; its responses must then stay in line with the submitted snapshot.
+    pub async fn is_assessment_locked(&self, assessment_id: Uuid) -> Result<bool, DbErr> {
+        Ok(self
+            .submission_service
+            .get_submission_by_assessment_id(assessment_id)
+            .await?
+            .is_some_and(|submission| !submission.is_reopened()))
+    }
+
+    /// Reopen a submitted assessment for corrections by removing its submission
+    /// (and the reports generated from it) and any pending temp submission, which
+    /// puts it back in draft status.
+    ///
+    /// Returns the unlocked assessment.
+    pub async fn unlock_assessment(&self, assessment_id: Uuid) -> Result<Model, UnlockError> {
+        use super::{assessments_submission, submission_reports, temp_submission};
+
+        let txn = self.db_service.get_connection().begin().await?;
+
+        let assessment = Entity::find_by_id(assessment_id)
+            .one(&txn)
+            .await?
+            .ok_or(UnlockError::NotFound)?;
+
+        let submission = assessments_submission::Entity::find_by_id(assessment_id)
+            .one(&txn)
+            .await?
+            .ok_or(UnlockError::NotLocked)?;
+
+        submission_reports::Entity::delete_many()
+            .filter(submission_reports::Column::SubmissionId.eq(submission.submission_id))
+            .exec(&txn)
+            .await?;
+
+        assessments_submission::Entity::delete_by_id(submission.submission_id)
+            .exec(&txn)
+            .await?;
+
+        temp_submission::Entity::delete_by_id(assessment_id)
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+        Ok(assessment)
+    }
+
     pub async fn delete_assessment(&self, id: Uuid) -> Result<DeleteResult, DbErr> {
         // Check if a submission exists for this assessment
         let submission = self.submission_service.get_submission_by_assessment_id(id).await?;
@@ -182,10 +590,13 @@ mod tests {
         let mock_submission = SubmissionModel {
             submission_id: mock_assessment.assessment_id,
             org_id: "test_org".to_string(),
+            org_name: "Test Organization".to_string(),
             content: json!({}),
             submitted_at: Utc::now(),
             status: SubmissionStatus::UnderReview,
             reviewed_at: None,
+            reopened_at: None,
+            reopened_by: None,
         };
 
         // Create separate mock databases for assessments and submissions
@@ -300,10 +711,13 @@ mod tests {
         let mock_submission = SubmissionModel {
             submission_id: assessment_id,
             org_id: "test_org".to_string(),
+            org_name: "Test Organization".to_string(),
             content: json!({}),
             submitted_at: Utc::now(),
             status: SubmissionStatus::UnderReview,
             reviewed_at: None,
+            reopened_at: None,
+            reopened_by: None,
         };
 
         let mock_response_1 = ResponseModel {
@@ -313,6 +727,7 @@ mod tests {
             response: "Answer 1".to_string(),
             version: 1,
             updated_at: Utc::now(),
+            key_version: None,
         };
 
         let mock_response_2 = ResponseModel {
@@ -322,6 +737,7 @@ mod tests {
             response: "Answer 2".to_string(),
             version: 2,
             updated_at: Utc::now(),
+            key_version: None,
         };
 
         // Create separate mock databases for each service
@@ -343,7 +759,9 @@ mod tests {
 
         let responses_db = MockDatabase::new(DatabaseBackend::Postgres)
             .append_query_results([
+                vec![], // question of response 1, not sensitive
                 vec![mock_response_1.clone()], // create_response result 1
+                vec![], // question of response 2, not sensitive
                 vec![mock_response_2.clone()], // create_response result 2
                 vec![mock_response_1.clone(), mock_response_2.clone()], // get_responses_by_assessment before delete
                 vec![] as Vec<ResponseModel>, // get_responses_by_assessment after delete (empty due to cascade)
@@ -464,4 +882,370 @@ mod tests {
 
         Ok(())
     }
+
+    /// Answers are only read when there are skip rules, which these tests have none of
+    fn unused_responses() -> AssessmentsResponseService {
+        AssessmentsResponseService::new(Arc::new(MockDatabase::new(DatabaseBackend::Postgres).into_connection()))
+    }
+
+    fn completion_service(db: MockDatabase) -> AssessmentsService {
+        AssessmentsService {
+            db_service: DatabaseService::new(Arc::new(db.into_connection())),
+            submission_service: AssessmentsSubmissionService::new(Arc::new(
+                MockDatabase::new(DatabaseBackend::Postgres).into_connection(),
+            )),
+        }
+    }
+
+    fn count_row(column: &str, value: i64) -> std::collections::BTreeMap<String, sea_orm::Value> {
+        std::collections::BTreeMap::from([(column.to_string(), value.into())])
+    }
+
+    #[tokio::test]
+    async fn test_completion_percent_without_questions() -> Result<(), Box<dyn std::error::Error>> {
+        // Total question count of zero must short-circuit before the answered count query
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![count_row("num_items", 0)]]);
+        let service = completion_service(db);
+
+        let percent = service
+            .get_completion_percent(Uuid::new_v4(), &[Uuid::new_v4()], &unused_responses())
+            .await?;
+        assert_eq!(percent, 0.0);
+
+        // No categories assigned at all
+        let service = completion_service(MockDatabase::new(DatabaseBackend::Postgres));
+        let percent = service.get_completion_percent(Uuid::new_v4(), &[], &unused_responses()).await?;
+        assert_eq!(percent, 0.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_completion_percent_partial() -> Result<(), Box<dyn std::error::Error>> {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![count_row("num_items", 4)]])
+            .append_query_results([Vec::<super::super::questions::Model>::new()])
+            .append_query_results([vec![count_row("answered_questions", 1)]]);
+        let service = completion_service(db);
+
+        let percent = service
+            .get_completion_percent(Uuid::new_v4(), &[Uuid::new_v4(), Uuid::new_v4()], &unused_responses())
+            .await?;
+        assert_eq!(percent, 25.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_completion_percent_complete() -> Result<(), Box<dyn std::error::Error>> {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![count_row("num_items", 3)]])
+            .append_query_results([Vec::<super::super::questions::Model>::new()])
+            .append_query_results([vec![count_row("answered_questions", 3)]]);
+        let service = completion_service(db);
+
+        let percent = service
+            .get_completion_percent(Uuid::new_v4(), &[Uuid::new_v4()], &unused_responses())
+            .await?;
+        assert_eq!(percent, 100.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_org_assessment_summaries_empty() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::assessments_submission::Model as SubmissionModel;
+
+        // No submissions means the report lookup is skipped entirely
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results::<Model, _, _>([vec![]])
+            .append_query_results::<SubmissionModel, _, _>([vec![]]);
+        let service = completion_service(db);
+
+        let summaries = service.get_org_assessment_summaries("test_org").await?;
+        assert!(summaries.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_org_assessment_summaries_draft_and_reviewed() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::assessments_submission::{Model as SubmissionModel, SubmissionStatus};
+        use crate::common::database::entity::submission_reports::Model as ReportModel;
+        use serde_json::json;
+
+        let draft = Model {
+            assessment_id: Uuid::new_v4(),
+            org_id: "test_org".to_string(),
+            language: "en".to_string(),
+            name: "Draft Assessment".to_string(),
+            created_at: Utc::now(),
+        };
+        let reviewed = Model {
+            assessment_id: Uuid::new_v4(),
+            name: "Reviewed Assessment".to_string(),
+            ..draft.clone()
+        };
+        let submission = SubmissionModel {
+            submission_id: reviewed.assessment_id,
+            org_id: "test_org".to_string(),
+            org_name: "Test Organization".to_string(),
+            content: json!({}),
+            submitted_at: Utc::now(),
+            status: SubmissionStatus::Reviewed,
+            reviewed_at: Some(Utc::now()),
+            reopened_at: None,
+            reopened_by: None,
+        };
+        let report = ReportModel {
+            report_id: Uuid::new_v4(),
+            submission_id: reviewed.assessment_id,
+            report_type: "sustainability".to_string(),
+            status: "completed".to_string(),
+            generated_at: Utc::now(),
+            data: None,
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![draft.clone(), reviewed.clone()]])
+            .append_query_results([vec![submission.clone()]])
+            .append_query_results([vec![report]]);
+        let service = completion_service(db);
+
+        let summaries = service.get_org_assessment_summaries("test_org").await?;
+        assert_eq!(summaries.len(), 2);
+
+        assert_eq!(summaries[0].assessment_id, draft.assessment_id);
+        assert_eq!(summaries[0].status, "draft");
+        assert_eq!(summaries[0].submitted_at, None);
+        assert!(!summaries[0].has_report);
+
+        assert_eq!(summaries[1].assessment_id, reviewed.assessment_id);
+        assert_eq!(summaries[1].status, "reviewed");
+        assert_eq!(summaries[1].submitted_at, Some(submission.submitted_at));
+        assert!(summaries[1].has_report);
+
+        Ok(())
+    }
+
+    async fn draft_deletion_db() -> Result<DatabaseConnection, DbErr> {
+        use super::super::{
+            assessment_categories, assessments_response, assessments_response_file,
+            assessments_submission, file, submission_reports, temp_submission,
+        };
+        use sea_orm::{ConnectionTrait, Database, Schema};
+
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(assessments_response_file::Entity),
+            schema.create_table_from_entity(file::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(temp_submission::Entity),
+            schema.create_table_from_entity(assessment_categories::Entity),
+            schema.create_table_from_entity(submission_reports::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        Ok(db)
+    }
+
+    async fn insert_draft(db: &DatabaseConnection, org_id: &str) -> Result<Uuid, DbErr> {
+        let assessment_id = Uuid::new_v4();
+        ActiveModel {
+            assessment_id: Set(assessment_id),
+            org_id: Set(org_id.to_string()),
+            language: Set("en".to_string()),
+            name: Set("Draft".to_string()),
+            created_at: Set(Utc::now()),
+        }
+        .insert(db)
+        .await?;
+        Ok(assessment_id)
+    }
+
+    #[tokio::test]
+    async fn test_delete_draft_assessment_removes_responses_and_orphaned_files(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use super::super::{
+            assessment_categories, assessments_response, assessments_response_file, file,
+        };
+        use serde_json::json;
+
+        let db = draft_deletion_db().await?;
+        let assessment_id = insert_draft(&db, "test_org").await?;
+        let other_assessment_id = insert_draft(&db, "test_org").await?;
+
+        let mut response_ids = Vec::new();
+        for target in [assessment_id, other_assessment_id] {
+            let response_id = Uuid::new_v4();
+            assessments_response::ActiveModel {
+                response_id: Set(response_id),
+                assessment_id: Set(target),
+                question_revision_id: Set(Uuid::new_v4()),
+                response: Set("yes".to_string()),
+                version: Set(1),
+                updated_at: Set(Utc::now()),
+                key_version: Set(None),
+            }
+            .insert(&db)
+            .await?;
+            response_ids.push(response_id);
+        }
+
+        let orphaned_file = Uuid::new_v4();
+        let shared_file = Uuid::new_v4();
+        for (id, object_key) in [(orphaned_file, Some("files/orphaned")), (shared_file, None)] {
+            file::ActiveModel {
+                id: Set(id),
+                content: Set(Vec::new()),
+                metadata: Set(json!({})),
+                object_key: Set(object_key.map(str::to_string)),
+            }
+            .insert(&db)
+            .await?;
+        }
+        for (response_id, file_id) in [
+            (response_ids[0], orphaned_file),
+            (response_ids[0], shared_file),
+            (response_ids[1], shared_file),
+        ] {
+            assessments_response_file::ActiveModel {
+                response_id: Set(response_id),
+                file_id: Set(file_id),
+            }
+            .insert(&db)
+            .await?;
+        }
+        assessment_categories::ActiveModel {
+            assessment_id: Set(assessment_id),
+            category_catalog_id: Set(Uuid::new_v4()),
+        }
+        .insert(&db)
+        .await?;
+
+        let db = Arc::new(db);
+        let service = AssessmentsService::new(db.clone());
+        let deleted_object_keys = service
+            .delete_draft_assessment(assessment_id, "test_org")
+            .await?;
+
+        assert_eq!(deleted_object_keys, vec!["files/orphaned".to_string()]);
+        assert!(Entity::find_by_id(assessment_id).one(db.as_ref()).await?.is_none());
+        assert!(Entity::find_by_id(other_assessment_id).one(db.as_ref()).await?.is_some());
+        assert!(assessments_response::Entity::find_by_id(response_ids[0])
+            .one(db.as_ref())
+            .await?
+            .is_none());
+        assert!(file::Entity::find_by_id(orphaned_file).one(db.as_ref()).await?.is_none());
+        assert!(file::Entity::find_by_id(shared_file).one(db.as_ref()).await?.is_some());
+        assert_eq!(assessments_response_file::Entity::find().count(db.as_ref()).await?, 1);
+        assert_eq!(assessment_categories::Entity::find().count(db.as_ref()).await?, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_draft_assessment_rejects_submitted_assessment(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::assessments_submission::{self, SubmissionStatus};
+        use serde_json::json;
+
+        let db = draft_deletion_db().await?;
+        let assessment_id = insert_draft(&db, "test_org").await?;
+        assessments_submission::ActiveModel {
+            submission_id: Set(assessment_id),
+            org_id: Set("test_org".to_string()),
+            org_name: Set("Test Organization".to_string()),
+            content: Set(json!({})),
+            submitted_at: Set(Utc::now()),
+            status: Set(SubmissionStatus::Reviewed),
+            reviewed_at: Set(Some(Utc::now())),
+            reopened_at: Set(None),
+            reopened_by: Set(None),
+        }
+        .insert(&db)
+        .await?;
+
+        let db = Arc::new(db);
+        let service = AssessmentsService::new(db.clone());
+
+        let result = service.delete_draft_assessment(assessment_id, "test_org").await;
+        assert!(matches!(result, Err(DeleteDraftError::Submitted)));
+
+        let result = service.delete_draft_assessment(assessment_id, "other_org").await;
+        assert!(matches!(result, Err(DeleteDraftError::OtherOrganization)));
+
+        assert!(Entity::find_by_id(assessment_id).one(db.as_ref()).await?.is_some());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unlock_assessment_returns_it_to_draft() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::assessments_submission::{self, SubmissionStatus};
+        use crate::common::database::entity::{submission_reports, temp_submission};
+        use serde_json::json;
+
+        let db = draft_deletion_db().await?;
+        let assessment_id = insert_draft(&db, "test_org").await?;
+        temp_submission::ActiveModel {
+            temp_id: Set(assessment_id),
+            org_id: Set("test_org".to_string()),
+            content: Set(json!({})),
+            submitted_at: Set(Utc::now()),
+            status: Set(SubmissionStatus::UnderReview),
+            reviewed_at: Set(None),
+        }
+        .insert(&db)
+        .await?;
+        assessments_submission::ActiveModel {
+            submission_id: Set(assessment_id),
+            org_id: Set("test_org".to_string()),
+            org_name: Set("Test Organization".to_string()),
+            content: Set(json!({})),
+            submitted_at: Set(Utc::now()),
+            status: Set(SubmissionStatus::Reviewed),
+            reviewed_at: Set(Some(Utc::now())),
+            reopened_at: Set(None),
+            reopened_by: Set(None),
+        }
+        .insert(&db)
+        .await?;
+        submission_reports::ActiveModel {
+            report_id: Set(Uuid::new_v4()),
+            submission_id: Set(assessment_id),
+            report_type: Set("sustainability".to_string()),
+            status: Set("completed".to_string()),
+            generated_at: Set(Utc::now()),
+            data: Set(None),
+        }
+        .insert(&db)
+        .await?;
+
+        let db = Arc::new(db);
+        let service = AssessmentsService::new(db.clone());
+        assert!(service.is_assessment_locked(assessment_id).await?);
+
+        service.unlock_assessment(assessment_id).await?;
+
+        assert!(!service.is_assessment_locked(assessment_id).await?);
+        assert!(Entity::find_by_id(assessment_id).one(db.as_ref()).await?.is_some());
+        assert_eq!(temp_submission::Entity::find().count(db.as_ref()).await?, 0);
+        assert_eq!(submission_reports::Entity::find().count(db.as_ref()).await?, 0);
+
+        let result = service.unlock_assessment(assessment_id).await;
+        assert!(matches!(result, Err(UnlockError::NotLocked)));
+
+        let result = service.unlock_assessment(Uuid::new_v4()).await;
+        assert!(matches!(result, Err(UnlockError::NotFound)));
+
+        Ok(())
+    }
 }
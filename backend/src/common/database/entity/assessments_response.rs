@@ -1,9 +1,12 @@
 use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
+use crate::common::services::encryption::{EncryptionService, KEY_VERSION};
 use crate::impl_database_entity;
 use chrono::{DateTime, Utc};
 use sea_orm::entity::prelude::*;
-use sea_orm::{DeleteResult, QueryOrder, Set};
+use sea_orm::{ConnectionTrait, DeleteResult, JoinType, QueryOrder, QuerySelect, Set, TransactionTrait};
+use std::collections::HashMap;
 use std::sync::Arc;
+use thiserror::Error;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "assessments_response")]
@@ -15,6 +18,9 @@ pub struct Model {
     pub response: String,
     pub version: i32,
     pub updated_at: DateTime<Utc>,
+    /// Version of the key `response` is encrypted with, `None` when it is stored as written;
+    /// see `AssessmentsResponseService::decrypt`
+    pub key_version: Option<i16>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -57,10 +63,40 @@ impl ActiveModelBehavior for ActiveModel {}
 
 impl_database_entity!(Entity, Column::ResponseId);
 
+#[derive(Error, Debug)]
+pub enum VersionConflictError {
+    #[error("Response not found")]
+    NotFound,
+    #[error("Version conflict: assessment responses have changed (current ETag {current})")]
+    Conflict { current: String },
+    #[error("Database error: {0}")]
+    Database(#[from] DbErr),
+}
+
+/// ETag describing the current state of an assessment's responses.
+///
+/// Every save inserts a new row with a fresh `updated_at` and a bumped `version`, so the
+/// most recently written row is enough to detect that someone else saved in between.
+pub fn assessment_responses_etag(responses: &[Model]) -> String {
+    match responses
+        .iter()
+        .max_by_key(|r| (r.updated_at, r.version))
+    {
+        Some(latest) => format!(
+            "\"{}-{}\"",
+            latest.version,
+            latest.updated_at.timestamp_micros()
+        ),
+        None => "\"0\"".to_string(),
+    }
+}
+
 #[allow(dead_code)]
 #[derive(Clone)]
 pub struct AssessmentsResponseService {
     db_service: DatabaseService<Entity>,
+    /// Encrypts answers to sensitive questions, `None` when no key is configured
+    encryption: Option<Arc<EncryptionService>>,
 }
 
 #[allow(dead_code)]
@@ -68,9 +104,83 @@ impl AssessmentsResponseService {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
             db_service: DatabaseService::new(db),
+            encryption: None,
         }
     }
 
+    pub fn with_encryption(mut self, encryption: Arc<EncryptionService>) -> Self {
+        self.encryption = Some(encryption);
+        self
+    }
+
+    /// `response` in the form it is stored in, with the version of the key it is encrypted
+    /// with. Answers to sensitive questions are encrypted, and cannot be saved at all
+    /// without a key.
+    async fn seal<C: ConnectionTrait>(
+        &self,
+        conn: &C,
+        question_revision_id: Uuid,
+        response: String,
+    ) -> Result<(String, Option<i16>), DbErr> {
+        let sensitive = super::questions_revisions::Entity::find_by_id(question_revision_id)
+            .find_also_related(super::questions::Entity)
+            .one(conn)
+            .await?
+            .and_then(|(_, question)| question)
+            .is_some_and(|question| question.sensitive);
+        if !sensitive {
+            return Ok((response, None));
+        }
+
+        let encryption = self.encryption.as_ref().ok_or_else(|| {
+            DbErr::Custom("Answers to sensitive questions cannot be saved, no encryption key is configured".to_string())
+        })?;
+        let sealed = encryption
+            .encrypt_text(&response)
+            .map_err(|e| DbErr::Custom(e.to_string()))?;
+        Ok((sealed, Some(KEY_VERSION as i16)))
+    }
+
+    /// `response` as it was written, decrypted if it is stored encrypted
+    pub fn decrypt_text(&self, response: &str, key_version: Option<i16>) -> Result<String, DbErr> {
+        if key_version.is_none() {
+            return Ok(response.to_string());
+        }
+        let encryption = self.encryption.as_ref().ok_or_else(|| {
+            DbErr::Custom("Response is encrypted, but no encryption key is configured".to_string())
+        })?;
+        encryption
+            .decrypt_text(response)
+            .map_err(|e| DbErr::Custom(e.to_string()))
+    }
+
+    /// `model` with its response decrypted
+    pub fn decrypt(&self, mut model: Model) -> Result<Model, DbErr> {
+        model.response = self.decrypt_text(&model.response, model.key_version)?;
+        model.key_version = None;
+        Ok(model)
+    }
+
+    /// Decrypt the `responses` of submission or draft content in place. Encrypted entries
+    /// carry the `key_version` of their response, which is removed once decrypted.
+    pub fn decrypt_content(&self, content: &mut serde_json::Value) -> Result<(), DbErr> {
+        let entries = content
+            .get_mut("responses")
+            .and_then(|responses| responses.as_array_mut())
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.as_object_mut());
+        for entry in entries {
+            let Some(key_version) = entry.remove("key_version").and_then(|v| v.as_i64()) else {
+                continue;
+            };
+            let response = entry.get("response").and_then(|r| r.as_str()).unwrap_or_default();
+            let decrypted = self.decrypt_text(response, Some(key_version as i16))?;
+            entry.insert("response".to_string(), serde_json::Value::String(decrypted));
+        }
+        Ok(())
+    }
+
     pub async fn create_response(
         &self,
         assessment_id: Uuid,
@@ -78,6 +188,9 @@ impl AssessmentsResponseService {
         response: String,
         version: i32,
     ) -> Result<Model, DbErr> {
+        let (response, key_version) = self
+            .seal(self.db_service.get_connection(), question_revision_id, response)
+            .await?;
         let response_model = ActiveModel {
             response_id: Set(Uuid::new_v4()),
             assessment_id: Set(assessment_id),
@@ -85,6 +198,7 @@ impl AssessmentsResponseService {
             response: Set(response),
             version: Set(version),
             updated_at: Set(Utc::now()),
+            key_version: Set(key_version),
         };
 
         self.db_service.create(response_model).await
@@ -94,6 +208,20 @@ impl AssessmentsResponseService {
         self.db_service.find_by_id(id).await
     }
 
+    /// Latest response to a question revision within an assessment
+    pub async fn get_response_by_assessment_and_question(
+        &self,
+        assessment_id: Uuid,
+        question_revision_id: Uuid,
+    ) -> Result<Option<Model>, DbErr> {
+        Entity::find()
+            .filter(Column::AssessmentId.eq(assessment_id))
+            .filter(Column::QuestionRevisionId.eq(question_revision_id))
+            .order_by_desc(Column::Version)
+            .one(self.db_service.get_connection())
+            .await
+    }
+
     pub async fn get_responses_by_assessment(
         &self,
         assessment_id: Uuid,
@@ -132,6 +260,47 @@ impl AssessmentsResponseService {
         Ok(latest_map.into_values().collect())
     }
 
+    /// The latest answer to each question answered in this assessment, decrypted, by
+    /// question ID
+    pub async fn get_latest_answers(&self, assessment_id: Uuid) -> Result<HashMap<Uuid, String>, DbErr> {
+        let answers: Vec<(Uuid, String, Option<i16>)> = Entity::find()
+            .select_only()
+            .column(super::questions_revisions::Column::QuestionId)
+            .column(Column::Response)
+            .column(Column::KeyVersion)
+            .join(JoinType::InnerJoin, Relation::QuestionRevision.def())
+            .filter(Column::AssessmentId.eq(assessment_id))
+            .order_by_asc(Column::UpdatedAt)
+            .into_tuple()
+            .all(self.db_service.get_connection())
+            .await?;
+
+        // Later responses replace earlier ones, only those are decrypted
+        let latest: HashMap<Uuid, (String, Option<i16>)> = answers
+            .into_iter()
+            .map(|(question_id, response, key_version)| (question_id, (response, key_version)))
+            .collect();
+        latest
+            .into_iter()
+            .map(|(question_id, (response, key_version))| {
+                Ok((question_id, self.decrypt_text(&response, key_version)?))
+            })
+            .collect()
+    }
+
+    /// Every response row of an assessment up to and including version `max_version`
+    pub async fn get_responses_up_to_version(
+        &self,
+        assessment_id: Uuid,
+        max_version: i32,
+    ) -> Result<Vec<Model>, DbErr> {
+        Entity::find()
+            .filter(Column::AssessmentId.eq(assessment_id))
+            .filter(Column::Version.lte(max_version))
+            .all(self.db_service.get_connection())
+            .await
+    }
+
     pub async fn get_responses_by_question_revision(
         &self,
         question_revision_id: Uuid,
@@ -182,6 +351,69 @@ impl AssessmentsResponseService {
         .await
     }
 
+    /// Save a new version of a response, but only if `etag` still matches the current
+    /// state of the assessment's responses. The assessment row is locked for the duration
+    /// of the transaction so concurrent saves are checked one after the other.
+    pub async fn update_response_with_version(
+        &self,
+        response_id: Uuid,
+        etag: &str,
+        value: &str,
+    ) -> Result<Model, VersionConflictError> {
+        let txn = self.db_service.get_connection().begin().await?;
+
+        let existing = Entity::find_by_id(response_id)
+            .one(&txn)
+            .await?
+            .ok_or(VersionConflictError::NotFound)?;
+
+        super::assessments::Entity::find_by_id(existing.assessment_id)
+            .lock_exclusive()
+            .one(&txn)
+            .await?;
+
+        let latest = Entity::find()
+            .filter(Column::AssessmentId.eq(existing.assessment_id))
+            .order_by_desc(Column::UpdatedAt)
+            .order_by_desc(Column::Version)
+            .one(&txn)
+            .await?;
+
+        let current = assessment_responses_etag(latest.as_slice());
+        if current != etag {
+            txn.rollback().await?;
+            return Err(VersionConflictError::Conflict { current });
+        }
+
+        let current_version = Entity::find()
+            .filter(Column::AssessmentId.eq(existing.assessment_id))
+            .filter(Column::QuestionRevisionId.eq(existing.question_revision_id))
+            .order_by_desc(Column::Version)
+            .one(&txn)
+            .await?
+            .map(|r| r.version)
+            .unwrap_or(0);
+
+        let (response, key_version) = self
+            .seal(&txn, existing.question_revision_id, value.to_string())
+            .await?;
+        let updated = ActiveModel {
+            response_id: Set(Uuid::new_v4()),
+            assessment_id: Set(existing.assessment_id),
+            question_revision_id: Set(existing.question_revision_id),
+            response: Set(response),
+            version: Set(current_version + 1),
+            updated_at: Set(Utc::now()),
+            key_version: Set(key_version),
+        }
+        .insert(&txn)
+        .await?;
+
+        txn.commit().await?;
+
+        Ok(updated)
+    }
+
     /// Create a response with version validation from frontend
     /// If the version already exists, keep the latest one based on timestamp
     pub async fn create_response_with_version_validation(
@@ -229,6 +461,62 @@ impl AssessmentsResponseService {
         self.db_service.delete(id).await
     }
 
+    /// Removes every version of the answer to one question in an assessment, together
+    /// with its file links. The files themselves are kept so they can be re-used.
+    pub async fn delete_response_by_assessment_and_question(
+        &self,
+        assessment_id: Uuid,
+        question_revision_id: Uuid,
+    ) -> Result<(), DbErr> {
+        let deleted = self
+            .delete_responses_matching(
+                Column::AssessmentId
+                    .eq(assessment_id)
+                    .and(Column::QuestionRevisionId.eq(question_revision_id)),
+            )
+            .await?;
+
+        if deleted == 0 {
+            return Err(DbErr::RecordNotFound("Response not found".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Removes all answers of an assessment and their file links, keeping the files.
+    /// Returns the number of response rows removed.
+    pub async fn delete_responses_by_assessment(&self, assessment_id: Uuid) -> Result<u64, DbErr> {
+        self.delete_responses_matching(Column::AssessmentId.eq(assessment_id))
+            .await
+    }
+
+    async fn delete_responses_matching(&self, condition: sea_orm::sea_query::SimpleExpr) -> Result<u64, DbErr> {
+        use super::assessments_response_file;
+
+        let txn = self.db_service.get_connection().begin().await?;
+        let response_ids: Vec<Uuid> = Entity::find()
+            .select_only()
+            .column(Column::ResponseId)
+            .filter(condition)
+            .into_tuple()
+            .all(&txn)
+            .await?;
+        if response_ids.is_empty() {
+            return Ok(0);
+        }
+
+        assessments_response_file::Entity::delete_many()
+            .filter(assessments_response_file::Column::ResponseId.is_in(response_ids.clone()))
+            .exec(&txn)
+            .await?;
+        let result = Entity::delete_many()
+            .filter(Column::ResponseId.is_in(response_ids))
+            .exec(&txn)
+            .await?;
+
+        txn.commit().await?;
+        Ok(result.rows_affected)
+    }
+
     pub async fn delete_responses_by_question_revision_id(
         &self,
         question_revision_id: Uuid,
@@ -259,6 +547,7 @@ mod tests {
             response: "test answer".to_string(),
             version: 1,
             updated_at: now,
+            key_version: None,
         };
 
         let mock_response_v2 = Model {
@@ -268,10 +557,13 @@ mod tests {
             response: "updated answer".to_string(),
             version: 2,
             updated_at: now,
+            key_version: None,
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
             .append_query_results([
+                // Question of the response, none found so not sensitive
+                vec![],
                 vec![mock_response.clone()],
                 vec![mock_response.clone()],
                 vec![mock_response.clone(), mock_response_v2.clone()],
@@ -339,6 +631,7 @@ mod tests {
             response: "old answer".to_string(),
             version: 1,
             updated_at: old_time,
+            key_version: None,
         };
 
         // Mock new response that should replace the old one
@@ -349,6 +642,7 @@ mod tests {
             response: "new answer".to_string(),
             version: 1, // Same version, but newer timestamp
             updated_at: new_time,
+            key_version: None,
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -356,9 +650,11 @@ mod tests {
                 // First call: check for existing version (returns existing)
                 vec![existing_response.clone()],
                 // Second call: after delete, create new response
+                vec![],                     // Question of the response, not sensitive
                 vec![new_response.clone()],
                 // Third call: test no conflict scenario
                 vec![],                     // No existing response
+                vec![],                     // Question of the response, not sensitive
                 vec![new_response.clone()], // Create new response
             ])
             .append_exec_results([
@@ -409,4 +705,190 @@ mod tests {
 
         Ok(())
     }
+
+    /// SQLite database with an assessment and the tables responses are saved to
+    async fn sqlite_with_assessment() -> Result<(DatabaseConnection, Uuid), Box<dyn std::error::Error>> {
+        use sea_orm::{Database, Schema};
+
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let schema = Schema::new(db.get_database_backend());
+        let backend = db.get_database_backend();
+        db.execute(backend.build(&schema.create_table_from_entity(super::super::assessments::Entity)))
+            .await?;
+        db.execute(backend.build(&schema.create_table_from_entity(super::super::questions::Entity)))
+            .await?;
+        db.execute(backend.build(&schema.create_table_from_entity(super::super::questions_revisions::Entity)))
+            .await?;
+        db.execute(backend.build(&schema.create_table_from_entity(Entity)))
+            .await?;
+
+        let assessment_id = Uuid::new_v4();
+        super::super::assessments::ActiveModel {
+            assessment_id: Set(assessment_id),
+            org_id: Set("test_org".to_string()),
+            language: Set("en".to_string()),
+            name: Set("Test Assessment".to_string()),
+            created_at: Set(Utc::now()),
+        }
+        .insert(&db)
+        .await?;
+
+        Ok((db, assessment_id))
+    }
+
+    /// A question with one revision, whose revision ID is returned
+    async fn question_revision(db: &DatabaseConnection, sensitive: bool) -> Result<Uuid, DbErr> {
+        let question_id = Uuid::new_v4();
+        super::super::questions::ActiveModel {
+            question_id: Set(question_id),
+            category_id: Set(Uuid::new_v4()),
+            created_at: Set(Utc::now()),
+            external_key: Set(None),
+            skip_rules: Set(serde_json::json!([])),
+            sensitive: Set(sensitive),
+        }
+        .insert(db)
+        .await?;
+        let revision = super::super::questions_revisions::ActiveModel {
+            question_revision_id: Set(Uuid::new_v4()),
+            question_id: Set(question_id),
+            text: Set(serde_json::json!({ "en": "Annual revenue?" })),
+            weight: Set(1.0),
+            created_at: Set(Utc::now()),
+        }
+        .insert(db)
+        .await?;
+        Ok(revision.question_revision_id)
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_updates_with_same_etag() -> Result<(), Box<dyn std::error::Error>> {
+        // SQLite in-memory uses a single pooled connection, so the two transactions below
+        // really do queue up behind each other like they would behind the row lock.
+        let (db, assessment_id) = sqlite_with_assessment().await?;
+
+        let service = AssessmentsResponseService::new(Arc::new(db));
+        let original = service
+            .create_response(assessment_id, Uuid::new_v4(), "first".to_string(), 1)
+            .await?;
+
+        let etag = assessment_responses_etag(std::slice::from_ref(&original));
+
+        let (first, second) = tokio::join!(
+            service.update_response_with_version(original.response_id, &etag, "from admin"),
+            service.update_response_with_version(original.response_id, &etag, "from expert"),
+        );
+
+        let outcomes = [first, second];
+        let saved: Vec<&Model> = outcomes.iter().filter_map(|r| r.as_ref().ok()).collect();
+        let conflicts = outcomes
+            .iter()
+            .filter(|r| matches!(r, Err(VersionConflictError::Conflict { .. })))
+            .count();
+
+        assert_eq!(saved.len(), 1);
+        assert_eq!(conflicts, 1);
+        assert_eq!(saved[0].version, 2);
+
+        // The winner's ETag is now the current one
+        let latest = service.get_latest_responses_by_assessment(assessment_id).await?;
+        assert_eq!(
+            assessment_responses_etag(&latest),
+            assessment_responses_etag(std::slice::from_ref(saved[0]))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_with_stale_etag_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+        let assessment_id = Uuid::new_v4();
+        let existing = Model {
+            response_id: Uuid::new_v4(),
+            assessment_id,
+            question_revision_id: Uuid::new_v4(),
+            response: "answer".to_string(),
+            version: 3,
+            updated_at: Utc::now(),
+            key_version: None,
+        };
+        let assessment = super::super::assessments::Model {
+            assessment_id,
+            org_id: "test_org".to_string(),
+            language: "en".to_string(),
+            name: "Test Assessment".to_string(),
+            created_at: Utc::now(),
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![existing.clone()]])
+            .append_query_results([vec![assessment]])
+            .append_query_results([vec![existing.clone()]])
+            .into_connection();
+
+        let service = AssessmentsResponseService::new(Arc::new(db));
+        let result = service
+            .update_response_with_version(existing.response_id, "\"2-0\"", "changed")
+            .await;
+
+        match result {
+            Err(VersionConflictError::Conflict { current }) => {
+                assert_eq!(current, assessment_responses_etag(&[existing]));
+            }
+            other => panic!("expected a version conflict, got {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_answers_to_sensitive_questions_are_encrypted() -> Result<(), Box<dyn std::error::Error>> {
+        let (db, assessment_id) = sqlite_with_assessment().await?;
+        let sensitive = question_revision(&db, true).await?;
+        let plain = question_revision(&db, false).await?;
+        let db = Arc::new(db);
+        let service = AssessmentsResponseService::new(db.clone())
+            .with_encryption(Arc::new(EncryptionService::new([3; 32])));
+        let answer = r#"{"text":"Revenue of 1.2M EUR"}"#;
+
+        let stored = service.create_response(assessment_id, sensitive, answer.to_string(), 1).await?;
+        assert_eq!(stored.key_version, Some(KEY_VERSION as i16));
+        assert!(!stored.response.contains("Revenue"));
+        assert_eq!(service.decrypt(stored.clone())?.response, answer);
+
+        let etag = assessment_responses_etag(std::slice::from_ref(&stored));
+        let updated = service
+            .update_response_with_version(stored.response_id, &etag, r#"{"text":"Revenue of 1.5M EUR"}"#)
+            .await?;
+        assert_eq!(updated.key_version, Some(KEY_VERSION as i16));
+        assert_eq!(service.decrypt(updated.clone())?.response, r#"{"text":"Revenue of 1.5M EUR"}"#);
+
+        // Answers to other questions are stored as they were written
+        let stored_plain = service.create_response(assessment_id, plain, answer.to_string(), 1).await?;
+        assert_eq!((stored_plain.response.as_str(), stored_plain.key_version), (answer, None));
+
+        // Submission content carries the key version of encrypted entries
+        let mut content = serde_json::json!({ "responses": [
+            { "question_revision_id": sensitive, "response": updated.response, "key_version": updated.key_version },
+            { "question_revision_id": plain, "response": answer },
+        ] });
+        service.decrypt_content(&mut content)?;
+        assert_eq!(content["responses"][0]["response"], r#"{"text":"Revenue of 1.5M EUR"}"#);
+        assert!(content["responses"][0].get("key_version").is_none());
+        assert_eq!(content["responses"][1]["response"], answer);
+
+        // Tampered ciphertext is rejected instead of returned
+        let mut tampered = updated.clone();
+        tampered.response.replace_range(..4, "AAAA");
+        assert!(service.decrypt(tampered).is_err());
+
+        // Without a key, answers to sensitive questions are refused rather than stored in the clear
+        let without_key = AssessmentsResponseService::new(db);
+        assert!(without_key.create_response(assessment_id, sensitive, answer.to_string(), 2).await.is_err());
+        assert!(without_key.decrypt(updated).is_err());
+        assert!(without_key.create_response(assessment_id, plain, answer.to_string(), 2).await.is_ok());
+
+        Ok(())
+    }
 }
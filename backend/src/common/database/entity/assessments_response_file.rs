@@ -99,6 +99,23 @@ impl AssessmentsResponseFileService {
             .await
     }
 
+    /// Organizations owning the assessments whose responses reference this file
+    pub async fn get_org_ids_for_file(&self, file_id: Uuid) -> Result<Vec<String>, DbErr> {
+        Entity::find()
+            .select_only()
+            .column_as(super::assessments::Column::OrgId, "org_id")
+            .distinct()
+            .join(JoinType::InnerJoin, Relation::AssessmentsResponse.def())
+            .join(
+                JoinType::InnerJoin,
+                super::assessments_response::Relation::Assessment.def(),
+            )
+            .filter(Column::FileId.eq(file_id))
+            .into_tuple()
+            .all(self.db.as_ref())
+            .await
+    }
+
     pub async fn unlink_file_from_response(
         &self,
         response_id: Uuid,
@@ -140,6 +157,7 @@ mod tests {
             id: mock_response_file.file_id,
             content: vec![1, 2, 3, 4],
             metadata: serde_json::json!({"filename": "test.pdf"}),
+            object_key: None,
         };
 
         let mock_response = assessments_response::Model {
@@ -149,6 +167,7 @@ mod tests {
             response: "test answer".to_string(),
             version: 1,
             updated_at: chrono::Utc::now(),
+            key_version: None,
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -157,10 +176,7 @@ mod tests {
                 mock_response_file.clone(),
                 Some(mock_file.clone())
             )]]) // get_files_for_response result
-            .append_query_results([vec![(
-                mock_response.clone(),
-                Some(mock_response_file.clone())
-            )]]) // get_responses_for_file result
+            .append_query_results([vec![mock_response.clone()]]) // get_responses_for_file result
             .append_exec_results([
                 MockExecResult {
                     last_insert_id: 1,
@@ -2,11 +2,15 @@ use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
 use crate::impl_database_entity;
 use chrono::{DateTime, Utc};
 use sea_orm::entity::prelude::*;
-use sea_orm::{DeleteResult, Set};
+use sea_orm::sea_query::{Func, LikeExpr, SimpleExpr};
+use sea_orm::{Condition, QueryOrder, QuerySelect, Set, TransactionTrait};
 use sea_orm::prelude::StringLen;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::Arc;
+use thiserror::Error;
+
+use super::submission_timeline::{record_transition, TimelineActor};
 
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, EnumIter, DeriveActiveEnum)]
@@ -30,6 +34,9 @@ pub enum SubmissionStatus {
     #[sea_orm(string_value = "revision_requested")]
     #[serde(rename = "revision_requested")]
     RevisionRequested,
+    #[sea_orm(string_value = "reopened")]
+    #[serde(rename = "reopened")]
+    Reopened,
 }
 
 impl Default for SubmissionStatus {
@@ -47,6 +54,7 @@ impl SubmissionStatus {
             Self::Approved => "approved",
             Self::Rejected => "rejected",
             Self::RevisionRequested => "revision_requested",
+            Self::Reopened => "reopened",
         }
     }
 }
@@ -61,7 +69,7 @@ impl std::fmt::Display for SubmissionStatus {
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "assessments_submission")]
 pub struct Model {
-    #[sea_orm(primary_key)]
+    #[sea_orm(primary_key, auto_increment = false)]
     pub submission_id: Uuid, // Also FK to assessments
     pub org_id: String,             // Keycloak organization id
     pub org_name: String,           // Denormalized organization name
@@ -69,6 +77,15 @@ pub struct Model {
     pub submitted_at: DateTime<Utc>, // When the submission was created
     pub status: SubmissionStatus,    // Review status (under_review, reviewed, etc.)
     pub reviewed_at: Option<DateTime<Utc>>, // When the submission was reviewed
+    pub reopened_at: Option<DateTime<Utc>>, // When the submission was last reopened for corrections
+    pub reopened_by: Option<String>,        // Keycloak user id of who reopened it
+}
+
+impl Model {
+    /// Reopened submissions can be edited and submitted again
+    pub fn is_reopened(&self) -> bool {
+        self.status == SubmissionStatus::Reopened
+    }
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -99,6 +116,42 @@ impl ActiveModelBehavior for ActiveModel {}
 
 impl_database_entity!(Entity, Column::SubmissionId);
 
+#[derive(Error, Debug)]
+pub enum RecallError {
+    #[error("Submission not found")]
+    NotFound,
+    #[error("Submission can no longer be recalled, its review has started")]
+    ReviewStarted,
+    #[error("Submission can no longer be recalled, reports have been generated from it")]
+    HasReports,
+    #[error("Database error: {0}")]
+    Database(#[from] DbErr),
+}
+
+/// Filters of `list_submissions_filtered`; every filter given must match
+#[derive(Clone, Debug, Default)]
+pub struct FilteredSubmissionQuery {
+    /// Exact Keycloak organization id
+    pub org_id: Option<String>,
+    /// Part of the organization name, any case
+    pub org_name: Option<String>,
+    /// Part of the assessment name, any case
+    pub assessment_name: Option<String>,
+    pub status: Option<SubmissionStatus>,
+    pub submitted_after: Option<DateTime<Utc>>,
+    pub submitted_before: Option<DateTime<Utc>>,
+}
+
+/// `LOWER(expr) LIKE '%needle%'`, the portable spelling of ILIKE
+fn contains_ignore_case(expr: SimpleExpr, needle: &str) -> SimpleExpr {
+    let escaped = needle
+        .to_lowercase()
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    Expr::expr(Func::lower(expr)).like(LikeExpr::new(format!("%{escaped}%")).escape('\\'))
+}
+
 #[allow(dead_code)]
 #[derive(Clone)]
 pub struct AssessmentsSubmissionService {
@@ -144,6 +197,8 @@ impl AssessmentsSubmissionService {
             submitted_at: Set(Utc::now()),
             status: Set(SubmissionStatus::UnderReview),
             reviewed_at: Set(None),
+            reopened_at: Set(None),
+            reopened_by: Set(None),
         };
 
         // Create the submission first
@@ -185,19 +240,75 @@ impl AssessmentsSubmissionService {
         self.db_service.find_all().await
     }
 
-    pub async fn delete_submission(&self, assessment_id: Uuid) -> Result<DeleteResult, DbErr> {
-        self.db_service.delete(assessment_id).await
+    /// Number of submissions in each status, counted in one grouped query
+    pub async fn count_by_status(&self) -> Result<Vec<(SubmissionStatus, i64)>, DbErr> {
+        Entity::find()
+            .select_only()
+            .column(Column::Status)
+            .column_as(Expr::col(Column::SubmissionId).count(), "count")
+            .group_by(Column::Status)
+            .into_tuple()
+            .all(self.db_service.get_connection())
+            .await
+    }
+
+    /// Submissions matching every filter in `query`, newest first
+    pub async fn list_submissions_filtered(
+        &self,
+        query: &FilteredSubmissionQuery,
+    ) -> Result<Vec<Model>, DbErr> {
+        let mut condition = Condition::all();
+        if let Some(org_id) = &query.org_id {
+            condition = condition.add(Column::OrgId.eq(org_id.as_str()));
+        }
+        if let Some(org_name) = query.org_name.as_deref().filter(|name| !name.is_empty()) {
+            condition = condition.add(contains_ignore_case(Expr::col(Column::OrgName).into(), org_name));
+        }
+        if let Some(assessment_name) = query.assessment_name.as_deref().filter(|name| !name.is_empty()) {
+            // Submissions store the name at the top level of their content; older
+            // ones only carry it inside the assessment object
+            let name = Expr::cust("COALESCE(content->>'assessment_name', content->'assessment'->>'name')");
+            condition = condition.add(contains_ignore_case(name, assessment_name));
+        }
+        if let Some(status) = &query.status {
+            condition = condition.add(Column::Status.eq(status.clone()));
+        }
+        if let Some(after) = query.submitted_after {
+            condition = condition.add(Column::SubmittedAt.gte(after));
+        }
+        if let Some(before) = query.submitted_before {
+            condition = condition.add(Column::SubmittedAt.lte(before));
+        }
+
+        Entity::find()
+            .filter(condition)
+            .order_by_desc(Column::SubmittedAt)
+            .all(self.db_service.get_connection())
+            .await
+    }
+
+    /// Delete a submission together with its timeline and reports; fails with "not
+    /// found" when there is none
+    pub async fn delete_submission(&self, submission_id: Uuid) -> Result<(), DbErr> {
+        let deleted = self.db_service.delete(submission_id).await?;
+        if deleted.rows_affected == 0 {
+            return Err(DbErr::Custom("Submission not found".to_string()));
+        }
+        Ok(())
     }
 
+    /// Change a submission's status and record the change on its timeline
     pub async fn update_submission_status(
         &self,
         assessment_id: Uuid,
         status: SubmissionStatus,
+        actor: &TimelineActor,
     ) -> Result<Model, DbErr> {
         let submission = self
             .get_submission_by_assessment_id(assessment_id)
             .await?
-            .ok_or(DbErr::Custom("Submission not found".to_string()))?;
+            .ok_or(DbErr::RecordNotFound("Submission not found".to_string()))?;
+        let previous_status = submission.status.clone();
 
         let mut submission: ActiveModel = submission.into();
 
@@ -206,30 +317,140 @@ impl AssessmentsSubmissionService {
             SubmissionStatus::Approved | SubmissionStatus::Rejected | SubmissionStatus::RevisionRequested
         );
 
-        submission.status = Set(status);
+        submission.status = Set(status.clone());
 
         if should_set_reviewed_at {
             submission.reviewed_at = Set(Some(Utc::now()));
         }
 
-        self.db_service.update(submission).await
+        self.update_with_timeline(submission, previous_status, status, actor).await
     }
 
     pub async fn mark_submission_reviewed(
         &self,
         assessment_id: Uuid,
         status: SubmissionStatus,
+        actor: &TimelineActor,
     ) -> Result<Model, DbErr> {
         let submission = self
             .get_submission_by_assessment_id(assessment_id)
             .await?
             .ok_or(DbErr::Custom("Submission not found".to_string()))?;
+        let previous_status = submission.status.clone();
 
         let mut submission: ActiveModel = submission.into();
-        submission.status = Set(status);
+        submission.status = Set(status.clone());
         submission.reviewed_at = Set(Some(Utc::now()));
 
-        self.db_service.update(submission).await
+        self.update_with_timeline(submission, previous_status, status, actor).await
+    }
+
+    /// Move a submission back to an editable state, recording `actor` as who reopened
+    /// it. With `invalidate_reports` its reports are marked "invalidated", which keeps
+    /// them out of everything that only reads completed reports.
+    pub async fn reopen_submission(
+        &self,
+        assessment_id: Uuid,
+        actor: &TimelineActor,
+        invalidate_reports: bool,
+    ) -> Result<Model, DbErr> {
+        let txn = self.db_service.get_connection().begin().await?;
+
+        let submission = Entity::find_by_id(assessment_id)
+            .one(&txn)
+            .await?
+            .ok_or(DbErr::Custom("Submission not found".to_string()))?;
+        let previous_status = submission.status.clone();
+
+        let mut submission: ActiveModel = submission.into();
+        submission.status = Set(SubmissionStatus::Reopened);
+        submission.reopened_at = Set(Some(Utc::now()));
+        submission.reopened_by = Set(Some(actor.user_id.clone()));
+        let updated = submission.update(&txn).await?;
+
+        if invalidate_reports {
+            super::submission_reports::Entity::update_many()
+                .col_expr(super::submission_reports::Column::Status, Expr::value("invalidated"))
+                .filter(super::submission_reports::Column::SubmissionId.eq(assessment_id))
+                .exec(&txn)
+                .await?;
+        }
+
+        record_transition(&txn, assessment_id, Some(&previous_status), &SubmissionStatus::Reopened, actor, None).await?;
+        txn.commit().await?;
+
+        Ok(updated)
+    }
+
+    /// Take back a submission still waiting for review, recording `actor` as who
+    /// recalled it. The submission is reopened and the pending temp submission removed,
+    /// so the assessment is a draft again while its timeline is kept.
+    pub async fn recall_submission(
+        &self,
+        assessment_id: Uuid,
+        actor: &TimelineActor,
+    ) -> Result<Model, RecallError> {
+        let txn = self.db_service.get_connection().begin().await?;
+
+        let submission = Entity::find_by_id(assessment_id)
+            .lock_exclusive()
+            .one(&txn)
+            .await?
+            .ok_or(RecallError::NotFound)?;
+        let awaiting_review = matches!(
+            submission.status,
+            SubmissionStatus::PendingReview | SubmissionStatus::UnderReview
+        ) && submission.reviewed_at.is_none();
+        if !awaiting_review {
+            return Err(RecallError::ReviewStarted);
+        }
+
+        let reports = super::submission_reports::Entity::find()
+            .filter(super::submission_reports::Column::SubmissionId.eq(assessment_id))
+            .count(&txn)
+            .await?;
+        if reports > 0 {
+            return Err(RecallError::HasReports);
+        }
+
+        let previous_status = submission.status.clone();
+        let mut submission: ActiveModel = submission.into();
+        submission.status = Set(SubmissionStatus::Reopened);
+        submission.reopened_at = Set(Some(Utc::now()));
+        submission.reopened_by = Set(Some(actor.user_id.clone()));
+        let updated = submission.update(&txn).await?;
+
+        super::temp_submission::Entity::delete_by_id(assessment_id)
+            .exec(&txn)
+            .await?;
+
+        record_transition(
+            &txn,
+            assessment_id,
+            Some(&previous_status),
+            &SubmissionStatus::Reopened,
+            actor,
+            Some("Recalled by the organization".to_string()),
+        )
+        .await?;
+        txn.commit().await?;
+
+        Ok(updated)
+    }
+
+    async fn update_with_timeline(
+        &self,
+        submission: ActiveModel,
+        previous_status: SubmissionStatus,
+        status: SubmissionStatus,
+        actor: &TimelineActor,
+    ) -> Result<Model, DbErr> {
+        let txn = self.db_service.get_connection().begin().await?;
+        let updated = submission.update(&txn).await?;
+        record_transition(&txn, updated.submission_id, Some(&previous_status), &status, actor, None).await?;
+        txn.commit().await?;
+
+        Ok(updated)
     }
 
     pub async fn update_submission_content(
@@ -291,39 +512,24 @@ mod tests {
 
     #[tokio::test]
     async fn test_automatic_assessment_deletion_after_submission() -> Result<(), Box<dyn std::error::Error>> {
-        use crate::common::database::entity::assessments::{AssessmentsService, Model as AssessmentModel};
+        use crate::common::database::entity::assessments::AssessmentsService;
 
         let assessment_id = Uuid::new_v4();
 
-        let mock_assessment = AssessmentModel {
-            assessment_id,
-            org_id: "test_org".to_string(),
-            language: "en".to_string(),
-            name: "Test Assessment".to_string(),
-            created_at: Utc::now(),
-        };
-
         let mock_submission = Model {
             submission_id: assessment_id,
             org_id: "test_org".to_string(),
+            org_name: "Test Organization".to_string(),
             content: json!({"question1": "answer1"}),
             submitted_at: chrono::Utc::now(),
             status: SubmissionStatus::UnderReview,
             reviewed_at: None,
+            reopened_at: None,
+            reopened_by: None,
         };
 
         // Create mock databases
-        let submissions_db1 = MockDatabase::new(DatabaseBackend::Postgres)
-            .append_query_results([
-                vec![mock_submission.clone()], // create_submission result
-            ])
-            .append_exec_results([MockExecResult {
-                last_insert_id: 1,
-                rows_affected: 1,
-            }])
-            .into_connection();
-
-        let submissions_db2 = MockDatabase::new(DatabaseBackend::Postgres)
+        let submissions_db = MockDatabase::new(DatabaseBackend::Postgres)
             .append_query_results([
                 vec![mock_submission.clone()], // create_submission result
             ])
@@ -346,7 +552,7 @@ mod tests {
         // Create services
         let assessments_service = AssessmentsService::new(Arc::new(assessments_db));
 
-        let submission_service = AssessmentsSubmissionService::new(Arc::new(submissions_db2))
+        let submission_service = AssessmentsSubmissionService::new(Arc::new(submissions_db))
             .with_assessments_service(assessments_service);
 
         // Test that submission creation triggers automatic assessment deletion
@@ -354,6 +560,7 @@ mod tests {
             .create_submission(
                 assessment_id,
                 "test_user".to_string(),
+                "Test Organization".to_string(),
                 json!({"question1": "answer1"}),
                 Some("Test Assessment".to_string()),
             )
@@ -376,10 +583,13 @@ mod tests {
         let mock_submission = Model {
             submission_id: Uuid::new_v4(),
             org_id: "test_org".to_string(),
+            org_name: "Test Organization".to_string(),
             content: json!({"question1": "answer1", "question2": "answer2"}),
             submitted_at: Utc::now(),
             status: SubmissionStatus::UnderReview,
             reviewed_at: None,
+            reopened_at: None,
+            reopened_by: None,
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -402,6 +612,7 @@ mod tests {
             .create_submission(
                 mock_submission.submission_id,
                 "test_user".to_string(),
+                "Test Organization".to_string(),
                 json!({"question1": "answer1", "question2": "answer2"}),
                 Some("Test Assessment".to_string()),
             )
@@ -426,8 +637,112 @@ mod tests {
         assert!(!all_submissions.is_empty());
 
         // Test delete
-        let delete_result = service.delete_submission(submission.submission_id).await?;
-        assert_eq!(delete_result.rows_affected, 1);
+        service.delete_submission(submission.submission_id).await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_list_submissions_filtered() -> Result<(), Box<dyn std::error::Error>> {
+        use chrono::TimeZone;
+        use sea_orm::{ActiveModelTrait, ConnectionTrait, Database, Schema};
+
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        db.execute(backend.build(&Schema::new(backend).create_table_from_entity(Entity)))
+            .await?;
+
+        let day = |d: u32| Utc.with_ymd_and_hms(2026, 3, d, 12, 0, 0).unwrap();
+        for (org_name, content, submitted_at) in [
+            ("Green Coop", json!({ "assessment_name": "Yearly Check" }), day(1)),
+            ("Green Coop", json!({ "assessment_name": "Energy audit" }), day(10)),
+            ("Blue Bank", json!({ "assessment_name": "yearly check 2026" }), day(20)),
+            ("Blue Bank", json!({ "assessment": { "name": "100% Energy" } }), day(25)),
+        ] {
+            ActiveModel {
+                submission_id: Set(Uuid::new_v4()),
+                org_id: Set(org_name.to_lowercase().replace(' ', "-")),
+                org_name: Set(org_name.to_string()),
+                content: Set(content),
+                submitted_at: Set(submitted_at),
+                status: Set(SubmissionStatus::UnderReview),
+                reviewed_at: Set(None),
+                reopened_at: Set(None),
+                reopened_by: Set(None),
+            }
+            .insert(&db)
+            .await?;
+        }
+
+        let service = AssessmentsSubmissionService::new(Arc::new(db));
+        let found = |query: FilteredSubmissionQuery| {
+            let service = service.clone();
+            async move {
+                let submissions = service.list_submissions_filtered(&query).await?;
+                Ok::<_, DbErr>(
+                    submissions
+                        .into_iter()
+                        .map(|s| (s.org_name, s.submitted_at))
+                        .collect::<Vec<_>>(),
+                )
+            }
+        };
+
+        // No filters: everything, newest first
+        assert_eq!(found(FilteredSubmissionQuery::default()).await?.len(), 4);
+        assert_eq!(found(FilteredSubmissionQuery::default()).await?[0].1, day(25));
+
+        let by_org = found(FilteredSubmissionQuery { org_name: Some("green".into()), ..Default::default() }).await?;
+        assert_eq!(by_org, vec![("Green Coop".to_string(), day(10)), ("Green Coop".to_string(), day(1))]);
+
+        let by_name =
+            found(FilteredSubmissionQuery { assessment_name: Some("YEARLY".into()), ..Default::default() }).await?;
+        assert_eq!(by_name, vec![("Blue Bank".to_string(), day(20)), ("Green Coop".to_string(), day(1))]);
+
+        // Names kept inside the assessment object match too, and % is taken literally
+        let literal =
+            found(FilteredSubmissionQuery { assessment_name: Some("100%".into()), ..Default::default() }).await?;
+        assert_eq!(literal, vec![("Blue Bank".to_string(), day(25))]);
+
+        let after = found(FilteredSubmissionQuery { submitted_after: Some(day(10)), ..Default::default() }).await?;
+        assert_eq!(after.len(), 3);
+
+        let before = found(FilteredSubmissionQuery { submitted_before: Some(day(10)), ..Default::default() }).await?;
+        assert_eq!(before.len(), 2);
+
+        let combined = found(FilteredSubmissionQuery {
+            org_name: Some("Blue".into()),
+            assessment_name: Some("energy".into()),
+            submitted_after: Some(day(15)),
+            submitted_before: Some(day(31)),
+            ..Default::default()
+        })
+        .await?;
+        assert_eq!(combined, vec![("Blue Bank".to_string(), day(25))]);
+
+        let by_status = found(FilteredSubmissionQuery {
+            status: Some(SubmissionStatus::UnderReview),
+            submitted_after: Some(day(5)),
+            ..Default::default()
+        })
+        .await?;
+        assert_eq!(by_status.len(), 3);
+        let by_org_id = found(FilteredSubmissionQuery { org_id: Some("blue-bank".into()), ..Default::default() }).await?;
+        assert_eq!(by_org_id, vec![("Blue Bank".to_string(), day(25)), ("Blue Bank".to_string(), day(20))]);
+
+        let other_status =
+            found(FilteredSubmissionQuery { status: Some(SubmissionStatus::Approved), ..Default::default() }).await?;
+        assert!(other_status.is_empty());
+
+        let none = found(FilteredSubmissionQuery {
+            org_name: Some("Green".into()),
+            assessment_name: Some("energy".into()),
+            submitted_before: Some(day(5)),
+            ..Default::default()
+        })
+        .await?;
+        assert!(none.is_empty());
 
         Ok(())
     }
@@ -1,9 +1,31 @@
 use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
+use crate::common::locale::select_localized_text;
 use crate::impl_database_entity;
 use chrono::{DateTime, Utc};
 use sea_orm::entity::prelude::*;
-use sea_orm::{QueryOrder, Set};
+use sea_orm::prelude::StringLen;
+use sea_orm::{DbBackend, FromQueryResult, PaginatorTrait, QueryOrder, Set, Statement, TransactionTrait};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use thiserror::Error;
+
+/// Deepest level `get_category_tree` descends to
+pub const MAX_CATEGORY_TREE_DEPTH: u32 = 16;
+
+/// How a category's report score is computed, see `ScoreEngine::category_scores`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, EnumIter, DeriveActiveEnum, utoipa::ToSchema)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+#[serde(rename_all = "lowercase")]
+pub enum ScoringMode {
+    /// The weighted average of the category's answers, 0 to 100
+    #[default]
+    #[sea_orm(string_value = "linear")]
+    Linear,
+    /// 100 when the weighted average reaches `score_threshold`, 0 below it
+    #[sea_orm(string_value = "threshold")]
+    Threshold,
+}
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "category_catalog")]
@@ -16,6 +38,21 @@ pub struct Model {
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Report recommendation used when reviewers give none, keyed by language code
+    pub default_recommendation: Option<Json>,
+    /// When the category was archived; archived categories cannot be assigned
+    pub deactivated_at: Option<DateTime<Utc>>,
+    /// Theme the category is grouped under, None for top-level categories
+    pub parent_category_catalog_id: Option<Uuid>,
+    pub scoring_mode: ScoringMode,
+    /// Pass mark between 0 and 100, used with `ScoringMode::Threshold`
+    pub score_threshold: Option<f64>,
+    /// Icon the frontend shows on the category card, e.g. "leaf"
+    pub icon_name: Option<String>,
+    /// Card color as `#RRGGBB`
+    pub color_hex: Option<String>,
+    /// Further display settings for the frontend, `{}` when there are none
+    pub metadata: Json,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -40,8 +77,53 @@ impl Related<super::assessment_categories::Entity> for Entity {
 
 impl ActiveModelBehavior for ActiveModel {}
 
+impl Model {
+    /// Default recommendation in `language`, or the first available one of `fallbacks`
+    pub fn default_recommendation_for(&self, language: &str, fallbacks: &[String]) -> Option<String> {
+        let translations = self.default_recommendation.as_ref()?;
+        select_localized_text(translations, language, fallbacks).map(str::to_string)
+    }
+}
+
 impl_database_entity!(Entity, Column::CategoryCatalogId);
 
+#[derive(Error, Debug)]
+pub enum ArchiveCategoryError {
+    #[error("Category catalog not found")]
+    NotFound,
+    #[error("Category is assigned to an organization")]
+    Assigned,
+    #[error("Database error: {0}")]
+    Database(#[from] DbErr),
+}
+
+/// A category and the categories grouped under it
+#[derive(Clone, Debug, PartialEq, Serialize, utoipa::ToSchema)]
+pub struct CategoryNode {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub icon_name: Option<String>,
+    pub color_hex: Option<String>,
+    pub children: Vec<CategoryNode>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct CategoryTreeRow {
+    category_catalog_id: Uuid,
+    name: String,
+    description: Option<String>,
+    icon_name: Option<String>,
+    color_hex: Option<String>,
+    parent_category_catalog_id: Option<Uuid>,
+}
+
+#[derive(Debug, FromQueryResult)]
+struct CategoryParentRow {
+    category_catalog_id: Uuid,
+    parent_category_catalog_id: Option<Uuid>,
+}
+
 #[allow(dead_code)]
 #[derive(Clone)]
 pub struct CategoryCatalogService {
@@ -63,6 +145,7 @@ impl CategoryCatalogService {
         description: Option<String>,
         template_id: String,
         is_active: bool,
+        default_recommendation: Option<Json>,
     ) -> Result<Model, DbErr> {
         let now = Utc::now();
         let category_catalog = ActiveModel {
@@ -73,6 +156,14 @@ impl CategoryCatalogService {
             is_active: Set(is_active),
             created_at: Set(now),
             updated_at: Set(now),
+            default_recommendation: Set(default_recommendation),
+            deactivated_at: Set(None),
+            parent_category_catalog_id: Set(None),
+            scoring_mode: Set(ScoringMode::Linear),
+            score_threshold: Set(None),
+            icon_name: Set(None),
+            color_hex: Set(None),
+            metadata: Set(serde_json::json!({})),
         };
 
         self.db_service.create(category_catalog).await
@@ -94,6 +185,143 @@ impl CategoryCatalogService {
         self.db_service.find_all().await
     }
 
+    /// All categories by name, optionally leaving out archived ones
+    pub async fn list_categories(&self, active_only: bool) -> Result<Vec<Model>, DbErr> {
+        let mut query = Entity::find();
+        if active_only {
+            query = query
+                .filter(Column::IsActive.eq(true))
+                .filter(Column::DeactivatedAt.is_null());
+        }
+        query
+            .order_by_asc(Column::Name)
+            .all(self.db_service.get_connection())
+            .await
+    }
+
+    /// Archive a category by stamping `deactivated_at` and clearing `is_active`.
+    ///
+    /// Categories still assigned to an organization are refused; organizations drop
+    /// their assignments when deleted, so any remaining row belongs to a live one.
+    /// Archiving an already archived category returns it unchanged.
+    pub async fn deactivate_category(&self, category_catalog_id: Uuid) -> Result<Model, ArchiveCategoryError> {
+        let txn = self.db_service.get_connection().begin().await?;
+
+        let model = Entity::find_by_id(category_catalog_id)
+            .one(&txn)
+            .await?
+            .ok_or(ArchiveCategoryError::NotFound)?;
+        if model.deactivated_at.is_some() {
+            return Ok(model);
+        }
+
+        let assignments = super::organization_categories::Entity::find()
+            .filter(super::organization_categories::Column::CategoryCatalogId.eq(category_catalog_id))
+            .count(&txn)
+            .await?;
+        if assignments > 0 {
+            return Err(ArchiveCategoryError::Assigned);
+        }
+
+        let now = Utc::now();
+        let mut active_model: ActiveModel = model.into();
+        active_model.is_active = Set(false);
+        active_model.deactivated_at = Set(Some(now));
+        active_model.updated_at = Set(now);
+        let updated = active_model.update(&txn).await?;
+
+        txn.commit().await?;
+        Ok(updated)
+    }
+
+    /// Top-level categories with their descendants down to `max_depth` levels, by name.
+    ///
+    /// Only categories reachable from a top-level one are returned, so categories
+    /// whose parents form a cycle are left out instead of being walked forever.
+    pub async fn get_category_tree(&self, max_depth: Option<u32>) -> Result<Vec<CategoryNode>, DbErr> {
+        let max_depth = max_depth.unwrap_or(MAX_CATEGORY_TREE_DEPTH).clamp(1, MAX_CATEGORY_TREE_DEPTH);
+        let db = self.db_service.get_connection();
+        let backend = db.get_database_backend();
+        let placeholder = match backend {
+            DbBackend::Postgres => "$1",
+            _ => "?",
+        };
+        let sql = format!(
+            r#"WITH RECURSIVE tree (category_catalog_id, name, description, icon_name, color_hex, parent_category_catalog_id, depth) AS (
+                SELECT category_catalog_id, name, description, icon_name, color_hex, parent_category_catalog_id, 1
+                  FROM category_catalog
+                 WHERE parent_category_catalog_id IS NULL
+                UNION ALL
+                SELECT c.category_catalog_id, c.name, c.description, c.icon_name, c.color_hex, c.parent_category_catalog_id, t.depth + 1
+                  FROM category_catalog c
+                  JOIN tree t ON c.parent_category_catalog_id = t.category_catalog_id
+                 WHERE t.depth < {placeholder}
+            )
+            SELECT category_catalog_id, name, description, icon_name, color_hex, parent_category_catalog_id FROM tree ORDER BY name"#
+        );
+
+        let rows = CategoryTreeRow::find_by_statement(Statement::from_sql_and_values(
+            backend,
+            sql,
+            [(max_depth as i32).into()],
+        ))
+        .all(db)
+        .await?;
+
+        let mut children: HashMap<Option<Uuid>, Vec<CategoryTreeRow>> = HashMap::new();
+        for row in rows {
+            children.entry(row.parent_category_catalog_id).or_default().push(row);
+        }
+        Ok(build_category_nodes(&mut children, None))
+    }
+
+    /// Parent, grandparent and so on of a category, nearest first.
+    ///
+    /// Stops at the first category seen twice, so a cycle in the parent links ends
+    /// the list instead of looping.
+    pub async fn get_ancestors(&self, id: Uuid) -> Result<Vec<Uuid>, DbErr> {
+        let db = self.db_service.get_connection();
+        let backend = db.get_database_backend();
+        let placeholder = match backend {
+            DbBackend::Postgres => "$1",
+            _ => "?",
+        };
+        // UNION drops rows already produced, which ends the recursion on cycles
+        let sql = format!(
+            r#"WITH RECURSIVE ancestors (category_catalog_id, parent_category_catalog_id) AS (
+                SELECT category_catalog_id, parent_category_catalog_id
+                  FROM category_catalog
+                 WHERE category_catalog_id = {placeholder}
+                UNION
+                SELECT c.category_catalog_id, c.parent_category_catalog_id
+                  FROM category_catalog c
+                  JOIN ancestors a ON c.category_catalog_id = a.parent_category_catalog_id
+            )
+            SELECT category_catalog_id, parent_category_catalog_id FROM ancestors"#
+        );
+
+        let parents: HashMap<Uuid, Option<Uuid>> = CategoryParentRow::find_by_statement(
+            Statement::from_sql_and_values(backend, sql, [id.into()]),
+        )
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|row| (row.category_catalog_id, row.parent_category_catalog_id))
+        .collect();
+
+        let mut ancestors = Vec::new();
+        let mut seen = HashSet::from([id]);
+        let mut current = parents.get(&id).copied().flatten();
+        while let Some(parent) = current {
+            if !seen.insert(parent) {
+                break;
+            }
+            ancestors.push(parent);
+            current = parents.get(&parent).copied().flatten();
+        }
+        Ok(ancestors)
+    }
+
     pub async fn get_categories_by_template(&self, template_id: &str) -> Result<Vec<Model>, DbErr> {
         Entity::find()
             .filter(Column::TemplateId.eq(template_id))
@@ -109,6 +337,7 @@ impl CategoryCatalogService {
         name: Option<String>,
         description: Option<String>,
         is_active: Option<bool>,
+        default_recommendation: Option<Json>,
     ) -> Result<Model, DbErr> {
         let model = self.db_service.find_by_id(category_catalog_id).await?
             .ok_or_else(|| DbErr::RecordNotFound("Category catalog not found".to_string()))?;
@@ -123,6 +352,45 @@ impl CategoryCatalogService {
         if let Some(is_active) = is_active {
             active_model.is_active = Set(is_active);
         }
+        if let Some(default_recommendation) = default_recommendation {
+            active_model.default_recommendation = Set(Some(default_recommendation));
+        }
+        active_model.updated_at = Set(Utc::now());
+
+        self.db_service.update(active_model).await
+    }
+
+    /// Set how the category's report score is computed
+    pub async fn update_scoring(
+        &self,
+        category_catalog_id: Uuid,
+        scoring_mode: ScoringMode,
+        score_threshold: Option<f64>,
+    ) -> Result<Model, DbErr> {
+        let model = self.db_service.find_by_id(category_catalog_id).await?
+            .ok_or_else(|| DbErr::RecordNotFound("Category catalog not found".to_string()))?;
+        let mut active_model: ActiveModel = model.into();
+        active_model.scoring_mode = Set(scoring_mode);
+        active_model.score_threshold = Set(score_threshold);
+        active_model.updated_at = Set(Utc::now());
+
+        self.db_service.update(active_model).await
+    }
+
+    /// Replace the description, icon and color the frontend renders the category with
+    pub async fn update_display_metadata(
+        &self,
+        category_catalog_id: Uuid,
+        description: Option<String>,
+        icon_name: Option<String>,
+        color_hex: Option<String>,
+    ) -> Result<Model, DbErr> {
+        let model = self.db_service.find_by_id(category_catalog_id).await?
+            .ok_or_else(|| DbErr::RecordNotFound("Category catalog not found".to_string()))?;
+        let mut active_model: ActiveModel = model.into();
+        active_model.description = Set(description);
+        active_model.icon_name = Set(icon_name);
+        active_model.color_hex = Set(color_hex);
         active_model.updated_at = Set(Utc::now());
 
         self.db_service.update(active_model).await
@@ -150,3 +418,120 @@ impl CategoryCatalogService {
         Ok(())
     }
 }
+
+/// Nodes for the rows under `parent`, taking them out of `children`
+fn build_category_nodes(
+    children: &mut HashMap<Option<Uuid>, Vec<CategoryTreeRow>>,
+    parent: Option<Uuid>,
+) -> Vec<CategoryNode> {
+    children
+        .remove(&parent)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|row| CategoryNode {
+            id: row.category_catalog_id,
+            children: build_category_nodes(children, Some(row.category_catalog_id)),
+            name: row.name,
+            description: row.description,
+            icon_name: row.icon_name,
+            color_hex: row.color_hex,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{ConnectionTrait, Database, Schema};
+
+    /// Environmental > Climate > Carbon, Social on its own, and two categories that
+    /// are each other's parent
+    async fn setup() -> Result<(CategoryCatalogService, HashMap<&'static str, Uuid>), Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        let backend = db.get_database_backend();
+        db.execute(backend.build(&Schema::new(backend).create_table_from_entity(Entity)))
+            .await?;
+
+        let ids: HashMap<&str, Uuid> = ["Environmental", "Climate", "Carbon", "Social", "Loop A", "Loop B"]
+            .into_iter()
+            .map(|name| (name, Uuid::new_v4()))
+            .collect();
+        for (name, parent) in [
+            ("Environmental", None),
+            ("Climate", Some("Environmental")),
+            ("Carbon", Some("Climate")),
+            ("Social", None),
+            ("Loop A", Some("Loop B")),
+            ("Loop B", Some("Loop A")),
+        ] {
+            ActiveModel {
+                category_catalog_id: Set(ids[name]),
+                name: Set(name.to_string()),
+                description: Set(None),
+                template_id: Set("sustainability_template_1".to_string()),
+                is_active: Set(true),
+                created_at: Set(Utc::now()),
+                updated_at: Set(Utc::now()),
+                default_recommendation: Set(None),
+                deactivated_at: Set(None),
+                parent_category_catalog_id: Set(parent.map(|parent| ids[parent])),
+                scoring_mode: Set(ScoringMode::Linear),
+                score_threshold: Set(None),
+                icon_name: Set(None),
+                color_hex: Set(None),
+                metadata: Set(serde_json::json!({})),
+            }
+            .insert(&db)
+            .await?;
+        }
+
+        Ok((CategoryCatalogService::new(Arc::new(db)), ids))
+    }
+
+    #[tokio::test]
+    async fn test_category_tree_nests_three_levels() -> Result<(), Box<dyn std::error::Error>> {
+        let (service, ids) = setup().await?;
+        let node = |name: &str, children| CategoryNode {
+            id: ids[name],
+            name: name.to_string(),
+            description: None,
+            icon_name: None,
+            color_hex: None,
+            children,
+        };
+
+        let tree = service.get_category_tree(None).await?;
+
+        // The two categories in a cycle are not reachable from a top-level one
+        assert_eq!(
+            tree,
+            vec![
+                node("Environmental", vec![node("Climate", vec![node("Carbon", vec![])])]),
+                node("Social", vec![]),
+            ]
+        );
+
+        let tree = service.get_category_tree(Some(2)).await?;
+        assert_eq!(
+            tree,
+            vec![
+                node("Environmental", vec![node("Climate", vec![])]),
+                node("Social", vec![]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_ancestors_nearest_first_and_stop_on_cycles() -> Result<(), Box<dyn std::error::Error>> {
+        let (service, ids) = setup().await?;
+
+        assert_eq!(
+            service.get_ancestors(ids["Carbon"]).await?,
+            vec![ids["Climate"], ids["Environmental"]]
+        );
+        assert_eq!(service.get_ancestors(ids["Social"]).await?, Vec::<Uuid>::new());
+        assert_eq!(service.get_ancestors(ids["Loop A"]).await?, vec![ids["Loop B"]]);
+        Ok(())
+    }
+}
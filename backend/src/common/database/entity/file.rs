@@ -1,5 +1,7 @@
 use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
+use crate::common::services::object_store::{ObjectStore, ObjectStream};
 use crate::impl_database_entity;
+use axum::body::Bytes;
 use sea_orm::entity::prelude::*;
 use sea_orm::{DeleteResult, Set};
 use serde_json::Value;
@@ -10,8 +12,9 @@ use std::sync::Arc;
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
-    pub content: Vec<u8>, // Binary content
+    pub content: Vec<u8>, // Binary content, empty when the bytes live in object storage
     pub metadata: Value,  // JSON metadata
+    pub object_key: Option<String>, // Object storage key for files not stored inline
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -30,10 +33,17 @@ impl ActiveModelBehavior for ActiveModel {}
 
 impl_database_entity!(Entity, Column::Id);
 
+/// The bytes of a stored file, wherever they are kept
+pub enum FileContent {
+    Inline(Vec<u8>),
+    Stream(ObjectStream),
+}
+
 #[allow(dead_code)]
 #[derive(Clone)]
 pub struct FileService {
     db_service: DatabaseService<Entity>,
+    object_store: Option<Arc<dyn ObjectStore>>,
 }
 
 #[allow(dead_code)]
@@ -41,17 +51,74 @@ impl FileService {
     pub fn new(db: Arc<DatabaseConnection>) -> Self {
         Self {
             db_service: DatabaseService::new(db),
+            object_store: None,
         }
     }
 
+    pub fn with_object_store(mut self, object_store: Arc<dyn ObjectStore>) -> Self {
+        self.object_store = Some(object_store);
+        self
+    }
+
+    /// Store a new file. With an object store configured the bytes are uploaded there
+    /// and only the key is written to the database.
     pub async fn create_file(&self, content: Vec<u8>, metadata: Value) -> Result<Model, DbErr> {
+        let id = Uuid::new_v4();
+
+        let Some(object_store) = &self.object_store else {
+            let file = ActiveModel {
+                id: Set(id),
+                content: Set(content),
+                metadata: Set(metadata),
+                object_key: Set(None),
+            };
+            return self.db_service.create(file).await;
+        };
+
+        let object_key = format!("files/{id}");
+        let content_type = metadata
+            .get("content_type")
+            .and_then(|v| v.as_str())
+            .unwrap_or("application/octet-stream");
+        object_store
+            .put(&object_key, Bytes::from(content), content_type)
+            .await
+            .map_err(|e| DbErr::Custom(format!("Failed to store file content: {e}")))?;
+
         let file = ActiveModel {
-            id: Set(Uuid::new_v4()),
-            content: Set(content),
+            id: Set(id),
+            content: Set(Vec::new()),
             metadata: Set(metadata),
+            object_key: Set(Some(object_key.clone())),
         };
 
-        self.db_service.create(file).await
+        match self.db_service.create(file).await {
+            Ok(file) => Ok(file),
+            Err(e) => {
+                // Don't leave an orphaned object behind when the row could not be written
+                if let Err(cleanup) = object_store.delete(&object_key).await {
+                    tracing::warn!(object_key = %object_key, error = %cleanup, "Failed to remove orphaned object");
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Open a file's bytes, streaming them from object storage when they are not inline
+    pub async fn open_content(&self, file: Model) -> Result<FileContent, DbErr> {
+        let Some(object_key) = file.object_key else {
+            return Ok(FileContent::Inline(file.content));
+        };
+
+        let object_store = self.object_store.as_ref().ok_or_else(|| {
+            DbErr::Custom("File is kept in object storage but none is configured".to_string())
+        })?;
+
+        object_store
+            .get(&object_key)
+            .await
+            .map(FileContent::Stream)
+            .map_err(|e| DbErr::Custom(format!("Failed to read file content: {e}")))
     }
 
     pub async fn get_file_by_id(&self, id: Uuid) -> Result<Option<Model>, DbErr> {
@@ -59,7 +126,34 @@ impl FileService {
     }
 
     pub async fn delete_file(&self, id: Uuid) -> Result<DeleteResult, DbErr> {
-        self.db_service.delete(id).await
+        let Some(object_store) = &self.object_store else {
+            return self.db_service.delete(id).await;
+        };
+
+        let object_key = self.get_file_by_id(id).await?.and_then(|file| file.object_key);
+        let result = self.db_service.delete(id).await?;
+
+        // The row is gone either way; a leftover object is only wasted space
+        if let Some(object_key) = object_key {
+            if let Err(e) = object_store.delete(&object_key).await {
+                tracing::warn!(object_key = %object_key, error = %e, "Failed to delete stored object");
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Best-effort removal of object storage entries whose rows were deleted elsewhere
+    pub async fn delete_stored_objects(&self, object_keys: &[String]) {
+        let Some(object_store) = &self.object_store else {
+            return;
+        };
+
+        for object_key in object_keys {
+            if let Err(e) = object_store.delete(object_key).await {
+                tracing::warn!(object_key = %object_key, error = %e, "Failed to delete stored object");
+            }
+        }
     }
 
     pub async fn update_file_metadata(&self, id: Uuid, metadata: Value) -> Result<Model, DbErr> {
@@ -87,6 +181,7 @@ mod tests {
             id: Uuid::new_v4(),
             content: vec![1, 2, 3, 4], // Sample binary data
             metadata: json!({"filename": "test.pdf", "size": 1024}),
+            object_key: None,
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -135,4 +230,105 @@ mod tests {
 
         Ok(())
     }
+
+    /// In-memory stand-in for S3
+    #[derive(Default)]
+    struct MockObjectStore {
+        objects: std::sync::Mutex<std::collections::HashMap<String, Bytes>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ObjectStore for MockObjectStore {
+        async fn put(&self, key: &str, data: Bytes, _content_type: &str) -> anyhow::Result<()> {
+            self.objects.lock().unwrap().insert(key.to_string(), data);
+            Ok(())
+        }
+
+        async fn get(&self, key: &str) -> anyhow::Result<ObjectStream> {
+            let data = self
+                .objects
+                .lock()
+                .unwrap()
+                .get(key)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("No such object: {key}"))?;
+            // Two chunks, like a real multi-part response body
+            let (head, tail) = data.split_at(data.len() / 2);
+            let chunks = vec![Ok(Bytes::copy_from_slice(head)), Ok(Bytes::copy_from_slice(tail))];
+            Ok(Box::pin(futures::stream::iter(chunks)))
+        }
+
+        async fn delete(&self, key: &str) -> anyhow::Result<()> {
+            self.objects.lock().unwrap().remove(key);
+            Ok(())
+        }
+    }
+
+    async fn file_test_db() -> Result<Arc<DatabaseConnection>, DbErr> {
+        use sea_orm::{ConnectionTrait, Database, Schema};
+
+        let db = Database::connect("sqlite::memory:").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(Entity)))
+            .await?;
+        Ok(Arc::new(db))
+    }
+
+    async fn read_all(content: FileContent) -> Vec<u8> {
+        use futures::TryStreamExt;
+
+        match content {
+            FileContent::Inline(bytes) => bytes,
+            FileContent::Stream(stream) => stream
+                .try_fold(Vec::new(), |mut acc, chunk| async move {
+                    acc.extend_from_slice(&chunk);
+                    Ok(acc)
+                })
+                .await
+                .unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_object_store_upload_and_download() -> Result<(), Box<dyn std::error::Error>> {
+        let store = Arc::new(MockObjectStore::default());
+        let service = FileService::new(file_test_db().await?).with_object_store(store.clone());
+
+        let file = service
+            .create_file(b"%PDF-1.7 evidence".to_vec(), json!({"content_type": "application/pdf"}))
+            .await?;
+
+        // Only the key is kept in the database
+        let object_key = file.object_key.clone().expect("object key");
+        assert_eq!(object_key, format!("files/{}", file.id));
+        assert!(file.content.is_empty());
+        assert!(store.objects.lock().unwrap().contains_key(&object_key));
+
+        let stored = service.get_file_by_id(file.id).await?.unwrap();
+        assert!(matches!(service.open_content(stored.clone()).await?, FileContent::Stream(_)));
+        assert_eq!(read_all(service.open_content(stored).await?).await, b"%PDF-1.7 evidence");
+
+        service.delete_file(file.id).await?;
+        assert!(store.objects.lock().unwrap().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_inline_files_still_readable_with_object_store() -> Result<(), Box<dyn std::error::Error>> {
+        let db = file_test_db().await?;
+
+        // A file uploaded before object storage was enabled
+        let legacy = FileService::new(db.clone())
+            .create_file(vec![1, 2, 3, 4], json!({"filename": "legacy.bin"}))
+            .await?;
+        assert_eq!(legacy.object_key, None);
+
+        let service = FileService::new(db).with_object_store(Arc::new(MockObjectStore::default()));
+        let stored = service.get_file_by_id(legacy.id).await?.unwrap();
+        assert_eq!(read_all(service.open_content(stored).await?).await, vec![1, 2, 3, 4]);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,173 @@
+use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
+use crate::impl_database_entity;
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{QuerySelect, Set, TransactionTrait};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// How long a stored response is replayed for
+const IDEMPOTENCY_TTL_HOURS: i64 = 24;
+/// A claim this old without a response belongs to a request that never finished
+const ABANDONED_CLAIM_SECS: i64 = 60;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "idempotency_keys")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub key: String,
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub user_id: String, // Keycloak user id, keys are only unique per user
+    pub status_code: Option<i32>, // None while the first request is still running
+    pub response_body: Option<Vec<u8>>,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl_database_entity!(Entity, Column::Key);
+
+/// Response of the request that first used an idempotency key
+#[derive(Clone, Debug, PartialEq)]
+pub struct StoredResponse {
+    pub status_code: u16,
+    pub body: Vec<u8>,
+}
+
+enum Claim {
+    /// This request runs the handler
+    Acquired,
+    Completed(StoredResponse),
+    InProgress,
+}
+
+#[derive(Clone)]
+pub struct IdempotencyService {
+    db_service: DatabaseService<Entity>,
+    wait_timeout: Duration,
+    poll_interval: Duration,
+}
+
+impl IdempotencyService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            db_service: DatabaseService::new(db),
+            wait_timeout: Duration::from_secs(10),
+            poll_interval: Duration::from_millis(100),
+        }
+    }
+
+    /// Run `handler` once per key and user, replaying its response for repeated requests.
+    ///
+    /// A request arriving while another one with the same key is still running waits
+    /// for its response. `None` means that response did not arrive in time. Server
+    /// errors are not stored, so the client can retry them with the same key.
+    pub async fn check_or_store<F>(
+        &self,
+        key: &str,
+        user_id: &str,
+        handler: F,
+    ) -> Result<Option<StoredResponse>, DbErr>
+    where
+        F: Future<Output = StoredResponse>,
+    {
+        let deadline = Instant::now() + self.wait_timeout;
+
+        loop {
+            match self.claim(key, user_id).await? {
+                Claim::Acquired => {
+                    let response = handler.await;
+                    if response.status_code >= 500 {
+                        Entity::delete_by_id((key.to_string(), user_id.to_string()))
+                            .exec(self.db_service.get_connection())
+                            .await?;
+                    } else {
+                        self.store(key, user_id, &response).await?;
+                    }
+                    return Ok(Some(response));
+                }
+                Claim::Completed(response) => return Ok(Some(response)),
+                Claim::InProgress if Instant::now() >= deadline => return Ok(None),
+                Claim::InProgress => tokio::time::sleep(self.poll_interval).await,
+            }
+        }
+    }
+
+    /// Look the key up under a row lock, taking it over when it is new, expired or abandoned
+    async fn claim(&self, key: &str, user_id: &str) -> Result<Claim, DbErr> {
+        let now = Utc::now();
+        let pending = ActiveModel {
+            key: Set(key.to_string()),
+            user_id: Set(user_id.to_string()),
+            status_code: Set(None),
+            response_body: Set(None),
+            created_at: Set(now),
+            expires_at: Set(now + ChronoDuration::hours(IDEMPOTENCY_TTL_HOURS)),
+        };
+
+        let txn = self.db_service.get_connection().begin().await?;
+        let existing = Entity::find_by_id((key.to_string(), user_id.to_string()))
+            .lock_exclusive()
+            .one(&txn)
+            .await?;
+
+        let claim = match existing {
+            None => {
+                // A concurrent request may insert the same key between our SELECT and INSERT
+                let inserted = Entity::insert(pending)
+                    .on_conflict(
+                        OnConflict::columns([Column::Key, Column::UserId])
+                            .do_nothing()
+                            .to_owned(),
+                    )
+                    .exec_without_returning(&txn)
+                    .await?;
+                if inserted == 1 {
+                    Claim::Acquired
+                } else {
+                    Claim::InProgress
+                }
+            }
+            Some(row) if row.expires_at <= now => {
+                pending.update(&txn).await?;
+                Claim::Acquired
+            }
+            Some(Model {
+                status_code: Some(status_code),
+                response_body,
+                ..
+            }) => Claim::Completed(StoredResponse {
+                status_code: status_code as u16,
+                body: response_body.unwrap_or_default(),
+            }),
+            Some(row) if row.created_at <= now - ChronoDuration::seconds(ABANDONED_CLAIM_SECS) => {
+                pending.update(&txn).await?;
+                Claim::Acquired
+            }
+            Some(_) => Claim::InProgress,
+        };
+        txn.commit().await?;
+
+        Ok(claim)
+    }
+
+    async fn store(&self, key: &str, user_id: &str, response: &StoredResponse) -> Result<(), DbErr> {
+        ActiveModel {
+            key: Set(key.to_string()),
+            user_id: Set(user_id.to_string()),
+            status_code: Set(Some(i32::from(response.status_code))),
+            response_body: Set(Some(response.body.clone())),
+            ..Default::default()
+        }
+        .update(self.db_service.get_connection())
+        .await?;
+
+        Ok(())
+    }
+}
@@ -1,12 +1,18 @@
 pub mod assessment_categories;
+pub mod assessment_questions;
+pub mod assessment_templates;
 pub mod assessments;
 pub mod assessments_response;
 pub mod assessments_response_file;
 pub mod assessments_submission;
 pub mod category_catalog;
 pub mod file;
+pub mod idempotency_keys;
+pub mod notifications;
 pub mod organization_categories;
+pub mod organizations_cache;
 pub mod questions;
 pub mod questions_revisions;
 pub mod submission_reports;
+pub mod submission_timeline;
 pub mod temp_submission;
@@ -0,0 +1,228 @@
+use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
+use crate::impl_database_entity;
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::Expr;
+use sea_orm::{QueryOrder, QuerySelect, Set};
+use std::sync::Arc;
+
+/// Sent to organization admins once a report has been generated for one of their submissions
+pub const REPORT_GENERATED: &str = "report_generated";
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "notifications")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub notification_id: Uuid,
+    pub user_id: String, // Keycloak user id of the recipient
+    pub org_id: String,  // Keycloak organization id the event belongs to
+    #[sea_orm(column_name = "type")]
+    pub notification_type: String,
+    pub title: String,
+    pub body: String,
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl_database_entity!(Entity, Column::NotificationId);
+
+#[allow(dead_code)]
+#[derive(Clone)]
+pub struct NotificationsService {
+    db_service: DatabaseService<Entity>,
+}
+
+#[allow(dead_code)]
+impl NotificationsService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            db_service: DatabaseService::new(db),
+        }
+    }
+
+    pub async fn create(
+        &self,
+        user_id: String,
+        org_id: String,
+        notification_type: &str,
+        title: String,
+        body: String,
+    ) -> Result<Model, DbErr> {
+        let notification = ActiveModel {
+            notification_id: Set(Uuid::new_v4()),
+            user_id: Set(user_id),
+            org_id: Set(org_id),
+            notification_type: Set(notification_type.to_string()),
+            title: Set(title),
+            body: Set(body),
+            read_at: Set(None),
+            created_at: Set(Utc::now()),
+        };
+
+        self.db_service.create(notification).await
+    }
+
+    /// Tell every organization admin that a report is ready for one of their submissions
+    pub async fn notify_report_generated(
+        &self,
+        org_id: &str,
+        admin_user_ids: &[String],
+        assessment_name: &str,
+    ) -> Result<Vec<Model>, DbErr> {
+        let mut notifications = Vec::with_capacity(admin_user_ids.len());
+        for user_id in admin_user_ids {
+            notifications.push(
+                self.create(
+                    user_id.clone(),
+                    org_id.to_string(),
+                    REPORT_GENERATED,
+                    "Report available".to_string(),
+                    format!("The report for \"{assessment_name}\" has been generated."),
+                )
+                .await?,
+            );
+        }
+        Ok(notifications)
+    }
+
+    /// Newest first
+    pub async fn list_for_user(
+        &self,
+        user_id: &str,
+        unread_only: bool,
+        limit: u64,
+        offset: u64,
+    ) -> Result<Vec<Model>, DbErr> {
+        let mut query = Entity::find().filter(Column::UserId.eq(user_id));
+        if unread_only {
+            query = query.filter(Column::ReadAt.is_null());
+        }
+
+        query
+            .order_by_desc(Column::CreatedAt)
+            .limit(limit)
+            .offset(offset)
+            .all(self.db_service.get_connection())
+            .await
+    }
+
+    /// Mark one of the user's notifications as read, `None` when the user has no such
+    /// notification. Already read notifications keep their original `read_at`.
+    pub async fn mark_read(&self, notification_id: Uuid, user_id: &str) -> Result<Option<Model>, DbErr> {
+        let Some(notification) = Entity::find_by_id(notification_id)
+            .filter(Column::UserId.eq(user_id))
+            .one(self.db_service.get_connection())
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        if notification.read_at.is_some() {
+            return Ok(Some(notification));
+        }
+
+        let mut notification: ActiveModel = notification.into();
+        notification.read_at = Set(Some(Utc::now()));
+        self.db_service.update(notification).await.map(Some)
+    }
+
+    /// Mark all of the user's unread notifications as read, returning how many changed
+    pub async fn mark_all_read(&self, user_id: &str) -> Result<u64, DbErr> {
+        let result = Entity::update_many()
+            .col_expr(Column::ReadAt, Expr::value(Utc::now()))
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::ReadAt.is_null())
+            .exec(self.db_service.get_connection())
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{ConnectionTrait, Database, Schema};
+
+    async fn notifications_service() -> Result<NotificationsService, DbErr> {
+        let db = Database::connect("sqlite::memory:").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(Entity)))
+            .await?;
+        Ok(NotificationsService::new(Arc::new(db)))
+    }
+
+    #[tokio::test]
+    async fn test_report_generated_notifies_every_admin() -> Result<(), Box<dyn std::error::Error>> {
+        let service = notifications_service().await?;
+        let admins = vec!["admin-1".to_string(), "admin-2".to_string()];
+
+        let created = service
+            .notify_report_generated("test_org", &admins, "Annual Assessment")
+            .await?;
+        assert_eq!(created.len(), 2);
+
+        for admin in &admins {
+            let notifications = service.list_for_user(admin, true, 50, 0).await?;
+            assert_eq!(notifications.len(), 1);
+            assert_eq!(notifications[0].notification_type, REPORT_GENERATED);
+            assert_eq!(notifications[0].org_id, "test_org");
+            assert!(notifications[0].body.contains("Annual Assessment"));
+        }
+        assert!(service.list_for_user("someone-else", false, 50, 0).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_mark_read_clears_unread_list() -> Result<(), Box<dyn std::error::Error>> {
+        let service = notifications_service().await?;
+        let notification = service
+            .create(
+                "user-1".to_string(),
+                "test_org".to_string(),
+                REPORT_GENERATED,
+                "Report available".to_string(),
+                "Body".to_string(),
+            )
+            .await?;
+
+        // Another user cannot mark it read
+        assert!(service.mark_read(notification.notification_id, "user-2").await?.is_none());
+
+        let read = service.mark_read(notification.notification_id, "user-1").await?.unwrap();
+        assert!(read.read_at.is_some());
+        assert!(service.list_for_user("user-1", true, 50, 0).await?.is_empty());
+        assert_eq!(service.list_for_user("user-1", false, 50, 0).await?.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_mark_all_read() -> Result<(), Box<dyn std::error::Error>> {
+        let service = notifications_service().await?;
+        for title in ["First", "Second"] {
+            service
+                .create(
+                    "user-1".to_string(),
+                    "test_org".to_string(),
+                    REPORT_GENERATED,
+                    title.to_string(),
+                    "Body".to_string(),
+                )
+                .await?;
+        }
+
+        assert_eq!(service.mark_all_read("user-1").await?, 2);
+        assert_eq!(service.mark_all_read("user-1").await?, 0);
+        assert!(service.list_for_user("user-1", true, 50, 0).await?.is_empty());
+
+        Ok(())
+    }
+}
@@ -2,8 +2,10 @@ use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
 use crate::impl_database_entity;
 use chrono::{DateTime, Utc};
 use sea_orm::entity::prelude::*;
-use sea_orm::{QueryOrder, Set};
+use sea_orm::{QueryOrder, Set, TransactionTrait};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use thiserror::Error;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "organization_categories")]
@@ -38,6 +40,40 @@ impl ActiveModelBehavior for ActiveModel {}
 
 impl_database_entity!(Entity, Column::OrganizationCategoryId);
 
+#[derive(Error, Debug)]
+pub enum RebalanceError {
+    #[error("Organization category not found")]
+    NotFound,
+    #[error("Cannot rebalance weights of an organization with a single category")]
+    SingleCategory,
+    #[error("Cannot rebalance weights when all other categories have zero weight")]
+    NoOtherWeight,
+    #[error("Database error: {0}")]
+    Database(#[from] DbErr),
+}
+
+#[derive(Error, Debug)]
+pub enum CategoryWeightsError {
+    #[error("Category weights must be positive")]
+    NotPositive,
+    #[error("Category weights must sum to 100")]
+    InvalidTotal,
+    #[error("Category {0} is not assigned to the organization")]
+    NotAssigned(Uuid),
+    #[error("Category set does not match the organization's categories")]
+    Mismatch,
+    #[error("Database error: {0}")]
+    Database(#[from] DbErr),
+}
+
+#[derive(Error, Debug)]
+pub enum ReorderError {
+    #[error("Category set does not match the organization's categories")]
+    Mismatch,
+    #[error("Database error: {0}")]
+    Database(#[from] DbErr),
+}
+
 #[allow(dead_code)]
 #[derive(Clone)]
 pub struct OrganizationCategoriesService {
@@ -131,9 +167,344 @@ impl OrganizationCategoriesService {
         Ok(())
     }
 
+    /// Set one category's weight and rebalance the others so the total stays 100.
+    ///
+    /// `new_weight` is clamped to 1..=98 and the remainder is split proportionally to
+    /// the other categories' current weights, with rounding left over going to the
+    /// last of them. Everything is written in one transaction; the updated categories
+    /// are returned in display order.
+    pub async fn update_single_category_weight(
+        &self,
+        org_id: &str,
+        category_catalog_id: Uuid,
+        new_weight: i32,
+    ) -> Result<Vec<Model>, RebalanceError> {
+        let txn = self.db_service.get_connection().begin().await?;
+
+        let categories = Entity::find()
+            .filter(Column::KeycloakOrganizationId.eq(org_id))
+            .order_by_asc(Column::Order)
+            .all(&txn)
+            .await?;
+
+        if !categories
+            .iter()
+            .any(|category| category.category_catalog_id == category_catalog_id)
+        {
+            return Err(RebalanceError::NotFound);
+        }
+        if categories.len() < 2 {
+            return Err(RebalanceError::SingleCategory);
+        }
+
+        let new_weight = new_weight.clamp(1, 98);
+        let (target, others): (Vec<Model>, Vec<Model>) = categories
+            .into_iter()
+            .partition(|category| category.category_catalog_id == category_catalog_id);
+
+        let others_total: i64 = others.iter().map(|category| i64::from(category.weight)).sum();
+        if others_total <= 0 {
+            return Err(RebalanceError::NoOtherWeight);
+        }
+
+        let remaining = i64::from(100 - new_weight);
+        let mut distributed = 0;
+        let last = others.len() - 1;
+        let mut weights = vec![(target[0].clone(), new_weight)];
+        for (index, category) in others.into_iter().enumerate() {
+            let weight = if index == last {
+                remaining - distributed
+            } else {
+                i64::from(category.weight) * remaining / others_total
+            };
+            distributed += weight;
+            weights.push((category, weight as i32));
+        }
+
+        let now = Utc::now();
+        let mut updated = Vec::with_capacity(weights.len());
+        for (category, weight) in weights {
+            let mut active_model: ActiveModel = category.into();
+            active_model.weight = Set(weight);
+            active_model.updated_at = Set(now);
+            updated.push(active_model.update(&txn).await?);
+        }
+
+        txn.commit().await?;
+
+        updated.sort_by_key(|category| category.order);
+        Ok(updated)
+    }
+
+    /// Replace the weights of all of an organization's categories at once.
+    ///
+    /// `weights` pairs each assigned category catalog ID with its new weight; every
+    /// assigned category must appear exactly once, each weight must be positive and
+    /// together they must sum to 100. All rows are updated in one transaction and
+    /// returned in display order.
+    pub async fn set_category_weights(
+        &self,
+        org_id: &str,
+        weights: &[(Uuid, i32)],
+    ) -> Result<Vec<Model>, CategoryWeightsError> {
+        if weights.iter().any(|(_, weight)| *weight < 1) {
+            return Err(CategoryWeightsError::NotPositive);
+        }
+        if weights.iter().map(|(_, weight)| i64::from(*weight)).sum::<i64>() != 100 {
+            return Err(CategoryWeightsError::InvalidTotal);
+        }
+
+        let txn = self.db_service.get_connection().begin().await?;
+
+        let categories = Entity::find()
+            .filter(Column::KeycloakOrganizationId.eq(org_id))
+            .all(&txn)
+            .await?;
+
+        let existing: HashSet<Uuid> = categories.iter().map(|category| category.category_catalog_id).collect();
+        let new_weights: HashMap<Uuid, i32> = weights.iter().copied().collect();
+        if let Some((unknown, _)) = weights.iter().find(|(id, _)| !existing.contains(id)) {
+            return Err(CategoryWeightsError::NotAssigned(*unknown));
+        }
+        if new_weights.len() != weights.len() || new_weights.len() != existing.len() {
+            return Err(CategoryWeightsError::Mismatch);
+        }
+
+        let now = Utc::now();
+        let mut updated = Vec::with_capacity(categories.len());
+        for category in categories {
+            let weight = new_weights[&category.category_catalog_id];
+            let mut active_model: ActiveModel = category.into();
+            active_model.weight = Set(weight);
+            active_model.updated_at = Set(now);
+            updated.push(active_model.update(&txn).await?);
+        }
+
+        txn.commit().await?;
+
+        updated.sort_by_key(|category| category.order);
+        Ok(updated)
+    }
+
+    /// Rewrite the display order of an organization's categories.
+    ///
+    /// `category_catalog_ids` must list every category assigned to the organization
+    /// exactly once; each row's `order` becomes its position in the list. All rows
+    /// are updated in one transaction and returned in their new order.
+    pub async fn reorder_categories(
+        &self,
+        org_id: &str,
+        category_catalog_ids: &[Uuid],
+    ) -> Result<Vec<Model>, ReorderError> {
+        let txn = self.db_service.get_connection().begin().await?;
+
+        let categories = Entity::find()
+            .filter(Column::KeycloakOrganizationId.eq(org_id))
+            .all(&txn)
+            .await?;
+
+        let existing: HashSet<Uuid> = categories.iter().map(|category| category.category_catalog_id).collect();
+        let requested: HashSet<Uuid> = category_catalog_ids.iter().copied().collect();
+        if requested.len() != category_catalog_ids.len() || requested != existing {
+            return Err(ReorderError::Mismatch);
+        }
+
+        let positions: HashMap<Uuid, i32> = category_catalog_ids
+            .iter()
+            .enumerate()
+            .map(|(index, id)| (*id, index as i32))
+            .collect();
+
+        let now = Utc::now();
+        let mut updated = Vec::with_capacity(categories.len());
+        for category in categories {
+            let order = positions[&category.category_catalog_id];
+            let mut active_model: ActiveModel = category.into();
+            active_model.order = Set(order);
+            active_model.updated_at = Set(now);
+            updated.push(active_model.update(&txn).await?);
+        }
+
+        txn.commit().await?;
+
+        updated.sort_by_key(|category| category.order);
+        Ok(updated)
+    }
+
     pub async fn get_total_weight_for_organization(&self, keycloak_organization_id: &str) -> Result<i32, DbErr> {
         let categories = self.get_organization_categories_by_keycloak_organization_id(keycloak_organization_id).await?;
         let total_weight: i32 = categories.iter().map(|cat| cat.weight).sum();
         Ok(total_weight)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{ConnectionTrait, Database, Schema};
+
+    /// Organization `test_org` with one category per weight, ordered as given
+    async fn service_with_weights(weights: &[i32]) -> Result<(OrganizationCategoriesService, Vec<Uuid>), DbErr> {
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let schema = Schema::new(db.get_database_backend());
+        db.execute(db.get_database_backend().build(&schema.create_table_from_entity(Entity)))
+            .await?;
+
+        let service = OrganizationCategoriesService::new(Arc::new(db));
+        let mut catalog_ids = Vec::new();
+        for (order, weight) in weights.iter().enumerate() {
+            let catalog_id = Uuid::new_v4();
+            service
+                .create_organization_category(Uuid::new_v4(), "test_org".to_string(), catalog_id, *weight, order as i32)
+                .await?;
+            catalog_ids.push(catalog_id);
+        }
+        Ok((service, catalog_ids))
+    }
+
+    fn weights_of(categories: &[Model]) -> Vec<i32> {
+        categories.iter().map(|category| category.weight).collect()
+    }
+
+    fn catalog_ids_of(categories: &[Model]) -> Vec<Uuid> {
+        categories.iter().map(|category| category.category_catalog_id).collect()
+    }
+
+    #[tokio::test]
+    async fn test_update_single_category_weight_rebalances_proportionally() -> Result<(), RebalanceError> {
+        let (service, ids) = service_with_weights(&[50, 30, 20]).await?;
+
+        let updated = service.update_single_category_weight("test_org", ids[0], 40).await?;
+        assert_eq!(weights_of(&updated), vec![40, 36, 24]);
+
+        let stored = service.get_organization_categories_by_keycloak_organization_id("test_org").await?;
+        assert_eq!(weights_of(&stored), vec![40, 36, 24]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_single_category_weight_gives_rounding_to_last_category() -> Result<(), RebalanceError> {
+        let (service, ids) = service_with_weights(&[40, 30, 20, 10]).await?;
+
+        // 93 split as 46.5 / 31 / 15.5 is floored to 46 / 31, the last takes the rest
+        let updated = service.update_single_category_weight("test_org", ids[0], 7).await?;
+        assert_eq!(weights_of(&updated), vec![7, 46, 31, 16]);
+        assert_eq!(updated.iter().map(|category| category.weight).sum::<i32>(), 100);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_single_category_weight_clamps_new_weight() -> Result<(), RebalanceError> {
+        let (service, ids) = service_with_weights(&[50, 50]).await?;
+
+        let updated = service.update_single_category_weight("test_org", ids[1], 100).await?;
+        assert_eq!(weights_of(&updated), vec![2, 98]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_single_category_weight_rejects_single_category() -> Result<(), RebalanceError> {
+        let (service, ids) = service_with_weights(&[100]).await?;
+
+        let err = service.update_single_category_weight("test_org", ids[0], 50).await.unwrap_err();
+        assert!(matches!(err, RebalanceError::SingleCategory));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_single_category_weight_rejects_zero_weight_others() -> Result<(), RebalanceError> {
+        let (service, ids) = service_with_weights(&[100, 0, 0]).await?;
+
+        let err = service.update_single_category_weight("test_org", ids[0], 60).await.unwrap_err();
+        assert!(matches!(err, RebalanceError::NoOtherWeight));
+
+        let stored = service.get_organization_categories_by_keycloak_organization_id("test_org").await?;
+        assert_eq!(weights_of(&stored), vec![100, 0, 0]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reorder_categories_applies_new_order() -> Result<(), ReorderError> {
+        let (service, ids) = service_with_weights(&[50, 30, 20]).await?;
+
+        let updated = service.reorder_categories("test_org", &[ids[2], ids[0], ids[1]]).await?;
+        assert_eq!(catalog_ids_of(&updated), vec![ids[2], ids[0], ids[1]]);
+        assert_eq!(updated.iter().map(|category| category.order).collect::<Vec<_>>(), vec![0, 1, 2]);
+
+        let stored = service.get_organization_categories_by_keycloak_organization_id("test_org").await?;
+        assert_eq!(catalog_ids_of(&stored), vec![ids[2], ids[0], ids[1]]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reorder_categories_rejects_mismatched_set() -> Result<(), ReorderError> {
+        let (service, ids) = service_with_weights(&[50, 30, 20]).await?;
+
+        let missing = service.reorder_categories("test_org", &[ids[1], ids[0]]).await.unwrap_err();
+        assert!(matches!(missing, ReorderError::Mismatch));
+
+        let extra = service
+            .reorder_categories("test_org", &[ids[2], ids[1], ids[0], Uuid::new_v4()])
+            .await
+            .unwrap_err();
+        assert!(matches!(extra, ReorderError::Mismatch));
+
+        let duplicate = service.reorder_categories("test_org", &[ids[0], ids[0], ids[1]]).await.unwrap_err();
+        assert!(matches!(duplicate, ReorderError::Mismatch));
+
+        let stored = service.get_organization_categories_by_keycloak_organization_id("test_org").await?;
+        assert_eq!(catalog_ids_of(&stored), ids);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_category_weights_replaces_all_weights() -> Result<(), CategoryWeightsError> {
+        let (service, ids) = service_with_weights(&[50, 30, 20]).await?;
+
+        let updated = service
+            .set_category_weights("test_org", &[(ids[2], 60), (ids[0], 10), (ids[1], 30)])
+            .await?;
+        assert_eq!(weights_of(&updated), vec![10, 30, 60]);
+
+        let stored = service.get_organization_categories_by_keycloak_organization_id("test_org").await?;
+        assert_eq!(weights_of(&stored), vec![10, 30, 60]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_category_weights_rejects_sum_other_than_100() -> Result<(), CategoryWeightsError> {
+        let (service, ids) = service_with_weights(&[50, 30, 20]).await?;
+
+        let err = service
+            .set_category_weights("test_org", &[(ids[0], 50), (ids[1], 30), (ids[2], 30)])
+            .await
+            .unwrap_err();
+        assert!(matches!(err, CategoryWeightsError::InvalidTotal));
+
+        let stored = service.get_organization_categories_by_keycloak_organization_id("test_org").await?;
+        assert_eq!(weights_of(&stored), vec![50, 30, 20]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_set_category_weights_rejects_unknown_or_missing_category() -> Result<(), CategoryWeightsError> {
+        let (service, ids) = service_with_weights(&[50, 30, 20]).await?;
+
+        let unknown = service
+            .set_category_weights("test_org", &[(ids[0], 50), (ids[1], 30), (Uuid::new_v4(), 20)])
+            .await
+            .unwrap_err();
+        assert!(matches!(unknown, CategoryWeightsError::NotAssigned(_)));
+
+        let missing = service
+            .set_category_weights("test_org", &[(ids[0], 70), (ids[1], 30)])
+            .await
+            .unwrap_err();
+        assert!(matches!(missing, CategoryWeightsError::Mismatch));
+
+        let stored = service.get_organization_categories_by_keycloak_organization_id("test_org").await?;
+        assert_eq!(weights_of(&stored), vec![50, 30, 20]);
+        Ok(())
+    }
+}
@@ -0,0 +1,196 @@
+use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
+use crate::common::models::keycloak::KeycloakOrganization;
+use crate::impl_database_entity;
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::{Alias, Condition, Expr, Func, LikeExpr, SimpleExpr};
+use sea_orm::{PaginatorTrait, QueryOrder, QuerySelect, Set, TransactionTrait};
+use std::sync::Arc;
+
+/// Keycloak organization as of the last sync, see `services::organizations_cache`
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "organizations_cache")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub keycloak_org_id: String,
+    pub name: String,
+    pub domains: Json, // JSON array of domain names
+    pub synced_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl_database_entity!(Entity, Column::KeycloakOrgId);
+
+impl Model {
+    pub fn domain_names(&self) -> Vec<String> {
+        serde_json::from_value(self.domains.clone()).unwrap_or_default()
+    }
+}
+
+#[derive(Clone)]
+pub struct OrganizationsCacheService {
+    db_service: DatabaseService<Entity>,
+}
+
+impl OrganizationsCacheService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            db_service: DatabaseService::new(db),
+        }
+    }
+
+    /// Swap the cached organizations for `organizations` in one transaction, so
+    /// readers see either the old or the new set
+    pub async fn replace_all(&self, organizations: &[KeycloakOrganization]) -> Result<usize, DbErr> {
+        let synced_at = Utc::now();
+        let rows: Vec<ActiveModel> = organizations
+            .iter()
+            .map(|org| {
+                let domains: Vec<&str> = org
+                    .domains
+                    .iter()
+                    .flatten()
+                    .map(|domain| domain.name.as_str())
+                    .collect();
+                ActiveModel {
+                    keycloak_org_id: Set(org.id.clone()),
+                    name: Set(org.name.clone()),
+                    domains: Set(serde_json::json!(domains)),
+                    synced_at: Set(synced_at),
+                }
+            })
+            .collect();
+
+        let txn = self.db_service.get_connection().begin().await?;
+        Entity::delete_many().exec(&txn).await?;
+        if !rows.is_empty() {
+            Entity::insert_many(rows).exec(&txn).await?;
+        }
+        txn.commit().await?;
+
+        Ok(organizations.len())
+    }
+
+    /// Organizations whose name or one of whose domains contains `query`, ignoring case
+    pub async fn search(&self, query: &str, limit: u64) -> Result<Vec<Model>, DbErr> {
+        Entity::find()
+            .filter(Self::matches(query))
+            .order_by_asc(Column::Name)
+            .limit(limit)
+            .all(self.db_service.get_connection())
+            .await
+    }
+
+    /// Number of cached organizations, optionally only those matching `query`
+    pub async fn count(&self, query: Option<&str>) -> Result<u64, DbErr> {
+        let mut select = Entity::find();
+        if let Some(query) = query {
+            select = select.filter(Self::matches(query));
+        }
+        select.count(self.db_service.get_connection()).await
+    }
+
+    /// When the cache was last filled, `None` if it never was or Keycloak had no organizations
+    pub async fn last_synced_at(&self) -> Result<Option<DateTime<Utc>>, DbErr> {
+        Ok(Entity::find()
+            .order_by_desc(Column::SyncedAt)
+            .one(self.db_service.get_connection())
+            .await?
+            .map(|org| org.synced_at))
+    }
+
+    fn matches(query: &str) -> Condition {
+        let escaped = query
+            .to_lowercase()
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_");
+        let pattern = || LikeExpr::new(format!("%{escaped}%")).escape('\\');
+        let lower = |expr: SimpleExpr| SimpleExpr::from(Func::lower(expr));
+
+        Condition::any()
+            .add(Expr::expr(lower(Expr::col(Column::Name).into())).like(pattern()))
+            .add(
+                Expr::expr(lower(Expr::col(Column::Domains).cast_as(Alias::new("text"))))
+                    .like(pattern()),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::models::keycloak::OrganizationDomain;
+    use sea_orm::{ConnectionTrait, Database, Schema};
+
+    fn organization(id: &str, name: &str, domains: &[&str]) -> KeycloakOrganization {
+        KeycloakOrganization {
+            id: String::from(id),
+            name: String::from(name),
+            alias: None,
+            enabled: true,
+            description: None,
+            redirect_url: None,
+            domains: Some(
+                domains
+                    .iter()
+                    .map(|&name| OrganizationDomain {
+                        name: String::from(name),
+                        verified: None,
+                    })
+                    .collect(),
+            ),
+            attributes: None,
+        }
+    }
+
+    async fn setup() -> Result<OrganizationsCacheService, DbErr> {
+        let db = Database::connect("sqlite::memory:").await?;
+        let backend = db.get_database_backend();
+        db.execute(backend.build(&Schema::new(backend).create_table_from_entity(Entity)))
+            .await?;
+        Ok(OrganizationsCacheService::new(Arc::new(db)))
+    }
+
+    #[tokio::test]
+    async fn test_replace_all_drops_organizations_gone_from_keycloak() -> Result<(), DbErr> {
+        let cache = setup().await?;
+        cache
+            .replace_all(&[organization("1", "Green Coop", &[]), organization("2", "Blue Bank", &[])])
+            .await?;
+
+        cache.replace_all(&[organization("2", "Blue Bank", &["bluebank.org"])]).await?;
+
+        let cached = cache.search("", 10).await?;
+        assert_eq!(cached.len(), 1);
+        assert_eq!(cached[0].keycloak_org_id, "2");
+        assert_eq!(cached[0].domain_names(), vec!["bluebank.org".to_string()]);
+        assert!(cache.last_synced_at().await?.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_search_matches_name_or_domain_ignoring_case() -> Result<(), DbErr> {
+        let cache = setup().await?;
+        cache
+            .replace_all(&[
+                organization("1", "Green Coop", &["green.example"]),
+                organization("2", "Blue Bank", &["bluebank.org"]),
+                organization("3", "100% Organic", &[]),
+            ])
+            .await?;
+
+        let ids = |models: Vec<Model>| models.into_iter().map(|m| m.keycloak_org_id).collect::<Vec<_>>();
+        assert_eq!(ids(cache.search("GREEN", 10).await?), vec!["1"]);
+        assert_eq!(ids(cache.search("bank.org", 10).await?), vec!["2"]);
+        assert_eq!(ids(cache.search("0%", 10).await?), vec!["3"]);
+        assert_eq!(ids(cache.search("_", 10).await?), Vec::<String>::new());
+        assert_eq!(cache.count(Some("o")).await?, 3);
+        assert_eq!(cache.count(None).await?, 3);
+        Ok(())
+    }
+}
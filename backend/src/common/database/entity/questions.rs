@@ -2,7 +2,9 @@ use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
 use crate::impl_database_entity;
 use chrono::{DateTime, Utc};
 use sea_orm::entity::prelude::*;
-use sea_orm::{DeleteResult, Set};
+use sea_orm::{DbBackend, DeleteResult, FromQueryResult, QueryOrder, QuerySelect, Set, Statement, TransactionTrait};
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
@@ -12,6 +14,12 @@ pub struct Model {
     pub question_id: Uuid,
     pub category_id: Uuid,
     pub created_at: DateTime<Utc>,
+    /// Stable key of imported questions, see `QuestionsService::import_questions`
+    pub external_key: Option<String>,
+    /// Rules for skipping this question, see `common::services::skip_logic`
+    pub skip_rules: Json,
+    /// Answers are encrypted at rest, see `common::services::encryption`
+    pub sensitive: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -40,8 +48,48 @@ impl Related<super::category_catalog::Entity> for Entity {
 
 impl ActiveModelBehavior for ActiveModel {}
 
+/// Responses to one question within one assessment, see `QuestionsService::usage_by_assessment`
+#[derive(Debug, Clone, PartialEq, FromQueryResult)]
+pub struct QuestionUsageRow {
+    pub assessment_id: Uuid,
+    pub org_id: String,
+    /// Taken from the assessment's submission, if it has one
+    pub org_name: Option<String>,
+    pub response_count: i64,
+    pub last_responded_at: Option<DateTime<Utc>>,
+    /// 1 when the assessment's submission is still waiting for review
+    pub has_pending_submission: i32,
+}
+
 impl_database_entity!(Entity, Column::QuestionId);
 
+/// One question of an import, identified by its `external_key`
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuestionImport {
+    pub external_key: String,
+    pub category_id: Uuid,
+    pub text: Json, // Multilingual text, keyed by language code
+    pub weight: f32,
+}
+
+/// What an import does with questions whose key already exists
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DuplicateQuestionPolicy {
+    /// Save a new revision when the text, weight or category changed
+    #[default]
+    Update,
+    /// Leave existing questions as they are
+    Skip,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QuestionImportCounts {
+    pub created: usize,
+    pub updated: usize,
+    pub skipped: usize,
+}
+
 #[allow(dead_code)]
 #[derive(Clone)]
 pub struct QuestionsService {
@@ -61,6 +109,9 @@ impl QuestionsService {
             question_id: Set(Uuid::new_v4()),
             category_id: Set(category_id),
             created_at: Set(Utc::now()),
+            external_key: Set(None),
+            sensitive: Set(false),
+            skip_rules: Set(Json::Array(Vec::new())),
         };
 
         self.db_service.create(question).await
@@ -77,6 +128,145 @@ impl QuestionsService {
             .await
     }
 
+    /// Number of questions per category, counted in the database. Categories
+    /// without questions are absent from the map.
+    pub async fn count_by_category(&self) -> Result<HashMap<Uuid, i64>, DbErr> {
+        let counts: Vec<(Uuid, i64)> = Entity::find()
+            .select_only()
+            .column(Column::CategoryId)
+            .column_as(Expr::col(Column::QuestionId).count(), "question_count")
+            .group_by(Column::CategoryId)
+            .into_tuple()
+            .all(self.db_service.get_connection())
+            .await?;
+
+        Ok(counts.into_iter().collect())
+    }
+
+    /// Every assessment holding a response to any revision of `question_id`, with
+    /// the number of responses and when the latest was saved. Assessments without
+    /// such responses are absent.
+    pub async fn usage_by_assessment(&self, question_id: Uuid) -> Result<Vec<QuestionUsageRow>, DbErr> {
+        let db = self.db_service.get_connection();
+        let backend = db.get_database_backend();
+        let placeholder = match backend {
+            DbBackend::Postgres => "$1",
+            _ => "?",
+        };
+        let sql = format!(
+            r#"SELECT a.assessment_id, a.org_id, s.org_name,
+                   COUNT(r.response_id) AS response_count,
+                   MAX(r.updated_at) AS last_responded_at,
+                   CASE WHEN s.status IN ('pending_review', 'under_review') THEN 1 ELSE 0 END AS has_pending_submission
+              FROM questions_revisions qr
+              JOIN assessments_response r ON r.question_revision_id = qr.question_revision_id
+              JOIN assessments a ON a.assessment_id = r.assessment_id
+              LEFT JOIN assessments_submission s ON s.submission_id = a.assessment_id
+             WHERE qr.question_id = {placeholder}
+             GROUP BY a.assessment_id, a.org_id, s.org_name, s.status
+             ORDER BY a.assessment_id"#
+        );
+
+        QuestionUsageRow::find_by_statement(Statement::from_sql_and_values(
+            backend,
+            sql,
+            [question_id.into()],
+        ))
+        .all(db)
+        .await
+    }
+
+    /// Create or update `questions` by external key, all in one transaction. A key
+    /// that is the ID of a question without external key matches that question.
+    ///
+    /// New keys get a question and a first revision. Existing ones are skipped
+    /// unchanged, or with `DuplicateQuestionPolicy::Update` get a new revision (and
+    /// their new category) when anything differs from their latest revision.
+    pub async fn import_questions(
+        &self,
+        questions: &[QuestionImport],
+        on_duplicate: DuplicateQuestionPolicy,
+    ) -> Result<QuestionImportCounts, DbErr> {
+        use super::questions_revisions;
+
+        let txn = self.db_service.get_connection().begin().await?;
+        let mut counts = QuestionImportCounts::default();
+        let now = Utc::now();
+
+        for import in questions {
+            let mut existing = Entity::find()
+                .filter(Column::ExternalKey.eq(import.external_key.as_str()))
+                .one(&txn)
+                .await?;
+            // Questions without a key are exported under their ID
+            if existing.is_none() {
+                if let Ok(question_id) = Uuid::parse_str(&import.external_key) {
+                    existing = Entity::find_by_id(question_id)
+                        .filter(Column::ExternalKey.is_null())
+                        .one(&txn)
+                        .await?;
+                }
+            }
+
+            let question_id = match existing {
+                None => {
+                    let question = ActiveModel {
+                        question_id: Set(Uuid::new_v4()),
+                        category_id: Set(import.category_id),
+                        created_at: Set(now),
+                        external_key: Set(Some(import.external_key.clone())),
+                        sensitive: Set(false),
+                        skip_rules: Set(Json::Array(Vec::new())),
+                    }
+                    .insert(&txn)
+                    .await?;
+                    counts.created += 1;
+                    question.question_id
+                }
+                Some(_) if on_duplicate == DuplicateQuestionPolicy::Skip => {
+                    counts.skipped += 1;
+                    continue;
+                }
+                Some(question) => {
+                    let latest = questions_revisions::Entity::find()
+                        .filter(questions_revisions::Column::QuestionId.eq(question.question_id))
+                        .order_by_desc(questions_revisions::Column::CreatedAt)
+                        .one(&txn)
+                        .await?;
+                    let unchanged = question.category_id == import.category_id
+                        && latest.is_some_and(|revision| {
+                            revision.text == import.text && revision.weight == import.weight
+                        });
+                    if unchanged {
+                        counts.skipped += 1;
+                        continue;
+                    }
+
+                    if question.category_id != import.category_id {
+                        let mut question: ActiveModel = question.clone().into();
+                        question.category_id = Set(import.category_id);
+                        question.update(&txn).await?;
+                    }
+                    counts.updated += 1;
+                    question.question_id
+                }
+            };
+
+            questions_revisions::ActiveModel {
+                question_revision_id: Set(Uuid::new_v4()),
+                question_id: Set(question_id),
+                text: Set(import.text.clone()),
+                weight: Set(import.weight),
+                created_at: Set(now),
+            }
+            .insert(&txn)
+            .await?;
+        }
+
+        txn.commit().await?;
+        Ok(counts)
+    }
+
     pub async fn get_all_questions(&self) -> Result<Vec<Model>, DbErr> {
         self.db_service.find_all().await
     }
@@ -85,6 +275,8 @@ impl QuestionsService {
         &self,
         id: Uuid,
         category_id: Option<Uuid>,
+        skip_rules: Option<Json>,
+        sensitive: Option<bool>,
     ) -> Result<Model, DbErr> {
         let question = self
             .get_question_by_id(id)
@@ -96,6 +288,12 @@ impl QuestionsService {
         if let Some(category_id) = category_id {
             question.category_id = Set(category_id);
         }
+        if let Some(skip_rules) = skip_rules {
+            question.skip_rules = Set(skip_rules);
+        }
+        if let Some(sensitive) = sensitive {
+            question.sensitive = Set(sensitive);
+        }
 
         self.db_service.update(question).await
     }
@@ -130,6 +328,9 @@ mod tests {
             question_id: Uuid::new_v4(),
             category_id: Uuid::new_v4(),
             created_at: Utc::now(),
+            external_key: None,
+            sensitive: false,
+            skip_rules: Json::Array(Vec::new()),
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -199,4 +400,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_count_by_category() -> Result<(), Box<dyn std::error::Error>> {
+        use sea_orm::{ConnectionTrait, Database, Schema};
+
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        db.execute(backend.build(&Schema::new(backend).create_table_from_entity(Entity)))
+            .await?;
+
+        let questions_service = QuestionsService::new(Arc::new(db));
+        let (environment, social) = (Uuid::new_v4(), Uuid::new_v4());
+        for category_id in [environment, environment, environment, social] {
+            questions_service.create_question(category_id).await?;
+        }
+
+        let counts = questions_service.count_by_category().await?;
+        assert_eq!(counts.len(), 2);
+        assert_eq!(counts[&environment], 3);
+        assert_eq!(counts[&social], 1);
+
+        Ok(())
+    }
 }
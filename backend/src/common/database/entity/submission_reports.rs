@@ -2,7 +2,7 @@ use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
 use crate::impl_database_entity;
 use chrono::{DateTime, Utc};
 use sea_orm::entity::prelude::*;
-use sea_orm::{DeleteResult, Set};
+use sea_orm::{DeleteResult, PaginatorTrait, QueryOrder, QuerySelect, Set, TransactionTrait};
 use serde_json::Value;
 use std::sync::Arc;
 
@@ -99,6 +99,69 @@ impl SubmissionReportsService {
         self.db_service.find_all().await
     }
 
+    pub async fn count_reports(&self) -> Result<u64, DbErr> {
+        Entity::find().count(self.db_service.get_connection()).await
+    }
+
+    /// Reports of an organization's submissions that have the given status
+    pub async fn get_reports_by_org_and_status(
+        &self,
+        org_id: &str,
+        status: &str,
+    ) -> Result<Vec<Model>, DbErr> {
+        Entity::find()
+            .inner_join(super::assessments_submission::Entity)
+            .filter(super::assessments_submission::Column::OrgId.eq(org_id))
+            .filter(Column::Status.eq(status))
+            .all(self.db_service.get_connection())
+            .await
+    }
+
+    /// Every report of an organization's submissions, each with its submission
+    pub async fn get_reports_with_submissions_by_org(
+        &self,
+        org_id: &str,
+    ) -> Result<Vec<(Model, Option<super::assessments_submission::Model>)>, DbErr> {
+        Entity::find()
+            .find_also_related(super::assessments_submission::Entity)
+            .filter(super::assessments_submission::Column::OrgId.eq(org_id))
+            .order_by_asc(Column::GeneratedAt)
+            .all(self.db_service.get_connection())
+            .await
+    }
+
+    /// Reports generated since `since`, each with the submission it was generated from
+    pub async fn get_reports_with_submissions_since(
+        &self,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<(Model, Option<super::assessments_submission::Model>)>, DbErr> {
+        Entity::find()
+            .filter(Column::GeneratedAt.gte(since))
+            .find_also_related(super::assessments_submission::Entity)
+            .all(self.db_service.get_connection())
+            .await
+    }
+
+    /// Reports generated within `from..=to` (either end open when `None`), each with
+    /// the submission it was generated from
+    pub async fn get_reports_with_submissions_between(
+        &self,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<(Model, Option<super::assessments_submission::Model>)>, DbErr> {
+        let mut query = Entity::find();
+        if let Some(from) = from {
+            query = query.filter(Column::GeneratedAt.gte(from));
+        }
+        if let Some(to) = to {
+            query = query.filter(Column::GeneratedAt.lte(to));
+        }
+        query
+            .find_also_related(super::assessments_submission::Entity)
+            .all(self.db_service.get_connection())
+            .await
+    }
+
     pub async fn update_report_status(
         &self,
         id: Uuid,
@@ -119,11 +182,68 @@ impl SubmissionReportsService {
         self.db_service.update(report).await
     }
 
+    /// Applies `update` to a report while holding a lock on its row, so a regeneration
+    /// and a recommendation status change cannot overwrite each other. Nothing is
+    /// written when `update` returns false. Returns `None` if the report does not exist.
+    pub async fn update_report_locked<F>(&self, id: Uuid, update: F) -> Result<Option<Model>, DbErr>
+    where
+        F: FnOnce(&mut Model) -> bool,
+    {
+        let txn = self.db_service.get_connection().begin().await?;
+        let Some(mut report) = Entity::find_by_id(id).lock_exclusive().one(&txn).await? else {
+            return Ok(None);
+        };
+
+        if !update(&mut report) {
+            txn.rollback().await?;
+            return Ok(Some(report));
+        }
+
+        let report = ActiveModel::from(report).reset_all().update(&txn).await?;
+        txn.commit().await?;
+        Ok(Some(report))
+    }
+
     pub async fn delete_report(&self, id: Uuid) -> Result<DeleteResult, DbErr> {
         self.db_service.delete(id).await
     }
 }
 
+/// Copies the status of every recommendation in `previous` onto the recommendation
+/// with the same id in `data`, so progress survives a report being regenerated.
+pub fn carry_over_recommendation_statuses(data: &mut Value, previous: &Value) {
+    let mut statuses = std::collections::HashMap::new();
+    for recommendation in recommendations(previous) {
+        if let (Some(id), Some(status)) = (recommendation.get("id"), recommendation.get("status")) {
+            statuses.insert(id.clone(), status.clone());
+        }
+    }
+
+    let Some(categories) = data.get_mut(0).and_then(Value::as_object_mut) else {
+        return;
+    };
+    for category in categories.values_mut() {
+        let Some(recommendations) = category.get_mut("recommendations").and_then(Value::as_array_mut) else {
+            continue;
+        };
+        for recommendation in recommendations {
+            let status = recommendation.get("id").and_then(|id| statuses.get(id)).cloned();
+            if let (Some(status), Some(recommendation)) = (status, recommendation.as_object_mut()) {
+                recommendation.insert("status".to_string(), status);
+            }
+        }
+    }
+}
+
+fn recommendations(data: &Value) -> impl Iterator<Item = &Value> {
+    data.get(0)
+        .and_then(Value::as_object)
+        .into_iter()
+        .flat_map(|categories| categories.values())
+        .filter_map(|category| category.get("recommendations").and_then(Value::as_array))
+        .flatten()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
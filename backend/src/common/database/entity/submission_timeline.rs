@@ -0,0 +1,134 @@
+use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
+use crate::common::models::claims::Claims;
+use crate::impl_database_entity;
+use chrono::{DateTime, Utc};
+use sea_orm::entity::prelude::*;
+use sea_orm::{ConnectionTrait, QueryOrder, Set};
+use std::sync::Arc;
+
+use super::assessments_submission::SubmissionStatus;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "submission_timeline")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub event_id: Uuid,
+    pub submission_id: Uuid,
+    pub from_status: Option<String>, // None for the initial submission
+    pub to_status: String,           // Same as from_status for comments
+    pub actor_user_id: String,       // Keycloak user id
+    pub actor_role: String,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::assessments_submission::Entity",
+        from = "Column::SubmissionId",
+        to = "super::assessments_submission::Column::SubmissionId"
+    )]
+    AssessmentsSubmission,
+}
+
+impl Related<super::assessments_submission::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::AssessmentsSubmission.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl_database_entity!(Entity, Column::EventId);
+
+/// Who caused a timeline event
+#[derive(Clone, Debug)]
+pub struct TimelineActor {
+    pub user_id: String,
+    pub role: String,
+}
+
+impl TimelineActor {
+    /// The actor's most privileged role is the one recorded
+    pub fn from_claims(claims: &Claims) -> Self {
+        let role = if claims.is_application_admin() {
+            "application_admin"
+        } else if claims.is_organization_admin() {
+            "org_admin"
+        } else if claims.is_org_expert() {
+            "Org_Expert"
+        } else {
+            "Org_User"
+        };
+
+        Self {
+            user_id: claims.sub.clone(),
+            role: role.to_string(),
+        }
+    }
+}
+
+/// Record a status change on `db`, which may be a transaction shared with the change itself
+pub async fn record_transition<C: ConnectionTrait>(
+    db: &C,
+    submission_id: Uuid,
+    from_status: Option<&SubmissionStatus>,
+    to_status: &SubmissionStatus,
+    actor: &TimelineActor,
+    comment: Option<String>,
+) -> Result<Model, DbErr> {
+    ActiveModel {
+        event_id: Set(Uuid::new_v4()),
+        submission_id: Set(submission_id),
+        from_status: Set(from_status.map(|status| status.to_string())),
+        to_status: Set(to_status.to_string()),
+        actor_user_id: Set(actor.user_id.clone()),
+        actor_role: Set(actor.role.clone()),
+        comment: Set(comment),
+        created_at: Set(Utc::now()),
+    }
+    .insert(db)
+    .await
+}
+
+#[derive(Clone)]
+pub struct SubmissionTimelineService {
+    db_service: DatabaseService<Entity>,
+}
+
+impl SubmissionTimelineService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            db_service: DatabaseService::new(db),
+        }
+    }
+
+    /// Events of a submission, oldest first
+    pub async fn get_timeline(&self, submission_id: Uuid) -> Result<Vec<Model>, DbErr> {
+        Entity::find()
+            .filter(Column::SubmissionId.eq(submission_id))
+            .order_by_asc(Column::CreatedAt)
+            .all(self.db_service.get_connection())
+            .await
+    }
+
+    /// Attach a comment to a submission without changing its status
+    pub async fn add_comment(
+        &self,
+        submission_id: Uuid,
+        status: &SubmissionStatus,
+        actor: &TimelineActor,
+        comment: String,
+    ) -> Result<Model, DbErr> {
+        record_transition(
+            self.db_service.get_connection(),
+            submission_id,
+            Some(status),
+            status,
+            actor,
+            Some(comment),
+        )
+        .await
+    }
+}
@@ -2,15 +2,17 @@ use crate::common::entitytrait::{DatabaseEntity, DatabaseService};
 use crate::impl_database_entity;
 use chrono::{DateTime, Utc};
 use sea_orm::entity::prelude::*;
+use sea_orm::sea_query::OnConflict;
 use sea_orm::{DeleteResult, Set};
 use serde_json::Value;
 use std::sync::Arc;
+use thiserror::Error;
 use crate::common::database::entity::assessments_submission::SubmissionStatus;
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "temp_submission")]
 pub struct Model {
-    #[sea_orm(primary_key)]
+    #[sea_orm(primary_key, auto_increment = false)]
     pub temp_id: Uuid, // Also FK to assessments
     pub org_id: String,             // Keycloak organization id
     pub content: Value,              // JSON blob with all answers
@@ -39,6 +41,14 @@ impl ActiveModelBehavior for ActiveModel {}
 
 impl_database_entity!(Entity, Column::TempId);
 
+#[derive(Error, Debug)]
+pub enum SubmitError {
+    #[error("Assessment has already been finalized")]
+    AlreadyFinalized,
+    #[error("Database error: {0}")]
+    Database(#[from] DbErr),
+}
+
 #[allow(dead_code)]
 #[derive(Clone)]
 pub struct TempSubmissionService {
@@ -81,6 +91,56 @@ impl TempSubmissionService {
         Ok(created_submission)
     }
 
+    /// Submit an assessment for review exactly once.
+    ///
+    /// A repeated submit hands back the temp submission that already exists instead of
+    /// creating another one, and an assessment that already has a final submission is
+    /// refused unless that submission was reopened. The insert uses `ON CONFLICT DO NOTHING` so two racing submits cannot
+    /// both create a row.
+    pub async fn submit_for_review(
+        &self,
+        assessment_id: Uuid,
+        org_id: String,
+        content: Value,
+    ) -> Result<(Model, bool), SubmitError> {
+        let db = self.db_service.get_connection();
+
+        if super::assessments_submission::Entity::find_by_id(assessment_id)
+            .one(db)
+            .await?
+            .is_some_and(|submission| !submission.is_reopened())
+        {
+            return Err(SubmitError::AlreadyFinalized);
+        }
+
+        if let Some(existing) = self.get_temp_submission_by_assessment_id(assessment_id).await? {
+            return Ok((existing, false));
+        }
+
+        let submission = ActiveModel {
+            temp_id: Set(assessment_id),
+            org_id: Set(org_id),
+            content: Set(content),
+            submitted_at: Set(Utc::now()),
+            status: Set(SubmissionStatus::UnderReview),
+            reviewed_at: Set(None),
+        };
+
+        let inserted = Entity::insert(submission)
+            .on_conflict(OnConflict::column(Column::TempId).do_nothing().to_owned())
+            .do_nothing()
+            .exec(db)
+            .await?;
+        let created = matches!(inserted, sea_orm::TryInsertResult::Inserted(_));
+
+        let submission = self
+            .get_temp_submission_by_assessment_id(assessment_id)
+            .await?
+            .ok_or(DbErr::Custom("Submission not found".to_string()))?;
+
+        Ok((submission, created))
+    }
+
     pub async fn get_temp_submission_by_assessment_id(
         &self,
         assessment_id: Uuid,
@@ -220,7 +280,9 @@ mod tests {
             submitted_at: chrono::Utc::now(),
             status: SubmissionStatus::UnderReview,
             reviewed_at: None,
-            name: Some("Test Assessment".to_string()),
+            reopened_at: None,
+            reopened_by: None,
+            org_name: "Test Organization".to_string(),
         };
 
         let mock_temp_submission = Model {
@@ -257,19 +319,19 @@ mod tests {
             .into_connection();
 
         // Create services
-        let assessments_service = Arc::new(AssessmentsService::new(Arc::new(assessments_db)));
-        let temp_submission_service = Arc::new(TempSubmissionService::new(Arc::new(temp_submission_db)));
+        let assessments_service = AssessmentsService::new(Arc::new(assessments_db));
+        let _temp_submission_service = TempSubmissionService::new(Arc::new(temp_submission_db));
 
         let submission_service =
             AssessmentsSubmissionService::new(Arc::new(submission_db))
-                .with_assessments_service(assessments_service)
-                .with_temp_submission_service(temp_submission_service);
+                .with_assessments_service(assessments_service);
 
         // Test that submission creation triggers automatic assessment deletion
         let result = submission_service
             .create_submission(
                 assessment_id,
                 "test_user".to_string(),
+                "Test Organization".to_string(),
                 json!({"question1": "answer1"}),
                 Some("Test Assessment".to_string()),
             )
@@ -342,4 +404,87 @@ mod tests {
 
         Ok(())
     }
+
+    async fn submission_test_db() -> Result<DatabaseConnection, DbErr> {
+        use sea_orm::{ConnectionTrait, Database, Schema};
+
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(
+            &schema.create_table_from_entity(super::super::assessments_submission::Entity),
+        ))
+        .await?;
+        db.execute(backend.build(&schema.create_table_from_entity(Entity)))
+            .await?;
+        Ok(db)
+    }
+
+    #[tokio::test]
+    async fn test_submit_for_review_first_submit() -> Result<(), Box<dyn std::error::Error>> {
+        let service = TempSubmissionService::new(Arc::new(submission_test_db().await?));
+        let assessment_id = Uuid::new_v4();
+
+        let (submission, created) = service
+            .submit_for_review(assessment_id, "test_org".to_string(), json!({"responses": []}))
+            .await?;
+
+        assert!(created);
+        assert_eq!(submission.temp_id, assessment_id);
+        assert_eq!(submission.org_id, "test_org");
+        assert_eq!(service.get_all_temp_submissions().await?.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_submit_for_review_duplicate_returns_existing() -> Result<(), Box<dyn std::error::Error>> {
+        let service = TempSubmissionService::new(Arc::new(submission_test_db().await?));
+        let assessment_id = Uuid::new_v4();
+
+        let (first, _) = service
+            .submit_for_review(assessment_id, "test_org".to_string(), json!({"responses": ["a"]}))
+            .await?;
+        let (second, created) = service
+            .submit_for_review(assessment_id, "test_org".to_string(), json!({"responses": ["b"]}))
+            .await?;
+
+        assert!(!created);
+        assert_eq!(second, first);
+        assert_eq!(service.get_all_temp_submissions().await?.len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_submit_for_review_after_finalization_fails() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::assessments_submission::ActiveModel as SubmissionActiveModel;
+
+        let db = submission_test_db().await?;
+        let assessment_id = Uuid::new_v4();
+        SubmissionActiveModel {
+            submission_id: Set(assessment_id),
+            org_id: Set("test_org".to_string()),
+            org_name: Set("Test Organization".to_string()),
+            content: Set(json!({})),
+            submitted_at: Set(Utc::now()),
+            status: Set(SubmissionStatus::Reviewed),
+            reviewed_at: Set(Some(Utc::now())),
+            reopened_at: Set(None),
+            reopened_by: Set(None),
+        }
+        .insert(&db)
+        .await?;
+
+        let service = TempSubmissionService::new(Arc::new(db));
+        let result = service
+            .submit_for_review(assessment_id, "test_org".to_string(), json!({"responses": []}))
+            .await;
+
+        assert!(matches!(result, Err(SubmitError::AlreadyFinalized)));
+        assert!(service.get_all_temp_submissions().await?.is_empty());
+
+        Ok(())
+    }
 }
@@ -1,31 +1,37 @@
-use dotenv::dotenv;
 use sea_orm::{Database, DatabaseConnection, DbErr};
 use sea_orm_migration::prelude::*;
-use std::env;
 use std::sync::Arc;
-use crate::common::migrations::Migrator;
+use crate::common::migrations::{MigrationStatus, Migrator};
 
 /// Initialize the database connection and run migrations
 ///
 /// This function:
-/// 1. Loads environment variables from .env file
-/// 2. Connects to the database using DATABASE_URL
-/// 3. Runs database migrations
-/// 4. Returns the database connection wrapped in Arc
-pub async fn initialize_database() -> Result<Arc<DatabaseConnection>, DbErr> {
-    // Load environment variables from .env file
-    dotenv().ok();
-
-    // Get database URL from environment
-    let database_url = env::var("DATABASE_URL").expect("DATABASE_URL must be set in .env file");
-
+/// 1. Connects to the database at `database_url` (`DATABASE_URL`)
+/// 2. Logs pending migrations and runs them when `auto_run` is set
+/// 3. Returns the database connection wrapped in Arc
+///
+/// Without `auto_run` pending migrations are left for an operator to apply; the API
+/// refuses requests until then, see `web::handlers::migration_gate`.
+pub async fn initialize_database(database_url: &str, auto_run: bool) -> Result<Arc<DatabaseConnection>, DbErr> {
     // Connect to the database
     let conn = Database::connect(database_url).await?;
 
+    let pending = MigrationStatus::check_pending(&conn).await?;
+    for name in &pending {
+        tracing::warn!(migration = %name, "Database migration pending");
+    }
+
     // Run migrations
-    println!("🚀 Running database migrations...");
-    Migrator::up(&conn, None).await?;
-    println!("✅ Database migrations completed successfully");
+    if auto_run {
+        println!("🚀 Running database migrations...");
+        Migrator::up(&conn, None).await?;
+        println!("✅ Database migrations completed successfully");
+    } else if !pending.is_empty() {
+        tracing::warn!(
+            pending = pending.len(),
+            "MIGRATIONS_AUTO_RUN is off, API requests are refused until migrations are applied"
+        );
+    }
 
     // Return the connection wrapped in Arc for thread safety
     Ok(Arc::new(conn))
@@ -1,2 +1,2 @@
-pub(crate) mod entity;
+pub mod entity;
 pub mod init;
@@ -0,0 +1,60 @@
+//! Picking one language out of multilingual texts.
+//!
+//! Question texts and default recommendations are stored as `{"<language>": "<text>"}`
+//! objects. A text is looked up in the requested language, then in each language of
+//! the configured fallback chain (`LANGUAGE_FALLBACK`), and as a last resort in any
+//! language the object has.
+
+use serde_json::Value;
+
+/// Text of `map` in the first available language, or `None` when it has no text at all.
+/// Blank texts count as missing.
+pub fn select_localized_text<'a>(map: &'a Value, requested: &str, fallbacks: &[String]) -> Option<&'a str> {
+    let translations = map.as_object()?;
+    let text = |language: &str| {
+        translations
+            .get(language)
+            .and_then(Value::as_str)
+            .filter(|text| !text.trim().is_empty())
+    };
+
+    std::iter::once(requested)
+        .chain(fallbacks.iter().map(String::as_str))
+        .find_map(text)
+        .or_else(|| translations.keys().find_map(|language| text(language)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn fallbacks() -> Vec<String> {
+        vec!["fr".to_string(), "en".to_string()]
+    }
+
+    #[test]
+    fn test_requested_language_is_preferred() {
+        let text = json!({ "en": "Policy?", "fr": "Politique ?", "de": "Richtlinie?" });
+
+        assert_eq!(select_localized_text(&text, "de", &fallbacks()), Some("Richtlinie?"));
+    }
+
+    #[test]
+    fn test_fallback_chain_is_followed_in_order() {
+        let text = json!({ "en": "Policy?", "fr": "Politique ?" });
+        assert_eq!(select_localized_text(&text, "de", &fallbacks()), Some("Politique ?"));
+
+        let text = json!({ "en": "Policy?", "fr": " " });
+        assert_eq!(select_localized_text(&text, "de", &fallbacks()), Some("Policy?"));
+    }
+
+    #[test]
+    fn test_any_language_is_used_last() {
+        let text = json!({ "es": "¿Política?" });
+
+        assert_eq!(select_localized_text(&text, "de", &fallbacks()), Some("¿Política?"));
+        assert_eq!(select_localized_text(&json!({}), "de", &fallbacks()), None);
+        assert_eq!(select_localized_text(&json!("Policy?"), "de", &fallbacks()), None);
+    }
+}
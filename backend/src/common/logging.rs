@@ -0,0 +1,68 @@
+//! Tracing setup
+//!
+//! `RUST_LOG` selects what is logged and `RUST_LOG_FORMAT` how: `text` (default)
+//! for human-readable lines, `json` for one JSON object per line that ECS and
+//! CloudWatch can ingest without parsing rules.
+
+use tracing::Span;
+use tracing_subscriber::{
+    fmt::{self, MakeWriter},
+    layer::SubscriberExt,
+    util::SubscriberInitExt,
+    EnvFilter, Layer, Registry,
+};
+
+pub const SERVICE_NAME: &str = "dgat-sustainability";
+pub const SERVICE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+impl LogFormat {
+    /// Read `RUST_LOG_FORMAT`, falling back to text for unset or unknown values
+    pub fn from_env() -> Self {
+        match std::env::var("RUST_LOG_FORMAT") {
+            Ok(value) if value.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Text,
+        }
+    }
+}
+
+/// JSON log lines with the event fields flattened next to `timestamp`, `level`,
+/// `target` and the enclosing `span`.
+pub fn json_layer<W>(writer: W) -> impl Layer<Registry>
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
+    fmt::layer()
+        .json()
+        .flatten_event(true)
+        .with_current_span(true)
+        .with_span_list(false)
+        .with_writer(writer)
+}
+
+/// Attach the service name and version to `span` so every event inside it carries them
+pub fn record_service_fields(span: &Span) {
+    span.record("service.name", SERVICE_NAME);
+    span.record("service.version", SERVICE_VERSION);
+}
+
+/// Install the global subscriber. Must be called once, before anything logs.
+pub fn init_tracing() {
+    let filter = EnvFilter::new(std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()));
+
+    let format_layer = match LogFormat::from_env() {
+        LogFormat::Json => json_layer(std::io::stdout).boxed(),
+        LogFormat::Text => fmt::layer().boxed(),
+    };
+
+    tracing_subscriber::registry()
+        .with(format_layer)
+        .with(filter)
+        .init();
+}
@@ -0,0 +1,74 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Notifications::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Notifications::NotificationId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Notifications::UserId).text().not_null())
+                    .col(ColumnDef::new(Notifications::OrgId).text().not_null())
+                    .col(ColumnDef::new(Notifications::Type).text().not_null())
+                    .col(ColumnDef::new(Notifications::Title).text().not_null())
+                    .col(ColumnDef::new(Notifications::Body).text().not_null())
+                    .col(
+                        ColumnDef::new(Notifications::ReadAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(Notifications::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Listing a user's unread notifications is the hot path
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_notifications_user_id_read_at")
+                    .table(Notifications::Table)
+                    .col(Notifications::UserId)
+                    .col(Notifications::ReadAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Notifications::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Notifications {
+    Table,
+    NotificationId,
+    UserId,
+    OrgId,
+    Type,
+    Title,
+    Body,
+    ReadAt,
+    CreatedAt,
+}
@@ -0,0 +1,30 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Files kept in object storage have an empty `content` and point here instead
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("file"))
+                    .add_column(ColumnDef::new(Alias::new("object_key")).text().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("file"))
+                    .drop_column(Alias::new("object_key"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,35 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Recommendation used in reports when reviewers leave a category without one,
+        // keyed by language code, e.g. {"en": "...", "de": "..."}
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("category_catalog"))
+                    .add_column(
+                        ColumnDef::new(Alias::new("default_recommendation"))
+                            .json_binary()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("category_catalog"))
+                    .drop_column(Alias::new("default_recommendation"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
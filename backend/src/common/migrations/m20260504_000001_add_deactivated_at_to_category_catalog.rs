@@ -0,0 +1,35 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Archived categories keep their row so existing assessments and reports
+        // still resolve them; NULL means the category is in use
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("category_catalog"))
+                    .add_column(
+                        ColumnDef::new(Alias::new("deactivated_at"))
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new("category_catalog"))
+                    .drop_column(Alias::new("deactivated_at"))
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,68 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Local copy of Keycloak's organizations, refreshed periodically, so admin
+        // search and counts do not have to list every organization from Keycloak.
+        // Domains are a JSON array of names rather than TEXT[] so the table also
+        // works on SQLite.
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrganizationsCache::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(OrganizationsCache::KeycloakOrgId)
+                            .text()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(OrganizationsCache::Name).text().not_null())
+                    .col(
+                        ColumnDef::new(OrganizationsCache::Domains)
+                            .json_binary()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(OrganizationsCache::SyncedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_organizations_cache_name")
+                    .table(OrganizationsCache::Table)
+                    .col(OrganizationsCache::Name)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(OrganizationsCache::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum OrganizationsCache {
+    Table,
+    KeycloakOrgId,
+    Name,
+    Domains,
+    SyncedAt,
+}
@@ -0,0 +1,86 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // One row per status change of a submission, plus review comments
+        manager
+            .create_table(
+                Table::create()
+                    .table(SubmissionTimeline::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SubmissionTimeline::EventId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SubmissionTimeline::SubmissionId).uuid().not_null())
+                    .col(ColumnDef::new(SubmissionTimeline::FromStatus).text().null())
+                    .col(ColumnDef::new(SubmissionTimeline::ToStatus).text().not_null())
+                    .col(ColumnDef::new(SubmissionTimeline::ActorUserId).text().not_null())
+                    .col(ColumnDef::new(SubmissionTimeline::ActorRole).text().not_null())
+                    .col(ColumnDef::new(SubmissionTimeline::Comment).text().null())
+                    .col(
+                        ColumnDef::new(SubmissionTimeline::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_submission_timeline_assessment_submission")
+                            .from(SubmissionTimeline::Table, SubmissionTimeline::SubmissionId)
+                            .to(
+                                AssessmentsSubmission::Table,
+                                AssessmentsSubmission::SubmissionId,
+                            )
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_submission_timeline_submission_id_created_at")
+                    .table(SubmissionTimeline::Table)
+                    .col(SubmissionTimeline::SubmissionId)
+                    .col(SubmissionTimeline::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SubmissionTimeline::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum SubmissionTimeline {
+    Table,
+    EventId,
+    SubmissionId,
+    FromStatus,
+    ToStatus,
+    ActorUserId,
+    ActorRole,
+    Comment,
+    CreatedAt,
+}
+
+#[derive(Iden)]
+enum AssessmentsSubmission {
+    Table,
+    SubmissionId,
+}
@@ -0,0 +1,62 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Responses to POST requests sent with an Idempotency-Key header, so retries
+        // get the original response instead of creating the resource again.
+        // status_code and response_body stay NULL while the first request runs.
+        manager
+            .create_table(
+                Table::create()
+                    .table(IdempotencyKeys::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(IdempotencyKeys::Key).text().not_null())
+                    .col(ColumnDef::new(IdempotencyKeys::UserId).text().not_null())
+                    .col(ColumnDef::new(IdempotencyKeys::StatusCode).integer().null())
+                    .col(ColumnDef::new(IdempotencyKeys::ResponseBody).binary().null())
+                    .col(
+                        ColumnDef::new(IdempotencyKeys::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(IdempotencyKeys::ExpiresAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .primary_key(
+                        Index::create()
+                            .name("idx_idempotency_keys_key_user_id")
+                            .col(IdempotencyKeys::Key)
+                            .col(IdempotencyKeys::UserId),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(IdempotencyKeys::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum IdempotencyKeys {
+    Table,
+    Key,
+    UserId,
+    StatusCode,
+    ResponseBody,
+    CreatedAt,
+    ExpiresAt,
+}
@@ -0,0 +1,72 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Categories can be grouped under a theme; NULL marks a top-level category.
+        // Deleting a parent turns its children into top-level categories.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CategoryCatalog::Table)
+                    .add_column(
+                        ColumnDef::new(CategoryCatalog::ParentCategoryCatalogId)
+                            .uuid()
+                            .null(),
+                    )
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk_category_catalog_parent")
+                            .from_tbl(CategoryCatalog::Table)
+                            .from_col(CategoryCatalog::ParentCategoryCatalogId)
+                            .to_tbl(CategoryCatalog::Table)
+                            .to_col(CategoryCatalog::CategoryCatalogId)
+                            .on_delete(ForeignKeyAction::SetNull),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_category_catalog_parent_category_catalog_id")
+                    .table(CategoryCatalog::Table)
+                    .col(CategoryCatalog::ParentCategoryCatalogId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_category_catalog_parent_category_catalog_id")
+                    .table(CategoryCatalog::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CategoryCatalog::Table)
+                    .drop_foreign_key(Alias::new("fk_category_catalog_parent"))
+                    .drop_column(CategoryCatalog::ParentCategoryCatalogId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+#[allow(clippy::enum_variant_names)]
+enum CategoryCatalog {
+    Table,
+    CategoryCatalogId,
+    ParentCategoryCatalogId,
+}
@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Reusable assessment setups org admins can start a draft from
+        manager
+            .create_table(
+                Table::create()
+                    .table(AssessmentTemplates::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AssessmentTemplates::TemplateId)
+                            .uuid()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AssessmentTemplates::Name).string().not_null())
+                    .col(ColumnDef::new(AssessmentTemplates::Language).string().not_null())
+                    .col(ColumnDef::new(AssessmentTemplates::CategoryIds).json_binary().not_null())
+                    .col(ColumnDef::new(AssessmentTemplates::QuestionIds).json_binary().not_null())
+                    .col(ColumnDef::new(AssessmentTemplates::CreatedBy).text().not_null())
+                    .col(
+                        ColumnDef::new(AssessmentTemplates::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AssessmentTemplates::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum AssessmentTemplates {
+    Table,
+    TemplateId,
+    Name,
+    Language,
+    CategoryIds,
+    QuestionIds,
+    CreatedBy,
+    CreatedAt,
+}
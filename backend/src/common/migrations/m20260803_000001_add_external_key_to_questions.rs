@@ -0,0 +1,57 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Stable key of questions maintained through imports, NULL for questions
+        // created one by one
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Questions::Table)
+                    .add_column(ColumnDef::new(Questions::ExternalKey).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_questions_external_key")
+                    .table(Questions::Table)
+                    .col(Questions::ExternalKey)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_questions_external_key")
+                    .table(Questions::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Questions::Table)
+                    .drop_column(Questions::ExternalKey)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Questions {
+    Table,
+    ExternalKey,
+}
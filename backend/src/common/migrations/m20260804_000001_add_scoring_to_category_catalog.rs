@@ -0,0 +1,45 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // How a category's report score is computed: "linear" keeps the weighted
+        // average, "threshold" turns it into pass (100) or fail (0) at score_threshold
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CategoryCatalog::Table)
+                    .add_column(
+                        ColumnDef::new(CategoryCatalog::ScoringMode)
+                            .string()
+                            .not_null()
+                            .default("linear"),
+                    )
+                    .add_column(ColumnDef::new(CategoryCatalog::ScoreThreshold).double().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CategoryCatalog::Table)
+                    .drop_column(CategoryCatalog::ScoreThreshold)
+                    .drop_column(CategoryCatalog::ScoringMode)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CategoryCatalog {
+    Table,
+    ScoringMode,
+    ScoreThreshold,
+}
@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Lets the frontend render category cards without hardcoding icons and colors
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CategoryCatalog::Table)
+                    .add_column(ColumnDef::new(CategoryCatalog::IconName).string_len(64).null())
+                    .add_column(ColumnDef::new(CategoryCatalog::ColorHex).char_len(7).null())
+                    .add_column(
+                        ColumnDef::new(CategoryCatalog::Metadata)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'{}'")),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Display defaults for the standard DGRV sustainability categories; values an
+        // admin already set are left alone
+        let db = manager.get_connection();
+        for (name, description, icon_name, color_hex) in [
+            ("Environmental", "Use of energy, water and materials, emissions and waste", "leaf", "#2E7D32"),
+            ("Social", "Working conditions, members, customers and the local community", "users", "#1565C0"),
+            ("Governance", "Management, transparency, ethics and compliance", "landmark", "#6A1B9A"),
+        ] {
+            db.execute_unprepared(&format!(
+                "UPDATE category_catalog
+                    SET description = COALESCE(description, '{description}'),
+                        icon_name = COALESCE(icon_name, '{icon_name}'),
+                        color_hex = COALESCE(color_hex, '{color_hex}')
+                  WHERE name = '{name}'"
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CategoryCatalog::Table)
+                    .drop_column(CategoryCatalog::Metadata)
+                    .drop_column(CategoryCatalog::ColorHex)
+                    .drop_column(CategoryCatalog::IconName)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CategoryCatalog {
+    Table,
+    IconName,
+    ColorHex,
+    Metadata,
+}
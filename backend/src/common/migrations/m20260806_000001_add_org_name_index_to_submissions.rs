@@ -0,0 +1,39 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Admins filter the submission list by organization name and submission date
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_assessments_submission_org_name_submitted_at")
+                    .table(AssessmentsSubmission::Table)
+                    .col(AssessmentsSubmission::OrgName)
+                    .col(AssessmentsSubmission::SubmittedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_assessments_submission_org_name_submitted_at")
+                    .table(AssessmentsSubmission::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AssessmentsSubmission {
+    Table,
+    OrgName,
+    SubmittedAt,
+}
@@ -0,0 +1,42 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Rules hiding or showing a question depending on the answer to another one,
+        // see `common::services::skip_logic`
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Questions::Table)
+                    .add_column(
+                        ColumnDef::new(Questions::SkipRules)
+                            .json_binary()
+                            .not_null()
+                            .default(Expr::cust("'[]'")),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Questions::Table)
+                    .drop_column(Questions::SkipRules)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Questions {
+    Table,
+    SkipRules,
+}
@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // When, and by which user, a reviewed submission was last reopened for corrections
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AssessmentsSubmission::Table)
+                    .add_column(
+                        ColumnDef::new(AssessmentsSubmission::ReopenedAt)
+                            .timestamp_with_time_zone()
+                            .null(),
+                    )
+                    .add_column(ColumnDef::new(AssessmentsSubmission::ReopenedBy).string().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AssessmentsSubmission::Table)
+                    .drop_column(AssessmentsSubmission::ReopenedBy)
+                    .drop_column(AssessmentsSubmission::ReopenedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AssessmentsSubmission {
+    Table,
+    ReopenedAt,
+    ReopenedBy,
+}
@@ -0,0 +1,61 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Answers to sensitive questions are encrypted at rest, see `common::services::encryption`
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Questions::Table)
+                    .add_column(ColumnDef::new(Questions::Sensitive).boolean().not_null().default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Version of the key an answer is encrypted with; NULL for answers stored as written
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AssessmentsResponse::Table)
+                    .add_column(ColumnDef::new(AssessmentsResponse::KeyVersion).small_integer().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AssessmentsResponse::Table)
+                    .drop_column(AssessmentsResponse::KeyVersion)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Questions::Table)
+                    .drop_column(Questions::Sensitive)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Questions {
+    Table,
+    Sensitive,
+}
+
+#[derive(DeriveIden)]
+enum AssessmentsResponse {
+    Table,
+    KeyVersion,
+}
@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Questions an assessment started from a template was set up with
+        manager
+            .create_table(
+                Table::create()
+                    .table(AssessmentQuestions::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(AssessmentQuestions::AssessmentId).uuid().not_null())
+                    .col(ColumnDef::new(AssessmentQuestions::QuestionId).uuid().not_null())
+                    .primary_key(
+                        Index::create()
+                            .col(AssessmentQuestions::AssessmentId)
+                            .col(AssessmentQuestions::QuestionId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_assessment_questions_assessment_id")
+                            .from(AssessmentQuestions::Table, AssessmentQuestions::AssessmentId)
+                            .to(Assessments::Table, Assessments::AssessmentId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_assessment_questions_question_id")
+                            .from(AssessmentQuestions::Table, AssessmentQuestions::QuestionId)
+                            .to(Questions::Table, Questions::QuestionId)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AssessmentQuestions::Table).to_owned())
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum Assessments {
+    Table,
+    AssessmentId,
+}
+
+#[derive(Iden)]
+enum Questions {
+    Table,
+    QuestionId,
+}
+
+#[derive(Iden)]
+enum AssessmentQuestions {
+    Table,
+    AssessmentId,
+    QuestionId,
+}
@@ -1,4 +1,7 @@
 use sea_orm_migration::prelude::*;
+use sea_orm_migration::sea_orm::{ConnectionTrait, EntityTrait, QueryOrder};
+use sea_orm_migration::seaql_migrations;
+use std::collections::HashSet;
 
 mod m20250123_000014_create_categories_table;
 mod m20250124_000016_create_category_catalog_table;
@@ -22,6 +25,23 @@ mod m20250916_000016_add_categories_to_assessments;
 mod m20250917_000017_create_assessment_categories_join_table;
 mod m20251010_082000_refactor_questions_category_link;
 mod m20251104_153200_add_org_name_to_submissions;
+mod m20260501_000001_create_notifications;
+mod m20260502_000001_add_object_key_to_file;
+mod m20260503_000001_add_default_recommendation_to_category_catalog;
+mod m20260504_000001_add_deactivated_at_to_category_catalog;
+mod m20260505_000001_create_organizations_cache;
+mod m20260601_000001_create_submission_timeline;
+mod m20260701_000001_create_idempotency_keys;
+mod m20260801_000001_add_parent_to_category_catalog;
+mod m20260802_000001_create_assessment_templates;
+mod m20260803_000001_add_external_key_to_questions;
+mod m20260804_000001_add_scoring_to_category_catalog;
+mod m20260805_000001_add_display_metadata_to_category_catalog;
+mod m20260806_000001_add_org_name_index_to_submissions;
+mod m20260807_000001_add_skip_rules_to_questions;
+mod m20260808_000001_add_reopened_to_submissions;
+mod m20260809_000001_add_answer_encryption;
+mod m20260810_000001_create_assessment_questions;
 
 pub struct Migrator;
 
@@ -51,6 +71,139 @@ impl MigratorTrait for Migrator {
             Box::new(m20250917_000017_create_assessment_categories_join_table::Migration),
             Box::new(m20251010_082000_refactor_questions_category_link::Migration),
             Box::new(m20251104_153200_add_org_name_to_submissions::Migration),
+            Box::new(m20260501_000001_create_notifications::Migration),
+            Box::new(m20260502_000001_add_object_key_to_file::Migration),
+            Box::new(m20260503_000001_add_default_recommendation_to_category_catalog::Migration),
+            Box::new(m20260504_000001_add_deactivated_at_to_category_catalog::Migration),
+            Box::new(m20260505_000001_create_organizations_cache::Migration),
+            Box::new(m20260601_000001_create_submission_timeline::Migration),
+            Box::new(m20260701_000001_create_idempotency_keys::Migration),
+            Box::new(m20260801_000001_add_parent_to_category_catalog::Migration),
+            Box::new(m20260802_000001_create_assessment_templates::Migration),
+            Box::new(m20260803_000001_add_external_key_to_questions::Migration),
+            Box::new(m20260804_000001_add_scoring_to_category_catalog::Migration),
+            Box::new(m20260805_000001_add_display_metadata_to_category_catalog::Migration),
+            Box::new(m20260806_000001_add_org_name_index_to_submissions::Migration),
+            Box::new(m20260807_000001_add_skip_rules_to_questions::Migration),
+            Box::new(m20260808_000001_add_reopened_to_submissions::Migration),
+            Box::new(m20260809_000001_add_answer_encryption::Migration),
+            Box::new(m20260810_000001_create_assessment_questions::Migration),
         ]
     }
 }
+
+/// A migration recorded in the migration table
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppliedMigration {
+    pub name: String,
+    /// Unix timestamp in seconds
+    pub applied_at: i64,
+}
+
+/// How the database's migration table compares to the migrations compiled in
+#[derive(Debug, Clone, PartialEq)]
+pub struct MigrationStatus {
+    pub applied: Vec<AppliedMigration>,
+    /// Known to this build but not yet run, in the order `Migrator::up` would run them
+    pub pending: Vec<String>,
+    /// Recorded as applied but unknown to this build, e.g. after a rollback of the code
+    pub unknown: Vec<String>,
+}
+
+impl MigrationStatus {
+    /// Names of the migrations known to this build that have not been applied yet
+    pub async fn check_pending<C: ConnectionTrait>(db: &C) -> Result<Vec<String>, DbErr> {
+        Ok(Migrator::migration_status(db).await?.pending)
+    }
+}
+
+impl Migrator {
+    /// Compare the migration table with `Migrator::migrations()`.
+    ///
+    /// Creates the migration table if it does not exist yet, like `Migrator::status` does.
+    pub async fn migration_status<C: ConnectionTrait>(db: &C) -> Result<MigrationStatus, DbErr> {
+        Self::install(db).await?;
+
+        let known: Vec<String> = Self::migrations()
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .collect();
+        let known_set: HashSet<&str> = known.iter().map(String::as_str).collect();
+
+        let recorded = seaql_migrations::Entity::find()
+            .order_by_asc(seaql_migrations::Column::AppliedAt)
+            .order_by_asc(seaql_migrations::Column::Version)
+            .all(db)
+            .await?;
+        let recorded_set: HashSet<&str> = recorded.iter().map(|m| m.version.as_str()).collect();
+
+        let pending = known
+            .iter()
+            .filter(|name| !recorded_set.contains(name.as_str()))
+            .cloned()
+            .collect();
+        let unknown = recorded
+            .iter()
+            .filter(|m| !known_set.contains(m.version.as_str()))
+            .map(|m| m.version.clone())
+            .collect();
+        let applied = recorded
+            .into_iter()
+            .map(|m| AppliedMigration {
+                name: m.version,
+                applied_at: m.applied_at,
+            })
+            .collect();
+
+        Ok(MigrationStatus {
+            applied,
+            pending,
+            unknown,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm_migration::sea_orm::{ActiveModelTrait, Database, Set};
+
+    #[tokio::test]
+    async fn test_migration_status_before_and_after_running() -> Result<(), DbErr> {
+        let db = Database::connect("sqlite::memory:").await?;
+        let names: Vec<String> = Migrator::migrations()
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .collect();
+
+        let status = Migrator::migration_status(&db).await?;
+        assert!(status.applied.is_empty());
+        assert_eq!(status.pending, names);
+        assert!(status.unknown.is_empty());
+
+        // Several migrations use Postgres-only SQL, so record them the way
+        // `Migrator::up` does instead of running them against SQLite
+        for (applied_at, name) in names.iter().enumerate() {
+            seaql_migrations::ActiveModel {
+                version: Set(name.clone()),
+                applied_at: Set(applied_at as i64),
+            }
+            .insert(&db)
+            .await?;
+        }
+        seaql_migrations::ActiveModel {
+            version: Set("m20990101_000001_from_a_newer_build".to_string()),
+            applied_at: Set(names.len() as i64),
+        }
+        .insert(&db)
+        .await?;
+
+        let status = Migrator::migration_status(&db).await?;
+        assert!(status.pending.is_empty());
+        assert_eq!(status.applied.len(), names.len() + 1);
+        assert_eq!(status.applied[0].name, names[0]);
+        assert_eq!(status.unknown, vec!["m20990101_000001_from_a_newer_build".to_string()]);
+
+        Ok(())
+    }
+}
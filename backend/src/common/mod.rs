@@ -1,8 +1,11 @@
+pub mod build_info;
 pub mod cache;
 pub mod config;
 pub mod database;
 mod database_macros;
 mod entitytrait;
+pub mod locale;
+pub mod logging;
 pub mod migrations;
 pub mod models;
 pub mod services;
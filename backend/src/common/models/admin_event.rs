@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Events kept for slow subscribers before the oldest are dropped
+pub const ADMIN_EVENT_CAPACITY: usize = 1000;
+
+/// Something application admins are told about as it happens, see `GET /api/admin/events/stream`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AdminEvent {
+    NewSubmission {
+        submission_id: Uuid,
+        org_id: String,
+        org_name: String,
+    },
+    /// An organization admin took back a submission before its review started
+    SubmissionRecalled {
+        submission_id: Uuid,
+        org_id: String,
+        org_name: String,
+    },
+    UserInvited {
+        user_id: String,
+        email: String,
+        org_id: String,
+    },
+    ReportGenerated {
+        report_id: Uuid,
+        org_id: String,
+    },
+}
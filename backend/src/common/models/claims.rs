@@ -35,10 +35,40 @@ pub struct RealmAccess {
 }
 
 impl Claims {
+    /// Add the roles included by any composite role the token carries.
+    ///
+    /// Keycloak only lists included roles in the token when composites are expanded
+    /// server-side, so `role_map` (composite role -> included roles) fills the gap.
+    /// Expansion is transitive and roles already present are not duplicated. Role names
+    /// are compared ignoring case, like in `has_role`.
+    pub fn expand_composite_roles(mut self, role_map: &HashMap<String, Vec<String>>) -> Self {
+        if let Some(access) = self.realm_access.as_mut() {
+            let mut index = 0;
+            while index < access.roles.len() {
+                let included = role_map
+                    .iter()
+                    .filter(|(composite, _)| composite.eq_ignore_ascii_case(&access.roles[index]))
+                    .flat_map(|(_, included)| included.clone())
+                    .collect::<Vec<_>>();
+                for role in included {
+                    if !access.roles.iter().any(|granted| granted.eq_ignore_ascii_case(&role)) {
+                        access.roles.push(role);
+                    }
+                }
+                index += 1;
+            }
+        }
+        self
+    }
+
+    /// Check a realm role, including roles added by `expand_composite_roles`.
+    ///
+    /// Role names are compared ignoring case: the realm and the composite role
+    /// configuration spell them differently (`Org_User` and `org_user`).
     pub fn has_role(&self, role: &str) -> bool {
         self.realm_access
             .as_ref()
-            .map(|access| access.roles.contains(&role.to_string()))
+            .map(|access| access.roles.iter().any(|granted| granted.eq_ignore_ascii_case(role)))
             .unwrap_or(false)
     }
 
@@ -50,10 +80,16 @@ impl Claims {
         self.has_role("organization_admin") || self.has_role("org_admin")
     }
 
+    /// `organization_id` is matched against both the organization names the token is keyed
+    /// by and the IDs inside them
     pub fn can_manage_organization(&self, organization_id: &str) -> bool {
         self.is_application_admin()
             || (self.is_organization_admin()
-                && self.organizations.as_ref().map(|orgs| orgs.orgs.contains_key(organization_id)).unwrap_or(false))
+                && self.organizations.as_ref().is_some_and(|orgs| {
+                    orgs.orgs
+                        .iter()
+                        .any(|(name, info)| name == organization_id || info.id.as_deref() == Some(organization_id))
+                }))
     }
 
     /// Get the first organization ID (for backward compatibility)
@@ -91,14 +127,29 @@ impl Claims {
         self.has_role("Org_User")
     }
 
+    /// Check if user has Org_Expert role
+    pub fn is_org_expert(&self) -> bool {
+        self.has_role("Org_Expert")
+    }
+
     /// Check if user can create assessments (only org_admin)
     pub fn can_create_assessments(&self) -> bool {
         self.is_org_admin() || self.is_application_admin()
     }
 
-    /// Check if user can answer assessments (Org_User or org_admin)
+    /// Check if user can answer assessments (Org_User, Org_Expert or org_admin)
     pub fn can_answer_assessments(&self) -> bool {
-        self.is_Org_User() || self.is_org_admin() || self.is_application_admin()
+        self.is_Org_User() || self.is_org_expert() || self.is_org_admin() || self.is_application_admin()
+    }
+
+    /// Check if user can submit assessments for review (only org_admin)
+    pub fn can_submit_assessment(&self) -> bool {
+        self.is_org_admin()
+    }
+
+    /// Check if user can view their organization's reports (org_admin or Org_Expert)
+    pub fn can_view_reports(&self) -> bool {
+        self.is_org_admin() || self.is_org_expert()
     }
 
     /// Check if user has a specific role in a specific organization
@@ -111,6 +162,8 @@ impl Claims {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{claims, org_claims};
+    use crate::common::config::CompositeRoles;
     use serde_json::json;
 
     #[test]
@@ -215,4 +268,117 @@ mod tests {
         let org_info = claims.organizations.as_ref().unwrap().orgs.get("another-org").unwrap();
         assert!(org_info.categories.is_empty());
     }
+
+    #[test]
+    fn test_composite_org_admin_grants_org_user() {
+        let test_json = json!({
+            "sub": "admin-456",
+            "realm_access": {
+                "roles": ["org_admin"]
+            },
+            "preferred_username": "orgadmin",
+            "exp": 1234567890_u64,
+            "iat": 1234567890_u64,
+            "aud": "test-audience",
+            "iss": "test-issuer"
+        });
+        let claims: Claims =
+            serde_json::from_value(test_json).expect("Should deserialize successfully");
+        assert!(!claims.has_role("org_user"));
+
+        let claims = claims.expand_composite_roles(&CompositeRoles::default());
+        assert!(claims.has_role("org_admin"));
+        assert!(claims.has_role("org_user"));
+        assert!(claims.is_Org_User());
+        assert!(claims.can_answer_assessments());
+        assert!(!claims.has_role("application_admin"));
+    }
+
+    #[test]
+    fn test_composite_lowercase_org_user_grant_is_recognized() {
+        let role_map = HashMap::from([("reviewer".to_string(), vec!["org_user".to_string()])]);
+
+        let claims = claims("user-1", &["Reviewer"]);
+        assert!(!claims.can_answer_assessments());
+
+        let claims = claims.expand_composite_roles(&role_map);
+        assert!(claims.is_Org_User());
+        assert!(claims.can_answer_assessments());
+    }
+
+    #[test]
+    fn test_has_role_ignores_case() {
+        let claims = claims("user-1", &["ORG_ADMIN", "org_user"]);
+        assert!(claims.is_org_admin());
+        assert!(claims.is_Org_User());
+        assert!(!claims.is_application_admin());
+    }
+
+    #[test]
+    fn test_expand_composite_roles_is_transitive_and_deduplicated() {
+        let role_map = HashMap::from([
+            ("org_admin".to_string(), vec!["org_expert".to_string(), "org_user".to_string()]),
+            ("org_expert".to_string(), vec!["org_user".to_string()]),
+            ("org_user".to_string(), vec!["org_admin".to_string()]),
+        ]);
+        let claims: Claims = serde_json::from_value(json!({
+            "sub": "admin-789",
+            "realm_access": { "roles": ["org_admin"] },
+            "preferred_username": "orgadmin",
+            "exp": 1234567890_u64,
+            "iat": 1234567890_u64,
+            "aud": "test-audience",
+            "iss": "test-issuer"
+        }))
+        .expect("Should deserialize successfully");
+
+        let claims = claims.expand_composite_roles(&role_map);
+        assert_eq!(
+            claims.realm_access.unwrap().roles,
+            vec!["org_admin", "org_expert", "org_user"]
+        );
+    }
+
+    #[test]
+    fn test_is_org_expert() {
+        assert!(claims("user-1", &["Org_Expert"]).is_org_expert());
+        assert!(claims("user-1", &["org_expert"]).is_org_expert());
+        assert!(!claims("user-1", &["Org_User"]).is_org_expert());
+        assert!(!claims("user-1", &["org_admin"]).is_org_expert());
+    }
+
+    #[test]
+    fn test_can_submit_assessment_only_for_org_admin() {
+        assert!(claims("user-1", &["org_admin"]).can_submit_assessment());
+        assert!(!claims("user-1", &["Org_Expert"]).can_submit_assessment());
+        assert!(!claims("user-1", &["Org_User"]).can_submit_assessment());
+        assert!(!claims("user-1", &["application_admin"]).can_submit_assessment());
+    }
+
+    #[test]
+    fn test_can_view_reports() {
+        assert!(claims("user-1", &["org_admin"]).can_view_reports());
+        assert!(claims("user-1", &["Org_Expert"]).can_view_reports());
+        assert!(!claims("user-1", &["Org_User"]).can_view_reports());
+        assert!(!claims("user-1", &[]).can_view_reports());
+    }
+
+    #[test]
+    fn test_org_expert_can_answer_assessments() {
+        let expert = claims("user-1", &["Org_Expert"]);
+        assert!(expert.can_answer_assessments());
+        assert!(!expert.can_create_assessments());
+    }
+
+    #[test]
+    fn test_can_manage_organization_by_name_or_id() {
+        let admin = org_claims("admin-1", &["org_admin"], "Org One", "org-1");
+        assert!(admin.can_manage_organization("Org One"));
+        assert!(admin.can_manage_organization("org-1"));
+        assert!(!admin.can_manage_organization("Org Two"));
+        assert!(!admin.can_manage_organization("org-2"));
+
+        let member = org_claims("user-1", &["Org_User"], "Org One", "org-1");
+        assert!(!member.can_manage_organization("org-1"));
+    }
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KeycloakToken {
@@ -12,13 +13,13 @@ pub struct KeycloakToken {
     pub scope: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct OrganizationDomain {
     pub name: String,
     pub verified: Option<bool>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct KeycloakOrganization {
     pub id: String,
     pub name: String,
@@ -31,7 +32,21 @@ pub struct KeycloakOrganization {
     pub attributes: Option<serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+impl KeycloakOrganization {
+    /// First value of one of the organization's attributes, `None` if it is not set
+    pub fn attribute(&self, key: &str) -> Option<String> {
+        self.attributes
+            .as_ref()
+            .and_then(|attributes| attributes.get(key))
+            .and_then(|values| match values {
+                serde_json::Value::Array(values) => values.first().and_then(|v| v.as_str()).map(str::to_string),
+                serde_json::Value::String(value) => Some(value.clone()),
+                _ => None,
+            })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct KeycloakOrganizationMember {
     pub id: String,
     pub username: String,
@@ -46,7 +61,7 @@ pub struct KeycloakOrganizationMember {
     pub attributes: Option<serde_json::Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct KeycloakInvitation {
     pub id: String,
     pub email: String,
@@ -110,6 +125,7 @@ pub struct KeycloakCredential {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct UserInvitationRequest {
     pub email: String,
     pub first_name: Option<String>,
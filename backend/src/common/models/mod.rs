@@ -1,3 +1,4 @@
+pub mod admin_event;
 pub mod claims;
 pub mod organization;
 pub mod keycloak;
@@ -0,0 +1,181 @@
+//! Emails sent by the backend itself over SMTP.
+//!
+//! Templates hold a subject and a plain-text body per language, picked like any other
+//! multilingual text (see `common::locale`), with `{{name}}` placeholders filled in
+//! from the variables passed to [`EmailTemplate::render`]. Without `SMTP_HOST` there
+//! is no [`EmailService`] and Keycloak's own emails are all users get.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use lettre::message::{header::ContentType, Mailbox};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde_json::{json, Value};
+
+use crate::common::config::EmailConfig;
+use crate::common::locale::select_localized_text;
+
+/// Subject and body as `{"<language>": "<text>"}` objects
+pub struct EmailTemplate {
+    subject: Value,
+    body: Value,
+}
+
+/// A template rendered in one language
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedEmail {
+    pub subject: String,
+    pub body: String,
+}
+
+impl EmailTemplate {
+    /// Sent by `add_org_admin_member` instead of Keycloak's verification email.
+    /// Variables: `first_name`, `organization_name`, `roles`, `app_url`.
+    pub fn invitation() -> Self {
+        Self {
+            subject: json!({
+                "en": "You have been invited to {{organization_name}}",
+                "fr": "Vous avez été invité(e) à rejoindre {{organization_name}}",
+                "de": "Einladung zu {{organization_name}}",
+            }),
+            body: json!({
+                "en": "Hello {{first_name}},\n\n\
+                       you have been added to {{organization_name}} on the DGAT Sustainability Tool as {{roles}}.\n\n\
+                       Please sign in at {{app_url}}. You will be asked to verify your email address when you first sign in.\n",
+                "fr": "Bonjour {{first_name}},\n\n\
+                       vous avez été ajouté(e) à {{organization_name}} sur l'outil DGAT Sustainability en tant que {{roles}}.\n\n\
+                       Veuillez vous connecter sur {{app_url}}. Il vous sera demandé de confirmer votre adresse e-mail lors de votre première connexion.\n",
+                "de": "Hallo {{first_name}},\n\n\
+                       Sie wurden {{organization_name}} im DGAT Sustainability Tool als {{roles}} hinzugefügt.\n\n\
+                       Bitte melden Sie sich unter {{app_url}} an. Bei der ersten Anmeldung werden Sie gebeten, Ihre E-Mail-Adresse zu bestätigen.\n",
+            }),
+        }
+    }
+
+    /// The template in `language` (or the first available of `fallbacks`) with its placeholders filled in
+    pub fn render(&self, language: &str, fallbacks: &[String], variables: &HashMap<&str, String>) -> RenderedEmail {
+        let text = |map: &Value| {
+            select_localized_text(map, language, fallbacks)
+                .map(|template| substitute(template, variables))
+                .unwrap_or_default()
+        };
+        RenderedEmail {
+            subject: text(&self.subject),
+            body: text(&self.body),
+        }
+    }
+}
+
+/// Replaces `{{name}}` with the variable `name`; unknown placeholders are kept as they are
+fn substitute(template: &str, variables: &HashMap<&str, String>) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        let Some(length) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        output.push_str(&rest[..start]);
+        let placeholder = &rest[start..start + 2 + length + 2];
+        match variables.get(rest[start + 2..start + 2 + length].trim()) {
+            Some(value) => output.push_str(value),
+            None => output.push_str(placeholder),
+        }
+        rest = &rest[start + placeholder.len()..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// Sends rendered emails from `EMAIL_FROM` through the configured SMTP relay
+pub struct EmailService {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    pub app_url: String,
+}
+
+impl EmailService {
+    /// `None` when `SMTP_HOST` is not set. Port 465 uses implicit TLS, any other port STARTTLS.
+    pub fn from_config(config: &EmailConfig) -> Result<Option<Self>> {
+        let Some(host) = config.smtp_host.as_deref().filter(|host| !host.trim().is_empty()) else {
+            return Ok(None);
+        };
+
+        let mut builder = if config.smtp_port == 465 {
+            AsyncSmtpTransport::<Tokio1Executor>::relay(host)?
+        } else {
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)?
+        }
+        .port(config.smtp_port);
+        if let (Some(username), Some(password)) = (&config.smtp_username, &config.smtp_password) {
+            builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+        }
+
+        let from = config
+            .from
+            .parse()
+            .map_err(|e| anyhow!("Invalid EMAIL_FROM {:?}: {}", config.from, e))?;
+
+        Ok(Some(Self {
+            transport: builder.build(),
+            from,
+            app_url: config.app_url.clone(),
+        }))
+    }
+
+    pub async fn send(&self, to: &str, email: RenderedEmail) -> Result<()> {
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to.parse().map_err(|e| anyhow!("Invalid recipient {:?}: {}", to, e))?)
+            .subject(email.subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(email.body)?;
+        self.transport.send(message).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variables() -> HashMap<&'static str, String> {
+        HashMap::from([
+            ("first_name", "Ada".to_string()),
+            ("organization_name", "Acme".to_string()),
+            ("roles", "org_user".to_string()),
+            ("app_url", "https://dgat.example.org".to_string()),
+        ])
+    }
+
+    #[test]
+    fn test_invitation_is_rendered_with_variables() {
+        let email = EmailTemplate::invitation().render("fr", &["en".to_string()], &variables());
+
+        assert_eq!(email.subject, "Vous avez été invité(e) à rejoindre Acme");
+        assert!(email.body.starts_with("Bonjour Ada,\n\n"));
+        assert!(email.body.contains("Acme sur l'outil DGAT Sustainability en tant que org_user."));
+        assert!(email.body.contains("vous connecter sur https://dgat.example.org."));
+        assert!(!email.body.contains("{{"));
+    }
+
+    #[test]
+    fn test_unknown_language_uses_fallback() {
+        let email = EmailTemplate::invitation().render("sw", &["de".to_string(), "en".to_string()], &variables());
+
+        assert_eq!(email.subject, "Einladung zu Acme");
+    }
+
+    #[test]
+    fn test_substitute_keeps_unknown_and_unterminated_placeholders() {
+        let variables = HashMap::from([("name", "Ada".to_string())]);
+
+        assert_eq!(substitute("Hi {{ name }}, {{other}}!", &variables), "Hi Ada, {{other}}!");
+        assert_eq!(substitute("{{name}}{{name", &variables), "Ada{{name");
+    }
+
+    #[test]
+    fn test_no_service_without_smtp_host() {
+        assert!(EmailService::from_config(&EmailConfig::default()).unwrap().is_none());
+    }
+}
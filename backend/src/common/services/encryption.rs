@@ -0,0 +1,135 @@
+//! Encryption at rest of answers to sensitive questions.
+//!
+//! Answers are sealed with AES-256-GCM under a random 96-bit nonce and stored as
+//! `base64(nonce || ciphertext)`, next to the `key_version` of the key used. The key
+//! comes from `ENCRYPTION_KEY_BASE64`, see `EncryptionConfig`.
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use anyhow::{anyhow, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+
+use crate::common::config::EncryptionConfig;
+
+/// Version of the key in use, stored with every encrypted answer so that a later
+/// key rotation can tell which key to decrypt with
+pub const KEY_VERSION: u8 = 1;
+
+const NONCE_LEN: usize = 12;
+
+#[derive(Clone)]
+pub struct EncryptionService {
+    key: [u8; 32],
+}
+
+impl std::fmt::Debug for EncryptionService {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EncryptionService").field("key", &"<redacted>").finish()
+    }
+}
+
+impl EncryptionService {
+    pub fn new(key: [u8; 32]) -> Self {
+        Self { key }
+    }
+
+    /// `None` when `ENCRYPTION_KEY_BASE64` is not set
+    pub fn from_config(config: &EncryptionConfig) -> Result<Option<Self>> {
+        let Some(key_base64) = config.key_base64.as_deref().filter(|key| !key.trim().is_empty()) else {
+            return Ok(None);
+        };
+        let key = BASE64
+            .decode(key_base64.trim())
+            .map_err(|e| anyhow!("ENCRYPTION_KEY_BASE64 is not valid base64: {e}"))?;
+        let key: [u8; 32] = key
+            .try_into()
+            .map_err(|key: Vec<u8>| anyhow!("ENCRYPTION_KEY_BASE64 must be 32 bytes, got {}", key.len()))?;
+        Ok(Some(Self::new(key)))
+    }
+
+    /// `nonce || ciphertext`, the ciphertext ending in the authentication tag
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher()
+            .encrypt(&nonce, plaintext)
+            .map_err(|_| anyhow!("Encryption failed"))?;
+
+        let mut sealed = nonce.to_vec();
+        sealed.extend(ciphertext);
+        Ok(sealed)
+    }
+
+    /// Fails when `ciphertext` was not produced by `encrypt` with this key or was modified since
+    pub fn decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        if ciphertext.len() < NONCE_LEN {
+            return Err(anyhow!("Ciphertext is too short"));
+        }
+        let (nonce, ciphertext) = ciphertext.split_at(NONCE_LEN);
+        self.cipher()
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| anyhow!("Decryption failed, the ciphertext or key is wrong"))
+    }
+
+    /// `text` encrypted in its stored form, `base64(nonce || ciphertext)`
+    pub fn encrypt_text(&self, text: &str) -> Result<String> {
+        Ok(BASE64.encode(self.encrypt(text.as_bytes())?))
+    }
+
+    /// The text `encrypt_text` produced `stored` from
+    pub fn decrypt_text(&self, stored: &str) -> Result<String> {
+        let ciphertext = BASE64
+            .decode(stored)
+            .map_err(|e| anyhow!("Encrypted value is not valid base64: {e}"))?;
+        String::from_utf8(self.decrypt(&ciphertext)?).map_err(|e| anyhow!("Decrypted value is not UTF-8: {e}"))
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(&self.key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        let service = EncryptionService::new([7; 32]);
+        let answer = r#"{"yesNo":true,"text":"Revenue of 1.2M EUR"}"#;
+
+        let stored = service.encrypt_text(answer)?;
+        assert!(!stored.contains("Revenue"));
+        assert_ne!(stored, service.encrypt_text(answer)?, "every encryption uses a new nonce");
+        assert_eq!(service.decrypt_text(&stored)?, answer);
+        Ok(())
+    }
+
+    #[test]
+    fn test_tampering_is_detected() -> Result<()> {
+        let service = EncryptionService::new([7; 32]);
+        let mut sealed = service.encrypt(b"Revenue of 1.2M EUR")?;
+
+        let last = sealed.len() - 1;
+        sealed[last] ^= 1;
+        assert!(service.decrypt(&sealed).is_err());
+        assert!(service.decrypt(&sealed[..4]).is_err());
+
+        let stored = service.encrypt_text("Revenue of 1.2M EUR")?;
+        assert!(EncryptionService::new([8; 32]).decrypt_text(&stored).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_key_from_config() {
+        let config = |key: Option<&str>| EncryptionConfig {
+            key_base64: key.map(str::to_string),
+        };
+
+        assert!(EncryptionService::from_config(&config(None)).unwrap().is_none());
+        assert!(EncryptionService::from_config(&config(Some(&BASE64.encode([1; 32])))).unwrap().is_some());
+        assert!(EncryptionService::from_config(&config(Some(&BASE64.encode([1; 16])))).is_err());
+        assert!(EncryptionService::from_config(&config(Some("not base64!"))).is_err());
+    }
+}
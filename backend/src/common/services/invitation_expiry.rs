@@ -0,0 +1,180 @@
+//! Periodic removal of organization invitations nobody accepted.
+//!
+//! Keycloak keeps pending invitations until they are deleted. Every
+//! `invitation_expiry_interval_secs` the backend's service account goes through each
+//! organization's invitations and deletes those older than `invitation_ttl_secs`, or
+//! past their own expiration. With `disable_unverified_users` the invited user is also
+//! disabled when they never verified their email.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use chrono::{DateTime, TimeZone, Utc};
+use tracing::{info, warn};
+
+use crate::common::config::SyncConfig;
+use crate::common::models::keycloak::{CreateUserRequest, KeycloakInvitation};
+use crate::common::services::keycloak_service::KeycloakService;
+
+/// Whether `invitation` has expired at `now`. Invitations whose dates cannot be read are kept.
+pub fn is_invitation_expired(invitation: &KeycloakInvitation, now: DateTime<Utc>, ttl: chrono::Duration) -> bool {
+    let past_expiration = invitation
+        .expiration
+        .as_deref()
+        .and_then(parse_timestamp)
+        .is_some_and(|expiration| expiration <= now);
+    let past_ttl = parse_timestamp(&invitation.invited_at).is_some_and(|invited_at| invited_at + ttl <= now);
+    past_expiration || past_ttl
+}
+
+/// RFC 3339, or milliseconds since the epoch as Keycloak sends them
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|timestamp| timestamp.with_timezone(&Utc))
+        .ok()
+        .or_else(|| value.parse::<i64>().ok().and_then(|millis| Utc.timestamp_millis_opt(millis).single()))
+}
+
+/// Delete the expired invitations of every organization, returning how many were deleted
+pub async fn expire_invitations(keycloak: &KeycloakService, token: &str, config: &SyncConfig) -> Result<usize> {
+    let ttl = chrono::Duration::seconds(config.invitation_ttl_secs as i64);
+    let now = Utc::now();
+    let mut expired = 0;
+
+    for organization in keycloak.get_organizations(token).await? {
+        let invitations = match keycloak.get_invitations(token, &organization.id).await {
+            Ok(invitations) => invitations,
+            Err(e) => {
+                warn!(org_id = %organization.id, error = %e, "Failed to list invitations");
+                continue;
+            }
+        };
+
+        for invitation in invitations.iter().filter(|invitation| is_invitation_expired(invitation, now, ttl)) {
+            if let Err(e) = keycloak.delete_invitation(token, &organization.id, &invitation.id).await {
+                warn!(org_id = %organization.id, invitation_id = %invitation.id, error = %e, "Failed to delete expired invitation");
+                continue;
+            }
+            info!(
+                org_id = %organization.id,
+                invitation_id = %invitation.id,
+                email = %invitation.email,
+                invited_at = %invitation.invited_at,
+                "Expired organization invitation"
+            );
+            expired += 1;
+
+            if config.disable_unverified_users {
+                disable_if_unverified(keycloak, token, &invitation.email).await;
+            }
+        }
+    }
+
+    Ok(expired)
+}
+
+async fn disable_if_unverified(keycloak: &KeycloakService, token: &str, email: &str) {
+    let user = match keycloak.find_user_by_username_or_email(token, email).await {
+        Ok(Some(user)) if !user.email_verified && user.enabled => user,
+        Ok(_) => return,
+        Err(e) => {
+            warn!(email = %email, error = %e, "Failed to look up invited user");
+            return;
+        }
+    };
+
+    let request = CreateUserRequest {
+        username: user.username.clone(),
+        email: user.email.clone(),
+        first_name: None,
+        last_name: None,
+        email_verified: None,
+        enabled: Some(false),
+        attributes: None,
+        credentials: None,
+        required_actions: None,
+    };
+    match keycloak.update_user_attributes(token, &user.id, &request).await {
+        Ok(()) => info!(user_id = %user.id, email = %email, "Disabled user who never verified their email"),
+        Err(e) => warn!(user_id = %user.id, error = %e, "Failed to disable unverified user"),
+    }
+}
+
+/// Expire invitations every `invitation_expiry_interval_secs`, starting right away.
+///
+/// Does nothing when the interval is 0 or no client secret is configured.
+pub fn spawn_invitation_expiry(keycloak: Arc<KeycloakService>, config: &SyncConfig) {
+    let Some(client_secret) = config.keycloak_client_secret.clone() else {
+        warn!("KEYCLOAK_CLIENT_SECRET is not set, stale invitations are not expired");
+        return;
+    };
+    if config.invitation_expiry_interval_secs == 0 {
+        return;
+    }
+    let period = Duration::from_secs(config.invitation_expiry_interval_secs);
+    let config = config.clone();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            let expired = match keycloak.service_account_token(&client_secret).await {
+                Ok(token) => expire_invitations(&keycloak, &token, &config).await,
+                Err(e) => Err(e),
+            };
+            match expired {
+                Ok(count) => info!(invitations = count, "Expired stale invitations"),
+                Err(e) => warn!(error = %e, "Failed to expire stale invitations"),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invitation(invited_at: &str, expiration: Option<&str>) -> KeycloakInvitation {
+        KeycloakInvitation {
+            id: "invitation-1".to_string(),
+            email: "ada@example.org".to_string(),
+            invited_at: invited_at.to_string(),
+            expiration: expiration.map(str::to_string),
+            roles: vec![],
+        }
+    }
+
+    #[test]
+    fn test_invitations_expire_after_ttl() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap();
+        let ttl = chrono::Duration::days(7);
+
+        assert!(!is_invitation_expired(&invitation("2026-10-16T12:00:00Z", None), now, ttl));
+        assert!(!is_invitation_expired(&invitation("2026-10-10T12:00:01+00:00", None), now, ttl));
+        assert!(is_invitation_expired(&invitation("2026-10-10T12:00:00Z", None), now, ttl));
+        assert!(is_invitation_expired(&invitation("2026-09-01T08:30:00+02:00", None), now, ttl));
+        // Milliseconds since the epoch
+        let day_old = (now - chrono::Duration::days(1)).timestamp_millis().to_string();
+        let month_old = (now - chrono::Duration::days(30)).timestamp_millis().to_string();
+        assert!(!is_invitation_expired(&invitation(&day_old, None), now, ttl));
+        assert!(is_invitation_expired(&invitation(&month_old, None), now, ttl));
+    }
+
+    #[test]
+    fn test_invitations_expire_at_their_own_expiration() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap();
+        let ttl = chrono::Duration::days(7);
+
+        assert!(is_invitation_expired(&invitation("2026-10-16T12:00:00Z", Some("2026-10-17T00:00:00Z")), now, ttl));
+        assert!(!is_invitation_expired(&invitation("2026-10-16T12:00:00Z", Some("2026-10-20T00:00:00Z")), now, ttl));
+    }
+
+    #[test]
+    fn test_unreadable_invitations_are_kept() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 17, 12, 0, 0).unwrap();
+
+        assert!(!is_invitation_expired(&invitation("yesterday", Some("")), now, chrono::Duration::days(7)));
+    }
+}
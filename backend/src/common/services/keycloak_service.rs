@@ -5,16 +5,49 @@ use reqwest::{Client, StatusCode};
 use serde_json::json;
 use tracing::{debug, error, info, warn};
 use serde::Deserialize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
 
 #[derive(Deserialize)]
 struct TokenResponse {
     access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+/// Service account tokens are renewed this long before Keycloak says they expire
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(30);
+
+/// User profiles fetched at the same time when filtering members client-side
+const USER_LOOKUP_CONCURRENCY: usize = 10;
+
+/// How long a user's organizations are reused, see `get_user_organizations_direct`
+const USER_ORGANIZATIONS_TTL: Duration = Duration::from_secs(30);
+
+/// Whether `error` is Keycloak answering with `status`
+fn has_status(error: &anyhow::Error, status: StatusCode) -> bool {
+    error.downcast_ref::<reqwest::Error>().and_then(reqwest::Error::status) == Some(status)
+}
+
+/// Whether `error` is Keycloak answering 404 Not Found
+pub fn is_not_found(error: &anyhow::Error) -> bool {
+    has_status(error, StatusCode::NOT_FOUND)
+}
+
+/// Whether `error` is Keycloak answering 409 Conflict
+pub fn is_conflict(error: &anyhow::Error) -> bool {
+    has_status(error, StatusCode::CONFLICT)
 }
 
 #[derive(Debug, Clone)]
 pub struct KeycloakService {
     client: Client,
     config: KeycloakConfigs,
+    /// Service account token and when it stops being used, shared by all clones
+    service_token: Arc<RwLock<Option<(String, Instant)>>>,
+    /// Organizations by user ID and hash of the token they were read with
+    user_organizations: moka::sync::Cache<(String, blake3::Hash), Vec<KeycloakOrganization>>,
 }
 
 impl KeycloakService {
@@ -23,7 +56,73 @@ impl KeycloakService {
             .danger_accept_invalid_certs(true)
             .build().expect("Failed to create reqwest client");
 
-        Self { client, config }
+        Self {
+            client,
+            config,
+            service_token: Arc::new(RwLock::new(None)),
+            user_organizations: moka::sync::Cache::builder()
+                .time_to_live(USER_ORGANIZATIONS_TTL)
+                .max_capacity(10_000)
+                .build(),
+        }
+    }
+
+    pub fn config(&self) -> &KeycloakConfigs {
+        &self.config
+    }
+
+    /// Check that the realm's OpenID discovery document can be fetched within `timeout`
+    pub async fn check_connectivity(&self, timeout: std::time::Duration) -> Result<()> {
+        let url = format!(
+            "{}/realms/{}/.well-known/openid-configuration",
+            self.config.url, self.config.realm
+        );
+
+        let response = self.client.get(&url).timeout(timeout).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow!("Keycloak returned {}", response.status()));
+        }
+        Ok(())
+    }
+
+    /// Access token for the backend's own service account, via the client credentials grant.
+    ///
+    /// The token is cached until shortly before it expires. Concurrent callers that find
+    /// no valid token wait for a single request to Keycloak instead of each sending one.
+    pub async fn service_account_token(&self, client_secret: &str) -> Result<String> {
+        if let Some(token) = Self::valid_token(&*self.service_token.read().await) {
+            return Ok(token);
+        }
+
+        let mut cached = self.service_token.write().await;
+        // Another caller may have fetched a token while we waited for the write lock
+        if let Some(token) = Self::valid_token(&cached) {
+            return Ok(token);
+        }
+
+        let response = self.client.post(self.config.token_url())
+            .form(&[
+                ("grant_type", "client_credentials"),
+                ("client_id", self.config.client_id.as_str()),
+                ("client_secret", client_secret),
+            ])
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<TokenResponse>()
+            .await?;
+
+        let lifetime = Duration::from_secs(response.expires_in.unwrap_or(60));
+        let expires_at = Instant::now() + lifetime.saturating_sub(TOKEN_EXPIRY_MARGIN);
+        *cached = Some((response.access_token.clone(), expires_at));
+        Ok(response.access_token)
+    }
+
+    fn valid_token(cached: &Option<(String, Instant)>) -> Option<String> {
+        cached
+            .as_ref()
+            .filter(|(_, expires_at)| Instant::now() < *expires_at)
+            .map(|(token, _)| token.clone())
     }
 
     /// Create a new organization
@@ -79,9 +178,14 @@ impl KeycloakService {
                 }
             },
             _ => {
+                let status_error = response.error_for_status_ref().err();
                 let error_text = response.text().await?;
                 error!("Failed to create organization: {}", error_text);
-                Err(anyhow!("Failed to create organization: {}", error_text))
+                let message = format!("Failed to create organization: {error_text}");
+                Err(match status_error {
+                    Some(status_error) => anyhow::Error::new(status_error).context(message),
+                    None => anyhow!(message),
+                })
             }
         }
     }
@@ -104,6 +208,20 @@ impl KeycloakService {
         Ok(orgs)
     }
 
+    /// Number of users in the realm, counted by Keycloak
+    pub async fn count_users(&self, token: &str) -> Result<u64> {
+        let url = format!("{}/admin/realms/{}/users/count", self.config.url, self.config.realm);
+
+        let count = self.client.get(&url)
+            .bearer_auth(token)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(count)
+    }
+
     /// Get a specific organization by ID
     pub async fn get_organization(&self, token: &str, org_id: &str) -> Result<KeycloakOrganization> {
         let url = format!("{}/admin/realms/{}/organizations/{}?briefRepresentation=false", self.config.url, self.config.realm, org_id);
@@ -122,6 +240,13 @@ impl KeycloakService {
         Ok(org)
     }
 
+    /// First value of one of an organization's attributes, `None` if it is not set
+    pub async fn get_org_attribute(&self, token: &str, org_id: &str, key: &str) -> Result<Option<String>> {
+        let org = self.get_organization(token, org_id).await?;
+
+        Ok(org.attribute(key))
+    }
+
     /// Update an organization
     pub async fn update_organization(&self,
                                      token: &str,
@@ -190,6 +315,115 @@ impl KeycloakService {
         Ok(members)
     }
 
+    /// Organizations `user_id` is a member of, in one request to Keycloak's
+    /// `users/{user_id}/orgs` endpoint.
+    ///
+    /// Keycloak versions without that endpoint answer 404, in which case every
+    /// organization's members are listed instead. Results are cached for 30 seconds per
+    /// user and token, so a token only ever sees what it was allowed to read, and
+    /// dropped as soon as the user is added to or removed from an organization.
+    pub async fn get_user_organizations_direct(&self, token: &str, user_id: &str) -> Result<Vec<KeycloakOrganization>> {
+        let key = (user_id.to_string(), blake3::hash(token.as_bytes()));
+        if let Some(organizations) = self.user_organizations.get(&key) {
+            return Ok(organizations);
+        }
+
+        let url = format!("{}/admin/realms/{}/users/{}/orgs", self.config.url, self.config.realm, user_id);
+        let response = self.client.get(&url)
+            .bearer_auth(token)
+            .send()
+            .await?;
+
+        let organizations = if response.status() == StatusCode::NOT_FOUND {
+            debug!("Keycloak has no users/{{id}}/orgs endpoint, scanning organization members");
+            self.scan_user_organizations(token, user_id).await?
+        } else {
+            response.error_for_status()?.json().await?
+        };
+
+        self.user_organizations.insert(key, organizations.clone());
+        Ok(organizations)
+    }
+
+    /// Drop the cached organizations of `user_id` under every token, so a membership
+    /// change is seen right away instead of after `USER_ORGANIZATIONS_TTL`
+    pub fn forget_user_organizations(&self, user_id: &str) {
+        for (key, _) in self.user_organizations.iter() {
+            if key.0 == user_id {
+                self.user_organizations.invalidate(&*key);
+            }
+        }
+    }
+
+    /// Organizations whose member list contains `user_id`, one request per organization.
+    /// Organizations whose members cannot be read are skipped.
+    async fn scan_user_organizations(&self, token: &str, user_id: &str) -> Result<Vec<KeycloakOrganization>> {
+        let mut member_organizations = Vec::new();
+        for organization in self.get_organizations(token).await? {
+            match self.get_organization_members(token, &organization.id).await {
+                Ok(members) if members.iter().any(|member| member.id == user_id) => {
+                    member_organizations.push(organization);
+                }
+                Ok(_) => {}
+                Err(e) => debug!(org_id = %organization.id, error = %e, "Skipping organization"),
+            }
+        }
+        Ok(member_organizations)
+    }
+
+    /// One page of an organization's members, paged and searched by Keycloak itself.
+    ///
+    /// `search` matches username, email, first or last name; `exact` makes it an
+    /// equality instead of a substring match.
+    pub async fn get_organization_members_page(
+        &self,
+        token: &str,
+        org_id: &str,
+        first: u32,
+        max: u32,
+        search: Option<&str>,
+        exact: bool,
+    ) -> Result<Vec<KeycloakOrganizationMember>> {
+        let url = format!("{}/admin/realms/{}/organizations/{}/members", self.config.url, self.config.realm, org_id);
+
+        let mut query = vec![("first", first.to_string()), ("max", max.to_string())];
+        if let Some(search) = search {
+            query.push(("search", search.to_string()));
+            query.push(("exact", exact.to_string()));
+        }
+
+        let response = self.client.get(&url)
+            .bearer_auth(token)
+            .query(&query)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        let members: Vec<KeycloakOrganizationMember> = response.json().await?;
+        Ok(members)
+    }
+
+    /// Check whether a user is already a member of an organization
+    pub async fn is_user_in_organization(&self, token: &str, org_id: &str, user_id: &str) -> Result<bool> {
+        let url = format!("{}/admin/realms/{}/organizations/{}/members/{}",
+                          self.config.url, self.config.realm, org_id, user_id);
+
+        let response = self.client.get(&url)
+            .bearer_auth(token)
+            .send()
+            .await?;
+
+        match response.status() {
+            StatusCode::OK => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            _ => {
+                let error_text = response.text().await?;
+                error!("Failed to check organization membership: {}", error_text);
+                Err(anyhow!("Failed to check organization membership: {}", error_text))
+            }
+        }
+    }
+
     /// Find a user by username or email
     pub async fn find_user_by_username_or_email(&self, token: &str, query: &str) -> Result<Option<KeycloakUser>> {
         let url = format!("{}/admin/realms/{}/users?search={}", self.config.url, self.config.realm, query);
@@ -380,7 +614,10 @@ impl KeycloakService {
             .send()
             .await?;
         match response.status() {
-            StatusCode::CREATED | StatusCode::NO_CONTENT => Ok(()),
+            StatusCode::CREATED | StatusCode::NO_CONTENT => {
+                self.forget_user_organizations(&payload);
+                Ok(())
+            },
             _ => {
                 let error_text = response.text().await?;
                 error!("Failed to add user to organization: {}", error_text);
@@ -402,11 +639,13 @@ impl KeycloakService {
         match response.status() {
             StatusCode::NO_CONTENT => {
                 info!("Successfully removed user {} from organization {}", membership_id, org_id);
+                self.forget_user_organizations(membership_id);
                 Ok(())
             },
             StatusCode::NOT_FOUND => {
                 // Member doesn't exist or was already removed - consider this a success (idempotent)
                 info!("User {} was not found in organization {} (may have been already removed)", membership_id, org_id);
+                self.forget_user_organizations(membership_id);
                 Ok(())
             },
             _ => {
@@ -501,6 +740,35 @@ impl KeycloakService {
         Ok(invitations)
     }
 
+    /// Get one invitation of an organization, `None` when it does not exist.
+    /// Keycloak versions without the single-invitation endpoint answer 404 or 405,
+    /// then the invitation is looked up in the full list.
+    pub async fn get_invitation(&self, token: &str, org_id: &str, invitation_id: &str) -> Result<Option<KeycloakInvitation>> {
+        let url = format!("{}/admin/realms/{}/organizations/{}/members/invitations/{}",
+                          self.config.url, self.config.realm, org_id, invitation_id);
+
+        let response = self.client.get(&url)
+            .bearer_auth(token)
+            .send()
+            .await?;
+
+        match response.status() {
+            StatusCode::OK => Ok(Some(response.json().await?)),
+            StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED => {
+                debug!(org_id = %org_id, invitation_id = %invitation_id, "Looking up invitation in the invitation list");
+                Ok(self.get_invitations(token, org_id)
+                    .await?
+                    .into_iter()
+                    .find(|invitation| invitation.id == invitation_id))
+            },
+            _ => {
+                let error_text = response.text().await?;
+                error!("Failed to get invitation: {}", error_text);
+                Err(anyhow!("Failed to get invitation: {}", error_text))
+            }
+        }
+    }
+
     /// Delete an invitation
     pub async fn delete_invitation(&self, token: &str, org_id: &str, invitation_id: &str) -> Result<()> {
         let url = format!("{}/admin/realms/{}/organizations/{}/members/invitations/{}",
@@ -582,6 +850,22 @@ impl KeycloakService {
 
     /// Create a new user with email verification required
     pub async fn create_user_with_email_verification(&self, token: &str, request: &CreateUserRequest) -> Result<KeycloakUser> {
+        self.create_user_requiring_verification(token, request, true).await
+    }
+
+    /// Create a new user with email verification required, without Keycloak's
+    /// verification email: for callers sending their own invitation email, the user
+    /// is asked to verify their address when they first sign in
+    pub async fn create_user_without_verification_email(&self, token: &str, request: &CreateUserRequest) -> Result<KeycloakUser> {
+        self.create_user_requiring_verification(token, request, false).await
+    }
+
+    async fn create_user_requiring_verification(
+        &self,
+        token: &str,
+        request: &CreateUserRequest,
+        send_verification_email: bool,
+    ) -> Result<KeycloakUser> {
         let url = format!("{}/admin/realms/{}/users", self.config.url, self.config.realm);
         
         // Generate a temporary password
@@ -634,13 +918,15 @@ impl KeycloakService {
                 let user = self.get_user_by_id(token, user_id).await?;
                 
                 // Try to trigger email verification email, but don't fail if it doesn't work
-                match self.trigger_email_verification(token, user_id).await {
-                    Ok(_) => {
-                        info!(user_id = %user_id, "Email verification email sent successfully");
-                    },
-                    Err(e) => {
-                        warn!(user_id = %user_id, error = %e, "Failed to send email verification email, but user was created successfully");
-                        // Don't fail the entire operation, just log the warning
+                if send_verification_email {
+                    match self.trigger_email_verification(token, user_id).await {
+                        Ok(_) => {
+                            info!(user_id = %user_id, "Email verification email sent successfully");
+                        },
+                        Err(e) => {
+                            warn!(user_id = %user_id, error = %e, "Failed to send email verification email, but user was created successfully");
+                            // Don't fail the entire operation, just log the warning
+                        }
                     }
                 }
                 
@@ -673,13 +959,38 @@ impl KeycloakService {
                 Ok(user)
             },
             _ => {
+                let status_error = response.error_for_status_ref().err();
                 let error_text = response.text().await?;
                 error!("Failed to get user by ID: {}", error_text);
-                Err(anyhow!("Failed to get user by ID: {}", error_text))
+                let message = format!("Failed to get user by ID: {error_text}");
+                Err(match status_error {
+                    Some(status_error) => anyhow::Error::new(status_error).context(message),
+                    None => anyhow!(message),
+                })
             }
         }
     }
 
+    /// Get the categories assigned to a user through the `categories` profile attribute
+    pub async fn get_user_assigned_categories(&self, token: &str, user_id: &str) -> Result<Vec<String>> {
+        let user = self.get_user_by_id(token, user_id).await?;
+
+        let categories = user
+            .attributes
+            .as_ref()
+            .and_then(|attributes| attributes.get("categories"))
+            .and_then(|categories| categories.as_array())
+            .map(|categories| {
+                categories
+                    .iter()
+                    .filter_map(|c| c.as_str().map(|s| s.to_string()))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(categories)
+    }
+
     /// Check if user's email is verified
     pub async fn is_user_email_verified(&self, token: &str, user_id: &str) -> Result<bool> {
         let user = self.get_user_by_id(token, user_id).await?;
@@ -1007,6 +1318,39 @@ impl KeycloakService {
         Ok(filtered_members)
     }
 
+    /// Organization members whose `categories` attribute contains `category_name`.
+    ///
+    /// Keycloak cannot search members by attribute, so every member's profile is
+    /// fetched, a few at a time, and filtered here. Members whose profile cannot be
+    /// read are logged and left out.
+    pub async fn find_users_by_category(
+        &self,
+        token: &str,
+        org_id: &str,
+        category_name: &str,
+    ) -> Result<Vec<KeycloakOrganizationMember>> {
+        use futures::StreamExt;
+
+        let all_members = self.get_organization_members(token, org_id).await?;
+
+        let members = futures::stream::iter(all_members)
+            .map(|member| async move {
+                match self.get_user_assigned_categories(token, &member.id).await {
+                    Ok(categories) => categories.iter().any(|category| category == category_name).then_some(member),
+                    Err(e) => {
+                        error!("Failed to get categories for user {}: {}", member.id, e);
+                        None
+                    }
+                }
+            })
+            .buffered(USER_LOOKUP_CONCURRENCY)
+            .filter_map(|member| async move { member })
+            .collect()
+            .await;
+
+        Ok(members)
+    }
+
     /// Delete a user entirely from Keycloak
     pub async fn delete_user(&self, token: &str, user_id: &str) -> Result<()> {
         let url = format!("{}/admin/realms/{}/users/{}", self.config.url, self.config.realm, user_id);
@@ -1035,3 +1379,158 @@ impl KeycloakService {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{keycloak_config, serve};
+    use axum::{routing::post, Json, Router};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Keycloak token endpoint that counts requests and answers slowly enough for
+    /// concurrent callers to overlap
+    async fn fake_token_endpoint(requests: Arc<AtomicUsize>, expires_in: u64) -> String {
+        let app = Router::new().route(
+            "/realms/test-realm/protocol/openid-connect/token",
+            post(move || async move {
+                let n = requests.fetch_add(1, Ordering::SeqCst) + 1;
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Json(json!({ "access_token": format!("token-{n}"), "expires_in": expires_in }))
+            }),
+        );
+
+        serve(app).await
+    }
+
+    fn service(url: String) -> KeycloakService {
+        KeycloakService::new(keycloak_config(url))
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_callers_share_one_token_request() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let keycloak = service(fake_token_endpoint(requests.clone(), 300).await);
+
+        let tasks: Vec<_> = (0..10)
+            .map(|_| {
+                let keycloak = keycloak.clone();
+                tokio::spawn(async move { keycloak.service_account_token("secret").await.unwrap() })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap(), "token-1");
+        }
+
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_expiring_token_is_renewed() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        // Shorter than the expiry margin, so the token is never reused
+        let keycloak = service(fake_token_endpoint(requests.clone(), 10).await);
+
+        assert_eq!(keycloak.service_account_token("secret").await.unwrap(), "token-1");
+        assert_eq!(keycloak.service_account_token("secret").await.unwrap(), "token-2");
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    fn organization(id: &str) -> serde_json::Value {
+        json!({ "id": id, "name": id, "enabled": true })
+    }
+
+    /// Keycloak with three organizations, `ada` being a member of `org-2`, counting
+    /// every request. Without `native` the `users/{id}/orgs` endpoint answers 404.
+    async fn fake_organizations_keycloak(requests: Arc<AtomicUsize>, native: bool) -> String {
+        use axum::{extract::Path, http::StatusCode, response::IntoResponse, routing::get};
+
+        let count = move |requests: &Arc<AtomicUsize>| {
+            requests.fetch_add(1, Ordering::SeqCst);
+        };
+        let (orgs_requests, list_requests, member_requests) = (requests.clone(), requests.clone(), requests);
+        let app = Router::new()
+            .route(
+                "/admin/realms/test-realm/users/:user_id/orgs",
+                get(move |Path(user_id): Path<String>| async move {
+                    count(&orgs_requests);
+                    if !native {
+                        return StatusCode::NOT_FOUND.into_response();
+                    }
+                    let organizations = if user_id == "ada" { vec![organization("org-2")] } else { vec![] };
+                    Json(organizations).into_response()
+                }),
+            )
+            .route(
+                "/admin/realms/test-realm/organizations",
+                get(move || async move {
+                    count(&list_requests);
+                    Json(vec![organization("org-1"), organization("org-2"), organization("org-3")])
+                }),
+            )
+            .route(
+                "/admin/realms/test-realm/organizations/:org_id/members",
+                get(move |Path(org_id): Path<String>| async move {
+                    count(&member_requests);
+                    let members = if org_id == "org-2" {
+                        vec![json!({ "id": "ada", "username": "ada", "email": "ada@example.org" })]
+                    } else {
+                        vec![]
+                    };
+                    Json(members)
+                }),
+            )
+            .route(
+                "/admin/realms/test-realm/organizations/:org_id/members/:member_id",
+                axum::routing::delete(|| async { StatusCode::NO_CONTENT }),
+            );
+
+        serve(app).await
+    }
+
+    #[tokio::test]
+    async fn test_user_organizations_take_one_request_and_are_cached() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let keycloak = service(fake_organizations_keycloak(requests.clone(), true).await);
+
+        let organizations = keycloak.get_user_organizations_direct("token", "ada").await.unwrap();
+        assert_eq!(organizations.iter().map(|o| o.id.as_str()).collect::<Vec<_>>(), ["org-2"]);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        keycloak.get_user_organizations_direct("token", "ada").await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // Another token does not see what this one read
+        keycloak.get_user_organizations_direct("other-token", "ada").await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_removing_a_member_forgets_their_cached_organizations() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let keycloak = service(fake_organizations_keycloak(requests.clone(), true).await);
+
+        keycloak.get_user_organizations_direct("token", "ada").await.unwrap();
+        keycloak.get_user_organizations_direct("other-token", "ada").await.unwrap();
+        keycloak.get_user_organizations_direct("token", "bob").await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+
+        keycloak.remove_user_from_organization("token", "org-2", "ada").await.unwrap();
+
+        keycloak.get_user_organizations_direct("token", "bob").await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+        keycloak.get_user_organizations_direct("token", "ada").await.unwrap();
+        keycloak.get_user_organizations_direct("other-token", "ada").await.unwrap();
+        assert_eq!(requests.load(Ordering::SeqCst), 5);
+    }
+
+    #[tokio::test]
+    async fn test_user_organizations_fall_back_to_scanning_members() {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let keycloak = service(fake_organizations_keycloak(requests.clone(), false).await);
+
+        let organizations = keycloak.get_user_organizations_direct("token", "ada").await.unwrap();
+
+        assert_eq!(organizations.iter().map(|o| o.id.as_str()).collect::<Vec<_>>(), ["org-2"]);
+        // The 404, the organization list and one member list per organization
+        assert_eq!(requests.load(Ordering::SeqCst), 5);
+    }
+}
@@ -1 +1,12 @@
+pub mod email;
+pub mod encryption;
+pub mod invitation_expiry;
 pub mod keycloak_service;
+pub mod object_store;
+pub mod organizations_cache;
+pub mod pdf;
+pub mod report_progress;
+pub mod score_engine;
+pub mod secrets;
+pub mod seed;
+pub mod skip_logic;
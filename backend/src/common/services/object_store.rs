@@ -0,0 +1,104 @@
+//! Object storage for uploaded file bytes.
+//!
+//! When an object store is configured, new uploads are written there and the `file`
+//! table only keeps the object key. Files uploaded before that keep their bytes inline.
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use aws_sdk_s3::primitives::ByteStream;
+use axum::body::Bytes;
+use futures::stream::BoxStream;
+use tracing::info;
+
+use crate::common::config::StorageConfig;
+
+/// Stream of object bytes, handed straight to the response body
+pub type ObjectStream = BoxStream<'static, std::io::Result<Bytes>>;
+
+#[async_trait]
+pub trait ObjectStore: Send + Sync {
+    async fn put(&self, key: &str, data: Bytes, content_type: &str) -> Result<()>;
+    async fn get(&self, key: &str) -> Result<ObjectStream>;
+    async fn delete(&self, key: &str) -> Result<()>;
+}
+
+/// S3 (or S3-compatible, e.g. MinIO) backed object store
+pub struct S3ObjectStore {
+    client: aws_sdk_s3::Client,
+    bucket: String,
+}
+
+impl S3ObjectStore {
+    /// Build a client from the standard AWS environment (credentials, region), with
+    /// optional overrides from the storage config.
+    pub async fn from_config(config: &StorageConfig) -> Result<Self> {
+        let bucket = config
+            .s3_bucket
+            .clone()
+            .filter(|bucket| !bucket.is_empty())
+            .ok_or_else(|| anyhow!("S3_BUCKET must be set when STORAGE_BACKEND=s3"))?;
+
+        let mut loader = aws_config::defaults(aws_config::BehaviorVersion::latest());
+        if let Some(region) = &config.s3_region {
+            loader = loader.region(aws_config::Region::new(region.clone()));
+        }
+        let shared_config = loader.load().await;
+
+        let mut builder = aws_sdk_s3::config::Builder::from(&shared_config);
+        if let Some(endpoint) = &config.s3_endpoint {
+            // Custom endpoints are S3-compatible servers, which generally need path-style URLs
+            builder = builder.endpoint_url(endpoint).force_path_style(true);
+        }
+
+        info!(bucket = %bucket, "Using S3 object storage for uploaded files");
+        Ok(Self {
+            client: aws_sdk_s3::Client::from_conf(builder.build()),
+            bucket,
+        })
+    }
+}
+
+#[async_trait]
+impl ObjectStore for S3ObjectStore {
+    async fn put(&self, key: &str, data: Bytes, content_type: &str) -> Result<()> {
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .content_type(content_type)
+            .body(ByteStream::from(data))
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to upload object {key}: {e}"))?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<ObjectStream> {
+        let object = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to fetch object {key}: {e}"))?;
+
+        let stream = futures::stream::unfold(object.body, |mut body| async move {
+            body.next()
+                .await
+                .map(|chunk| (chunk.map_err(std::io::Error::other), body))
+        });
+        Ok(Box::pin(stream))
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| anyhow!("Failed to delete object {key}: {e}"))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,60 @@
+//! Periodic copy of Keycloak's organizations into the `organizations_cache` table.
+//!
+//! Admin search and counts read the cache instead of listing every organization
+//! from Keycloak. It is refreshed in the background as the backend's service
+//! account, and on demand by admins with their own token.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use tracing::{info, warn};
+
+use crate::common::config::SyncConfig;
+use crate::common::database::entity::organizations_cache::OrganizationsCacheService;
+use crate::common::services::keycloak_service::KeycloakService;
+
+/// Replace the cached organizations with Keycloak's current list, returning how many there are
+pub async fn refresh_organizations_cache(
+    keycloak: &KeycloakService,
+    cache: &OrganizationsCacheService,
+    token: &str,
+) -> Result<usize> {
+    let organizations = keycloak.get_organizations(token).await?;
+    Ok(cache.replace_all(&organizations).await?)
+}
+
+/// Refresh the cache every `org_cache_refresh_interval_secs`, starting right away.
+///
+/// Does nothing when the interval is 0 or no client secret is configured, in which
+/// case the cache only changes through the refresh endpoint.
+pub fn spawn_organizations_cache_refresh(
+    keycloak: Arc<KeycloakService>,
+    cache: OrganizationsCacheService,
+    config: &SyncConfig,
+) {
+    let Some(client_secret) = config.keycloak_client_secret.clone() else {
+        warn!("KEYCLOAK_CLIENT_SECRET is not set, organizations cache is only refreshed on request");
+        return;
+    };
+    if config.org_cache_refresh_interval_secs == 0 {
+        return;
+    }
+    let period = Duration::from_secs(config.org_cache_refresh_interval_secs);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(period);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            let refreshed = match keycloak.service_account_token(&client_secret).await {
+                Ok(token) => refresh_organizations_cache(&keycloak, &cache, &token).await,
+                Err(e) => Err(e),
+            };
+            match refreshed {
+                Ok(count) => info!(organizations = count, "Refreshed organizations cache"),
+                Err(e) => warn!(error = %e, "Failed to refresh organizations cache"),
+            }
+        }
+    });
+}
@@ -0,0 +1,238 @@
+//! Plain-text PDF documents.
+//!
+//! Documents are A4 pages of Helvetica text: a title followed by headings and text
+//! lines that wrap at the page width and flow onto new pages. Only the standard
+//! fonts every PDF reader ships are used, so nothing is embedded. Text is written in
+//! WinAnsi encoding; characters outside Latin-1 are replaced with "?".
+
+use std::fmt::Write as _;
+
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+
+#[derive(Clone, Copy)]
+enum Style {
+    Title,
+    Heading,
+    Text,
+}
+
+impl Style {
+    fn font(self) -> &'static str {
+        match self {
+            Style::Title | Style::Heading => "F2",
+            Style::Text => "F1",
+        }
+    }
+
+    fn size(self) -> f32 {
+        match self {
+            Style::Title => 16.0,
+            Style::Heading => 13.0,
+            Style::Text => 11.0,
+        }
+    }
+
+    /// Characters per line, assuming Helvetica's average glyph width of half the font size
+    fn line_chars(self) -> usize {
+        ((PAGE_WIDTH - 2.0 * MARGIN) / (self.size() * 0.5)) as usize
+    }
+}
+
+/// A document built line by line and rendered with [`PdfDocument::render`]
+pub struct PdfDocument {
+    lines: Vec<(Style, String)>,
+}
+
+impl PdfDocument {
+    pub fn new(title: &str) -> Self {
+        let mut document = Self { lines: Vec::new() };
+        document.push(Style::Title, title);
+        document.blank();
+        document
+    }
+
+    pub fn heading(&mut self, text: &str) -> &mut Self {
+        self.blank();
+        self.push(Style::Heading, text)
+    }
+
+    pub fn text(&mut self, text: &str) -> &mut Self {
+        self.push(Style::Text, text)
+    }
+
+    pub fn blank(&mut self) -> &mut Self {
+        self.lines.push((Style::Text, String::new()));
+        self
+    }
+
+    fn push(&mut self, style: Style, text: &str) -> &mut Self {
+        for paragraph in text.lines() {
+            self.lines
+                .extend(wrap(paragraph, style.line_chars()).into_iter().map(|line| (style, line)));
+        }
+        self
+    }
+
+    /// The document as PDF bytes
+    pub fn render(&self) -> Vec<u8> {
+        let pages = self.page_contents();
+
+        // Objects 1-4 are fixed, then a page object and its content stream per page
+        let page_ids: Vec<usize> = (0..pages.len()).map(|i| 5 + 2 * i).collect();
+        let mut objects: Vec<Vec<u8>> = vec![
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {} >>",
+                page_ids.iter().map(|id| format!("{id} 0 R")).collect::<Vec<_>>().join(" "),
+                pages.len()
+            )
+            .into_bytes(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>".to_vec(),
+            b"<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica-Bold /Encoding /WinAnsiEncoding >>".to_vec(),
+        ];
+        for (page_id, content) in page_ids.iter().zip(pages) {
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                     /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                    page_id + 1
+                )
+                .into_bytes(),
+            );
+            let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+            stream.extend(content);
+            stream.extend(b"\nendstream");
+            objects.push(stream);
+        }
+
+        let mut pdf = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend(format!("{} 0 obj\n", index + 1).into_bytes());
+            pdf.extend(object);
+            pdf.extend(b"\nendobj\n");
+        }
+
+        let xref = pdf.len();
+        let mut trailer = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+        for offset in offsets {
+            let _ = writeln!(trailer, "{offset:010} 00000 n ");
+        }
+        let _ = write!(
+            trailer,
+            "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref}\n%%EOF\n",
+            objects.len() + 1
+        );
+        pdf.extend(trailer.into_bytes());
+        pdf
+    }
+
+    /// Content stream of each page
+    fn page_contents(&self) -> Vec<Vec<u8>> {
+        let mut pages = Vec::new();
+        let mut content = Vec::new();
+        let mut y = PAGE_HEIGHT - MARGIN;
+        for (style, line) in &self.lines {
+            let leading = style.size() * 1.3;
+            if y - leading < MARGIN {
+                pages.push(std::mem::take(&mut content));
+                y = PAGE_HEIGHT - MARGIN;
+            }
+            y -= leading;
+            if line.is_empty() {
+                continue;
+            }
+            content.extend(format!("BT /{} {} Tf {MARGIN} {y:.1} Td (", style.font(), style.size()).into_bytes());
+            content.extend(encode_text(line));
+            content.extend(b") Tj ET\n");
+        }
+        pages.push(content);
+        pages
+    }
+}
+
+/// Breaks `text` into lines of at most `width` characters, at spaces where possible
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        let mut word = word.to_string();
+        while word.chars().count() > width {
+            if !line.is_empty() {
+                lines.push(std::mem::take(&mut line));
+            }
+            let rest = word.split_off(word.char_indices().nth(width).map(|(i, _)| i).unwrap_or(word.len()));
+            lines.push(std::mem::replace(&mut word, rest));
+        }
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(std::mem::take(&mut line));
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line.push_str(&word);
+    }
+    if !line.is_empty() || lines.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+/// Text as the bytes of a PDF literal string
+fn encode_text(text: &str) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => bytes.extend([b'\\', c as u8]),
+            c if (c as u32) < 0x20 => bytes.push(b' '),
+            c if (c as u32) <= 0xFF => bytes.push(c as u32 as u8),
+            _ => bytes.push(b'?'),
+        }
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count(haystack: &[u8], needle: &[u8]) -> usize {
+        haystack.windows(needle.len()).filter(|window| *window == needle).count()
+    }
+
+    #[test]
+    fn test_render_is_a_well_formed_pdf() {
+        let mut document = PdfDocument::new("Report (draft)");
+        document.heading("Environmental").text("Größe \\ 100% – done");
+        let pdf = document.render();
+
+        assert!(pdf.starts_with(b"%PDF-1.4\n"));
+        assert!(pdf.ends_with(b"%%EOF\n"));
+        // startxref points at the cross-reference table
+        let text = String::from_utf8_lossy(&pdf);
+        let xref: usize = text.rsplit("startxref\n").next().unwrap().lines().next().unwrap().parse().unwrap();
+        assert!(pdf[xref..].starts_with(b"xref\n"));
+        assert_eq!(count(&pdf, b"(Report \\(draft\\)) Tj"), 1);
+        assert_eq!(count(&pdf, b"(Gr\xF6\xDFe \\\\ 100% ? done) Tj"), 1);
+    }
+
+    #[test]
+    fn test_long_documents_flow_onto_new_pages() {
+        let mut document = PdfDocument::new("Report");
+        for i in 0..120 {
+            document.text(&format!("Line {i}"));
+        }
+
+        assert_eq!(count(&document.render(), b"/Type /Page "), 3);
+    }
+
+    #[test]
+    fn test_wrap_breaks_at_spaces_and_splits_long_words() {
+        assert_eq!(wrap("one two three", 7), vec!["one two", "three"]);
+        assert_eq!(wrap("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(wrap("", 4), vec![""]);
+    }
+}
@@ -0,0 +1,220 @@
+//! Progress of reports generated in the background, polled through
+//! `GET /reports/{report_id}/generation-progress`.
+//!
+//! Entries live in memory only. Finished ones are dropped once `retention` has passed,
+//! and every completed generation feeds the average used to estimate how long the
+//! running ones still need.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// How long finished entries can still be polled
+pub const PROGRESS_RETENTION: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum GenerationStatus {
+    Pending,
+    Processing,
+    Completed,
+    Failed,
+}
+
+/// The steps of a generation, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportStep {
+    LoadingSubmission,
+    ResolvingQuestions,
+    ComputingScores,
+    FormattingOutput,
+    SavingReport,
+}
+
+impl ReportStep {
+    pub const ALL: [ReportStep; 5] = [
+        Self::LoadingSubmission,
+        Self::ResolvingQuestions,
+        Self::ComputingScores,
+        Self::FormattingOutput,
+        Self::SavingReport,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::LoadingSubmission => "loading_submission",
+            Self::ResolvingQuestions => "resolving_questions",
+            Self::ComputingScores => "computing_scores",
+            Self::FormattingOutput => "formatting_output",
+            Self::SavingReport => "saving_report",
+        }
+    }
+
+    /// Steps finished before this one starts
+    fn index(&self) -> u32 {
+        Self::ALL.iter().position(|step| step == self).unwrap_or_default() as u32
+    }
+}
+
+/// Where one generation stands
+#[derive(Debug, Clone)]
+pub struct ProgressEntry {
+    /// Organization of the report's submission, for access checks
+    pub org_id: String,
+    pub status: GenerationStatus,
+    pub steps_completed: u32,
+    pub current_step: ReportStep,
+    pub error: Option<String>,
+    pub started_at: Instant,
+    pub finished_at: Option<Instant>,
+}
+
+#[derive(Clone)]
+pub struct ReportProgress {
+    entries: Arc<DashMap<Uuid, ProgressEntry>>,
+    /// Total time and number of completed generations
+    completed: Arc<Mutex<(Duration, u32)>>,
+    retention: Duration,
+}
+
+impl ReportProgress {
+    pub fn new(retention: Duration) -> Self {
+        Self {
+            entries: Arc::new(DashMap::new()),
+            completed: Arc::new(Mutex::new((Duration::ZERO, 0))),
+            retention,
+        }
+    }
+
+    /// Track a generation that has not started yet
+    pub fn start(&self, report_id: Uuid, org_id: &str) {
+        self.evict_expired();
+        self.entries.insert(
+            report_id,
+            ProgressEntry {
+                org_id: org_id.to_string(),
+                status: GenerationStatus::Pending,
+                steps_completed: 0,
+                current_step: ReportStep::LoadingSubmission,
+                error: None,
+                started_at: Instant::now(),
+                finished_at: None,
+            },
+        );
+    }
+
+    pub fn step(&self, report_id: Uuid, step: ReportStep) {
+        if let Some(mut entry) = self.entries.get_mut(&report_id) {
+            entry.status = GenerationStatus::Processing;
+            entry.steps_completed = step.index();
+            entry.current_step = step;
+        }
+    }
+
+    pub fn complete(&self, report_id: Uuid) {
+        if let Some(mut entry) = self.entries.get_mut(&report_id) {
+            let now = Instant::now();
+            entry.status = GenerationStatus::Completed;
+            entry.steps_completed = ReportStep::ALL.len() as u32;
+            entry.finished_at = Some(now);
+
+            if let Ok(mut completed) = self.completed.lock() {
+                completed.0 += now - entry.started_at;
+                completed.1 += 1;
+            }
+        }
+    }
+
+    /// The current step stays as the one that failed
+    pub fn fail(&self, report_id: Uuid, error: String) {
+        if let Some(mut entry) = self.entries.get_mut(&report_id) {
+            entry.status = GenerationStatus::Failed;
+            entry.error = Some(error);
+            entry.finished_at = Some(Instant::now());
+        }
+    }
+
+    pub fn get(&self, report_id: Uuid) -> Option<ProgressEntry> {
+        self.evict_expired();
+        self.entries.get(&report_id).map(|entry| entry.clone())
+    }
+
+    /// Average time of the completed generations, if there were any
+    pub fn average_duration(&self) -> Option<Duration> {
+        let completed = self.completed.lock().ok()?;
+        (completed.1 > 0).then(|| completed.0 / completed.1)
+    }
+
+    /// Seconds `entry` presumably still needs, from the average duration; `None` once it
+    /// has finished or before any generation completed
+    pub fn estimated_remaining_secs(&self, entry: &ProgressEntry) -> Option<u32> {
+        if entry.finished_at.is_some() {
+            return None;
+        }
+        let average = self.average_duration()?;
+        let remaining = average.saturating_sub(entry.started_at.elapsed());
+        Some(remaining.as_secs_f64().ceil() as u32)
+    }
+
+    fn evict_expired(&self) {
+        self.entries
+            .retain(|_, entry| entry.finished_at.is_none_or(|finished_at| finished_at.elapsed() < self.retention));
+    }
+}
+
+impl Default for ReportProgress {
+    fn default() -> Self {
+        Self::new(PROGRESS_RETENTION)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_steps_and_average_duration() {
+        let progress = ReportProgress::default();
+        let report_id = Uuid::new_v4();
+
+        progress.start(report_id, "org-1");
+        let entry = progress.get(report_id).unwrap();
+        assert_eq!((entry.status, entry.steps_completed), (GenerationStatus::Pending, 0));
+        assert_eq!(progress.estimated_remaining_secs(&entry), None);
+
+        progress.step(report_id, ReportStep::ComputingScores);
+        let entry = progress.get(report_id).unwrap();
+        assert_eq!(entry.status, GenerationStatus::Processing);
+        assert_eq!((entry.steps_completed, entry.current_step), (2, ReportStep::ComputingScores));
+
+        progress.complete(report_id);
+        let entry = progress.get(report_id).unwrap();
+        assert_eq!((entry.status, entry.steps_completed), (GenerationStatus::Completed, 5));
+        assert!(progress.average_duration().is_some());
+        assert_eq!(progress.estimated_remaining_secs(&entry), None);
+
+        let running = Uuid::new_v4();
+        progress.start(running, "org-1");
+        assert!(progress.estimated_remaining_secs(&progress.get(running).unwrap()).is_some());
+    }
+
+    #[test]
+    fn test_finished_entries_are_evicted() {
+        let progress = ReportProgress::new(Duration::ZERO);
+        let [running, completed, failed] = std::array::from_fn(|_| Uuid::new_v4());
+        for report_id in [running, completed, failed] {
+            progress.start(report_id, "org-1");
+        }
+
+        progress.complete(completed);
+        progress.fail(failed, "Submission not found".to_string());
+
+        assert!(progress.get(running).is_some());
+        assert!(progress.get(completed).is_none());
+        assert!(progress.get(failed).is_none());
+    }
+}
@@ -0,0 +1,220 @@
+//! Category scores of generated reports, and how they compare across organizations.
+//!
+//! A report's `data` is a list of `{ "<category>": { "questions": [{ "answer": .. }] } }`
+//! objects. Each answered question scores `percentage / 100` when `yesNo` is true
+//! (the full point when no percentage was given) and 0 otherwise, the same rule the
+//! frontend's radar chart uses. A category's score is the mean over its questions,
+//! scaled to 0-100. Categories scored with `ScoringMode::Threshold` then pass with
+//! 100 when that mean reaches their threshold and fail with 0 otherwise.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde_json::Value;
+
+use crate::common::database::entity::category_catalog::{self, ScoringMode};
+
+/// Category name -> score between 0 and 100
+pub type CategoryScores = BTreeMap<String, f64>;
+
+/// How one catalog category is scored
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct CategoryScoring {
+    pub mode: ScoringMode,
+    /// Pass mark between 0 and 100; a threshold category without one always passes
+    pub threshold: Option<f64>,
+}
+
+impl CategoryScoring {
+    fn apply(&self, score: f64) -> f64 {
+        match self.mode {
+            ScoringMode::Linear => score,
+            ScoringMode::Threshold if score >= self.threshold.unwrap_or(0.0) => 100.0,
+            ScoringMode::Threshold => 0.0,
+        }
+    }
+}
+
+/// Scoring by category name; categories not listed are scored linearly
+pub type ScoringRules = HashMap<String, CategoryScoring>;
+
+impl From<&category_catalog::Model> for CategoryScoring {
+    fn from(category: &category_catalog::Model) -> Self {
+        Self {
+            mode: category.scoring_mode,
+            threshold: category.score_threshold,
+        }
+    }
+}
+
+pub struct ScoreEngine;
+
+impl ScoreEngine {
+    /// Scoring rules of the given catalog categories, keyed by name as reports are
+    pub fn scoring_rules(categories: &[category_catalog::Model]) -> ScoringRules {
+        categories
+            .iter()
+            .map(|category| (category.name.clone(), CategoryScoring::from(category)))
+            .collect()
+    }
+
+    pub fn category_scores(data: &Value, rules: &ScoringRules) -> CategoryScores {
+        let mut totals: BTreeMap<String, (f64, usize)> = BTreeMap::new();
+        for categories in data.as_array().into_iter().flatten().filter_map(Value::as_object) {
+            for (category, content) in categories {
+                let Some(questions) = content.get("questions").and_then(Value::as_array) else {
+                    continue;
+                };
+                let (sum, count) = totals.entry(category.clone()).or_default();
+                for question in questions {
+                    *sum += Self::question_score(question.get("answer").unwrap_or(&Value::Null));
+                    *count += 1;
+                }
+            }
+        }
+
+        totals
+            .into_iter()
+            .filter(|(_, (_, count))| *count > 0)
+            .map(|(category, (sum, count))| {
+                let score = sum / count as f64 * 100.0;
+                let score = rules.get(&category).map_or(score, |scoring| scoring.apply(score));
+                (category, score)
+            })
+            .collect()
+    }
+
+    /// Score of a single answer between 0 and 1
+    fn question_score(answer: &Value) -> f64 {
+        if answer.get("yesNo").and_then(Value::as_bool) != Some(true) {
+            return 0.0;
+        }
+        answer
+            .get("percentage")
+            .and_then(Value::as_f64)
+            .map_or(1.0, |percentage| (percentage / 100.0).clamp(0.0, 1.0))
+    }
+
+    /// Percentile rank of `score` within `pool`, as `(below + equal / 2) / pool size * 100`.
+    ///
+    /// Ties count half, so the middle of a pool of identical scores is the 50th percentile.
+    pub fn percentile_rank(score: f64, pool: &[f64]) -> f64 {
+        if pool.is_empty() {
+            return 0.0;
+        }
+        let below = pool.iter().filter(|other| **other < score).count() as f64;
+        let equal = pool.iter().filter(|other| **other == score).count() as f64;
+        (below + equal / 2.0) / pool.len() as f64 * 100.0
+    }
+}
+
+/// Latest category scores of each organization in the comparison pool
+#[derive(Clone, Debug, Default)]
+pub struct SectorPool {
+    pub scores_by_org: BTreeMap<String, CategoryScores>,
+}
+
+impl SectorPool {
+    pub fn org_count(&self) -> usize {
+        self.scores_by_org.len()
+    }
+
+    /// Scores of every organization that answered `category`
+    pub fn category_pool(&self, category: &str) -> Vec<f64> {
+        self.scores_by_org
+            .values()
+            .filter_map(|scores| scores.get(category).copied())
+            .collect()
+    }
+
+    /// Mean score per category, leaving out categories answered by fewer than `min_orgs`
+    /// organizations so that a single organization's score cannot be read back
+    pub fn averages(&self, min_orgs: usize) -> CategoryScores {
+        let categories: HashSet<&String> = self.scores_by_org.values().flat_map(|scores| scores.keys()).collect();
+        categories
+            .into_iter()
+            .filter_map(|category| {
+                let pool = self.category_pool(category);
+                (pool.len() >= min_orgs).then(|| (category.clone(), pool.iter().sum::<f64>() / pool.len() as f64))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_category_scores_follow_yes_no_and_percentage() {
+        let data = json!([
+            { "Environmental": { "questions": [
+                { "question": "Policy?", "answer": { "yesNo": true, "percentage": 80 } },
+                { "question": "Footprint?", "answer": { "yesNo": false, "percentage": 90 } },
+            ] } },
+            { "Social": { "questions": [
+                { "question": "Training?", "answer": { "yesNo": true } },
+                { "question": "Diversity?", "answer": { "text": "Not yet" } },
+            ] } },
+        ]);
+
+        let scores = ScoreEngine::category_scores(&data, &ScoringRules::new());
+
+        assert_eq!(scores.len(), 2);
+        assert!((scores["Environmental"] - 40.0).abs() < 1e-9);
+        assert!((scores["Social"] - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_percentile_rank_counts_ties_half() {
+        let pool = [10.0, 20.0, 30.0, 40.0, 50.0];
+
+        assert_eq!(ScoreEngine::percentile_rank(30.0, &pool), 50.0);
+        assert_eq!(ScoreEngine::percentile_rank(50.0, &pool), 90.0);
+        assert_eq!(ScoreEngine::percentile_rank(10.0, &pool), 10.0);
+        assert_eq!(ScoreEngine::percentile_rank(60.0, &pool), 100.0);
+        assert_eq!(ScoreEngine::percentile_rank(0.0, &pool), 0.0);
+        assert_eq!(ScoreEngine::percentile_rank(70.0, &[70.0; 4]), 50.0);
+    }
+
+    #[test]
+    fn test_threshold_scoring_passes_or_fails_linear_score() {
+        let data = json!([
+            { "Environmental": { "questions": [
+                { "question": "Policy?", "answer": { "yesNo": true, "percentage": 90 } },
+                { "question": "Footprint?", "answer": { "yesNo": true, "percentage": 60 } },
+            ] } },
+            { "Social": { "questions": [
+                { "question": "Training?", "answer": { "yesNo": true } },
+                { "question": "Diversity?", "answer": { "yesNo": false } },
+            ] } },
+        ]);
+        let threshold = |threshold| CategoryScoring {
+            mode: ScoringMode::Threshold,
+            threshold: Some(threshold),
+        };
+
+        let linear = ScoreEngine::category_scores(&data, &ScoringRules::new());
+        let thresholded = ScoreEngine::category_scores(
+            &data,
+            &ScoringRules::from([
+                ("Environmental".to_string(), threshold(70.0)),
+                ("Social".to_string(), threshold(70.0)),
+            ]),
+        );
+
+        assert!((linear["Environmental"] - 75.0).abs() < 1e-9);
+        assert!((linear["Social"] - 50.0).abs() < 1e-9);
+        // Reaching the threshold passes, falling short fails
+        assert_eq!(thresholded["Environmental"], 100.0);
+        assert_eq!(thresholded["Social"], 0.0);
+
+        // Only the listed categories change
+        let mixed = ScoreEngine::category_scores(
+            &data,
+            &ScoringRules::from([("Social".to_string(), threshold(40.0))]),
+        );
+        assert_eq!(mixed["Environmental"], linear["Environmental"]);
+        assert_eq!(mixed["Social"], 100.0);
+    }
+}
@@ -0,0 +1,208 @@
+//! Sources for sensitive configuration values.
+//!
+//! Secrets are looked up by their environment variable name (e.g. `DATABASE_URL`).
+//! With `SECRETS_PROVIDER=env` (the default) they are read from the environment as
+//! before; with `ssm` they are fetched from AWS Parameter Store under
+//! `SECRETS_PREFIX`, so `/dgat/DATABASE_URL` provides `DATABASE_URL`.
+
+use std::collections::HashMap;
+
+use anyhow::{anyhow, Result};
+use async_trait::async_trait;
+use tracing::info;
+
+use crate::common::config::SecretsConfig;
+
+/// Configuration values that may come from a secrets provider instead of the environment
+pub const SECRET_KEYS: &[&str] = &["DATABASE_URL", "KEYCLOAK_CLIENT_SECRET", "SMTP_PASSWORD", "ENCRYPTION_KEY_BASE64"];
+
+#[async_trait]
+pub trait SecretsProvider: Send + Sync {
+    /// Fetch the given keys; keys the provider does not know are left out of the result
+    async fn fetch(&self, keys: &[&str]) -> Result<HashMap<String, String>>;
+}
+
+/// Build the provider selected by `SECRETS_PROVIDER`
+pub async fn secrets_provider(config: &SecretsConfig) -> Result<Box<dyn SecretsProvider>> {
+    match config.provider.as_str() {
+        "env" => Ok(Box::new(EnvSecretsProvider)),
+        "ssm" => Ok(Box::new(AwsSsmSecretsProvider::from_config(config).await)),
+        "vault" => Ok(Box::new(HashicorpVaultSecretsProvider::new(&config.prefix))),
+        other => Err(anyhow!("Unknown SECRETS_PROVIDER: {other}")),
+    }
+}
+
+/// Reads secrets from process environment variables
+pub struct EnvSecretsProvider;
+
+#[async_trait]
+impl SecretsProvider for EnvSecretsProvider {
+    async fn fetch(&self, keys: &[&str]) -> Result<HashMap<String, String>> {
+        Ok(keys
+            .iter()
+            .filter_map(|key| std::env::var(key).ok().map(|value| (key.to_string(), value)))
+            .collect())
+    }
+}
+
+/// Reads secrets from AWS Systems Manager Parameter Store
+pub struct AwsSsmSecretsProvider {
+    client: aws_sdk_ssm::Client,
+    prefix: String,
+}
+
+impl AwsSsmSecretsProvider {
+    pub fn new(client: aws_sdk_ssm::Client, prefix: &str) -> Self {
+        Self {
+            client,
+            prefix: prefix.to_string(),
+        }
+    }
+
+    /// Build a client from the standard AWS environment (credentials, region), with
+    /// an optional endpoint override from the secrets config.
+    pub async fn from_config(config: &SecretsConfig) -> Self {
+        let shared_config = aws_config::defaults(aws_config::BehaviorVersion::latest()).load().await;
+
+        let mut builder = aws_sdk_ssm::config::Builder::from(&shared_config);
+        if let Some(endpoint) = &config.ssm_endpoint {
+            builder = builder.endpoint_url(endpoint);
+        }
+
+        info!(prefix = %config.prefix, "Loading secrets from AWS Parameter Store");
+        Self::new(aws_sdk_ssm::Client::from_conf(builder.build()), &config.prefix)
+    }
+
+    /// Parameter Store paths must not end with a slash, except for the root
+    fn path(&self) -> &str {
+        match self.prefix.trim_end_matches('/') {
+            "" => "/",
+            path => path,
+        }
+    }
+}
+
+#[async_trait]
+impl SecretsProvider for AwsSsmSecretsProvider {
+    async fn fetch(&self, keys: &[&str]) -> Result<HashMap<String, String>> {
+        let path = self.path();
+        let mut secrets = HashMap::new();
+        let mut next_token = None;
+
+        loop {
+            let page = self
+                .client
+                .get_parameters_by_path()
+                .path(path)
+                .recursive(true)
+                .with_decryption(true)
+                .set_next_token(next_token)
+                .send()
+                .await
+                .map_err(|e| anyhow!("Failed to read parameters under {path}: {e}"))?;
+
+            for parameter in page.parameters() {
+                let (Some(name), Some(value)) = (parameter.name(), parameter.value()) else {
+                    continue;
+                };
+                let key = name.trim_start_matches(path).trim_start_matches('/');
+                if keys.contains(&key) {
+                    secrets.insert(key.to_string(), value.to_string());
+                }
+            }
+
+            next_token = page.next_token().map(str::to_string);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(secrets)
+    }
+}
+
+/// Placeholder for reading secrets from HashiCorp Vault's KV engine
+pub struct HashicorpVaultSecretsProvider {
+    mount_path: String,
+}
+
+impl HashicorpVaultSecretsProvider {
+    pub fn new(mount_path: &str) -> Self {
+        Self {
+            mount_path: mount_path.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl SecretsProvider for HashicorpVaultSecretsProvider {
+    async fn fetch(&self, _keys: &[&str]) -> Result<HashMap<String, String>> {
+        Err(anyhow!(
+            "Vault secrets provider is not supported yet (path {})",
+            self.mount_path
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aws_sdk_ssm::config::{BehaviorVersion, Credentials, Region};
+    use aws_sdk_ssm::types::ParameterType;
+    use testcontainers_modules::localstack::LocalStack;
+    use testcontainers_modules::testcontainers::{runners::AsyncRunner, ImageExt};
+
+    #[tokio::test]
+    async fn test_env_provider_skips_missing_keys() -> Result<()> {
+        let secrets = EnvSecretsProvider
+            .fetch(&["PATH", "DGAT_SECRET_THAT_IS_NEVER_SET"])
+            .await?;
+
+        assert!(secrets.contains_key("PATH"));
+        assert!(!secrets.contains_key("DGAT_SECRET_THAT_IS_NEVER_SET"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[ignore = "starts LocalStack, needs Docker"]
+    async fn test_ssm_provider_loads_parameters_under_prefix() -> Result<()> {
+        let localstack = LocalStack::default().with_env_var("SERVICES", "ssm").start().await?;
+        let port = localstack.get_host_port_ipv4(4566).await?;
+
+        let config = aws_sdk_ssm::Config::builder()
+            .behavior_version(BehaviorVersion::latest())
+            .region(Region::new("us-east-1"))
+            .credentials_provider(Credentials::new("test", "test", None, None, "localstack"))
+            .endpoint_url(format!("http://127.0.0.1:{port}"))
+            .build();
+        let client = aws_sdk_ssm::Client::from_conf(config);
+
+        for (name, value, kind) in [
+            ("/dgat/DATABASE_URL", "postgres://db/dgat", ParameterType::SecureString),
+            ("/dgat/KEYCLOAK_CLIENT_SECRET", "s3cret", ParameterType::SecureString),
+            ("/dgat/UNRELATED", "ignored", ParameterType::String),
+            ("/other/DATABASE_URL", "postgres://other", ParameterType::String),
+        ] {
+            client
+                .put_parameter()
+                .name(name)
+                .value(value)
+                .r#type(kind)
+                .send()
+                .await?;
+        }
+
+        let secrets = AwsSsmSecretsProvider::new(client, "/dgat/")
+            .fetch(SECRET_KEYS)
+            .await?;
+
+        assert_eq!(
+            secrets,
+            HashMap::from([
+                ("DATABASE_URL".to_string(), "postgres://db/dgat".to_string()),
+                ("KEYCLOAK_CLIENT_SECRET".to_string(), "s3cret".to_string()),
+            ])
+        );
+        Ok(())
+    }
+}
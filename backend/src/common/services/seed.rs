@@ -0,0 +1,316 @@
+//! Test data for end-to-end and integration tests, behind `POST /internal/seed`.
+//!
+//! One request creates organizations, then their users, then assessments, each
+//! referring to the ones before by name. Everything created is remembered until
+//! `rollback` deletes it again, in reverse order. Keycloak calls run with the token
+//! the seed request came with, which must be allowed to manage the realm.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::common::models::keycloak::CreateUserRequest;
+use crate::common::services::keycloak_service::KeycloakService;
+use crate::common::state::AppDatabase;
+use crate::web::api::models::OrganizationDomainRequest;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeedRequest {
+    #[serde(default)]
+    pub organizations: Vec<SeedOrg>,
+    #[serde(default)]
+    pub users: Vec<SeedUser>,
+    #[serde(default)]
+    pub assessments: Vec<SeedAssessment>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeedOrg {
+    pub name: String,
+    #[serde(default)]
+    pub domains: Vec<String>,
+    /// Catalog category names, assigned with equal weights
+    #[serde(default)]
+    pub categories: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeedUser {
+    pub email: String,
+    /// Defaults to the email
+    pub username: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    /// Name of a seeded organization to add the user to
+    pub organization: Option<String>,
+    /// Realm roles, e.g. `org_admin`
+    #[serde(default)]
+    pub roles: Vec<String>,
+    /// Categories assigned to the user, as stored in their `categories` attribute
+    #[serde(default)]
+    pub categories: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeedAssessment {
+    /// Name of a seeded organization
+    pub organization: String,
+    pub name: String,
+    #[serde(default = "default_language")]
+    pub language: String,
+    /// Catalog category names
+    #[serde(default)]
+    pub categories: Vec<String>,
+}
+
+fn default_language() -> String {
+    "en".to_string()
+}
+
+/// IDs of seeded data
+#[derive(Debug, Default, Clone, PartialEq, Serialize)]
+pub struct SeededData {
+    /// Organization name -> Keycloak organization ID
+    pub organizations: BTreeMap<String, String>,
+    /// Email -> Keycloak user ID
+    pub users: BTreeMap<String, String>,
+    pub assessments: Vec<SeededAssessment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SeededAssessment {
+    pub assessment_id: Uuid,
+    pub org_id: String,
+}
+
+impl SeededData {
+    fn extend(&mut self, other: SeededData) {
+        self.organizations.extend(other.organizations);
+        self.users.extend(other.users);
+        self.assessments.extend(other.assessments);
+    }
+}
+
+/// What `rollback` deleted, and what it could not
+#[derive(Debug, Default, Serialize)]
+pub struct RollbackResult {
+    pub deleted: SeededData,
+    pub errors: Vec<String>,
+}
+
+#[derive(Clone)]
+pub struct SeedService {
+    keycloak: Arc<KeycloakService>,
+    database: AppDatabase,
+    seeded: Arc<Mutex<SeededData>>,
+}
+
+impl SeedService {
+    pub fn new(keycloak: Arc<KeycloakService>, database: AppDatabase) -> Self {
+        Self {
+            keycloak,
+            database,
+            seeded: Arc::new(Mutex::new(SeededData::default())),
+        }
+    }
+
+    /// Create everything in `request`, organizations first.
+    ///
+    /// On failure, what was created before the failing item stays seeded, so
+    /// `rollback` still removes it.
+    pub async fn seed(&self, token: &str, request: SeedRequest) -> Result<SeededData> {
+        let mut seeded = self.seeded.lock().await;
+        let mut created = SeededData::default();
+        let result = self.seed_into(token, request, &mut created).await;
+        seeded.extend(created.clone());
+        result.map(|_| created)
+    }
+
+    async fn seed_into(&self, token: &str, request: SeedRequest, created: &mut SeededData) -> Result<()> {
+        let catalog = self
+            .database
+            .category_catalog
+            .get_all_categories()
+            .await?
+            .into_iter()
+            .map(|category| (category.name, category.category_catalog_id))
+            .collect::<BTreeMap<_, _>>();
+        let category_ids = |names: &[String]| -> Result<Vec<Uuid>> {
+            names
+                .iter()
+                .map(|name| catalog.get(name).copied().ok_or_else(|| anyhow!("Unknown category '{name}'")))
+                .collect()
+        };
+
+        for org in request.organizations {
+            let categories = category_ids(&org.categories)?;
+            let domains = org
+                .domains
+                .into_iter()
+                .map(|name| OrganizationDomainRequest { name })
+                .collect();
+            let keycloak_org = self
+                .keycloak
+                .create_organization(token, &org.name, domains, String::new(), "true".to_string(), None)
+                .await
+                .with_context(|| format!("Failed to create organization '{}'", org.name))?;
+            if keycloak_org.id.is_empty() {
+                return Err(anyhow!("Keycloak returned no ID for organization '{}'", org.name));
+            }
+            created.organizations.insert(org.name, keycloak_org.id.clone());
+
+            let weight = if categories.is_empty() { 0 } else { 100 / categories.len() as i32 };
+            for (order, category_id) in categories.into_iter().enumerate() {
+                self.database
+                    .organization_categories
+                    .create_organization_category(Uuid::new_v4(), keycloak_org.id.clone(), category_id, weight, order as i32)
+                    .await?;
+            }
+        }
+
+        for user in request.users {
+            let org_id = user
+                .organization
+                .as_ref()
+                .map(|name| {
+                    created
+                        .organizations
+                        .get(name)
+                        .cloned()
+                        .ok_or_else(|| anyhow!("User '{}' refers to unknown organization '{name}'", user.email))
+                })
+                .transpose()?;
+
+            let keycloak_user = self
+                .keycloak
+                .create_user_with_email_verification(
+                    token,
+                    &CreateUserRequest {
+                        username: user.username.clone().unwrap_or_else(|| user.email.clone()),
+                        email: user.email.clone(),
+                        first_name: user.first_name,
+                        last_name: user.last_name,
+                        email_verified: None,
+                        enabled: Some(true),
+                        attributes: (!user.categories.is_empty())
+                            .then(|| serde_json::json!({ "categories": user.categories })),
+                        credentials: None,
+                        required_actions: None,
+                    },
+                )
+                .await
+                .with_context(|| format!("Failed to create user '{}'", user.email))?;
+            created.users.insert(user.email.clone(), keycloak_user.id.clone());
+
+            // Joining an organization assigns the first role, the rest are assigned directly
+            let mut roles = user.roles.as_slice();
+            if let Some(org_id) = &org_id {
+                self.keycloak
+                    .add_user_to_organization(token, org_id, &user.email, user.roles.clone())
+                    .await
+                    .with_context(|| format!("Failed to add user '{}' to their organization", user.email))?;
+                roles = roles.get(1..).unwrap_or_default();
+            }
+            for role in roles {
+                self.keycloak
+                    .assign_realm_role_to_user(token, &keycloak_user.id, role)
+                    .await
+                    .with_context(|| format!("Failed to assign role '{role}' to user '{}'", user.email))?;
+            }
+        }
+
+        for assessment in request.assessments {
+            let org_id = created.organizations.get(&assessment.organization).cloned().ok_or_else(|| {
+                anyhow!(
+                    "Assessment '{}' refers to unknown organization '{}'",
+                    assessment.name,
+                    assessment.organization
+                )
+            })?;
+            let categories = category_ids(&assessment.categories)?;
+            let model = self
+                .database
+                .assessments
+                .create_assessment(org_id.clone(), assessment.language, assessment.name, categories)
+                .await?;
+            created.assessments.push(SeededAssessment {
+                assessment_id: model.assessment_id,
+                org_id,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Delete everything seeded so far, assessments first. Items that fail to
+    /// delete are reported and kept, so a later rollback can retry them.
+    pub async fn rollback(&self, token: &str) -> RollbackResult {
+        let mut seeded = self.seeded.lock().await;
+        let mut result = RollbackResult::default();
+        let mut kept = SeededData::default();
+
+        for assessment in std::mem::take(&mut seeded.assessments) {
+            match self
+                .database
+                .assessments
+                .delete_draft_assessment(assessment.assessment_id, &assessment.org_id)
+                .await
+            {
+                Ok(object_keys) => {
+                    self.database.file.delete_stored_objects(&object_keys).await;
+                    result.deleted.assessments.push(assessment);
+                }
+                Err(e) => {
+                    result.errors.push(format!("assessment {}: {e}", assessment.assessment_id));
+                    kept.assessments.push(assessment);
+                }
+            }
+        }
+
+        for (email, user_id) in std::mem::take(&mut seeded.users) {
+            match self.keycloak.delete_user(token, &user_id).await {
+                Ok(()) => {
+                    result.deleted.users.insert(email, user_id);
+                }
+                Err(e) => {
+                    result.errors.push(format!("user {email}: {e}"));
+                    kept.users.insert(email, user_id);
+                }
+            }
+        }
+
+        for (name, org_id) in std::mem::take(&mut seeded.organizations) {
+            let deleted = async {
+                self.database
+                    .organization_categories
+                    .delete_organization_categories_by_keycloak_organization_id(&org_id)
+                    .await?;
+                self.keycloak.delete_organization(token, &org_id).await
+            };
+            match deleted.await {
+                Ok(()) => {
+                    result.deleted.organizations.insert(name, org_id);
+                }
+                Err(e) => {
+                    result.errors.push(format!("organization {name}: {e}"));
+                    kept.organizations.insert(name, org_id);
+                }
+            }
+        }
+
+        if !result.errors.is_empty() {
+            warn!(errors = ?result.errors, "Seed rollback left some data behind");
+        }
+        *seeded = kept;
+        result
+    }
+}
@@ -0,0 +1,339 @@
+//! Questions shown or skipped depending on earlier answers.
+//!
+//! `questions.skip_rules` holds a list of `{ "if": { "question_id", "value" }, "then":
+//! "skip" | "show" }` objects. A question is skipped when one of its "skip" rules
+//! matches, or when it has "show" rules and none of them matches. A rule matches when
+//! its question is not skipped itself and was answered with `value`, so skipping a
+//! question also drops the rules that depend on its answer. Answers are compared
+//! case-insensitively, as plain text, JSON strings, elements of JSON arrays, or the
+//! `yesNo` ("Yes"/"No") and `text` fields of answer objects.
+//!
+//! Rules that refer to each other in a circle are rejected when saved, see
+//! [`SkipLogicEngine::find_cycle`]. Any that still get evaluated treat the question
+//! closing the circle as visible.
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::common::database::entity::questions;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SkipAction {
+    Skip,
+    Show,
+}
+
+/// The answer a rule waits for
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SkipCondition {
+    pub question_id: Uuid,
+    pub value: String,
+}
+
+/// One rule as stored in `questions.skip_rules`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct QuestionSkipRule {
+    #[serde(rename = "if")]
+    pub condition: SkipCondition,
+    #[serde(rename = "then")]
+    pub action: SkipAction,
+}
+
+/// A rule together with the question it applies to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkipRule {
+    pub question_id: Uuid,
+    pub condition: SkipCondition,
+    pub action: SkipAction,
+}
+
+impl QuestionSkipRule {
+    /// Rules in a `skip_rules` column; rules that cannot be read are left out
+    pub fn parse(skip_rules: &Value) -> Vec<QuestionSkipRule> {
+        let rules = match skip_rules {
+            Value::Array(rules) => rules.as_slice(),
+            Value::Object(_) => std::slice::from_ref(skip_rules),
+            _ => &[],
+        };
+        rules
+            .iter()
+            .filter_map(|rule| match serde_json::from_value(rule.clone()) {
+                Ok(rule) => Some(rule),
+                Err(e) => {
+                    tracing::warn!(rule = %rule, error = %e, "Ignoring unreadable skip rule");
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+impl SkipRule {
+    pub fn new(question_id: Uuid, rule: QuestionSkipRule) -> Self {
+        Self {
+            question_id,
+            condition: rule.condition,
+            action: rule.action,
+        }
+    }
+
+    /// Rules of all `questions`
+    pub fn from_questions(questions: &[questions::Model]) -> Vec<SkipRule> {
+        questions
+            .iter()
+            .flat_map(|question| {
+                QuestionSkipRule::parse(&question.skip_rules)
+                    .into_iter()
+                    .map(|rule| Self::new(question.question_id, rule))
+            })
+            .collect()
+    }
+}
+
+pub struct SkipLogicEngine;
+
+impl SkipLogicEngine {
+    /// Questions skipped given `responses`, keyed by question ID
+    pub fn evaluate(rules: &[SkipRule], responses: &HashMap<Uuid, String>) -> HashSet<Uuid> {
+        let by_question = Self::by_question(rules);
+        let mut state = HashMap::new();
+        for question_id in by_question.keys() {
+            Self::visit(*question_id, &by_question, responses, &mut state);
+        }
+
+        state
+            .into_iter()
+            .filter_map(|(question_id, skipped)| (skipped == Some(true)).then_some(question_id))
+            .collect()
+    }
+
+    /// A circle of questions whose rules depend on each other, starting and ending
+    /// with the same question, if there is one
+    pub fn find_cycle(rules: &[SkipRule]) -> Option<Vec<Uuid>> {
+        let by_question = Self::by_question(rules);
+        let mut done = HashSet::new();
+        let mut path = Vec::new();
+
+        fn walk(
+            question_id: Uuid,
+            by_question: &HashMap<Uuid, Vec<&SkipRule>>,
+            done: &mut HashSet<Uuid>,
+            path: &mut Vec<Uuid>,
+        ) -> Option<Vec<Uuid>> {
+            if let Some(start) = path.iter().position(|id| *id == question_id) {
+                let mut cycle = path[start..].to_vec();
+                cycle.push(question_id);
+                return Some(cycle);
+            }
+            if !done.insert(question_id) {
+                return None;
+            }
+            path.push(question_id);
+            for rule in by_question.get(&question_id).into_iter().flatten() {
+                if let Some(cycle) = walk(rule.condition.question_id, by_question, done, path) {
+                    return Some(cycle);
+                }
+            }
+            path.pop();
+            None
+        }
+
+        let mut question_ids: Vec<Uuid> = by_question.keys().copied().collect();
+        question_ids.sort();
+        question_ids
+            .into_iter()
+            .find_map(|question_id| walk(question_id, &by_question, &mut done, &mut path))
+    }
+
+    fn by_question(rules: &[SkipRule]) -> HashMap<Uuid, Vec<&SkipRule>> {
+        let mut by_question: HashMap<Uuid, Vec<&SkipRule>> = HashMap::new();
+        for rule in rules {
+            by_question.entry(rule.question_id).or_default().push(rule);
+        }
+        by_question
+    }
+
+    /// Whether `question_id` is skipped. `state` holds `None` while a question is being
+    /// decided, which is how circles are noticed.
+    fn visit(
+        question_id: Uuid,
+        by_question: &HashMap<Uuid, Vec<&SkipRule>>,
+        responses: &HashMap<Uuid, String>,
+        state: &mut HashMap<Uuid, Option<bool>>,
+    ) -> bool {
+        match state.get(&question_id) {
+            Some(Some(skipped)) => return *skipped,
+            // Part of a circle
+            Some(None) => return false,
+            None => {}
+        }
+        let Some(rules) = by_question.get(&question_id) else {
+            return false;
+        };
+        state.insert(question_id, None);
+
+        let mut skipped = false;
+        let mut has_show_rules = false;
+        let mut shown = false;
+        for rule in rules {
+            let condition = &rule.condition;
+            let matches = !Self::visit(condition.question_id, by_question, responses, state)
+                && responses
+                    .get(&condition.question_id)
+                    .is_some_and(|response| answer_matches(response, &condition.value));
+            match rule.action {
+                SkipAction::Skip => skipped |= matches,
+                SkipAction::Show => {
+                    has_show_rules = true;
+                    shown |= matches;
+                }
+            }
+        }
+        let skipped = skipped || (has_show_rules && !shown);
+
+        state.insert(question_id, Some(skipped));
+        skipped
+    }
+}
+
+/// Whether `response` holds `value`, see the module documentation
+fn answer_matches(response: &str, value: &str) -> bool {
+    let value = value.trim();
+    let matches = |candidate: &str| candidate.trim().eq_ignore_ascii_case(value);
+
+    if matches(response) {
+        return true;
+    }
+    match serde_json::from_str::<Value>(response) {
+        Ok(Value::String(text)) => matches(&text),
+        Ok(Value::Array(items)) => items.iter().filter_map(Value::as_str).any(matches),
+        Ok(Value::Object(answer)) => {
+            let yes_no = answer
+                .get("yesNo")
+                .and_then(Value::as_bool)
+                .is_some_and(|yes| matches(if yes { "Yes" } else { "No" }));
+            yes_no || answer.get("text").and_then(Value::as_str).is_some_and(matches)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(question_id: Uuid, if_question: Uuid, value: &str, action: SkipAction) -> SkipRule {
+        SkipRule {
+            question_id,
+            condition: SkipCondition {
+                question_id: if_question,
+                value: value.to_string(),
+            },
+            action,
+        }
+    }
+
+    fn ids<const N: usize>() -> [Uuid; N] {
+        std::array::from_fn(|_| Uuid::new_v4())
+    }
+
+    #[test]
+    fn test_no_rules_skip_nothing() {
+        let [q1] = ids();
+        let responses = HashMap::from([(q1, "No".to_string())]);
+
+        assert!(SkipLogicEngine::evaluate(&[], &responses).is_empty());
+        assert_eq!(SkipLogicEngine::find_cycle(&[]), None);
+    }
+
+    #[test]
+    fn test_skip_and_show_rules() {
+        let [q1, q2, q3] = ids();
+        // If Q1 is "No" skip Q2; show Q3 only if Q1 is "Yes"
+        let rules = [
+            rule(q2, q1, "No", SkipAction::Skip),
+            rule(q3, q1, "Yes", SkipAction::Show),
+        ];
+
+        let skipped = SkipLogicEngine::evaluate(&rules, &HashMap::from([(q1, "no".to_string())]));
+        assert_eq!(skipped, HashSet::from([q2, q3]));
+
+        let skipped = SkipLogicEngine::evaluate(&rules, &HashMap::from([(q1, r#"{"yesNo":true}"#.to_string())]));
+        assert!(skipped.is_empty());
+
+        // Unanswered: nothing to skip on, and nothing to show on
+        assert_eq!(SkipLogicEngine::evaluate(&rules, &HashMap::new()), HashSet::from([q3]));
+    }
+
+    #[test]
+    fn test_chained_rules() {
+        let [q1, q2, q3, q4] = ids();
+        // Q1 "No" skips Q2, Q2 "Yes" shows Q3, Q3 "Yes" skips Q4
+        let rules = [
+            rule(q2, q1, "No", SkipAction::Skip),
+            rule(q3, q2, "Yes", SkipAction::Show),
+            rule(q4, q3, "Yes", SkipAction::Skip),
+        ];
+        let mut responses = HashMap::from([
+            (q1, "Yes".to_string()),
+            (q2, r#""Yes""#.to_string()),
+            (q3, r#"["Maybe","Yes"]"#.to_string()),
+        ]);
+
+        assert_eq!(SkipLogicEngine::evaluate(&rules, &responses), HashSet::from([q4]));
+
+        // Skipping Q2 drops its answer, which hides Q3, whose answer no longer skips Q4
+        responses.insert(q1, "No".to_string());
+        assert_eq!(SkipLogicEngine::evaluate(&rules, &responses), HashSet::from([q2, q3]));
+        assert_eq!(SkipLogicEngine::find_cycle(&rules), None);
+    }
+
+    #[test]
+    fn test_circular_rules() {
+        let [q1, q2, q3, q4] = ids();
+        let rules = [
+            rule(q2, q1, "No", SkipAction::Skip),
+            rule(q3, q2, "No", SkipAction::Skip),
+            rule(q1, q3, "No", SkipAction::Skip),
+            rule(q4, q3, "Yes", SkipAction::Show),
+        ];
+
+        let cycle = SkipLogicEngine::find_cycle(&rules).unwrap();
+        assert_eq!(cycle.len(), 4);
+        assert_eq!(cycle.first(), cycle.last());
+        assert_eq!(cycle[..3].iter().collect::<HashSet<_>>(), HashSet::from([&q1, &q2, &q3]));
+
+        // Evaluation still ends, and every answer is "No"
+        let responses = HashMap::from([(q1, "No".to_string()), (q2, "No".to_string()), (q3, "No".to_string())]);
+        let skipped = SkipLogicEngine::evaluate(&rules, &responses);
+        assert!(skipped.contains(&q4));
+
+        // A question depending on itself
+        assert_eq!(
+            SkipLogicEngine::find_cycle(&[rule(q1, q1, "No", SkipAction::Skip)]),
+            Some(vec![q1, q1])
+        );
+    }
+
+    #[test]
+    fn test_parse_stored_rules() {
+        let [q1, q2] = ids();
+        let stored = serde_json::json!([
+            { "if": { "question_id": q1, "value": "No" }, "then": "skip" },
+            { "if": { "question_id": q1 }, "then": "hide" },
+        ]);
+
+        let rules = QuestionSkipRule::parse(&stored);
+        assert_eq!(rules.len(), 1);
+        assert_eq!(SkipRule::new(q2, rules[0].clone()), rule(q2, q1, "No", SkipAction::Skip));
+        assert_eq!(QuestionSkipRule::parse(&stored[0]), rules);
+        assert!(QuestionSkipRule::parse(&Value::Null).is_empty());
+    }
+}
@@ -1,14 +1,21 @@
+use crate::common::database::entity::assessment_templates::AssessmentTemplatesService;
 use crate::common::database::entity::assessments::AssessmentsService;
 use crate::common::database::entity::assessments_response::AssessmentsResponseService;
 use crate::common::database::entity::assessments_response_file::AssessmentsResponseFileService;
 use crate::common::database::entity::assessments_submission::AssessmentsSubmissionService;
 use crate::common::database::entity::category_catalog::CategoryCatalogService;
 use crate::common::database::entity::file::FileService;
+use crate::common::database::entity::idempotency_keys::IdempotencyService;
+use crate::common::database::entity::notifications::NotificationsService;
 use crate::common::database::entity::organization_categories::OrganizationCategoriesService;
+use crate::common::database::entity::organizations_cache::OrganizationsCacheService;
 use crate::common::database::entity::questions::QuestionsService;
 use crate::common::database::entity::questions_revisions::QuestionsRevisionsService;
 use crate::common::database::entity::submission_reports::SubmissionReportsService;
+use crate::common::database::entity::submission_timeline::SubmissionTimelineService;
 use crate::common::database::entity::temp_submission::TempSubmissionService;
+use crate::common::services::encryption::EncryptionService;
+use crate::common::services::object_store::ObjectStore;
 use sea_orm::{DatabaseConnection, TransactionTrait};
 use std::sync::Arc;
 
@@ -16,16 +23,21 @@ use std::sync::Arc;
 #[allow(dead_code)]
 pub struct AppDatabase {
     conn: Arc<DatabaseConnection>,
+    pub assessment_templates: AssessmentTemplatesService,
     pub assessments: Arc<AssessmentsService>,
     pub assessments_response: AssessmentsResponseService,
     pub assessments_submission: AssessmentsSubmissionService,
     pub assessments_response_file: AssessmentsResponseFileService,
     pub category_catalog: CategoryCatalogService,
     pub file: FileService,
+    pub idempotency_keys: IdempotencyService,
+    pub notifications: NotificationsService,
     pub organization_categories: OrganizationCategoriesService,
+    pub organizations_cache: OrganizationsCacheService,
     pub questions: QuestionsService,
     pub questions_revisions: QuestionsRevisionsService,
     pub submission_reports: SubmissionReportsService,
+    pub submission_timeline: SubmissionTimelineService,
     pub temp_submission: TempSubmissionService,
 }
 
@@ -33,25 +45,47 @@ pub struct AppDatabase {
 impl AppDatabase {
     pub async fn new(conn: Arc<DatabaseConnection>) -> Self {
         Self {
+            assessment_templates: AssessmentTemplatesService::new(conn.clone()),
             assessments: AssessmentsService::new(conn.clone()),
             assessments_response: AssessmentsResponseService::new(conn.clone()),
             assessments_submission: AssessmentsSubmissionService::new(conn.clone()),
             assessments_response_file: AssessmentsResponseFileService::new(conn.clone()),
             category_catalog: CategoryCatalogService::new(conn.clone()),
             file: FileService::new(conn.clone()),
+            idempotency_keys: IdempotencyService::new(conn.clone()),
+            notifications: NotificationsService::new(conn.clone()),
             organization_categories: OrganizationCategoriesService::new(conn.clone()),
+            organizations_cache: OrganizationsCacheService::new(conn.clone()),
             questions: QuestionsService::new(conn.clone()),
             questions_revisions: QuestionsRevisionsService::new(conn.clone()),
             submission_reports: SubmissionReportsService::new(conn.clone()),
+            submission_timeline: SubmissionTimelineService::new(conn.clone()),
             temp_submission: TempSubmissionService::new(conn.clone()),
             conn,
         }
     }
 
+    /// Keep uploaded file bytes in object storage instead of the `file` table
+    pub fn with_object_store(mut self, object_store: Arc<dyn ObjectStore>) -> Self {
+        self.file = self.file.with_object_store(object_store);
+        self
+    }
+
+    /// Encrypt answers to sensitive questions with `encryption`
+    pub fn with_encryption(mut self, encryption: Arc<EncryptionService>) -> Self {
+        self.assessments_response = self.assessments_response.with_encryption(encryption);
+        self
+    }
+
     pub fn get_connection(&self) -> &DatabaseConnection {
         &self.conn
     }
 
+    /// Close the connection pool, waiting for checked-out connections to be returned
+    pub async fn close(&self) -> Result<(), sea_orm::DbErr> {
+        self.conn.close_by_ref().await
+    }
+
     pub async fn begin_transaction(
         &self,
     ) -> Result<sea_orm::DatabaseTransaction, sea_orm::DbErr> {
@@ -1,2 +1,5 @@
 pub mod common;
 pub mod web;
+
+#[cfg(test)]
+pub(crate) mod test_support;
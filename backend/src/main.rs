@@ -1,35 +1,72 @@
+use std::sync::Arc;
 use sustainability_tool::{
-    common::config::Configs,
+    common::build_info::BuildInfo,
+    common::config::{Configs, DatabaseConfig, EncryptionConfig, MigrationsConfig, StorageConfig},
+    common::logging::{init_tracing, record_service_fields},
     common::database::init::initialize_database,
+    common::migrations::MigrationStatus,
+    common::services::email::EmailService,
+    common::services::encryption::EncryptionService,
+    common::services::invitation_expiry::spawn_invitation_expiry,
+    common::services::object_store::S3ObjectStore,
+    common::services::organizations_cache::spawn_organizations_cache_refresh,
     common::state::AppDatabase,
     web::routes::{create_app, AppState},
+    web::shutdown::{serve_until, shutdown_signal},
 };
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+use tracing::{field::Empty, Instrument};
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    startup().await
+    // Initialize tracing, as text or JSON depending on RUST_LOG_FORMAT
+    init_tracing();
+
+    let service_span = tracing::info_span!("service", service.name = Empty, service.version = Empty);
+    record_service_fields(&service_span);
+
+    startup().instrument(service_span).await
 }
 
 async fn startup() -> Result<(), Box<dyn std::error::Error>> {
-    // Initialize tracing
-    tracing_subscriber::registry()
-        .with(tracing_subscriber::EnvFilter::new(
-            std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into()),
-        ))
-        .with(tracing_subscriber::fmt::layer())
-        .init();
-
     // Load configuration
-    let config = Configs::new()?;
+    let config = Configs::load().await?;
 
-    tracing::info!("Starting Sustainability Tool backend server");
+    let build_info = BuildInfo::CURRENT;
+    tracing::info!(
+        version = build_info.version,
+        git_commit = build_info.git_commit,
+        build_timestamp = build_info.build_timestamp,
+        rust_version = build_info.rust_version,
+        "Starting Sustainability Tool backend server"
+    );
 
     // Initialize application database
-    let app_db = initialize_app().await?;
+    let app_db = initialize_app(&config.database, &config.storage, &config.migrations, &config.encryption).await?;
+    let pending_migrations = MigrationStatus::check_pending(app_db.get_connection()).await?;
 
     // Initialize application state
-    let app_state = AppState::new(config.keycloak.clone(), app_db).await;
+    let app_state = AppState::new(config.keycloak.clone(), app_db.clone())
+        .await
+        .with_rate_limit(&config.rate_limit)
+        .with_upload_config(config.upload.clone())
+        .with_limits_config(config.limits.clone())
+        .with_locale_config(config.locale.clone())
+        .with_strict_request_validation(config.server.strict_request_validation)
+        .with_pending_migrations(&pending_migrations)
+        .with_email_service(EmailService::from_config(&config.email)?);
+
+    // Pick up rotated token signing keys ahead of the tokens using them
+    app_state.jwt_validator.spawn_key_refresh();
+
+    // Keep the local copy of Keycloak's organizations up to date
+    spawn_organizations_cache_refresh(
+        app_state.keycloak_service.clone(),
+        app_state.database.organizations_cache.clone(),
+        &config.sync,
+    );
+
+    // Delete organization invitations nobody accepted
+    spawn_invitation_expiry(app_state.keycloak_service.clone(), &config.sync);
 
     // Create the application with all routes and middleware
     let app = create_app(app_state, config.clone());
@@ -39,18 +76,41 @@ async fn startup() -> Result<(), Box<dyn std::error::Error>> {
     let listener = tokio::net::TcpListener::bind(&bind_address).await?;
     tracing::info!("Server started and listening");
 
-    axum::serve(listener, app).await?;
+    // Stop on SIGTERM/SIGINT once in-flight requests have finished
+    serve_until(listener, app, shutdown_signal()).await?;
+
+    tracing::info!("Closing database connections");
+    app_db.close().await?;
+    tracing::info!("Shutdown complete");
 
     Ok(())
 }
 
-async fn initialize_app() -> Result<AppDatabase, Box<dyn std::error::Error>> {
+async fn initialize_app(
+    database: &DatabaseConfig,
+    storage: &StorageConfig,
+    migrations: &MigrationsConfig,
+    encryption: &EncryptionConfig,
+) -> Result<AppDatabase, Box<dyn std::error::Error>> {
     // Initialize database connection
-    let conn = initialize_database().await?;
+    let conn = initialize_database(&database.url, migrations.auto_run).await?;
     tracing::info!("Database connection established successfully");
 
     // Initialize the application database state
-    let app_db = AppDatabase::new(conn).await;
+    let mut app_db = AppDatabase::new(conn).await;
+
+    match storage.backend.as_str() {
+        "database" => {}
+        "s3" => {
+            let object_store = S3ObjectStore::from_config(storage).await?;
+            app_db = app_db.with_object_store(Arc::new(object_store));
+        }
+        other => return Err(format!("Unknown STORAGE_BACKEND: {other}").into()),
+    }
+    match EncryptionService::from_config(encryption)? {
+        Some(encryption) => app_db = app_db.with_encryption(Arc::new(encryption)),
+        None => tracing::warn!("ENCRYPTION_KEY_BASE64 is not set, answers to sensitive questions cannot be saved"),
+    }
     tracing::info!("Application initialized successfully");
 
     Ok(app_db)
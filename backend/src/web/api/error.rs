@@ -4,6 +4,7 @@ use axum::{
     Json,
 };
 use serde_json::json;
+use std::collections::BTreeMap;
 
 #[derive(Debug)]
 pub enum ApiError {
@@ -11,17 +12,113 @@ pub enum ApiError {
     NotFound(String),
     Forbidden(String),
     Conflict(String),
+    PreconditionFailed(String),
+    PreconditionRequired(String),
+    PayloadTooLarge(String),
+    UnsupportedMediaType(String),
+    TooManyRequests(String),
     InternalServerError(String),
     DatabaseError(String),
+    /// 400 listing every bad field, see [`ValidationErrors`]
+    Validation(ValidationErrors),
+}
+
+/// Field-level problems of a request, collected so that all of them are reported
+/// at once instead of only the first
+#[derive(Debug, Default)]
+pub struct ValidationErrors {
+    fields: BTreeMap<String, Vec<String>>,
+    summary: String,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        let message = message.into();
+        if !self.summary.is_empty() {
+            self.summary.push_str("; ");
+        }
+        self.summary.push_str(&message);
+        self.fields.entry(field.to_string()).or_default().push(message);
+    }
+
+    /// `message` for `field` when `value` is missing or blank
+    pub fn require(&mut self, field: &str, value: Option<&str>, message: &str) {
+        if value.map(str::trim).unwrap_or_default().is_empty() {
+            self.add(field, message);
+        }
+    }
+
+    /// Problem with `field` when `value` is not an email address
+    pub fn email(&mut self, field: &str, value: &str) {
+        if value.trim().is_empty() {
+            self.add(field, "Email is required");
+        } else if !value.contains('@') || !value.contains('.') {
+            self.add(field, "Invalid email format");
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Messages of each bad field
+    pub fn fields(&self) -> &BTreeMap<String, Vec<String>> {
+        &self.fields
+    }
+
+    /// `Ok` when nothing was added
+    pub fn into_result(self) -> Result<(), ApiError> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(ApiError::Validation(self))
+        }
+    }
+}
+
+impl ApiError {
+    /// Message sent to the client in the `error` field
+    pub fn message(&self) -> &str {
+        match self {
+            ApiError::BadRequest(message)
+            | ApiError::NotFound(message)
+            | ApiError::Forbidden(message)
+            | ApiError::Conflict(message)
+            | ApiError::PreconditionFailed(message)
+            | ApiError::PreconditionRequired(message)
+            | ApiError::PayloadTooLarge(message)
+            | ApiError::UnsupportedMediaType(message)
+            | ApiError::TooManyRequests(message)
+            | ApiError::InternalServerError(message)
+            | ApiError::DatabaseError(message) => message,
+            ApiError::Validation(errors) => &errors.summary,
+        }
+    }
 }
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
         let (status, error_message) = match self {
+            ApiError::Validation(errors) => {
+                let body = Json(json!({
+                    "error": errors.summary,
+                    "fields": errors.fields,
+                }));
+                return (StatusCode::BAD_REQUEST, body).into_response();
+            }
             ApiError::BadRequest(message) => (StatusCode::BAD_REQUEST, message),
             ApiError::NotFound(message) => (StatusCode::NOT_FOUND, message),
             ApiError::Conflict(message) => (StatusCode::CONFLICT, message),
             ApiError::Forbidden(message) => (StatusCode::FORBIDDEN, message),
+            ApiError::PreconditionFailed(message) => (StatusCode::PRECONDITION_FAILED, message),
+            ApiError::PreconditionRequired(message) => (StatusCode::PRECONDITION_REQUIRED, message),
+            ApiError::PayloadTooLarge(message) => (StatusCode::PAYLOAD_TOO_LARGE, message),
+            ApiError::UnsupportedMediaType(message) => (StatusCode::UNSUPPORTED_MEDIA_TYPE, message),
+            ApiError::TooManyRequests(message) => (StatusCode::TOO_MANY_REQUESTS, message),
             ApiError::InternalServerError(message) => (StatusCode::INTERNAL_SERVER_ERROR, message),
             ApiError::DatabaseError(message) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -1,17 +1,31 @@
 use crate::web::routes::AppState;
-use crate::web::api::error::ApiError;
+use crate::web::api::error::{ApiError, ValidationErrors};
+use crate::web::api::strict_json::StrictJson;
 use crate::web::api::models::{
-    AdminAssessmentInfo, AdminResponseDetail, AdminSubmissionContent, AdminSubmissionDetail,
-    AdminSubmissionListResponse,
+    AdminAssessmentInfo, AdminDashboardResponse, AdminResponseDetail, AdminSubmissionContent, AdminSubmissionDetail,
+    AdminSubmissionListResponse, AdminUserAssessmentsResponse, AppliedMigrationInfo,
+    AssessmentSummary, MigrationStatusResponse, TransferStep, TransferStepStatus,
+    UserTransferRequest, UserTransferResponse,
 };
+use crate::common::database::entity::assessments::UnlockError;
+use crate::common::database::entity::assessments_submission::{FilteredSubmissionQuery, SubmissionStatus};
+use crate::common::locale::select_localized_text;
+use crate::common::migrations::Migrator;
+use crate::common::models::admin_event::AdminEvent;
 use crate::common::models::claims::Claims;
 use crate::common::models::keycloak::{UserInvitationRequest, UserInvitationResponse, UserInvitationStatus};
+use crate::common::services::keycloak_service::is_not_found;
 use axum::{
     extract::{Path, Query, State, Extension},
     http::StatusCode,
+    response::sse::{Event, KeepAlive, Sse},
     Json,
 };
+use futures::Stream;
+use sea_orm::Iterable;
 use serde::Deserialize;
+use std::convert::Infallible;
+use tokio::sync::broadcast::error::RecvError;
 use uuid::Uuid;
 
 // Helper function to extract token from request extensions
@@ -21,7 +35,15 @@ fn get_token_from_extensions(token: &str) -> Result<String, ApiError> {
 
 #[derive(Deserialize)]
 pub struct ListSubmissionsQuery {
-    status: Option<String>,
+    status: Option<SubmissionStatus>,
+    /// Only this organization's submissions
+    org_id: Option<String>,
+    /// Part of the organization name, any case
+    org_name: Option<String>,
+    /// Part of the assessment name, any case
+    assessment_name: Option<String>,
+    submitted_after: Option<chrono::DateTime<chrono::Utc>>,
+    submitted_before: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 pub async fn list_all_submissions(
@@ -30,11 +52,26 @@ pub async fn list_all_submissions(
     Extension(token): Extension<String>,
     Query(params): Query<ListSubmissionsQuery>,
 ) -> Result<Json<AdminSubmissionListResponse>, ApiError> {
-    // Fetch all submissions from the database
+    if let (Some(after), Some(before)) = (params.submitted_after, params.submitted_before) {
+        if after > before {
+            return Err(ApiError::BadRequest(
+                "submitted_after must not be later than submitted_before".to_string(),
+            ));
+        }
+    }
+
+    // Fetch the matching submissions from the database
     let submission_models = app_state
         .database
         .assessments_submission
-        .get_all_submissions()
+        .list_submissions_filtered(&FilteredSubmissionQuery {
+            org_id: params.org_id.clone(),
+            org_name: params.org_name.clone(),
+            assessment_name: params.assessment_name.clone(),
+            status: params.status.clone(),
+            submitted_after: params.submitted_after,
+            submitted_before: params.submitted_before,
+        })
         .await
         .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submissions: {e}")))?;
 
@@ -57,184 +94,233 @@ pub async fn list_all_submissions(
     // Convert database models to API models
     let mut submissions = Vec::new();
     for model in submission_models {
-        // Parse the content to extract assessment and responses information
-        let default_map = serde_json::Map::new();
-        let content_obj = model.content.as_object().unwrap_or(&default_map);
+        // Get organization name from the map, fallback to org_id if not found
+        let org_name = org_map.get(&model.org_id)
+            .cloned()
+            .unwrap_or_else(|| format!("Unknown Organization ({})", model.org_id));
 
-        // Extract assessment info
-        let assessment_info = content_obj
-            .get("assessment")
-            .and_then(|a| a.as_object())
-            .map(|a| AdminAssessmentInfo {
-                assessment_id: a
-                    .get("assessment_id")
-                    .and_then(|id| id.as_str())
-                    .and_then(|s| Uuid::parse_str(s).ok())
-                    .unwrap_or(model.submission_id),
-                language: a
-                    .get("language")
-                    .and_then(|l| l.as_str())
-                    .unwrap_or("en")
-                    .to_string(),
-            })
-            .unwrap_or(AdminAssessmentInfo {
-                assessment_id: model.submission_id,
-                language: "en".to_string(),
-            });
+        submissions.push(to_admin_submission_detail(&app_state, model, org_name).await?);
+    }
 
-        // Extract responses info
-        let mut responses = Vec::new();
-        if let Some(responses_array) = content_obj.get("responses").and_then(|r| r.as_array()) {
-            for response_obj in responses_array.iter().filter_map(|r| r.as_object()) {
-                // Extract file metadata from the response
-                let files = response_obj
-                    .get("files")
-                    .and_then(|f| f.as_array())
-                    .map(|files_array| {
-                        files_array
-                            .iter()
-                            .filter_map(|f| f.as_object())
-                            .filter_map(|file_obj| {
-                                // Convert JSON file metadata to FileMetadata struct
-                                let file_id = file_obj
-                                    .get("file_id")
-                                    .and_then(|id| id.as_str())
-                                    .and_then(|s| Uuid::parse_str(s).ok())?;
+    Ok(Json(AdminSubmissionListResponse { submissions }))
+}
 
-                                Some(crate::web::api::models::FileMetadata {
-                                    file_id,
-                                    filename: file_obj
-                                        .get("filename")
-                                        .and_then(|f| f.as_str())
-                                        .unwrap_or("unknown")
-                                        .to_string(),
-                                    size: file_obj
-                                        .get("size")
-                                        .and_then(|s| s.as_i64())
-                                        .unwrap_or(0),
-                                    content_type: file_obj
-                                        .get("content_type")
-                                        .and_then(|ct| ct.as_str())
-                                        .unwrap_or("application/octet-stream")
-                                        .to_string(),
-                                    created_at: file_obj
-                                        .get("created_at")
-                                        .and_then(|ca| ca.as_str())
-                                        .unwrap_or(&chrono::Utc::now().to_rfc3339())
-                                        .to_string(),
-                                    metadata: file_obj.get("metadata").cloned(),
-                                })
+/// A submission as shown to application admins: decrypted, with the text and category of
+/// every answered question resolved
+async fn to_admin_submission_detail(
+    app_state: &AppState,
+    mut model: crate::common::database::entity::assessments_submission::Model,
+    org_name: String,
+) -> Result<AdminSubmissionDetail, ApiError> {
+    app_state
+        .database
+        .assessments_response
+        .decrypt_content(&mut model.content)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decrypt responses: {e}")))?;
+
+    // Parse the content to extract assessment and responses information
+    let default_map = serde_json::Map::new();
+    let content_obj = model.content.as_object().unwrap_or(&default_map);
+
+    // Extract assessment info
+    let assessment_info = content_obj
+        .get("assessment")
+        .and_then(|a| a.as_object())
+        .map(|a| AdminAssessmentInfo {
+            assessment_id: a
+                .get("assessment_id")
+                .and_then(|id| id.as_str())
+                .and_then(|s| Uuid::parse_str(s).ok())
+                .unwrap_or(model.submission_id),
+            language: a
+                .get("language")
+                .and_then(|l| l.as_str())
+                .unwrap_or("en")
+                .to_string(),
+        })
+        .unwrap_or(AdminAssessmentInfo {
+            assessment_id: model.submission_id,
+            language: "en".to_string(),
+        });
+
+    // Extract responses info
+    let mut responses = Vec::new();
+    if let Some(responses_array) = content_obj.get("responses").and_then(|r| r.as_array()) {
+        for response_obj in responses_array.iter().filter_map(|r| r.as_object()) {
+            // Extract file metadata from the response
+            let files = response_obj
+                .get("files")
+                .and_then(|f| f.as_array())
+                .map(|files_array| {
+                    files_array
+                        .iter()
+                        .filter_map(|f| f.as_object())
+                        .filter_map(|file_obj| {
+                            // Convert JSON file metadata to FileMetadata struct
+                            let file_id = file_obj
+                                .get("file_id")
+                                .and_then(|id| id.as_str())
+                                .and_then(|s| Uuid::parse_str(s).ok())?;
+
+                            Some(crate::web::api::models::FileMetadata {
+                                file_id,
+                                filename: file_obj
+                                    .get("filename")
+                                    .and_then(|f| f.as_str())
+                                    .unwrap_or("unknown")
+                                    .to_string(),
+                                size: file_obj
+                                    .get("size")
+                                    .and_then(|s| s.as_i64())
+                                    .unwrap_or(0),
+                                content_type: file_obj
+                                    .get("content_type")
+                                    .and_then(|ct| ct.as_str())
+                                    .unwrap_or("application/octet-stream")
+                                    .to_string(),
+                                created_at: file_obj
+                                    .get("created_at")
+                                    .and_then(|ca| ca.as_str())
+                                    .unwrap_or(&chrono::Utc::now().to_rfc3339())
+                                    .to_string(),
+                                metadata: file_obj.get("metadata").cloned(),
                             })
-                            .collect()
-                    })
-                    .unwrap_or_default();
-
-                // Extract question_revision_id
-                let question_revision_id = response_obj
-                    .get("question_revision_id")
-                    .and_then(|id| id.as_str())
-                    .and_then(|s| Uuid::parse_str(s).ok())
-                    .unwrap_or_else(|| Uuid::new_v4()); // fallback to new UUID if parsing fails
-
-                // Fetch question text and category using question_revision_id
-                let (question_text, question_category) = match app_state
-                    .database
-                    .questions_revisions
-                    .get_revision_by_id(question_revision_id)
-                    .await
-                {
-                    Ok(Some(revision)) => {
-                        // Get the question to fetch category
-                        match app_state
-                            .database
-                            .questions
-                            .get_question_by_id(revision.question_id)
-                            .await
-                        {
-                            Ok(Some(question)) => {
-                                // Extract text from JSON (assuming "en" language for now)
-                                let text = revision
-                                    .text
-                                    .get("en")
-                                    .and_then(|t| t.as_str())
-                                    .unwrap_or("Unknown question")
-                                    .to_string();
-                                let category = app_state
-                                    .database
-                                    .category_catalog
-                                    .get_category_catalog_by_id(question.category_id)
-                                    .await
-                                    .ok()
-                                    .flatten()
-                                    .map(|c| c.name)
-                                    .unwrap_or("Unknown".to_string());
-                                (text, category)
-                            }
-                            _ => ("Unknown question".to_string(), "Unknown".to_string()),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            // Extract question_revision_id
+            let question_revision_id = response_obj
+                .get("question_revision_id")
+                .and_then(|id| id.as_str())
+                .and_then(|s| Uuid::parse_str(s).ok())
+                .unwrap_or_else(|| Uuid::new_v4()); // fallback to new UUID if parsing fails
+
+            // Fetch question text and category using question_revision_id
+            let (question_text, question_category) = match app_state
+                .database
+                .questions_revisions
+                .get_revision_by_id(question_revision_id)
+                .await
+            {
+                Ok(Some(revision)) => {
+                    // Get the question to fetch category
+                    match app_state
+                        .database
+                        .questions
+                        .get_question_by_id(revision.question_id)
+                        .await
+                    {
+                        Ok(Some(question)) => {
+                            let text = select_localized_text(
+                                &revision.text,
+                                &assessment_info.language,
+                                &app_state.locale_config.language_fallback,
+                            )
+                            .unwrap_or("Unknown question")
+                            .to_string();
+                            let category = app_state
+                                .database
+                                .category_catalog
+                                .get_category_catalog_by_id(question.category_id)
+                                .await
+                                .ok()
+                                .flatten()
+                                .map(|c| c.name)
+                                .unwrap_or("Unknown".to_string());
+                            (text, category)
                         }
+                        _ => ("Unknown question".to_string(), "Unknown".to_string()),
                     }
-                    _ => ("Unknown question".to_string(), "Unknown".to_string()),
-                };
-
-                // Extract response as string
-                let response = response_obj
-                    .get("response")
-                    .map(|r| {
-                        if let Some(s) = r.as_str() {
-                            s.to_string()
-                        } else {
-                            // If it's not a string, serialize it as JSON
-                            serde_json::to_string(r).unwrap_or_else(|_| "".to_string())
-                        }
-                    })
-                    .unwrap_or_else(|| "".to_string());
-
-                // Extract version
-                let version = response_obj
-                    .get("version")
-                    .and_then(|v| v.as_i64())
-                    .unwrap_or(1) as i32;
-
-                responses.push(AdminResponseDetail {
-                    question_text,
-                    question_category,
-                    response,
-                    version,
-                    files,
-                });
-            }
+                }
+                _ => ("Unknown question".to_string(), "Unknown".to_string()),
+            };
+
+            // Extract response as string
+            let response = response_obj
+                .get("response")
+                .map(|r| {
+                    if let Some(s) = r.as_str() {
+                        s.to_string()
+                    } else {
+                        // If it's not a string, serialize it as JSON
+                        serde_json::to_string(r).unwrap_or_else(|_| "".to_string())
+                    }
+                })
+                .unwrap_or_else(|| "".to_string());
+
+            // Extract version
+            let version = response_obj
+                .get("version")
+                .and_then(|v| v.as_i64())
+                .unwrap_or(1) as i32;
+
+            responses.push(AdminResponseDetail {
+                question_text,
+                question_category,
+                response,
+                version,
+                files,
+            });
         }
+    }
 
-        // Get organization name from the map, fallback to org_id if not found
-        let org_name = org_map.get(&model.org_id)
-            .cloned()
-            .unwrap_or_else(|| format!("Unknown Organization ({})", model.org_id));
+    Ok(AdminSubmissionDetail {
+        submission_id: model.submission_id,
+        assessment_id: model.submission_id,
+        org_id: model.org_id,
+        org_name, // Include organization name
+        content: AdminSubmissionContent {
+            assessment: assessment_info,
+            responses,
+        },
+        review_status: model.status.to_string(),
+        submitted_at: model.submitted_at.to_rfc3339(),
+        reviewed_at: model.reviewed_at.map(|dt| dt.to_rfc3339()),
+    })
+}
 
-        let submission = AdminSubmissionDetail {
-            submission_id: model.submission_id,
-            assessment_id: model.submission_id,
-            org_id: model.org_id,
-            org_name, // Include organization name
-            content: AdminSubmissionContent {
-                assessment: assessment_info,
-                responses,
-            },
-            review_status: model.status.to_string(),
-            submitted_at: model.submitted_at.to_rfc3339(),
-            reviewed_at: model.reviewed_at.map(|dt| dt.to_rfc3339()),
-        };
+/// One submission with the same detail `list_all_submissions` gives for each
+#[utoipa::path(
+    get,
+    path = "/admin/submissions/{submission_id}",
+    tag = "Admin",
+    params(("submission_id" = uuid::Uuid, Path, description = "Submission ID")),
+    responses(
+        (status = 200, description = "Submission detail", body = AdminSubmissionDetail),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 404, description = "Submission not found")
+    )
+)]
+pub async fn get_submission_by_id(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    Path(submission_id): Path<Uuid>,
+) -> Result<Json<AdminSubmissionDetail>, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only application admins can view submissions".to_string()));
+    }
 
-        // Apply status filter if provided
-        if let Some(status) = &params.status {
-            if submission.review_status == *status {
-                submissions.push(submission);
-            }
-        } else {
-            submissions.push(submission);
+    let model = app_state
+        .database
+        .assessments_submission
+        .get_submission_by_assessment_id(submission_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Submission not found".to_string()))?;
+
+    // Fall back to the name stored with the submission if Keycloak is unavailable
+    let org_name = match app_state.keycloak_service.get_organization(&token, &model.org_id).await {
+        Ok(org) => org.name,
+        Err(e) => {
+            tracing::error!("Failed to fetch organization {}: {}", model.org_id, e);
+            model.org_name.clone()
         }
-    }
+    };
 
-    Ok(Json(AdminSubmissionListResponse { submissions }))
+    Ok(Json(to_admin_submission_detail(&app_state, model, org_name).await?))
 }
 
 pub async fn list_temp_submissions_by_assessment(
@@ -281,7 +367,13 @@ pub async fn list_temp_submissions_by_assessment(
     // Convert database models to API models
     let mut submissions = Vec::new();
     
-    for model in temp_submissions {
+    for mut model in temp_submissions {
+        app_state
+            .database
+            .assessments_response
+            .decrypt_content(&mut model.content)
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to decrypt responses: {e}")))?;
+
         // Parse the content to extract assessment and responses information
         let default_map = serde_json::Map::new();
         let content_obj = model.content.as_object().unwrap_or(&default_map);
@@ -369,12 +461,13 @@ pub async fn list_temp_submissions_by_assessment(
                     .await
                 {
                     Ok(Some(revision)) => {
-                        let text = revision
-                            .text
-                            .get("en")
-                            .and_then(|t| t.as_str())
-                            .unwrap_or("Unknown question")
-                            .to_string();
+                        let text = select_localized_text(
+                            &revision.text,
+                            &assessment_info.language,
+                            &app_state.locale_config.language_fallback,
+                        )
+                        .unwrap_or("Unknown question")
+                        .to_string();
 
                         let category = match app_state
                             .database
@@ -452,29 +545,20 @@ pub async fn create_user_invitation(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Extension(token): Extension<String>,
-    Json(request): Json<UserInvitationRequest>,
+    StrictJson(request): StrictJson<UserInvitationRequest>,
 ) -> Result<Json<UserInvitationResponse>, ApiError> {
     // Check if user has admin permissions
     if !claims.is_application_admin() {
         return Err(ApiError::BadRequest("Insufficient permissions".to_string()));
     }
 
-    // Validate email format
-    if !request.email.contains('@') || !request.email.contains('.') {
-        return Err(ApiError::BadRequest("Invalid email format".to_string()));
-    }
-
-    // Validate required fields
-    if request.email.trim().is_empty() || 
-       request.first_name.as_ref().map_or(true, |name| name.trim().is_empty()) || 
-       request.last_name.as_ref().map_or(true, |name| name.trim().is_empty()) {
-        return Err(ApiError::BadRequest("Email, first name, and last name are required".to_string()));
-    }
-
-    // Validate organization ID
-    if request.organization_id.trim().is_empty() {
-        return Err(ApiError::BadRequest("Organization ID is required".to_string()));
-    }
+    // Report every problem with the invitation at once
+    let mut errors = ValidationErrors::new();
+    errors.email("email", &request.email);
+    errors.require("first_name", request.first_name.as_deref(), "First name is required");
+    errors.require("last_name", request.last_name.as_deref(), "Last name is required");
+    errors.require("organization_id", Some(&request.organization_id), "Organization ID is required");
+    errors.into_result()?;
 
     // Generate username from email
     let username = request.email.split('@').next().unwrap_or(&request.email).to_string();
@@ -506,6 +590,11 @@ pub async fn create_user_invitation(
             match app_state.keycloak_service.send_organization_invitation_immediate(&token, &request.organization_id, &user_id, request.roles.clone()).await {
                 Ok(_invitation) => {
                     tracing::info!(user_id = %user_id, org_id = %request.organization_id, "Organization invitation sent immediately");
+                    app_state.publish_admin_event(AdminEvent::UserInvited {
+                        user_id: user_id.clone(),
+                        email: user_email.clone(),
+                        org_id: request.organization_id.clone(),
+                    });
                     
                     let response = UserInvitationResponse {
                         user_id: user.id,
@@ -597,6 +686,107 @@ pub async fn get_user_invitation_status(
     Ok(Json(response))
 }
 
+/// Read the organization a user was invited into from their Keycloak attributes.
+///
+/// Keycloak returns attribute values as arrays, but users created before the
+/// attributes round-tripped through Keycloak may still hold a plain string.
+fn organization_id_from_attributes(attributes: Option<&serde_json::Value>) -> Option<String> {
+    let value = attributes?.get("organization_id")?;
+    let org_id = match value {
+        serde_json::Value::Array(values) => values.first()?.as_str()?,
+        other => other.as_str()?,
+    };
+    (!org_id.trim().is_empty()).then(|| org_id.to_string())
+}
+
+/// List a user's organization assessments for application admins
+#[utoipa::path(
+    get,
+    path = "/admin/users/{user_id}/assessments",
+    tag = "Admin",
+    params(("user_id" = String, Path, description = "Keycloak user ID")),
+    responses(
+        (status = 200, description = "Assessments of the user's organization", body = AdminUserAssessmentsResponse),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn get_user_assessments(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    Path(user_id): Path<String>,
+) -> Result<Json<AdminUserAssessmentsResponse>, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can view user assessments".to_string(),
+        ));
+    }
+
+    let token = get_token_from_extensions(&token)?;
+
+    let user = app_state
+        .keycloak_service
+        .get_user_by_id(&token, &user_id)
+        .await
+        .map_err(|e| {
+            if e.to_string().to_lowercase().contains("not found") {
+                ApiError::NotFound("User not found".to_string())
+            } else {
+                ApiError::InternalServerError(format!("Failed to get user: {e}"))
+            }
+        })?;
+
+    let Some(org_id) = organization_id_from_attributes(user.attributes.as_ref()) else {
+        return Ok(Json(AdminUserAssessmentsResponse {
+            user_id,
+            org_id: None,
+            org_name: None,
+            assessments: Vec::new(),
+        }));
+    };
+
+    let (organization, summaries) = tokio::try_join!(
+        async {
+            app_state
+                .keycloak_service
+                .get_organization(&token, &org_id)
+                .await
+                .map_err(|e| {
+                    ApiError::InternalServerError(format!("Failed to get organization: {e}"))
+                })
+        },
+        async {
+            app_state
+                .database
+                .assessments
+                .get_org_assessment_summaries(&org_id)
+                .await
+                .map_err(|e| {
+                    ApiError::InternalServerError(format!("Failed to fetch assessments: {e}"))
+                })
+        },
+    )?;
+
+    let assessments = summaries
+        .into_iter()
+        .map(|summary| AssessmentSummary {
+            assessment_id: summary.assessment_id,
+            name: summary.name,
+            status: summary.status,
+            submitted_at: summary.submitted_at.map(|dt| dt.to_rfc3339()),
+            has_report: summary.has_report,
+        })
+        .collect();
+
+    Ok(Json(AdminUserAssessmentsResponse {
+        user_id,
+        org_id: Some(org_id),
+        org_name: Some(organization.name),
+        assessments,
+    }))
+}
+
 /// Delete a user entirely from the system
 pub async fn delete_user(
     Extension(claims): Extension<Claims>,
@@ -624,16 +814,396 @@ pub async fn delete_user(
     }
 }
 
+/// Send a user the email verification email again
+#[utoipa::path(
+    post,
+    path = "/admin/users/{user_id}/resend-verification",
+    tag = "Admin",
+    params(("user_id" = String, Path, description = "Keycloak user ID")),
+    responses(
+        (status = 200, description = "Verification email sent"),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 404, description = "User not found"),
+        (status = 409, description = "User has already verified their email")
+    )
+)]
+pub async fn resend_verification_email(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    Path(user_id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can resend verification emails".to_string(),
+        ));
+    }
+
+    let user = app_state.keycloak_service.get_user_by_id(&token, &user_id).await.map_err(|e| {
+        if is_not_found(&e) {
+            ApiError::NotFound("User not found".to_string())
+        } else {
+            ApiError::InternalServerError(format!("Failed to get user: {e}"))
+        }
+    })?;
+    if user.email_verified {
+        return Err(ApiError::Conflict("User has already verified their email".to_string()));
+    }
+
+    app_state
+        .keycloak_service
+        .trigger_email_verification(&token, &user_id)
+        .await
+        .map_err(|e| {
+            tracing::error!(user_id = %user_id, error = %e, "Failed to resend verification email");
+            ApiError::InternalServerError("Failed to send verification email".to_string())
+        })?;
+
+    tracing::info!(user_id = %user_id, "Verification email resent");
+    Ok(StatusCode::OK)
+}
+
+/// Move a user from one organization to another, keeping their categories.
+///
+/// The user joins the target organization first; when that fails nothing has changed.
+/// Leaving the source organization and mapping the categories are then tried in turn,
+/// and the response lists which steps failed.
+#[utoipa::path(
+    post,
+    path = "/admin/users/{user_id}/transfer",
+    tag = "Admin",
+    params(("user_id" = String, Path, description = "Keycloak user ID")),
+    request_body = UserTransferRequest,
+    responses(
+        (status = 200, description = "Steps of the transfer; `completed` is false when one failed", body = UserTransferResponse),
+        (status = 400, description = "Source and target organization are the same, or no role given"),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 404, description = "User not found"),
+        (status = 409, description = "User is not a member of the source organization")
+    )
+)]
+pub async fn transfer_user(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    Path(user_id): Path<String>,
+    StrictJson(request): StrictJson<UserTransferRequest>,
+) -> Result<Json<UserTransferResponse>, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can transfer users".to_string(),
+        ));
+    }
+    if request.from_org == request.to_org {
+        return Err(ApiError::BadRequest("Source and target organization must differ".to_string()));
+    }
+    if request.roles.is_empty() {
+        return Err(ApiError::BadRequest("At least one role must be assigned".to_string()));
+    }
+
+    let keycloak = &app_state.keycloak_service;
+    let user = keycloak.get_user_by_id(&token, &user_id).await.map_err(|e| {
+        if is_not_found(&e) {
+            ApiError::NotFound("User not found".to_string())
+        } else {
+            ApiError::InternalServerError(format!("Failed to get user: {e}"))
+        }
+    })?;
+    let is_member = keycloak
+        .is_user_in_organization(&token, &request.from_org, &user_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to check organization membership: {e}")))?;
+    if !is_member {
+        return Err(ApiError::Conflict("User is not a member of the source organization".to_string()));
+    }
+    let categories = keycloak
+        .get_user_categories_by_id(&token, &user_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to get user categories: {e}")))?;
+
+    keycloak
+        .add_user_to_organization(&token, &request.to_org, &user.email, request.roles.clone())
+        .await
+        .map_err(|e| {
+            tracing::error!(user_id = %user_id, org_id = %request.to_org, error = %e, "Failed to add user to target organization");
+            ApiError::InternalServerError("Failed to add user to the target organization, nothing was changed".to_string())
+        })?;
+    let mut steps = vec![TransferStep {
+        step: "add_to_target_org".to_string(),
+        status: TransferStepStatus::Done,
+        error: None,
+    }];
+    // Whatever happens next, the user's organizations have changed
+    app_state.session_cache.invalidate_user(&user_id);
+
+    let removed = keycloak
+        .remove_user_from_organization(&token, &request.from_org, &user_id)
+        .await;
+    steps.push(transfer_step("remove_from_source_org", removed));
+
+    let mapped_categories: Vec<String> = categories
+        .iter()
+        .map(|category| request.category_mapping.get(category).unwrap_or(category).clone())
+        .collect();
+    let categories = if mapped_categories == categories {
+        categories
+    } else {
+        let mapped = keycloak
+            .set_user_categories_by_id(&token, &user_id, &mapped_categories)
+            .await;
+        let mapped_ok = mapped.is_ok();
+        steps.push(transfer_step("map_categories", mapped));
+        if mapped_ok { mapped_categories } else { categories }
+    };
+
+    let completed = steps.iter().all(|step| step.status == TransferStepStatus::Done);
+    if completed {
+        tracing::info!(user_id = %user_id, from_org = %request.from_org, to_org = %request.to_org, "User transferred");
+    } else {
+        tracing::warn!(user_id = %user_id, from_org = %request.from_org, to_org = %request.to_org, "User transfer partially failed");
+    }
+
+    Ok(Json(UserTransferResponse {
+        user_id,
+        from_org: request.from_org,
+        to_org: request.to_org,
+        completed,
+        steps,
+        categories,
+    }))
+}
+
+fn transfer_step(step: &str, result: anyhow::Result<()>) -> TransferStep {
+    match result {
+        Ok(()) => TransferStep {
+            step: step.to_string(),
+            status: TransferStepStatus::Done,
+            error: None,
+        },
+        Err(e) => TransferStep {
+            step: step.to_string(),
+            status: TransferStepStatus::Failed,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+/// Headline numbers for application admins, each from a single count query
+#[utoipa::path(
+    get,
+    path = "/admin/dashboard",
+    tag = "Admin",
+    responses(
+        (status = 200, description = "Aggregate counts", body = AdminDashboardResponse),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn get_admin_dashboard(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+) -> Result<Json<AdminDashboardResponse>, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can view the dashboard".to_string(),
+        ));
+    }
+
+    let database = &app_state.database;
+    let total_organizations = database
+        .organizations_cache
+        .count(None)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to count organizations: {e}")))?;
+    let total_users = app_state
+        .keycloak_service
+        .count_users(&token)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to count users: {e}")))?;
+    let status_counts = database
+        .assessments_submission
+        .count_by_status()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to count submissions: {e}")))?;
+    let total_reports = database
+        .submission_reports
+        .count_reports()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to count reports: {e}")))?;
+    let overdue_cutoff = chrono::Utc::now()
+        - chrono::Duration::days(i64::from(app_state.limits_config.assessment_overdue_days));
+    let overdue_assessments = database
+        .assessments
+        .count_unsubmitted_before(overdue_cutoff)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to count overdue assessments: {e}")))?;
+
+    let mut submissions_by_status: std::collections::BTreeMap<String, u64> = SubmissionStatus::iter()
+        .map(|status| (status.as_str().to_string(), 0))
+        .collect();
+    for (status, count) in status_counts {
+        submissions_by_status.insert(status.as_str().to_string(), count.max(0) as u64);
+    }
+
+    Ok(Json(AdminDashboardResponse {
+        total_organizations,
+        total_users,
+        total_submissions: submissions_by_status.values().sum(),
+        submissions_by_status,
+        total_reports,
+        overdue_assessments,
+    }))
+}
+
+/// Report applied and pending database migrations for application admins. Mounted
+/// outside the pending migrations gate, so operators applying migrations by hand
+/// while `MIGRATIONS_AUTO_RUN` is off can follow them.
+#[utoipa::path(
+    get,
+    path = "/admin/migrations/status",
+    tag = "Admin",
+    responses(
+        (status = 200, description = "Applied, pending and unknown migrations", body = MigrationStatusResponse),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn get_migration_status(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<MigrationStatusResponse>, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can view migration status".to_string(),
+        ));
+    }
+
+    let status = Migrator::migration_status(app_state.database.get_connection())
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to read migration status: {e}")))?;
+
+    let applied = status
+        .applied
+        .into_iter()
+        .map(|migration| AppliedMigrationInfo {
+            name: migration.name,
+            applied_at: chrono::DateTime::from_timestamp(migration.applied_at, 0)
+                .map(|dt| dt.to_rfc3339())
+                .unwrap_or_default(),
+        })
+        .collect();
+
+    Ok(Json(MigrationStatusResponse {
+        applied,
+        pending: status.pending,
+        unknown: status.unknown,
+    }))
+}
+
+/// Stream admin notifications as server-sent events, one JSON `AdminEvent` per event.
+/// Events published while nobody is subscribed are not replayed.
+#[utoipa::path(
+    get,
+    path = "/admin/events/stream",
+    tag = "Admin",
+    responses(
+        (status = 200, description = "Event stream of AdminEvent objects", content_type = "text/event-stream", body = AdminEvent),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn stream_admin_events(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can stream admin events".to_string(),
+        ));
+    }
+
+    let events = futures::stream::unfold(app_state.admin_events.subscribe(), |mut receiver| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) => {
+                    let event = Event::default().json_data(&event).unwrap_or_default();
+                    return Some((Ok(event), receiver));
+                }
+                Err(RecvError::Lagged(skipped)) => {
+                    tracing::warn!(skipped, "Admin event subscriber fell behind, events were dropped");
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    Ok(Sse::new(events).keep_alive(KeepAlive::default()))
+}
+
+/// Reopen a submitted assessment so its organization can correct responses
+#[utoipa::path(
+    post,
+    path = "/admin/assessments/{assessment_id}/unlock",
+    tag = "Admin",
+    params(("assessment_id" = uuid::Uuid, Path, description = "Assessment ID")),
+    responses(
+        (status = 204, description = "Submission removed, assessment is a draft again"),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 404, description = "Assessment not found"),
+        (status = 409, description = "Assessment has not been submitted")
+    )
+)]
+pub async fn unlock_assessment(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(assessment_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can unlock assessments".to_string(),
+        ));
+    }
+
+    let assessment = app_state
+        .database
+        .assessments
+        .unlock_assessment(assessment_id)
+        .await
+        .map_err(|e| match e {
+            UnlockError::NotFound => ApiError::NotFound("Assessment not found".to_string()),
+            UnlockError::NotLocked => ApiError::Conflict("Assessment has not been submitted".to_string()),
+            UnlockError::Database(e) => ApiError::InternalServerError(format!("Failed to unlock assessment: {e}")),
+        })?;
+
+    // The organization's members must see the assessment as a draft again
+    app_state.session_cache.invalidate_assessment(&assessment.org_id, &assessment_id);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{claims, keycloak_config, serve};
     use crate::common::database::entity::questions::QuestionsService;
     use crate::common::database::entity::questions_revisions::QuestionsRevisionsService;
     use sea_orm::{DatabaseBackend, MockDatabase};
     use serde_json::json;
     use uuid::Uuid;
+    use std::collections::HashMap;
     use std::sync::Arc;
 
+    #[test]
+    fn test_organization_id_from_attributes() {
+        let keycloak_style = serde_json::json!({ "organization_id": ["org-1"] });
+        assert_eq!(organization_id_from_attributes(Some(&keycloak_style)), Some("org-1".to_string()));
+
+        let plain = serde_json::json!({ "organization_id": "org-2" });
+        assert_eq!(organization_id_from_attributes(Some(&plain)), Some("org-2".to_string()));
+
+        let missing = serde_json::json!({ "invitation_status": ["active"] });
+        assert_eq!(organization_id_from_attributes(Some(&missing)), None);
+        assert_eq!(organization_id_from_attributes(None), None);
+    }
+
     #[tokio::test]
     async fn test_question_text_and_category_extraction() -> Result<(), Box<dyn std::error::Error>> {
         // Create mock question and revision data
@@ -644,6 +1214,9 @@ mod tests {
             question_id,
             category_id: Uuid::new_v4(),
             created_at: chrono::Utc::now(),
+            external_key: None,
+            sensitive: false,
+            skip_rules: serde_json::json!([]),
         };
 
         let mock_revision = crate::common::database::entity::questions_revisions::Model {
@@ -696,8 +1269,514 @@ mod tests {
         assert_eq!(category_id, mock_question.category_id);
 
         println!("✓ Successfully extracted question_text: {}", text);
-        println!("✓ Successfully extracted question_category: {}", category);
+        println!("✓ Successfully extracted question_category: {}", category_id);
 
         Ok(())
     }
+
+    /// Keycloak stand-in where `verified-user` has verified their email, `missing-user`
+    /// does not exist and everyone else is unverified. Counts verification emails sent.
+    async fn fake_keycloak(sent: Arc<std::sync::atomic::AtomicUsize>) -> String {
+        use axum::{http::StatusCode, response::IntoResponse, routing::{get, post}, Router};
+
+        let app = Router::new()
+            .route(
+                "/admin/realms/test-realm/users/:user_id",
+                get(|Path(user_id): Path<String>| async move {
+                    if user_id == "missing-user" {
+                        return (StatusCode::NOT_FOUND, r#"{"error":"User not found"}"#).into_response();
+                    }
+                    Json(json!({
+                        "id": user_id,
+                        "username": user_id,
+                        "email": format!("{user_id}@example.com"),
+                        "emailVerified": user_id == "verified-user",
+                        "enabled": true,
+                    }))
+                    .into_response()
+                }),
+            )
+            .route(
+                "/admin/realms/test-realm/users/:user_id/send-verify-email",
+                post(move || async move {
+                    sent.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                    StatusCode::NO_CONTENT
+                }),
+            );
+
+        serve(app).await
+    }
+
+    async fn admin_state(keycloak_url: String) -> AppState {
+        use crate::common::state::AppDatabase;
+
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        AppState::new(
+            keycloak_config(keycloak_url),
+            AppDatabase::new(Arc::new(db)).await,
+        )
+        .await
+    }
+
+    async fn resend_verification(user_id: &str) -> (Result<StatusCode, ApiError>, usize) {
+        let sent = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let app_state = admin_state(fake_keycloak(sent.clone()).await).await;
+
+        let result = resend_verification_email(
+            State(app_state),
+            Extension(claims("admin", &["application_admin"])),
+            Extension("test-token".to_string()),
+            Path(user_id.to_string()),
+        )
+        .await;
+        (result, sent.load(std::sync::atomic::Ordering::SeqCst))
+    }
+
+    #[tokio::test]
+    async fn test_user_invitation_reports_every_invalid_field() {
+        use crate::common::models::keycloak::UserInvitationRequest;
+
+        let app_state = admin_state("http://127.0.0.1:1".to_string()).await;
+        let result = create_user_invitation(
+            State(app_state),
+            Extension(claims("admin", &["application_admin"])),
+            Extension("test-token".to_string()),
+            StrictJson(UserInvitationRequest {
+                email: "".to_string(),
+                first_name: None,
+                last_name: Some("Doe".to_string()),
+                organization_id: " ".to_string(),
+                roles: vec!["Org_User".to_string()],
+                categories: None,
+            }),
+        )
+        .await;
+
+        let Err(ApiError::Validation(errors)) = result else {
+            panic!("expected validation errors");
+        };
+        let fields: Vec<&str> = errors.fields().keys().map(String::as_str).collect();
+        assert_eq!(fields, ["email", "first_name", "organization_id"]);
+        assert_eq!(errors.fields()["email"], ["Email is required"]);
+    }
+
+    #[tokio::test]
+    async fn test_resend_verification_email() {
+        let (result, sent) = resend_verification("new-user").await;
+
+        assert_eq!(result.unwrap(), StatusCode::OK);
+        assert_eq!(sent, 1);
+    }
+
+    #[tokio::test]
+    async fn test_resend_verification_to_verified_user_conflicts() {
+        let (result, sent) = resend_verification("verified-user").await;
+
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+        assert_eq!(sent, 0);
+    }
+
+    #[tokio::test]
+    async fn test_resend_verification_to_missing_user_is_not_found() {
+        let (result, _) = resend_verification("missing-user").await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    /// Keycloak stand-in for transfers: user `ada` is a member of `source-org` only and has
+    /// the Environment and Social categories; other users do not exist. Records every change made.
+    async fn fake_transfer_keycloak() -> (String, Arc<std::sync::Mutex<Vec<String>>>) {
+        use axum::{
+            http::{Method, StatusCode, Uri},
+            response::IntoResponse,
+            routing::{get, post},
+            Router,
+        };
+
+        let changes = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let record = {
+            let changes = changes.clone();
+            move |method: Method, uri: Uri| {
+                let changes = changes.clone();
+                async move {
+                    changes.lock().unwrap().push(format!("{method} {}", uri.path()));
+                    StatusCode::NO_CONTENT
+                }
+            }
+        };
+        let user = || json!({
+            "id": "ada",
+            "username": "ada",
+            "email": "ada@example.com",
+            "emailVerified": true,
+            "enabled": true,
+            "attributes": { "categories": ["Environment", "Social"] },
+        });
+
+        let app = Router::new()
+            .route("/admin/realms/test-realm/users", get(move || async move { Json(json!([user()])) }))
+            .route(
+                "/admin/realms/test-realm/users/:user_id",
+                get(move |Path(user_id): Path<String>| async move {
+                    if user_id == "ada" { Json(user()).into_response() } else { StatusCode::NOT_FOUND.into_response() }
+                })
+                .put(record.clone()),
+            )
+            .route(
+                "/admin/realms/test-realm/roles/:role",
+                get(|Path(role): Path<String>| async move { Json(json!({ "name": role })) }),
+            )
+            .route("/admin/realms/test-realm/users/:user_id/role-mappings/realm", post(record.clone()))
+            .route("/admin/realms/test-realm/organizations/:org_id/members", post(record.clone()))
+            .route(
+                "/admin/realms/test-realm/organizations/:org_id/members/:member_id",
+                get(|Path((org_id, _)): Path<(String, String)>| async move {
+                    if org_id == "source-org" { StatusCode::OK } else { StatusCode::NOT_FOUND }
+                })
+                .delete(record),
+            );
+
+        let url = serve(app).await;
+        (url, changes)
+    }
+
+    async fn transfer(user_id: &str, from_org: &str) -> (Result<Json<UserTransferResponse>, ApiError>, Vec<String>) {
+        let (url, changes) = fake_transfer_keycloak().await;
+        let request = UserTransferRequest {
+            from_org: from_org.to_string(),
+            to_org: "target-org".to_string(),
+            roles: vec!["Org_User".to_string()],
+            category_mapping: HashMap::from([("Social".to_string(), "Community".to_string())]),
+        };
+
+        let result = transfer_user(
+            State(admin_state(url).await),
+            Extension(claims("admin", &["application_admin"])),
+            Extension("test-token".to_string()),
+            Path(user_id.to_string()),
+            StrictJson(request),
+        )
+        .await;
+        let changes = changes.lock().unwrap().clone();
+        (result, changes)
+    }
+
+    #[tokio::test]
+    async fn test_transfer_user() {
+        let (result, changes) = transfer("ada", "source-org").await;
+        let response = result.unwrap().0;
+
+        assert!(response.completed);
+        assert_eq!(
+            response.steps.iter().map(|step| (step.step.as_str(), step.status)).collect::<Vec<_>>(),
+            vec![
+                ("add_to_target_org", TransferStepStatus::Done),
+                ("remove_from_source_org", TransferStepStatus::Done),
+                ("map_categories", TransferStepStatus::Done),
+            ]
+        );
+        assert_eq!(response.categories, vec!["Environment", "Community"]);
+        assert_eq!(
+            changes,
+            vec![
+                "POST /admin/realms/test-realm/users/ada/role-mappings/realm",
+                "POST /admin/realms/test-realm/organizations/target-org/members",
+                "DELETE /admin/realms/test-realm/organizations/source-org/members/ada",
+                "PUT /admin/realms/test-realm/users/ada",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transfer_from_organization_user_is_not_in_conflicts() {
+        let (result, changes) = transfer("ada", "other-org").await;
+
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+        assert!(changes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_transfer_missing_user_is_not_found() {
+        let (result, changes) = transfer("ghost", "source-org").await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+        assert!(changes.is_empty());
+    }
+
+    /// Two organizations, Keycloak reporting 7 users, and four assessments: two
+    /// unsubmitted (one 40 days old), one under review and one approved with a report
+    async fn dashboard_state() -> AppState {
+        use crate::common::database::entity::{
+            assessments, assessments_submission, organizations_cache, submission_reports,
+        };
+        use axum::{routing::get, Router};
+        use sea_orm::{ActiveModelTrait, ConnectionTrait, Schema, Set};
+
+        let app = Router::new().route("/admin/realms/test-realm/users/count", get(|| async { Json(7) }));
+        let url = serve(app).await;
+
+        let app_state = admin_state(url).await;
+        let db = app_state.database.get_connection();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(organizations_cache::Entity),
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(submission_reports::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await.unwrap();
+        }
+
+        let now = chrono::Utc::now();
+        for org_id in ["org-1", "org-2"] {
+            organizations_cache::ActiveModel {
+                keycloak_org_id: Set(org_id.to_string()),
+                name: Set(org_id.to_string()),
+                domains: Set(json!([])),
+                synced_at: Set(now),
+            }
+            .insert(db)
+            .await
+            .unwrap();
+        }
+
+        let submitted = [None, None, Some(SubmissionStatus::UnderReview), Some(SubmissionStatus::Approved)];
+        for (index, status) in submitted.into_iter().enumerate() {
+            let assessment_id = Uuid::new_v4();
+            assessments::ActiveModel {
+                assessment_id: Set(assessment_id),
+                org_id: Set("org-1".to_string()),
+                language: Set("en".to_string()),
+                name: Set(format!("Assessment {index}")),
+                created_at: Set(if index == 1 { now } else { now - chrono::Duration::days(40) }),
+            }
+            .insert(db)
+            .await
+            .unwrap();
+
+            let Some(status) = status else { continue };
+            let approved = status == SubmissionStatus::Approved;
+            assessments_submission::ActiveModel {
+                submission_id: Set(assessment_id),
+                org_id: Set("org-1".to_string()),
+                org_name: Set("org-1".to_string()),
+                content: Set(json!({})),
+                submitted_at: Set(now),
+                status: Set(status),
+                reviewed_at: Set(None),
+                reopened_at: Set(None),
+                reopened_by: Set(None),
+            }
+            .insert(db)
+            .await
+            .unwrap();
+            if approved {
+                submission_reports::ActiveModel {
+                    report_id: Set(Uuid::new_v4()),
+                    submission_id: Set(assessment_id),
+                    report_type: Set("sustainability".to_string()),
+                    status: Set("completed".to_string()),
+                    generated_at: Set(now),
+                    data: Set(None),
+                }
+                .insert(db)
+                .await
+                .unwrap();
+            }
+        }
+
+        app_state
+    }
+
+    #[tokio::test]
+    async fn test_admin_dashboard_counts_seeded_data() {
+        let Json(dashboard) = get_admin_dashboard(
+            State(dashboard_state().await),
+            Extension(claims("admin", &["application_admin"])),
+            Extension("test-token".to_string()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(dashboard.total_organizations, 2);
+        assert_eq!(dashboard.total_users, 7);
+        assert_eq!(dashboard.total_submissions, 2);
+        assert_eq!(dashboard.submissions_by_status["under_review"], 1);
+        assert_eq!(dashboard.submissions_by_status["approved"], 1);
+        assert_eq!(dashboard.submissions_by_status["rejected"], 0);
+        assert_eq!(dashboard.submissions_by_status.len(), SubmissionStatus::iter().count());
+        assert_eq!(dashboard.total_reports, 1);
+        assert_eq!(dashboard.overdue_assessments, 1);
+    }
+
+    #[tokio::test]
+    async fn test_admin_dashboard_is_for_application_admins() {
+        let mut claims = claims("admin", &["application_admin"]);
+        claims.realm_access = None;
+
+        let result = get_admin_dashboard(
+            State(dashboard_state().await),
+            Extension(claims),
+            Extension("test-token".to_string()),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::Forbidden(_))));
+    }
+
+    /// Submissions on March 1st, 10th and 20th, the middle one approved and from org-2
+    async fn submissions_state() -> AppState {
+        use crate::common::database::entity::assessments_submission;
+        use chrono::TimeZone;
+        use sea_orm::{ActiveModelTrait, ConnectionTrait, Schema, Set};
+
+        let app_state = admin_state("http://127.0.0.1:1".to_string()).await;
+        let db = app_state.database.get_connection();
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await.unwrap();
+        let backend = db.get_database_backend();
+        db.execute(backend.build(&Schema::new(backend).create_table_from_entity(assessments_submission::Entity)))
+            .await
+            .unwrap();
+
+        for day in [1, 10, 20] {
+            assessments_submission::ActiveModel {
+                submission_id: Set(Uuid::new_v4()),
+                org_id: Set(if day == 10 { "org-2" } else { "org-1" }.to_string()),
+                org_name: Set("Org".to_string()),
+                content: Set(json!({ "responses": [] })),
+                submitted_at: Set(chrono::Utc.with_ymd_and_hms(2026, 3, day, 12, 0, 0).unwrap()),
+                status: Set(if day == 10 { SubmissionStatus::Approved } else { SubmissionStatus::UnderReview }),
+                reviewed_at: Set(None),
+                reopened_at: Set(None),
+                reopened_by: Set(None),
+            }
+            .insert(db)
+            .await
+            .unwrap();
+        }
+        app_state
+    }
+
+    async fn list_submissions(query: &str) -> Result<Vec<String>, ApiError> {
+        let Query(params) = Query::<ListSubmissionsQuery>::try_from_uri(&format!("/api/admin/submissions?{query}").parse().unwrap())
+            .unwrap();
+        let Json(response) = list_all_submissions(
+            State(submissions_state().await),
+            Extension(claims("admin", &["application_admin"])),
+            Extension("test-token".to_string()),
+            Query(params),
+        )
+        .await?;
+        Ok(response.submissions.into_iter().map(|s| s.submitted_at[..10].to_string()).collect())
+    }
+
+    #[tokio::test]
+    async fn test_list_submissions_in_date_range() {
+        let in_range = list_submissions("submitted_after=2026-03-05T00:00:00Z&submitted_before=2026-03-31T00:00:00Z").await;
+        assert_eq!(in_range.unwrap(), ["2026-03-20", "2026-03-10"]);
+
+        let with_status = list_submissions(
+            "status=under_review&submitted_after=2026-03-05T00:00:00Z&submitted_before=2026-03-31T00:00:00Z",
+        )
+        .await;
+        assert_eq!(with_status.unwrap(), ["2026-03-20"]);
+
+        let none = list_submissions("submitted_after=2026-04-01T00:00:00Z&submitted_before=2026-04-30T00:00:00Z").await;
+        assert!(none.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_submissions_of_one_organization() {
+        assert_eq!(list_submissions("org_id=org-1").await.unwrap(), ["2026-03-20", "2026-03-01"]);
+        assert_eq!(list_submissions("org_id=org-2").await.unwrap(), ["2026-03-10"]);
+        assert!(list_submissions("org_id=unknown-org").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_submissions_rejects_inverted_range() {
+        let result = list_submissions("submitted_after=2026-03-31T00:00:00Z&submitted_before=2026-03-01T00:00:00Z").await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    async fn get_submission(app_state: AppState, claims: Claims, submission_id: Uuid) -> Result<AdminSubmissionDetail, ApiError> {
+        get_submission_by_id(
+            State(app_state),
+            Extension(claims),
+            Extension("test-token".to_string()),
+            Path(submission_id),
+        )
+        .await
+        .map(|Json(submission)| submission)
+    }
+
+    #[tokio::test]
+    async fn test_get_submission_by_id() {
+        use crate::common::database::entity::{assessments_submission, category_catalog, questions, questions_revisions};
+        use sea_orm::{ActiveModelTrait, ConnectionTrait, Schema, Set};
+
+        let app_state = submissions_state().await;
+        let db = app_state.database.get_connection();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(category_catalog::Entity))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(questions::Entity))).await.unwrap();
+        db.execute(backend.build(&schema.create_table_from_entity(questions_revisions::Entity))).await.unwrap();
+
+        let category = app_state
+            .database
+            .category_catalog
+            .create_category_catalog(Uuid::new_v4(), "Energy".to_string(), None, "template".to_string(), true, None)
+            .await
+            .unwrap();
+        let question = app_state.database.questions.create_question(category.category_catalog_id).await.unwrap();
+        let revision = app_state
+            .database
+            .questions_revisions
+            .create_question_revision(question.question_id, json!({ "en": "Do you track energy use?" }), 1.0)
+            .await
+            .unwrap();
+
+        let submission_id = Uuid::new_v4();
+        assessments_submission::ActiveModel {
+            submission_id: Set(submission_id),
+            org_id: Set("org-3".to_string()),
+            org_name: Set("Stored Org".to_string()),
+            content: Set(json!({
+                "assessment": { "assessment_id": submission_id, "language": "en" },
+                "responses": [{ "question_revision_id": revision.question_revision_id, "response": "Yes", "version": 2 }],
+            })),
+            submitted_at: Set(chrono::Utc::now()),
+            status: Set(SubmissionStatus::UnderReview),
+            reviewed_at: Set(None),
+            reopened_at: Set(None),
+            reopened_by: Set(None),
+        }
+        .insert(db)
+        .await
+        .unwrap();
+
+        let submission = get_submission(app_state, claims("admin", &["application_admin"]), submission_id).await.unwrap();
+
+        assert_eq!(submission.submission_id, submission_id);
+        // Keycloak is unreachable in this test, so the stored name is used
+        assert_eq!((submission.org_id.as_str(), submission.org_name.as_str()), ("org-3", "Stored Org"));
+        assert_eq!(submission.review_status, "under_review");
+        let response = &submission.content.responses[0];
+        assert_eq!(response.question_text, "Do you track energy use?");
+        assert_eq!(response.question_category, "Energy");
+        assert_eq!((response.response.as_str(), response.version), ("Yes", 2));
+    }
+
+    #[tokio::test]
+    async fn test_get_submission_by_id_not_found_or_forbidden() {
+        let result = get_submission(submissions_state().await, claims("admin", &["application_admin"]), Uuid::new_v4()).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+
+        let mut claims = claims("admin", &["application_admin"]);
+        claims.realm_access = None;
+        let result = get_submission(submissions_state().await, claims, Uuid::new_v4()).await;
+        assert!(matches!(result, Err(ApiError::Forbidden(_))));
+    }
 }
@@ -0,0 +1,461 @@
+//! Assessment templates
+//!
+//! Application admins save assessment setups (language, categories and questions)
+//! as templates. Organization admins start a draft assessment from one instead of
+//! picking the categories by hand. Assessments are scoped by category, so a
+//! template's questions contribute their categories to the draft.
+
+use crate::common::database::entity::assessment_templates;
+use crate::common::models::claims::Claims;
+use crate::web::api::error::ApiError;
+use crate::web::api::strict_json::StrictJson;
+use crate::web::api::handlers::assessments::create_draft_assessment;
+use crate::web::api::models::{
+    AssessmentFromTemplateResponse, AssessmentTemplate, AssessmentTemplateListResponse,
+    AssessmentTemplateResponse, CreateAssessmentTemplateRequest,
+};
+use crate::web::routes::AppState;
+use crate::with_request_cache;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use uuid::Uuid;
+
+fn template_model_to_api(model: assessment_templates::Model) -> AssessmentTemplate {
+    AssessmentTemplate {
+        template_id: model.template_id,
+        categories: model.category_ids(),
+        questions: model.question_ids(),
+        name: model.name,
+        language: model.language,
+        created_by: model.created_by,
+        created_at: model.created_at.to_rfc3339(),
+    }
+}
+
+/// `ids` without repeats, in first-seen order
+fn dedup_ids(ids: impl IntoIterator<Item = Uuid>) -> Vec<Uuid> {
+    let mut unique = Vec::new();
+    for id in ids {
+        if !unique.contains(&id) {
+            unique.push(id);
+        }
+    }
+    unique
+}
+
+/// Save an assessment setup as a template
+#[utoipa::path(
+    post,
+    path = "/admin/assessment-templates",
+    tag = "Admin",
+    request_body = CreateAssessmentTemplateRequest,
+    responses(
+        (status = 201, description = "Template created", body = AssessmentTemplateResponse),
+        (status = 400, description = "Invalid template or unknown category or question"),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn create_assessment_template(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    StrictJson(request): StrictJson<CreateAssessmentTemplateRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can create assessment templates".to_string(),
+        ));
+    }
+
+    if request.name.trim().is_empty() {
+        return Err(ApiError::BadRequest("Template name must not be empty".to_string()));
+    }
+    if request.language.trim().is_empty() {
+        return Err(ApiError::BadRequest("Language must not be empty".to_string()));
+    }
+    if request.categories.is_empty() && request.questions.is_empty() {
+        return Err(ApiError::BadRequest(
+            "A template needs at least one category or question".to_string(),
+        ));
+    }
+
+    let categories = dedup_ids(request.categories);
+    for category_id in &categories {
+        let category = app_state
+            .database
+            .category_catalog
+            .get_category_catalog_by_id(*category_id)
+            .await
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch category: {e}")))?;
+        match category {
+            Some(category) if category.deactivated_at.is_none() => {}
+            Some(_) => {
+                return Err(ApiError::BadRequest(format!("Category {category_id} is archived")));
+            }
+            None => return Err(ApiError::BadRequest(format!("Category {category_id} not found"))),
+        }
+    }
+
+    let questions = dedup_ids(request.questions);
+    for question_id in &questions {
+        let question = app_state
+            .database
+            .questions
+            .get_question_by_id(*question_id)
+            .await
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch question: {e}")))?;
+        if question.is_none() {
+            return Err(ApiError::BadRequest(format!("Question {question_id} not found")));
+        }
+    }
+
+    let model = app_state
+        .database
+        .assessment_templates
+        .create_template(
+            request.name.trim().to_string(),
+            request.language.trim().to_string(),
+            &categories,
+            &questions,
+            claims.sub.clone(),
+        )
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to create template: {e}")))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(AssessmentTemplateResponse {
+            template: template_model_to_api(model),
+        }),
+    ))
+}
+
+/// List assessment templates by name
+#[utoipa::path(
+    get,
+    path = "/admin/assessment-templates",
+    tag = "Admin",
+    responses(
+        (status = 200, description = "Assessment templates", body = AssessmentTemplateListResponse),
+        (status = 403, description = "Caller cannot create assessments")
+    )
+)]
+pub async fn list_assessment_templates(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<impl IntoResponse, ApiError> {
+    if !claims.can_create_assessments() {
+        return Err(ApiError::Forbidden(
+            "Only organization or application admins can list assessment templates".to_string(),
+        ));
+    }
+
+    let templates = app_state
+        .database
+        .assessment_templates
+        .list_templates()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to list templates: {e}")))?;
+
+    Ok(Json(AssessmentTemplateListResponse {
+        templates: templates.into_iter().map(template_model_to_api).collect(),
+    }))
+}
+
+/// Start a draft assessment for the caller's organization from a template.
+///
+/// The draft gets the template's name and language, its questions, and its categories
+/// plus those of its questions. Like `POST /assessments`, it replaces the organization's
+/// previous drafts.
+#[utoipa::path(
+    post,
+    path = "/assessments/from-template/{template_id}",
+    tag = "Assessment",
+    params(
+        ("template_id" = Uuid, Path, description = "Template ID")
+    ),
+    responses(
+        (status = 201, description = "Draft assessment created", body = AssessmentFromTemplateResponse),
+        (status = 400, description = "Validation error or permissions"),
+        (status = 404, description = "Template not found"),
+        (status = 429, description = "Organization has reached its assessment limit")
+    )
+)]
+pub async fn create_assessment_from_template(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    Path(template_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    with_request_cache!({
+        let template = app_state
+            .database
+            .assessment_templates
+            .get_template(template_id)
+            .await
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch template: {e}")))?
+            .ok_or_else(|| ApiError::NotFound("Assessment template not found".to_string()))?;
+
+        // Questions deleted since the template was saved are left out
+        let mut questions = Vec::new();
+        let mut question_categories = Vec::new();
+        for question_id in template.question_ids() {
+            let question = app_state
+                .database
+                .questions
+                .get_question_by_id(question_id)
+                .await
+                .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch question: {e}")))?;
+            if let Some(question) = question {
+                questions.push(question.question_id);
+                question_categories.push(question.category_id);
+            }
+        }
+        let categories = dedup_ids(template.category_ids().into_iter().chain(question_categories));
+
+        let assessment = create_draft_assessment(
+            &app_state,
+            &claims,
+            &token,
+            template.language,
+            template.name,
+            categories,
+            questions.clone(),
+        )
+        .await?;
+
+        Ok((
+            StatusCode::CREATED,
+            Json(AssessmentFromTemplateResponse {
+                assessment,
+                template_id,
+                questions,
+            }),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{claims, keycloak_config, org_claims};
+    use crate::common::database::entity::{
+        assessment_categories, assessment_questions, assessments, assessments_response,
+        assessments_submission, category_catalog, questions, temp_submission,
+    };
+    use crate::common::state::AppDatabase;
+    use axum::{body::Body, http::header, http::Request, routing::post, Router};
+    use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, Database, EntityTrait, QueryFilter, Schema, Set};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    async fn insert_category(db: &sea_orm::DatabaseConnection, name: &str) -> Result<Uuid, sea_orm::DbErr> {
+        let category_id = Uuid::new_v4();
+        category_catalog::ActiveModel {
+            category_catalog_id: Set(category_id),
+            name: Set(name.to_string()),
+            description: Set(None),
+            template_id: Set("sustainability_template_1".to_string()),
+            is_active: Set(true),
+            created_at: Set(chrono::Utc::now()),
+            updated_at: Set(chrono::Utc::now()),
+            default_recommendation: Set(None),
+            deactivated_at: Set(None),
+            parent_category_catalog_id: Set(None),
+            scoring_mode: Set(Default::default()),
+            score_threshold: Set(None),
+            icon_name: Set(None),
+            color_hex: Set(None),
+            metadata: Set(serde_json::json!({})),
+        }
+        .insert(db)
+        .await?;
+        Ok(category_id)
+    }
+
+    /// Environment and Social categories, with one question in Social. Keycloak is
+    /// unreachable, so the default assessment limit applies.
+    async fn setup() -> Result<(AppState, Vec<Uuid>, Uuid), Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessment_templates::Entity),
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessment_categories::Entity),
+            schema.create_table_from_entity(assessment_questions::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(temp_submission::Entity),
+            schema.create_table_from_entity(category_catalog::Entity),
+            schema.create_table_from_entity(questions::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        let environment = insert_category(&db, "Environment").await?;
+        let social = insert_category(&db, "Social").await?;
+        let question_id = Uuid::new_v4();
+        questions::ActiveModel {
+            question_id: Set(question_id),
+            category_id: Set(social),
+            created_at: Set(chrono::Utc::now()),
+            external_key: Set(None),
+            sensitive: Set(false),
+            skip_rules: Set(serde_json::json!([])),
+        }
+        .insert(&db)
+        .await?;
+
+        let app_state = AppState::new(
+            keycloak_config("http://127.0.0.1:9"),
+            AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+
+        Ok((app_state, vec![environment, social], question_id))
+    }
+
+    async fn send(
+        app_state: &AppState,
+        claims: Claims,
+        method: &str,
+        uri: &str,
+        body: Option<serde_json::Value>,
+    ) -> Result<(StatusCode, serde_json::Value), Box<dyn std::error::Error>> {
+        let app = Router::new()
+            .route(
+                "/api/admin/assessment-templates",
+                post(create_assessment_template).get(list_assessment_templates),
+            )
+            .route(
+                "/api/assessments/from-template/:template_id",
+                post(create_assessment_from_template),
+            )
+            .layer(Extension(claims))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state.clone());
+
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))?;
+        let response = app.oneshot(request).await?;
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok((status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null)))
+    }
+
+    #[tokio::test]
+    async fn test_create_and_list_templates() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, categories, question_id) = setup().await?;
+        let admin = claims("application_admin-user", &["application_admin"]);
+
+        let body = serde_json::json!({
+            "name": "Baseline",
+            "language": "en",
+            "categories": [categories[0], categories[0]],
+            "questions": [question_id],
+        });
+        let (status, created) =
+            send(&app_state, admin.clone(), "POST", "/api/admin/assessment-templates", Some(body.clone())).await?;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["template"]["name"], "Baseline");
+        assert_eq!(created["template"]["categories"], serde_json::json!([categories[0]]));
+        assert_eq!(created["template"]["questions"], serde_json::json!([question_id]));
+        assert_eq!(created["template"]["created_by"], "application_admin-user");
+
+        // Only application admins create templates, org admins may list them
+        let org_admin = org_claims("org_admin-user", &["org_admin"], "Test Organization", "test-org");
+        let (status, _) =
+            send(&app_state, org_admin.clone(), "POST", "/api/admin/assessment-templates", Some(body)).await?;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, listed) = send(&app_state, org_admin, "GET", "/api/admin/assessment-templates", None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(listed["templates"].as_array().map(Vec::len), Some(1));
+
+        let unknown = serde_json::json!({
+            "name": "Broken",
+            "language": "en",
+            "categories": [Uuid::new_v4()],
+        });
+        let (status, _) = send(&app_state, admin, "POST", "/api/admin/assessment-templates", Some(unknown)).await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_assessment_from_template_copies_categories_and_questions(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, categories, question_id) = setup().await?;
+        let template = app_state
+            .database
+            .assessment_templates
+            .create_template(
+                "Baseline".to_string(),
+                "fr".to_string(),
+                &[categories[0]],
+                &[question_id],
+                "admin".to_string(),
+            )
+            .await?;
+
+        let (status, body) = send(
+            &app_state,
+            org_claims("org_admin-user", &["org_admin"], "Test Organization", "test-org"),
+            "POST",
+            &format!("/api/assessments/from-template/{}", template.template_id),
+            None,
+        )
+        .await?;
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["template_id"], serde_json::json!(template.template_id));
+        assert_eq!(body["questions"], serde_json::json!([question_id]));
+        let assessment = &body["assessment"];
+        assert_eq!(assessment["name"], "Baseline");
+        assert_eq!(assessment["language"], "fr");
+        assert_eq!(assessment["org_id"], "test-org");
+        assert_eq!(assessment["status"], "draft");
+        assert_eq!(assessment["categories"], serde_json::json!(categories));
+
+        let stored = app_state.database.assessments.get_assessments_by_org("test-org").await?;
+        assert_eq!(stored.len(), 1);
+        let mut stored_categories: Vec<Uuid> = assessment_categories::Entity::find()
+            .filter(assessment_categories::Column::AssessmentId.eq(stored[0].assessment_id))
+            .all(app_state.database.get_connection())
+            .await?
+            .into_iter()
+            .map(|row| row.category_catalog_id)
+            .collect();
+        stored_categories.sort();
+        let mut expected = categories.clone();
+        expected.sort();
+        assert_eq!(stored_categories, expected);
+        let stored_questions: Vec<Uuid> = assessment_questions::Entity::find()
+            .filter(assessment_questions::Column::AssessmentId.eq(stored[0].assessment_id))
+            .all(app_state.database.get_connection())
+            .await?
+            .into_iter()
+            .map(|row| row.question_id)
+            .collect();
+        assert_eq!(stored_questions, vec![question_id]);
+
+        let (status, _) = send(
+            &app_state,
+            org_claims("org_admin-user", &["org_admin"], "Test Organization", "test-org"),
+            "POST",
+            &format!("/api/assessments/from-template/{}", Uuid::new_v4()),
+            None,
+        )
+        .await?;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        Ok(())
+    }
+}
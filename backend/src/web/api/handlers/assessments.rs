@@ -1,17 +1,25 @@
 use axum::{
     extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    http::{header, StatusCode},
     response::IntoResponse,
     Json,
 };
-use sea_orm::{ActiveModelTrait, EntityTrait, ModelTrait, TransactionTrait, Set};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, JoinType, ModelTrait, QueryFilter, QuerySelect,
+    RelationTrait, Set, TransactionTrait,
+};
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
+use crate::common::models::admin_event::AdminEvent;
 use crate::common::models::claims::Claims;
 use crate::web::routes::AppState;
 use crate::web::api::error::ApiError;
+use crate::web::api::strict_json::StrictJson;
 use crate::web::api::models::*;
 use crate::common::cache::cached_ops;
+use crate::common::database::entity::assessments::DeleteDraftError;
+use crate::common::database::entity::temp_submission::SubmitError;
 use crate::with_request_cache;
 
 // Helper function to determine assessment status based on three-tier system
@@ -20,10 +28,10 @@ async fn determine_assessment_status(
     claims: &Claims,
     assessment_id: Uuid,
 ) -> Result<AssessmentStatus, ApiError> {
-    // Check if assessment has been reviewed (in assessments_submission table)
+    // Check if assessment has been reviewed (in assessments_submission table), and not reopened since
     let has_final_submission = cached_ops::get_submission_with_session(app_state, claims, assessment_id)
         .await?
-        .is_some();
+        .is_some_and(|submission| !submission.is_reopened());
     
     if has_final_submission {
         return Ok(AssessmentStatus::Reviewed);
@@ -42,6 +50,20 @@ async fn determine_assessment_status(
     Ok(AssessmentStatus::Draft)
 }
 
+// Helper function to compute how much of an assessment has been answered
+async fn fetch_completion_percent(
+    app_state: &AppState,
+    assessment_id: Uuid,
+    categories: &[Uuid],
+) -> Result<f64, ApiError> {
+    app_state
+        .database
+        .assessments
+        .get_completion_percent(assessment_id, categories, &app_state.database.assessments_response)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to compute completion: {e}")))
+}
+
 // Helper function to convert file::Model to FileMetadata
 async fn convert_file_model_to_metadata(
     file_model: crate::common::database::entity::file::Model,
@@ -98,6 +120,39 @@ async fn fetch_files_for_response(
     Ok(files)
 }
 
+/// `model` as listed, with its status, categories and completion
+async fn to_listed_assessment(
+    app_state: &AppState,
+    claims: &Claims,
+    model: crate::common::database::entity::assessments::Model,
+) -> Result<Assessment, ApiError> {
+    // Determine status using three-tier system (under_review, submitted, reviewed)
+    let status = determine_assessment_status(app_state, claims, model.assessment_id).await?;
+
+    let categories: Vec<Uuid> = model
+        .find_related(crate::common::database::entity::assessment_categories::Entity)
+        .all(app_state.database.get_connection())
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch categories: {}", e)))?
+        .into_iter()
+        .map(|cat| cat.category_catalog_id)
+        .collect();
+
+    let completion_percent = fetch_completion_percent(app_state, model.assessment_id, &categories).await?;
+
+    Ok(Assessment {
+        assessment_id: model.assessment_id,
+        org_id: model.org_id,
+        language: model.language,
+        name: model.name,
+        categories,
+        status,
+        completion_percent,
+        created_at: model.created_at.to_rfc3339(),
+        updated_at: model.created_at.to_rfc3339(),
+    })
+}
+
 // Use AssessmentQuery from models (implements IntoParams)
 use crate::web::api::models::AssessmentQuery;
 
@@ -128,28 +183,7 @@ pub async fn list_assessments(
         // Convert database models to API models
         let mut assessments = Vec::new();
         for model in assessment_models {
-            // Determine status using three-tier system (under_review, submitted, reviewed)
-            let status = determine_assessment_status(&app_state, &claims, model.assessment_id).await?;
-
-            let categories = model
-                .find_related(crate::common::database::entity::assessment_categories::Entity)
-                .all(app_state.database.get_connection())
-                .await
-                .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch categories: {}", e)))?
-                .into_iter()
-                .map(|cat| cat.category_catalog_id)
-                .collect();
-
-            assessments.push(Assessment {
-                assessment_id: model.assessment_id,
-                org_id: model.org_id,
-                language: model.language,
-                name: model.name,
-                categories,
-                status,
-                created_at: model.created_at.to_rfc3339(),
-                updated_at: model.created_at.to_rfc3339(),
-            });
+            assessments.push(to_listed_assessment(&app_state, &claims, model).await?);
         }
 
         // Filter by language if specified
@@ -190,6 +224,59 @@ pub async fn list_assessments(
     })
 }
 
+#[derive(Debug, serde::Deserialize)]
+pub struct OrganizationAssessmentsQuery {
+    status: Option<AssessmentStatus>,
+}
+
+/// List every assessment of an organization, whatever its status
+///
+/// Unlike `GET /assessments`, which shows drafts unless asked otherwise, this includes
+/// submitted and reviewed assessments, for application admins and the organization's admins.
+#[utoipa::path(
+    get,
+    path = "/organizations/{org_id}/assessments",
+    tag = "Assessment",
+    params(
+        ("org_id" = String, Path, description = "Organization ID"),
+        ("status" = Option<AssessmentStatus>, Query, description = "Only assessments with this status")
+    ),
+    responses(
+        (status = 200, description = "Assessments list", body = AssessmentListResponse),
+        (status = 400, description = "Unknown status"),
+        (status = 403, description = "Caller cannot manage the organization")
+    )
+)]
+pub async fn list_organization_assessments(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(org_id): Path<String>,
+    Query(query): Query<OrganizationAssessmentsQuery>,
+) -> Result<Json<AssessmentListResponse>, ApiError> {
+    if !claims.can_manage_organization(&org_id) {
+        return Err(ApiError::Forbidden(
+            "You don't have permission to view this organization's assessments".to_string(),
+        ));
+    }
+
+    let assessment_models = app_state
+        .database
+        .assessments
+        .get_assessments_by_org(&org_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessments: {e}")))?;
+
+    let mut assessments = Vec::new();
+    for model in assessment_models {
+        let assessment = to_listed_assessment(&app_state, &claims, model).await?;
+        if query.status.as_ref().is_none_or(|status| *status == assessment.status) {
+            assessments.push(assessment);
+        }
+    }
+
+    Ok(Json(AssessmentListResponse { assessments }))
+}
+
 /// Create a new assessment
 #[utoipa::path(
     post,
@@ -199,109 +286,272 @@ pub async fn list_assessments(
     responses(
         (status = 201, description = "Assessment created", body = AssessmentResponse),
         (status = 400, description = "Validation error or permissions"),
+        (status = 429, description = "Organization has reached its assessment limit"),
         (status = 500, description = "Server error")
     )
 )]
 pub async fn create_assessment(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
-    Json(request): Json<CreateAssessmentRequest>,
+    Extension(token): Extension<String>,
+    StrictJson(request): StrictJson<CreateAssessmentRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     with_request_cache!({
-        let org_id = claims.get_org_id()
-            .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
+        let assessment = create_draft_assessment(
+            &app_state,
+            &claims,
+            &token,
+            request.language,
+            request.name,
+            request.categories,
+            Vec::new(),
+        )
+        .await?;
 
-        // Check if user has permission to create assessments (only org_admin)
-        if !claims.can_create_assessments() {
-            return Err(ApiError::BadRequest(
-                "Only organization administrators can create assessments".to_string(),
-            ));
-        }
+        Ok((StatusCode::CREATED, Json(AssessmentResponse { assessment })))
+    })
+}
 
-        // Validate request
-        if request.language.trim().is_empty() {
-            return Err(ApiError::BadRequest(
-                "Language must not be empty".to_string(),
-            ));
-        }
+/// Create a draft assessment for the caller's organization, replacing its previous
+/// drafts. `questions` are recorded with it when it is set up from a template. Must
+/// run inside `with_request_cache!`.
+pub(crate) async fn create_draft_assessment(
+    app_state: &AppState,
+    claims: &Claims,
+    token: &str,
+    language: String,
+    name: String,
+    categories: Vec<Uuid>,
+    questions: Vec<Uuid>,
+) -> Result<Assessment, ApiError> {
+    let org_id = claims.get_org_id()
+        .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
 
-        if request.name.trim().is_empty() {
-            return Err(ApiError::BadRequest(
-                "Assessment name must not be empty".to_string(),
-            ));
-        }
+    // Check if user has permission to create assessments (only org_admin)
+    if !claims.can_create_assessments() {
+        return Err(ApiError::BadRequest(
+            "Only organization administrators can create assessments".to_string(),
+        ));
+    }
 
-        // Clean up only previous draft assessments (no submission) and their responses for this organization
-        let existing_assessments = app_state
-            .database
-            .assessments
-            .get_assessments_by_org(&org_id)
-            .await
-            .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch existing assessments: {e}")))?;
+    // Validate request
+    if language.trim().is_empty() {
+        return Err(ApiError::BadRequest(
+            "Language must not be empty".to_string(),
+        ));
+    }
 
-        for existing_assessment in existing_assessments {
-            // Check if this assessment has a submission (submitted) - using session-level cache
-            let has_submission = cached_ops::get_submission_with_session(&app_state, &claims, existing_assessment.assessment_id)
-                .await?
-                .is_some();
-
-            if !has_submission {
-                // Only delete responses and the assessment if it is a draft (no submission) - using cached operation
-                let existing_responses = cached_ops::get_latest_responses_by_assessment(&app_state, existing_assessment.assessment_id)
-                    .await?;
-
-                for response in existing_responses {
-                    let _ = app_state
-                        .database
-                        .assessments_response
-                        .delete_response(response.response_id)
-                        .await; // Ignore errors for cleanup
-                }
+    if name.trim().is_empty() {
+        return Err(ApiError::BadRequest(
+            "Assessment name must not be empty".to_string(),
+        ));
+    }
+
+    let existing_assessments = app_state
+        .database
+        .assessments
+        .get_assessments_by_org(&org_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch existing assessments: {e}")))?;
 
-                // Delete the draft assessment itself
+    // Drafts count towards the limit too, even though they are replaced below
+    let limit = assessment_limit(app_state, token, &org_id).await;
+    if existing_assessments.len() >= limit as usize {
+        return Err(ApiError::TooManyRequests(
+            "Organization has reached the maximum number of assessments".to_string(),
+        ));
+    }
+
+    // Clean up only previous draft assessments (no submission) and their responses for this organization
+
+    for existing_assessment in existing_assessments {
+        // Check if this assessment has a submission (submitted) - using session-level cache
+        let has_submission = cached_ops::get_submission_with_session(app_state, claims, existing_assessment.assessment_id)
+            .await?
+            .is_some();
+
+        if !has_submission {
+            // Only delete responses and the assessment if it is a draft (no submission) - using cached operation
+            let existing_responses = cached_ops::get_latest_responses_by_assessment(app_state, existing_assessment.assessment_id)
+                .await?;
+
+            for response in existing_responses {
                 let _ = app_state
                     .database
-                    .assessments
-                    .delete_assessment(existing_assessment.assessment_id)
+                    .assessments_response
+                    .delete_response(response.response_id)
                     .await; // Ignore errors for cleanup
             }
+
+            // Delete the draft assessment itself
+            let _ = app_state
+                .database
+                .assessments
+                .delete_assessment(existing_assessment.assessment_id)
+                .await; // Ignore errors for cleanup
         }
+    }
 
-        // Parse categories from JSON to Vec<Uuid>
-        // Create the new assessment in the database with categories
-        let assessment_model = app_state
-            .database
-            .assessments
-            .create_assessment(org_id, request.language, request.name, request.categories.clone())
-            .await
-            .map_err(|e| ApiError::InternalServerError(format!("Failed to create assessment: {e}")))?;
+    // Create the new assessment in the database with categories and questions
+    let assessment_model = app_state
+        .database
+        .assessments
+        .create_assessment_with_questions(org_id, language, name, categories.clone(), questions)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to create assessment: {e}")))?;
 
-        // Convert a database model to an API model
-        let assessment = Assessment {
-            assessment_id: assessment_model.assessment_id,
-            org_id: assessment_model.org_id,
-            language: assessment_model.language,
-            name: assessment_model.name,
-            categories: request.categories,
-            status: AssessmentStatus::Draft,
-            created_at: assessment_model.created_at.to_rfc3339(),
-            updated_at: assessment_model.created_at.to_rfc3339(),
-        };
+    // Convert a database model to an API model
+    let assessment = Assessment {
+        assessment_id: assessment_model.assessment_id,
+        org_id: assessment_model.org_id,
+        language: assessment_model.language,
+        name: assessment_model.name,
+        categories,
+        status: AssessmentStatus::Draft,
+        completion_percent: 0.0,
+        created_at: assessment_model.created_at.to_rfc3339(),
+        updated_at: assessment_model.created_at.to_rfc3339(),
+    };
 
-        // Invalidate user's session cache since we created/deleted assessments
+    Ok(assessment)
+}
 
-        Ok((StatusCode::CREATED, Json(AssessmentResponse { assessment })))
-    })
+/// How many assessments an organization may have: its `max_assessments_override`
+/// Keycloak attribute if set, otherwise the configured default.
+///
+/// Keycloak being unreachable should not block assessment creation, so lookup
+/// failures fall back to the default.
+async fn assessment_limit(app_state: &AppState, token: &str, org_id: &str) -> u32 {
+    let default = app_state.limits_config.max_assessments_per_org;
+
+    match app_state
+        .keycloak_service
+        .get_org_attribute(token, org_id, "max_assessments_override")
+        .await
+    {
+        Ok(Some(value)) => value.trim().parse().unwrap_or_else(|_| {
+            tracing::warn!(org_id = %org_id, value = %value, "Ignoring invalid max_assessments_override");
+            default
+        }),
+        Ok(None) => default,
+        Err(e) => {
+            tracing::warn!(org_id = %org_id, error = %e, "Failed to read max_assessments_override, using default limit");
+            default
+        }
+    }
+}
+
+/// The catalog categories an org_expert may work on: those assigned to them in
+/// Keycloak, matched by category ID or name.
+///
+/// `None` when the caller is not restricted: everyone but experts, and experts without
+/// any assignment.
+pub(crate) async fn expert_assigned_categories(
+    app_state: &AppState,
+    claims: &Claims,
+    token: &str,
+) -> Result<Option<HashSet<Uuid>>, ApiError> {
+    use crate::common::database::entity::category_catalog;
+
+    if !claims.is_org_expert() || claims.is_org_admin() || claims.is_application_admin() {
+        return Ok(None);
+    }
+
+    let assigned = app_state
+        .keycloak_service
+        .get_user_assigned_categories(token, &claims.sub)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assigned categories: {e}")))?;
+
+    if assigned.is_empty() {
+        return Ok(None);
+    }
+
+    let allowed = category_catalog::Entity::find()
+        .all(app_state.database.get_connection())
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch categories: {e}")))?
+        .into_iter()
+        .filter(|category| {
+            assigned.iter().any(|a| {
+                *a == category.name || *a == category.category_catalog_id.to_string()
+            })
+        })
+        .map(|category| category.category_catalog_id)
+        .collect();
+
+    Ok(Some(allowed))
+}
+
+/// Narrow the categories and responses an org_expert sees to the categories assigned
+/// to them, see `expert_assigned_categories`.
+///
+/// Everyone else, and experts without any assignment, see the whole assessment.
+async fn restrict_to_assigned_categories(
+    app_state: &AppState,
+    claims: &Claims,
+    token: &str,
+    categories: Vec<Uuid>,
+    responses: Vec<Response>,
+) -> Result<(Vec<Uuid>, Vec<Response>), ApiError> {
+    use crate::common::database::entity::{questions, questions_revisions};
+
+    let Some(allowed) = expert_assigned_categories(app_state, claims, token).await? else {
+        return Ok((categories, responses));
+    };
+
+    let revision_categories: HashMap<Uuid, Uuid> = if responses.is_empty() {
+        HashMap::new()
+    } else {
+        questions_revisions::Entity::find()
+            .select_only()
+            .column(questions_revisions::Column::QuestionRevisionId)
+            .column(questions::Column::CategoryId)
+            .join(JoinType::InnerJoin, questions_revisions::Relation::Question.def())
+            .filter(
+                questions_revisions::Column::QuestionRevisionId
+                    .is_in(responses.iter().map(|r| r.question_revision_id)),
+            )
+            .into_tuple::<(Uuid, Uuid)>()
+            .all(app_state.database.get_connection())
+            .await
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch question categories: {e}")))?
+            .into_iter()
+            .collect()
+    };
+
+    let categories = categories
+        .into_iter()
+        .filter(|id| allowed.contains(id))
+        .collect();
+    let responses = responses
+        .into_iter()
+        .filter(|r| {
+            revision_categories
+                .get(&r.question_revision_id)
+                .is_some_and(|category_id| allowed.contains(category_id))
+        })
+        .collect();
+
+    Ok((categories, responses))
 }
 
 /// Get an assessment by ID with latest responses
+///
+/// The response carries an `ETag` derived from the newest response; clients sending
+/// it back in `If-None-Match` get `304 Not Modified` from `etag_middleware`.
 #[utoipa::path(
     get,
     path = "/assessments/{assessment_id}",
     tag = "Assessment",
-    params(("assessment_id" = uuid::Uuid, Path, description = "Assessment ID")),
+    params(
+        ("assessment_id" = uuid::Uuid, Path, description = "Assessment ID"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag from an earlier response")
+    ),
     responses(
-        (status = 200, description = "Assessment detail", body = AssessmentWithResponsesResponse),
+        (status = 200, description = "Assessment detail", body = AssessmentWithResponsesResponse,
+            headers(("ETag" = String, description = "Changes whenever a response is saved"))),
+        (status = 304, description = "Assessment unchanged since the given ETag"),
         (status = 404, description = "Assessment not found"),
         (status = 500, description = "Server error")
     )
@@ -309,8 +559,9 @@ pub async fn create_assessment(
 pub async fn get_assessment(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
     Path(assessment_id): Path<Uuid>,
-) -> Result<Json<AssessmentWithResponsesResponse>, ApiError> {
+) -> Result<impl IntoResponse, ApiError> {
     with_request_cache!({
         let org_id = claims.get_org_id()
             .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
@@ -346,7 +597,7 @@ pub async fn get_assessment(
         // Determine status using three-tier system (under_review, submitted, reviewed)
         let status = determine_assessment_status(&app_state, &claims, assessment_id).await?;
 
-        let categories = assessment_model
+        let categories: Vec<Uuid> = assessment_model
             .find_related(crate::common::database::entity::assessment_categories::Entity)
             .all(app_state.database.get_connection())
             .await
@@ -355,17 +606,8 @@ pub async fn get_assessment(
             .map(|cat| cat.category_catalog_id)
             .collect();
 
-        // Convert a database model to an API model
-        let assessment = Assessment {
-            assessment_id: assessment_model.assessment_id,
-            org_id: assessment_model.org_id,
-            language: assessment_model.language,
-            name: assessment_model.name,
-            categories,
-            status,
-            created_at: assessment_model.created_at.to_rfc3339(),
-            updated_at: assessment_model.created_at.to_rfc3339(),
-        };
+        let completion_percent =
+            fetch_completion_percent(&app_state, assessment_id, &categories).await?;
 
         // Fetch the latest responses for this assessment - using cached operation
         let response_models = cached_ops::get_latest_responses_by_assessment(&app_state, assessment_id).await?;
@@ -374,6 +616,11 @@ pub async fn get_assessment(
         let mut responses = Vec::new();
         for response_model in response_models {
             let files = fetch_files_for_response(&app_state, response_model.response_id).await?;
+            let response_model = app_state
+                .database
+                .assessments_response
+                .decrypt(response_model)
+                .map_err(|e| ApiError::InternalServerError(format!("Failed to decrypt response: {e}")))?;
 
             // Store response as single string instead of array
             let response_array = vec![response_model.response.clone()];
@@ -389,13 +636,46 @@ pub async fn get_assessment(
             });
         }
 
-        Ok(Json(AssessmentWithResponsesResponse {
+        let (categories, responses) =
+            restrict_to_assigned_categories(&app_state, &claims, &token, categories, responses).await?;
+
+        // Convert a database model to an API model
+        let assessment = Assessment {
+            assessment_id: assessment_model.assessment_id,
+            org_id: assessment_model.org_id,
+            language: assessment_model.language,
+            name: assessment_model.name,
+            categories,
+            status,
+            completion_percent,
+            created_at: assessment_model.created_at.to_rfc3339(),
+            updated_at: assessment_model.created_at.to_rfc3339(),
+        };
+
+        let detail = AssessmentWithResponsesResponse {
             assessment,
             responses,
-        }))
+        };
+        let etag = assessment_detail_etag(&detail)?;
+
+        Ok((
+            [(header::ETAG, etag), (header::CACHE_CONTROL, "no-cache".to_string())],
+            Json(detail),
+        ))
     })
 }
 
+/// ETag for the assessment detail view, used for conditional GETs.
+///
+/// Hashes the whole payload, so it changes with anything the client would see: the
+/// assessment's fields and status, and which responses there are with their versions
+/// and file links.
+fn assessment_detail_etag(detail: &AssessmentWithResponsesResponse) -> Result<String, ApiError> {
+    let payload = serde_json::to_vec(detail)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to serialize assessment: {e}")))?;
+    Ok(format!("\"{}\"", blake3::hash(&payload).to_hex()))
+}
+
 /// Update an assessment
 #[utoipa::path(
     put,
@@ -414,7 +694,7 @@ pub async fn update_assessment(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path(assessment_id): Path<Uuid>,
-    Json(request): Json<UpdateAssessmentRequest>,
+    StrictJson(request): StrictJson<UpdateAssessmentRequest>,
 ) -> Result<Json<AssessmentResponse>, ApiError> {
     let org_id = claims.get_org_id()
         .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
@@ -477,7 +757,7 @@ pub async fn update_assessment(
             }
         })?;
 
-    let categories = assessment_model
+    let categories: Vec<Uuid> = assessment_model
         .find_related(crate::common::database::entity::assessment_categories::Entity)
         .all(app_state.database.get_connection())
         .await
@@ -486,6 +766,9 @@ pub async fn update_assessment(
         .map(|cat| cat.category_catalog_id)
         .collect();
 
+    let completion_percent =
+        fetch_completion_percent(&app_state, assessment_id, &categories).await?;
+
     // Convert database model to API model
     let assessment = Assessment {
         assessment_id: assessment_model.assessment_id,
@@ -494,6 +777,7 @@ pub async fn update_assessment(
         name: assessment_model.name,
         categories,
         status: AssessmentStatus::Draft,
+        completion_percent,
         created_at: assessment_model.created_at.to_rfc3339(),
         updated_at: assessment_model.created_at.to_rfc3339(),
     };
@@ -503,122 +787,298 @@ pub async fn update_assessment(
     Ok(Json(AssessmentResponse { assessment }))
 }
 
-/// Delete an assessment
+/// Status as `determine_assessment_status` infers it, read from the database rather
+/// than the caller's session cache
+async fn stored_assessment_status(app_state: &AppState, assessment_id: Uuid) -> Result<AssessmentStatus, ApiError> {
+    if app_state
+        .database
+        .assessments_submission
+        .get_submission_by_assessment_id(assessment_id)
+        .await?
+        .is_some_and(|submission| !submission.is_reopened())
+    {
+        return Ok(AssessmentStatus::Reviewed);
+    }
+    if app_state
+        .database
+        .temp_submission
+        .get_temp_submission_by_assessment_id(assessment_id)
+        .await?
+        .is_some()
+    {
+        return Ok(AssessmentStatus::Submitted);
+    }
+    Ok(AssessmentStatus::Draft)
+}
+
+/// Allowed status changes: draft -> submitted -> reviewed, and back to draft
+fn check_status_transition(from: &AssessmentStatus, to: &AssessmentStatus) -> Result<(), ApiError> {
+    match (from, to) {
+        (AssessmentStatus::Draft, AssessmentStatus::Submitted)
+        | (AssessmentStatus::Submitted, AssessmentStatus::Reviewed)
+        | (AssessmentStatus::Submitted | AssessmentStatus::Reviewed, AssessmentStatus::Draft) => Ok(()),
+        _ => Err(ApiError::BadRequest(format!(
+            "Cannot change assessment status from {from} to {to}"
+        ))),
+    }
+}
+
+/// Change an assessment's status (application admins)
+///
+/// Submitting stores the current responses in `temp_submission` like
+/// `POST /assessments/{id}/draft`, reviewing moves them into `assessments_submission`
+/// like `POST /assessments/{id}/submit`. Reopening to draft removes both, and is
+/// refused while reports exist for the submission.
 #[utoipa::path(
-    delete,
-    path = "/assessments/{assessment_id}",
+    post,
+    path = "/assessments/{assessment_id}/status",
     tag = "Assessment",
     params(("assessment_id" = uuid::Uuid, Path, description = "Assessment ID")),
+    request_body = UpdateAssessmentStatusRequest,
     responses(
-        (status = 204, description = "Assessment deleted"),
-        (status = 400, description = "Permission error or submitted"),
+        (status = 200, description = "Status changed", body = AssessmentStatusResponse),
+        (status = 400, description = "Transition not allowed"),
+        (status = 403, description = "Not an application admin"),
         (status = 404, description = "Assessment not found"),
+        (status = 409, description = "The submission has reports"),
         (status = 500, description = "Server error")
     )
 )]
-pub async fn delete_assessment(
+pub async fn update_assessment_status(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
     Path(assessment_id): Path<Uuid>,
-) -> Result<StatusCode, ApiError> {
-    let org_id = claims.get_org_id()
-        .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
+    StrictJson(request): StrictJson<UpdateAssessmentStatusRequest>,
+) -> Result<Json<AssessmentStatusResponse>, ApiError> {
+    use crate::common::database::entity::{assessments_submission, submission_timeline::TimelineActor, temp_submission};
+
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can change assessment status".to_string(),
+        ));
+    }
 
-    // Check if the assessment exists and get its details
     let assessment = app_state
         .database
         .assessments
         .get_assessment_by_id(assessment_id)
         .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessment: {e}")))?;
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessment: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Assessment not found".to_string()))?;
+
+    let previous_status = stored_assessment_status(&app_state, assessment_id).await?;
+    check_status_transition(&previous_status, &request.status)?;
+
+    match request.status {
+        AssessmentStatus::Submitted => {
+            let content = draft_content(&app_state, &assessment).await?;
+            app_state
+                .database
+                .temp_submission
+                .submit_for_review(assessment_id, assessment.org_id.clone(), content)
+                .await
+                .map_err(|e| ApiError::InternalServerError(format!("Failed to store temp submission: {e}")))?;
+        }
+        AssessmentStatus::Reviewed => {
+            let temp_submission = app_state
+                .database
+                .temp_submission
+                .get_temp_submission_by_assessment_id(assessment_id)
+                .await?
+                .ok_or_else(|| ApiError::Conflict("Assessment is no longer submitted".to_string()))?;
+            // Admins act on other organizations, so the name comes from Keycloak
+            let org_name = match app_state.keycloak_service.get_organization(&token, &assessment.org_id).await {
+                Ok(organization) => organization.name,
+                Err(e) => {
+                    tracing::warn!(org_id = %assessment.org_id, error = %e, "Failed to fetch organization name");
+                    assessment.org_id.clone()
+                }
+            };
 
-    let assessment = match assessment {
-        Some(a) => a,
-        None => return Err(ApiError::NotFound("Assessment not found".to_string())),
-    };
+            let txn = app_state.database.get_connection().begin().await?;
+            finalize_submission(&txn, &temp_submission, &assessment, &org_name, &TimelineActor::from_claims(&claims)).await?;
+            txn.commit().await?;
 
-    // Verify that the current organization is the owner of the assessment
-    if assessment.org_id != org_id {
-        return Err(ApiError::BadRequest(
-            "You don't have permission to delete this assessment".to_string(),
-        ));
+            app_state.publish_admin_event(AdminEvent::NewSubmission {
+                submission_id: assessment_id,
+                org_id: assessment.org_id.clone(),
+                org_name,
+            });
+        }
+        AssessmentStatus::Draft => {
+            let has_reports = !app_state
+                .database
+                .submission_reports
+                .get_reports_by_submission(assessment_id)
+                .await?
+                .is_empty();
+            if has_reports {
+                return Err(ApiError::Conflict(
+                    "Delete the submission's reports before reopening the assessment".to_string(),
+                ));
+            }
+
+            let txn = app_state.database.get_connection().begin().await?;
+            assessments_submission::Entity::delete_by_id(assessment_id).exec(&txn).await?;
+            temp_submission::Entity::delete_by_id(assessment_id).exec(&txn).await?;
+            txn.commit().await?;
+        }
     }
 
-    // Check if an assessment has been submitted (cannot delete submitted assessments)
-    let has_submission = app_state
-        .database
-        .assessments_submission
-        .get_submission_by_assessment_id(assessment_id)
-        .await
-        .map_err(|e| {
-            ApiError::InternalServerError(format!("Failed to check submission status: {e}"))
-        })?
-        .is_some();
+    // The admin changed the status for the organization's members, not for themselves
+    app_state.session_cache.invalidate_assessment(&assessment.org_id, &assessment_id);
 
-    if has_submission {
-        return Err(ApiError::BadRequest(
-            "Cannot delete a submitted assessment".to_string(),
-        ));
-    }
+    Ok(Json(AssessmentStatusResponse {
+        assessment_id,
+        previous_status,
+        status: request.status,
+    }))
+}
 
-    // Delete the assessment from the database (this will cascade delete responses due to a foreign key)
-    app_state
-        .database
+/// Which questions of an assessment to show, given the answers so far
+///
+/// Applies the questions' skip rules to `answered_so_far`; answers not yet saved count
+/// as much as saved ones. Like `get_assessment`, any assessment can be looked at by ID.
+#[utoipa::path(
+    post,
+    path = "/assessments/{assessment_id}/next-questions",
+    tag = "Assessment",
+    params(("assessment_id" = uuid::Uuid, Path, description = "Assessment ID")),
+    request_body = NextQuestionsRequest,
+    responses(
+        (status = 200, description = "Visible and skipped questions", body = NextQuestionsResponse),
+        (status = 400, description = "Unknown question revision"),
+        (status = 404, description = "Assessment not found"),
+        (status = 500, description = "Server error")
+    )
+)]
+pub async fn get_next_questions(
+    State(app_state): State<AppState>,
+    Path(assessment_id): Path<Uuid>,
+    StrictJson(request): StrictJson<NextQuestionsRequest>,
+) -> Result<Json<NextQuestionsResponse>, ApiError> {
+    use crate::common::database::entity::{assessment_categories, questions, questions_revisions};
+    use crate::common::services::skip_logic::{SkipLogicEngine, SkipRule};
+    use sea_orm::QueryOrder;
+
+    let assessment = app_state
+        .database
         .assessments
-        .delete_assessment(assessment_id)
+        .get_assessment_by_id(assessment_id)
         .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to delete assessment: {e}")))?;
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessment: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Assessment not found".to_string()))?;
 
-    // Invalidate user's session cache since we deleted an assessment
+    let db = app_state.database.get_connection();
+    let categories: Vec<Uuid> = assessment
+        .find_related(assessment_categories::Entity)
+        .all(db)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch categories: {e}")))?
+        .into_iter()
+        .map(|category| category.category_catalog_id)
+        .collect();
+    let questions = questions::Entity::find()
+        .filter(questions::Column::CategoryId.is_in(categories))
+        .order_by_asc(questions::Column::CreatedAt)
+        .all(db)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch questions: {e}")))?;
 
-    Ok(StatusCode::NO_CONTENT)
+    let revision_ids: HashSet<Uuid> = request
+        .answered_so_far
+        .iter()
+        .map(|answer| answer.question_revision_id)
+        .collect();
+    let revision_questions: HashMap<Uuid, Uuid> = questions_revisions::Entity::find()
+        .filter(questions_revisions::Column::QuestionRevisionId.is_in(revision_ids))
+        .all(db)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch question revisions: {e}")))?
+        .into_iter()
+        .map(|revision| (revision.question_revision_id, revision.question_id))
+        .collect();
+
+    let mut responses = HashMap::new();
+    for answer in request.answered_so_far {
+        let question_id = revision_questions.get(&answer.question_revision_id).ok_or_else(|| {
+            ApiError::BadRequest(format!("Unknown question revision {}", answer.question_revision_id))
+        })?;
+        responses.insert(*question_id, answer.value);
+    }
+
+    let skipped = SkipLogicEngine::evaluate(&SkipRule::from_questions(&questions), &responses);
+    let (skipped_questions, visible_questions): (Vec<Uuid>, Vec<Uuid>) = questions
+        .iter()
+        .map(|question| question.question_id)
+        .partition(|question_id| skipped.contains(question_id));
+
+    Ok(Json(NextQuestionsResponse {
+        visible_questions,
+        skipped_questions,
+    }))
 }
 
-/// API handler for user draft submission -- constructs content from live state and saves to temp_submission table
+/// Delete a draft assessment
+///
+/// Removes the assessment, its responses and files no longer attached to anything.
 #[utoipa::path(
-    post,
-    path = "/assessments/{assessment_id}/draft",
+    delete,
+    path = "/assessments/{assessment_id}",
     tag = "Assessment",
     params(("assessment_id" = uuid::Uuid, Path, description = "Assessment ID")),
     responses(
-        (status = 200, description = "Draft stored", body = serde_json::Value),
-        (status = 400, description = "Permission or validation error"),
+        (status = 204, description = "Assessment deleted"),
+        (status = 403, description = "Assessment belongs to another organization"),
         (status = 404, description = "Assessment not found"),
+        (status = 409, description = "Assessment has been submitted or reviewed"),
         (status = 500, description = "Server error")
     )
 )]
-pub async fn user_submit_draft_assessment(
+pub async fn delete_assessment(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path(assessment_id): Path<Uuid>,
-) -> Result<impl IntoResponse, ApiError> {
+) -> Result<StatusCode, ApiError> {
     let org_id = claims.get_org_id()
         .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
 
-    if !claims.can_answer_assessments() {
-        return Err(ApiError::BadRequest(
-            "You don't have permission to submit assessments. Only Org_User and org_admin roles can submit assessments.".to_string(),
-        ));
-    }
-
-    // Verify that the assessment exists and belongs to the organization
-    let assessment_model = app_state
+    let deleted_object_keys = app_state
         .database
         .assessments
-        .get_assessment_by_id(assessment_id)
+        .delete_draft_assessment(assessment_id, &org_id)
         .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessment: {e}")))?;
+        .map_err(|e| match e {
+            DeleteDraftError::NotFound => ApiError::NotFound("Assessment not found".to_string()),
+            DeleteDraftError::OtherOrganization => {
+                ApiError::Forbidden("You don't have permission to delete this assessment".to_string())
+            }
+            DeleteDraftError::Submitted => ApiError::Conflict("Cannot delete a submitted assessment".to_string()),
+            DeleteDraftError::Database(e) => ApiError::InternalServerError(format!("Failed to delete assessment: {e}")),
+        })?;
 
-    let assessment_model = match assessment_model {
-        Some(a) => a,
-        None => return Err(ApiError::NotFound("Assessment not found".to_string())),
-    };
+    app_state
+        .database
+        .file
+        .delete_stored_objects(&deleted_object_keys)
+        .await;
 
-    // Gather responses for this assessment as draft content
+    // The cached assessment and response lists no longer match the database
+    app_state.session_cache.invalidate_user(&claims.sub);
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// The latest responses of an assessment, with their files, as stored in `temp_submission`
+async fn draft_content(
+    app_state: &AppState,
+    assessment_model: &crate::common::database::entity::assessments::Model,
+) -> Result<serde_json::Value, ApiError> {
     let response_models = app_state
         .database
         .assessments_response
-        .get_latest_responses_by_assessment(assessment_id)
+        .get_latest_responses_by_assessment(assessment_model.assessment_id)
         .await
         .map_err(|e| {
             ApiError::InternalServerError(format!("Failed to fetch assessment responses: {e}"))
@@ -648,40 +1108,157 @@ pub async fn user_submit_draft_assessment(
             })
         }).collect();
 
-        responses_with_files.push(serde_json::json!({
+        // Answers to sensitive questions stay encrypted, see `AssessmentsResponseService::decrypt_content`
+        let mut entry = serde_json::json!({
             "question_revision_id": response_model.question_revision_id,
             "response": response_model.response,
             "version": response_model.version,
             "files": file_metadata
-        }));
+        });
+        if let Some(key_version) = response_model.key_version {
+            entry["key_version"] = key_version.into();
+        }
+        responses_with_files.push(entry);
     }
 
-    let draft_content = serde_json::json!({
+    Ok(serde_json::json!({
         "assessment": {
-            "assessment_id": assessment_id,
+            "assessment_id": assessment_model.assessment_id,
             "language": assessment_model.language
         },
         "responses": responses_with_files
-    });
+    }))
+}
+
+/// API handler for user draft submission -- constructs content from live state and saves to temp_submission table
+#[utoipa::path(
+    post,
+    path = "/assessments/{assessment_id}/draft",
+    tag = "Assessment",
+    params(("assessment_id" = uuid::Uuid, Path, description = "Assessment ID")),
+    responses(
+        (status = 200, description = "Draft stored, or the existing draft if already submitted", body = serde_json::Value),
+        (status = 400, description = "Permission or validation error"),
+        (status = 403, description = "Org experts cannot submit assessments"),
+        (status = 404, description = "Assessment not found"),
+        (status = 409, description = "Assessment has already been finalized"),
+        (status = 500, description = "Server error")
+    )
+)]
+pub async fn user_submit_draft_assessment(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(assessment_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    let org_id = claims.get_org_id()
+        .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
 
-    // Insert or update the draft in temp_submission using service API
-    let existing_temp = app_state.database.temp_submission.get_temp_submission_by_assessment_id(assessment_id)
+    if !claims.can_answer_assessments() {
+        return Err(ApiError::BadRequest(
+            "You don't have permission to submit assessments. Only Org_User and org_admin roles can submit assessments.".to_string(),
+        ));
+    }
+
+    // Org experts answer their assigned categories but leave submitting to the organization
+    if claims.is_org_expert() && !claims.is_Org_User() && !claims.is_org_admin() && !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Org experts cannot submit assessments. Only Org_User and org_admin roles can submit assessments.".to_string(),
+        ));
+    }
+
+    // Verify that the assessment exists and belongs to the organization
+    let assessment_model = app_state
+        .database
+        .assessments
+        .get_assessment_by_id(assessment_id)
         .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to query temp submission: {e}")))?;
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessment: {e}")))?;
 
-    if existing_temp.is_some() {
-        // Update draft
-        app_state.database.temp_submission.update_submission_content(assessment_id, draft_content.clone())
-            .await
-            .map_err(|e| ApiError::InternalServerError(format!("Failed to update temp submission: {e}")))?;
+    let assessment_model = match assessment_model {
+        Some(a) => a,
+        None => return Err(ApiError::NotFound("Assessment not found".to_string())),
+    };
+
+    let draft_content = draft_content(&app_state, &assessment_model).await?;
+
+    // Store the draft in temp_submission; submitting again returns the draft that is already there
+    let (temp_submission, _created) = app_state
+        .database
+        .temp_submission
+        .submit_for_review(assessment_id, org_id, draft_content)
+        .await
+        .map_err(|e| match e {
+            SubmitError::AlreadyFinalized => ApiError::Conflict("Assessment has already been finalized".to_string()),
+            SubmitError::Database(e) => ApiError::InternalServerError(format!("Failed to store temp submission: {e}")),
+        })?;
+
+    // The assessment status shown in listings changes from draft to submitted
+    app_state.session_cache.invalidate_user(&claims.sub);
+
+    Ok((StatusCode::OK, Json(temp_submission.content)))
+}
+
+/// Move `temp_submission` into `assessments_submission` as under review, within `txn`.
+/// A reopened submission is replaced in place, keeping who reopened it and when.
+async fn finalize_submission(
+    txn: &sea_orm::DatabaseTransaction,
+    temp_submission: &crate::common::database::entity::temp_submission::Model,
+    assessment: &crate::common::database::entity::assessments::Model,
+    org_name: &str,
+    actor: &crate::common::database::entity::submission_timeline::TimelineActor,
+) -> Result<(), ApiError> {
+    let assessment_id = assessment.assessment_id;
+
+    // Enhance the temp submission content with assessment name
+    let mut enhanced_content = temp_submission.content.clone();
+    if let Some(content_obj) = enhanced_content.as_object_mut() {
+        content_obj.insert("assessment_name".to_string(), serde_json::Value::String(assessment.name.clone()));
+    }
+
+    let reopened = crate::common::database::entity::assessments_submission::Entity::find_by_id(assessment_id)
+        .one(txn)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
+        .filter(|submission| submission.is_reopened());
+
+    // Create final submission using the enhanced content with assessment name
+    let submission = crate::common::database::entity::assessments_submission::ActiveModel {
+        submission_id: Set(assessment_id),
+        org_id: Set(temp_submission.org_id.clone()),
+        org_name: Set(org_name.to_string()),
+        content: Set(enhanced_content),
+        submitted_at: Set(chrono::Utc::now()),
+        status: Set(crate::common::database::entity::assessments_submission::SubmissionStatus::UnderReview),
+        reviewed_at: Set(None),
+        reopened_at: Set(reopened.as_ref().and_then(|submission| submission.reopened_at)),
+        reopened_by: Set(reopened.as_ref().and_then(|submission| submission.reopened_by.clone())),
+    };
+
+    if reopened.is_some() {
+        submission.update(txn).await
     } else {
-        // Create draft
-        app_state.database.temp_submission.create_temp_submission(assessment_id, org_id.clone(), draft_content.clone())
-            .await
-            .map_err(|e| ApiError::InternalServerError(format!("Failed to store temp submission: {e}")))?;
+        submission.insert(txn).await
     }
+    .map_err(|e| ApiError::InternalServerError(format!("Failed to create final submission: {e}")))?;
+
+    crate::common::database::entity::submission_timeline::record_transition(
+        txn,
+        assessment_id,
+        reopened.as_ref().map(|submission| &submission.status),
+        &crate::common::database::entity::assessments_submission::SubmissionStatus::UnderReview,
+        actor,
+        None,
+    )
+    .await
+    .map_err(|e| ApiError::InternalServerError(format!("Failed to record submission timeline: {e}")))?;
+
+    // Delete the temp submission after successful final submission, if there was one
+    crate::common::database::entity::temp_submission::Entity::delete_by_id(assessment_id)
+        .exec(txn)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to clean up temp submission: {e}")))?;
 
-    Ok((StatusCode::OK, Json(draft_content)))
+    Ok(())
 }
 
 /// API handler to move a user's temp_submission to assessments_submission (approval/finalize)
@@ -692,7 +1269,8 @@ pub async fn user_submit_draft_assessment(
     params(("assessment_id" = uuid::Uuid, Path, description = "Assessment ID")),
     responses(
         (status = 200, description = "Assessment submitted"),
-        (status = 400, description = "Permission or validation error"),
+        (status = 400, description = "Validation error"),
+        (status = 403, description = "Only org_admin can submit assessments"),
         (status = 404, description = "Not found"),
         (status = 500, description = "Server error")
     )
@@ -702,9 +1280,9 @@ pub async fn submit_assessment(
     Extension(claims): Extension<Claims>,
     Path(assessment_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, ApiError> {
-    if !claims.can_create_assessments() {
+    if !claims.can_submit_assessment() {
         return Err(ApiError::Forbidden(
-            "You don't have permission to finalize assessments.".to_string(),
+            "You don't have permission to finalize assessments. Only org_admin can submit assessments.".to_string(),
         ));
     }
 
@@ -725,12 +1303,16 @@ pub async fn submit_assessment(
                 
                 let mut content_responses = Vec::new();
                 for res in responses {
-                    content_responses.push(serde_json::json!({
+                    let mut entry = serde_json::json!({
                         "question_revision_id": res.question_revision_id,
                         "response": res.response,
                         "version": res.version,
                         "files": []
-                    }));
+                    });
+                    if let Some(key_version) = res.key_version {
+                        entry["key_version"] = key_version.into();
+                    }
+                    content_responses.push(entry);
                 }
 
                 let content = serde_json::json!({
@@ -756,42 +1338,26 @@ pub async fn submit_assessment(
             .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessment: {e}")))?
             .ok_or_else(|| ApiError::NotFound("Assessment not found".to_string()))?;
 
-        // Enhance the temp submission content with assessment name
-        let mut enhanced_content = temp_submission.content.clone();
-        if let Some(content_obj) = enhanced_content.as_object_mut() {
-            content_obj.insert("assessment_name".to_string(), serde_json::Value::String(assessment.name.clone()));
-        }
         let org_name = claims.get_organization_name().ok_or_else(|| ApiError::BadRequest("No organization name found in token".to_string()))?;
 
-        // Create final submission using the enhanced content with assessment name
-        let submission = crate::common::database::entity::assessments_submission::ActiveModel {
-            submission_id: Set(assessment_id),
-            org_id: Set(temp_submission.org_id.clone()),
-            org_name: Set(org_name),
-            content: Set(enhanced_content),
-            submitted_at: Set(chrono::Utc::now()),
-            status: Set(crate::common::database::entity::assessments_submission::SubmissionStatus::UnderReview),
-            reviewed_at: Set(None),
-        };
-        
-        submission.insert(&txn)
-            .await
-            .map_err(|e| ApiError::InternalServerError(format!("Failed to create final submission: {e}")))?;
-
-        // Delete the temp submission after successful final submission
-        // Only delete the temp submission if it exists
-        if app_state.database.temp_submission.get_temp_submission_by_assessment_id(assessment_id).await?.is_some() {
-            crate::common::database::entity::temp_submission::Entity::delete_by_id(assessment_id)
-                .exec(&txn)
-                .await
-                .map_err(|e| ApiError::InternalServerError(format!("Failed to clean up temp submission: {e}")))?;
-        }
-
-        Ok::<(), ApiError>(())
+        finalize_submission(
+            &txn,
+            &temp_submission,
+            &assessment,
+            &org_name,
+            &crate::common::database::entity::submission_timeline::TimelineActor::from_claims(&claims),
+        )
+        .await?;
+
+        Ok::<_, ApiError>(AdminEvent::NewSubmission {
+            submission_id: assessment_id,
+            org_id: temp_submission.org_id,
+            org_name,
+        })
     }.await;
 
     match result {
-        Ok(_) => {
+        Ok(event) => {
             // Commit transaction on success
             txn.commit()
                 .await
@@ -799,6 +1365,7 @@ pub async fn submit_assessment(
             
             // Invalidate user's session cache since we submitted an assessment
             app_state.session_cache.invalidate_user(&claims.sub);
+            app_state.publish_admin_event(event);
             
             Ok(StatusCode::OK)
         }
@@ -811,4 +1378,885 @@ pub async fn submit_assessment(
             Err(e)
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{claims, keycloak_config, org_claims, serve};
+    use crate::common::database::entity::{category_catalog, questions, questions_revisions};
+    use crate::common::state::AppDatabase;
+    use axum::{routing::get, Router};
+    use chrono::Utc;
+    use sea_orm::{ConnectionTrait, Database, Schema};
+    use std::sync::Arc;
+
+    /// Keycloak stand-in serving user profiles and organizations: `assigned-expert`
+    /// has the Environment category assigned and `raised-org` may keep three
+    /// assessments; every other user or organization has no attributes.
+    async fn fake_keycloak() -> String {
+        let app = Router::new()
+            .route(
+                "/admin/realms/test-realm/users/:user_id",
+                get(|Path(user_id): Path<String>| async move {
+                    let mut user = serde_json::json!({
+                        "id": user_id,
+                        "username": user_id,
+                        "email": format!("{user_id}@example.com"),
+                    });
+                    if user_id == "assigned-expert" {
+                        user["attributes"] = serde_json::json!({ "categories": ["Environment"] });
+                    }
+                    Json(user)
+                }),
+            )
+            .route(
+                "/admin/realms/test-realm/organizations/:org_id",
+                get(|Path(org_id): Path<String>| async move {
+                    let mut org = serde_json::json!({
+                        "id": org_id,
+                        "name": org_id,
+                        "enabled": true,
+                    });
+                    if org_id == "raised-org" {
+                        org["attributes"] = serde_json::json!({ "max_assessments_override": ["3"] });
+                    }
+                    Json(org)
+                }),
+            );
+
+        serve(app).await
+    }
+
+    /// Two categories with one answered question each. Returns the state, the
+    /// category IDs and responses in (Environment, Social) order.
+    async fn setup() -> Result<(AppState, Vec<Uuid>, Vec<Response>), Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(category_catalog::Entity),
+            schema.create_table_from_entity(questions::Entity),
+            schema.create_table_from_entity(questions_revisions::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        let assessment_id = Uuid::new_v4();
+        let mut categories = Vec::new();
+        let mut responses = Vec::new();
+        for name in ["Environment", "Social"] {
+            let category_id = Uuid::new_v4();
+            category_catalog::ActiveModel {
+                category_catalog_id: Set(category_id),
+                name: Set(name.to_string()),
+                description: Set(None),
+                template_id: Set("sustainability_template_1".to_string()),
+                is_active: Set(true),
+                created_at: Set(Utc::now()),
+                updated_at: Set(Utc::now()),
+                default_recommendation: Set(None),
+                deactivated_at: Set(None),
+                parent_category_catalog_id: Set(None),
+                scoring_mode: Set(Default::default()),
+                score_threshold: Set(None),
+                icon_name: Set(None),
+                color_hex: Set(None),
+                metadata: Set(serde_json::json!({})),
+            }
+            .insert(&db)
+            .await?;
+
+            let question_id = Uuid::new_v4();
+            questions::ActiveModel {
+                question_id: Set(question_id),
+                category_id: Set(category_id),
+                created_at: Set(Utc::now()),
+                external_key: Set(None),
+                sensitive: Set(false),
+                skip_rules: Set(serde_json::json!([])),
+            }
+            .insert(&db)
+            .await?;
+
+            let question_revision_id = Uuid::new_v4();
+            questions_revisions::ActiveModel {
+                question_revision_id: Set(question_revision_id),
+                question_id: Set(question_id),
+                text: Set(serde_json::json!({ "en": format!("{name} question") })),
+                weight: Set(1.0),
+                created_at: Set(Utc::now()),
+            }
+            .insert(&db)
+            .await?;
+
+            categories.push(category_id);
+            responses.push(Response {
+                response_id: Uuid::new_v4(),
+                assessment_id,
+                question_revision_id,
+                response: vec!["yes".to_string()],
+                version: 1,
+                updated_at: Utc::now().to_rfc3339(),
+                files: Vec::new(),
+            });
+        }
+
+        let app_state = AppState::new(
+            keycloak_config(fake_keycloak().await),
+            AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+
+        Ok((app_state, categories, responses))
+    }
+
+    fn revision_ids(responses: &[Response]) -> Vec<Uuid> {
+        responses.iter().map(|r| r.question_revision_id).collect()
+    }
+
+    #[tokio::test]
+    async fn test_org_admin_sees_all_categories() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, categories, responses) = setup().await?;
+        let expected = revision_ids(&responses);
+
+        let (visible_categories, visible_responses) = restrict_to_assigned_categories(
+            &app_state,
+            &claims("assigned-expert", &["org_admin"]),
+            "test-token",
+            categories.clone(),
+            responses,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(visible_categories, categories);
+        assert_eq!(revision_ids(&visible_responses), expected);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_expert_with_assignments_sees_assigned_categories_only(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, categories, responses) = setup().await?;
+        let environment_revision = responses[0].question_revision_id;
+
+        let (visible_categories, visible_responses) = restrict_to_assigned_categories(
+            &app_state,
+            &claims("assigned-expert", &["Org_Expert"]),
+            "test-token",
+            categories.clone(),
+            responses,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(visible_categories, vec![categories[0]]);
+        assert_eq!(revision_ids(&visible_responses), vec![environment_revision]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_expert_without_assignments_sees_all_categories(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, categories, responses) = setup().await?;
+        let expected = revision_ids(&responses);
+
+        let (visible_categories, visible_responses) = restrict_to_assigned_categories(
+            &app_state,
+            &claims("unassigned-expert", &["Org_Expert"]),
+            "test-token",
+            categories.clone(),
+            responses,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(visible_categories, categories);
+        assert_eq!(revision_ids(&visible_responses), expected);
+        Ok(())
+    }
+
+    /// State whose organization `org_id` already has `existing` draft assessments
+    async fn setup_with_assessments(
+        org_id: &str,
+        existing: usize,
+    ) -> Result<AppState, Box<dyn std::error::Error>> {
+        use crate::common::database::entity::{
+            assessment_categories, assessments, assessments_response, assessments_submission,
+            submission_reports, submission_timeline, temp_submission,
+        };
+
+        // `submit_assessment` reads outside of its transaction, so it needs a second connection
+        let mut options = sea_orm::ConnectOptions::new(format!(
+            "sqlite:file:{}?mode=memory&cache=shared",
+            Uuid::new_v4()
+        ));
+        options
+            .max_connections(4)
+            .map_sqlx_sqlite_opts(|options| options.foreign_keys(false));
+        let db = Database::connect(options).await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessment_categories::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(temp_submission::Entity),
+            schema.create_table_from_entity(submission_timeline::Entity),
+            schema.create_table_from_entity(submission_reports::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        for n in 0..existing {
+            assessments::ActiveModel {
+                assessment_id: Set(Uuid::new_v4()),
+                org_id: Set(org_id.to_string()),
+                language: Set("en".to_string()),
+                name: Set(format!("Assessment {n}")),
+                created_at: Set(Utc::now()),
+            }
+            .insert(&db)
+            .await?;
+        }
+
+        Ok(AppState::new(
+            keycloak_config(fake_keycloak().await),
+            AppDatabase::new(Arc::new(db)).await,
+        )
+        .await
+        .with_limits_config(crate::common::config::LimitsConfig {
+            max_assessments_per_org: 2,
+            ..Default::default()
+        }))
+    }
+
+    async fn post_assessment(
+        app_state: AppState,
+        org_id: &str,
+    ) -> Result<StatusCode, Box<dyn std::error::Error>> {
+        use axum::{body::Body, http::Request, routing::post};
+        use tower::ServiceExt;
+
+        let app = Router::new()
+            .route("/api/assessments", post(create_assessment))
+            .layer(Extension(org_claims("org-admin", &["org_admin"], "Test Organization", org_id)))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+
+        let body = serde_json::json!({ "language": "en", "name": "New assessment", "categories": [] });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/assessments")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(body.to_string()))?,
+            )
+            .await?;
+        Ok(response.status())
+    }
+
+    #[tokio::test]
+    async fn test_create_assessment_below_limit() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup_with_assessments("test-org", 1).await?;
+
+        assert_eq!(post_assessment(app_state, "test-org").await?, StatusCode::CREATED);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_assessment_at_limit_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup_with_assessments("test-org", 2).await?;
+
+        assert_eq!(
+            post_assessment(app_state.clone(), "test-org").await?,
+            StatusCode::TOO_MANY_REQUESTS
+        );
+        let remaining = app_state.database.assessments.get_assessments_by_org("test-org").await?;
+        assert_eq!(remaining.len(), 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_org_override_raises_limit() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup_with_assessments("raised-org", 2).await?;
+
+        assert_eq!(post_assessment(app_state, "raised-org").await?, StatusCode::CREATED);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_org_expert_can_save_responses_but_not_submit() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::{assessments, assessments_response_file, file};
+        use crate::web::api::handlers::responses::create_response;
+        use axum::{body::Body, http::Request, routing::post};
+        use sea_orm::EntityTrait;
+        use tower::ServiceExt;
+
+        let app_state = setup_with_assessments("test-org", 1).await?;
+        let db = app_state.database.get_connection();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(questions::Entity),
+            schema.create_table_from_entity(questions_revisions::Entity),
+            schema.create_table_from_entity(file::Entity),
+            schema.create_table_from_entity(assessments_response_file::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+        let assessment_id = assessments::Entity::find().one(db).await?.unwrap().assessment_id;
+
+        let expert = org_claims("expert", &["Org_Expert"], "Test Organization", "test-org");
+
+        let body = serde_json::json!([{ "question_revision_id": Uuid::new_v4(), "response": "Solar panels" }]);
+        let saved = Router::new()
+            .route("/api/assessments/:assessment_id/responses", post(create_response))
+            .layer(Extension(expert.clone()))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state.clone())
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/assessments/{assessment_id}/responses"))
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(body.to_string()))?,
+            )
+            .await?;
+        assert_eq!(saved.status(), StatusCode::CREATED);
+
+        let drafted = user_submit_draft_assessment(State(app_state.clone()), Extension(expert.clone()), Path(assessment_id))
+            .await
+            .map(IntoResponse::into_response);
+        assert!(matches!(drafted, Err(ApiError::Forbidden(_))));
+
+        let submitted = submit_assessment(State(app_state.clone()), Extension(expert), Path(assessment_id))
+            .await
+            .map(IntoResponse::into_response);
+        assert!(matches!(submitted, Err(ApiError::Forbidden(_))));
+
+        let submitted = submit_assessment(
+            State(app_state.clone()),
+            Extension(org_claims("org-admin", &["org_admin"], "Test Organization", "test-org")),
+            Path(assessment_id),
+        )
+        .await
+        .map(IntoResponse::into_response)
+        .map_err(|e| format!("{e:?}"))?;
+        assert_eq!(submitted.status(), StatusCode::OK);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_assigned_expert_only_answers_assigned_categories() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::{assessments, assessments_response_file, file};
+        use crate::web::api::handlers::responses::create_response;
+        use axum::{body::Body, http::Request, routing::post};
+        use sea_orm::EntityTrait;
+        use tower::ServiceExt;
+
+        let app_state = setup_with_assessments("test-org", 1).await?;
+        let db = app_state.database.get_connection();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(category_catalog::Entity),
+            schema.create_table_from_entity(questions::Entity),
+            schema.create_table_from_entity(questions_revisions::Entity),
+            schema.create_table_from_entity(file::Entity),
+            schema.create_table_from_entity(assessments_response_file::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+        let assessment_id = assessments::Entity::find().one(db).await?.unwrap().assessment_id;
+
+        // One question in the expert's assigned "Environment" category and one outside it
+        let mut revisions = Vec::new();
+        for name in ["Environment", "Social"] {
+            let category_id = Uuid::new_v4();
+            category_catalog::ActiveModel {
+                category_catalog_id: Set(category_id),
+                name: Set(name.to_string()),
+                description: Set(None),
+                template_id: Set("sustainability_template_1".to_string()),
+                is_active: Set(true),
+                created_at: Set(Utc::now()),
+                updated_at: Set(Utc::now()),
+                default_recommendation: Set(None),
+                deactivated_at: Set(None),
+                parent_category_catalog_id: Set(None),
+                scoring_mode: Set(Default::default()),
+                score_threshold: Set(None),
+                icon_name: Set(None),
+                color_hex: Set(None),
+                metadata: Set(serde_json::json!({})),
+            }
+            .insert(db)
+            .await?;
+            let question_id = Uuid::new_v4();
+            questions::ActiveModel {
+                question_id: Set(question_id),
+                category_id: Set(category_id),
+                created_at: Set(Utc::now()),
+                external_key: Set(None),
+                sensitive: Set(false),
+                skip_rules: Set(serde_json::json!([])),
+            }
+            .insert(db)
+            .await?;
+            let question_revision_id = Uuid::new_v4();
+            questions_revisions::ActiveModel {
+                question_revision_id: Set(question_revision_id),
+                question_id: Set(question_id),
+                text: Set(serde_json::json!({ "en": format!("{name} question") })),
+                weight: Set(1.0),
+                created_at: Set(Utc::now()),
+            }
+            .insert(db)
+            .await?;
+            revisions.push(question_revision_id);
+        }
+
+        let app = Router::new()
+            .route("/api/assessments/:assessment_id/responses", post(create_response))
+            .layer(Extension(org_claims("assigned-expert", &["Org_Expert"], "Test Organization", "test-org")))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state.clone());
+        let answer = |question_revision_id: Uuid| {
+            let app = app.clone();
+            let body = serde_json::json!([{ "question_revision_id": question_revision_id, "response": "Yes" }]);
+            async move {
+                app.oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(format!("/api/assessments/{assessment_id}/responses"))
+                        .header(header::CONTENT_TYPE, "application/json")
+                        .body(Body::from(body.to_string()))
+                        .unwrap(),
+                )
+                .await
+                .unwrap()
+                .status()
+            }
+        };
+
+        assert_eq!(answer(revisions[1]).await, StatusCode::FORBIDDEN);
+        assert_eq!(answer(revisions[0]).await, StatusCode::CREATED);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_submission_is_streamed_to_admin_subscribers() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::assessments;
+        use crate::common::models::admin_event::AdminEvent;
+        use crate::web::api::handlers::admin::stream_admin_events;
+        use axum::{body::Body, http::Request};
+        use futures::StreamExt;
+        use sea_orm::EntityTrait;
+        use tower::ServiceExt;
+
+        let app_state = setup_with_assessments("test-org", 1).await?;
+        let assessment_id = assessments::Entity::find()
+            .one(app_state.database.get_connection())
+            .await?
+            .unwrap()
+            .assessment_id;
+
+        let stream = Router::new()
+            .route("/api/admin/events/stream", get(stream_admin_events))
+            .layer(Extension(claims("admin", &["application_admin"])))
+            .with_state(app_state.clone())
+            .oneshot(Request::builder().uri("/api/admin/events/stream").body(Body::empty())?)
+            .await?;
+        assert_eq!(stream.status(), StatusCode::OK);
+        let mut body = stream.into_body().into_data_stream();
+
+        let submitted = submit_assessment(
+            State(app_state.clone()),
+            Extension(org_claims("org-admin", &["org_admin"], "Test Organization", "test-org")),
+            Path(assessment_id),
+        )
+        .await
+        .map(IntoResponse::into_response)
+        .map_err(|e| format!("{e:?}"))?;
+        assert_eq!(submitted.status(), StatusCode::OK);
+
+        let chunk = tokio::time::timeout(std::time::Duration::from_secs(5), body.next())
+            .await?
+            .unwrap()?;
+        let chunk = String::from_utf8(chunk.to_vec())?;
+        let data = chunk.strip_prefix("data: ").and_then(|rest| rest.strip_suffix("\n\n")).unwrap();
+        assert_eq!(
+            serde_json::from_str::<AdminEvent>(data)?,
+            AdminEvent::NewSubmission {
+                submission_id: assessment_id,
+                org_id: "test-org".to_string(),
+                org_name: "Test Organization".to_string(),
+            }
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_only_application_admins_can_stream_admin_events() {
+        use crate::web::api::handlers::admin::stream_admin_events;
+
+        let app_state = setup_with_assessments("test-org", 0).await.unwrap();
+        let result = stream_admin_events(State(app_state), Extension(org_claims("org-admin", &["org_admin"], "Test Organization", "test-org"))).await;
+
+        assert!(matches!(result, Err(ApiError::Forbidden(_))));
+    }
+
+    /// `setup` with the Social question skipped when the Environment question is
+    /// answered "No", and an assessment of both categories
+    async fn setup_with_skip_rule() -> Result<(AppState, Uuid, Vec<Uuid>, Vec<Response>), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::{assessment_categories, assessments, assessments_response};
+
+        let (app_state, categories, responses) = setup().await?;
+        let db = app_state.database.get_connection();
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessment_categories::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        let mut question_ids = Vec::new();
+        for response in &responses {
+            let revision = questions_revisions::Entity::find_by_id(response.question_revision_id)
+                .one(db)
+                .await?
+                .unwrap();
+            question_ids.push(revision.question_id);
+        }
+        let (environment, social) = (question_ids[0], question_ids[1]);
+        questions::ActiveModel {
+            question_id: Set(social),
+            sensitive: Set(false),
+            skip_rules: Set(serde_json::json!([
+                { "if": { "question_id": environment, "value": "No" }, "then": "skip" }
+            ])),
+            ..Default::default()
+        }
+        .update(db)
+        .await?;
+
+        let assessment_id = app_state
+            .database
+            .assessments
+            .create_assessment("test-org".to_string(), "en".to_string(), "Skips".to_string(), categories)
+            .await?
+            .assessment_id;
+
+        Ok((app_state, assessment_id, vec![environment, social], responses))
+    }
+
+    #[tokio::test]
+    async fn test_next_questions_apply_skip_rules() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, assessment_id, question_ids, responses) = setup_with_skip_rule().await?;
+        let (environment, social) = (question_ids[0], question_ids[1]);
+        let next = |value: &str| NextQuestionsRequest {
+            answered_so_far: vec![AnsweredQuestion {
+                question_revision_id: responses[0].question_revision_id,
+                value: value.to_string(),
+            }],
+        };
+
+        let Json(shown) =
+            get_next_questions(State(app_state.clone()), Path(assessment_id), StrictJson(next("yes")))
+                .await
+                .map_err(|e| format!("{e:?}"))?;
+        assert_eq!(shown.visible_questions.len(), 2);
+        assert!(shown.skipped_questions.is_empty());
+
+        let Json(skipped) =
+            get_next_questions(State(app_state.clone()), Path(assessment_id), StrictJson(next("no")))
+                .await
+                .map_err(|e| format!("{e:?}"))?;
+        assert_eq!(skipped.visible_questions, vec![environment]);
+        assert_eq!(skipped.skipped_questions, vec![social]);
+
+        let unknown = NextQuestionsRequest {
+            answered_so_far: vec![AnsweredQuestion {
+                question_revision_id: Uuid::new_v4(),
+                value: "No".to_string(),
+            }],
+        };
+        let result = get_next_questions(State(app_state.clone()), Path(assessment_id), StrictJson(unknown)).await;
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+
+        let result = get_next_questions(State(app_state), Path(Uuid::new_v4()), StrictJson(next("no"))).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_skipped_questions_do_not_count_towards_completion() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::assessments_response;
+
+        let (app_state, assessment_id, _, responses) = setup_with_skip_rule().await?;
+        let categories = assessment_categories_of(&app_state, assessment_id).await?;
+        let answer = |value: &str| assessments_response::ActiveModel {
+            response_id: Set(Uuid::new_v4()),
+            assessment_id: Set(assessment_id),
+            question_revision_id: Set(responses[0].question_revision_id),
+            response: Set(value.to_string()),
+            version: Set(1),
+            updated_at: Set(Utc::now()),
+            key_version: Set(None),
+        };
+
+        answer(r#"{"yesNo":true}"#).insert(app_state.database.get_connection()).await?;
+        let percent = fetch_completion_percent(&app_state, assessment_id, &categories)
+            .await
+            .map_err(|e| format!("{e:?}"))?;
+        assert_eq!(percent, 50.0);
+
+        // The newer answer skips the Social question, leaving one question, answered
+        answer(r#"{"yesNo":false}"#).insert(app_state.database.get_connection()).await?;
+        let percent = fetch_completion_percent(&app_state, assessment_id, &categories)
+            .await
+            .map_err(|e| format!("{e:?}"))?;
+        assert_eq!(percent, 100.0);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_skip_rules_see_decrypted_answers() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::services::encryption::EncryptionService;
+
+        let (mut app_state, assessment_id, question_ids, responses) = setup_with_skip_rule().await?;
+        app_state.database = app_state
+            .database
+            .clone()
+            .with_encryption(Arc::new(EncryptionService::new([4; 32])));
+        questions::ActiveModel {
+            question_id: Set(question_ids[0]),
+            sensitive: Set(true),
+            ..Default::default()
+        }
+        .update(app_state.database.get_connection())
+        .await?;
+        let categories = assessment_categories_of(&app_state, assessment_id).await?;
+
+        // Stored encrypted, but the "No" still skips the Social question
+        let stored = app_state
+            .database
+            .assessments_response
+            .create_response(assessment_id, responses[0].question_revision_id, r#"{"yesNo":false}"#.to_string(), 1)
+            .await?;
+        assert!(stored.key_version.is_some());
+        let percent = fetch_completion_percent(&app_state, assessment_id, &categories)
+            .await
+            .map_err(|e| format!("{e:?}"))?;
+        assert_eq!(percent, 100.0);
+        Ok(())
+    }
+
+    async fn assessment_categories_of(app_state: &AppState, assessment_id: Uuid) -> Result<Vec<Uuid>, sea_orm::DbErr> {
+        use crate::common::database::entity::assessment_categories;
+
+        Ok(assessment_categories::Entity::find()
+            .filter(assessment_categories::Column::AssessmentId.eq(assessment_id))
+            .all(app_state.database.get_connection())
+            .await?
+            .into_iter()
+            .map(|category| category.category_catalog_id)
+            .collect())
+    }
+
+    #[test]
+    fn test_status_transitions() {
+        use AssessmentStatus::{Draft, Reviewed, Submitted};
+
+        for (from, to) in [(Draft, Submitted), (Submitted, Reviewed), (Submitted, Draft), (Reviewed, Draft)] {
+            assert!(check_status_transition(&from, &to).is_ok(), "{from} -> {to}");
+        }
+        for (from, to) in [
+            (Draft, Draft),
+            (Draft, Reviewed),
+            (Submitted, Submitted),
+            (Reviewed, Submitted),
+            (Reviewed, Reviewed),
+        ] {
+            assert!(
+                matches!(check_status_transition(&from, &to), Err(ApiError::BadRequest(_))),
+                "{from} -> {to}"
+            );
+        }
+    }
+
+    async fn change_status(
+        app_state: &AppState,
+        claims: Claims,
+        assessment_id: Uuid,
+        status: AssessmentStatus,
+    ) -> Result<AssessmentStatusResponse, ApiError> {
+        update_assessment_status(
+            State(app_state.clone()),
+            Extension(claims),
+            Extension("test-token".to_string()),
+            Path(assessment_id),
+            StrictJson(UpdateAssessmentStatusRequest { status }),
+        )
+        .await
+        .map(|Json(response)| response)
+    }
+
+    #[tokio::test]
+    async fn test_admin_moves_assessment_through_statuses() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::{submission_reports, submission_timeline};
+        use AssessmentStatus::{Draft, Reviewed, Submitted};
+
+        let app_state = setup_with_assessments("test-org", 1).await?;
+        let db = app_state.database.get_connection();
+        let assessment_id = app_state.database.assessments.get_assessments_by_org("test-org").await?[0].assessment_id;
+        let admin = || claims("admin", &["application_admin"]);
+        let status = |expected: AssessmentStatus| {
+            let app_state = app_state.clone();
+            async move { assert_eq!(stored_assessment_status(&app_state, assessment_id).await.unwrap(), expected) }
+        };
+
+        let result = change_status(&app_state, admin(), assessment_id, Reviewed).await;
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+
+        let changed = change_status(&app_state, admin(), assessment_id, Submitted).await.map_err(|e| format!("{e:?}"))?;
+        assert_eq!((changed.previous_status, changed.status), (Draft, Submitted));
+        status(Submitted).await;
+        let result = change_status(&app_state, admin(), assessment_id, Submitted).await;
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+
+        change_status(&app_state, admin(), assessment_id, Reviewed).await.map_err(|e| format!("{e:?}"))?;
+        status(Reviewed).await;
+        let submission = app_state.database.assessments_submission.get_submission_by_assessment_id(assessment_id).await?.unwrap();
+        assert_eq!(submission.org_name, "test-org");
+        assert_eq!(submission_timeline::Entity::find().all(db).await?.len(), 1);
+        let result = change_status(&app_state, admin(), assessment_id, Submitted).await;
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+
+        // Reopening is refused while the submission has reports
+        let report = submission_reports::ActiveModel {
+            report_id: Set(Uuid::new_v4()),
+            submission_id: Set(assessment_id),
+            report_type: Set("sustainability".to_string()),
+            status: Set("generated".to_string()),
+            generated_at: Set(Utc::now()),
+            data: Set(None),
+        }
+        .insert(db)
+        .await?;
+        let result = change_status(&app_state, admin(), assessment_id, Draft).await;
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+        submission_reports::Entity::delete_by_id(report.report_id).exec(db).await?;
+
+        change_status(&app_state, admin(), assessment_id, Draft).await.map_err(|e| format!("{e:?}"))?;
+        status(Draft).await;
+
+        // Reopening a submitted assessment drops its temp submission
+        change_status(&app_state, admin(), assessment_id, Submitted).await.map_err(|e| format!("{e:?}"))?;
+        let changed = change_status(&app_state, admin(), assessment_id, Draft).await.map_err(|e| format!("{e:?}"))?;
+        assert_eq!((changed.previous_status, changed.status), (Submitted, Draft));
+        status(Draft).await;
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_status_change_refreshes_members_cached_status() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup_with_assessments("test-org", 1).await?;
+        let assessment_id = app_state.database.assessments.get_assessments_by_org("test-org").await?[0].assessment_id;
+        let member = org_claims("member", &["Org_User"], "Test Organization", "test-org");
+
+        let status = determine_assessment_status(&app_state, &member, assessment_id).await.map_err(|e| format!("{e:?}"))?;
+        assert_eq!(status, AssessmentStatus::Draft);
+
+        change_status(&app_state, claims("admin", &["application_admin"]), assessment_id, AssessmentStatus::Submitted)
+            .await
+            .map_err(|e| format!("{e:?}"))?;
+
+        let status = determine_assessment_status(&app_state, &member, assessment_id).await.map_err(|e| format!("{e:?}"))?;
+        assert_eq!(status, AssessmentStatus::Submitted);
+        Ok(())
+    }
+
+    async fn organization_assessments(
+        app_state: &AppState,
+        claims: Claims,
+        org_id: &str,
+        status: Option<AssessmentStatus>,
+    ) -> Result<Vec<Assessment>, ApiError> {
+        list_organization_assessments(
+            State(app_state.clone()),
+            Extension(claims),
+            Path(org_id.to_string()),
+            Query(OrganizationAssessmentsQuery { status }),
+        )
+        .await
+        .map(|Json(response)| response.assessments)
+    }
+
+    #[tokio::test]
+    async fn test_admins_list_reviewed_assessments_of_an_organization() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup_with_assessments("test-org", 2).await?;
+        let assessments = app_state.database.assessments.get_assessments_by_org("test-org").await?;
+        let (reviewed, draft) = (assessments[0].assessment_id, assessments[1].assessment_id);
+        let admin = || claims("admin", &["application_admin"]);
+        change_status(&app_state, admin(), reviewed, AssessmentStatus::Submitted).await.map_err(|e| format!("{e:?}"))?;
+        change_status(&app_state, admin(), reviewed, AssessmentStatus::Reviewed).await.map_err(|e| format!("{e:?}"))?;
+
+        // The user-facing list only shows drafts
+        let Json(listed) = list_assessments(
+            State(app_state.clone()),
+            Extension(org_claims("org-admin", &["org_admin"], "Test Organization", "test-org")),
+            Query(AssessmentQuery { status: None, language: None, cache_buster: None }),
+        )
+        .await
+        .map_err(|e| format!("{e:?}"))?;
+        let ids: Vec<Uuid> = listed.assessments.iter().map(|a| a.assessment_id).collect();
+        assert_eq!(ids, [draft]);
+
+        let all = organization_assessments(&app_state, admin(), "test-org", None).await.map_err(|e| format!("{e:?}"))?;
+        let mut statuses: Vec<(Uuid, AssessmentStatus)> = all.into_iter().map(|a| (a.assessment_id, a.status)).collect();
+        statuses.sort_by_key(|(id, _)| *id != reviewed);
+        assert_eq!(statuses, [(reviewed, AssessmentStatus::Reviewed), (draft, AssessmentStatus::Draft)]);
+
+        let only_reviewed = organization_assessments(&app_state, admin(), "test-org", Some(AssessmentStatus::Reviewed))
+            .await
+            .map_err(|e| format!("{e:?}"))?;
+        assert_eq!(only_reviewed.iter().map(|a| a.assessment_id).collect::<Vec<_>>(), [reviewed]);
+
+        // Organization admins see their own organization only
+        let own = organization_assessments(&app_state, org_claims("org-admin", &["org_admin"], "Test Organization", "test-org"), "test-org", None)
+            .await
+            .map_err(|e| format!("{e:?}"))?;
+        assert_eq!(own.len(), 2);
+        let other = organization_assessments(&app_state, org_claims("org-admin", &["org_admin"], "Test Organization", "other-org"), "test-org", None).await;
+        assert!(matches!(other, Err(ApiError::Forbidden(_))));
+        let member = organization_assessments(&app_state, claims("member", &["org_user"]), "test-org", None).await;
+        assert!(matches!(member, Err(ApiError::Forbidden(_))));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_only_application_admins_change_status() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup_with_assessments("test-org", 1).await?;
+        let assessment_id = app_state.database.assessments.get_assessments_by_org("test-org").await?[0].assessment_id;
+
+        let result = change_status(&app_state, org_claims("org-admin", &["org_admin"], "Test Organization", "test-org"), assessment_id, AssessmentStatus::Submitted).await;
+        assert!(matches!(result, Err(ApiError::Forbidden(_))));
+
+        let result = change_status(
+            &app_state,
+            claims("admin", &["application_admin"]),
+            Uuid::new_v4(),
+            AssessmentStatus::Submitted,
+        )
+        .await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+        Ok(())
+    }
+}
@@ -0,0 +1,559 @@
+//! Category catalog administration
+//!
+//! DGRV admins curate the catalog of categories organizations can be assigned.
+//! Anyone signed in can read it; only application admins can change it.
+//! Categories are never deleted here, only archived, so assessments and reports
+//! that refer to them keep working.
+
+use crate::common::database::entity::category_catalog::{self, ArchiveCategoryError};
+use crate::common::models::claims::Claims;
+use crate::web::api::error::ApiError;
+use crate::web::api::strict_json::StrictJson;
+use crate::web::api::models::{
+    CategoryCatalog, CategoryCatalogListResponse, CategoryCatalogResponse, CategoryListQuery,
+    CategoryMetadataRequest, CategoryTreeQuery, CategoryTreeResponse, CreateCategoryCatalogRequest,
+    UpdateCategoryDetailsRequest,
+};
+use crate::web::routes::AppState;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+use sea_orm::DbErr;
+use uuid::Uuid;
+
+fn category_model_to_catalog(model: category_catalog::Model) -> CategoryCatalog {
+    CategoryCatalog {
+        category_catalog_id: model.category_catalog_id,
+        name: model.name,
+        description: model.description,
+        template_id: model.template_id,
+        is_active: model.is_active,
+        created_at: model.created_at.to_rfc3339(),
+        updated_at: model.updated_at.to_rfc3339(),
+        default_recommendation: model.default_recommendation,
+        deactivated_at: model.deactivated_at.map(|at| at.to_rfc3339()),
+        scoring_mode: model.scoring_mode,
+        score_threshold: model.score_threshold,
+        icon_name: model.icon_name,
+        color_hex: model.color_hex,
+        metadata: model.metadata,
+    }
+}
+
+fn require_application_admin(claims: &Claims) -> Result<(), ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can manage categories".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Add a category to the catalog
+#[utoipa::path(
+    post,
+    path = "/admin/categories",
+    tag = "Admin",
+    request_body = CreateCategoryCatalogRequest,
+    responses(
+        (status = 201, description = "Category created", body = CategoryCatalogResponse),
+        (status = 400, description = "Invalid category"),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn create_category(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    StrictJson(request): StrictJson<CreateCategoryCatalogRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_application_admin(&claims)?;
+
+    if request.name.trim().is_empty() {
+        return Err(ApiError::BadRequest("Category name must not be empty".to_string()));
+    }
+
+    let model = app_state
+        .database
+        .category_catalog
+        .create_category_catalog(
+            Uuid::new_v4(),
+            request.name,
+            request.description,
+            request.template_id,
+            request.is_active.unwrap_or(true),
+            request.default_recommendation,
+        )
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to create category: {e}")))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(CategoryCatalogResponse {
+            category_catalog: category_model_to_catalog(model),
+        }),
+    ))
+}
+
+/// List catalog categories, archived ones included unless `active_only` is set
+#[utoipa::path(
+    get,
+    path = "/admin/categories",
+    tag = "Admin",
+    params(
+        ("active_only" = Option<bool>, Query, description = "Leave out archived categories")
+    ),
+    responses(
+        (status = 200, description = "Catalog categories by name", body = CategoryCatalogListResponse)
+    )
+)]
+pub async fn list_categories(
+    State(app_state): State<AppState>,
+    Query(query): Query<CategoryListQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let categories = app_state
+        .database
+        .category_catalog
+        .list_categories(query.active_only)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to list categories: {e}")))?;
+
+    Ok(Json(CategoryCatalogListResponse {
+        category_catalogs: categories.into_iter().map(category_model_to_catalog).collect(),
+    }))
+}
+
+/// Catalog categories grouped under their parents, top-level categories first
+#[utoipa::path(
+    get,
+    path = "/categories/tree",
+    tag = "Category Catalog",
+    params(
+        ("depth" = Option<u32>, Query, description = "Levels to return, 1 for top-level categories only")
+    ),
+    responses(
+        (status = 200, description = "Category tree, each level by name", body = CategoryTreeResponse),
+        (status = 400, description = "Depth is 0")
+    )
+)]
+pub async fn get_category_tree(
+    State(app_state): State<AppState>,
+    Query(query): Query<CategoryTreeQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    if query.depth == Some(0) {
+        return Err(ApiError::BadRequest("depth must be at least 1".to_string()));
+    }
+
+    let categories = app_state
+        .database
+        .category_catalog
+        .get_category_tree(query.depth)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to load category tree: {e}")))?;
+
+    Ok(Json(CategoryTreeResponse { categories }))
+}
+
+/// Get one catalog category
+#[utoipa::path(
+    get,
+    path = "/admin/categories/{category_catalog_id}",
+    tag = "Admin",
+    params(("category_catalog_id" = Uuid, Path, description = "Category Catalog ID")),
+    responses(
+        (status = 200, description = "Category found", body = CategoryCatalogResponse),
+        (status = 404, description = "Category not found")
+    )
+)]
+pub async fn get_category(
+    State(app_state): State<AppState>,
+    Path(category_catalog_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    let model = app_state
+        .database
+        .category_catalog
+        .get_category_catalog_by_id(category_catalog_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to get category: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Category not found".to_string()))?;
+
+    Ok(Json(CategoryCatalogResponse {
+        category_catalog: category_model_to_catalog(model),
+    }))
+}
+
+/// Rename a category or change its description
+#[utoipa::path(
+    put,
+    path = "/admin/categories/{category_catalog_id}",
+    tag = "Admin",
+    request_body = UpdateCategoryDetailsRequest,
+    params(("category_catalog_id" = Uuid, Path, description = "Category Catalog ID")),
+    responses(
+        (status = 200, description = "Category updated", body = CategoryCatalogResponse),
+        (status = 400, description = "Invalid category"),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 404, description = "Category not found")
+    )
+)]
+pub async fn update_category(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(category_catalog_id): Path<Uuid>,
+    StrictJson(request): StrictJson<UpdateCategoryDetailsRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_application_admin(&claims)?;
+
+    if request.name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+        return Err(ApiError::BadRequest("Category name must not be empty".to_string()));
+    }
+
+    let model = app_state
+        .database
+        .category_catalog
+        .update_category_catalog(category_catalog_id, request.name, request.description, None, None)
+        .await
+        .map_err(|e| match e {
+            DbErr::RecordNotFound(_) => ApiError::NotFound("Category not found".to_string()),
+            e => ApiError::InternalServerError(format!("Failed to update category: {e}")),
+        })?;
+
+    Ok(Json(CategoryCatalogResponse {
+        category_catalog: category_model_to_catalog(model),
+    }))
+}
+
+/// Whether `color` is a `#RRGGBB` hex color
+fn is_hex_color(color: &str) -> bool {
+    color.len() == 7
+        && color.starts_with('#')
+        && color[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Set the description, icon and color a category card is rendered with
+#[utoipa::path(
+    put,
+    path = "/admin/categories/{category_catalog_id}/metadata",
+    tag = "Admin",
+    request_body = CategoryMetadataRequest,
+    params(("category_catalog_id" = Uuid, Path, description = "Category Catalog ID")),
+    responses(
+        (status = 200, description = "Category updated", body = CategoryCatalogResponse),
+        (status = 400, description = "Invalid icon name or color"),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 404, description = "Category not found")
+    )
+)]
+pub async fn update_category_metadata(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(category_catalog_id): Path<Uuid>,
+    StrictJson(request): StrictJson<CategoryMetadataRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_application_admin(&claims)?;
+
+    if let Some(color_hex) = request.color_hex.as_deref() {
+        if !is_hex_color(color_hex) {
+            return Err(ApiError::BadRequest(format!(
+                "color_hex must look like #RRGGBB, got {color_hex:?}"
+            )));
+        }
+    }
+    if request.icon_name.as_deref().is_some_and(|icon| icon.is_empty() || icon.len() > 64) {
+        return Err(ApiError::BadRequest("icon_name must be 1 to 64 characters".to_string()));
+    }
+
+    let model = app_state
+        .database
+        .category_catalog
+        .update_display_metadata(category_catalog_id, request.description, request.icon_name, request.color_hex)
+        .await
+        .map_err(|e| match e {
+            DbErr::RecordNotFound(_) => ApiError::NotFound("Category not found".to_string()),
+            e => ApiError::InternalServerError(format!("Failed to update category: {e}")),
+        })?;
+
+    Ok(Json(CategoryCatalogResponse {
+        category_catalog: category_model_to_catalog(model),
+    }))
+}
+
+/// Archive a category so it can no longer be assigned
+#[utoipa::path(
+    delete,
+    path = "/admin/categories/{category_catalog_id}",
+    tag = "Admin",
+    params(("category_catalog_id" = Uuid, Path, description = "Category Catalog ID")),
+    responses(
+        (status = 200, description = "Category archived", body = CategoryCatalogResponse),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 404, description = "Category not found"),
+        (status = 409, description = "Category is still assigned to an organization")
+    )
+)]
+pub async fn archive_category(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(category_catalog_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_application_admin(&claims)?;
+
+    let model = app_state
+        .database
+        .category_catalog
+        .deactivate_category(category_catalog_id)
+        .await
+        .map_err(|e| match e {
+            ArchiveCategoryError::NotFound => ApiError::NotFound("Category not found".to_string()),
+            ArchiveCategoryError::Assigned => {
+                ApiError::Conflict("Category is still assigned to an organization".to_string())
+            }
+            ArchiveCategoryError::Database(e) => ApiError::InternalServerError(format!("Failed to archive category: {e}")),
+        })?;
+
+    Ok(Json(CategoryCatalogResponse {
+        category_catalog: category_model_to_catalog(model),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{claims, keycloak_config};
+    use crate::common::database::entity::organization_categories;
+    use crate::common::state::AppDatabase;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use sea_orm::{ConnectionTrait, Database, Schema};
+    use serde_json::{json, Value};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    async fn setup() -> Result<AppState, Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(category_catalog::Entity),
+            schema.create_table_from_entity(organization_categories::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        Ok(AppState::new(
+            keycloak_config("http://localhost:8080"),
+            AppDatabase::new(Arc::new(db)).await,
+        )
+        .await)
+    }
+
+    fn app(app_state: AppState, claims: Claims) -> Router {
+        Router::new()
+            .route("/api/admin/categories", get(list_categories).post(create_category))
+            .route(
+                "/api/admin/categories/:category_catalog_id",
+                get(get_category).put(update_category).delete(archive_category),
+            )
+            .route(
+                "/api/admin/categories/:category_catalog_id/metadata",
+                axum::routing::put(update_category_metadata),
+            )
+            .route("/api/categories/tree", get(get_category_tree))
+            .layer(Extension(claims))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state)
+    }
+
+    async fn send(
+        app_state: &AppState,
+        roles: &[&str],
+        method: &str,
+        uri: &str,
+        body: Option<Value>,
+    ) -> Result<(StatusCode, Value), Box<dyn std::error::Error>> {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("content-type", "application/json");
+        let request = match body {
+            Some(body) => request.body(Body::from(body.to_string()))?,
+            None => request.body(Body::empty())?,
+        };
+        let response = app(app_state.clone(), claims("user-1", roles)).oneshot(request).await?;
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        let value = if bytes.is_empty() { Value::Null } else { serde_json::from_slice(&bytes)? };
+        Ok((status, value))
+    }
+
+    async fn create(app_state: &AppState, name: &str) -> Result<String, Box<dyn std::error::Error>> {
+        let (status, body) = send(
+            app_state,
+            &["application_admin"],
+            "POST",
+            "/api/admin/categories",
+            Some(json!({ "name": name, "description": "About", "template_id": "sustainability_template_1" })),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::CREATED);
+        Ok(body["category_catalog"]["category_catalog_id"].as_str().unwrap().to_string())
+    }
+
+    #[tokio::test]
+    async fn test_create_and_get_category() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let id = create(&app_state, "Environment").await?;
+
+        let (status, body) = send(&app_state, &["Org_User"], "GET", &format!("/api/admin/categories/{id}"), None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["category_catalog"]["name"], "Environment");
+        assert_eq!(body["category_catalog"]["is_active"], true);
+        assert!(body["category_catalog"]["deactivated_at"].is_null());
+
+        let (status, _) = send(&app_state, &["Org_User"], "GET", &format!("/api/admin/categories/{}", Uuid::new_v4()), None).await?;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_endpoints_require_application_admin() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let id = create(&app_state, "Environment").await?;
+
+        let (status, _) = send(
+            &app_state,
+            &["org_admin"],
+            "POST",
+            "/api/admin/categories",
+            Some(json!({ "name": "Social", "template_id": "sustainability_template_1" })),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let uri = format!("/api/admin/categories/{id}");
+        let (status, _) = send(&app_state, &["org_admin"], "PUT", &uri, Some(json!({ "name": "Renamed" }))).await?;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = send(&app_state, &["org_admin"], "DELETE", &uri, None).await?;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_category() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let id = create(&app_state, "Environment").await?;
+
+        let (status, body) = send(
+            &app_state,
+            &["application_admin"],
+            "PUT",
+            &format!("/api/admin/categories/{id}"),
+            Some(json!({ "name": "Environmental", "description": "Climate and resources" })),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["category_catalog"]["name"], "Environmental");
+        assert_eq!(body["category_catalog"]["description"], "Climate and resources");
+
+        let (status, _) = send(
+            &app_state,
+            &["application_admin"],
+            "PUT",
+            &format!("/api/admin/categories/{id}"),
+            Some(json!({ "name": "  " })),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_archive_category_and_list_active_only() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let archived = create(&app_state, "Environment").await?;
+        create(&app_state, "Social").await?;
+
+        let (status, body) = send(&app_state, &["application_admin"], "DELETE", &format!("/api/admin/categories/{archived}"), None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["category_catalog"]["is_active"], false);
+        assert!(body["category_catalog"]["deactivated_at"].is_string());
+
+        let (_, all) = send(&app_state, &["Org_User"], "GET", "/api/admin/categories", None).await?;
+        assert_eq!(all["category_catalogs"].as_array().unwrap().len(), 2);
+
+        let (_, active) = send(&app_state, &["Org_User"], "GET", "/api/admin/categories?active_only=true", None).await?;
+        let active = active["category_catalogs"].as_array().unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0]["name"], "Social");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_archive_assigned_category_conflicts() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let id = create(&app_state, "Environment").await?;
+        app_state
+            .database
+            .organization_categories
+            .create_organization_category(Uuid::new_v4(), "org-1".to_string(), Uuid::parse_str(&id)?, 100, 0)
+            .await?;
+
+        let uri = format!("/api/admin/categories/{id}");
+        let (status, _) = send(&app_state, &["application_admin"], "DELETE", &uri, None).await?;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let (_, body) = send(&app_state, &["Org_User"], "GET", &uri, None).await?;
+        assert!(body["category_catalog"]["deactivated_at"].is_null());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_category_metadata() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let id = create(&app_state, "Environment").await?;
+
+        let (status, body) = send(
+            &app_state,
+            &["application_admin"],
+            "PUT",
+            &format!("/api/admin/categories/{id}/metadata"),
+            Some(json!({ "description": "Energy and emissions", "icon_name": "leaf", "color_hex": "#2e7D32" })),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["category_catalog"]["color_hex"], "#2e7D32");
+
+        let (_, list) = send(&app_state, &["Org_User"], "GET", "/api/admin/categories", None).await?;
+        let category = &list["category_catalogs"][0];
+        assert_eq!(category["description"], "Energy and emissions");
+        assert_eq!(category["icon_name"], "leaf");
+        assert_eq!(category["metadata"], json!({}));
+
+        let (_, tree) = send(&app_state, &["Org_User"], "GET", "/api/categories/tree", None).await?;
+        assert_eq!(tree["categories"][0]["icon_name"], "leaf");
+        assert_eq!(tree["categories"][0]["color_hex"], "#2e7D32");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_update_category_metadata_rejects_invalid_colors() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let id = create(&app_state, "Environment").await?;
+        let uri = format!("/api/admin/categories/{id}/metadata");
+
+        for color in ["2E7D32", "#2E7D3", "#2E7D320", "#GGGGGG", "red", "", "#2E7D3é"] {
+            let (status, _) = send(&app_state, &["application_admin"], "PUT", &uri, Some(json!({ "color_hex": color }))).await?;
+            assert_eq!(status, StatusCode::BAD_REQUEST, "{color:?} was accepted");
+        }
+
+        let (_, body) = send(&app_state, &["Org_User"], "GET", &format!("/api/admin/categories/{id}"), None).await?;
+        assert!(body["category_catalog"]["color_hex"].is_null());
+        assert_eq!(body["category_catalog"]["description"], "About");
+
+        let (status, _) = send(&app_state, &["org_admin"], "PUT", &uri, Some(json!({ "color_hex": "#FFFFFF" }))).await?;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        Ok(())
+    }
+}
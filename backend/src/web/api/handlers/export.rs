@@ -0,0 +1,510 @@
+use crate::common::database::entity::{
+    assessments, assessments_response, assessments_submission, submission_reports,
+};
+use crate::common::models::claims::Claims;
+use crate::common::services::keycloak_service::is_not_found;
+use crate::web::api::error::ApiError;
+use crate::web::routes::AppState;
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::{header, HeaderValue},
+    response::{
+        sse::{Event, Sse},
+        IntoResponse, Response,
+    },
+    Json,
+};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use sea_orm::{ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder};
+use std::convert::Infallible;
+use std::io::Write;
+use uuid::Uuid;
+
+/// Organizations with at least this many responses are streamed instead of returned inline
+const INLINE_EXPORT_RESPONSE_LIMIT: u64 = 1_000;
+
+/// Organizations with at least this many responses must be exported by a background job
+const ONLINE_EXPORT_RESPONSE_LIMIT: u64 = 10_000;
+
+/// Responses sent per event when streaming an export
+const STREAMED_RESPONSES_PER_EVENT: usize = 500;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    Zip,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExportQuery {
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+/// Everything stored about an organization, one JSON array per entity type
+struct OrganizationExport {
+    org_name: String,
+    sections: Vec<(&'static str, Value)>,
+}
+
+impl OrganizationExport {
+    fn into_json(self) -> Value {
+        Value::Object(
+            self.sections
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        )
+    }
+
+    /// In-memory ZIP archive with a `{section}.json` file per entity type
+    fn to_zip(&self) -> Result<Vec<u8>, ApiError> {
+        let options = zip::write::SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated);
+        let mut archive = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+
+        for (name, value) in &self.sections {
+            let contents = serde_json::to_vec_pretty(value).map_err(|e| {
+                ApiError::InternalServerError(format!("Failed to serialize {name}: {e}"))
+            })?;
+            archive
+                .start_file(format!("{name}.json"), options)
+                .and_then(|_| archive.write_all(&contents).map_err(Into::into))
+                .map_err(|e| ApiError::InternalServerError(format!("Failed to write {name}.json: {e}")))?;
+        }
+
+        let cursor = archive
+            .finish()
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to finish archive: {e}")))?;
+        Ok(cursor.into_inner())
+    }
+
+    /// One SSE event per section, with responses split over several events
+    fn into_events(self) -> Vec<Result<Event, Infallible>> {
+        let mut events = Vec::new();
+        for (name, value) in self.sections {
+            match value {
+                Value::Array(items) if name == "responses" => {
+                    for chunk in items.chunks(STREAMED_RESPONSES_PER_EVENT) {
+                        events.push(section_event(name, &Value::Array(chunk.to_vec())));
+                    }
+                }
+                value => events.push(section_event(name, &value)),
+            }
+        }
+        events.push(Ok(Event::default().event("complete").data("{}")));
+        events
+    }
+}
+
+fn section_event(name: &str, value: &Value) -> Result<Event, Infallible> {
+    Ok(Event::default().event(name).data(value.to_string()))
+}
+
+fn assessment_json(model: assessments::Model) -> Value {
+    json!({
+        "assessment_id": model.assessment_id,
+        "org_id": model.org_id,
+        "language": model.language,
+        "name": model.name,
+        "created_at": model.created_at.to_rfc3339(),
+    })
+}
+
+fn response_json(model: assessments_response::Model) -> Value {
+    json!({
+        "response_id": model.response_id,
+        "assessment_id": model.assessment_id,
+        "question_revision_id": model.question_revision_id,
+        "response": model.response,
+        "version": model.version,
+        "updated_at": model.updated_at.to_rfc3339(),
+    })
+}
+
+fn submission_json(model: assessments_submission::Model) -> Value {
+    json!({
+        "submission_id": model.submission_id,
+        "org_id": model.org_id,
+        "org_name": model.org_name,
+        "content": model.content,
+        "submitted_at": model.submitted_at.to_rfc3339(),
+        "status": model.status.to_string(),
+        "reviewed_at": model.reviewed_at.map(|dt| dt.to_rfc3339()),
+    })
+}
+
+fn report_json(model: submission_reports::Model) -> Value {
+    json!({
+        "report_id": model.report_id,
+        "submission_id": model.submission_id,
+        "report_type": model.report_type,
+        "status": model.status,
+        "generated_at": model.generated_at.to_rfc3339(),
+        "data": model.data,
+    })
+}
+
+async fn collect_export(
+    app_state: &AppState,
+    token: &str,
+    org_id: &str,
+) -> Result<(OrganizationExport, u64), ApiError> {
+    let conn = app_state.database.get_connection();
+
+    let (organization, members) = tokio::try_join!(
+        app_state.keycloak_service.get_organization(token, org_id),
+        app_state.keycloak_service.get_organization_members(token, org_id),
+    )
+    .map_err(|e| {
+        if is_not_found(&e) {
+            ApiError::NotFound("Organization not found".to_string())
+        } else {
+            ApiError::InternalServerError(format!("Failed to fetch organization: {e}"))
+        }
+    })?;
+
+    let assessment_models = assessments::Entity::find()
+        .filter(assessments::Column::OrgId.eq(org_id))
+        .order_by_asc(assessments::Column::CreatedAt)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessments: {e}")))?;
+    let assessment_ids: Vec<Uuid> = assessment_models.iter().map(|a| a.assessment_id).collect();
+
+    let responses_query = assessments_response::Entity::find()
+        .filter(assessments_response::Column::AssessmentId.is_in(assessment_ids));
+    let response_count = responses_query
+        .clone()
+        .count(conn)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to count responses: {e}")))?;
+
+    if response_count >= ONLINE_EXPORT_RESPONSE_LIMIT {
+        return Err(ApiError::BadRequest(format!(
+            "Organization has {response_count} responses; exports of {ONLINE_EXPORT_RESPONSE_LIMIT} or more must run as a background job"
+        )));
+    }
+
+    let response_models = responses_query
+        .order_by_asc(assessments_response::Column::UpdatedAt)
+        .all(conn)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch responses: {e}")))?
+        .into_iter()
+        .map(|model| app_state.database.assessments_response.decrypt(model))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decrypt response: {e}")))?;
+
+    let mut submission_models = app_state
+        .database
+        .assessments_submission
+        .get_submissions_by_org(org_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submissions: {e}")))?;
+    for submission in &mut submission_models {
+        app_state
+            .database
+            .assessments_response
+            .decrypt_content(&mut submission.content)
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to decrypt submission: {e}")))?;
+    }
+    let submission_ids: Vec<Uuid> = submission_models.iter().map(|s| s.submission_id).collect();
+
+    let report_models = if submission_ids.is_empty() {
+        Vec::new()
+    } else {
+        submission_reports::Entity::find()
+            .filter(submission_reports::Column::SubmissionId.is_in(submission_ids))
+            .order_by_asc(submission_reports::Column::GeneratedAt)
+            .all(conn)
+            .await
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch reports: {e}")))?
+    };
+
+    let org_name = organization.name.clone();
+    let to_value = |value: Result<Value, serde_json::Error>| {
+        value.map_err(|e| ApiError::InternalServerError(format!("Failed to serialize export: {e}")))
+    };
+
+    let export = OrganizationExport {
+        org_name,
+        sections: vec![
+            ("organization", to_value(serde_json::to_value(&organization))?),
+            ("members", to_value(serde_json::to_value(&members))?),
+            ("assessments", assessment_models.into_iter().map(assessment_json).collect()),
+            ("responses", response_models.into_iter().map(response_json).collect()),
+            ("submissions", submission_models.into_iter().map(submission_json).collect()),
+            ("reports", report_models.into_iter().map(report_json).collect()),
+        ],
+    };
+
+    Ok((export, response_count))
+}
+
+/// Export all data held for an organization
+///
+/// Small organizations get a single JSON document; larger ones are streamed as
+/// server-sent events, one per entity type. `format=zip` returns an archive with a
+/// JSON file per entity type instead.
+#[utoipa::path(
+    get,
+    path = "/admin/organizations/{org_id}/export",
+    tag = "Admin",
+    params(
+        ("org_id" = String, Path, description = "Keycloak organization ID"),
+        ("format" = Option<String>, Query, description = "json (default) or zip")
+    ),
+    responses(
+        (status = 200, description = "Organization data as JSON, an event stream, or a ZIP archive"),
+        (status = 400, description = "Too many responses to export online"),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 404, description = "Organization not found")
+    )
+)]
+pub async fn export_organization(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    Path(org_id): Path<String>,
+    Query(query): Query<ExportQuery>,
+) -> Result<Response, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can export organization data".to_string(),
+        ));
+    }
+
+    let (export, response_count) = collect_export(&app_state, &token, &org_id).await?;
+
+    match query.format {
+        ExportFormat::Json if response_count < INLINE_EXPORT_RESPONSE_LIMIT => {
+            Ok(Json(export.into_json()).into_response())
+        }
+        ExportFormat::Json => {
+            let events = futures::stream::iter(export.into_events());
+            Ok(Sse::new(events).into_response())
+        }
+        ExportFormat::Zip => {
+            // Quotes and control characters would break out of the header's quoted string
+            let org_name: String = export
+                .org_name
+                .chars()
+                .filter(|c| *c != '"' && *c != '\\' && !c.is_control())
+                .collect();
+            let filename = format!(
+                "{org_name}-export-{}.zip",
+                chrono::Utc::now().format("%Y-%m-%d")
+            );
+            let content_disposition =
+                HeaderValue::from_str(&format!("attachment; filename=\"{filename}\""))
+                    .map_err(|e| ApiError::InternalServerError(format!("Invalid header value: {e}")))?;
+
+            let archive = export.to_zip()?;
+            Ok((
+                [
+                    (header::CONTENT_TYPE, HeaderValue::from_static("application/zip")),
+                    (header::CONTENT_DISPOSITION, content_disposition),
+                ],
+                archive,
+            )
+                .into_response())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{claims, keycloak_config, serve};
+    use crate::common::database::entity::assessments_submission::SubmissionStatus;
+    use crate::common::services::encryption::{EncryptionService, KEY_VERSION};
+    use crate::common::state::AppDatabase;
+    use axum::{body::Body, http::{Request, StatusCode}, routing::get, Router};
+    use chrono::Utc;
+    use sea_orm::{ActiveModelTrait, ConnectionTrait, Database, Schema, Set};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    const REALM_PATH: &str = "/admin/realms/test-realm";
+
+    /// Keycloak stand-in knowing a single organization `test-org` with one member
+    async fn fake_keycloak() -> String {
+        let app = Router::new()
+            .route(
+                &format!("{REALM_PATH}/organizations/test-org"),
+                get(|| async {
+                    Json(json!({
+                        "id": "test-org",
+                        "name": "Test Cooperative",
+                        "alias": null,
+                        "enabled": true,
+                        "description": null,
+                        "redirectUrl": null,
+                        "domains": [],
+                        "attributes": {}
+                    }))
+                }),
+            )
+            .route(
+                &format!("{REALM_PATH}/organizations/test-org/members"),
+                get(|| async {
+                    Json(json!([{ "id": "member-1", "username": "member-1", "email": "member@example.com" }]))
+                }),
+            );
+
+        serve(app).await
+    }
+
+    /// Key the seeded answer to a sensitive question is encrypted with
+    fn encryption() -> EncryptionService {
+        EncryptionService::new([5; 32])
+    }
+
+    /// One reviewed assessment with a response and a report for `test-org`, plus an
+    /// assessment of another organization that must not leak into the export. The
+    /// answer is to a sensitive question, so it is stored encrypted.
+    async fn seeded_db() -> Result<sea_orm::DatabaseConnection, sea_orm::DbErr> {
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(submission_reports::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        let assessment_id = Uuid::new_v4();
+        for (id, org_id) in [(assessment_id, "test-org"), (Uuid::new_v4(), "other-org")] {
+            assessments::ActiveModel {
+                assessment_id: Set(id),
+                org_id: Set(org_id.to_string()),
+                language: Set("en".to_string()),
+                name: Set(format!("{org_id} assessment")),
+                created_at: Set(Utc::now()),
+            }
+            .insert(&db)
+            .await?;
+        }
+
+        assessments_response::ActiveModel {
+            response_id: Set(Uuid::new_v4()),
+            assessment_id: Set(assessment_id),
+            question_revision_id: Set(Uuid::new_v4()),
+            response: Set(encryption().encrypt_text("yes").unwrap()),
+            version: Set(1),
+            updated_at: Set(Utc::now()),
+            key_version: Set(Some(KEY_VERSION as i16)),
+        }
+        .insert(&db)
+        .await?;
+
+        assessments_submission::ActiveModel {
+            submission_id: Set(assessment_id),
+            org_id: Set("test-org".to_string()),
+            org_name: Set("Test Cooperative".to_string()),
+            content: Set(json!({ "responses": [
+                { "response": encryption().encrypt_text("yes").unwrap(), "key_version": KEY_VERSION },
+            ] })),
+            submitted_at: Set(Utc::now()),
+            status: Set(SubmissionStatus::Reviewed),
+            reviewed_at: Set(Some(Utc::now())),
+            reopened_at: Set(None),
+            reopened_by: Set(None),
+        }
+        .insert(&db)
+        .await?;
+
+        submission_reports::ActiveModel {
+            report_id: Set(Uuid::new_v4()),
+            submission_id: Set(assessment_id),
+            report_type: Set("sustainability".to_string()),
+            status: Set("generated".to_string()),
+            generated_at: Set(Utc::now()),
+            data: Set(Some(json!({ "score": 42 }))),
+        }
+        .insert(&db)
+        .await?;
+
+        Ok(db)
+    }
+
+    async fn export(uri: &str, roles: &[&str]) -> Response {
+        let app_state = AppState::new(
+            keycloak_config(fake_keycloak().await),
+            AppDatabase::new(Arc::new(seeded_db().await.unwrap()))
+                .await
+                .with_encryption(Arc::new(encryption())),
+        )
+        .await;
+
+        Router::new()
+            .route("/api/admin/organizations/:org_id/export", get(export_organization))
+            .layer(Extension(claims("admin-user", roles)))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state)
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_export_organization_as_json() {
+        let response = export("/api/admin/organizations/test-org/export", &["application_admin"]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let export: Value = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(export["organization"]["name"], "Test Cooperative");
+        assert_eq!(export["members"][0]["email"], "member@example.com");
+        assert_eq!(export["assessments"].as_array().unwrap().len(), 1);
+        assert_eq!(export["assessments"][0]["org_id"], "test-org");
+        assert_eq!(export["responses"][0]["response"], "yes");
+        assert_eq!(export["submissions"][0]["status"], "reviewed");
+        assert_eq!(export["submissions"][0]["content"]["responses"][0]["response"], "yes");
+        assert_eq!(export["reports"][0]["data"]["score"], 42);
+    }
+
+    #[tokio::test]
+    async fn test_export_organization_as_zip() {
+        let response = export("/api/admin/organizations/test-org/export?format=zip", &["application_admin"]).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let disposition = response.headers()[header::CONTENT_DISPOSITION].to_str().unwrap().to_string();
+        assert!(disposition.starts_with("attachment; filename=\"Test Cooperative-export-"));
+        assert!(disposition.ends_with(".zip\""));
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let archive = zip::ZipArchive::new(std::io::Cursor::new(body.to_vec())).unwrap();
+        let mut names: Vec<&str> = archive.file_names().collect();
+        names.sort_unstable();
+        assert_eq!(
+            names,
+            ["assessments.json", "members.json", "organization.json", "reports.json", "responses.json", "submissions.json"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_requires_application_admin() {
+        let response = export("/api/admin/organizations/test-org/export", &["org_admin"]).await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_export_unknown_organization_is_not_found() {
+        let response = export(
+            "/api/admin/organizations/unknown-org/export",
+            &["application_admin"],
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
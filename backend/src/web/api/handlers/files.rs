@@ -1,16 +1,19 @@
 use axum::{
-    body::Bytes,
-    extract::{Extension, Multipart, Path, State},
-    http::{header, HeaderValue, StatusCode},
+    body::{Body, Bytes},
+    extract::{multipart::MultipartError, Extension, Multipart, Path, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
     response::IntoResponse,
     Json,
 };
 use uuid::Uuid;
 
+use crate::common::database::entity::file::FileContent;
 use crate::common::models::claims::Claims;
 use crate::web::routes::AppState;
 use crate::web::api::error::ApiError;
+use crate::web::api::strict_json::StrictJson;
 use crate::web::api::models::*;
+use crate::web::api::handlers::responses::ensure_assessment_unlocked;
 
 // Helper: check if user is member of org by org_id
 fn is_member_of_org_by_id(claims: &crate::common::models::claims::Claims, org_id: &str) -> bool {
@@ -23,45 +26,191 @@ fn is_member_of_org_by_id(claims: &crate::common::models::claims::Claims, org_id
         .unwrap_or(false)
 }
 
+/// Size of the chunks a download is streamed in
+const DOWNLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Content types accepted for uploads: PDFs, images and common office documents
+const ALLOWED_CONTENT_TYPES: [&str; 12] = [
+    "application/pdf",
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "application/msword",
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    "application/vnd.ms-excel",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+    "application/vnd.openxmlformats-officedocument.presentationml.presentation",
+    "text/plain",
+    "text/csv",
+];
+
+/// Allowed types that have no magic bytes and are checked for valid UTF-8 instead
+const TEXT_CONTENT_TYPES: [&str; 2] = ["text/plain", "text/csv"];
+
+fn multipart_error(e: MultipartError, context: &str) -> ApiError {
+    if e.status() == StatusCode::PAYLOAD_TOO_LARGE {
+        ApiError::PayloadTooLarge("File size exceeds maximum allowed size".to_string())
+    } else {
+        ApiError::BadRequest(format!("{context}: {e}"))
+    }
+}
+
+/// Check the declared content type against the allow-list and the file's magic bytes.
+///
+/// Returns the normalized content type (lowercase, without parameters) to store.
+fn validate_content_type(declared: &str, data: &[u8]) -> Result<String, ApiError> {
+    let declared = declared
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    let declared = match declared.as_str() {
+        "image/jpg" | "image/pjpeg" => "image/jpeg".to_string(),
+        _ => declared,
+    };
+
+    if !ALLOWED_CONTENT_TYPES.contains(&declared.as_str()) {
+        return Err(ApiError::UnsupportedMediaType(format!(
+            "Content type '{declared}' is not allowed"
+        )));
+    }
+
+    let matches = match infer::get(data) {
+        Some(kind) => kind.mime_type() == declared,
+        None => {
+            TEXT_CONTENT_TYPES.contains(&declared.as_str()) && std::str::from_utf8(data).is_ok()
+        }
+    };
+
+    if !matches {
+        return Err(ApiError::UnsupportedMediaType(format!(
+            "File content does not match declared content type '{declared}'"
+        )));
+    }
+
+    Ok(declared)
+}
+
+/// API view of a stored file, with defaults for metadata fields missing on older uploads
+fn file_model_to_metadata(file_model: crate::common::database::entity::file::Model) -> FileMetadata {
+    let default_map = serde_json::Map::new();
+    let metadata_obj = file_model.metadata.as_object().unwrap_or(&default_map);
+
+    let filename = metadata_obj
+        .get("filename")
+        .and_then(|v| v.as_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    let size = metadata_obj
+        .get("size")
+        .and_then(|v| v.as_i64())
+        .unwrap_or(file_model.content.len() as i64);
+
+    let content_type = metadata_obj
+        .get("content_type")
+        .and_then(|v| v.as_str())
+        .unwrap_or("application/octet-stream")
+        .to_string();
+
+    let created_at = metadata_obj
+        .get("created_at")
+        .and_then(|v| v.as_str())
+        .unwrap_or(&chrono::Utc::now().to_rfc3339())
+        .to_string();
+
+    FileMetadata {
+        file_id: file_model.id,
+        filename,
+        size,
+        content_type,
+        created_at,
+        metadata: Some(file_model.metadata),
+    }
+}
+
+/// Load an assessment whose response files the caller may manage: one of their
+/// own organization's, or any assessment for application admins.
+async fn authorize_assessment_files(
+    app_state: &AppState,
+    claims: &Claims,
+    assessment_id: Uuid,
+) -> Result<crate::common::database::entity::assessments::Model, ApiError> {
+    let assessment_model = app_state
+        .database
+        .assessments
+        .get_assessment_by_id(assessment_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessment: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Assessment not found".to_string()))?;
+
+    let is_owner = claims.get_org_id().as_deref() == Some(assessment_model.org_id.as_str());
+    if !is_owner && !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "You don't have permission to access files of this assessment".to_string(),
+        ));
+    }
+
+    Ok(assessment_model)
+}
+
 /// Upload a file
 #[utoipa::path(
     post,
     path = "/files",
     tag = "File",
-    responses((status = 201, description = "File uploaded"))
+    responses(
+        (status = 201, description = "File uploaded"),
+        (status = 413, description = "File exceeds the configured size limit"),
+        (status = 415, description = "Content type not allowed or not matching the file content")
+    )
 )]
 pub async fn upload_file(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     mut multipart: Multipart,
 ) -> Result<impl IntoResponse, ApiError> {
+    let max_file_bytes = app_state.upload_config.max_file_bytes;
+
     // Extract file data from multipart form
     let mut file_data: Option<Bytes> = None;
     let mut filename: Option<String> = None;
     let mut content_type: Option<String> = None;
     let mut additional_metadata: Option<serde_json::Value> = None;
 
-    while let Some(field) = multipart
+    while let Some(mut field) = multipart
         .next_field()
         .await
-        .map_err(|e| ApiError::BadRequest(format!("Failed to process multipart form: {e}")))?
+        .map_err(|e| multipart_error(e, "Failed to process multipart form"))?
     {
         let name = field.name().unwrap_or("").to_string();
 
         if name == "file" {
             filename = field.file_name().map(|s| s.to_string());
             content_type = field.content_type().map(|s| s.to_string());
-            file_data = Some(
-                field
-                    .bytes()
-                    .await
-                    .map_err(|e| ApiError::BadRequest(format!("Failed to read file data: {e}")))?,
-            );
+
+            // Read in chunks so oversized files are rejected without buffering them whole
+            let mut data = Vec::new();
+            while let Some(chunk) = field
+                .chunk()
+                .await
+                .map_err(|e| multipart_error(e, "Failed to read file data"))?
+            {
+                if data.len() + chunk.len() > max_file_bytes {
+                    return Err(ApiError::PayloadTooLarge(format!(
+                        "File size exceeds maximum allowed size ({max_file_bytes} bytes)"
+                    )));
+                }
+                data.extend_from_slice(&chunk);
+            }
+            file_data = Some(Bytes::from(data));
         } else if name == "metadata" {
             let data = field
                 .bytes()
                 .await
-                .map_err(|e| ApiError::BadRequest(format!("Failed to read metadata: {e}")))?;
+                .map_err(|e| multipart_error(e, "Failed to read metadata"))?;
             let text = String::from_utf8_lossy(&data);
             additional_metadata = Some(
                 serde_json::from_str(&text)
@@ -75,38 +224,26 @@ pub async fn upload_file(
         file_data.ok_or_else(|| ApiError::BadRequest("No file provided".to_string()))?;
     let filename =
         filename.ok_or_else(|| ApiError::BadRequest("No filename provided".to_string()))?;
-    let content_type = content_type.unwrap_or_else(|| "application/octet-stream".to_string());
-
-    // Validate file size (e.g., max 1MB)
-    const MAX_FILE_SIZE: usize = 1024 * 1024; // 1MB
-    if file_data.len() > MAX_FILE_SIZE {
-        return Err(ApiError::BadRequest(
-            "File size exceeds maximum allowed size (1MB)".to_string(),
-        ));
-    }
+    let content_type = validate_content_type(
+        content_type.as_deref().unwrap_or("application/octet-stream"),
+        &file_data,
+    )?;
 
     // Create metadata JSON with file information
     let now = chrono::Utc::now().to_rfc3339();
     let size = file_data.len() as i64;
 
-    let mut metadata = serde_json::json!({
-        "filename": filename,
-        "content_type": content_type,
-        "size": size,
-        "created_at": now,
-        "uploaded_by": claims.sub
-    });
-
-    // Merge additional metadata if provided
-    if let Some(additional) = additional_metadata {
-        if let (Some(metadata_obj), Some(additional_obj)) =
-            (metadata.as_object_mut(), additional.as_object())
-        {
-            for (key, value) in additional_obj {
-                metadata_obj.insert(key.clone(), value.clone());
-            }
-        }
-    }
+    // Client metadata goes in first so it cannot override what the server determined
+    let mut metadata = match additional_metadata {
+        Some(serde_json::Value::Object(additional)) => additional,
+        _ => serde_json::Map::new(),
+    };
+    metadata.insert("filename".to_string(), filename.into());
+    metadata.insert("content_type".to_string(), content_type.into());
+    metadata.insert("size".to_string(), size.into());
+    metadata.insert("created_at".to_string(), now.into());
+    metadata.insert("uploaded_by".to_string(), claims.sub.into());
+    let metadata = serde_json::Value::Object(metadata);
 
     // Store the file in the database
     app_state
@@ -120,20 +257,26 @@ pub async fn upload_file(
 }
 
 /// Download a file
+///
+/// Files attached to responses are only served to members of the organization that
+/// owns the assessment; files not attached yet are only served to their uploader.
+/// Application admins can download any file.
 #[utoipa::path(
     get,
     path = "/files/{file_id}",
     tag = "File",
     params(("file_id" = uuid::Uuid, Path, description = "File ID")),
-    responses((status = 200, description = "Binary file content"), (status = 404, description = "Not found"))
+    responses(
+        (status = 200, description = "Binary file content"),
+        (status = 403, description = "File belongs to another organization"),
+        (status = 404, description = "Not found")
+    )
 )]
 pub async fn download_file(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path(file_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let _user_id = &claims.sub; // For future use in access control
-
     // Fetch the file from the database
     let file_model = app_state
         .database
@@ -151,11 +294,37 @@ pub async fn download_file(
     let default_map = serde_json::Map::new();
     let metadata_obj = file_model.metadata.as_object().unwrap_or(&default_map);
 
-    let filename = metadata_obj
+    let owner_org_ids = app_state
+        .database
+        .assessments_response_file
+        .get_org_ids_for_file(file_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch file owner: {e}")))?;
+
+    let can_access = if claims.is_application_admin() {
+        true
+    } else if owner_org_ids.is_empty() {
+        metadata_obj.get("uploaded_by").and_then(|v| v.as_str()) == Some(claims.sub.as_str())
+    } else {
+        owner_org_ids
+            .iter()
+            .any(|org_id| is_member_of_org_by_id(&claims, org_id))
+    };
+
+    if !can_access {
+        return Err(ApiError::Forbidden(
+            "You don't have permission to access this file".to_string(),
+        ));
+    }
+
+    // Quotes and control characters would break out of the header's quoted string
+    let filename: String = metadata_obj
         .get("filename")
         .and_then(|v| v.as_str())
         .unwrap_or("unknown")
-        .to_string();
+        .chars()
+        .filter(|c| *c != '"' && *c != '\\' && !c.is_control())
+        .collect();
 
     let content_type = metadata_obj
         .get("content_type")
@@ -169,12 +338,37 @@ pub async fn download_file(
     let content_type_header = HeaderValue::from_str(content_type)
         .map_err(|e| ApiError::InternalServerError(format!("Invalid content type: {e}")))?;
 
-    let headers = [
-        (header::CONTENT_TYPE, content_type_header),
-        (header::CONTENT_DISPOSITION, content_disposition),
-    ];
+    let mut headers = HeaderMap::new();
+    headers.insert(header::CONTENT_TYPE, content_type_header);
+    headers.insert(header::CONTENT_DISPOSITION, content_disposition);
 
-    Ok((headers, file_model.content))
+    let content = app_state
+        .database
+        .file
+        .open_content(file_model)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to read file: {e}")))?;
+
+    let body = match content {
+        FileContent::Inline(content) => {
+            // Hand the stored bytes to the body in slices of the same buffer instead of copying them
+            let content = Bytes::from(content);
+            let content_length = content.len();
+            headers.insert(header::CONTENT_LENGTH, HeaderValue::from(content_length));
+
+            let chunks = (0..content_length)
+                .step_by(DOWNLOAD_CHUNK_SIZE)
+                .map(move |start| {
+                    Ok::<_, std::convert::Infallible>(
+                        content.slice(start..(start + DOWNLOAD_CHUNK_SIZE).min(content_length)),
+                    )
+                });
+            Body::from_stream(futures::stream::iter(chunks))
+        }
+        FileContent::Stream(stream) => Body::from_stream(stream),
+    };
+
+    Ok((headers, body))
 }
 
 /// Delete a file
@@ -277,43 +471,52 @@ pub async fn get_file_metadata(
         None => return Err(ApiError::NotFound("File not found".to_string())),
     };
 
-    // Extract metadata from the file
-    let default_map = serde_json::Map::new();
-    let metadata_obj = file_model.metadata.as_object().unwrap_or(&default_map);
+    let metadata = file_model_to_metadata(file_model);
 
-    let filename = metadata_obj
-        .get("filename")
-        .and_then(|v| v.as_str())
-        .unwrap_or("unknown")
-        .to_string();
-
-    let size = metadata_obj
-        .get("size")
-        .and_then(|v| v.as_i64())
-        .unwrap_or(file_model.content.len() as i64);
+    Ok(Json(FileMetadataResponse { metadata }))
+}
 
-    let content_type = metadata_obj
-        .get("content_type")
-        .and_then(|v| v.as_str())
-        .unwrap_or("application/octet-stream")
-        .to_string();
+/// List the files attached to the response for a question
+#[utoipa::path(
+    get,
+    path = "/assessments/{assessment_id}/responses/{question_revision_id}/files",
+    tag = "File",
+    params(
+        ("assessment_id" = uuid::Uuid, Path, description = "Assessment ID"),
+        ("question_revision_id" = uuid::Uuid, Path, description = "Question revision ID")
+    ),
+    responses(
+        (status = 200, description = "Attached files", body = FileListResponse),
+        (status = 403, description = "Assessment belongs to another organization"),
+        (status = 404, description = "Assessment or response not found")
+    )
+)]
+pub async fn list_response_files(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((assessment_id, question_revision_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<FileListResponse>, ApiError> {
+    authorize_assessment_files(&app_state, &claims, assessment_id).await?;
 
-    let created_at = metadata_obj
-        .get("created_at")
-        .and_then(|v| v.as_str())
-        .unwrap_or(&chrono::Utc::now().to_rfc3339())
-        .to_string();
+    let response_model = app_state
+        .database
+        .assessments_response
+        .get_response_by_assessment_and_question(assessment_id, question_revision_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch response: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Response not found".to_string()))?;
 
-    let metadata = FileMetadata {
-        file_id: file_model.id,
-        filename,
-        size,
-        content_type,
-        created_at,
-        metadata: Some(file_model.metadata),
-    };
+    let files = app_state
+        .database
+        .assessments_response_file
+        .get_files_for_response(response_model.response_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch files for response: {e}")))?
+        .into_iter()
+        .map(file_model_to_metadata)
+        .collect();
 
-    Ok(Json(FileMetadataResponse { metadata }))
+    Ok(Json(FileListResponse { files }))
 }
 
 /// Attach a file to a response
@@ -326,13 +529,17 @@ pub async fn get_file_metadata(
         ("response_id" = uuid::Uuid, Path, description = "Response ID")
     ),
     request_body = AttachFileRequest,
-    responses((status = 204, description = "Attached"), (status = 404, description = "Not found"))
+    responses(
+        (status = 204, description = "Attached"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Assessment is locked after submission")
+    )
 )]
 pub async fn attach_file(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path((assessment_id, response_id)): Path<(Uuid, Uuid)>,
-    Json(request): Json<AttachFileRequest>,
+    StrictJson(request): StrictJson<AttachFileRequest>,
 ) -> Result<StatusCode, ApiError> {
     let org_id = claims.get_org_id()
         .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
@@ -365,22 +572,7 @@ pub async fn attach_file(
         // ));
     }
 
-    // Verify that the assessment is in draft status (not submitted)
-    let has_submission = app_state
-        .database
-        .assessments_submission
-        .get_submission_by_assessment_id(assessment_id)
-        .await
-        .map_err(|e| {
-            ApiError::InternalServerError(format!("Failed to check submission status: {e}"))
-        })?
-        .is_some();
-
-    if has_submission {
-        return Err(ApiError::BadRequest(
-            "Cannot attach files to a submitted assessment".to_string(),
-        ));
-    }
+    ensure_assessment_unlocked(&app_state, assessment_id).await?;
 
     // Verify that the response exists and belongs to the assessment
     let response_model = app_state
@@ -431,6 +623,9 @@ pub async fn attach_file(
 }
 
 /// Remove a file from a response
+///
+/// Only the link between the response and the file is deleted; the file itself
+/// stays available to any other response referencing it.
 #[utoipa::path(
     delete,
     path = "/assessments/{assessment_id}/responses/{response_id}/files/{file_id}",
@@ -440,60 +635,21 @@ pub async fn attach_file(
         ("response_id" = uuid::Uuid, Path, description = "Response ID"),
         ("file_id" = uuid::Uuid, Path, description = "File ID")
     ),
-    responses((status = 204, description = "Removed"), (status = 404, description = "Not found"))
+    responses(
+        (status = 204, description = "Removed"),
+        (status = 403, description = "Assessment belongs to another organization"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Assessment is locked after submission")
+    )
 )]
 pub async fn remove_file(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path((assessment_id, response_id, file_id)): Path<(Uuid, Uuid, Uuid)>,
 ) -> Result<StatusCode, ApiError> {
-    let org_id = claims.get_org_id()
-        .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
+    authorize_assessment_files(&app_state, &claims, assessment_id).await?;
 
-    // Verify that the current organization is the owner of the assessment
-    let assessment_model = app_state
-        .database
-        .assessments
-        .get_assessment_by_id(assessment_id)
-        .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessment: {e}")))?;
-
-    let assessment_model = match assessment_model {
-        Some(a) => a,
-        None => return Err(ApiError::NotFound("Assessment not found".to_string())),
-    };
-
-    // Allow access to assessments in the following cases:
-    // 1. User owns the assessment (same org_id)
-    // 2. User is a super user (can access any assessment)
-    // 3. Any user can access any assessment if they have the assessment_id (shared assessments)
-    let is_owner = assessment_model.org_id == org_id;
-    let is_super_user = claims.is_super_user();
-
-    if !is_owner && !is_super_user {
-        // Allow access to any assessment - this enables the sharing use case
-        // Comment out the permission check to enable sharing
-        // return Err(ApiError::BadRequest(
-        //     "You don't have permission to access this assessment".to_string(),
-        // ));
-    }
-
-    // Verify that the assessment is in draft status (not submitted)
-    let has_submission = app_state
-        .database
-        .assessments_submission
-        .get_submission_by_assessment_id(assessment_id)
-        .await
-        .map_err(|e| {
-            ApiError::InternalServerError(format!("Failed to check submission status: {e}"))
-        })?
-        .is_some();
-
-    if has_submission {
-        return Err(ApiError::BadRequest(
-            "Cannot remove files from a submitted assessment".to_string(),
-        ));
-    }
+    ensure_assessment_unlocked(&app_state, assessment_id).await?;
 
     // Verify that the response exists and belongs to the assessment
     let response_model = app_state
@@ -501,12 +657,8 @@ pub async fn remove_file(
         .assessments_response
         .get_response_by_id(response_id)
         .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch response: {e}")))?;
-
-    let response_model = match response_model {
-        Some(r) => r,
-        None => return Err(ApiError::NotFound("Response not found".to_string())),
-    };
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch response: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Response not found".to_string()))?;
 
     if response_model.assessment_id != assessment_id {
         return Err(ApiError::BadRequest(
@@ -514,7 +666,55 @@ pub async fn remove_file(
         ));
     }
 
-    // Remove the association between the response and the file
+    unlink_response_file(&app_state, response_model.response_id, file_id).await
+}
+
+/// Remove a file from the response to a question
+///
+/// Same as removing it by response ID, for clients that only know the question
+/// revision the response answers.
+#[utoipa::path(
+    delete,
+    path = "/assessments/{assessment_id}/questions/{question_revision_id}/files/{file_id}",
+    tag = "File",
+    params(
+        ("assessment_id" = uuid::Uuid, Path, description = "Assessment ID"),
+        ("question_revision_id" = uuid::Uuid, Path, description = "Question revision ID"),
+        ("file_id" = uuid::Uuid, Path, description = "File ID")
+    ),
+    responses(
+        (status = 204, description = "Removed"),
+        (status = 403, description = "Assessment belongs to another organization"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Assessment is locked after submission")
+    )
+)]
+pub async fn remove_question_file(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((assessment_id, question_revision_id, file_id)): Path<(Uuid, Uuid, Uuid)>,
+) -> Result<StatusCode, ApiError> {
+    authorize_assessment_files(&app_state, &claims, assessment_id).await?;
+
+    ensure_assessment_unlocked(&app_state, assessment_id).await?;
+
+    let response_model = app_state
+        .database
+        .assessments_response
+        .get_response_by_assessment_and_question(assessment_id, question_revision_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch response: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Response not found".to_string()))?;
+
+    unlink_response_file(&app_state, response_model.response_id, file_id).await
+}
+
+/// Delete the link between a response and a file, keeping the file itself
+async fn unlink_response_file(
+    app_state: &AppState,
+    response_id: Uuid,
+    file_id: Uuid,
+) -> Result<StatusCode, ApiError> {
     let result = app_state
         .database
         .assessments_response_file
@@ -532,3 +732,454 @@ pub async fn remove_file(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{claims, keycloak_config, org_claims};
+    use crate::common::config::UploadConfig;
+    use crate::common::state::AppDatabase;
+    use crate::common::database::entity::file::Model as FileModel;
+    use axum::{extract::DefaultBodyLimit, http::Request, routing::{get, post}, Router};
+    use std::collections::BTreeMap;
+    use sea_orm::{DatabaseBackend, MockDatabase};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    const PNG_BYTES: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, 0, 0, 0, 0x0D];
+    const PDF_BYTES: &[u8] = b"%PDF-1.7\n%test\n";
+
+    async fn test_state(db: MockDatabase, max_file_bytes: usize) -> AppState {
+        let keycloak_config = keycloak_config("http://localhost:8080");
+        AppState::new(
+            keycloak_config,
+            AppDatabase::new(Arc::new(db.into_connection())).await,
+        )
+        .await
+        .with_upload_config(UploadConfig { max_file_bytes })
+    }
+
+    async fn test_app(max_file_bytes: usize) -> Router {
+        // No queries are expected: every request here is rejected before storage
+        let app_state =
+            test_state(MockDatabase::new(DatabaseBackend::Postgres), max_file_bytes).await;
+
+        Router::new()
+            .route(
+                "/api/files",
+                post(upload_file).layer(DefaultBodyLimit::max(max_file_bytes + 64 * 1024)),
+            )
+            .layer(Extension(org_claims("test-user-123", &[], "Test Organization", "test-org")))
+            .with_state(app_state)
+    }
+
+    fn multipart_request(content_type: &str, data: &[u8]) -> Request<Body> {
+        let boundary = "test-boundary";
+        let mut body = format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"upload\"\r\nContent-Type: {content_type}\r\n\r\n"
+        )
+        .into_bytes();
+        body.extend_from_slice(data);
+        body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+
+        Request::builder()
+            .method("POST")
+            .uri("/api/files")
+            .header(
+                header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={boundary}"),
+            )
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_upload_rejects_oversize_file() {
+        let app = test_app(1024).await;
+        let mut data = PDF_BYTES.to_vec();
+        data.resize(2048, b' ');
+
+        let response = app
+            .oneshot(multipart_request("application/pdf", &data))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[tokio::test]
+    async fn test_upload_rejects_disallowed_type() {
+        let app = test_app(1024).await;
+
+        let response = app
+            .oneshot(multipart_request("application/x-msdownload", b"MZ\x90\x00"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[tokio::test]
+    async fn test_upload_rejects_spoofed_content_type() {
+        let app = test_app(1024).await;
+
+        let response = app
+            .oneshot(multipart_request("application/pdf", PNG_BYTES))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[tokio::test]
+    async fn test_upload_metadata_cannot_override_file_information() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::file;
+        use sea_orm::{ConnectionTrait, Database, EntityTrait, Schema};
+
+        let db = Database::connect("sqlite::memory:").await?;
+        let backend = db.get_database_backend();
+        db.execute(backend.build(&Schema::new(backend).create_table_from_entity(file::Entity)))
+            .await?;
+        let db = Arc::new(db);
+        let app_state = AppState::new(keycloak_config("http://localhost:8080"), AppDatabase::new(db.clone()).await)
+            .await
+            .with_upload_config(UploadConfig { max_file_bytes: 1024 });
+        let app = Router::new()
+            .route("/api/files", post(upload_file))
+            .layer(Extension(org_claims("test-user-123", &[], "Test Organization", "test-org")))
+            .with_state(app_state);
+
+        let boundary = "test-boundary";
+        let mut body = format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"metadata\"\r\n\r\n\
+             {{\"content_type\":\"text/html\",\"size\":1,\"uploaded_by\":\"someone-else\",\"note\":\"kept\"}}\r\n\
+             --{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"upload\"\r\nContent-Type: application/pdf\r\n\r\n"
+        )
+        .into_bytes();
+        body.extend_from_slice(PDF_BYTES);
+        body.extend_from_slice(format!("\r\n--{boundary}--\r\n").as_bytes());
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/files")
+                    .header(header::CONTENT_TYPE, format!("multipart/form-data; boundary={boundary}"))
+                    .body(Body::from(body))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let stored = file::Entity::find().one(db.as_ref()).await?.unwrap();
+        assert_eq!(stored.metadata["content_type"], "application/pdf");
+        assert_eq!(stored.metadata["size"], PDF_BYTES.len());
+        assert_eq!(stored.metadata["uploaded_by"], "test-user-123");
+        assert_eq!(stored.metadata["note"], "kept");
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_content_type() {
+        assert_eq!(validate_content_type("image/png", PNG_BYTES).unwrap(), "image/png");
+        assert_eq!(
+            validate_content_type("Application/PDF; charset=binary", PDF_BYTES).unwrap(),
+            "application/pdf"
+        );
+        assert_eq!(validate_content_type("text/csv", b"a,b\n1,2\n").unwrap(), "text/csv");
+
+        // Binary content cannot pass as text, and text cannot pass as an image
+        assert!(validate_content_type("text/plain", &[0xFF, 0xFE, 0x00, 0x9F]).is_err());
+        assert!(validate_content_type("image/png", b"not an image").is_err());
+    }
+
+    fn stored_file(content: &[u8]) -> FileModel {
+        FileModel {
+            id: Uuid::new_v4(),
+            content: content.to_vec(),
+            metadata: serde_json::json!({
+                "filename": "evidence.pdf",
+                "content_type": "application/pdf",
+                "size": content.len(),
+                "uploaded_by": "someone-else"
+            }),
+            object_key: None,
+        }
+    }
+
+    fn owner_row(org_id: &str) -> BTreeMap<String, sea_orm::Value> {
+        BTreeMap::from([("org_id".to_string(), org_id.into())])
+    }
+
+    async fn download(db: MockDatabase, file_id: Uuid) -> axum::response::Response {
+        download_as(org_claims("test-user-123", &[], "Test Organization", "test-org"), db, file_id).await
+    }
+
+    async fn download_as(claims: Claims, db: MockDatabase, file_id: Uuid) -> axum::response::Response {
+        let app = Router::new()
+            .route("/api/files/:file_id", get(download_file))
+            .layer(Extension(claims))
+            .with_state(test_state(db, 1024).await);
+
+        app.oneshot(
+            Request::builder()
+                .uri(format!("/api/files/{file_id}"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_download_streams_file_for_owning_org() {
+        // Larger than one chunk so the body is assembled from several slices
+        let mut content = PDF_BYTES.to_vec();
+        content.resize(DOWNLOAD_CHUNK_SIZE * 2 + 10, b'x');
+        let file = stored_file(&content);
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![file.clone()]])
+            .append_query_results([vec![owner_row("test-org")]]);
+        let response = download(db, file.id).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/pdf");
+        assert_eq!(
+            response.headers()[header::CONTENT_DISPOSITION],
+            "attachment; filename=\"evidence.pdf\""
+        );
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(body.as_ref(), content.as_slice());
+    }
+
+    #[tokio::test]
+    async fn test_download_rejects_other_org() {
+        let file = stored_file(PDF_BYTES);
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![file.clone()]])
+            .append_query_results([vec![owner_row("other-org")]]);
+        let response = download(db, file.id).await;
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_download_allows_application_admin() {
+        let file = stored_file(PDF_BYTES);
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![file.clone()]])
+            .append_query_results([vec![owner_row("other-org")]]);
+        let response = download_as(claims("admin", &["application_admin"]), db, file.id).await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_download_missing_file() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results::<FileModel, _, _>([vec![]]);
+        let response = download(db, Uuid::new_v4()).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    /// A draft of `test-org` with one answered question and one attached file.
+    /// Returns the app as seen by `claims`, the database, and the assessment,
+    /// question revision and file IDs.
+    async fn response_files_app(
+        claims: Claims,
+    ) -> Result<(Router, Arc<sea_orm::DatabaseConnection>, Uuid, Uuid, Uuid), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::{
+            assessments, assessments_response, assessments_response_file, assessments_submission, file,
+        };
+        use sea_orm::{ActiveModelTrait, ConnectionTrait, Database, Schema, Set};
+
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(assessments_response_file::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(file::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        let assessment_id = Uuid::new_v4();
+        assessments::ActiveModel {
+            assessment_id: Set(assessment_id),
+            org_id: Set("test-org".to_string()),
+            language: Set("en".to_string()),
+            name: Set("Draft".to_string()),
+            created_at: Set(chrono::Utc::now()),
+        }
+        .insert(&db)
+        .await?;
+
+        let question_revision_id = Uuid::new_v4();
+        let response_id = Uuid::new_v4();
+        assessments_response::ActiveModel {
+            response_id: Set(response_id),
+            assessment_id: Set(assessment_id),
+            question_revision_id: Set(question_revision_id),
+            response: Set("yes".to_string()),
+            version: Set(1),
+            updated_at: Set(chrono::Utc::now()),
+            key_version: Set(None),
+        }
+        .insert(&db)
+        .await?;
+
+        let file_id = Uuid::new_v4();
+        file::ActiveModel {
+            id: Set(file_id),
+            content: Set(PDF_BYTES.to_vec()),
+            metadata: Set(serde_json::json!({ "filename": "policy.pdf", "content_type": "application/pdf" })),
+            object_key: Set(None),
+        }
+        .insert(&db)
+        .await?;
+        assessments_response_file::ActiveModel {
+            response_id: Set(response_id),
+            file_id: Set(file_id),
+        }
+        .insert(&db)
+        .await?;
+
+        let db = Arc::new(db);
+        let app_state = AppState::new(
+            keycloak_config("http://localhost:8080"),
+            AppDatabase::new(db.clone()).await,
+        )
+        .await;
+
+        let app = Router::new()
+            .route(
+                "/assessments/:assessment_id/responses/:response_id/files",
+                get(list_response_files),
+            )
+            .route(
+                "/assessments/:assessment_id/responses/:response_id/files/:file_id",
+                axum::routing::delete(remove_file),
+            )
+            .route(
+                "/assessments/:assessment_id/questions/:question_revision_id/files/:file_id",
+                axum::routing::delete(remove_question_file),
+            )
+            .layer(Extension(claims))
+            .with_state(app_state);
+
+        Ok((app, db, assessment_id, question_revision_id, file_id))
+    }
+
+    fn request(method: &str, uri: String) -> Result<Request<Body>, axum::http::Error> {
+        Request::builder().method(method).uri(uri).body(Body::empty())
+    }
+
+    #[tokio::test]
+    async fn test_list_response_files_by_question() -> Result<(), Box<dyn std::error::Error>> {
+        for claims in [org_claims("test-user-123", &["Org_User"], "test-org", "test-org"), org_claims("test-user-123", &["application_admin"], "other-org", "other-org")] {
+            let (app, _db, assessment_id, question_revision_id, file_id) = response_files_app(claims).await?;
+
+            let response = app
+                .oneshot(request("GET", format!("/assessments/{assessment_id}/responses/{question_revision_id}/files"))?)
+                .await?;
+
+            assert_eq!(response.status(), StatusCode::OK);
+            let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+            let list: FileListResponse = serde_json::from_slice(&body)?;
+            assert_eq!(list.files.len(), 1);
+            assert_eq!(list.files[0].file_id, file_id);
+            assert_eq!(list.files[0].filename, "policy.pdf");
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_response_files_reject_other_org() -> Result<(), Box<dyn std::error::Error>> {
+        let (app, _db, assessment_id, question_revision_id, file_id) =
+            response_files_app(org_claims("test-user-123", &["org_admin"], "other-org", "other-org")).await?;
+
+        let response = app
+            .clone()
+            .oneshot(request("GET", format!("/assessments/{assessment_id}/responses/{question_revision_id}/files"))?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = app
+            .oneshot(request(
+                "DELETE",
+                format!("/assessments/{assessment_id}/questions/{question_revision_id}/files/{file_id}"),
+            )?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_remove_file_by_question_keeps_file() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::{assessments_response_file, file};
+        use sea_orm::{EntityTrait, PaginatorTrait};
+
+        let (app, db, assessment_id, question_revision_id, file_id) =
+            response_files_app(org_claims("test-user-123", &["Org_User"], "test-org", "test-org")).await?;
+
+        // A question revision ID is not a response ID
+        let response = app
+            .clone()
+            .oneshot(request(
+                "DELETE",
+                format!("/assessments/{assessment_id}/responses/{question_revision_id}/files/{file_id}"),
+            )?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = app
+            .oneshot(request(
+                "DELETE",
+                format!("/assessments/{assessment_id}/questions/{question_revision_id}/files/{file_id}"),
+            )?)
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(assessments_response_file::Entity::find().count(db.as_ref()).await?, 0);
+        assert!(file::Entity::find_by_id(file_id).one(db.as_ref()).await?.is_some());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_remove_file_only_while_assessment_is_unlocked() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::assessments_submission::{self, SubmissionStatus};
+        use sea_orm::{ActiveModelTrait, Set};
+
+        let (app, db, assessment_id, question_revision_id, file_id) =
+            response_files_app(org_claims("test-user-123", &["Org_User"], "test-org", "test-org")).await?;
+        let submission = assessments_submission::ActiveModel {
+            submission_id: Set(assessment_id),
+            org_id: Set("test-org".to_string()),
+            org_name: Set("test-org".to_string()),
+            content: Set(serde_json::json!({})),
+            submitted_at: Set(chrono::Utc::now()),
+            status: Set(SubmissionStatus::Reviewed),
+            reviewed_at: Set(Some(chrono::Utc::now())),
+            reopened_at: Set(None),
+            reopened_by: Set(None),
+        }
+        .insert(db.as_ref())
+        .await?;
+        let uri = format!("/assessments/{assessment_id}/questions/{question_revision_id}/files/{file_id}");
+
+        let response = app.clone().oneshot(request("DELETE", uri.clone())?).await?;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        // Reopened for corrections, files can be changed again
+        let mut submission: assessments_submission::ActiveModel = submission.into();
+        submission.status = Set(SubmissionStatus::Reopened);
+        submission.update(db.as_ref()).await?;
+        let response = app.oneshot(request("DELETE", uri)?).await?;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        Ok(())
+    }
+}
@@ -1,12 +1,25 @@
 use crate::web::api::models::{
-    DatabaseMetrics, HealthChecks, HealthResponse, MemoryMetrics, MetricsResponse, RequestMetrics,
+    CheckResult, DatabaseMetrics, HealthChecks, HealthResponse, HealthStatus, HealthStatusChecks,
+    MemoryMetrics, MetricsResponse, RequestMetrics,
+};
+use crate::common::build_info::BuildInfo;
+use crate::web::routes::AppState;
+use axum::{
+    extract::State,
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
 };
-use axum::Json;
 use once_cell::sync::Lazy;
+use sea_orm::ConnectionTrait;
+use std::future::Future;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::SystemTime;
+use std::time::{Duration, Instant, SystemTime};
 use sysinfo::System;
 
+const DATABASE_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+const KEYCLOAK_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
 // Global metrics tracking
 static REQUEST_COUNT: AtomicU64 = AtomicU64::new(0);
 static START_TIME: Lazy<SystemTime> = Lazy::new(SystemTime::now);
@@ -38,6 +51,75 @@ pub async fn health_check() -> Json<HealthResponse> {
     })
 }
 
+/// Run a single dependency check, timing it and turning failures into a `CheckResult`
+async fn run_check<F, E>(timeout: Duration, check: F) -> CheckResult
+where
+    F: Future<Output = Result<(), E>>,
+    E: std::fmt::Display,
+{
+    let started = Instant::now();
+    let outcome = match tokio::time::timeout(timeout, check).await {
+        Ok(Ok(())) => None,
+        Ok(Err(e)) => Some(e.to_string()),
+        Err(_) => Some(format!("timed out after {}s", timeout.as_secs())),
+    };
+
+    CheckResult {
+        status: if outcome.is_none() { "ok" } else { "error" }.to_string(),
+        latency_ms: started.elapsed().as_millis() as u64,
+        error: outcome,
+    }
+}
+
+/// Healthy when every check passed, unhealthy when none did, degraded otherwise
+fn overall_status(checks: &[&CheckResult]) -> &'static str {
+    let passed = checks.iter().filter(|check| check.status == "ok").count();
+    if passed == checks.len() {
+        "healthy"
+    } else if passed == 0 {
+        "unhealthy"
+    } else {
+        "degraded"
+    }
+}
+
+/// Dependency health check served at `/health`.
+///
+/// Checks the database and Keycloak independently; responds 503 only when both are down.
+pub async fn health_status(State(app_state): State<AppState>) -> (StatusCode, Json<HealthStatus>) {
+    increment_request_count();
+
+    let (database, keycloak) = tokio::join!(
+        run_check(DATABASE_CHECK_TIMEOUT, async {
+            app_state
+                .database
+                .get_connection()
+                .execute_unprepared("SELECT 1")
+                .await
+                .map(|_| ())
+        }),
+        run_check(
+            KEYCLOAK_CHECK_TIMEOUT,
+            app_state.keycloak_service.check_connectivity(KEYCLOAK_CHECK_TIMEOUT),
+        ),
+    );
+
+    let status = overall_status(&[&database, &keycloak]);
+    let status_code = if status == "unhealthy" {
+        StatusCode::SERVICE_UNAVAILABLE
+    } else {
+        StatusCode::OK
+    };
+
+    (
+        status_code,
+        Json(HealthStatus {
+            status: status.to_string(),
+            checks: HealthStatusChecks { database, keycloak },
+        }),
+    )
+}
+
 /// Basic metrics
 #[utoipa::path(
     get,
@@ -86,3 +168,171 @@ pub async fn metrics() -> Json<MetricsResponse> {
         },
     })
 }
+
+/// Version and build metadata, no authentication required
+///
+/// With `Accept: text/plain` only a single line like
+/// `sustainability-tool 0.0.1 (abc1234, built 2026-01-01T00:00:00Z)` is returned.
+#[utoipa::path(
+    get,
+    path = "/version",
+    tag = "Health",
+    responses(
+        (status = 200, description = "Build information", body = crate::common::build_info::BuildInfo,
+            content_type = "application/json"),
+        (status = 200, description = "Version line", body = String, content_type = "text/plain")
+    )
+)]
+pub async fn version(headers: HeaderMap) -> Response {
+    let build_info = BuildInfo::CURRENT;
+    let wants_text = headers
+        .get(header::ACCEPT)
+        .and_then(|accept| accept.to_str().ok())
+        .is_some_and(|accept| accept.split(',').any(|media| media.trim().starts_with("text/plain")));
+
+    if wants_text {
+        format!("{}\n", build_info.version_line()).into_response()
+    } else {
+        Json(build_info).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{keycloak_config, serve};
+    use crate::common::state::AppDatabase;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use sea_orm::{Database, DatabaseBackend, DatabaseConnection, MockDatabase};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    /// Keycloak stand-in whose discovery endpoint answers with `status`
+    async fn fake_keycloak(status: StatusCode) -> String {
+        let app = Router::new().route(
+            "/realms/test-realm/.well-known/openid-configuration",
+            get(move || async move { (status, Json(serde_json::json!({ "issuer": "test" }))) }),
+        );
+
+        serve(app).await
+    }
+
+    async fn check_health(db: DatabaseConnection, keycloak_status: StatusCode) -> (StatusCode, HealthStatus) {
+        let app_state = AppState::new(
+            keycloak_config(fake_keycloak(keycloak_status).await),
+            AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+
+        let app = Router::new()
+            .route("/health", get(health_status))
+            .with_state(app_state);
+
+        let response = app
+            .oneshot(Request::builder().uri("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    async fn working_db() -> DatabaseConnection {
+        Database::connect("sqlite::memory:").await.unwrap()
+    }
+
+    /// A mock connection with no queued results fails every statement
+    fn failing_db() -> DatabaseConnection {
+        MockDatabase::new(DatabaseBackend::Postgres).into_connection()
+    }
+
+    #[tokio::test]
+    async fn test_health_all_checks_ok() {
+        let (status, health) = check_health(working_db().await, StatusCode::OK).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(health.status, "healthy");
+        assert_eq!(health.checks.database.status, "ok");
+        assert_eq!(health.checks.keycloak.status, "ok");
+        assert!(health.checks.keycloak.error.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_health_degraded_when_keycloak_fails() {
+        let (status, health) =
+            check_health(working_db().await, StatusCode::INTERNAL_SERVER_ERROR).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(health.status, "degraded");
+        assert_eq!(health.checks.database.status, "ok");
+        assert_eq!(health.checks.keycloak.status, "error");
+        assert!(health.checks.keycloak.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_health_degraded_when_database_fails() {
+        let (status, health) = check_health(failing_db(), StatusCode::OK).await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(health.status, "degraded");
+        assert_eq!(health.checks.database.status, "error");
+        assert_eq!(health.checks.keycloak.status, "ok");
+    }
+
+    #[tokio::test]
+    async fn test_health_unhealthy_when_everything_fails() {
+        let (status, health) =
+            check_health(failing_db(), StatusCode::SERVICE_UNAVAILABLE).await;
+
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(health.status, "unhealthy");
+        assert!(health.checks.database.error.is_some());
+        assert!(health.checks.keycloak.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_version() -> Result<(), Box<dyn std::error::Error>> {
+        let app = Router::new().route("/version", get(version));
+
+        let response = app.clone().oneshot(Request::builder().uri("/version").body(Body::empty())?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let info: serde_json::Value = serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+
+        // MAJOR.MINOR.PATCH, optionally followed by a pre-release or build suffix
+        let version = info["version"].as_str().unwrap();
+        let core = version.split(['-', '+']).next().unwrap();
+        let parts: Vec<&str> = core.split('.').collect();
+        assert_eq!(parts.len(), 3, "{version}");
+        assert!(parts.iter().all(|part| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit())), "{version}");
+
+        // "unknown" when built outside a git checkout without GIT_COMMIT
+        let git_commit = info["git_commit"].as_str().unwrap();
+        if git_commit != "unknown" {
+            assert_eq!(git_commit.len(), 7, "{git_commit}");
+            assert!(git_commit.bytes().all(|b| b.is_ascii_hexdigit()), "{git_commit}");
+        }
+        assert!(info["rust_version"].as_str().unwrap().starts_with("rustc "));
+        assert!(chrono::DateTime::parse_from_rfc3339(info["build_timestamp"].as_str().unwrap()).is_ok());
+
+        let response = app
+            .oneshot(Request::builder().uri("/version").header(header::ACCEPT, "text/plain").body(Body::empty())?)
+            .await?;
+        assert!(response.headers()[header::CONTENT_TYPE].to_str()?.starts_with("text/plain"));
+        let line = String::from_utf8(axum::body::to_bytes(response.into_body(), usize::MAX).await?.to_vec())?;
+        assert_eq!(line, format!("sustainability-tool {version} ({git_commit}, built {})\n", info["build_timestamp"].as_str().unwrap()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_check_times_out() {
+        let result = run_check(Duration::from_millis(10), async {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            Ok::<(), String>(())
+        })
+        .await;
+
+        assert_eq!(result.status, "error");
+        assert_eq!(result.error.as_deref(), Some("timed out after 0s"));
+    }
+}
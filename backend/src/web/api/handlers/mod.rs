@@ -1,11 +1,17 @@
 pub mod admin;
+pub mod assessment_templates;
 pub mod assessments;
+pub mod categories;
+pub mod export;
 pub mod files;
 pub mod health;
+pub mod notifications;
 pub mod openapi;
 pub mod organization_categories;
 pub mod organizations;
 pub mod questions;
 pub mod reports;
 pub mod responses;
+pub mod seed;
 pub mod submissions;
+pub mod users;
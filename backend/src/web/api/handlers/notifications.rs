@@ -0,0 +1,111 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::common::database::entity::notifications::Model as NotificationModel;
+use crate::common::models::claims::Claims;
+use crate::web::api::error::ApiError;
+use crate::web::api::models::{Notification, NotificationListResponse};
+use crate::web::routes::AppState;
+
+const DEFAULT_PAGE_SIZE: u64 = 50;
+const MAX_PAGE_SIZE: u64 = 200;
+
+#[derive(Deserialize)]
+pub struct ListNotificationsQuery {
+    #[serde(default)]
+    unread_only: bool,
+    limit: Option<u64>,
+    offset: Option<u64>,
+}
+
+fn to_api_notification(model: NotificationModel) -> Notification {
+    Notification {
+        notification_id: model.notification_id,
+        notification_type: model.notification_type,
+        title: model.title,
+        body: model.body,
+        read_at: model.read_at.map(|dt| dt.to_rfc3339()),
+        created_at: model.created_at.to_rfc3339(),
+    }
+}
+
+/// List the current user's notifications, newest first
+#[utoipa::path(
+    get,
+    path = "/notifications",
+    tag = "Notification",
+    params(
+        ("unread_only" = Option<bool>, Query, description = "Only return unread notifications"),
+        ("limit" = Option<u64>, Query, description = "Page size (default 50, max 200)"),
+        ("offset" = Option<u64>, Query, description = "Number of notifications to skip")
+    ),
+    responses((status = 200, description = "Notifications", body = NotificationListResponse))
+)]
+pub async fn list_notifications(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<ListNotificationsQuery>,
+) -> Result<Json<NotificationListResponse>, ApiError> {
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE).min(MAX_PAGE_SIZE);
+
+    let notifications = app_state
+        .database
+        .notifications
+        .list_for_user(&claims.sub, query.unread_only, limit, query.offset.unwrap_or(0))
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch notifications: {e}")))?;
+
+    Ok(Json(NotificationListResponse {
+        notifications: notifications.into_iter().map(to_api_notification).collect(),
+    }))
+}
+
+/// Mark one of the current user's notifications as read
+#[utoipa::path(
+    patch,
+    path = "/notifications/{notification_id}/read",
+    tag = "Notification",
+    params(("notification_id" = uuid::Uuid, Path, description = "Notification ID")),
+    responses((status = 200, description = "Notification marked as read", body = Notification), (status = 404, description = "Not found"))
+)]
+pub async fn mark_notification_read(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(notification_id): Path<Uuid>,
+) -> Result<Json<Notification>, ApiError> {
+    let notification = app_state
+        .database
+        .notifications
+        .mark_read(notification_id, &claims.sub)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to update notification: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Notification not found".to_string()))?;
+
+    Ok(Json(to_api_notification(notification)))
+}
+
+/// Mark all of the current user's notifications as read
+#[utoipa::path(
+    patch,
+    path = "/notifications/read-all",
+    tag = "Notification",
+    responses((status = 204, description = "All notifications marked as read"))
+)]
+pub async fn mark_all_notifications_read(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<StatusCode, ApiError> {
+    app_state
+        .database
+        .notifications
+        .mark_all_read(&claims.sub)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to update notifications: {e}")))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
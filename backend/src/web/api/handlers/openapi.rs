@@ -1,24 +1,42 @@
+use crate::common::config::KeycloakConfigs;
 use crate::web::api::models::*;
-use axum::response::IntoResponse;
+use crate::web::routes::AppState;
+use axum::{extract::State, response::IntoResponse};
+use utoipa::openapi::security::{
+    AuthorizationCode, Flow, OAuth2, Scopes, SecurityRequirement, SecurityScheme,
+};
 use utoipa::OpenApi;
+
+/// Name of the Keycloak OAuth2 security scheme in the generated spec
+const KEYCLOAK_SECURITY_SCHEME: &str = "keycloak";
 /// OpenAPI documentation structure
 #[derive(OpenApi)]
 #[openapi(
     paths(
         crate::web::api::handlers::organization_categories::get_category_catalogs,
+        crate::web::api::handlers::organization_categories::get_categories_with_counts,
         crate::web::api::handlers::organization_categories::create_category_catalog,
         crate::web::api::handlers::organization_categories::get_organization_categories,
         crate::web::api::handlers::organization_categories::assign_categories_to_organization,
         crate::web::api::handlers::organization_categories::update_organization_category,
+        crate::web::api::handlers::organization_categories::update_category_weight,
+        crate::web::api::handlers::organization_categories::reorder_organization_categories,
+        crate::web::api::handlers::organization_categories::update_organization_category_weights,
         // Categories
         // Assessments
         crate::web::api::handlers::assessments::list_assessments,
+        crate::web::api::handlers::assessments::list_organization_assessments,
         crate::web::api::handlers::assessments::create_assessment,
         crate::web::api::handlers::assessments::get_assessment,
         crate::web::api::handlers::assessments::update_assessment,
+        crate::web::api::handlers::assessments::get_next_questions,
+        crate::web::api::handlers::assessments::update_assessment_status,
         crate::web::api::handlers::assessments::delete_assessment,
         crate::web::api::handlers::assessments::user_submit_draft_assessment,
         crate::web::api::handlers::assessments::submit_assessment,
+        crate::web::api::handlers::assessment_templates::create_assessment_template,
+        crate::web::api::handlers::assessment_templates::list_assessment_templates,
+        crate::web::api::handlers::assessment_templates::create_assessment_from_template,
         // Questions
         crate::web::api::handlers::questions::list_questions,
         crate::web::api::handlers::questions::create_question,
@@ -28,42 +46,94 @@ use utoipa::OpenApi;
         // Health
         crate::web::api::handlers::health::health_check,
         crate::web::api::handlers::health::metrics,
+        crate::web::api::handlers::health::version,
         // Questions
         crate::web::api::handlers::questions::list_questions,
         crate::web::api::handlers::questions::create_question,
         crate::web::api::handlers::questions::get_question,
         crate::web::api::handlers::questions::update_question,
         crate::web::api::handlers::questions::delete_question_revision_by_id,
+        crate::web::api::handlers::questions::get_question_usage,
+        crate::web::api::handlers::questions::import_questions,
+        crate::web::api::handlers::questions::export_questions,
         // Responses
         crate::web::api::handlers::responses::list_responses,
+        crate::web::api::handlers::responses::diff_responses,
         crate::web::api::handlers::responses::create_response,
         crate::web::api::handlers::responses::get_response,
         crate::web::api::handlers::responses::update_response,
         crate::web::api::handlers::responses::delete_response,
+        crate::web::api::handlers::responses::delete_all_responses,
         // Files
         crate::web::api::handlers::files::upload_file,
         crate::web::api::handlers::files::download_file,
         crate::web::api::handlers::files::delete_file,
         crate::web::api::handlers::files::get_file_metadata,
+        crate::web::api::handlers::files::list_response_files,
         crate::web::api::handlers::files::attach_file,
         crate::web::api::handlers::files::remove_file,
+        crate::web::api::handlers::files::remove_question_file,
         // Submissions
         crate::web::api::handlers::submissions::list_user_submissions,
         crate::web::api::handlers::submissions::get_submission,
+        crate::web::api::handlers::submissions::get_submission_responses,
+        crate::web::api::handlers::submissions::get_submission_pdf,
+        crate::web::api::handlers::submissions::get_submission_timeline,
+        crate::web::api::handlers::submissions::add_submission_comment,
+        crate::web::api::handlers::submissions::reopen_submission,
+        crate::web::api::handlers::submissions::recall_submission,
         crate::web::api::handlers::submissions::delete_submission,
+        // Notifications
+        crate::web::api::handlers::notifications::list_notifications,
+        crate::web::api::handlers::notifications::mark_notification_read,
+        crate::web::api::handlers::notifications::mark_all_notifications_read,
+        // Current user
+        crate::web::api::handlers::users::get_current_user,
+        // Admin
+        crate::web::api::handlers::admin::get_user_assessments,
+        crate::web::api::handlers::admin::resend_verification_email,
+        crate::web::api::handlers::admin::transfer_user,
+        crate::web::api::handlers::admin::get_admin_dashboard,
+        crate::web::api::handlers::admin::get_submission_by_id,
+        crate::web::api::handlers::admin::get_migration_status,
+        crate::web::api::handlers::admin::stream_admin_events,
+        crate::web::api::handlers::admin::unlock_assessment,
+        crate::web::api::handlers::categories::create_category,
+        crate::web::api::handlers::categories::list_categories,
+        crate::web::api::handlers::categories::get_category_tree,
+        crate::web::api::handlers::categories::get_category,
+        crate::web::api::handlers::categories::update_category,
+        crate::web::api::handlers::categories::update_category_metadata,
+        crate::web::api::handlers::categories::archive_category,
+        crate::web::api::handlers::export::export_organization,
         // Reports
         crate::web::api::handlers::reports::list_user_reports,
         crate::web::api::handlers::reports::list_reports,
         crate::web::api::handlers::reports::generate_report,
+        crate::web::api::handlers::reports::regenerate_report,
+        crate::web::api::handlers::reports::preview_report,
+        crate::web::api::handlers::reports::review_submission,
         crate::web::api::handlers::reports::get_report,
+        crate::web::api::handlers::reports::get_report_generation_progress,
+        crate::web::api::handlers::reports::get_report_benchmark,
+        crate::web::api::handlers::reports::get_organization_statistics,
+        crate::web::api::handlers::reports::get_comparison_matrix,
+        crate::web::api::handlers::reports::export_organization_reports_zip,
         crate::web::api::handlers::reports::delete_report,
         crate::web::api::handlers::reports::list_all_action_plans,
+        crate::web::api::handlers::reports::export_action_plans_csv,
+        crate::web::api::handlers::reports::summarize_action_plans,
         crate::web::api::handlers::reports::list_all_reports,
-        crate::web::api::handlers::reports::update_recommendation_status
-        ,
+        crate::web::api::handlers::reports::update_recommendation_status,
+        crate::web::api::handlers::reports::add_report_recommendation,
+        crate::web::api::handlers::reports::delete_report_recommendation,
         // Organizations
         crate::web::api::handlers::organizations::get_organizations,
+        crate::web::api::handlers::organizations::search_organizations,
+        crate::web::api::handlers::organizations::count_cached_organizations,
+        crate::web::api::handlers::organizations::refresh_organizations_cache,
         crate::web::api::handlers::organizations::create_organization,
+        crate::web::api::handlers::organizations::bulk_import_organizations,
         crate::web::api::handlers::organizations::get_organization_by_id,
         crate::web::api::handlers::organizations::update_organization,
         crate::web::api::handlers::organizations::delete_organization,
@@ -82,14 +152,29 @@ use utoipa::OpenApi;
         crate::web::api::handlers::organizations::get_member_organizations_in_org,
         crate::web::api::handlers::organizations::remove_member,
         crate::web::api::handlers::organizations::get_org_admin_members,
+        crate::web::api::handlers::organizations::import_org_members,
         crate::web::api::handlers::organizations::remove_org_admin_member,
-        crate::web::api::handlers::organizations::update_org_admin_member_categories
+        crate::web::api::handlers::organizations::update_org_admin_member_categories,
+        crate::web::api::handlers::organizations::bulk_update_member_categories,
+        crate::web::api::handlers::organizations::get_invitation,
+        crate::web::api::handlers::organizations::resend_invitation,
+        crate::web::api::handlers::organizations::add_org_admin_member
     ),
     components(schemas(
+        crate::common::build_info::BuildInfo,
         QuestionRevision,
         CreateQuestionRequest,
         UpdateQuestionRequest,
         QuestionResponse,
+        QuestionUsage,
+        QuestionImportResult,
+        QuestionExport,
+        ExportedQuestion,
+        crate::common::database::entity::questions::DuplicateQuestionPolicy,
+        crate::common::services::skip_logic::QuestionSkipRule,
+        crate::common::services::skip_logic::SkipCondition,
+        crate::common::services::skip_logic::SkipAction,
+        AssessmentUsageSummary,
         QuestionWithRevisionsResponse,
         QuestionRevisionResponse,
         QuestionListResponse,
@@ -98,24 +183,59 @@ use utoipa::OpenApi;
         Assessment,
         CreateAssessmentRequest,
         UpdateAssessmentRequest,
+        UpdateAssessmentStatusRequest,
+        AssessmentStatusResponse,
+        AnsweredQuestion,
+        NextQuestionsRequest,
+        NextQuestionsResponse,
         AssessmentResponse,
         AssessmentListResponse,
         AssessmentWithResponsesResponse,
+        CreateAssessmentTemplateRequest,
+        AssessmentTemplate,
+        AssessmentTemplateResponse,
+        AssessmentTemplateListResponse,
+        AssessmentFromTemplateResponse,
         Response,
         CreateResponseRequest,
         UpdateResponseRequest,
         ResponseResponse,
         ResponseListResponse,
+        ResponseDiffResponse,
+        ResponseChange,
+        ResponseChangeKind,
         AssessmentSubmission,
         Submission,
         AssessmentSubmissionResponse,
         SubmissionListResponse,
         SubmissionDetailResponse,
+        SubmissionResponsesDetail,
+        TimelineEvent,
+        TimelineResponse,
+        TimelineCommentRequest,
+        ReopenedSubmission,
+        SubmittedResponse,
         AdminSubmissionDetail,
         AdminSubmissionContent,
         AdminAssessmentInfo,
         AdminResponseDetail,
         AdminSubmissionListResponse,
+        AssessmentSummary,
+        AdminUserAssessmentsResponse,
+        AppliedMigrationInfo,
+        AdminDashboardResponse,
+        MigrationStatusResponse,
+        UserTransferRequest,
+        TransferStepStatus,
+        TransferStep,
+        UserTransferResponse,
+        crate::common::models::admin_event::AdminEvent,
+        InvitationResultStatus,
+        Notification,
+        NotificationListResponse,
+        UserOrganization,
+        CurrentUserResponse,
+        InvitationResultResponse,
         Review,
         CreateReviewRequest,
         UpdateReviewRequest,
@@ -129,20 +249,54 @@ use utoipa::OpenApi;
         FileMetadata,
         FileUploadResponse,
         FileMetadataResponse,
+        FileListResponse,
         AttachFileRequest,
         Report,
+        CategoryScore,
+        CategoryPercentile,
+        BenchmarkResponse,
+        ComparisonMatrix,
+        OrgRow,
+        CategoryStatistics,
+        OrganizationStatisticsResponse,
         GenerateReportRequest,
         UpdateRecommendationStatusRequest,
+        AddRecommendationRequest,
+        ReportRecommendation,
         OrganizationActionPlan,
         RecommendationWithStatus,
         ActionPlanListResponse,
+        ActionPlanSummaryResponse,
+        OrganizationActionPlanSummary,
+        RecommendationStatusCounts,
         ReportGenerationResponse,
+        GenerationProgress,
+        crate::common::services::report_progress::GenerationStatus,
+        ReportPreviewResponse,
         ReportResponse,
         ReportListResponse,
         OrganizationDomainRequest,
         OrganizationCreateRequest,
+        ImportValidationError,
+        ImportRejectedResponse,
+        ImportRowError,
+        ImportResult,
+        MemberCategoryUpdateResult,
+        BulkMemberCategoryUpdateResponse,
+        CachedOrganization,
+        OrganizationSearchResponse,
+        OrganizationCacheRefreshResponse,
         MemberRequest,
         InvitationRequest,
+        crate::common::models::keycloak::KeycloakOrganization,
+        crate::common::models::keycloak::OrganizationDomain,
+        crate::common::models::keycloak::KeycloakOrganizationMember,
+        crate::common::models::keycloak::KeycloakInvitation,
+        crate::web::api::handlers::organizations::OrgAdminMemberRequest,
+        crate::web::api::handlers::organizations::OrgAdminUserInvitationResponse,
+        crate::web::api::handlers::organizations::OrgAdminMemberCategoryUpdateRequest,
+        crate::web::api::handlers::organizations::MemberImportResponse,
+        crate::web::api::handlers::organizations::MemberImportRowResult,
         Category,
         CreateCategoryRequest,
         UpdateCategoryRequest,
@@ -151,11 +305,22 @@ use utoipa::OpenApi;
         CategoryCatalog,
         CreateCategoryCatalogRequest,
         UpdateCategoryCatalogRequest,
+        crate::common::database::entity::category_catalog::ScoringMode,
         CategoryCatalogResponse,
         CategoryCatalogListResponse,
+        crate::common::database::entity::category_catalog::CategoryNode,
+        CategoryTreeResponse,
+        CategoryWithQuestionCount,
+        CategoryWithCountsListResponse,
         OrganizationCategory,
         CreateOrganizationCategoryRequest,
         UpdateOrganizationCategoryRequest,
+        UpdateCategoryWeightRequest,
+        ReorderCategoriesRequest,
+        CategoryWeight,
+        UpdateCategoryWeightsRequest,
+        UpdateCategoryDetailsRequest,
+        CategoryMetadataRequest,
         AssignCategoriesToOrganizationRequest,
         OrganizationCategoryResponse,
         OrganizationCategoryListResponse
@@ -166,13 +331,15 @@ use utoipa::OpenApi;
         (name = "Organization Categories", description = "Operations related to organization-specific category management"),
         (name = "Category Catalog", description = "Operations related to category catalog management"),
         (name = "Health", description = "Health check operations"),
+        (name = "Notification", description = "In-app notifications for the current user"),
         (name = "Protected", description = "Protected resource operations")
     )
 )]
 struct ApiDoc;
 
-#[axum::debug_handler]
-pub async fn get_openapi_json() -> impl IntoResponse {
+/// Build the OpenAPI spec, declaring Keycloak's authorization code flow so that
+/// Swagger UI can log users in instead of asking for a pasted Bearer token
+pub fn openapi_spec(keycloak: &KeycloakConfigs) -> utoipa::openapi::OpenApi {
     // Get the OpenAPI spec from the ApiDoc struct
     let mut spec = ApiDoc::openapi();
 
@@ -187,6 +354,31 @@ pub async fn get_openapi_json() -> impl IntoResponse {
     server.description = Some("Dynamic server address from environment".to_string());
     spec.servers = Some(vec![server]);
 
+    let scopes = Scopes::from_iter([
+        ("openid", "OpenID Connect sign-in"),
+        ("profile", "User profile"),
+        ("email", "Email address"),
+    ]);
+    let oauth2 = OAuth2::new([Flow::AuthorizationCode(AuthorizationCode::new(
+        keycloak.authorization_url(),
+        keycloak.token_url(),
+        scopes,
+    ))]);
+    spec.components
+        .get_or_insert_with(Default::default)
+        .add_security_scheme(KEYCLOAK_SECURITY_SCHEME, SecurityScheme::OAuth2(oauth2));
+    spec.security = Some(vec![SecurityRequirement::new(
+        KEYCLOAK_SECURITY_SCHEME,
+        ["openid", "profile", "email"],
+    )]);
+
+    spec
+}
+
+#[axum::debug_handler]
+pub async fn get_openapi_json(State(app_state): State<AppState>) -> impl IntoResponse {
+    let spec = openapi_spec(app_state.keycloak_service.config());
+
     match serde_json::to_string_pretty(&spec) {
         Ok(json) => (
             axum::http::StatusCode::OK,
@@ -197,3 +389,126 @@ pub async fn get_openapi_json() -> impl IntoResponse {
         Err(_) => axum::http::StatusCode::INTERNAL_SERVER_ERROR.into_response(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{keycloak_config, unreachable_keycloak};
+
+    #[test]
+    fn test_spec_declares_keycloak_authorization_code_flow() {
+        let spec = openapi_spec(&keycloak_config("http://localhost:8080"));
+        let spec = serde_json::to_value(&spec).unwrap();
+
+        let flow = &spec["components"]["securitySchemes"]["keycloak"]["flows"]["authorizationCode"];
+        assert_eq!(
+            flow["authorizationUrl"],
+            "http://localhost:8080/realms/test-realm/protocol/openid-connect/auth"
+        );
+        assert_eq!(
+            flow["tokenUrl"],
+            "http://localhost:8080/realms/test-realm/protocol/openid-connect/token"
+        );
+        assert!(flow["scopes"]["openid"].is_string());
+        assert!(spec["security"][0]["keycloak"].is_array());
+    }
+
+    /// Status the router answers `method path` with; handlers reject the request for
+    /// lack of claims, so only unmounted routes come back 404 or 405
+    async fn route_status(router: &axum::Router, method: &str, path: &str) -> axum::http::StatusCode {
+        use tower::ServiceExt;
+
+        let request = axum::http::Request::builder()
+            .method(method.to_uppercase().as_str())
+            .uri(path)
+            .body(axum::body::Body::empty())
+            .unwrap();
+        router.clone().oneshot(request).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_spec_documents_every_organization_route() {
+        use axum::http::StatusCode;
+
+        let spec = serde_json::to_value(ApiDoc::openapi()).unwrap();
+        let db = sea_orm::MockDatabase::new(sea_orm::DatabaseBackend::Postgres).into_connection();
+        let app_state = AppState::new(
+            unreachable_keycloak(),
+            crate::common::state::AppDatabase::new(std::sync::Arc::new(db)).await,
+        )
+        .await;
+        let router = crate::web::api::routes::create_router(app_state);
+
+        const METHODS: [&str; 5] = ["get", "post", "put", "patch", "delete"];
+        let mut operations = Vec::new();
+        let mut mismatches = Vec::new();
+        for (path, item) in spec["paths"].as_object().unwrap() {
+            let documented: Vec<&str> = METHODS
+                .into_iter()
+                .filter(|method| item[method]["tags"].as_array().is_some_and(|tags| tags.contains(&"Organization".into())))
+                .collect();
+            if documented.is_empty() {
+                continue;
+            }
+
+            // `/organizations/{org_id}` is mounted as `/api/organizations/:org_id`, the
+            // Keycloak-compatible `/admin/realms/...` routes without the prefix
+            let concrete = path
+                .split('/')
+                .map(|segment| if segment.starts_with('{') { "x" } else { segment })
+                .collect::<Vec<_>>()
+                .join("/");
+            let mut mounted_path = None;
+            for candidate in [format!("/api{concrete}"), concrete] {
+                let status = route_status(&router, documented[0], &candidate).await;
+                if status != StatusCode::NOT_FOUND && status != StatusCode::METHOD_NOT_ALLOWED {
+                    mounted_path = Some(candidate);
+                    break;
+                }
+            }
+            let Some(mounted_path) = mounted_path else {
+                mismatches.push(format!("{} {path} is documented but not mounted", documented[0]));
+                continue;
+            };
+
+            for method in METHODS {
+                let status = route_status(&router, method, &mounted_path).await;
+                let mounted = status != StatusCode::NOT_FOUND && status != StatusCode::METHOD_NOT_ALLOWED;
+                match (documented.contains(&method), mounted) {
+                    (true, false) => mismatches.push(format!("{method} {path} is documented but not mounted")),
+                    (false, true) => mismatches.push(format!("{method} {path} is mounted but not documented")),
+                    _ => {}
+                }
+            }
+            operations.extend(documented.into_iter().map(|method| (path.clone(), method)));
+        }
+        assert!(!operations.is_empty());
+        assert!(mismatches.is_empty(), "{mismatches:?}");
+
+        // Codegen fails on references to schemas the spec does not define
+        fn references<'a>(value: &'a serde_json::Value, found: &mut Vec<&'a str>) {
+            match value {
+                serde_json::Value::Object(map) => {
+                    if let Some(reference) = map.get("$ref").and_then(|reference| reference.as_str()) {
+                        found.push(reference);
+                    }
+                    map.values().for_each(|value| references(value, found));
+                }
+                serde_json::Value::Array(values) => values.iter().for_each(|value| references(value, found)),
+                _ => {}
+            }
+        }
+        let mut found = Vec::new();
+        for (path, method) in &operations {
+            references(&spec["paths"][path][method], &mut found);
+        }
+        let dangling: Vec<&str> = found
+            .into_iter()
+            .filter(|reference| {
+                let name = reference.trim_start_matches("#/components/schemas/");
+                spec["components"]["schemas"][name].is_null()
+            })
+            .collect();
+        assert!(dangling.is_empty(), "undefined schemas: {dangling:?}");
+    }
+}
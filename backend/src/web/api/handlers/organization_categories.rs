@@ -1,11 +1,15 @@
+use crate::common::database::entity::category_catalog::ScoringMode;
+use crate::common::database::entity::organization_categories::{self, CategoryWeightsError, RebalanceError, ReorderError};
 use crate::common::models::claims::Claims;
 use crate::web::routes::AppState;
 use crate::web::api::error::ApiError;
+use crate::web::api::strict_json::StrictJson;
 use crate::web::api::models::{
     AssignCategoriesToOrganizationRequest, CategoryCatalog, CategoryCatalogListResponse,
-    CategoryCatalogResponse, CreateCategoryCatalogRequest, OrganizationCategory,
-    OrganizationCategoryListResponse, OrganizationCategoryResponse,
-    UpdateOrganizationCategoryRequest, UpdateCategoryCatalogRequest,
+    CategoryCatalogResponse, CategoryWithCountsListResponse, CategoryWithQuestionCount,
+    CreateCategoryCatalogRequest, OrganizationCategory,
+    OrganizationCategoryListResponse, OrganizationCategoryResponse, ReorderCategoriesRequest,
+    UpdateCategoryWeightRequest, UpdateCategoryWeightsRequest, UpdateOrganizationCategoryRequest, UpdateCategoryCatalogRequest,
 };
 use axum::{
     extract::{Path, State},
@@ -17,6 +21,35 @@ use uuid::Uuid;
 
 // =============== Category Catalog Handlers ===============
 
+/// Default recommendations must map language codes to text
+fn validate_default_recommendation(value: Option<&serde_json::Value>) -> Result<(), ApiError> {
+    let Some(value) = value else {
+        return Ok(());
+    };
+
+    let valid = value
+        .as_object()
+        .map(|translations| translations.values().all(|text| text.is_string()))
+        .unwrap_or(false);
+    if !valid {
+        return Err(ApiError::BadRequest(
+            "default_recommendation must be an object mapping language codes to text".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Thresholds are percentages, and threshold scoring needs one
+fn validate_scoring(mode: ScoringMode, threshold: Option<f64>) -> Result<(), ApiError> {
+    if threshold.is_some_and(|threshold| !(0.0..=100.0).contains(&threshold)) {
+        return Err(ApiError::BadRequest("score_threshold must be between 0 and 100".to_string()));
+    }
+    if mode == ScoringMode::Threshold && threshold.is_none() {
+        return Err(ApiError::BadRequest("Threshold scoring requires a score_threshold".to_string()));
+    }
+    Ok(())
+}
+
 /// Get all active category catalogs
 #[utoipa::path(
     get,
@@ -45,6 +78,13 @@ pub async fn get_category_catalogs(
             is_active: cat.is_active,
             created_at: cat.created_at.to_rfc3339(),
             updated_at: cat.updated_at.to_rfc3339(),
+            default_recommendation: cat.default_recommendation,
+            deactivated_at: cat.deactivated_at.map(|at| at.to_rfc3339()),
+            scoring_mode: cat.scoring_mode,
+            score_threshold: cat.score_threshold,
+            icon_name: cat.icon_name,
+            color_hex: cat.color_hex,
+            metadata: cat.metadata,
         })
         .collect();
 
@@ -53,6 +93,45 @@ pub async fn get_category_catalogs(
     })))
 }
 
+/// Get all active category catalogs with the number of questions in each
+#[utoipa::path(
+    get,
+    path = "/categories/with-counts",
+    responses(
+        (status = 200, description = "Active categories with their question counts", body = CategoryWithCountsListResponse)
+    )
+)]
+pub async fn get_categories_with_counts(
+    State(app_state): State<AppState>,
+) -> Result<impl IntoResponse, ApiError> {
+    let category_catalogs = app_state
+        .database
+        .category_catalog
+        .get_all_active_categories()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to get category catalogs: {e}")))?;
+
+    let question_counts = app_state
+        .database
+        .questions
+        .count_by_category()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to count questions: {e}")))?;
+
+    let categories = category_catalogs
+        .into_iter()
+        .map(|cat| CategoryWithQuestionCount {
+            question_count: question_counts.get(&cat.category_catalog_id).copied().unwrap_or(0),
+            category_catalog_id: cat.category_catalog_id,
+            name: cat.name,
+            description: cat.description,
+            template_id: cat.template_id,
+        })
+        .collect();
+
+    Ok((StatusCode::OK, Json(CategoryWithCountsListResponse { categories })))
+}
+
 /// Create a new category catalog entry
 #[utoipa::path(
     post,
@@ -64,7 +143,7 @@ pub async fn get_category_catalogs(
 pub async fn create_category_catalog(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
-    Json(request): Json<CreateCategoryCatalogRequest>,
+    StrictJson(request): StrictJson<CreateCategoryCatalogRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Check if user has permission to create category catalogs (only drgv_admin)
     if !claims.is_super_user() {
@@ -79,6 +158,7 @@ pub async fn create_category_catalog(
             "Category catalog name must not be empty".to_string(),
         ));
     }
+    validate_default_recommendation(request.default_recommendation.as_ref())?;
 
     let category_catalog_service = &app_state.database.category_catalog;
     
@@ -90,6 +170,7 @@ pub async fn create_category_catalog(
             request.description,
             request.template_id,
             request.is_active.unwrap_or(true),
+            request.default_recommendation,
         )
         .await
         .map_err(|e| ApiError::InternalServerError(format!("Failed to create category catalog: {e}")))?;
@@ -102,6 +183,13 @@ pub async fn create_category_catalog(
         is_active: category_catalog_model.is_active,
         created_at: category_catalog_model.created_at.to_rfc3339(),
         updated_at: category_catalog_model.updated_at.to_rfc3339(),
+        default_recommendation: category_catalog_model.default_recommendation,
+        deactivated_at: category_catalog_model.deactivated_at.map(|at| at.to_rfc3339()),
+        scoring_mode: category_catalog_model.scoring_mode,
+        score_threshold: category_catalog_model.score_threshold,
+        icon_name: category_catalog_model.icon_name,
+        color_hex: category_catalog_model.color_hex,
+        metadata: category_catalog_model.metadata,
     };
 
     Ok((StatusCode::CREATED, Json(CategoryCatalogResponse { category_catalog })))
@@ -244,6 +332,13 @@ pub async fn get_category_catalog(
         is_active: category_catalog_model.is_active,
         created_at: category_catalog_model.created_at.to_rfc3339(),
         updated_at: category_catalog_model.updated_at.to_rfc3339(),
+        default_recommendation: category_catalog_model.default_recommendation,
+        deactivated_at: category_catalog_model.deactivated_at.map(|at| at.to_rfc3339()),
+        scoring_mode: category_catalog_model.scoring_mode,
+        score_threshold: category_catalog_model.score_threshold,
+        icon_name: category_catalog_model.icon_name,
+        color_hex: category_catalog_model.color_hex,
+        metadata: category_catalog_model.metadata,
     };
 
     Ok((StatusCode::OK, Json(CategoryCatalogResponse { category_catalog })))
@@ -267,7 +362,7 @@ pub async fn update_category_catalog(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path(category_catalog_id): Path<Uuid>,
-    Json(request): Json<UpdateCategoryCatalogRequest>,
+    StrictJson(request): StrictJson<UpdateCategoryCatalogRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Only super users can update catalog entries
     if !claims.is_super_user() {
@@ -275,15 +370,33 @@ pub async fn update_category_catalog(
             "Only system administrators can update category catalogs".to_string(),
         ));
     }
+    validate_default_recommendation(request.default_recommendation.as_ref())?;
 
     let category_catalog_service = &app_state.database.category_catalog;
 
-    let updated_model = category_catalog_service
+    // Omitted scoring fields keep their current value
+    let scoring = match (request.scoring_mode, request.score_threshold) {
+        (None, None) => None,
+        (mode, threshold) => {
+            let current = category_catalog_service
+                .get_category_catalog_by_id(category_catalog_id)
+                .await
+                .map_err(|e| ApiError::InternalServerError(format!("Failed to get category catalog: {e}")))?
+                .ok_or_else(|| ApiError::NotFound("Category catalog not found".to_string()))?;
+            let mode = mode.unwrap_or(current.scoring_mode);
+            let threshold = threshold.or(current.score_threshold);
+            validate_scoring(mode, threshold)?;
+            Some((mode, threshold))
+        }
+    };
+
+    let mut updated_model = category_catalog_service
         .update_category_catalog(
             category_catalog_id,
             request.name,
             request.description,
             request.is_active,
+            request.default_recommendation,
         )
         .await
         .map_err(|e| {
@@ -293,6 +406,12 @@ pub async fn update_category_catalog(
                 ApiError::InternalServerError(format!("Failed to update category catalog: {e}"))
             }
         })?;
+    if let Some((mode, threshold)) = scoring {
+        updated_model = category_catalog_service
+            .update_scoring(category_catalog_id, mode, threshold)
+            .await
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to update category scoring: {e}")))?;
+    }
 
     let category_catalog = CategoryCatalog {
         category_catalog_id: updated_model.category_catalog_id,
@@ -302,6 +421,13 @@ pub async fn update_category_catalog(
         is_active: updated_model.is_active,
         created_at: updated_model.created_at.to_rfc3339(),
         updated_at: updated_model.updated_at.to_rfc3339(),
+        default_recommendation: updated_model.default_recommendation,
+        deactivated_at: updated_model.deactivated_at.map(|at| at.to_rfc3339()),
+        scoring_mode: updated_model.scoring_mode,
+        score_threshold: updated_model.score_threshold,
+        icon_name: updated_model.icon_name,
+        color_hex: updated_model.color_hex,
+        metadata: updated_model.metadata,
     };
 
     Ok((StatusCode::OK, Json(CategoryCatalogResponse { category_catalog })))
@@ -381,7 +507,7 @@ pub async fn assign_categories_to_organization(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path(keycloak_organization_id): Path<String>,
-    Json(request): Json<AssignCategoriesToOrganizationRequest>,
+    StrictJson(request): StrictJson<AssignCategoriesToOrganizationRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Check if user has permission to assign categories (only drgv_admin)
     if !claims.is_super_user() {
@@ -495,7 +621,7 @@ pub async fn update_organization_category(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path((keycloak_organization_id, organization_category_id)): Path<(String, Uuid)>,
-    Json(request): Json<UpdateOrganizationCategoryRequest>,
+    StrictJson(request): StrictJson<UpdateOrganizationCategoryRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Check if user has permission to update organization categories
     if !claims.is_application_admin() && !is_member_of_org_by_id(&claims, &keycloak_organization_id) {
@@ -550,6 +676,162 @@ pub async fn update_organization_category(
     Ok((StatusCode::OK, Json(OrganizationCategoryResponse { organization_category })))
 }
 
+/// Set one category's weight and proportionally rebalance the others to total 100
+#[utoipa::path(
+    patch,
+    path = "/organizations/{keycloak_organization_id}/categories/{category_catalog_id}",
+    request_body = UpdateCategoryWeightRequest,
+    responses(
+        (status = 200, description = "All organization categories after rebalancing", body = OrganizationCategoryListResponse),
+        (status = 400, description = "Weights cannot be rebalanced"),
+        (status = 404, description = "Category not assigned to the organization")
+    ),
+    params(
+        ("keycloak_organization_id" = String, Path, description = "Keycloak Organization ID"),
+        ("category_catalog_id" = Uuid, Path, description = "Category Catalog ID")
+    )
+)]
+pub async fn update_category_weight(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((keycloak_organization_id, category_catalog_id)): Path<(String, Uuid)>,
+    StrictJson(request): StrictJson<UpdateCategoryWeightRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    // Check if user has permission to update organization categories
+    if !claims.is_application_admin() && !is_member_of_org_by_id(&claims, &keycloak_organization_id) {
+        return Err(ApiError::BadRequest("Insufficient permissions".to_string()));
+    }
+
+    let organization_categories = app_state
+        .database
+        .organization_categories
+        .update_single_category_weight(&keycloak_organization_id, category_catalog_id, request.weight)
+        .await
+        .map_err(|e| match e {
+            RebalanceError::NotFound => ApiError::NotFound("Organization category not found".to_string()),
+            RebalanceError::SingleCategory | RebalanceError::NoOtherWeight => ApiError::BadRequest(e.to_string()),
+            RebalanceError::Database(e) => {
+                ApiError::InternalServerError(format!("Failed to update category weight: {e}"))
+            }
+        })?;
+
+    organization_category_list_response(&app_state, organization_categories).await
+}
+
+/// Replace the weights of all of an organization's categories
+#[utoipa::path(
+    put,
+    path = "/organizations/{keycloak_organization_id}/categories/weights",
+    request_body = UpdateCategoryWeightsRequest,
+    responses(
+        (status = 200, description = "Organization categories with their new weights", body = OrganizationCategoryListResponse),
+        (status = 400, description = "Weights do not sum to 100 or do not cover the organization's categories"),
+        (status = 403, description = "Insufficient permissions")
+    ),
+    params(
+        ("keycloak_organization_id" = String, Path, description = "Keycloak Organization ID")
+    )
+)]
+pub async fn update_organization_category_weights(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(keycloak_organization_id): Path<String>,
+    StrictJson(request): StrictJson<UpdateCategoryWeightsRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    if !claims.can_manage_organization(&keycloak_organization_id) {
+        return Err(ApiError::Forbidden("Insufficient permissions".to_string()));
+    }
+
+    let weights: Vec<(Uuid, i32)> = request
+        .weights
+        .iter()
+        .map(|entry| (entry.category_catalog_id, entry.weight))
+        .collect();
+
+    let organization_categories = app_state
+        .database
+        .organization_categories
+        .set_category_weights(&keycloak_organization_id, &weights)
+        .await
+        .map_err(|e| match e {
+            CategoryWeightsError::Database(e) => {
+                ApiError::InternalServerError(format!("Failed to update category weights: {e}"))
+            }
+            e => ApiError::BadRequest(e.to_string()),
+        })?;
+
+    organization_category_list_response(&app_state, organization_categories).await
+}
+
+/// Reorder all of an organization's categories in one go
+#[utoipa::path(
+    put,
+    path = "/organizations/{keycloak_organization_id}/categories/order",
+    request_body = ReorderCategoriesRequest,
+    responses(
+        (status = 200, description = "Organization categories in their new order", body = OrganizationCategoryListResponse),
+        (status = 400, description = "Submitted IDs do not match the organization's categories"),
+        (status = 403, description = "Insufficient permissions")
+    ),
+    params(
+        ("keycloak_organization_id" = String, Path, description = "Keycloak Organization ID")
+    )
+)]
+pub async fn reorder_organization_categories(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(keycloak_organization_id): Path<String>,
+    StrictJson(request): StrictJson<ReorderCategoriesRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    if !claims.can_manage_organization(&keycloak_organization_id) {
+        return Err(ApiError::Forbidden("Insufficient permissions".to_string()));
+    }
+
+    let organization_categories = app_state
+        .database
+        .organization_categories
+        .reorder_categories(&keycloak_organization_id, &request.category_ids)
+        .await
+        .map_err(|e| match e {
+            ReorderError::Mismatch => ApiError::BadRequest(e.to_string()),
+            ReorderError::Database(e) => ApiError::InternalServerError(format!("Failed to reorder categories: {e}")),
+        })?;
+
+    organization_category_list_response(&app_state, organization_categories).await
+}
+
+/// Build the list response for updated organization categories, resolving their names
+async fn organization_category_list_response(
+    app_state: &AppState,
+    organization_categories: Vec<organization_categories::Model>,
+) -> Result<(StatusCode, Json<OrganizationCategoryListResponse>), ApiError> {
+    let category_catalog_service = &app_state.database.category_catalog;
+    let mut response_categories = Vec::new();
+    for org_cat in organization_categories {
+        let category_name = category_catalog_service
+            .get_category_catalog_by_id(org_cat.category_catalog_id)
+            .await
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to get category catalog: {e}")))?
+            .map(|cat| cat.name)
+            .unwrap_or_else(|| "Unknown Category".to_string());
+
+        response_categories.push(OrganizationCategory {
+            organization_category_id: org_cat.organization_category_id,
+            keycloak_organization_id: org_cat.keycloak_organization_id,
+            category_catalog_id: org_cat.category_catalog_id,
+            category_name,
+            weight: org_cat.weight,
+            order: org_cat.order,
+            created_at: org_cat.created_at.to_rfc3339(),
+            updated_at: org_cat.updated_at.to_rfc3339(),
+        });
+    }
+
+    Ok((StatusCode::OK, Json(OrganizationCategoryListResponse {
+        organization_categories: response_categories,
+    })))
+}
+
 // Helper function to check if user is member of organization
 fn is_member_of_org_by_id(claims: &Claims, org_id: &str) -> bool {
     if let Some(organizations) = &claims.organizations {
@@ -561,3 +843,84 @@ fn is_member_of_org_by_id(claims: &Claims, org_id: &str) -> bool {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::keycloak_config;
+    use crate::common::database::entity::{category_catalog, questions};
+    use crate::common::state::AppDatabase;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use sea_orm::{ConnectionTrait, Database, Schema};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_categories_with_counts() -> Result<(), Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(category_catalog::Entity),
+            schema.create_table_from_entity(questions::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        let app_database = AppDatabase::new(Arc::new(db)).await;
+        let mut category_ids = Vec::new();
+        for (name, is_active, question_count) in [
+            ("Environment", true, 3),
+            ("Governance", true, 0),
+            ("Retired", false, 2),
+            ("Social", true, 1),
+        ] {
+            let category = app_database
+                .category_catalog
+                .create_category_catalog(
+                    Uuid::new_v4(),
+                    name.to_string(),
+                    None,
+                    "sustainability_template_1".to_string(),
+                    is_active,
+                    None,
+                )
+                .await?;
+            for _ in 0..question_count {
+                app_database
+                    .questions
+                    .create_question(category.category_catalog_id)
+                    .await?;
+            }
+            category_ids.push(category.category_catalog_id);
+        }
+
+        let app_state = AppState::new(
+            keycloak_config("http://localhost:8080"),
+            app_database,
+        )
+        .await;
+
+        let app = Router::new()
+            .route("/categories/with-counts", get(get_categories_with_counts))
+            .with_state(app_state);
+        let response = app
+            .oneshot(Request::builder().uri("/categories/with-counts").body(Body::empty())?)
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        let list: CategoryWithCountsListResponse = serde_json::from_slice(&body)?;
+
+        let counts: Vec<(&str, i64)> = list
+            .categories
+            .iter()
+            .map(|category| (category.name.as_str(), category.question_count))
+            .collect();
+        assert_eq!(counts, vec![("Environment", 3), ("Governance", 0), ("Social", 1)]);
+        assert_eq!(list.categories[0].category_catalog_id, category_ids[0]);
+
+        Ok(())
+    }
+}
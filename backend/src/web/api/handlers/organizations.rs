@@ -1,16 +1,21 @@
 use axum::{
-    extract::{Extension, Path, Query, State},
+    extract::{Extension, Multipart, Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
+use futures::StreamExt;
 use serde::Deserialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
+use crate::common::models::admin_event::AdminEvent;
 use crate::common::models::claims::Claims;
 use crate::common::models::keycloak::*;
+use crate::common::services::email::{EmailService, EmailTemplate};
+use crate::common::services::keycloak_service::is_conflict;
 use crate::web::routes::AppState;
-use crate::web::api::error::ApiError;
+use crate::web::api::error::{ApiError, ValidationErrors};
+use crate::web::api::strict_json::StrictJson;
 use crate::web::api::models::*;
 
 // Query parameter structs for different endpoints
@@ -32,6 +37,14 @@ pub struct OrganizationsCountQuery {
     pub search: Option<String>,
 }
 
+#[derive(Debug, Default, Deserialize)]
+pub struct OrganizationSearchQuery {
+    #[serde(default)]
+    pub q: Option<String>,
+    #[serde(default)]
+    pub limit: Option<u64>,
+}
+
 #[derive(Deserialize)]
 pub struct MembersQuery {
     pub exact: Option<bool>,
@@ -40,6 +53,15 @@ pub struct MembersQuery {
     #[serde(rename = "membershipType")]
     pub membership_type: Option<String>,
     pub search: Option<String>,
+    /// Only members holding this realm role; everyone when absent
+    pub role: Option<String>,
+    /// Only members assigned this category
+    pub category: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct MembersCountQuery {
+    pub role: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -81,13 +103,33 @@ fn is_member_of_org_by_id(claims: &Claims, org_id: &str) -> bool {
         .unwrap_or(false)
 }
 
+/// Whether an organization's name or one of its domains matches an admin search term,
+/// ignoring case; without `exact` a partial match is enough
+fn organization_matches_search(org: &KeycloakOrganization, search_term: &str, exact: bool) -> bool {
+    let search_term = search_term.to_lowercase();
+    let matches = |value: &str| {
+        let value = value.to_lowercase();
+        if exact { value == search_term } else { value.contains(&search_term) }
+    };
+
+    matches(&org.name)
+        || org.domains.iter().flatten().any(|domain| matches(&domain.name))
+}
+
 // Get all organizations filtered according to the specified parameters
 /// List organizations
 #[utoipa::path(
     get,
     path = "/admin/organizations",
     tag = "Organization",
-    responses((status = 200, description = "Organizations"))
+    params(
+        ("search" = Option<String>, Query, description = "Match the name or a domain"),
+        ("exact" = Option<bool>, Query, description = "Require search to equal the name or a domain instead of contain it"),
+        ("first" = Option<i32>, Query, description = "Number of organizations to skip"),
+        ("max" = Option<i32>, Query, description = "Page size (default 10)"),
+        ("briefRepresentation" = Option<bool>, Query, description = "Accepted for Keycloak compatibility, full organizations are returned")
+    ),
+    responses((status = 200, description = "Organizations", body = Vec<KeycloakOrganization>))
 )]
 pub async fn get_organizations(
     Extension(_claims): Extension<Claims>,
@@ -108,22 +150,8 @@ pub async fn get_organizations(
             
             // Apply search filtering if provided
             if let Some(search_term) = &params.search {
-                organizations.retain(|org| {
-                    let name_matches = org.name.to_lowercase().contains(&search_term.to_lowercase());
-                    let domain_matches = org.domains.as_ref()
-                        .map(|domains| domains.iter().any(|domain| 
-                            domain.name.to_lowercase().contains(&search_term.to_lowercase())))
-                        .unwrap_or(false);
-
-                    if params.exact.unwrap_or(false) {
-                        org.name.eq_ignore_ascii_case(search_term) || 
-                        org.domains.as_ref()
-                            .map(|domains| domains.iter().any(|domain| domain.name.eq_ignore_ascii_case(search_term)))
-                            .unwrap_or(false)
-                    } else {
-                        name_matches || domain_matches
-                    }
-                });
+                let exact = params.exact.unwrap_or(false);
+                organizations.retain(|org| organization_matches_search(org, search_term, exact));
             }
 
             // Apply pagination
@@ -162,13 +190,16 @@ pub async fn get_organizations(
     path = "/admin/organizations",
     tag = "Organization",
     request_body = OrganizationCreateRequest,
-    responses((status = 201, description = "Created"))
+    responses(
+        (status = 201, description = "Created", body = KeycloakOrganization),
+        (status = 400, description = "Insufficient permissions")
+    )
 )]
 pub async fn create_organization(
     Extension(claims): Extension<Claims>,
     Extension(token): Extension<String>,
     State(app_state): State<AppState>,
-    Json(request): Json<OrganizationCreateRequest>,
+    StrictJson(request): StrictJson<OrganizationCreateRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     tracing::info!(?request, "Received organization create request");
     let token = get_token_from_extensions(&token)?;
@@ -192,65 +223,8 @@ pub async fn create_organization(
     {
         Ok(organization) => {
             // If categories are provided in attributes, assign them to the organization
-            if let Some(attributes) = &request.attributes {
-                if let Some(categories) = attributes.get("categories") {
-                    // categories is already a Vec<String>
-                    let category_names = categories.clone();
-                    
-                    if !category_names.is_empty() {
-                        // Convert category names to category catalog IDs and assign with equal weights
-                        let category_catalog_service = &app_state.database.category_catalog;
-                        let organization_categories_service = &app_state.database.organization_categories;
-                        
-                        let mut category_catalog_ids = Vec::new();
-                        for name in category_names {
-                            // Find category catalog by name
-                            let all_catalogs = category_catalog_service
-                                .get_all_active_categories()
-                                .await
-                                .map_err(|e| {
-                                    tracing::error!("Failed to get category catalogs: {}", e);
-                                    ApiError::InternalServerError("Failed to get category catalogs".to_string())
-                                })?;
-                            
-                            if let Some(catalog) = all_catalogs.iter().find(|cat| cat.name == name) {
-                                category_catalog_ids.push(catalog.category_catalog_id);
-                            }
-                        }
-                        
-                        if !category_catalog_ids.is_empty() {
-                            // Calculate equal weights
-                            let category_count = category_catalog_ids.len() as i32;
-                            let equal_weight = 100 / category_count;
-                            let remainder = 100 % category_count;
-                            
-                            // Create organization categories with equal weights
-                            for (index, category_catalog_id) in category_catalog_ids.iter().enumerate() {
-                                let organization_category_id = uuid::Uuid::new_v4();
-                                let mut weight = equal_weight;
-                                // Add remainder to first category
-                                if index == 0 && remainder > 0 {
-                                    weight += remainder;
-                                }
-                                let order = (index + 1) as i32;
-                                
-                                if let Err(e) = organization_categories_service
-                                    .create_organization_category(
-                                        organization_category_id,
-                                        organization.id.clone(),
-                                        *category_catalog_id,
-                                        weight,
-                                        order,
-                                    )
-                                    .await
-                                {
-                                    tracing::error!("Failed to create organization category: {}", e);
-                                    // Don't fail the entire operation, just log the error
-                                }
-                            }
-                        }
-                    }
-                }
+            if let Some(category_names) = request.attributes.as_ref().and_then(|attributes| attributes.get("categories")) {
+                assign_organization_categories(&app_state, &organization.id, category_names).await?;
             }
             
             Ok((StatusCode::CREATED, Json(organization)))
@@ -262,20 +236,82 @@ pub async fn create_organization(
     }
 }
 
+/// Assign the active catalog categories named in `category_names` to an organization
+/// with equal weights, skipping unknown names
+async fn assign_organization_categories(
+    app_state: &AppState,
+    org_id: &str,
+    category_names: &[String],
+) -> Result<(), ApiError> {
+    if category_names.is_empty() {
+        return Ok(());
+    }
+
+    // Convert category names to category catalog IDs and assign with equal weights
+    let all_catalogs = app_state
+        .database
+        .category_catalog
+        .get_all_active_categories()
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get category catalogs: {}", e);
+            ApiError::InternalServerError("Failed to get category catalogs".to_string())
+        })?;
+    let category_catalog_ids: Vec<uuid::Uuid> = category_names
+        .iter()
+        .filter_map(|name| all_catalogs.iter().find(|cat| &cat.name == name))
+        .map(|catalog| catalog.category_catalog_id)
+        .collect();
+    if category_catalog_ids.is_empty() {
+        return Ok(());
+    }
+
+    // Calculate equal weights
+    let category_count = category_catalog_ids.len() as i32;
+    let equal_weight = 100 / category_count;
+    let remainder = 100 % category_count;
+
+    // Create organization categories with equal weights
+    for (index, category_catalog_id) in category_catalog_ids.iter().enumerate() {
+        let mut weight = equal_weight;
+        // Add remainder to first category
+        if index == 0 && remainder > 0 {
+            weight += remainder;
+        }
+        let order = (index + 1) as i32;
+
+        if let Err(e) = app_state
+            .database
+            .organization_categories
+            .create_organization_category(uuid::Uuid::new_v4(), org_id.to_string(), *category_catalog_id, weight, order)
+            .await
+        {
+            tracing::error!("Failed to create organization category: {}", e);
+            // Don't fail the entire operation, just log the error
+        }
+    }
+
+    Ok(())
+}
+
 // Get a specific organization
 /// Get organization by id
 #[utoipa::path(
     get,
-    path = "/admin/realms/{realm}/organizations/{org_id}",
+    path = "/admin/organizations/{org_id}",
     tag = "Organization",
-    params(("realm", description = "Realm"), ("org_id", description = "Organization ID")),
-    responses((status = 200, description = "Organization"), (status = 404, description = "Not found"))
+    params(("org_id", description = "Organization ID")),
+    responses(
+        (status = 200, description = "Organization", body = KeycloakOrganization),
+        (status = 400, description = "Insufficient permissions"),
+        (status = 404, description = "Not found")
+    )
 )]
 pub async fn get_organization_by_id(
     Extension(claims): Extension<Claims>,
     Extension(token): Extension<String>,
     State(app_state): State<AppState>,
-    Path((_realm, org_id)): Path<(String, String)>,
+    Path(org_id): Path<String>,
 ) -> Result<impl IntoResponse, ApiError> {
     let token = get_token_from_extensions(&token)?;
 
@@ -304,14 +340,14 @@ pub async fn get_organization_by_id(
     tag = "Organization",
     params(("org_id", description = "Organization ID")),
     request_body = OrganizationCreateRequest,
-    responses((status = 204, description = "Updated"))
+    responses((status = 204, description = "Updated"), (status = 400, description = "Insufficient permissions"))
 )]
 pub async fn update_organization(
     Extension(claims): Extension<Claims>,
     Extension(token): Extension<String>,
     State(app_state): State<AppState>,
     Path(org_id): Path<String>,
-    Json(request): Json<OrganizationCreateRequest>,
+    StrictJson(request): StrictJson<OrganizationCreateRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let token = get_token_from_extensions(&token)?;
 
@@ -355,7 +391,7 @@ pub async fn update_organization(
     path = "/admin/organizations/{org_id}",
     tag = "Organization",
     params(("org_id", description = "Organization ID")),
-    responses((status = 204, description = "Deleted"))
+    responses((status = 204, description = "Deleted"), (status = 400, description = "Insufficient permissions"))
 )]
 pub async fn delete_organization(
     Extension(claims): Extension<Claims>,
@@ -402,14 +438,80 @@ pub async fn delete_organization(
     }
 }
 
+/// Whether the member's username, email, first or last name matches `search_term`,
+/// ignoring case: equal to it when `exact`, containing it otherwise
+fn member_matches_search(member: &KeycloakOrganizationMember, search_term: &str, exact: bool) -> bool {
+    let search_term = search_term.to_lowercase();
+    [
+        Some(member.username.as_str()),
+        Some(member.email.as_str()),
+        member.first_name.as_deref(),
+        member.last_name.as_deref(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(str::to_lowercase)
+    .any(|field| if exact { field == search_term } else { field.contains(&search_term) })
+}
+
+/// Members of an organization, or only those with `role` when one is given
+async fn organization_members(
+    app_state: &AppState,
+    token: &str,
+    org_id: &str,
+    role: Option<&str>,
+) -> anyhow::Result<Vec<KeycloakOrganizationMember>> {
+    match role.map(str::trim).filter(|role| !role.is_empty()) {
+        Some(role) => app_state.keycloak_service.get_organization_members_by_role(token, org_id, role).await,
+        None => app_state.keycloak_service.get_organization_members(token, org_id).await,
+    }
+}
+
+/// Members holding `role` and assigned `category`, each filter applied when given
+async fn filter_members(
+    app_state: &AppState,
+    token: &str,
+    org_id: &str,
+    role: Option<&str>,
+    category: Option<&str>,
+) -> anyhow::Result<Vec<KeycloakOrganizationMember>> {
+    let keycloak = &app_state.keycloak_service;
+    match (role, category) {
+        (Some(role), Some(category)) => {
+            let with_role: HashSet<String> = keycloak
+                .get_organization_members_by_role(token, org_id, role)
+                .await?
+                .into_iter()
+                .map(|member| member.id)
+                .collect();
+            let mut members = keycloak.find_users_by_category(token, org_id, category).await?;
+            members.retain(|member| with_role.contains(&member.id));
+            Ok(members)
+        }
+        (None, Some(category)) => keycloak.find_users_by_category(token, org_id, category).await,
+        (role, None) => organization_members(app_state, token, org_id, role).await,
+    }
+}
+
 // Get organization members filtered according to the specified parameters
 /// List organization members (org_admin)
 #[utoipa::path(
     get,
     path = "/organizations/{org_id}/members",
     tag = "Organization",
-    params(("org_id", description = "Organization ID")),
-    responses((status = 200, description = "Members"))
+    params(
+        ("org_id", description = "Organization ID"),
+        ("role" = Option<String>, Query, description = "Only members with this realm role, e.g. org_admin; all members when omitted"),
+        ("category" = Option<String>, Query, description = "Only members assigned this category, e.g. Finance"),
+        ("search" = Option<String>, Query, description = "Match username, email, first or last name"),
+        ("exact" = Option<bool>, Query, description = "Require search to equal a field instead of contain it"),
+        ("first" = Option<i32>, Query, description = "Number of members to skip"),
+        ("max" = Option<i32>, Query, description = "Page size (default 10)")
+    ),
+    responses(
+        (status = 200, description = "Members", body = Vec<KeycloakOrganizationMember>),
+        (status = 400, description = "Insufficient permissions")
+    )
 )]
 pub async fn get_members(
     Extension(claims): Extension<Claims>,
@@ -425,39 +527,35 @@ pub async fn get_members(
         return Err(ApiError::BadRequest("Insufficient permissions".to_string()));
     }
 
-    // Get organization members filtered by org_admin role
-    match app_state.keycloak_service.get_organization_members_by_role(&token, &org_id, "org_admin").await {
-        Ok(mut members) => {
-            // Apply search filtering if provided
-            if let Some(search_term) = &params.search {
-                members.retain(|member| {
-                    let username_matches = member.username.to_lowercase().contains(&search_term.to_lowercase());
-                    let email_matches = member.email.to_lowercase().contains(&search_term.to_lowercase());
-                    let first_name_matches = member.first_name.as_deref().unwrap_or("").to_lowercase().contains(&search_term.to_lowercase());
-                    let last_name_matches = member.last_name.as_deref().unwrap_or("").to_lowercase().contains(&search_term.to_lowercase());
-
-                    if params.exact.unwrap_or(false) {
-                        username_matches || email_matches || first_name_matches || last_name_matches
-                    } else {
-                        username_matches || email_matches || first_name_matches || last_name_matches
-                    }
-                });
-            }
-
-            // Apply pagination
-            let first = params.first.unwrap_or(0) as usize;
-            let max = params.max.unwrap_or(10) as usize;
-
-            let total_count = members.len();
-
-            if first < total_count {
-                members = members.into_iter().skip(first).take(max).collect();
-            } else {
-                members.clear();
-            }
+    let first = params.first.unwrap_or(0).max(0) as u32;
+    let max = params.max.unwrap_or(10).max(0) as u32;
+    let exact = params.exact.unwrap_or(false);
+    let search = params.search.as_deref().filter(|search| !search.is_empty());
+
+    let role = params.role.as_deref().map(str::trim).filter(|role| !role.is_empty());
+    let category = params.category.as_deref().map(str::trim).filter(|category| !category.is_empty());
+
+    let members = match (role, category) {
+        (None, None) => app_state
+            .keycloak_service
+            .get_organization_members_page(&token, &org_id, first, max, search, exact)
+            .await,
+        // Keycloak cannot filter members by realm role or attribute, so those filters
+        // need every member and the page is cut afterwards
+        (role, category) => filter_members(&app_state, &token, &org_id, role, category)
+            .await
+            .map(|members| {
+                members
+                    .into_iter()
+                    .filter(|member| search.is_none_or(|search| member_matches_search(member, search, exact)))
+                    .skip(first as usize)
+                    .take(max as usize)
+                    .collect::<Vec<_>>()
+            }),
+    };
 
-            Ok((StatusCode::OK, Json(members)))
-        },
+    match members {
+        Ok(members) => Ok((StatusCode::OK, Json(members))),
         Err(e) => {
             tracing::error!("Failed to get organization members: {}", e);
             Err(ApiError::InternalServerError("Failed to get organization members".to_string()))
@@ -473,14 +571,14 @@ pub async fn get_members(
     tag = "Organization",
     params(("org_id", description = "Organization ID")),
     request_body = MemberRequest,
-    responses((status = 201, description = "Added"))
+    responses((status = 201, description = "Added"), (status = 400, description = "Insufficient permissions"))
 )]
 pub async fn add_member(
     Extension(claims): Extension<Claims>,
     Extension(token): Extension<String>,
     State(app_state): State<AppState>,
     Path(org_id): Path<String>,
-    Json(request): Json<MemberRequest>,
+    StrictJson(request): StrictJson<MemberRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let token = get_token_from_extensions(&token)?;
 
@@ -557,7 +655,7 @@ pub async fn create_invitation(
     Extension(token): Extension<String>,
     State(app_state): State<AppState>,
     Path(id): Path<String>,
-    Json(request): Json<InvitationRequest>,
+    StrictJson(request): StrictJson<InvitationRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     let token = get_token_from_extensions(&token)?;
 
@@ -604,6 +702,101 @@ pub async fn delete_invitation(
     }
 }
 
+/// Look up an invitation, answering 404 when Keycloak has no such invitation
+async fn find_invitation(
+    app_state: &AppState,
+    token: &str,
+    org_id: &str,
+    invitation_id: &str,
+) -> Result<KeycloakInvitation, ApiError> {
+    app_state.keycloak_service
+        .get_invitation(token, org_id, invitation_id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get invitation: {}", e);
+            ApiError::InternalServerError("Failed to get invitation".to_string())
+        })?
+        .ok_or_else(|| ApiError::NotFound("Invitation not found".to_string()))
+}
+
+/// Get a single invitation of an organization
+#[utoipa::path(
+    get,
+    path = "/organizations/{org_id}/invitations/{invitation_id}",
+    tag = "Organization",
+    params(("org_id", description = "Organization ID"), ("invitation_id", description = "Invitation ID")),
+    responses(
+        (status = 200, description = "Invitation", body = KeycloakInvitation),
+        (status = 400, description = "Insufficient permissions"),
+        (status = 404, description = "Invitation not found")
+    )
+)]
+pub async fn get_invitation(
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    State(app_state): State<AppState>,
+    Path((org_id, invitation_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let token = get_token_from_extensions(&token)?;
+
+    // Check if user has appropriate permissions
+    if !claims.can_manage_organization(&org_id) {
+        return Err(ApiError::BadRequest("Insufficient permissions".to_string()));
+    }
+
+    let invitation = find_invitation(&app_state, &token, &org_id, &invitation_id).await?;
+
+    Ok((StatusCode::OK, Json(invitation)))
+}
+
+/// Re-send an invitation by replacing it with a new one for the same email and roles
+#[utoipa::path(
+    patch,
+    path = "/organizations/{org_id}/invitations/{invitation_id}/resend",
+    tag = "Organization",
+    params(("org_id", description = "Organization ID"), ("invitation_id", description = "Invitation ID")),
+    responses(
+        (status = 200, description = "The new invitation", body = KeycloakInvitation),
+        (status = 400, description = "Insufficient permissions"),
+        (status = 404, description = "Invitation not found")
+    )
+)]
+pub async fn resend_invitation(
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    State(app_state): State<AppState>,
+    Path((org_id, invitation_id)): Path<(String, String)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let token = get_token_from_extensions(&token)?;
+
+    // Check if user has appropriate permissions
+    if !claims.can_manage_organization(&org_id) {
+        return Err(ApiError::BadRequest("Insufficient permissions".to_string()));
+    }
+
+    let invitation = find_invitation(&app_state, &token, &org_id, &invitation_id).await?;
+
+    app_state.keycloak_service
+        .delete_invitation(&token, &org_id, &invitation_id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to delete invitation before resending: {}", e);
+            ApiError::InternalServerError("Failed to resend invitation".to_string())
+        })?;
+
+    // The old expiration may have passed already, so Keycloak's default applies
+    match app_state.keycloak_service
+        .create_invitation(&token, &org_id, &invitation.email, invitation.roles, None)
+        .await
+    {
+        Ok(invitation) => Ok((StatusCode::OK, Json(invitation))),
+        Err(e) => {
+            tracing::error!(email = %invitation.email, "Failed to recreate invitation: {}", e);
+            Err(ApiError::InternalServerError("Failed to resend invitation".to_string()))
+        }
+    }
+}
+
 // NEW ENDPOINTS MATCHING OPENAPI SPECIFICATION
 
 // Returns the organizations counts
@@ -612,8 +805,12 @@ pub async fn delete_invitation(
     get,
     path = "/admin/realms/{realm}/organizations/count",
     tag = "Organization",
-    params(("realm", description = "Realm")),
-    responses((status = 200, description = "Count"))
+    params(
+        ("realm", description = "Realm"),
+        ("search" = Option<String>, Query, description = "Match the name or a domain"),
+        ("exact" = Option<bool>, Query, description = "Require search to equal the name or a domain instead of contain it")
+    ),
+    responses((status = 200, description = "Count", body = i64))
 )]
 pub async fn get_organizations_count(
     Extension(_claims): Extension<Claims>,
@@ -629,22 +826,8 @@ pub async fn get_organizations_count(
         Ok(mut organizations) => {
             // Apply search filtering if provided
             if let Some(search_term) = &params.search {
-                organizations.retain(|org| {
-                    let name_matches = org.name.to_lowercase().contains(&search_term.to_lowercase());
-                    let domain_matches = org.domains.as_ref()
-                        .map(|domains| domains.iter().any(|domain| 
-                            domain.name.to_lowercase().contains(&search_term.to_lowercase())))
-                        .unwrap_or(false);
-
-                    if params.exact.unwrap_or(false) {
-                        org.name.eq_ignore_ascii_case(search_term) || 
-                        org.domains.as_ref()
-                            .map(|domains| domains.iter().any(|domain| domain.name.eq_ignore_ascii_case(search_term)))
-                            .unwrap_or(false)
-                    } else {
-                        name_matches || domain_matches
-                    }
-                });
+                let exact = params.exact.unwrap_or(false);
+                organizations.retain(|org| organization_matches_search(org, search_term, exact));
             }
 
             let count = organizations.len() as i64;
@@ -657,20 +840,147 @@ pub async fn get_organizations_count(
     }
 }
 
+fn require_application_admin(claims: &Claims) -> Result<(), ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can search organizations".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Search organizations by name or domain in the local copy of Keycloak's organizations
+#[utoipa::path(
+    get,
+    path = "/admin/organizations/search",
+    tag = "Organization",
+    params(
+        ("q" = Option<String>, Query, description = "Part of the name or a domain, case insensitive"),
+        ("limit" = Option<u64>, Query, description = "Maximum number of results, default 20, at most 100")
+    ),
+    responses(
+        (status = 200, description = "Matching organizations", body = OrganizationSearchResponse),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn search_organizations(
+    Extension(claims): Extension<Claims>,
+    State(app_state): State<AppState>,
+    Query(params): Query<OrganizationSearchQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_application_admin(&claims)?;
+
+    let cache = &app_state.database.organizations_cache;
+    let limit = params.limit.unwrap_or(20).clamp(1, 100);
+    let organizations = cache
+        .search(params.q.as_deref().unwrap_or_default(), limit)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to search organizations: {e}")))?
+        .into_iter()
+        .map(|org| CachedOrganization {
+            domains: org.domain_names(),
+            id: org.keycloak_org_id,
+            name: org.name,
+        })
+        .collect();
+    let synced_at = cache
+        .last_synced_at()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to read organizations cache: {e}")))?;
+
+    Ok(Json(OrganizationSearchResponse {
+        organizations,
+        synced_at: synced_at.map(|at| at.to_rfc3339()),
+    }))
+}
+
+/// Count organizations from the local cache, optionally only those matching `search`
+#[utoipa::path(
+    get,
+    path = "/admin/organizations/count",
+    tag = "Organization",
+    params(("search" = Option<String>, Query, description = "Part of the name or a domain, case insensitive")),
+    responses(
+        (status = 200, description = "Count", body = i64),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn count_cached_organizations(
+    Extension(claims): Extension<Claims>,
+    State(app_state): State<AppState>,
+    Query(params): Query<OrganizationsCountQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_application_admin(&claims)?;
+
+    let count = app_state
+        .database
+        .organizations_cache
+        .count(params.search.as_deref())
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to count organizations: {e}")))?;
+
+    Ok(Json(count as i64))
+}
+
+/// Refresh the local organizations cache from Keycloak right away, using the caller's token
+#[utoipa::path(
+    post,
+    path = "/admin/organizations/cache/refresh",
+    tag = "Organization",
+    responses(
+        (status = 200, description = "Cache refreshed", body = OrganizationCacheRefreshResponse),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn refresh_organizations_cache(
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    State(app_state): State<AppState>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_application_admin(&claims)?;
+
+    let cache = &app_state.database.organizations_cache;
+    let organizations = crate::common::services::organizations_cache::refresh_organizations_cache(
+        &app_state.keycloak_service,
+        cache,
+        &token,
+    )
+    .await
+    .map_err(|e| {
+        tracing::error!("Failed to refresh organizations cache: {}", e);
+        ApiError::InternalServerError("Failed to refresh organizations cache".to_string())
+    })?;
+    let synced_at = cache
+        .last_synced_at()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to read organizations cache: {e}")))?;
+
+    Ok(Json(OrganizationCacheRefreshResponse {
+        organizations,
+        synced_at: synced_at.map(|at| at.to_rfc3339()),
+    }))
+}
+
 // Returns the organizations associated with the user that has the specified id
 /// Get member organizations
 #[utoipa::path(
     get,
     path = "/admin/realms/{member_id}/organizations",
     tag = "Organization",
-    params(("member_id", description = "Member ID")),
-    responses((status = 200, description = "Organizations"))
+    params(
+        ("member_id", description = "Member ID"),
+        ("briefRepresentation" = Option<bool>, Query, description = "Accepted for Keycloak compatibility, full organizations are returned")
+    ),
+    responses(
+        (status = 200, description = "Organizations", body = Vec<KeycloakOrganization>),
+        (status = 400, description = "Insufficient permissions")
+    )
 )]
 pub async fn get_member_organizations(
     Extension(claims): Extension<Claims>,
     Extension(token): Extension<String>,
     State(app_state): State<AppState>,
-    Path((_realm, member_id)): Path<(String, String)>,
+    Path(member_id): Path<String>,
     Query(params): Query<MemberOrganizationsQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
     let token = get_token_from_extensions(&token)?;
@@ -680,26 +990,8 @@ pub async fn get_member_organizations(
         return Err(ApiError::BadRequest("Insufficient permissions".to_string()));
     }
 
-    // Get all organizations and filter for ones where the user is a member
-    match app_state.keycloak_service.get_organizations(&token).await {
-        Ok(mut organizations) => {
-            let mut member_organizations = Vec::new();
-
-            // For each organization, check if the user is a member
-            for org in organizations {
-                match app_state.keycloak_service.get_organization_members(&token, &org.id).await {
-                    Ok(members) => {
-                        if members.iter().any(|member| member.id == member_id) {
-                            member_organizations.push(org);
-                        }
-                    },
-                    Err(_) => {
-                        // Skip organizations we can't access
-                        continue;
-                    }
-                }
-            }
-
+    match app_state.keycloak_service.get_user_organizations_direct(&token, &member_id).await {
+        Ok(member_organizations) => {
             // Apply brief representation if requested
             if params.brief_representation.unwrap_or(true) {
                 // For brief representation, we could filter out some fields
@@ -723,7 +1015,10 @@ pub async fn get_member_organizations(
     path = "/admin/realms/{realm}/organizations/{org_id}/identity-providers",
     tag = "Organization",
     params(("realm", description = "Realm"), ("org_id", description = "Organization ID")),
-    responses((status = 200, description = "Identity providers"))
+    responses(
+        (status = 200, description = "Identity providers", body = Vec<serde_json::Value>),
+        (status = 400, description = "Insufficient permissions")
+    )
 )]
 pub async fn get_identity_providers(
     Extension(claims): Extension<Claims>,
@@ -750,8 +1045,8 @@ pub async fn get_identity_providers(
     path = "/admin/realms/{realm}/organizations/{org_id}/identity-providers",
     tag = "Organization",
     params(("realm", description = "Realm"), ("org_id", description = "Organization ID")),
-    request_body = String,
-    responses((status = 204, description = "Added"))
+    request_body(content = String, description = "ID or alias of the identity provider"),
+    responses((status = 204, description = "Added"), (status = 400, description = "Insufficient permissions"))
 )]
 pub async fn add_identity_provider(
     Extension(claims): Extension<Claims>,
@@ -781,7 +1076,11 @@ pub async fn add_identity_provider(
     path = "/admin/realms/{realm}/organizations/{org_id}/identity-providers/{alias}",
     tag = "Organization",
     params(("realm", description = "Realm"), ("org_id", description = "Organization ID"), ("alias", description = "Alias")),
-    responses((status = 200, description = "Identity provider"), (status = 404, description = "Not found"))
+    responses(
+        (status = 200, description = "Identity provider", body = serde_json::Value),
+        (status = 400, description = "Insufficient permissions"),
+        (status = 404, description = "Not found")
+    )
 )]
 pub async fn get_identity_provider(
     Extension(claims): Extension<Claims>,
@@ -808,7 +1107,7 @@ pub async fn get_identity_provider(
     path = "/admin/realms/{realm}/organizations/{org_id}/identity-providers/{alias}",
     tag = "Organization",
     params(("realm", description = "Realm"), ("org_id", description = "Organization ID"), ("alias", description = "Alias")),
-    responses((status = 204, description = "Removed"))
+    responses((status = 204, description = "Removed"), (status = 400, description = "Insufficient permissions"))
 )]
 pub async fn remove_identity_provider(
     Extension(claims): Extension<Claims>,
@@ -833,14 +1132,19 @@ pub async fn remove_identity_provider(
     get,
     path = "/admin/realms/{realm}/organizations/{org_id}/members/count",
     tag = "Organization",
-    params(("realm", description = "Realm"), ("org_id", description = "Organization ID")),
-    responses((status = 200, description = "Count"))
+    params(
+        ("realm", description = "Realm"),
+        ("org_id", description = "Organization ID"),
+        ("role" = Option<String>, Query, description = "Only count members with this realm role; all members when omitted")
+    ),
+    responses((status = 200, description = "Count", body = i64), (status = 400, description = "Insufficient permissions"))
 )]
 pub async fn get_members_count(
     Extension(claims): Extension<Claims>,
     Extension(token): Extension<String>,
     State(app_state): State<AppState>,
     Path((_realm, org_id)): Path<(String, String)>,
+    Query(params): Query<MembersCountQuery>,
 ) -> Result<impl IntoResponse, ApiError> {
     let token = get_token_from_extensions(&token)?;
 
@@ -849,8 +1153,7 @@ pub async fn get_members_count(
         return Err(ApiError::BadRequest("Insufficient permissions".to_string()));
     }
 
-    // Get organization members filtered by org_admin role
-    match app_state.keycloak_service.get_organization_members_by_role(&token, &org_id, "org_admin").await {
+    match organization_members(&app_state, &token, &org_id, params.role.as_deref()).await {
         Ok(members) => {
             let count = members.len() as i64;
             Ok((StatusCode::OK, Json(count)))
@@ -862,6 +1165,82 @@ pub async fn get_members_count(
     }
 }
 
+/// Roles passed as a comma-separated `roles` form field, the format Keycloak's invite form uses
+fn roles_from_form(form: &HashMap<String, String>) -> Vec<String> {
+    form.get("roles")
+        .map(|roles| {
+            roles
+                .split(',')
+                .map(str::trim)
+                .filter(|role| !role.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Organization roles a member can be given; anything else, realm roles like
+/// `application_admin` included, is refused
+const GRANTABLE_ORG_ROLES: [&str; 3] = ["org_user", "org_expert", "org_admin"];
+
+/// Refuse roles outside [`GRANTABLE_ORG_ROLES`], and `org_admin` unless the caller
+/// manages the organization themselves
+fn check_grantable_roles(claims: &Claims, org_id: &str, roles: &[String]) -> Result<(), ApiError> {
+    for role in roles {
+        let grantable = GRANTABLE_ORG_ROLES.contains(&role.as_str())
+            && (role != "org_admin" || claims.can_manage_organization(org_id));
+        if !grantable {
+            return Err(ApiError::BadRequest(format!("Role '{}' cannot be granted", role)));
+        }
+    }
+    Ok(())
+}
+
+/// Add an existing Keycloak user to an organization unless they already belong to it
+async fn add_existing_user(
+    app_state: &AppState,
+    token: &str,
+    org_id: &str,
+    user: &KeycloakUser,
+    roles: Vec<String>,
+) -> Result<(StatusCode, Json<InvitationResultResponse>), ApiError> {
+    let already_member = app_state
+        .keycloak_service
+        .is_user_in_organization(token, org_id, &user.id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to check organization membership: {}", e);
+            ApiError::InternalServerError("Failed to check organization membership".to_string())
+        })?;
+
+    if already_member {
+        return Ok((
+            StatusCode::OK,
+            Json(InvitationResultResponse {
+                status: InvitationResultStatus::AlreadyMember,
+                user_id: user.id.clone(),
+            }),
+        ));
+    }
+
+    app_state
+        .keycloak_service
+        .add_user_to_organization(token, org_id, &user.email, roles)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to add member to organization: {}", e);
+            ApiError::InternalServerError("Failed to add member to organization".to_string())
+        })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(InvitationResultResponse {
+            status: InvitationResultStatus::Added,
+            user_id: user.id.clone(),
+        }),
+    ))
+}
+
 // Invites an existing user to the organization, using the specified user id
 /// Invite existing user to org
 #[utoipa::path(
@@ -869,7 +1248,17 @@ pub async fn get_members_count(
     path = "/admin/realms/{realm}/organizations/{org_id}/members/invite-existing-user",
     tag = "Organization",
     params(("realm", description = "Realm"), ("org_id", description = "Organization ID")),
-    responses((status = 204, description = "Invited"))
+    request_body(
+        content = HashMap<String, String>,
+        content_type = "application/x-www-form-urlencoded",
+        description = "`id` of the user and comma-separated `roles` (`org_user`, `org_expert` or `org_admin`)"
+    ),
+    responses(
+        (status = 201, description = "User added to the organization", body = InvitationResultResponse),
+        (status = 200, description = "User is already a member", body = InvitationResultResponse),
+        (status = 400, description = "Missing id, a role that cannot be granted, or insufficient permissions"),
+        (status = 404, description = "User not found")
+    )
 )]
 pub async fn invite_existing_user(
     Extension(claims): Extension<Claims>,
@@ -878,17 +1267,27 @@ pub async fn invite_existing_user(
     Path((_realm, org_id)): Path<(String, String)>,
     axum::extract::Form(form): axum::extract::Form<HashMap<String, String>>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let _token = get_token_from_extensions(&token)?;
+    let token = get_token_from_extensions(&token)?;
 
     // Check if user has appropriate permissions
     if !claims.can_manage_organization(&org_id) {
         return Err(ApiError::BadRequest("Insufficient permissions".to_string()));
     }
 
-    let _user_id = form.get("id").ok_or_else(|| ApiError::BadRequest("Missing id parameter".to_string()))?;
+    let user_id = form.get("id").ok_or_else(|| ApiError::BadRequest("Missing id parameter".to_string()))?;
+    let roles = roles_from_form(&form);
+    check_grantable_roles(&claims, &org_id, &roles)?;
 
-    // TODO: Implement logic to invite existing user
-    Ok(StatusCode::NO_CONTENT)
+    // The membership call is keyed by email, so resolve the user first
+    let user = app_state.keycloak_service
+        .get_user_by_id(&token, user_id)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get user {}: {}", user_id, e);
+            ApiError::NotFound("User not found".to_string())
+        })?;
+
+    add_existing_user(&app_state, &token, &org_id, &user, roles).await
 }
 
 // Invites an existing user or sends a registration link to a new user, based on the provided e-mail address
@@ -898,7 +1297,16 @@ pub async fn invite_existing_user(
     path = "/admin/realms/{realm}/organizations/{org_id}/members/invite-user",
     tag = "Organization",
     params(("realm", description = "Realm"), ("org_id", description = "Organization ID")),
-    responses((status = 204, description = "Invited"))
+    request_body(
+        content = HashMap<String, String>,
+        content_type = "application/x-www-form-urlencoded",
+        description = "`email`, optional `firstName` and `lastName`, and comma-separated `roles` (`org_user`, `org_expert` or `org_admin`)"
+    ),
+    responses(
+        (status = 201, description = "User added or invited", body = InvitationResultResponse),
+        (status = 200, description = "User is already a member", body = InvitationResultResponse),
+        (status = 400, description = "Missing email, a role that cannot be granted, or insufficient permissions")
+    )
 )]
 pub async fn invite_user(
     Extension(claims): Extension<Claims>,
@@ -907,19 +1315,98 @@ pub async fn invite_user(
     Path((_realm, org_id)): Path<(String, String)>,
     axum::extract::Form(form): axum::extract::Form<HashMap<String, String>>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let _token = get_token_from_extensions(&token)?;
+    let token = get_token_from_extensions(&token)?;
 
     // Check if user has appropriate permissions
     if !claims.can_manage_organization(&org_id) {
         return Err(ApiError::BadRequest("Insufficient permissions".to_string()));
     }
 
-    let _email = form.get("email").ok_or_else(|| ApiError::BadRequest("Missing email parameter".to_string()))?;
-    let _first_name = form.get("firstName");
-    let _last_name = form.get("lastName");
+    let email = form.get("email")
+        .map(|email| email.trim())
+        .filter(|email| !email.is_empty())
+        .ok_or_else(|| ApiError::BadRequest("Missing email parameter".to_string()))?;
+    let roles = roles_from_form(&form);
+    check_grantable_roles(&claims, &org_id, &roles)?;
+
+    invite_user_by_email(
+        &app_state,
+        &token,
+        &org_id,
+        email,
+        form.get("firstName").cloned(),
+        form.get("lastName").cloned(),
+        roles,
+    )
+    .await
+}
 
-    // TODO: Implement logic to invite user by email
-    Ok(StatusCode::NO_CONTENT)
+/// Add the account with `email` to the organization, creating and inviting a new
+/// account when there is none
+async fn invite_user_by_email(
+    app_state: &AppState,
+    token: &str,
+    org_id: &str,
+    email: &str,
+    first_name: Option<String>,
+    last_name: Option<String>,
+    roles: Vec<String>,
+) -> Result<(StatusCode, Json<InvitationResultResponse>), ApiError> {
+    let existing_user = app_state.keycloak_service
+        .find_user_by_username_or_email(token, email)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to look up user by email: {}", e);
+            ApiError::InternalServerError("Failed to look up user".to_string())
+        })?;
+
+    if let Some(user) = existing_user {
+        return add_existing_user(app_state, token, org_id, &user, roles).await;
+    }
+
+    let create_user_request = CreateUserRequest {
+        username: email.split('@').next().unwrap_or(email).to_string(),
+        email: email.to_string(),
+        first_name,
+        last_name,
+        email_verified: Some(false),
+        enabled: Some(true),
+        attributes: Some(serde_json::json!({
+            "organization_id": org_id,
+            "invitation_status": "pending_email_verification"
+        })),
+        credentials: None,
+        required_actions: Some(vec!["VERIFY_EMAIL".to_string()]),
+    };
+
+    let user = app_state.keycloak_service
+        .create_user_with_email_verification(token, &create_user_request)
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to create invited user: {}", e);
+            ApiError::InternalServerError("Failed to create user".to_string())
+        })?;
+
+    app_state.keycloak_service
+        .send_organization_invitation_immediate(token, org_id, &user.id, roles)
+        .await
+        .map_err(|e| {
+            tracing::error!(user_id = %user.id, "Failed to send organization invitation: {}", e);
+            ApiError::InternalServerError("User created but the organization invitation failed".to_string())
+        })?;
+    app_state.publish_admin_event(AdminEvent::UserInvited {
+        user_id: user.id.clone(),
+        email: user.email.clone(),
+        org_id: org_id.to_string(),
+    });
+
+    Ok((
+        StatusCode::CREATED,
+        Json(InvitationResultResponse {
+            status: InvitationResultStatus::Invited,
+            user_id: user.id,
+        }),
+    ))
 }
 
 // Returns the member of the organization with the specified id
@@ -929,7 +1416,10 @@ pub async fn invite_user(
     path = "/admin/realms/{realm}/organizations/{org_id}/members/{member_id}",
     tag = "Organization",
     params(("realm", description = "Realm"), ("org_id", description = "Organization ID"), ("member_id", description = "Member ID")),
-    responses((status = 200, description = "Member"), (status = 404, description = "Not found"))
+    responses(
+        (status = 200, description = "Member", body = KeycloakOrganizationMember),
+        (status = 400, description = "Not an org_admin member of the organization, or insufficient permissions")
+    )
 )]
 pub async fn get_member(
     Extension(claims): Extension<Claims>,
@@ -967,8 +1457,16 @@ pub async fn get_member(
     get,
     path = "/admin/realms/{realm}/organizations/{org_id}/members/{member_id}/organizations",
     tag = "Organization",
-    params(("realm", description = "Realm"), ("org_id", description = "Organization ID"), ("member_id", description = "Member ID")),
-    responses((status = 200, description = "Organizations"))
+    params(
+        ("realm", description = "Realm"),
+        ("org_id", description = "Organization ID"),
+        ("member_id", description = "Member ID"),
+        ("briefRepresentation" = Option<bool>, Query, description = "Accepted for Keycloak compatibility, full organizations are returned")
+    ),
+    responses(
+        (status = 200, description = "Organizations", body = Vec<KeycloakOrganization>),
+        (status = 400, description = "Not a member of the organization, or insufficient permissions")
+    )
 )]
 pub async fn get_member_organizations_in_org(
     Extension(claims): Extension<Claims>,
@@ -997,26 +1495,9 @@ pub async fn get_member_organizations_in_org(
         }
     }
 
-    // Now get all organizations where this member belongs (same logic as get_member_organizations)
-    match app_state.keycloak_service.get_organizations(&token).await {
-        Ok(organizations) => {
-            let mut member_organizations = Vec::new();
-
-            // For each organization, check if the user is a member
-            for org in organizations {
-                match app_state.keycloak_service.get_organization_members(&token, &org.id).await {
-                    Ok(members) => {
-                        if members.iter().any(|member| member.id == member_id) {
-                            member_organizations.push(org);
-                        }
-                    },
-                    Err(_) => {
-                        // Skip organizations we can't access
-                        continue;
-                    }
-                }
-            }
-
+    // Now get all organizations where this member belongs (same as get_member_organizations)
+    match app_state.keycloak_service.get_user_organizations_direct(&token, &member_id).await {
+        Ok(member_organizations) => {
             // Apply brief representation if requested
             if params.brief_representation.unwrap_or(true) {
                 // For brief representation, we could filter out some fields
@@ -1040,7 +1521,7 @@ pub async fn get_member_organizations_in_org(
     path = "/admin/organizations/{org_id}/members/{member_id}",
     tag = "Organization",
     params(("org_id", description = "Organization ID"), ("member_id", description = "Member ID")),
-    responses((status = 204, description = "Removed"))
+    responses((status = 204, description = "Removed"), (status = 400, description = "Insufficient permissions"))
 )]
 pub async fn remove_member(
     Extension(claims): Extension<Claims>,
@@ -1117,16 +1598,20 @@ pub async fn remove_member(
     }
 }
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct OrgAdminMemberRequest {
     pub email: String,
     pub first_name: Option<String>,
     pub last_name: Option<String>,
     pub roles: Vec<String>,
     pub categories: Option<Vec<String>>,
+    /// Language of the invitation email sent when SMTP is configured
+    #[serde(default)]
+    pub language: Option<String>,
 }
 
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, utoipa::ToSchema)]
 pub struct OrgAdminUserInvitationResponse {
     pub user_id: String,
     pub email: String,
@@ -1135,34 +1620,85 @@ pub struct OrgAdminUserInvitationResponse {
 }
 
 /// Add a new member to an organization (Org Admin only)
+#[utoipa::path(
+    post,
+    path = "/organizations/{org_id}/org-admin/members",
+    tag = "Organization",
+    params(("org_id", description = "Organization ID")),
+    request_body = OrgAdminMemberRequest,
+    responses(
+        (status = 201, description = "Member created and invited", body = OrgAdminUserInvitationResponse),
+        (status = 400, description = "Invalid fields or insufficient permissions")
+    )
+)]
 pub async fn add_org_admin_member(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Extension(token): Extension<String>,
     Path(org_id): Path<String>,
-    Json(request): Json<OrgAdminMemberRequest>,
+    StrictJson(request): StrictJson<OrgAdminMemberRequest>,
 ) -> Result<(StatusCode, Json<OrgAdminUserInvitationResponse>), ApiError> {
     // Check if user has org admin permissions for this organization
     if !claims.is_org_admin() {
         return Err(ApiError::BadRequest("Insufficient permissions for this organization".to_string()));
     }
 
-    // Validate email format
-    if !request.email.contains('@') || !request.email.contains('.') {
-        return Err(ApiError::BadRequest("Invalid email format".to_string()));
-    }
+    let response = create_and_invite_org_member(&app_state, &token, &org_id, request).await?;
+    Ok((StatusCode::CREATED, Json(response)))
+}
 
-    // Validate required fields
-    if request.email.trim().is_empty() || 
-       request.first_name.as_ref().map_or(true, |name| name.trim().is_empty()) || 
-       request.last_name.as_ref().map_or(true, |name| name.trim().is_empty()) {
-        return Err(ApiError::BadRequest("Email, first name, and last name are required".to_string()));
+/// Our own invitation email, sent instead of Keycloak's verification email. Failures are only
+/// logged, the member has been invited either way.
+async fn send_invitation_email(
+    app_state: &AppState,
+    email_service: &EmailService,
+    token: &str,
+    org_id: &str,
+    user: &KeycloakUser,
+    roles: &[String],
+    language: Option<&str>,
+) {
+    let organization_name = match app_state.keycloak_service.get_organization(token, org_id).await {
+        Ok(organization) => organization.name,
+        Err(e) => {
+            tracing::warn!(org_id = %org_id, error = %e, "Failed to fetch organization name for invitation email");
+            org_id.to_string()
+        }
+    };
+    let variables = HashMap::from([
+        ("first_name", user.first_name.clone().unwrap_or_default()),
+        ("organization_name", organization_name),
+        ("roles", roles.join(", ")),
+        ("app_url", email_service.app_url.clone()),
+    ]);
+    let email = EmailTemplate::invitation().render(
+        language.unwrap_or("en"),
+        &app_state.locale_config.language_fallback,
+        &variables,
+    );
+
+    match email_service.send(&user.email, email).await {
+        Ok(()) => tracing::info!(user_id = %user.id, org_id = %org_id, "Invitation email sent"),
+        Err(e) => tracing::warn!(user_id = %user.id, org_id = %org_id, error = %e, "Failed to send invitation email"),
     }
+}
 
-    // Validate roles
+/// Create a Keycloak account for a new member and invite it to the organization
+async fn create_and_invite_org_member(
+    app_state: &AppState,
+    token: &str,
+    org_id: &str,
+    request: OrgAdminMemberRequest,
+) -> Result<OrgAdminUserInvitationResponse, ApiError> {
+    // Report every problem with the member at once
+    let mut errors = ValidationErrors::new();
+    errors.email("email", &request.email);
+    errors.require("first_name", request.first_name.as_deref(), "First name is required");
+    errors.require("last_name", request.last_name.as_deref(), "Last name is required");
     if request.roles.is_empty() {
-        return Err(ApiError::BadRequest("At least one role must be assigned".to_string()));
+        errors.add("roles", "At least one role must be assigned");
     }
+    errors.into_result()?;
 
     // Generate username from email
     let username = request.email.split('@').next().unwrap_or(&request.email).to_string();
@@ -1185,15 +1721,30 @@ pub async fn add_org_admin_member(
         required_actions: Some(vec!["VERIFY_EMAIL".to_string()]),
     };
 
-    match app_state.keycloak_service.create_user_with_email_verification(&token, &create_user_request).await {
+    // With our own invitation email the member should not get Keycloak's on top of it
+    let created = if app_state.email_service.is_some() {
+        app_state.keycloak_service.create_user_without_verification_email(token, &create_user_request).await
+    } else {
+        app_state.keycloak_service.create_user_with_email_verification(token, &create_user_request).await
+    };
+    match created {
         Ok(user) => {
             let user_id = user.id.clone();
             let user_email = user.email.clone();
             
             // Send organization invitation immediately (regardless of email verification)
-            match app_state.keycloak_service.send_organization_invitation_immediate(&token, &org_id, &user_id, request.roles.clone()).await {
+            match app_state.keycloak_service.send_organization_invitation_immediate(token, org_id, &user_id, request.roles.clone()).await {
                 Ok(_invitation) => {
                     tracing::info!(user_id = %user_id, org_id = %org_id, "Organization invitation sent immediately");
+                    app_state.publish_admin_event(AdminEvent::UserInvited {
+                        user_id: user_id.clone(),
+                        email: user_email.clone(),
+                        org_id: org_id.to_string(),
+                    });
+
+                    if let Some(email_service) = &app_state.email_service {
+                        send_invitation_email(app_state, email_service, token, org_id, &user, &request.roles, request.language.as_deref()).await;
+                    }
                     
                     let response = OrgAdminUserInvitationResponse {
                         user_id: user.id,
@@ -1203,7 +1754,7 @@ pub async fn add_org_admin_member(
                     };
                     
                     tracing::info!(user_id = %user_id, email = %user_email, "Org admin user invitation created successfully with immediate organization invitation");
-                    Ok((StatusCode::CREATED, Json(response)))
+                    Ok(response)
                 },
                 Err(e) => {
                     tracing::warn!(user_id = %user_id, org_id = %org_id, error = %e, "Failed to send organization invitation immediately, but user was created");
@@ -1215,7 +1766,7 @@ pub async fn add_org_admin_member(
                         message: "User created successfully and email verification sent. Organization invitation failed and will need to be sent manually.".to_string(),
                     };
                     
-                    Ok((StatusCode::CREATED, Json(response)))
+                    Ok(response)
                 }
             }
         },
@@ -1245,13 +1796,543 @@ pub async fn add_org_admin_member(
 
 
 
+/// Most rows accepted in one member or organization import
+const MAX_IMPORT_ROWS: usize = 500;
+const IMPORT_REQUIRED_COLUMNS: [&str; 4] = ["email", "first_name", "last_name", "roles"];
+const IMPORT_OPTIONAL_COLUMNS: [&str; 1] = ["categories"];
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+pub struct MemberImportRowResult {
+    pub row: usize, // Line of the record in the file, the header is row 1
+    pub email: String,
+    pub success: bool,
+    pub user_id: Option<String>,
+    pub status: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+pub struct MemberImportResponse {
+    pub imported: usize,
+    pub failed: usize,
+    pub results: Vec<MemberImportRowResult>,
+}
+
+/// Split CSV text into records, honouring double-quoted fields with `""` escapes
+pub(crate) fn parse_csv(text: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' if in_quotes => in_quotes = false,
+            '"' if field.is_empty() => in_quotes = true,
+            ',' if !in_quotes => record.push(std::mem::take(&mut field)),
+            '\r' if !in_quotes && chars.peek() == Some(&'\n') => {}
+            '\n' if !in_quotes => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err("Unterminated quoted field".to_string());
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+/// Text of the multipart `file` field of a bulk import
+pub(crate) async fn read_import_file(multipart: &mut Multipart) -> Result<String, ApiError> {
+    let mut file = None;
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| ApiError::BadRequest(format!("Failed to process multipart form: {e}")))?
+    {
+        if field.name() == Some("file") {
+            let data = field
+                .bytes()
+                .await
+                .map_err(|e| ApiError::BadRequest(format!("Failed to read file: {e}")))?;
+            file = Some(
+                String::from_utf8(data.to_vec())
+                    .map_err(|_| ApiError::BadRequest("File must be UTF-8".to_string()))?,
+            );
+        }
+    }
+    file.ok_or_else(|| ApiError::BadRequest("No file provided".to_string()))
+}
+
+/// Values of a multi-valued cell, separated by `;`
+fn split_cell(cell: &str) -> Vec<String> {
+    cell.split(';')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Column index of each known header, rejecting unknown, duplicate and missing columns
+fn import_columns(header: &[String], required: &[&str], optional: &[&str]) -> Result<HashMap<String, usize>, ApiError> {
+    let mut columns = HashMap::new();
+    for (index, name) in header.iter().enumerate() {
+        let name = name.trim().to_lowercase();
+        if !required.contains(&name.as_str()) && !optional.contains(&name.as_str()) {
+            return Err(ApiError::BadRequest(format!("Unknown column '{name}'")));
+        }
+        if columns.insert(name.clone(), index).is_some() {
+            return Err(ApiError::BadRequest(format!("Duplicate column '{name}'")));
+        }
+    }
+    let missing: Vec<&str> = required
+        .iter()
+        .copied()
+        .filter(|name| !columns.contains_key(*name))
+        .collect();
+    if !missing.is_empty() {
+        return Err(ApiError::BadRequest(format!("Missing columns: {}", missing.join(", "))));
+    }
+    Ok(columns)
+}
+
+// POST /api/organizations/:org_id/members/import
+/// Create and invite members from a CSV file (Org Admin only)
+///
+/// The multipart `file` field holds a CSV with the columns `email`, `first_name`,
+/// `last_name`, `roles` and optionally `categories`, in any order. Roles and
+/// categories take several values separated by `;`; roles are limited to
+/// `org_user`, `org_expert` and `org_admin`. Each row is created like a member
+/// added through `/org-admin/members`, and a failing row does not stop the others.
+#[utoipa::path(
+    post,
+    path = "/organizations/{org_id}/members/import",
+    tag = "Organization",
+    params(("org_id", description = "Organization ID")),
+    request_body(
+        content = String,
+        content_type = "multipart/form-data",
+        description = "CSV file with email, first_name, last_name, roles and optional categories columns"
+    ),
+    responses(
+        (status = 200, description = "Result of every row", body = MemberImportResponse),
+        (status = 400, description = "Missing file or invalid CSV header"),
+        (status = 403, description = "Forbidden")
+    )
+)]
+pub async fn import_org_members(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    Path(org_id): Path<String>,
+    mut multipart: Multipart,
+) -> Result<Json<MemberImportResponse>, ApiError> {
+    if !claims.is_organization_admin() || !is_member_of_org_by_id(&claims, &org_id) {
+        return Err(ApiError::Forbidden("Insufficient permissions for this organization".to_string()));
+    }
+
+    let csv = read_import_file(&mut multipart).await?;
+
+    let records = parse_csv(&csv).map_err(ApiError::BadRequest)?;
+    let mut records = records.into_iter().enumerate();
+    let (_, header) = records
+        .next()
+        .ok_or_else(|| ApiError::BadRequest("CSV file is empty".to_string()))?;
+    let columns = import_columns(&header, &IMPORT_REQUIRED_COLUMNS, &IMPORT_OPTIONAL_COLUMNS)?;
+    let rows: Vec<(usize, Vec<String>)> = records
+        .filter(|(_, record)| record.iter().any(|cell| !cell.trim().is_empty()))
+        .map(|(index, record)| (index + 1, record))
+        .collect();
+    if rows.len() > MAX_IMPORT_ROWS {
+        return Err(ApiError::BadRequest(format!("At most {MAX_IMPORT_ROWS} rows can be imported at once")));
+    }
+
+    let mut results = Vec::with_capacity(rows.len());
+    for (row, record) in rows {
+        let cell = |name: &str| {
+            columns
+                .get(name)
+                .and_then(|index| record.get(*index))
+                .map(|value| value.trim().to_string())
+                .unwrap_or_default()
+        };
+        let email = cell("email");
+        let request = OrgAdminMemberRequest {
+            email: email.clone(),
+            first_name: Some(cell("first_name")),
+            last_name: Some(cell("last_name")),
+            roles: split_cell(&cell("roles")),
+            categories: Some(split_cell(&cell("categories"))),
+            language: None,
+        };
+
+        let result = if record.len() != header.len() {
+            Err(ApiError::BadRequest(format!(
+                "Expected {} columns, found {}",
+                header.len(),
+                record.len()
+            )))
+        } else if let Err(e) = check_grantable_roles(&claims, &org_id, &request.roles) {
+            Err(e)
+        } else {
+            create_and_invite_org_member(&app_state, &token, &org_id, request).await
+        };
+        results.push(match result {
+            Ok(invited) => MemberImportRowResult {
+                row,
+                email,
+                success: true,
+                user_id: Some(invited.user_id),
+                status: Some(invited.status),
+                error: None,
+            },
+            Err(e) => {
+                tracing::warn!(org_id = %org_id, row, error = e.message(), "Failed to import member");
+                MemberImportRowResult {
+                    row,
+                    email,
+                    success: false,
+                    user_id: None,
+                    status: None,
+                    error: Some(e.message().to_string()),
+                }
+            }
+        });
+    }
+
+    let imported = results.iter().filter(|result| result.success).count();
+    Ok(Json(MemberImportResponse {
+        imported,
+        failed: results.len() - imported,
+        results,
+    }))
+}
+
+const ORGANIZATION_IMPORT_REQUIRED_COLUMNS: [&str; 2] = ["name", "admin_email"];
+const ORGANIZATION_IMPORT_OPTIONAL_COLUMNS: [&str; 2] = ["domain", "categories"];
+/// Organizations created at the same time during a bulk import
+const ORGANIZATION_IMPORT_CONCURRENCY: usize = 5;
+/// Share of invalid rows, in percent, above which a bulk import is rejected as a whole
+const ORGANIZATION_IMPORT_MAX_INVALID_PERCENT: usize = 10;
+
+/// An organization to create, from a CSV record or a JSON array element
+#[derive(Debug, Default, Deserialize)]
+struct OrganizationImportRow {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    domain: Option<String>,
+    #[serde(default)]
+    admin_email: String,
+    #[serde(default)]
+    categories: Vec<String>,
+}
+
+impl OrganizationImportRow {
+    fn trimmed(self) -> Self {
+        Self {
+            name: self.name.trim().to_string(),
+            domain: self
+                .domain
+                .map(|domain| domain.trim().to_string())
+                .filter(|domain| !domain.is_empty()),
+            admin_email: self.admin_email.trim().to_string(),
+            categories: self
+                .categories
+                .into_iter()
+                .map(|category| category.trim().to_string())
+                .filter(|category| !category.is_empty())
+                .collect(),
+        }
+    }
+}
+
+type ImportRows = Vec<(usize, OrganizationImportRow)>;
+
+/// Rows of a JSON array or a CSV file, with the records of the wrong length reported
+/// as validation errors
+fn parse_organization_import(text: &str) -> Result<(ImportRows, Vec<ImportValidationError>), ApiError> {
+    let text = text.trim_start_matches('\u{feff}');
+    if text.trim_start().starts_with('[') {
+        let rows: Vec<OrganizationImportRow> = serde_json::from_str(text)
+            .map_err(|e| ApiError::BadRequest(format!("Invalid JSON: {e}")))?;
+        let rows = rows
+            .into_iter()
+            .enumerate()
+            .map(|(index, row)| (index + 1, row.trimmed()))
+            .collect();
+        return Ok((rows, Vec::new()));
+    }
+
+    let mut records = parse_csv(text).map_err(ApiError::BadRequest)?.into_iter().enumerate();
+    let (_, header) = records
+        .next()
+        .ok_or_else(|| ApiError::BadRequest("CSV file is empty".to_string()))?;
+    let columns = import_columns(
+        &header,
+        &ORGANIZATION_IMPORT_REQUIRED_COLUMNS,
+        &ORGANIZATION_IMPORT_OPTIONAL_COLUMNS,
+    )?;
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for (index, record) in records.filter(|(_, record)| record.iter().any(|cell| !cell.trim().is_empty())) {
+        let row = index + 1;
+        if record.len() != header.len() {
+            errors.push(ImportValidationError {
+                row,
+                field: "row".to_string(),
+                message: format!("Expected {} columns, found {}", header.len(), record.len()),
+            });
+        }
+        let cell = |name: &str| {
+            columns
+                .get(name)
+                .and_then(|index| record.get(*index))
+                .cloned()
+                .unwrap_or_default()
+        };
+        rows.push((
+            row,
+            OrganizationImportRow {
+                name: cell("name"),
+                domain: Some(cell("domain")),
+                admin_email: cell("admin_email"),
+                categories: split_cell(&cell("categories")),
+            }
+            .trimmed(),
+        ));
+    }
+
+    Ok((rows, errors))
+}
+
+/// Problems with the fields of each row, `active_categories` being the names rows may use
+fn validate_organization_import(rows: &ImportRows, active_categories: &[String]) -> Vec<ImportValidationError> {
+    let mut errors = Vec::new();
+    let mut first_rows: HashMap<String, usize> = HashMap::new();
+    for (row, org) in rows {
+        let mut error = |field: &str, message: String| {
+            errors.push(ImportValidationError {
+                row: *row,
+                field: field.to_string(),
+                message,
+            })
+        };
+
+        if org.name.is_empty() {
+            error("name", "Name is required".to_string());
+        } else if let Some(first_row) = first_rows.get(&org.name.to_lowercase()) {
+            error("name", format!("Same name as row {first_row}"));
+        } else {
+            first_rows.insert(org.name.to_lowercase(), *row);
+        }
+        if !org.admin_email.contains('@') || !org.admin_email.contains('.') {
+            error("admin_email", "Invalid email format".to_string());
+        }
+        if let Some(domain) = &org.domain {
+            if !domain.contains('.') || domain.contains(char::is_whitespace) {
+                error("domain", format!("Invalid domain '{domain}'"));
+            }
+        }
+        for category in &org.categories {
+            if !active_categories.contains(category) {
+                error("categories", format!("Unknown category '{category}'"));
+            }
+        }
+    }
+    errors
+}
+
+/// Create one imported organization in Keycloak, assign its categories and invite its admin
+async fn import_organization(app_state: &AppState, token: &str, org: &OrganizationImportRow) -> Result<(), ApiError> {
+    let domains = org
+        .domain
+        .iter()
+        .map(|domain| OrganizationDomainRequest { name: domain.clone() })
+        .collect();
+    let redirect_url = org.domain.as_ref().map(|domain| format!("https://{domain}")).unwrap_or_default();
+    let attributes = (!org.categories.is_empty())
+        .then(|| HashMap::from([("categories".to_string(), org.categories.clone())]));
+
+    let organization = app_state
+        .keycloak_service
+        .create_organization(token, &org.name, domains, redirect_url, "true".to_string(), attributes)
+        .await
+        .map_err(|e| {
+            if is_conflict(&e) {
+                ApiError::Conflict(format!("Organization '{}' already exists", org.name))
+            } else {
+                tracing::error!(name = %org.name, "Failed to create organization: {}", e);
+                ApiError::InternalServerError("Failed to create organization".to_string())
+            }
+        })?;
+
+    assign_organization_categories(app_state, &organization.id, &org.categories).await?;
+
+    invite_user_by_email(
+        app_state,
+        token,
+        &organization.id,
+        &org.admin_email,
+        None,
+        None,
+        vec!["org_admin".to_string()],
+    )
+    .await
+    .map(|_| ())
+    .map_err(|e| {
+        ApiError::InternalServerError(format!(
+            "Organization created, but inviting its admin failed: {}",
+            e.message()
+        ))
+    })
+}
+
+// POST /api/admin/organizations/bulk-import
+/// Create organizations and invite their admins from a CSV or JSON file (Application Admin only)
+///
+/// The multipart `file` field holds either a CSV with the columns `name`,
+/// `admin_email` and optionally `domain` and `categories` (separated by `;`), or a
+/// JSON array of `{name, domain, admin_email, categories}` objects. When more than
+/// 10% of the rows are invalid nothing is imported and every problem is returned.
+/// Otherwise the valid rows are imported, five at a time, and the invalid ones are
+/// reported with the rows that failed.
+#[utoipa::path(
+    post,
+    path = "/admin/organizations/bulk-import",
+    tag = "Organization",
+    request_body(content = String, content_type = "multipart/form-data", description = "CSV or JSON file of organizations"),
+    responses(
+        (status = 200, description = "Import result", body = ImportResult),
+        (status = 400, description = "Missing file or unreadable CSV or JSON"),
+        (status = 403, description = "Forbidden"),
+        (status = 422, description = "Too many invalid rows, nothing was imported", body = ImportRejectedResponse)
+    )
+)]
+pub async fn bulk_import_organizations(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    mut multipart: Multipart,
+) -> Result<axum::response::Response, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only application admins can import organizations".to_string()));
+    }
+
+    let file = read_import_file(&mut multipart).await?;
+
+    let (rows, mut validation_errors) = parse_organization_import(&file)?;
+    if rows.is_empty() {
+        return Err(ApiError::BadRequest("The file contains no organizations".to_string()));
+    }
+    if rows.len() > MAX_IMPORT_ROWS {
+        return Err(ApiError::BadRequest(format!("At most {MAX_IMPORT_ROWS} rows can be imported at once")));
+    }
+
+    let active_categories: Vec<String> = app_state
+        .database
+        .category_catalog
+        .get_all_active_categories()
+        .await
+        .map_err(|e| {
+            tracing::error!("Failed to get category catalogs: {}", e);
+            ApiError::InternalServerError("Failed to get category catalogs".to_string())
+        })?
+        .into_iter()
+        .map(|category| category.name)
+        .collect();
+    validation_errors.extend(validate_organization_import(&rows, &active_categories));
+    validation_errors.sort_by_key(|error| error.row);
+
+    let invalid_rows: HashSet<usize> = validation_errors.iter().map(|error| error.row).collect();
+    if invalid_rows.len() * 100 > rows.len() * ORGANIZATION_IMPORT_MAX_INVALID_PERCENT {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ImportRejectedResponse {
+                error: format!(
+                    "{} of {} rows are invalid, nothing was imported",
+                    invalid_rows.len(),
+                    rows.len()
+                ),
+                validation_errors,
+            }),
+        )
+            .into_response());
+    }
+
+    let total = rows.len();
+    let (valid, invalid): (ImportRows, ImportRows) =
+        rows.into_iter().partition(|(row, _)| !invalid_rows.contains(row));
+
+    let mut errors: Vec<ImportRowError> = invalid
+        .into_iter()
+        .map(|(row, org)| ImportRowError {
+            row,
+            name: org.name,
+            message: validation_errors
+                .iter()
+                .filter(|error| error.row == row)
+                .map(|error| format!("{}: {}", error.field, error.message))
+                .collect::<Vec<_>>()
+                .join("; "),
+        })
+        .collect();
+
+    let outcomes: Vec<(usize, String, Result<(), ApiError>)> = futures::stream::iter(valid)
+        .map(|(row, org)| {
+            let (app_state, token) = (&app_state, &token);
+            async move {
+                let result = import_organization(app_state, token, &org).await;
+                (row, org.name, result)
+            }
+        })
+        .buffer_unordered(ORGANIZATION_IMPORT_CONCURRENCY)
+        .collect()
+        .await;
+    for (row, name, result) in outcomes {
+        if let Err(e) = result {
+            tracing::warn!(row, name = %name, error = e.message(), "Failed to import organization");
+            errors.push(ImportRowError {
+                row,
+                name,
+                message: e.message().to_string(),
+            });
+        }
+    }
+    errors.sort_by_key(|error| error.row);
+
+    Ok(Json(ImportResult {
+        total,
+        succeeded: total - errors.len(),
+        failed: errors.len(),
+        errors,
+    })
+    .into_response())
+}
+
 // GET /api/organizations/:org_id/org-admin/members
 #[utoipa::path(
     get,
     path = "/organizations/{org_id}/org-admin/members",
     tag = "Organization",
     params(("org_id", description = "Organization ID")),
-    responses((status = 200, description = "Org users list"))
+    responses(
+        (status = 200, description = "Org_User members, each with its `categories`", body = Vec<serde_json::Value>),
+        (status = 400, description = "Insufficient permissions")
+    )
 )]
 pub async fn get_org_admin_members(
     Extension(claims): Extension<Claims>,
@@ -1286,7 +2367,7 @@ pub async fn get_org_admin_members(
     path = "/organizations/{org_id}/org-admin/members/{member_id}",
     tag = "Organization",
     params(("org_id", description = "Organization ID"), ("member_id", description = "Member ID")),
-    responses((status = 204, description = "Removed"))
+    responses((status = 204, description = "Removed"), (status = 400, description = "Insufficient permissions"))
 )]
 pub async fn remove_org_admin_member(
     Extension(claims): Extension<Claims>,
@@ -1354,7 +2435,8 @@ pub async fn remove_org_admin_member(
     Ok(StatusCode::NO_CONTENT)
 }
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct OrgAdminMemberCategoryUpdateRequest {
     pub categories: Vec<String>,
 }
@@ -1366,14 +2448,14 @@ pub struct OrgAdminMemberCategoryUpdateRequest {
     tag = "Organization",
     params(("org_id", description = "Organization ID"), ("member_id", description = "Member ID")),
     request_body = OrgAdminMemberCategoryUpdateRequest,
-    responses((status = 204, description = "Updated"))
+    responses((status = 204, description = "Updated"), (status = 400, description = "Insufficient permissions"))
 )]
 pub async fn update_org_admin_member_categories(
     Extension(claims): Extension<Claims>,
     Extension(token): Extension<String>,
     State(app_state): State<AppState>,
     Path((org_id, member_id)): Path<(String, String)>,
-    Json(request): Json<OrgAdminMemberCategoryUpdateRequest>,
+    StrictJson(request): StrictJson<OrgAdminMemberCategoryUpdateRequest>,
 ) -> Result<StatusCode, ApiError> {
     let token = get_token_from_extensions(&token)?;
     if !claims.is_organization_admin() || (!claims.is_application_admin() && !is_member_of_org_by_id(&claims, &org_id)) {
@@ -1386,3 +2468,1206 @@ pub async fn update_org_admin_member_categories(
     })?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Most members whose categories can be set in one bulk update
+const MAX_BULK_CATEGORY_MEMBERS: usize = 500;
+
+// PUT /api/organizations/:org_id/members/categories/bulk
+/// Set the categories of several members at once (Org Admin only)
+///
+/// The body maps member IDs to their new categories. Each member is updated on its
+/// own, so a member that is not in the organization or a failing Keycloak call
+/// only fails that member.
+#[utoipa::path(
+    put,
+    path = "/organizations/{org_id}/members/categories/bulk",
+    tag = "Organization",
+    params(("org_id", description = "Organization ID")),
+    request_body = HashMap<String, Vec<String>>,
+    responses(
+        (status = 200, description = "Outcome per member", body = BulkMemberCategoryUpdateResponse),
+        (status = 400, description = "Too many members"),
+        (status = 403, description = "Forbidden")
+    )
+)]
+pub async fn bulk_update_member_categories(
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    State(app_state): State<AppState>,
+    Path(org_id): Path<String>,
+    Json(request): Json<HashMap<String, Vec<String>>>,
+) -> Result<Json<BulkMemberCategoryUpdateResponse>, ApiError> {
+    if !claims.can_manage_organization(&org_id) {
+        return Err(ApiError::Forbidden("Insufficient permissions for this organization".to_string()));
+    }
+    if request.len() > MAX_BULK_CATEGORY_MEMBERS {
+        return Err(ApiError::BadRequest(format!(
+            "At most {MAX_BULK_CATEGORY_MEMBERS} members can be updated at once"
+        )));
+    }
+
+    let mut results = BTreeMap::new();
+    for (member_id, categories) in request {
+        let result = match app_state
+            .keycloak_service
+            .is_user_in_organization(&token, &org_id, &member_id)
+            .await
+        {
+            Ok(true) => app_state
+                .keycloak_service
+                .set_user_categories_by_id(&token, &member_id, &categories)
+                .await
+                .map_err(|e| {
+                    tracing::error!(member_id = %member_id, "Failed to update user categories: {}", e);
+                    "Failed to update user categories".to_string()
+                }),
+            Ok(false) => Err("Not a member of this organization".to_string()),
+            Err(e) => {
+                tracing::error!(member_id = %member_id, "Failed to check organization membership: {}", e);
+                Err("Failed to check organization membership".to_string())
+            }
+        };
+        results.insert(
+            member_id,
+            MemberCategoryUpdateResult {
+                success: result.is_ok(),
+                error: result.err(),
+            },
+        );
+    }
+
+    let updated = results.values().filter(|result| result.success).count();
+    Ok(Json(BulkMemberCategoryUpdateResponse {
+        updated,
+        failed: results.len() - updated,
+        results,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{claims, keycloak_config, org_claims, serve, unreachable_keycloak};
+    use axum::{
+        body::Body,
+        extract::Query,
+        http::{header, Request},
+        routing::{get, post},
+        Router,
+    };
+    use crate::common::database::entity::organization_categories;
+    use sea_orm::{DatabaseBackend, MockDatabase};
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+
+    const REALM_PATH: &str = "/admin/realms/test-realm";
+
+    fn keycloak_user(id: &str, email: &str) -> serde_json::Value {
+        serde_json::json!({ "id": id, "username": id, "email": email })
+    }
+
+    /// Minimal stand-in for the Keycloak admin API. `member-user` and `second-member`
+    /// already belong to every organization; every POST and PUT is recorded so tests can
+    /// check what was called.
+    async fn fake_keycloak(calls: Arc<Mutex<Vec<String>>>) -> String {
+        let record = |calls: Arc<Mutex<Vec<String>>>, status: StatusCode| {
+            move |request: Request<Body>| async move {
+                calls.lock().unwrap().push(request.uri().path().to_string());
+                status
+            }
+        };
+
+        let app = Router::new()
+            .route(
+                &format!("{REALM_PATH}/users"),
+                get(|Query(query): Query<HashMap<String, String>>| async move {
+                    let users = match query.get("search").map(String::as_str) {
+                        Some("existing@example.com") => vec![keycloak_user("existing-user", "existing@example.com")],
+                        Some("member@example.com") => vec![keycloak_user("member-user", "member@example.com")],
+                        _ => Vec::new(),
+                    };
+                    Json(users)
+                })
+                .post({
+                    let calls = calls.clone();
+                    move |request: Request<Body>| async move {
+                        calls.lock().unwrap().push(request.uri().path().to_string());
+                        (
+                            StatusCode::CREATED,
+                            [(header::LOCATION, format!("{REALM_PATH}/users/new-user"))],
+                        )
+                    }
+                }),
+            )
+            .route(
+                &format!("{REALM_PATH}/users/:user_id"),
+                get(|Path(user_id): Path<String>| async move {
+                    match user_id.as_str() {
+                        "existing-user" => Ok(Json(keycloak_user("existing-user", "existing@example.com"))),
+                        "member-user" => Ok(Json(keycloak_user("member-user", "member@example.com"))),
+                        "new-user" => Ok(Json(keycloak_user("new-user", "new@example.com"))),
+                        "second-member" => Ok(Json(keycloak_user("second-member", "second@example.com"))),
+                        _ => Err(StatusCode::NOT_FOUND),
+                    }
+                })
+                .put({
+                    let calls = calls.clone();
+                    move |Path(user_id): Path<String>, Json(user): Json<serde_json::Value>| async move {
+                        calls.lock().unwrap().push(format!(
+                            "{REALM_PATH}/users/{user_id} categories={}",
+                            user["attributes"]["categories"]
+                        ));
+                        StatusCode::NO_CONTENT
+                    }
+                }),
+            )
+            .route(
+                &format!("{REALM_PATH}/roles/:role"),
+                get(|Path(role): Path<String>| async move { Json(serde_json::json!({ "id": format!("{role}-id"), "name": role })) }),
+            )
+            .route(
+                &format!("{REALM_PATH}/users/:user_id/role-mappings/realm"),
+                post(record(calls.clone(), StatusCode::NO_CONTENT)),
+            )
+            .route(
+                &format!("{REALM_PATH}/users/:user_id/send-verify-email"),
+                post(record(calls.clone(), StatusCode::NO_CONTENT)),
+            )
+            .route(
+                &format!("{REALM_PATH}/organizations"),
+                post({
+                    let calls = calls.clone();
+                    move |Json(org): Json<serde_json::Value>| async move {
+                        let name = org["name"].as_str().unwrap_or_default().to_string();
+                        calls.lock().unwrap().push(format!("{REALM_PATH}/organizations {name}"));
+                        if name == "Taken Coop" {
+                            return (
+                                StatusCode::CONFLICT,
+                                Json(serde_json::json!({ "errorMessage": "A organization with the same name already exists." })),
+                            );
+                        }
+                        let id = format!("org-{}", name.to_lowercase().replace(|c: char| !c.is_alphanumeric(), "-"));
+                        (StatusCode::CREATED, Json(serde_json::json!({ "id": id, "name": name, "enabled": true })))
+                    }
+                }),
+            )
+            .route(
+                &format!("{REALM_PATH}/organizations/:org_id/members"),
+                post(record(calls.clone(), StatusCode::CREATED)),
+            )
+            .route(
+                &format!("{REALM_PATH}/organizations/:org_id/members/invite-user"),
+                post(record(calls.clone(), StatusCode::NO_CONTENT)),
+            )
+            .route(
+                &format!("{REALM_PATH}/organizations/:org_id/members/:member_id"),
+                get(|Path((_org_id, member_id)): Path<(String, String)>| async move {
+                    if member_id == "member-user" || member_id == "second-member" {
+                        StatusCode::OK
+                    } else {
+                        StatusCode::NOT_FOUND
+                    }
+                }),
+            );
+
+        serve(app).await
+    }
+
+    async fn invite(uri: &str, form: &str) -> (StatusCode, Option<InvitationResultResponse>, Vec<String>) {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let keycloak_url = fake_keycloak(calls.clone()).await;
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let app_state = AppState::new(
+            keycloak_config(keycloak_url),
+            crate::common::state::AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+
+        let app = Router::new()
+            .route(
+                "/admin/realms/:realm/organizations/:org_id/members/invite-existing-user",
+                post(invite_existing_user),
+            )
+            .route(
+                "/admin/realms/:realm/organizations/:org_id/members/invite-user",
+                post(invite_user),
+            )
+            .layer(Extension(claims("admin-user", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(uri)
+                    .header(header::CONTENT_TYPE, "application/x-www-form-urlencoded")
+                    .body(Body::from(form.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let calls = calls.lock().unwrap().clone();
+        (status, serde_json::from_slice(&body).ok(), calls)
+    }
+
+    #[tokio::test]
+    async fn test_invite_existing_user_adds_member() {
+        let (status, body, calls) = invite(
+            "/admin/realms/test-realm/organizations/org-1/members/invite-existing-user",
+            "id=existing-user",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        let body = body.unwrap();
+        assert_eq!(body.status, InvitationResultStatus::Added);
+        assert_eq!(body.user_id, "existing-user");
+        assert_eq!(calls, vec![format!("{REALM_PATH}/organizations/org-1/members")]);
+    }
+
+    #[tokio::test]
+    async fn test_invite_existing_user_already_member() {
+        let (status, body, calls) = invite(
+            "/admin/realms/test-realm/organizations/org-1/members/invite-existing-user",
+            "id=member-user",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        let body = body.unwrap();
+        assert_eq!(body.status, InvitationResultStatus::AlreadyMember);
+        assert_eq!(body.user_id, "member-user");
+        assert!(calls.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_invite_user_by_email_adds_existing_account() {
+        let (status, body, calls) = invite(
+            "/admin/realms/test-realm/organizations/org-1/members/invite-user",
+            "email=existing%40example.com",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body.unwrap().status, InvitationResultStatus::Added);
+        assert_eq!(calls, vec![format!("{REALM_PATH}/organizations/org-1/members")]);
+    }
+
+    #[tokio::test]
+    async fn test_invite_user_creates_and_invites_new_account() {
+        let (status, body, calls) = invite(
+            "/admin/realms/test-realm/organizations/org-1/members/invite-user",
+            "email=new%40example.com&firstName=New&lastName=User",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::CREATED);
+        let body = body.unwrap();
+        assert_eq!(body.status, InvitationResultStatus::Invited);
+        assert_eq!(body.user_id, "new-user");
+        assert_eq!(
+            calls,
+            vec![
+                format!("{REALM_PATH}/users"),
+                format!("{REALM_PATH}/users/new-user/send-verify-email"),
+                format!("{REALM_PATH}/organizations/org-1/members/invite-user"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_roles_from_form() {
+        let form = HashMap::from([("roles".to_string(), "org_user, org_admin,,".to_string())]);
+        assert_eq!(roles_from_form(&form), vec!["org_user", "org_admin"]);
+        assert!(roles_from_form(&HashMap::new()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_invite_user_rejects_roles_outside_the_organization() {
+        let (status, _, calls) = invite(
+            "/admin/realms/test-realm/organizations/org-1/members/invite-user",
+            "email=new%40example.com&roles=org_user,application_admin",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(calls.is_empty());
+    }
+
+    #[test]
+    fn test_check_grantable_roles() {
+        let roles = |roles: &[&str]| roles.iter().map(|role| role.to_string()).collect::<Vec<_>>();
+        let org_admin = org_claims("admin-1", &["org_admin"], "Org One", "org-1");
+        let expert = org_claims("expert-1", &["org_expert"], "Org One", "org-1");
+
+        assert!(check_grantable_roles(&org_admin, "org-1", &roles(&["org_user", "org_expert", "org_admin"])).is_ok());
+        assert!(check_grantable_roles(&expert, "org-1", &roles(&["org_user", "org_expert"])).is_ok());
+        assert!(check_grantable_roles(&expert, "org-1", &roles(&["org_admin"])).is_err());
+        assert!(check_grantable_roles(&org_admin, "org-2", &roles(&["org_admin"])).is_err());
+        assert!(check_grantable_roles(&org_admin, "org-1", &roles(&["application_admin"])).is_err());
+        assert!(check_grantable_roles(&org_admin, "org-1", &roles(&["Org_User"])).is_err());
+    }
+
+    /// Keycloak stand-in for org-1's members: `admin-1` holds org_admin, the two
+    /// others are plain org_user members. `admin-1` and `user-1` are assigned
+    /// Finance, `admin-1` and `user-2` Energy. Member listings are paged like Keycloak
+    /// does and their query parameters recorded; `search` is not applied.
+    async fn fake_keycloak_members(queries: Arc<Mutex<Vec<HashMap<String, String>>>>) -> String {
+        let app = Router::new()
+            .route(
+                &format!("{REALM_PATH}/organizations/:org_id/members"),
+                get(move |Query(query): Query<HashMap<String, String>>| async move {
+                    queries.lock().unwrap().push(query.clone());
+
+                    let first = query.get("first").and_then(|first| first.parse().ok()).unwrap_or(0);
+                    let max = query.get("max").and_then(|max| max.parse().ok()).unwrap_or(usize::MAX);
+                    let members: Vec<_> = [
+                        ("admin-1", "admin@example.com"),
+                        ("user-1", "alice@example.com"),
+                        ("user-2", "bob@example.com"),
+                    ]
+                    .into_iter()
+                    .skip(first)
+                    .take(max)
+                    .map(|(id, email)| keycloak_user(id, email))
+                    .collect();
+                    Json(members)
+                }),
+            )
+            .route(
+                &format!("{REALM_PATH}/users/:user_id/role-mappings/realm"),
+                get(|Path(user_id): Path<String>| async move {
+                    let role = if user_id == "admin-1" { "org_admin" } else { "org_user" };
+                    Json(serde_json::json!([{ "name": role }]))
+                }),
+            )
+            .route(
+                &format!("{REALM_PATH}/users/:user_id"),
+                get(|Path(user_id): Path<String>| async move {
+                    let categories = match user_id.as_str() {
+                        "admin-1" => vec!["Finance", "Energy"],
+                        "user-1" => vec!["Finance"],
+                        _ => vec!["Energy"],
+                    };
+                    let mut user = keycloak_user(&user_id, &format!("{user_id}@example.com"));
+                    user["attributes"] = serde_json::json!({ "categories": categories });
+                    Json(user)
+                }),
+            );
+
+        serve(app).await
+    }
+
+    type RecordedQueries = Arc<Mutex<Vec<HashMap<String, String>>>>;
+
+    async fn members_app() -> (Router, RecordedQueries) {
+        let queries = RecordedQueries::default();
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let app_state = AppState::new(
+            keycloak_config(fake_keycloak_members(queries.clone()).await),
+            crate::common::state::AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+
+        let app = Router::new()
+            .route("/api/organizations/:org_id/members", get(get_members))
+            .route("/admin/realms/:realm/organizations/:org_id/members/count", get(get_members_count))
+            .layer(Extension(claims("admin-user", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+
+        (app, queries)
+    }
+
+    #[tokio::test]
+    async fn test_get_members_lists_everyone_without_role() {
+        let (app, _) = members_app().await;
+
+        let members = request_json(&app, "GET", "/api/organizations/org-1/members").await;
+        let ids: Vec<&str> = members.as_array().unwrap().iter().map(|m| m["id"].as_str().unwrap()).collect();
+        assert_eq!(ids, vec!["admin-1", "user-1", "user-2"]);
+
+        let count = request_json(&app, "GET", "/admin/realms/test-realm/organizations/org-1/members/count").await;
+        assert_eq!(count, 3);
+    }
+
+    #[test]
+    fn test_member_matches_search() {
+        let member: KeycloakOrganizationMember = serde_json::from_value(serde_json::json!({
+            "id": "user-1",
+            "username": "alice",
+            "email": "alice@example.com",
+            "firstName": "Alice",
+            "lastName": "Martin",
+        }))
+        .unwrap();
+
+        assert!(member_matches_search(&member, "MART", false));
+        assert!(member_matches_search(&member, "example.com", false));
+        assert!(!member_matches_search(&member, "bob", false));
+
+        assert!(member_matches_search(&member, "Alice@Example.com", true));
+        assert!(member_matches_search(&member, "martin", true));
+        assert!(!member_matches_search(&member, "example.com", true));
+    }
+
+    #[tokio::test]
+    async fn test_get_members_search_with_role_is_applied_in_memory() {
+        let (app, queries) = members_app().await;
+        let ids = |members: serde_json::Value| -> Vec<String> {
+            members.as_array().unwrap().iter().map(|m| m["id"].as_str().unwrap().to_string()).collect()
+        };
+
+        let partial = request_json(&app, "GET", "/api/organizations/org-1/members?role=org_user&search=ALICE").await;
+        assert_eq!(ids(partial), vec!["user-1"]);
+
+        let exact = request_json(&app, "GET", "/api/organizations/org-1/members?role=org_user&search=alice&exact=true").await;
+        assert!(ids(exact).is_empty());
+
+        // Keycloak lists every member, the search is left to the handler
+        assert!(queries.lock().unwrap().iter().all(|query| !query.contains_key("search")));
+    }
+
+    #[tokio::test]
+    async fn test_get_members_pages_in_keycloak() {
+        let (app, queries) = members_app().await;
+
+        let members = request_json(
+            &app,
+            "GET",
+            "/api/organizations/org-1/members?first=1&max=1&search=example.com",
+        )
+        .await;
+        assert_eq!(members[0]["id"], "user-1");
+        assert_eq!(members.as_array().unwrap().len(), 1);
+
+        let queries = queries.lock().unwrap().clone();
+        assert_eq!(queries.len(), 1);
+        assert_eq!(queries[0]["first"], "1");
+        assert_eq!(queries[0]["max"], "1");
+        assert_eq!(queries[0]["search"], "example.com");
+        assert_eq!(queries[0]["exact"], "false");
+    }
+
+    #[tokio::test]
+    async fn test_get_members_with_role_pages_in_memory() {
+        let (app, queries) = members_app().await;
+
+        let users = request_json(&app, "GET", "/api/organizations/org-1/members?role=org_user&first=1&max=1").await;
+        let ids: Vec<&str> = users.as_array().unwrap().iter().map(|m| m["id"].as_str().unwrap()).collect();
+        assert_eq!(ids, vec!["user-2"]);
+
+        // Every member is needed to check roles, so Keycloak is not asked for a page
+        let queries = queries.lock().unwrap().clone();
+        assert_eq!(queries.len(), 1);
+        assert!(!queries[0].contains_key("first"));
+        assert!(!queries[0].contains_key("max"));
+    }
+
+    #[tokio::test]
+    async fn test_get_members_filters_by_role() {
+        let (app, _) = members_app().await;
+
+        let admins = request_json(&app, "GET", "/api/organizations/org-1/members?role=org_admin").await;
+        let ids: Vec<&str> = admins.as_array().unwrap().iter().map(|m| m["id"].as_str().unwrap()).collect();
+        assert_eq!(ids, vec!["admin-1"]);
+
+        let users = request_json(
+            &app,
+            "GET",
+            "/admin/realms/test-realm/organizations/org-1/members/count?role=org_user",
+        )
+        .await;
+        assert_eq!(users, 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_members_filters_by_category() {
+        let (app, _) = members_app().await;
+        let ids = |members: serde_json::Value| -> Vec<String> {
+            members.as_array().unwrap().iter().map(|m| m["id"].as_str().unwrap().to_string()).collect()
+        };
+
+        let finance = request_json(&app, "GET", "/api/organizations/org-1/members?category=Finance").await;
+        assert_eq!(ids(finance), vec!["admin-1", "user-1"]);
+
+        let finance_users =
+            request_json(&app, "GET", "/api/organizations/org-1/members?category=Finance&role=org_user").await;
+        assert_eq!(ids(finance_users), vec!["user-1"]);
+
+        let unassigned = request_json(&app, "GET", "/api/organizations/org-1/members?category=Water").await;
+        assert!(ids(unassigned).is_empty());
+    }
+
+    /// Keycloak stand-in that only lists organizations
+    async fn fake_keycloak_organizations() -> String {
+        let organizations = serde_json::json!([
+            { "id": "org-1", "name": "Green Coop", "enabled": true, "domains": [{ "name": "greencoop.org" }] },
+            { "id": "org-2", "name": "Blue Bank", "enabled": true, "domains": [{ "name": "bluebank.example" }] },
+            { "id": "org-3", "name": "Organic Farmers", "enabled": true, "domains": [] },
+            { "id": "org-4", "name": "Sunrise Energy", "enabled": true },
+        ]);
+        let app = Router::new().route(
+            &format!("{REALM_PATH}/organizations"),
+            get(move || async move { Json(organizations) }),
+        );
+
+        serve(app).await
+    }
+
+    async fn request_json(app: &Router, method: &str, uri: &str) -> serde_json::Value {
+        let response = app
+            .clone()
+            .oneshot(Request::builder().method(method).uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK, "{method} {uri}");
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_cached_search_matches_keycloak_search() {
+        use crate::common::database::entity::organizations_cache;
+        use sea_orm::{ConnectionTrait, Database, Schema};
+
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        let backend = db.get_database_backend();
+        db.execute(backend.build(&Schema::new(backend).create_table_from_entity(organizations_cache::Entity)))
+            .await
+            .unwrap();
+        let app_state = AppState::new(
+            keycloak_config(fake_keycloak_organizations().await),
+            crate::common::state::AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+        let app = Router::new()
+            .route("/admin/organizations", get(get_organizations))
+            .route("/admin/organizations/search", get(search_organizations))
+            .route("/admin/organizations/count", get(count_cached_organizations))
+            .route("/admin/organizations/cache/refresh", post(refresh_organizations_cache))
+            .layer(Extension(claims("admin-user", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+
+        let refreshed = request_json(&app, "POST", "/admin/organizations/cache/refresh").await;
+        assert_eq!(refreshed["organizations"], 4);
+        assert!(refreshed["synced_at"].is_string());
+
+        let sorted_ids = |organizations: &serde_json::Value| {
+            let mut ids: Vec<String> = organizations
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|org| org["id"].as_str().unwrap().to_string())
+                .collect();
+            ids.sort();
+            ids
+        };
+        for term in ["green", "ORG", "bank.example", "e", "nothing-matches"] {
+            let from_keycloak = request_json(&app, "GET", &format!("/admin/organizations?search={term}&max=100")).await;
+            let from_cache = request_json(&app, "GET", &format!("/admin/organizations/search?q={term}&limit=100")).await;
+            assert_eq!(sorted_ids(&from_cache["organizations"]), sorted_ids(&from_keycloak), "search {term}");
+
+            let count = request_json(&app, "GET", &format!("/admin/organizations/count?search={term}")).await;
+            assert_eq!(count, from_keycloak.as_array().unwrap().len(), "count {term}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_organization_search_requires_application_admin() {
+        let claims = claims("admin-user", &["org_admin"]);
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let app_state = AppState::new(
+            unreachable_keycloak(),
+            crate::common::state::AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+        let app = Router::new()
+            .route("/admin/organizations/search", get(search_organizations))
+            .layer(Extension(claims))
+            .with_state(app_state);
+
+        let response = app
+            .oneshot(Request::builder().uri("/admin/organizations/search?q=green").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    async fn import_members(csv: &str) -> (StatusCode, serde_json::Value, Vec<String>) {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let keycloak_url = fake_keycloak(calls.clone()).await;
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let app_state = AppState::new(
+            keycloak_config(keycloak_url),
+            crate::common::state::AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+        let app = Router::new()
+            .route("/api/organizations/:org_id/members/import", post(import_org_members))
+            .layer(Extension(org_claims("admin-user", &["org_admin"], "Org One", "org-1")))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+
+        let boundary = "test-boundary";
+        let body = format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"members.csv\"\r\nContent-Type: text/csv\r\n\r\n{csv}\r\n--{boundary}--\r\n"
+        );
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/organizations/org-1/members/import")
+                    .header(header::CONTENT_TYPE, format!("multipart/form-data; boundary={boundary}"))
+                    .body(Body::from(body))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let calls = calls.lock().unwrap().clone();
+        (status, serde_json::from_slice(&body).unwrap_or_default(), calls)
+    }
+
+    #[tokio::test]
+    async fn test_import_members_processes_rows_independently() {
+        let (status, body, calls) = import_members(
+            "email,first_name,last_name,roles,categories\r\n\
+             not-an-email,Bad,Row,org_user,\r\n\
+             new@example.com,New,\"User, Jr.\",org_user,environment;social\r\n\
+             admin@example.com,Sneaky,Admin,org_user;application_admin,\r\n",
+        )
+        .await;
+
+        assert_eq!(status, StatusCode::OK);
+        let import: MemberImportResponse = serde_json::from_value(body).unwrap();
+        assert_eq!((import.imported, import.failed), (1, 2));
+
+        assert_eq!(import.results[0].row, 2);
+        assert!(!import.results[0].success);
+        assert_eq!(import.results[0].error.as_deref(), Some("Invalid email format"));
+
+        assert_eq!(import.results[1].row, 3);
+        assert_eq!(import.results[1].email, "new@example.com");
+        assert!(import.results[1].success);
+        assert_eq!(import.results[1].user_id.as_deref(), Some("new-user"));
+
+        assert_eq!(import.results[2].row, 4);
+        assert!(!import.results[2].success);
+        assert_eq!(import.results[2].error.as_deref(), Some("Role 'application_admin' cannot be granted"));
+        // Only the valid row reached Keycloak
+        assert_eq!(calls.iter().filter(|path| path.ends_with("/users")).count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_import_members_validates_header() {
+        for (csv, error) in [
+            ("email,first_name,roles\nnew@example.com,New,org_user\n", "Missing columns: last_name"),
+            ("email,first_name,last_name,roles,phone\n", "Unknown column 'phone'"),
+            ("", "CSV file is empty"),
+        ] {
+            let (status, body, calls) = import_members(csv).await;
+
+            assert_eq!(status, StatusCode::BAD_REQUEST);
+            assert_eq!(body["error"], error);
+            assert!(calls.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_add_member_reports_every_invalid_field() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let keycloak_url = fake_keycloak(calls.clone()).await;
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let app_state = AppState::new(
+            keycloak_config(keycloak_url),
+            crate::common::state::AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+        let app = Router::new()
+            .route("/api/organizations/:org_id/org-admin/members", post(add_org_admin_member))
+            .layer(Extension(org_claims("admin-user", &["org_admin"], "Org One", "org-1")))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+
+        let member = serde_json::json!({
+            "email": "not-an-email",
+            "first_name": " ",
+            "last_name": "Doe",
+            "roles": [],
+        });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/organizations/org-1/org-admin/members")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(member.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let body: serde_json::Value =
+            serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap()).unwrap();
+        assert_eq!(
+            body["fields"],
+            serde_json::json!({
+                "email": ["Invalid email format"],
+                "first_name": ["First name is required"],
+                "roles": ["At least one role must be assigned"],
+            })
+        );
+        assert_eq!(
+            body["error"],
+            "Invalid email format; First name is required; At least one role must be assigned"
+        );
+        assert!(calls.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_add_member_with_invitation_email_skips_keycloak_verification_email() {
+        use crate::common::config::EmailConfig;
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let keycloak_url = fake_keycloak(calls.clone()).await;
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let email_service = EmailService::from_config(&EmailConfig {
+            smtp_host: Some("127.0.0.1".to_string()),
+            smtp_port: 1,
+            smtp_username: None,
+            smtp_password: None,
+            from: "no-reply@localhost".to_string(),
+            app_url: "http://localhost:5173".to_string(),
+        })
+        .unwrap();
+        let app_state = AppState::new(
+            keycloak_config(keycloak_url),
+            crate::common::state::AppDatabase::new(Arc::new(db)).await,
+        )
+        .await
+        .with_email_service(email_service);
+        let app = Router::new()
+            .route("/api/organizations/:org_id/org-admin/members", post(add_org_admin_member))
+            .layer(Extension(org_claims("admin-user", &["org_admin"], "Org One", "org-1")))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+
+        let member = serde_json::json!({
+            "email": "new@example.com",
+            "first_name": "New",
+            "last_name": "User",
+            "roles": ["org_user"],
+        });
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/organizations/org-1/org-admin/members")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(member.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let calls = calls.lock().unwrap().clone();
+        assert!(calls.contains(&format!("{REALM_PATH}/users")));
+        assert!(!calls.iter().any(|path| path.ends_with("/send-verify-email")));
+    }
+
+    async fn bulk_import(
+        file: &str,
+    ) -> Result<(StatusCode, serde_json::Value, Vec<String>, Vec<organization_categories::Model>), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::category_catalog;
+        use sea_orm::{ActiveModelTrait, ConnectionTrait, Database, EntityTrait, Schema, Set};
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let keycloak_url = fake_keycloak(calls.clone()).await;
+
+        let db = Database::connect("sqlite::memory:").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        db.execute(backend.build(&schema.create_table_from_entity(category_catalog::Entity))).await?;
+        db.execute(backend.build(&schema.create_table_from_entity(organization_categories::Entity))).await?;
+        for name in ["Environment", "Social"] {
+            category_catalog::ActiveModel {
+                category_catalog_id: Set(uuid::Uuid::new_v4()),
+                name: Set(name.to_string()),
+                description: Set(None),
+                template_id: Set("sustainability_template_1".to_string()),
+                is_active: Set(true),
+                created_at: Set(chrono::Utc::now()),
+                updated_at: Set(chrono::Utc::now()),
+                default_recommendation: Set(None),
+                deactivated_at: Set(None),
+                parent_category_catalog_id: Set(None),
+                scoring_mode: Set(Default::default()),
+                score_threshold: Set(None),
+                icon_name: Set(None),
+                color_hex: Set(None),
+                metadata: Set(serde_json::json!({})),
+            }
+            .insert(&db)
+            .await?;
+        }
+        let db = Arc::new(db);
+
+        let app_state = AppState::new(
+            keycloak_config(keycloak_url),
+            crate::common::state::AppDatabase::new(db.clone()).await,
+        )
+        .await;
+        let app = Router::new()
+            .route("/api/admin/organizations/bulk-import", post(bulk_import_organizations))
+            .layer(Extension(claims("admin-user", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+
+        let boundary = "test-boundary";
+        let body = format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"organizations\"\r\n\r\n{file}\r\n--{boundary}--\r\n"
+        );
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/admin/organizations/bulk-import")
+                    .header(header::CONTENT_TYPE, format!("multipart/form-data; boundary={boundary}"))
+                    .body(Body::from(body))?,
+            )
+            .await?;
+
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        let calls = calls.lock().unwrap().clone();
+        let assigned = organization_categories::Entity::find().all(db.as_ref()).await?;
+        Ok((status, serde_json::from_slice(&body)?, calls, assigned))
+    }
+
+    fn created_organizations(calls: &[String]) -> Vec<&str> {
+        let mut names: Vec<&str> = calls
+            .iter()
+            .filter_map(|call| call.strip_prefix(&format!("{REALM_PATH}/organizations ")))
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[tokio::test]
+    async fn test_bulk_import_creates_organizations_and_admins() -> Result<(), Box<dyn std::error::Error>> {
+        let (status, body, calls, assigned) = bulk_import(
+            "\u{feff}name,domain,admin_email,categories\r\n\
+             \"Green, Inc.\",green.example,admin@green.example,Environment;Social\r\n\
+             Blue Coop,,admin@blue.example,\r\n",
+        )
+        .await?;
+
+        assert_eq!(status, StatusCode::OK);
+        let result: ImportResult = serde_json::from_value(body)?;
+        assert_eq!((result.total, result.succeeded, result.failed), (2, 2, 0));
+        assert_eq!(created_organizations(&calls), vec!["Blue Coop", "Green, Inc."]);
+        // Each admin got an account and an invitation to their organization
+        assert_eq!(calls.iter().filter(|call| call.ends_with("/members/invite-user")).count(), 2);
+        assert!(calls.contains(&format!("{REALM_PATH}/organizations/org-green--inc-/members/invite-user")));
+
+        assert_eq!(assigned.len(), 2);
+        assert!(assigned.iter().all(|category| category.keycloak_organization_id == "org-green--inc-"));
+        assert_eq!(assigned.iter().map(|category| category.weight).sum::<i32>(), 100);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bulk_import_rejects_file_with_too_many_invalid_rows() -> Result<(), Box<dyn std::error::Error>> {
+        let (status, body, calls, _) = bulk_import(
+            "name,admin_email,categories\n\
+             Green Coop,admin@green.example,Environment\n\
+             ,admin@nameless.example,\n\
+             Blue Coop,not-an-email,Governance\n",
+        )
+        .await?;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let rejected: ImportRejectedResponse = serde_json::from_value(body)?;
+        let errors: Vec<(usize, &str)> = rejected
+            .validation_errors
+            .iter()
+            .map(|error| (error.row, error.field.as_str()))
+            .collect();
+        assert_eq!(errors, vec![(3, "name"), (4, "admin_email"), (4, "categories")]);
+        assert!(created_organizations(&calls).is_empty());
+
+        // One invalid row in ten is tolerated, the others are imported
+        let mut csv = "name,admin_email\n".to_string();
+        for n in 1..=9 {
+            csv.push_str(&format!("Coop {n},admin@coop{n}.example\n"));
+        }
+        csv.push_str("Coop 1,admin@again.example\n");
+        let (status, body, calls, _) = bulk_import(&csv).await?;
+
+        assert_eq!(status, StatusCode::OK);
+        let result: ImportResult = serde_json::from_value(body)?;
+        assert_eq!((result.total, result.succeeded, result.failed), (10, 9, 1));
+        assert_eq!(
+            result.errors,
+            vec![ImportRowError {
+                row: 11,
+                name: "Coop 1".to_string(),
+                message: "name: Same name as row 2".to_string(),
+            }]
+        );
+        assert_eq!(created_organizations(&calls).len(), 9);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bulk_import_reports_existing_name_and_continues() -> Result<(), Box<dyn std::error::Error>> {
+        let (status, body, calls, _) = bulk_import(
+            r#"[
+                {"name": "Green Coop", "admin_email": "admin@green.example", "categories": ["Environment"]},
+                {"name": "Taken Coop", "domain": "taken.example", "admin_email": "admin@taken.example"},
+                {"name": "Blue Coop", "admin_email": "admin@blue.example"}
+            ]"#,
+        )
+        .await?;
+
+        assert_eq!(status, StatusCode::OK);
+        let result: ImportResult = serde_json::from_value(body)?;
+        assert_eq!((result.total, result.succeeded, result.failed), (3, 2, 1));
+        assert_eq!(
+            result.errors,
+            vec![ImportRowError {
+                row: 2,
+                name: "Taken Coop".to_string(),
+                message: "Organization 'Taken Coop' already exists".to_string(),
+            }]
+        );
+        assert_eq!(created_organizations(&calls), vec!["Blue Coop", "Green Coop", "Taken Coop"]);
+        // No admin was invited to the organization that could not be created
+        assert_eq!(calls.iter().filter(|call| call.ends_with("/members/invite-user")).count(), 2);
+        Ok(())
+    }
+
+    async fn bulk_update_categories(
+        claims: Claims,
+        body: serde_json::Value,
+    ) -> Result<(StatusCode, serde_json::Value, Vec<String>), Box<dyn std::error::Error>> {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let keycloak_url = fake_keycloak(calls.clone()).await;
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let app_state = AppState::new(
+            keycloak_config(keycloak_url),
+            crate::common::state::AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+        let app = Router::new()
+            .route(
+                "/api/organizations/:org_id/members/categories/bulk",
+                axum::routing::put(bulk_update_member_categories),
+            )
+            .layer(Extension(claims))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri("/api/organizations/org-1/members/categories/bulk")
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(body.to_string()))?,
+            )
+            .await?;
+
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        let calls = calls.lock().unwrap().clone();
+        Ok((status, serde_json::from_slice(&body)?, calls))
+    }
+
+    fn org_admin() -> Claims {
+        org_claims("admin-user", &["org_admin"], "Org One", "org-1")
+    }
+
+    #[tokio::test]
+    async fn test_bulk_member_categories_updates_every_member() -> Result<(), Box<dyn std::error::Error>> {
+        let (status, body, calls) = bulk_update_categories(org_admin(), serde_json::json!({
+            "member-user": ["environment", "social"],
+            "second-member": [],
+        }))
+        .await?;
+
+        assert_eq!(status, StatusCode::OK);
+        let response: BulkMemberCategoryUpdateResponse = serde_json::from_value(body)?;
+        assert_eq!((response.updated, response.failed), (2, 0));
+        assert!(response.results.values().all(|result| result.success));
+
+        let mut updates: Vec<&String> = calls.iter().filter(|call| call.contains("categories=")).collect();
+        updates.sort();
+        assert_eq!(
+            updates,
+            vec![
+                &format!("{REALM_PATH}/users/member-user categories=[\"environment\",\"social\"]"),
+                &format!("{REALM_PATH}/users/second-member categories=[]"),
+            ]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bulk_member_categories_reports_invalid_member() -> Result<(), Box<dyn std::error::Error>> {
+        let (status, body, calls) = bulk_update_categories(org_admin(), serde_json::json!({
+            "member-user": ["environment"],
+            "unknown-user": ["environment"],
+        }))
+        .await?;
+
+        assert_eq!(status, StatusCode::OK);
+        let response: BulkMemberCategoryUpdateResponse = serde_json::from_value(body)?;
+        assert_eq!((response.updated, response.failed), (1, 1));
+        assert!(response.results["member-user"].success);
+        assert_eq!(
+            response.results["unknown-user"],
+            MemberCategoryUpdateResult {
+                success: false,
+                error: Some("Not a member of this organization".to_string()),
+            }
+        );
+        // Only the organization's member was touched
+        assert_eq!(calls.iter().filter(|call| call.contains("categories=")).count(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_bulk_member_categories_requires_managing_the_organization() -> Result<(), Box<dyn std::error::Error>> {
+        let body = serde_json::json!({ "member-user": ["environment"] });
+
+        let (status, _, calls) =
+            bulk_update_categories(org_claims("other-admin", &["org_admin"], "Org Two", "org-2"), body.clone()).await?;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        assert!(calls.is_empty());
+
+        let (status, body, _) = bulk_update_categories(claims("app-admin", &["application_admin"]), body).await?;
+        assert_eq!(status, StatusCode::OK);
+        let response: BulkMemberCategoryUpdateResponse = serde_json::from_value(body)?;
+        assert_eq!((response.updated, response.failed), (1, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_csv_handles_quotes() {
+        assert_eq!(
+            parse_csv("a,\"b, c\",\"say \"\"hi\"\"\"\r\nd,,e").unwrap(),
+            vec![vec!["a", "b, c", "say \"hi\""], vec!["d", "", "e"]]
+        );
+        assert!(parse_csv("a,\"b\n").is_err());
+    }
+
+    /// Keycloak without the single-invitation endpoint, holding one pending invitation
+    async fn fake_keycloak_invitations(calls: Arc<Mutex<Vec<String>>>) -> String {
+        let app = Router::new()
+            .route(
+                &format!("{REALM_PATH}/organizations/:org_id/members/invitations"),
+                get(|| async {
+                    Json(serde_json::json!([{
+                        "id": "inv-1",
+                        "email": "invitee@example.com",
+                        "invited_at": "2026-01-01T00:00:00Z",
+                        "expiration": "2026-01-08T00:00:00Z",
+                        "roles": ["org_user"],
+                    }]))
+                }),
+            )
+            .route(
+                &format!("{REALM_PATH}/organizations/:org_id/members/invitations/:invitation_id"),
+                axum::routing::delete({
+                    let calls = calls.clone();
+                    move |request: Request<Body>| async move {
+                        calls.lock().unwrap().push(format!("DELETE {}", request.uri().path()));
+                        StatusCode::NO_CONTENT
+                    }
+                }),
+            )
+            .route(
+                &format!("{REALM_PATH}/organizations/:org_id/members/invite-user"),
+                post({
+                    let calls = calls.clone();
+                    move |axum::extract::Form(form): axum::extract::Form<HashMap<String, String>>| async move {
+                        calls.lock().unwrap().push(format!("POST invite-user {} {}", form["email"], form["roles"]));
+                        StatusCode::NO_CONTENT
+                    }
+                }),
+            );
+
+        serve(app).await
+    }
+
+    async fn invitations_app(calls: Arc<Mutex<Vec<String>>>) -> Router {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let app_state = AppState::new(
+            keycloak_config(fake_keycloak_invitations(calls).await),
+            crate::common::state::AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+
+        Router::new()
+            .route("/api/organizations/:org_id/invitations/:invitation_id", get(get_invitation))
+            .route(
+                "/api/organizations/:org_id/invitations/:invitation_id/resend",
+                axum::routing::patch(resend_invitation),
+            )
+            .layer(Extension(claims("admin-user", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state)
+    }
+
+    #[tokio::test]
+    async fn test_get_invitation_falls_back_to_invitation_list() {
+        let app = invitations_app(Arc::default()).await;
+
+        let invitation = request_json(&app, "GET", "/api/organizations/org-1/invitations/inv-1").await;
+        assert_eq!(invitation["email"], "invitee@example.com");
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/organizations/org-1/invitations/inv-2")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_resend_invitation_replaces_it() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let app = invitations_app(calls.clone()).await;
+
+        let invitation = request_json(&app, "PATCH", "/api/organizations/org-1/invitations/inv-1/resend").await;
+
+        assert_eq!(invitation["email"], "invitee@example.com");
+        assert_eq!(invitation["roles"], serde_json::json!(["org_user"]));
+        assert_ne!(invitation["id"], "inv-1");
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [
+                format!("DELETE {REALM_PATH}/organizations/org-1/members/invitations/inv-1"),
+                "POST invite-user invitee@example.com org_user".to_string(),
+            ]
+        );
+    }
+}
@@ -1,15 +1,20 @@
 use axum::{
-    extract::{Path, Query, State},
+    extract::{Multipart, Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
-    Json,
+    Extension, Json,
 };
 use serde::Deserialize;
 use std::collections::HashMap;
 use uuid::Uuid;
 
+use crate::common::database::entity::questions::{DuplicateQuestionPolicy, QuestionImport};
+use crate::common::models::claims::Claims;
+use crate::common::services::skip_logic::{QuestionSkipRule, SkipLogicEngine, SkipRule};
+use crate::web::api::handlers::organizations::{parse_csv, read_import_file};
 use crate::web::routes::AppState;
 use crate::web::api::error::ApiError;
+use crate::web::api::strict_json::StrictJson;
 use crate::web::api::models::*;
 
 #[derive(Debug, Deserialize)]
@@ -71,6 +76,8 @@ pub async fn list_questions(
                 question_id: db_question.question_id,
                 category: category.name,
                 created_at: db_question.created_at.to_rfc3339(),
+                skip_rules: QuestionSkipRule::parse(&db_question.skip_rules),
+                sensitive: db_question.sensitive,
                 latest_revision: QuestionRevision {
                     question_revision_id: revision_model.question_revision_id,
                     question_id: revision_model.question_id,
@@ -97,7 +104,7 @@ pub async fn list_questions(
 )]
 pub async fn create_question(
     State(app_state): State<AppState>,
-    Json(request): Json<CreateQuestionRequest>,
+    StrictJson(request): StrictJson<CreateQuestionRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Validate request
     if request.text.is_empty() {
@@ -117,6 +124,16 @@ pub async fn create_question(
         .create_question(request.category_id)
         .await
         .map_err(|e| ApiError::InternalServerError(format!("Failed to create question: {e}")))?;
+    let question_model = if request.sensitive {
+        app_state
+            .database
+            .questions
+            .update_question(question_model.question_id, None, None, Some(true))
+            .await
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to create question: {e}")))?
+    } else {
+        question_model
+    };
 
     // Create the initial revision with the multilingual text and weight
     let text_json = serde_json::to_value(&request.text)
@@ -143,6 +160,8 @@ pub async fn create_question(
         question_id: question_model.question_id,
         category: category.name,
         created_at: question_model.created_at.to_rfc3339(),
+        skip_rules: QuestionSkipRule::parse(&question_model.skip_rules),
+        sensitive: question_model.sensitive,
         latest_revision: QuestionRevision {
             question_revision_id: revision_model.question_revision_id,
             question_id: revision_model.question_id,
@@ -249,6 +268,8 @@ pub async fn get_question(
         question_id: question_model.question_id,
         category: category.name,
         created_at: question_model.created_at.to_rfc3339(),
+        skip_rules: QuestionSkipRule::parse(&question_model.skip_rules),
+        sensitive: question_model.sensitive,
         latest_revision: QuestionRevision {
             question_revision_id: revision.question_revision_id,
             question_id: revision.question_id,
@@ -261,6 +282,46 @@ pub async fn get_question(
     Ok(Json(QuestionResponse { question }))
 }
 
+/// `rules` as stored for `question_id`, once they are known to refer to existing
+/// questions and not to lead back to `question_id`
+async fn validate_skip_rules(
+    app_state: &AppState,
+    question_id: Uuid,
+    rules: &[QuestionSkipRule],
+) -> Result<serde_json::Value, ApiError> {
+    let mut questions = app_state
+        .database
+        .questions
+        .get_all_questions()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch questions: {e}")))?;
+
+    if let Some(rule) = rules
+        .iter()
+        .find(|rule| !questions.iter().any(|question| question.question_id == rule.condition.question_id))
+    {
+        return Err(ApiError::BadRequest(format!(
+            "Skip rule refers to unknown question {}",
+            rule.condition.question_id
+        )));
+    }
+
+    let stored = serde_json::to_value(rules)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to serialize skip rules: {e}")))?;
+    for question in questions.iter_mut().filter(|question| question.question_id == question_id) {
+        question.skip_rules = stored.clone();
+    }
+    if let Some(cycle) = SkipLogicEngine::find_cycle(&SkipRule::from_questions(&questions)) {
+        let cycle: Vec<String> = cycle.iter().map(Uuid::to_string).collect();
+        return Err(ApiError::BadRequest(format!(
+            "Skip rules must not depend on each other in a circle: {}",
+            cycle.join(" -> ")
+        )));
+    }
+
+    Ok(stored)
+}
+
 /// Update a question (creates a new revision)
 #[utoipa::path(
     put,
@@ -273,7 +334,7 @@ pub async fn get_question(
 pub async fn update_question(
     State(app_state): State<AppState>,
     Path(question_id): Path<Uuid>,
-    Json(request): Json<UpdateQuestionRequest>,
+    StrictJson(request): StrictJson<UpdateQuestionRequest>,
 ) -> Result<Json<QuestionResponse>, ApiError> {
     // Validate request
     if request.text.is_empty() {
@@ -286,11 +347,16 @@ pub async fn update_question(
         ));
     }
 
+    let skip_rules = match &request.skip_rules {
+        Some(rules) => Some(validate_skip_rules(&app_state, question_id, rules).await?),
+        None => None,
+    };
+
     // Update the question category in the database
     let updated_question_model = app_state
         .database
         .questions
-        .update_question(question_id, Some(request.category_id))
+        .update_question(question_id, Some(request.category_id), skip_rules, request.sensitive)
         .await
         .map_err(|e| {
             if e.to_string().contains("Question not found") {
@@ -325,6 +391,8 @@ pub async fn update_question(
         question_id: updated_question_model.question_id,
         category: category.name,
         created_at: updated_question_model.created_at.to_rfc3339(),
+        skip_rules: QuestionSkipRule::parse(&updated_question_model.skip_rules),
+        sensitive: updated_question_model.sensitive,
         latest_revision: QuestionRevision {
             question_revision_id: revision_model.question_revision_id,
             question_id: revision_model.question_id,
@@ -386,3 +454,751 @@ pub async fn delete_question_revision_by_id(
     Ok(StatusCode::NO_CONTENT)
 
 }
+
+/// Show which assessments hold responses to a question, across all of its revisions
+#[utoipa::path(
+    get,
+    path = "/admin/questions/{question_id}/usage",
+    tag = "Admin",
+    params(("question_id" = uuid::Uuid, Path, description = "Question ID")),
+    responses(
+        (status = 200, description = "Question usage", body = QuestionUsage),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 404, description = "Question not found")
+    )
+)]
+pub async fn get_question_usage(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(question_id): Path<Uuid>,
+) -> Result<Json<QuestionUsage>, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can view question usage".to_string(),
+        ));
+    }
+
+    let question = app_state
+        .database
+        .questions
+        .get_question_by_id(question_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch question: {e}")))?;
+    if question.is_none() {
+        return Err(ApiError::NotFound("Question not found".to_string()));
+    }
+
+    let rows = app_state
+        .database
+        .questions
+        .usage_by_assessment(question_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch question usage: {e}")))?;
+
+    let total_responses = rows.iter().map(|row| row.response_count.max(0) as u64).sum();
+    let has_pending_submissions = rows.iter().any(|row| row.has_pending_submission != 0);
+    let assessments_using = rows
+        .into_iter()
+        .map(|row| AssessmentUsageSummary {
+            assessment_id: row.assessment_id,
+            org_name: row.org_name.unwrap_or_else(|| row.org_id.clone()),
+            org_id: row.org_id,
+            response_count: row.response_count.max(0) as u32,
+            last_responded_at: row.last_responded_at.map(|at| at.to_rfc3339()),
+        })
+        .collect();
+
+    Ok(Json(QuestionUsage {
+        question_id,
+        total_responses,
+        assessments_using,
+        has_pending_submissions,
+    }))
+}
+
+/// Columns of a question import CSV besides the `text_<language>` ones
+const QUESTION_IMPORT_COLUMNS: [&str; 3] = ["external_key", "category", "weight"];
+const MAX_QUESTION_IMPORT_ROWS: usize = 1000;
+
+#[derive(Debug, Deserialize)]
+pub struct QuestionImportQuery {
+    #[serde(default)]
+    on_duplicate: DuplicateQuestionPolicy,
+}
+
+/// A question as given in an import file, `category` being a catalog category name
+#[derive(Debug, Clone, Deserialize)]
+struct QuestionImportRow {
+    external_key: String,
+    category: String,
+    text: HashMap<String, String>,
+    weight: f64,
+}
+
+type QuestionImportRows = Vec<(usize, QuestionImportRow)>;
+
+/// A document written by `export_questions`
+#[derive(Debug, Deserialize)]
+struct QuestionImportDocument {
+    version: u32,
+    questions: Vec<QuestionImportRow>,
+}
+
+/// Read a JSON array of questions, a question export document or a CSV with the
+/// columns `external_key`, `category`, `weight` and one `text_<language>` column
+/// per language
+fn parse_question_import(text: &str) -> Result<(QuestionImportRows, Vec<ImportValidationError>), ApiError> {
+    let text = text.trim_start_matches('\u{feff}');
+    let json_rows = match text.trim_start().chars().next() {
+        Some('[') => Some(
+            serde_json::from_str::<Vec<QuestionImportRow>>(text)
+                .map_err(|e| ApiError::BadRequest(format!("Invalid JSON: {e}")))?,
+        ),
+        Some('{') => {
+            let document: QuestionImportDocument = serde_json::from_str(text)
+                .map_err(|e| ApiError::BadRequest(format!("Invalid JSON: {e}")))?;
+            if document.version != QUESTION_EXPORT_VERSION {
+                return Err(ApiError::BadRequest(format!(
+                    "Unsupported export version {}, expected {QUESTION_EXPORT_VERSION}",
+                    document.version
+                )));
+            }
+            Some(document.questions)
+        }
+        _ => None,
+    };
+    if let Some(rows) = json_rows {
+        return Ok((rows.into_iter().enumerate().map(|(index, row)| (index + 1, row)).collect(), Vec::new()));
+    }
+
+    let mut records = parse_csv(text).map_err(ApiError::BadRequest)?.into_iter().enumerate();
+    let (_, header) = records
+        .next()
+        .ok_or_else(|| ApiError::BadRequest("CSV file is empty".to_string()))?;
+
+    let mut columns = HashMap::new();
+    let mut languages = Vec::new();
+    for (index, name) in header.iter().enumerate() {
+        let name = name.trim().to_lowercase();
+        if let Some(language) = name.strip_prefix("text_").filter(|language| !language.is_empty()) {
+            languages.push((language.to_string(), index));
+        } else if !QUESTION_IMPORT_COLUMNS.contains(&name.as_str()) {
+            return Err(ApiError::BadRequest(format!("Unknown column '{name}'")));
+        }
+        if columns.insert(name.clone(), index).is_some() {
+            return Err(ApiError::BadRequest(format!("Duplicate column '{name}'")));
+        }
+    }
+    let missing: Vec<&str> = QUESTION_IMPORT_COLUMNS
+        .iter()
+        .copied()
+        .filter(|name| !columns.contains_key(*name))
+        .collect();
+    if !missing.is_empty() {
+        return Err(ApiError::BadRequest(format!("Missing columns: {}", missing.join(", "))));
+    }
+    if languages.is_empty() {
+        return Err(ApiError::BadRequest("At least one text_<language> column is required".to_string()));
+    }
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for (index, record) in records.filter(|(_, record)| record.iter().any(|cell| !cell.trim().is_empty())) {
+        let row = index + 1;
+        let cell = |index: usize| record.get(index).map(|cell| cell.trim().to_string()).unwrap_or_default();
+        let weight = cell(columns["weight"]);
+        let weight = weight.parse().unwrap_or_else(|_| {
+            errors.push(ImportValidationError {
+                row,
+                field: "weight".to_string(),
+                message: format!("Invalid weight '{weight}'"),
+            });
+            0.0
+        });
+        rows.push((
+            row,
+            QuestionImportRow {
+                external_key: cell(columns["external_key"]),
+                category: cell(columns["category"]),
+                text: languages
+                    .iter()
+                    .map(|(language, index)| (language.clone(), cell(*index)))
+                    .collect(),
+                weight,
+            },
+        ));
+    }
+    Ok((rows, errors))
+}
+
+/// Problems of every row, given the catalog's category IDs by name
+fn validate_question_import(
+    rows: &QuestionImportRows,
+    categories: &HashMap<String, Uuid>,
+) -> Vec<ImportValidationError> {
+    let mut errors = Vec::new();
+    let mut first_rows: HashMap<&str, usize> = HashMap::new();
+    for (row, question) in rows {
+        let mut error = |field: &str, message: String| {
+            errors.push(ImportValidationError {
+                row: *row,
+                field: field.to_string(),
+                message,
+            })
+        };
+
+        let key = question.external_key.trim();
+        if key.is_empty() {
+            error("external_key", "External key is required".to_string());
+        } else if let Some(first_row) = first_rows.get(key) {
+            error("external_key", format!("Same external key as row {first_row}"));
+        } else {
+            first_rows.insert(key, *row);
+        }
+        if !categories.contains_key(question.category.trim()) {
+            error("category", format!("Unknown category '{}'", question.category));
+        }
+        if question.text.values().all(|text| text.trim().is_empty()) {
+            error("text", "Text is required in at least one language".to_string());
+        }
+        if question.weight <= 0.0 {
+            error("weight", "Weight must be greater than 0".to_string());
+        }
+    }
+    errors
+}
+
+// POST /api/admin/questions/import
+/// Create or update questions from a CSV or JSON file (Application Admin only)
+///
+/// The multipart `file` field holds either a CSV with the columns `external_key`,
+/// `category`, `weight` and one `text_<language>` column per language, a JSON
+/// array of `{external_key, category, text, weight}` objects, or a document from
+/// `GET /admin/questions/export`. Questions are matched
+/// by external key; `on_duplicate=skip` leaves existing ones untouched. Nothing is
+/// imported when any row is invalid.
+#[utoipa::path(
+    post,
+    path = "/admin/questions/import",
+    tag = "Admin",
+    params(
+        ("on_duplicate" = Option<DuplicateQuestionPolicy>, Query, description = "update (default) or skip questions whose external key exists")
+    ),
+    responses(
+        (status = 200, description = "Import counts", body = QuestionImportResult),
+        (status = 400, description = "Missing file or unreadable CSV or JSON"),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 422, description = "Invalid rows, nothing was imported", body = ImportRejectedResponse)
+    )
+)]
+pub async fn import_questions(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<QuestionImportQuery>,
+    mut multipart: Multipart,
+) -> Result<axum::response::Response, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only application admins can import questions".to_string()));
+    }
+
+    let file = read_import_file(&mut multipart).await?;
+    let (rows, mut validation_errors) = parse_question_import(&file)?;
+    if rows.is_empty() {
+        return Err(ApiError::BadRequest("The file contains no questions".to_string()));
+    }
+    if rows.len() > MAX_QUESTION_IMPORT_ROWS {
+        return Err(ApiError::BadRequest(format!(
+            "At most {MAX_QUESTION_IMPORT_ROWS} questions can be imported at once"
+        )));
+    }
+
+    let categories: HashMap<String, Uuid> = app_state
+        .database
+        .category_catalog
+        .get_all_categories()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch categories: {e}")))?
+        .into_iter()
+        .map(|category| (category.name, category.category_catalog_id))
+        .collect();
+    validation_errors.extend(validate_question_import(&rows, &categories));
+    if !validation_errors.is_empty() {
+        validation_errors.sort_by_key(|error| error.row);
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ImportRejectedResponse {
+                error: "The file has invalid rows, nothing was imported".to_string(),
+                validation_errors,
+            }),
+        )
+            .into_response());
+    }
+
+    let imports: Vec<QuestionImport> = rows
+        .into_iter()
+        .map(|(_, row)| {
+            let text: HashMap<String, String> = row
+                .text
+                .into_iter()
+                .filter(|(_, text)| !text.trim().is_empty())
+                .collect();
+            QuestionImport {
+                external_key: row.external_key.trim().to_string(),
+                category_id: categories[row.category.trim()],
+                text: serde_json::json!(text),
+                weight: row.weight as f32,
+            }
+        })
+        .collect();
+
+    let counts = app_state
+        .database
+        .questions
+        .import_questions(&imports, query.on_duplicate)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to import questions: {e}")))?;
+
+    Ok(Json(QuestionImportResult {
+        created: counts.created,
+        updated: counts.updated,
+        skipped: counts.skipped,
+    })
+    .into_response())
+}
+
+// GET /api/admin/questions/export
+/// Export the question catalog (Application Admin only)
+///
+/// Every question with its latest revision, in the format the question import
+/// accepts, so that importing the document restores the catalog.
+#[utoipa::path(
+    get,
+    path = "/admin/questions/export",
+    tag = "Admin",
+    responses(
+        (status = 200, description = "Question catalog", body = QuestionExport),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn export_questions(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<Json<QuestionExport>, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only application admins can export questions".to_string()));
+    }
+
+    let categories: HashMap<Uuid, String> = app_state
+        .database
+        .category_catalog
+        .get_all_categories()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch categories: {e}")))?
+        .into_iter()
+        .map(|category| (category.category_catalog_id, category.name))
+        .collect();
+    let db_questions = app_state
+        .database
+        .questions
+        .get_all_questions()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch questions: {e}")))?;
+
+    let mut questions = Vec::new();
+    for db_question in db_questions {
+        let Some(revision) = app_state
+            .database
+            .questions_revisions
+            .get_latest_revision_by_question(db_question.question_id)
+            .await
+            .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch question revision: {e}")))?
+        else {
+            continue;
+        };
+        let category = categories
+            .get(&db_question.category_id)
+            .cloned()
+            .ok_or(ApiError::InternalServerError("Category not found for question".to_string()))?;
+
+        questions.push(ExportedQuestion {
+            external_key: db_question
+                .external_key
+                .unwrap_or_else(|| db_question.question_id.to_string()),
+            category,
+            text: revision
+                .text
+                .as_object()
+                .map(|text| {
+                    text.iter()
+                        .filter_map(|(language, text)| text.as_str().map(|text| (language.clone(), text.to_string())))
+                        .collect()
+                })
+                .unwrap_or_default(),
+            weight: revision.weight as f64,
+        });
+    }
+    questions.sort_by(|a, b| a.external_key.cmp(&b.external_key));
+
+    Ok(Json(QuestionExport {
+        version: QUESTION_EXPORT_VERSION,
+        exported_at: chrono::Utc::now().to_rfc3339(),
+        questions,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{claims, unreachable_keycloak};
+    use crate::common::database::entity::{
+        assessment_categories, assessments, assessments_response, assessments_submission, category_catalog,
+        questions, questions_revisions,
+    };
+    use crate::common::state::AppDatabase;
+    use axum::{
+        body::Body,
+        http::{header, Request},
+        routing::{get, post},
+        Router,
+    };
+    use sea_orm::{ColumnTrait, ConnectionTrait, Database, EntityTrait, QueryFilter, QueryOrder, Schema};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    async fn setup() -> Result<AppState, Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(questions::Entity),
+            schema.create_table_from_entity(questions_revisions::Entity),
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessment_categories::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(category_catalog::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        Ok(AppState::new(
+            unreachable_keycloak(),
+            AppDatabase::new(Arc::new(db)).await,
+        )
+        .await)
+    }
+
+    async fn create_question(app_state: &AppState) -> Result<(Uuid, Uuid), Box<dyn std::error::Error>> {
+        let question = app_state
+            .database
+            .questions
+            .create_question(Uuid::new_v4())
+            .await?;
+        let revision = app_state
+            .database
+            .questions_revisions
+            .create_question_revision(
+                question.question_id,
+                serde_json::json!({ "en": "Do you recycle?" }),
+                1.0,
+            )
+            .await?;
+        Ok((question.question_id, revision.question_revision_id))
+    }
+
+    async fn fetch_usage(
+        app_state: &AppState,
+        claims: Claims,
+        question_id: Uuid,
+    ) -> Result<(StatusCode, serde_json::Value), Box<dyn std::error::Error>> {
+        let app = Router::new()
+            .route("/api/admin/questions/:question_id/usage", get(get_question_usage))
+            .layer(Extension(claims))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state.clone());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/admin/questions/{question_id}/usage"))
+                    .body(Body::empty())?,
+            )
+            .await?;
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        let json = serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null);
+        Ok((status, json))
+    }
+
+    #[tokio::test]
+    async fn test_fresh_question_has_no_usage() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let (question_id, _) = create_question(&app_state).await?;
+
+        let (status, body) =
+            fetch_usage(&app_state, claims("admin-123", &["application_admin"]), question_id).await?;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["total_responses"], 0);
+        assert_eq!(body["assessments_using"], serde_json::json!([]));
+        assert_eq!(body["has_pending_submissions"], false);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_usage_groups_responses_by_assessment() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let (question_id, revision_id) = create_question(&app_state).await?;
+        let assessment = app_state
+            .database
+            .assessments
+            .create_assessment("org-1".to_string(), "en".to_string(), "Yearly review".to_string(), Vec::new())
+            .await?;
+        let assessment_id = assessment.assessment_id;
+        app_state
+            .database
+            .assessments_response
+            .update_response(assessment_id, revision_id, "Yes".to_string())
+            .await?;
+        app_state
+            .database
+            .assessments_submission
+            .create_submission(
+                assessment_id,
+                "org-1".to_string(),
+                "Green Coop".to_string(),
+                serde_json::json!({}),
+                None,
+            )
+            .await?;
+
+        let (status, body) =
+            fetch_usage(&app_state, claims("admin-123", &["application_admin"]), question_id).await?;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["total_responses"], 1);
+        assert_eq!(body["has_pending_submissions"], true);
+        let usage = &body["assessments_using"][0];
+        assert_eq!(usage["assessment_id"], assessment_id.to_string());
+        assert_eq!(usage["org_name"], "Green Coop");
+        assert_eq!(usage["response_count"], 1);
+        assert!(usage["last_responded_at"].is_string());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_usage_requires_application_admin() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let (question_id, _) = create_question(&app_state).await?;
+
+        let (status, _) = fetch_usage(&app_state, claims("admin-123", &["Org_User"]), question_id).await?;
+
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        Ok(())
+    }
+
+    async fn import(
+        app_state: &AppState,
+        query: &str,
+        file: &str,
+    ) -> Result<(StatusCode, serde_json::Value), Box<dyn std::error::Error>> {
+        let app = Router::new()
+            .route("/api/admin/questions/import", post(import_questions))
+            .layer(Extension(claims("admin-123", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state.clone());
+
+        let boundary = "test-boundary";
+        let body = format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"questions\"\r\n\r\n{file}\r\n--{boundary}--\r\n"
+        );
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/admin/questions/import{query}"))
+                    .header(header::CONTENT_TYPE, format!("multipart/form-data; boundary={boundary}"))
+                    .body(Body::from(body))?,
+            )
+            .await?;
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok((status, serde_json::from_slice(&body)?))
+    }
+
+    async fn create_catalog_category(app_state: &AppState, name: &str) -> Result<Uuid, Box<dyn std::error::Error>> {
+        let category = app_state
+            .database
+            .category_catalog
+            .create_category_catalog(Uuid::new_v4(), name.to_string(), None, "template".to_string(), true, None)
+            .await?;
+        Ok(category.category_catalog_id)
+    }
+
+    /// Revisions of the question imported under `external_key`, oldest first
+    async fn imported_revisions(
+        app_state: &AppState,
+        external_key: &str,
+    ) -> Result<(questions::Model, Vec<questions_revisions::Model>), Box<dyn std::error::Error>> {
+        let db = app_state.database.get_connection();
+        let question = questions::Entity::find()
+            .filter(questions::Column::ExternalKey.eq(external_key))
+            .one(db)
+            .await?
+            .ok_or("question not imported")?;
+        let revisions = questions_revisions::Entity::find()
+            .filter(questions_revisions::Column::QuestionId.eq(question.question_id))
+            .order_by_asc(questions_revisions::Column::CreatedAt)
+            .all(db)
+            .await?;
+        Ok((question, revisions))
+    }
+
+    #[tokio::test]
+    async fn test_import_creates_questions_from_csv() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let environment = create_catalog_category(&app_state, "Environment").await?;
+        let social = create_catalog_category(&app_state, "Social").await?;
+
+        let (status, body) = import(
+            &app_state,
+            "",
+            "external_key,category,weight,text_en,text_fr\r\n\
+             env-1,Environment,2,Do you recycle?,Recyclez-vous ?\r\n\
+             soc-1,Social,1.5,\"Do you train staff, yearly?\",\r\n",
+        )
+        .await?;
+
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body, serde_json::json!({ "created": 2, "updated": 0, "skipped": 0 }));
+
+        let (question, revisions) = imported_revisions(&app_state, "env-1").await?;
+        assert_eq!(question.category_id, environment);
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].text, serde_json::json!({ "en": "Do you recycle?", "fr": "Recyclez-vous ?" }));
+        assert_eq!(revisions[0].weight, 2.0);
+
+        // Blank translations are left out
+        let (question, revisions) = imported_revisions(&app_state, "soc-1").await?;
+        assert_eq!(question.category_id, social);
+        assert_eq!(revisions[0].text, serde_json::json!({ "en": "Do you train staff, yearly?" }));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reimport_updates_changed_questions() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        create_catalog_category(&app_state, "Environment").await?;
+        let questions = |weight: f64| {
+            serde_json::json!([
+                { "external_key": "env-1", "category": "Environment", "text": { "en": "Do you recycle?" }, "weight": weight },
+                { "external_key": "env-2", "category": "Environment", "text": { "en": "Do you compost?" }, "weight": 1.0 }
+            ])
+            .to_string()
+        };
+        let (status, _) = import(&app_state, "", &questions(1.0)).await?;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = import(&app_state, "?on_duplicate=skip", &questions(3.0)).await?;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body, serde_json::json!({ "created": 0, "updated": 0, "skipped": 2 }));
+
+        let (status, body) = import(&app_state, "", &questions(3.0)).await?;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body, serde_json::json!({ "created": 0, "updated": 1, "skipped": 1 }));
+
+        let (_, revisions) = imported_revisions(&app_state, "env-1").await?;
+        assert_eq!(revisions.iter().map(|revision| revision.weight).collect::<Vec<_>>(), [1.0, 3.0]);
+        let (_, revisions) = imported_revisions(&app_state, "env-2").await?;
+        assert_eq!(revisions.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_import_rejects_invalid_rows() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        create_catalog_category(&app_state, "Environment").await?;
+
+        let (status, body) = import(
+            &app_state,
+            "",
+            "external_key,category,weight,text_en\n\
+             env-1,Environment,1,Do you recycle?\n\
+             env-1,Unknown,0,\n",
+        )
+        .await?;
+
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let fields: Vec<&str> = body["validation_errors"]
+            .as_array()
+            .ok_or("no validation errors")?
+            .iter()
+            .filter_map(|error| error["field"].as_str())
+            .collect();
+        assert_eq!(fields, ["external_key", "category", "text", "weight"]);
+        assert!(questions::Entity::find()
+            .all(app_state.database.get_connection())
+            .await?
+            .is_empty());
+        Ok(())
+    }
+
+    async fn export(app_state: &AppState) -> Result<QuestionExport, Box<dyn std::error::Error>> {
+        let app = Router::new()
+            .route("/api/admin/questions/export", get(export_questions))
+            .layer(Extension(claims("admin-123", &["application_admin"])))
+            .with_state(app_state.clone());
+
+        let response = app
+            .oneshot(Request::builder().uri("/api/admin/questions/export").body(Body::empty())?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    #[tokio::test]
+    async fn test_export_then_import_reproduces_catalog() -> Result<(), Box<dyn std::error::Error>> {
+        let source = setup().await?;
+        create_catalog_category(&source, "Environment").await?;
+        let social = create_catalog_category(&source, "Social").await?;
+        let (status, _) = import(
+            &source,
+            "",
+            "external_key,category,weight,text_en,text_fr\n\
+             env-1,Environment,2,Do you recycle?,Recyclez-vous ?\n\
+             soc-1,Social,0.5,Do you train staff?,\n",
+        )
+        .await?;
+        assert_eq!(status, StatusCode::OK);
+        // A question created by hand has no external key
+        let keyless = source.database.questions.create_question(social).await?;
+        source
+            .database
+            .questions_revisions
+            .create_question_revision(keyless.question_id, serde_json::json!({ "en": "Do you pay fairly?" }), 1.0)
+            .await?;
+
+        let exported = export(&source).await?;
+        assert_eq!(exported.version, QUESTION_EXPORT_VERSION);
+        assert_eq!(exported.questions.len(), 3);
+        let document = serde_json::to_string(&exported)?;
+
+        // Into an empty catalog with the same categories
+        let target = setup().await?;
+        create_catalog_category(&target, "Environment").await?;
+        create_catalog_category(&target, "Social").await?;
+        let (status, body) = import(&target, "", &document).await?;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body, serde_json::json!({ "created": 3, "updated": 0, "skipped": 0 }));
+        assert_eq!(export(&target).await?.questions, exported.questions);
+
+        // Back into the catalog it came from, where nothing changed
+        let (status, body) = import(&source, "", &document).await?;
+        assert_eq!(status, StatusCode::OK, "{body}");
+        assert_eq!(body, serde_json::json!({ "created": 0, "updated": 0, "skipped": 3 }));
+        assert_eq!(export(&source).await?.questions, exported.questions);
+        Ok(())
+    }
+}
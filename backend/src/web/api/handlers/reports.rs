@@ -1,15 +1,24 @@
 use axum::{
-    extract::{Extension, Path, State},
-    http::StatusCode,
-    response::IntoResponse,
+    extract::{Extension, Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
+use sea_orm::DbErr;
 use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::common::database::entity::{assessments_submission, submission_reports};
+use crate::common::locale::select_localized_text;
+use crate::common::models::admin_event::AdminEvent;
 use crate::common::models::claims::Claims;
+use crate::common::services::pdf::PdfDocument;
+use crate::common::services::score_engine::{CategoryScores, ScoreEngine, ScoringRules, SectorPool};
 use crate::web::routes::AppState;
 use crate::web::api::error::ApiError;
+use crate::web::api::strict_json::StrictJson;
 use crate::web::api::models::*;
 
 
@@ -19,13 +28,26 @@ async fn generate_report_content(
     submission_id: Uuid,
     app_state: &AppState
 ) -> Result<Value, ApiError> {
-    let submission = app_state
+    let mut submission = app_state
         .database
         .assessments_submission
         .get_submission_by_assessment_id(submission_id)
         .await
         .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
         .ok_or_else(|| ApiError::NotFound("Submission not found".to_string()))?;
+    app_state
+        .database
+        .assessments_response
+        .decrypt_content(&mut submission.content)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decrypt responses: {e}")))?;
+
+    let language = submission.content
+        .get("assessment")
+        .and_then(|a| a.get("language"))
+        .and_then(|l| l.as_str())
+        .unwrap_or("en")
+        .to_string();
+    let fallbacks = &app_state.locale_config.language_fallback;
 
     let empty_responses = vec![];
     let responses = submission.content
@@ -49,6 +71,7 @@ async fn generate_report_content(
     }
 
     let mut categories: std::collections::HashMap<String, Vec<serde_json::Value>> = std::collections::HashMap::new();
+    let mut default_recommendations: std::collections::HashMap<String, Option<String>> = std::collections::HashMap::new();
     for response in responses {
         if let (Some(question_revision_id_str), Some(response_str)) = (
             response.get("question_revision_id").and_then(|q| q.as_str()),
@@ -58,9 +81,12 @@ async fn generate_report_content(
                 if let Ok(Some(revision)) = app_state.database.questions_revisions.get_revision_by_id(question_revision_id).await {
                     if let Ok(Some(question)) = app_state.database.questions.get_question_by_id(revision.question_id).await {
                         if let Ok(Some(category_model)) = app_state.database.category_catalog.get_category_catalog_by_id(question.category_id).await {
-                            let question_text = revision.text.get("en").and_then(|t| t.as_str()).unwrap_or("Unknown question");
+                            let question_text = select_localized_text(&revision.text, &language, fallbacks).unwrap_or("Unknown question");
                             let answer = serde_json::from_str(response_str).unwrap_or(json!({ "text": response_str }));
-                            
+
+                            default_recommendations
+                                .entry(category_model.name.clone())
+                                .or_insert_with(|| category_model.default_recommendation_for(&language, fallbacks));
                             categories.entry(category_model.name)
                                 .or_default()
                                 .push(json!({ "question": question_text, "answer": answer }));
@@ -74,8 +100,7 @@ async fn generate_report_content(
     let mut result_object = serde_json::Map::new();
     for (category, questions) in categories {
         let category_recommendations = recommendations.remove(&category).unwrap_or_else(|| {
-            let default_id = Uuid::new_v5(&Uuid::NAMESPACE_DNS, format!("{}-{}", category, "No recommendation provided").as_bytes());
-            vec![json!({"id": default_id.to_string(), "text": "No recommendation provided", "status": "todo"})]
+            vec![placeholder_recommendation(&category, default_recommendations.remove(&category).flatten())]
         });
 
         result_object.insert(category, json!({
@@ -87,6 +112,50 @@ async fn generate_report_content(
     Ok(json!([result_object]))
 }
 
+/// Recommendation of a category nobody wrote one for: the category's configured default
+/// if it has one, otherwise a generic placeholder
+fn placeholder_recommendation(category: &str, default_text: Option<String>) -> Value {
+    let default_text = default_text.unwrap_or_else(|| "No recommendation provided".to_string());
+    let default_id = Uuid::new_v5(&Uuid::NAMESPACE_DNS, format!("{}-{}", category, default_text).as_bytes());
+    json!({"id": default_id.to_string(), "text": default_text, "status": "todo"})
+}
+
+/// Whether `recommendation` is the generic placeholder of `category`, which a
+/// reviewer's recommendation takes the place of
+fn is_generic_placeholder(category: &str, recommendation: &Value) -> bool {
+    recommendation.get("id") == placeholder_recommendation(category, None).get("id")
+}
+
+/// Keeps the recommendations reviewers added to `previous` in the categories `data`
+/// still has, see `add_report_recommendation`
+fn carry_over_added_recommendations(data: &mut Value, previous: &Value) {
+    let Some(previous) = previous.get(0).and_then(Value::as_object) else {
+        return;
+    };
+    for (category, previous_category) in previous {
+        let Some(recommendations) = data
+            .get_mut(0)
+            .and_then(|categories| categories.get_mut(category))
+            .and_then(|category| category.get_mut("recommendations"))
+            .and_then(Value::as_array_mut)
+        else {
+            continue;
+        };
+        let added = previous_category
+            .get("recommendations")
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter(|rec| rec.get("added_by_reviewer").and_then(Value::as_bool) == Some(true));
+        for rec in added {
+            if !recommendations.iter().any(|existing| existing.get("id") == rec.get("id")) {
+                recommendations.retain(|existing| !is_generic_placeholder(category, existing));
+                recommendations.push(rec.clone());
+            }
+        }
+    }
+}
+
 
 
 /// List all reports for the authenticated organization
@@ -96,7 +165,7 @@ async fn generate_report_content(
     get,
     path = "/user/reports",
     tag = "Report",
-    responses((status = 200, description = "Reports", body = ReportListResponse))
+    responses((status = 200, description = "Reports", body = ReportListResponse), (status = 403, description = "Not allowed to view reports"))
 )]
 pub async fn list_user_reports(
     State(app_state): State<AppState>,
@@ -104,6 +173,9 @@ pub async fn list_user_reports(
 ) -> Result<impl IntoResponse, ApiError> {
     let org_id = claims.get_org_id()
         .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
+    if !can_view_organization_reports(&claims, &org_id) {
+        return Err(ApiError::Forbidden("Only org_admin and Org_Expert roles can view reports".to_string()));
+    }
 
     // Get all submissions for the organization
     let org_submissions = app_state
@@ -158,10 +230,11 @@ pub async fn list_user_reports(
     path = "/submissions/{submission_id}/reports",
     tag = "Report",
     params(("submission_id" = uuid::Uuid, Path, description = "Submission ID")),
-    responses((status = 200, description = "Reports for submission", body = ReportListResponse), (status = 404, description = "Submission not found"))
+    responses((status = 200, description = "Reports for submission", body = ReportListResponse), (status = 403, description = "Not allowed to view these reports"), (status = 404, description = "Submission not found"))
 )]
 pub async fn list_reports(
     State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
     Path(submission_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Check if submission exists
@@ -172,6 +245,9 @@ pub async fn list_reports(
         .await
         .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
         .ok_or_else(|| ApiError::NotFound("Submission not found".to_string()))?;
+    if !can_view_organization_reports(&claims, &submission.org_id) {
+        return Err(ApiError::Forbidden("You don't have access to these reports".to_string()));
+    }
 
     // Get reports for the submission
     let report_models = app_state
@@ -204,64 +280,379 @@ pub async fn list_reports(
     Ok(Json(ReportListResponse { reports }))
 }
 
+/// Let the submitting organization's admins know their report is ready.
+///
+/// The report has already been stored at this point, so failures are logged rather
+/// than turned into an error response.
+async fn notify_org_admins_of_report(
+    app_state: &AppState,
+    token: &str,
+    submission: &crate::common::database::entity::assessments_submission::Model,
+) {
+    let admins = match app_state
+        .keycloak_service
+        .get_organization_members_by_role(token, &submission.org_id, "org_admin")
+        .await
+    {
+        Ok(admins) => admins,
+        Err(e) => {
+            tracing::warn!(org_id = %submission.org_id, error = %e, "Failed to fetch org admins for report notification");
+            return;
+        }
+    };
+
+    let admin_ids: Vec<String> = admins.into_iter().map(|admin| admin.id).collect();
+    let assessment_name = submission.content
+        .get("assessment_name")
+        .and_then(|n| n.as_str())
+        .unwrap_or("Unknown Assessment");
+
+    if let Err(e) = app_state
+        .database
+        .notifications
+        .notify_report_generated(&submission.org_id, &admin_ids, assessment_name)
+        .await
+    {
+        tracing::warn!(org_id = %submission.org_id, error = %e, "Failed to create report notifications");
+    }
+}
+
 /// Generate a new report for a submission
 /// POST /submissions/{submission_id}/reports
-/// Generate a new report for a submission
+///
+/// Generating a report leaves the submission's review status alone so admins can
+/// produce several drafts; pass `?mark_reviewed=true` to also finish the review.
+/// With `?background=true` the answer is 202 as soon as the report exists, and
+/// `GET /reports/{report_id}/generation-progress` tells when it is ready.
 #[utoipa::path(
     post,
     path = "/submissions/{submission_id}/reports",
     tag = "Report",
-    params(("submission_id" = uuid::Uuid, Path, description = "Submission ID")),
+    params(
+        ("submission_id" = uuid::Uuid, Path, description = "Submission ID"),
+        ("mark_reviewed" = Option<bool>, Query, description = "Also mark the submission as reviewed"),
+        ("background" = Option<bool>, Query, description = "Generate the report in the background")
+    ),
     request_body = Vec<GenerateReportRequest>,
-    responses((status = 201, description = "Report generation started", body = ReportGenerationResponse), (status = 404, description = "Submission not found"))
+    responses(
+        (status = 201, description = "Report generated", body = ReportGenerationResponse),
+        (status = 202, description = "Report generation started", body = ReportGenerationResponse),
+        (status = 403, description = "Submission of another organization, or marking reviewed without being a DGRV admin"),
+        (status = 404, description = "Submission not found")
+    )
 )]
 pub async fn generate_report(
     State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
     Path(submission_id): Path<Uuid>,
-    Json(request): Json<Vec<GenerateReportRequest>>,
+    Query(query): Query<GenerateReportQuery>,
+    StrictJson(request): StrictJson<Vec<GenerateReportRequest>>,
 ) -> Result<impl IntoResponse, ApiError> {
     // Check if submission exists
-    let _submission = app_state
+    let submission = app_state
         .database
         .assessments_submission
         .get_submission_by_assessment_id(submission_id)
         .await
         .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
         .ok_or_else(|| ApiError::NotFound("Submission not found".to_string()))?;
-
-    // Generate the actual report content using the provided data
-    let report_content = generate_report_content(&request, submission_id, &app_state).await?;
+    if !can_access_organization(&claims, &submission.org_id) {
+        return Err(ApiError::Forbidden("You don't have access to this submission".to_string()));
+    }
+    if query.mark_reviewed && !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only DGRV admins can mark submissions as reviewed".to_string()));
+    }
 
     // Create the report with initial "generating" status
-    let mut report_model = app_state
+    let report_model = app_state
         .database
         .submission_reports
         .create_report(submission_id, None)
         .await
         .map_err(|e| ApiError::InternalServerError(format!("Failed to create report: {e}")))?;
+    let report_id = report_model.report_id;
+    app_state.report_progress.start(report_id, &submission.org_id);
+
+    if query.background {
+        tokio::spawn(async move {
+            // Failures are recorded in the progress and on the report
+            let _ = run_report_generation(
+                &app_state,
+                &claims,
+                &token,
+                report_id,
+                submission_id,
+                &request,
+                query.mark_reviewed,
+            )
+            .await;
+        });
+        return Ok((
+            StatusCode::ACCEPTED,
+            Json(ReportGenerationResponse {
+                report_id,
+                status: report_model.status,
+            }),
+        ));
+    }
+
+    let report_model = run_report_generation(
+        &app_state,
+        &claims,
+        &token,
+        report_id,
+        submission_id,
+        &request,
+        query.mark_reviewed,
+    )
+    .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ReportGenerationResponse {
+            report_id,
+            status: report_model.status,
+        }),
+    ))
+}
+
+/// Fill in the report created by `generate_report`, tracking progress in
+/// `AppState::report_progress`. A report that fails before it is saved is marked "failed".
+async fn run_report_generation(
+    app_state: &AppState,
+    claims: &Claims,
+    token: &str,
+    report_id: Uuid,
+    submission_id: Uuid,
+    request: &Vec<GenerateReportRequest>,
+    mark_reviewed: bool,
+) -> Result<submission_reports::Model, ApiError> {
+    let result =
+        generate_report_steps(app_state, claims, token, report_id, submission_id, request, mark_reviewed).await;
+
+    if let Err(e) = &result {
+        tracing::error!(%report_id, error = e.message(), "Report generation failed");
+        app_state.report_progress.fail(report_id, e.message().to_string());
+
+        let reports = &app_state.database.submission_reports;
+        let still_generating = matches!(
+            reports.get_report_by_id(report_id).await,
+            Ok(Some(report)) if report.status == "generating"
+        );
+        if still_generating {
+            if let Err(e) = reports.update_report_status(report_id, "failed".to_string(), None).await {
+                tracing::warn!(%report_id, error = %e, "Failed to mark report as failed");
+            }
+        }
+    }
+    result
+}
+
+async fn generate_report_steps(
+    app_state: &AppState,
+    claims: &Claims,
+    token: &str,
+    report_id: Uuid,
+    submission_id: Uuid,
+    request: &Vec<GenerateReportRequest>,
+    mark_reviewed_too: bool,
+) -> Result<submission_reports::Model, ApiError> {
+    use crate::common::services::report_progress::ReportStep;
+
+    let progress = &app_state.report_progress;
+
+    progress.step(report_id, ReportStep::LoadingSubmission);
+    let submission = app_state
+        .database
+        .assessments_submission
+        .get_submission_by_assessment_id(submission_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Submission not found".to_string()))?;
 
+    // Recommendation ids are deterministic, so progress on the latest earlier report
+    // carries over instead of resetting to "todo". Reports still generating or that
+    // failed have no content to carry over, so they are skipped.
+    let previous_data = app_state
+        .database
+        .submission_reports
+        .get_reports_by_submission(submission_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch previous reports: {e}")))?
+        .into_iter()
+        .filter(|report| report.report_id != report_id && report.data.is_some())
+        .max_by_key(|report| report.generated_at)
+        .and_then(|report| report.data);
+
+    progress.step(report_id, ReportStep::ResolvingQuestions);
+    let mut report_content = generate_report_content(request, submission_id, app_state).await?;
+
+    progress.step(report_id, ReportStep::ComputingScores);
+    let scores = ScoreEngine::category_scores(&report_content, &scoring_rules(app_state).await?);
+    tracing::debug!(%report_id, scored_categories = scores.len(), "Report scored");
+
+    progress.step(report_id, ReportStep::FormattingOutput);
+    if let Some(previous) = previous_data {
+        submission_reports::carry_over_recommendation_statuses(&mut report_content, &previous);
+    }
+
+    progress.step(report_id, ReportStep::SavingReport);
     // Update the report with the generated content and "completed" status
-    report_model = app_state
+    let report_model = app_state
         .database
         .submission_reports
-        .update_report_status(report_model.report_id, "completed".to_string(), Some(report_content))
+        .update_report_status(report_id, "completed".to_string(), Some(report_content))
         .await
         .map_err(|e| ApiError::InternalServerError(format!("Failed to update report with content: {e}")))?;
 
-    // Update the assessment submission status to "reviewed" after successful report generation
+    if mark_reviewed_too {
+        mark_reviewed(app_state, claims, submission_id).await?;
+    }
+
+    notify_org_admins_of_report(app_state, token, &submission).await;
+    app_state.publish_admin_event(AdminEvent::ReportGenerated {
+        report_id,
+        org_id: submission.org_id.clone(),
+    });
+
+    progress.complete(report_id);
+    Ok(report_model)
+}
+
+/// How far the generation of a report has come
+/// GET /reports/{report_id}/generation-progress
+///
+/// Known while the report is generated and for ten minutes after it finished.
+#[utoipa::path(
+    get,
+    path = "/reports/{report_id}/generation-progress",
+    tag = "Report",
+    params(("report_id" = uuid::Uuid, Path, description = "Report ID")),
+    responses(
+        (status = 200, description = "Generation progress", body = GenerationProgress),
+        (status = 403, description = "Report of another organization"),
+        (status = 404, description = "No generation known for this report")
+    )
+)]
+pub async fn get_report_generation_progress(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(report_id): Path<Uuid>,
+) -> Result<Json<GenerationProgress>, ApiError> {
+    use crate::common::services::report_progress::ReportStep;
+
+    let entry = app_state
+        .report_progress
+        .get(report_id)
+        .ok_or_else(|| ApiError::NotFound("No generation known for this report".to_string()))?;
+    if !can_access_organization(&claims, &entry.org_id) {
+        return Err(ApiError::Forbidden("You don't have access to this report".to_string()));
+    }
+
+    Ok(Json(GenerationProgress {
+        report_id,
+        status: entry.status,
+        steps_completed: entry.steps_completed,
+        total_steps: ReportStep::ALL.len() as u32,
+        current_step: entry.current_step.as_str().to_string(),
+        error: entry.error.clone(),
+        estimated_completion_secs: app_state.report_progress.estimated_remaining_secs(&entry),
+    }))
+}
+
+async fn mark_reviewed(
+    app_state: &AppState,
+    claims: &Claims,
+    submission_id: Uuid,
+) -> Result<crate::common::database::entity::assessments_submission::Model, ApiError> {
     app_state
         .database
         .assessments_submission
-        .update_submission_status(submission_id, crate::common::database::entity::assessments_submission::SubmissionStatus::Reviewed)
+        .update_submission_status(
+            submission_id,
+            crate::common::database::entity::assessments_submission::SubmissionStatus::Reviewed,
+            &crate::common::database::entity::submission_timeline::TimelineActor::from_claims(claims),
+        )
         .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to update submission status: {e}")))?;
+        .map_err(|e| match e {
+            DbErr::RecordNotFound(_) => ApiError::NotFound("Submission not found".to_string()),
+            e => ApiError::InternalServerError(format!("Failed to update submission status: {e}")),
+        })
+}
 
-    let response = ReportGenerationResponse {
-        report_id: report_model.report_id,
-        status: report_model.status,
+/// Mark a submission as reviewed once its reports are final
+/// POST /submissions/{submission_id}/review
+#[utoipa::path(
+    post,
+    path = "/submissions/{submission_id}/review",
+    tag = "Report",
+    params(("submission_id" = uuid::Uuid, Path, description = "Submission ID")),
+    responses((status = 200, description = "Submission marked as reviewed", body = SubmissionDetailResponse), (status = 403, description = "Forbidden"), (status = 404, description = "Submission not found"))
+)]
+pub async fn review_submission(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(submission_id): Path<Uuid>,
+) -> Result<Json<SubmissionDetailResponse>, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only DGRV admins can mark submissions as reviewed".to_string()));
+    }
+
+    let submission_model = mark_reviewed(&app_state, &claims, submission_id).await?;
+
+    let assessment_name = submission_model.content
+        .get("assessment_name")
+        .and_then(|n| n.as_str())
+        .unwrap_or("Unknown Assessment")
+        .to_string();
+
+    let submission = Submission {
+        submission_id: submission_model.submission_id,
+        org_id: submission_model.org_id,
+        assessment_name,
+        content: submission_model.content,
+        submitted_at: submission_model.submitted_at.to_rfc3339(),
+        review_status: submission_model.status.to_string(),
+        reviewed_at: submission_model.reviewed_at.map(|dt| dt.to_rfc3339()),
     };
 
-    Ok((StatusCode::CREATED, Json(response)))
+    Ok(Json(SubmissionDetailResponse { submission }))
+}
+
+/// Preview the report a submission would get, without saving it
+/// POST /submissions/{submission_id}/reports/preview
+///
+/// Unlike `generate_report` no report row is created and the submission is not
+/// marked as reviewed.
+#[utoipa::path(
+    post,
+    path = "/submissions/{submission_id}/reports/preview",
+    tag = "Report",
+    params(("submission_id" = uuid::Uuid, Path, description = "Submission ID")),
+    request_body = Vec<GenerateReportRequest>,
+    responses((status = 200, description = "Report content that would be generated", body = ReportPreviewResponse), (status = 403, description = "Submission of another organization"), (status = 404, description = "Submission not found"))
+)]
+pub async fn preview_report(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(submission_id): Path<Uuid>,
+    StrictJson(request): StrictJson<Vec<GenerateReportRequest>>,
+) -> Result<Json<ReportPreviewResponse>, ApiError> {
+    let submission = app_state
+        .database
+        .assessments_submission
+        .get_submission_by_assessment_id(submission_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Submission not found".to_string()))?;
+    if !can_access_organization(&claims, &submission.org_id) {
+        return Err(ApiError::Forbidden("You don't have access to this submission".to_string()));
+    }
+
+    let data = generate_report_content(&request, submission_id, &app_state).await?;
+
+    Ok(Json(ReportPreviewResponse { submission_id, data }))
 }
 
 /// Get a specific report
@@ -272,10 +663,11 @@ pub async fn generate_report(
     path = "/reports/{report_id}",
     tag = "Report",
     params(("report_id" = uuid::Uuid, Path, description = "Report ID")),
-    responses((status = 200, description = "Report detail", body = ReportResponse), (status = 404, description = "Not found"))
+    responses((status = 200, description = "Report detail", body = ReportResponse), (status = 403, description = "Not allowed to view this report"), (status = 404, description = "Not found"))
 )]
 pub async fn get_report(
     State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
     Path(report_id): Path<Uuid>,
 ) -> Result<impl IntoResponse, ApiError> {
     let report_model = app_state
@@ -293,6 +685,9 @@ pub async fn get_report(
         .await
         .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
         .ok_or_else(|| ApiError::NotFound("Submission not found for this report".to_string()))?;
+    if !can_view_organization_reports(&claims, &submission.org_id) {
+        return Err(ApiError::Forbidden("You don't have access to this report".to_string()));
+    }
 
     let assessment_name = submission.content
         .get("assessment_name")
@@ -313,22 +708,108 @@ pub async fn get_report(
     Ok(Json(ReportResponse { report }))
 }
 
-/// Delete a report
-/// DELETE /reports/{report_id}
-/// Delete a report
+/// Fewer organizations than this in the comparison pool and no benchmark is given
+const BENCHMARK_MIN_ORGANIZATIONS: usize = 5;
+/// How far back reports are taken into the comparison pool
+const BENCHMARK_WINDOW_DAYS: i64 = 365;
+
+// Read access to an organization's data, by organization id
+fn can_access_organization(claims: &Claims, org_id: &str) -> bool {
+    claims.is_application_admin()
+        || claims
+            .organizations
+            .as_ref()
+            .map(|orgs| orgs.orgs.values().any(|info| info.id.as_deref() == Some(org_id)))
+            .unwrap_or(false)
+}
+
+// Reports are read by application admins and by the organization's org_admins and Org_Experts
+fn can_view_organization_reports(claims: &Claims, org_id: &str) -> bool {
+    claims.is_application_admin() || (claims.can_view_reports() && can_access_organization(claims, org_id))
+}
+
+/// Scoring mode and threshold of every catalog category
+async fn scoring_rules(app_state: &AppState) -> Result<ScoringRules, ApiError> {
+    let categories = app_state
+        .database
+        .category_catalog
+        .get_all_categories()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch categories: {e}")))?;
+    Ok(ScoreEngine::scoring_rules(&categories))
+}
+
+/// Latest report of every organization from the last year, scored by category
+async fn sector_pool(app_state: &AppState) -> Result<Arc<SectorPool>, ApiError> {
+    if let Some(pool) = app_state.sector_pool_cache.get() {
+        return Ok(pool);
+    }
+
+    let since = chrono::Utc::now() - chrono::Duration::days(BENCHMARK_WINDOW_DAYS);
+    let reports = app_state
+        .database
+        .submission_reports
+        .get_reports_with_submissions_since(since)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch reports: {e}")))?;
+
+    let rules = scoring_rules(app_state).await?;
+    let pool = Arc::new(SectorPool {
+        scores_by_org: latest_scores_by_org(reports, &rules),
+    });
+    app_state.sector_pool_cache.replace(pool.clone());
+    Ok(pool)
+}
+
+/// Category scores of each organization's latest report with data
+fn latest_scores_by_org(
+    reports: Vec<(submission_reports::Model, Option<assessments_submission::Model>)>,
+    rules: &ScoringRules,
+) -> std::collections::BTreeMap<String, CategoryScores> {
+    let mut latest: HashMap<String, submission_reports::Model> = HashMap::new();
+    for (report, submission) in reports {
+        let Some(submission) = submission else { continue };
+        if report.data.is_none() {
+            continue;
+        }
+        match latest.get(&submission.org_id) {
+            Some(current) if current.generated_at >= report.generated_at => {}
+            _ => {
+                latest.insert(submission.org_id, report);
+            }
+        }
+    }
+
+    latest
+        .into_iter()
+        .map(|(org_id, report)| (org_id, ScoreEngine::category_scores(report.data.as_ref().unwrap_or(&Value::Null), rules)))
+        .collect()
+}
+
+/// Compare a report's category scores with the other organizations' latest reports
+/// GET /reports/{report_id}/benchmark
+///
+/// Sector averages cover the latest report of each organization from the last 12
+/// months and are cached for an hour. With fewer than 5 organizations in that pool
+/// the response is `{ "insufficient_data": true }`, and categories answered by fewer
+/// than 5 organizations are left out of the averages and percentiles.
 #[utoipa::path(
-    delete,
-    path = "/reports/{report_id}",
+    get,
+    path = "/reports/{report_id}/benchmark",
     tag = "Report",
     params(("report_id" = uuid::Uuid, Path, description = "Report ID")),
-    responses((status = 204, description = "Deleted"), (status = 404, description = "Not found"))
+    responses(
+        (status = 200, description = "Benchmark, or `{ \"insufficient_data\": true }`", body = BenchmarkResponse),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found")
+    )
 )]
-pub async fn delete_report(
+pub async fn get_report_benchmark(
     State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
     Path(report_id): Path<Uuid>,
-) -> Result<impl IntoResponse, ApiError> {
-    // Check if report exists
-    let _report = app_state
+) -> Result<Response, ApiError> {
+    let report = app_state
         .database
         .submission_reports
         .get_report_by_id(report_id)
@@ -336,69 +817,642 @@ pub async fn delete_report(
         .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch report: {e}")))?
         .ok_or_else(|| ApiError::NotFound("Report not found".to_string()))?;
 
-    // Delete the report
-    app_state
+    let submission = app_state
         .database
-        .submission_reports
-        .delete_report(report_id)
+        .assessments_submission
+        .get_submission_by_assessment_id(report.submission_id)
         .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to delete report: {e}")))?;
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Submission not found for this report".to_string()))?;
 
-    Ok(StatusCode::NO_CONTENT)
+    if !can_access_organization(&claims, &submission.org_id) {
+        return Err(ApiError::Forbidden("You don't have access to this report".to_string()));
+    }
+
+    let pool = sector_pool(&app_state).await?;
+    if pool.org_count() < BENCHMARK_MIN_ORGANIZATIONS {
+        return Ok(Json(json!({ "insufficient_data": true })).into_response());
+    }
+
+    let rules = scoring_rules(&app_state).await?;
+    let org_scores = ScoreEngine::category_scores(report.data.as_ref().unwrap_or(&Value::Null), &rules);
+    let sector_averages = pool.averages(BENCHMARK_MIN_ORGANIZATIONS);
+    let percentile_ranks = org_scores
+        .iter()
+        .filter(|(category, _)| sector_averages.contains_key(*category))
+        .map(|(category, score)| CategoryPercentile {
+            category: category.clone(),
+            percentile: ScoreEngine::percentile_rank(*score, &pool.category_pool(category)),
+        })
+        .collect();
+
+    let to_scores = |scores: CategoryScores| {
+        scores
+            .into_iter()
+            .map(|(category, score)| CategoryScore { category, score })
+            .collect()
+    };
+    Ok(Json(BenchmarkResponse {
+        org_scores: to_scores(org_scores),
+        sector_averages: to_scores(sector_averages),
+        percentile_ranks,
+    })
+    .into_response())
 }
 
-/// Get all action plans for all organizations (DGRV admin view)
-/// GET /admin/action-plans
-/// Get all action plans for all organizations (DGRV admin view)
+/// Average category scores over an organization's completed reports
+/// GET /organizations/{org_id}/statistics
+///
+/// Each category is averaged over the reports that cover it, scored like the
+/// benchmark.
 #[utoipa::path(
     get,
-    path = "/admin/action-plans",
+    path = "/organizations/{org_id}/statistics",
     tag = "Report",
-    responses((status = 200, description = "All action plans", body = ActionPlanListResponse))
+    params(("org_id" = String, Path, description = "Organization ID")),
+    responses(
+        (status = 200, description = "Category averages", body = OrganizationStatisticsResponse),
+        (status = 403, description = "Forbidden")
+    )
 )]
-pub async fn list_all_action_plans(
+pub async fn get_organization_statistics(
     State(app_state): State<AppState>,
-) -> Result<impl IntoResponse, ApiError> {
-    // Get all submissions from the database
-    let all_submissions = app_state
+    Extension(claims): Extension<Claims>,
+    Path(org_id): Path<String>,
+) -> Result<Json<OrganizationStatisticsResponse>, ApiError> {
+    if !can_access_organization(&claims, &org_id) {
+        return Err(ApiError::Forbidden("You don't have access to this organization".to_string()));
+    }
+
+    let reports = app_state
         .database
-        .assessments_submission
-        .get_all_submissions()
+        .submission_reports
+        .get_reports_by_org_and_status(&org_id, "completed")
         .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch all submissions: {e}")))?;
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch reports: {e}")))?;
 
-    // Group submissions by organization
-    let mut submissions_by_org: std::collections::HashMap<String, Vec<_>> = std::collections::HashMap::new();
-    for submission in all_submissions {
-        submissions_by_org.entry(submission.org_id.clone()).or_default().push(submission);
+    let rules = scoring_rules(&app_state).await?;
+    let mut totals: std::collections::BTreeMap<String, (f64, usize)> = std::collections::BTreeMap::new();
+    for report in &reports {
+        let scores = ScoreEngine::category_scores(report.data.as_ref().unwrap_or(&Value::Null), &rules);
+        for (category, score) in scores {
+            let (sum, count) = totals.entry(category).or_default();
+            *sum += score;
+            *count += 1;
+        }
     }
 
-    let mut action_plans = Vec::new();
+    Ok(Json(OrganizationStatisticsResponse {
+        org_id,
+        report_count: reports.len(),
+        categories: totals
+            .into_iter()
+            .map(|(category, (sum, count))| CategoryStatistics {
+                category,
+                average_score: sum / count as f64,
+                report_count: count,
+            })
+            .collect(),
+    }))
+}
 
-    for (org_id, submissions) in submissions_by_org {
-        let mut org_recommendations = Vec::new();
-        let mut org_name = "Unknown Organization".to_string();
+/// Keycloak attribute an organization sets to "true" to be listed in the comparison matrix
+const BENCHMARK_SHARING_ATTRIBUTE: &str = "data_sharing_for_benchmarking";
 
-        for submission in submissions {
-            // Update org_name from the first submission
-            if org_name == "Unknown Organization" {
-                org_name = submission.org_name.clone();
-            }
+/// Category scores of the organizations sharing their data, side by side
+/// GET /admin/reports/comparison-matrix
+///
+/// Each organization is scored by its latest report generated in the period, like the
+/// benchmark. Only organizations whose `data_sharing_for_benchmarking` attribute is
+/// "true" are listed; those without a report in the period get a row of `null`s.
+#[utoipa::path(
+    get,
+    path = "/admin/reports/comparison-matrix",
+    tag = "Report",
+    params(
+        ("from" = Option<String>, Query, description = "Only reports generated at or after this RFC 3339 time"),
+        ("to" = Option<String>, Query, description = "Only reports generated at or before this RFC 3339 time"),
+        ("categories" = Option<String>, Query, description = "Comma-separated category names, in column order"),
+        ("format" = Option<String>, Query, description = "`csv` to download the matrix as CSV")
+    ),
+    responses(
+        (status = 200, description = "Comparison matrix, or CSV with `format=csv`", body = ComparisonMatrix),
+        (status = 400, description = "Invalid period or format"),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn get_comparison_matrix(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    Query(query): Query<ComparisonMatrixQuery>,
+) -> Result<Response, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only DGRV admins can compare organizations".to_string()));
+    }
+    if let (Some(from), Some(to)) = (query.from, query.to) {
+        if from > to {
+            return Err(ApiError::BadRequest("`from` must not be after `to`".to_string()));
+        }
+    }
+    let csv = match query.format.as_deref() {
+        None | Some("json") => false,
+        Some("csv") => true,
+        Some(other) => return Err(ApiError::BadRequest(format!("Unknown format {other:?}"))),
+    };
 
-            // Get reports for this submission
-            let reports = app_state
-                .database
-                .submission_reports
-                .get_reports_by_submission(submission.submission_id)
-                .await
-                .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch reports for submission {}: {e}", submission.submission_id)))?;
+    let organizations: Vec<(String, String)> = app_state
+        .keycloak_service
+        .get_organizations(&token)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch organizations: {e}")))?
+        .into_iter()
+        .filter(|org| {
+            org.attribute(BENCHMARK_SHARING_ATTRIBUTE)
+                .is_some_and(|value| value.trim().eq_ignore_ascii_case("true"))
+        })
+        .map(|org| (org.id, org.name))
+        .collect();
 
-            for report in reports {
-                if let Some(data) = report.data {
-                    // Parse the report data to extract recommendations
-                    if let Some(categories) = data.as_array() {
-                        for category_obj in categories {
-                            if let Some(category_map) = category_obj.as_object() {
+    let reports = app_state
+        .database
+        .submission_reports
+        .get_reports_with_submissions_between(query.from, query.to)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch reports: {e}")))?;
+    let rules = scoring_rules(&app_state).await?;
+    let scores_by_org = latest_scores_by_org(reports, &rules);
+
+    let categories = query.categories.map(|categories| {
+        categories
+            .split(',')
+            .map(str::trim)
+            .filter(|category| !category.is_empty())
+            .map(str::to_string)
+            .collect()
+    });
+    let matrix = comparison_matrix(categories, &organizations, &scores_by_org);
+
+    if !csv {
+        return Ok(Json(matrix).into_response());
+    }
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (header::CONTENT_DISPOSITION, "attachment; filename=\"comparison-matrix.csv\""),
+        ],
+        comparison_matrix_csv(&matrix),
+    )
+        .into_response())
+}
+
+/// One row per organization in `organizations`, with its score in each of `categories`
+/// (by default every category any of them was scored in, by name)
+fn comparison_matrix(
+    categories: Option<Vec<String>>,
+    organizations: &[(String, String)],
+    scores_by_org: &std::collections::BTreeMap<String, CategoryScores>,
+) -> ComparisonMatrix {
+    let categories = categories.unwrap_or_else(|| {
+        organizations
+            .iter()
+            .filter_map(|(org_id, _)| scores_by_org.get(org_id))
+            .flat_map(|scores| scores.keys().cloned())
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    });
+
+    let mut rows: Vec<(Option<f64>, OrgRow)> = organizations
+        .iter()
+        .map(|(org_id, org_name)| {
+            let scores: Vec<Option<f64>> = categories
+                .iter()
+                .map(|category| scores_by_org.get(org_id).and_then(|scores| scores.get(category)).copied())
+                .collect();
+            let present: Vec<f64> = scores.iter().flatten().copied().collect();
+            let average = (!present.is_empty()).then(|| present.iter().sum::<f64>() / present.len() as f64);
+            let row = OrgRow {
+                org_id: org_id.clone(),
+                org_name: org_name.clone(),
+                scores,
+            };
+            (average, row)
+        })
+        .collect();
+    // Organizations without any score go last
+    rows.sort_by(|(a, a_row), (b, b_row)| {
+        b.partial_cmp(a)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a_row.org_name.cmp(&b_row.org_name))
+    });
+
+    ComparisonMatrix {
+        categories,
+        rows: rows.into_iter().map(|(_, row)| row).collect(),
+    }
+}
+
+/// The matrix with an `org_id,org_name,<category>...` header and empty cells for missing scores
+fn comparison_matrix_csv(matrix: &ComparisonMatrix) -> String {
+    let mut csv = ["org_id", "org_name"]
+        .into_iter()
+        .chain(matrix.categories.iter().map(String::as_str))
+        .map(csv_field)
+        .collect::<Vec<_>>()
+        .join(",");
+    csv.push('\n');
+    for row in &matrix.rows {
+        let cells = [csv_field(&row.org_id), csv_field(&row.org_name)]
+            .into_iter()
+            .chain(row.scores.iter().map(|score| score.map(|score| format!("{score:.2}")).unwrap_or_default()));
+        csv.push_str(&cells.collect::<Vec<_>>().join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Reports of an organization as PDFs in one ZIP archive, for audits
+///
+/// Entries are named after the assessment and the report date. The zip crate needs
+/// a seekable writer, so the archive is assembled on a blocking thread and then
+/// streamed out in chunks.
+#[utoipa::path(
+    get,
+    path = "/organizations/{org_id}/reports/export/zip",
+    tag = "Report",
+    params(("org_id" = String, Path, description = "Organization ID")),
+    responses(
+        (status = 200, description = "ZIP archive with one PDF per report", content_type = "application/zip"),
+        (status = 403, description = "Forbidden")
+    )
+)]
+pub async fn export_organization_reports_zip(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(org_id): Path<String>,
+) -> Result<Response, ApiError> {
+    if !can_access_organization(&claims, &org_id) {
+        return Err(ApiError::Forbidden("You don't have access to this organization".to_string()));
+    }
+
+    let reports = app_state
+        .database
+        .submission_reports
+        .get_reports_with_submissions_by_org(&org_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch reports: {e}")))?;
+
+    let archive = tokio::task::spawn_blocking(move || reports_zip(&reports))
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to build archive: {e}")))??;
+
+    let archive = axum::body::Bytes::from(archive);
+    let chunks = (0..archive.len())
+        .step_by(ZIP_STREAM_CHUNK_BYTES)
+        .map(move |start| {
+            Ok::<_, std::convert::Infallible>(archive.slice(start..(start + ZIP_STREAM_CHUNK_BYTES).min(archive.len())))
+        })
+        .collect::<Vec<_>>();
+    let filename = format!("reports-{}.zip", chrono::Utc::now().format("%Y-%m-%d"));
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/zip".to_string()),
+            (header::CONTENT_DISPOSITION, format!("attachment; filename=\"{filename}\"")),
+        ],
+        axum::body::Body::from_stream(futures::stream::iter(chunks)),
+    )
+        .into_response())
+}
+
+const ZIP_STREAM_CHUNK_BYTES: usize = 64 * 1024;
+
+fn reports_zip(
+    reports: &[(submission_reports::Model, Option<assessments_submission::Model>)],
+) -> Result<Vec<u8>, ApiError> {
+    use std::io::Write;
+
+    let zip_error = |e: &dyn std::fmt::Display| ApiError::InternalServerError(format!("Failed to build archive: {e}"));
+    let options = zip::write::SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    let mut archive = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+    let mut used_names = std::collections::HashSet::new();
+
+    for (report, submission) in reports {
+        let assessment_name = submission
+            .as_ref()
+            .and_then(|submission| submission.content.get("assessment_name"))
+            .and_then(|name| name.as_str())
+            .unwrap_or("Unknown Assessment");
+        // Slashes would create folders inside the archive
+        let stem: String = format!("{assessment_name} {}", report.generated_at.format("%Y-%m-%d"))
+            .chars()
+            .map(|c| if c == '/' || c == '\\' || c.is_control() { '_' } else { c })
+            .collect();
+        let mut name = format!("{stem}.pdf");
+        let mut copy = 1;
+        while !used_names.insert(name.clone()) {
+            copy += 1;
+            name = format!("{stem} ({copy}).pdf");
+        }
+
+        archive.start_file(name, options).map_err(|e| zip_error(&e))?;
+        archive
+            .write_all(&report_pdf(assessment_name, submission.as_ref(), report))
+            .map_err(|e| zip_error(&e))?;
+    }
+
+    Ok(archive.finish().map_err(|e| zip_error(&e))?.into_inner())
+}
+
+/// A report's questions and recommendations, category by category
+fn report_pdf(
+    assessment_name: &str,
+    submission: Option<&assessments_submission::Model>,
+    report: &submission_reports::Model,
+) -> Vec<u8> {
+    let mut document = PdfDocument::new(&format!("Sustainability report: {assessment_name}"));
+    if let Some(submission) = submission {
+        document.text(&format!("Organization: {}", submission.org_name));
+    }
+    document.text(&format!("Generated: {}", report.generated_at.format("%Y-%m-%d %H:%M UTC")));
+
+    let categories = report
+        .data
+        .as_ref()
+        .and_then(|data| data.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.as_object())
+        .flatten();
+    for (category, content) in categories {
+        document.heading(category);
+        for question in content.get("questions").and_then(|q| q.as_array()).into_iter().flatten() {
+            let text = question.get("question").and_then(|q| q.as_str()).unwrap_or("Unknown question");
+            document.text(&format!("{text}: {}", answer_summary(question.get("answer"))));
+        }
+        for recommendation in content.get("recommendations").and_then(|r| r.as_array()).into_iter().flatten() {
+            document.text(&format!(
+                "Recommendation: {} [{}]",
+                recommendation.get("text").and_then(|t| t.as_str()).unwrap_or_default(),
+                recommendation.get("status").and_then(|s| s.as_str()).unwrap_or("todo"),
+            ));
+        }
+    }
+
+    document.render()
+}
+
+/// An answer as a short line, e.g. "Yes, 80%, We publish a yearly report"
+pub(crate) fn answer_summary(answer: Option<&Value>) -> String {
+    let Some(answer) = answer else {
+        return "No answer".to_string();
+    };
+    let mut parts = Vec::new();
+    if let Some(yes_no) = answer.get("yesNo").and_then(|v| v.as_bool()) {
+        parts.push(if yes_no { "Yes".to_string() } else { "No".to_string() });
+    }
+    if let Some(percentage) = answer.get("percentage").and_then(|v| v.as_f64()) {
+        parts.push(format!("{percentage}%"));
+    }
+    if let Some(text) = answer.get("text").and_then(|v| v.as_str()).filter(|t| !t.is_empty()) {
+        parts.push(text.to_string());
+    }
+    if parts.is_empty() {
+        answer.to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+/// Delete a report
+/// DELETE /reports/{report_id}
+/// Delete a report
+#[utoipa::path(
+    delete,
+    path = "/reports/{report_id}",
+    tag = "Report",
+    params(("report_id" = uuid::Uuid, Path, description = "Report ID")),
+    responses((status = 204, description = "Deleted"), (status = 404, description = "Not found"))
+)]
+pub async fn delete_report(
+    State(app_state): State<AppState>,
+    Path(report_id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    // Check if report exists
+    let _report = app_state
+        .database
+        .submission_reports
+        .get_report_by_id(report_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch report: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Report not found".to_string()))?;
+
+    // Delete the report
+    app_state
+        .database
+        .submission_reports
+        .delete_report(report_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to delete report: {e}")))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Statuses a recommendation of an action plan can have
+const RECOMMENDATION_STATUSES: [&str; 4] = ["todo", "in_progress", "done", "approved"];
+
+/// Get all action plans for all organizations (DGRV admin view)
+/// GET /admin/action-plans
+#[utoipa::path(
+    get,
+    path = "/admin/action-plans",
+    tag = "Report",
+    params(
+        ("status" = Option<String>, Query, description = "Only recommendations with this status: todo, in_progress, done or approved")
+    ),
+    responses(
+        (status = 200, description = "All action plans", body = ActionPlanListResponse),
+        (status = 400, description = "Unknown status"),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn list_all_action_plans(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Query(query): Query<ActionPlanQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only DGRV admins can view all action plans".to_string()));
+    }
+    if let Some(status) = &query.status {
+        if !RECOMMENDATION_STATUSES.contains(&status.as_str()) {
+            return Err(ApiError::BadRequest(format!("Invalid status: {status}")));
+        }
+    }
+
+    let mut action_plans = collect_action_plans(&app_state).await?;
+
+    if let Some(status) = &query.status {
+        for plan in &mut action_plans {
+            plan.recommendations.retain(|recommendation| &recommendation.status == status);
+        }
+        action_plans.retain(|plan| !plan.recommendations.is_empty());
+    }
+
+    Ok(Json(ActionPlanListResponse { organizations: action_plans }))
+}
+
+/// Count each organization's recommendations by status, for the admin dashboard
+#[utoipa::path(
+    get,
+    path = "/admin/action-plans/summary",
+    tag = "Report",
+    responses(
+        (status = 200, description = "Recommendation counts per organization", body = ActionPlanSummaryResponse),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn summarize_action_plans(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<impl IntoResponse, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only DGRV admins can view action plan summaries".to_string()));
+    }
+
+    let organizations = collect_action_plans(&app_state)
+        .await?
+        .into_iter()
+        .map(|plan| {
+            let mut counts = RecommendationStatusCounts::default();
+            for recommendation in &plan.recommendations {
+                match recommendation.status.as_str() {
+                    "todo" => counts.todo += 1,
+                    "in_progress" => counts.in_progress += 1,
+                    "done" => counts.done += 1,
+                    "approved" => counts.approved += 1,
+                    _ => {}
+                }
+            }
+            OrganizationActionPlanSummary {
+                organization_id: plan.organization_id,
+                organization_name: plan.organization_name,
+                counts,
+            }
+        })
+        .collect();
+
+    Ok(Json(ActionPlanSummaryResponse { organizations }))
+}
+
+/// Export every organization's action plan as CSV, one row per recommendation
+#[utoipa::path(
+    get,
+    path = "/admin/action-plans/export/csv",
+    tag = "Report",
+    responses(
+        (status = 200, description = "Action plans as CSV", content_type = "text/csv"),
+        (status = 403, description = "Caller is not an application admin")
+    )
+)]
+pub async fn export_action_plans_csv(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Result<impl IntoResponse, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only DGRV admins can export action plans".to_string()));
+    }
+
+    let action_plans = collect_action_plans(&app_state).await?;
+
+    let mut csv = String::from("org_name,assessment_name,category,recommendation,status,created_at\n");
+    for plan in &action_plans {
+        for recommendation in &plan.recommendations {
+            let row = [
+                plan.organization_name.as_str(),
+                recommendation.assessment_name.as_str(),
+                recommendation.category.as_str(),
+                recommendation.recommendation.as_str(),
+                recommendation.status.as_str(),
+                recommendation.created_at.as_str(),
+            ]
+            .map(csv_field)
+            .join(",");
+            csv.push_str(&row);
+            csv.push('\n');
+        }
+    }
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (header::CONTENT_DISPOSITION, "attachment; filename=\"action-plans.csv\""),
+        ],
+        csv,
+    ))
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break.
+///
+/// Fields a spreadsheet would read as a formula get a leading `'` so that opening
+/// the export never evaluates user-provided text.
+fn csv_field(value: &str) -> String {
+    let value = if value.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{value}")
+    } else {
+        value.to_string()
+    };
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value
+    }
+}
+
+/// Walk every submission's reports and gather their recommendations per organization.
+/// Organizations without any recommendation are left out.
+async fn collect_action_plans(app_state: &AppState) -> Result<Vec<OrganizationActionPlan>, ApiError> {
+    // Get all submissions from the database
+    let all_submissions = app_state
+        .database
+        .assessments_submission
+        .get_all_submissions()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch all submissions: {e}")))?;
+
+    // Group submissions by organization
+    let mut submissions_by_org: std::collections::HashMap<String, Vec<_>> = std::collections::HashMap::new();
+    for submission in all_submissions {
+        submissions_by_org.entry(submission.org_id.clone()).or_default().push(submission);
+    }
+
+    let mut action_plans = Vec::new();
+
+    for (org_id, submissions) in submissions_by_org {
+        let mut org_recommendations = Vec::new();
+        let mut org_name = "Unknown Organization".to_string();
+
+        for submission in submissions {
+            // Update org_name from the first submission
+            if org_name == "Unknown Organization" {
+                org_name = submission.org_name.clone();
+            }
+
+            // Get reports for this submission
+            let reports = app_state
+                .database
+                .submission_reports
+                .get_reports_by_submission(submission.submission_id)
+                .await
+                .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch reports for submission {}: {e}", submission.submission_id)))?;
+
+            for report in reports {
+                if let Some(data) = report.data {
+                    // Parse the report data to extract recommendations
+                    if let Some(categories) = data.as_array() {
+                        for category_obj in categories {
+                            if let Some(category_map) = category_obj.as_object() {
                                 for (category_name, category_data) in category_map {
                                     if let Some(category_obj) = category_data.as_object() {
                                         if let Some(recommendations_array) = category_obj.get("recommendations").and_then(|r| r.as_array()) {
@@ -454,7 +1508,7 @@ pub async fn list_all_action_plans(
         }
     }
 
-    Ok(Json(ActionPlanListResponse { organizations: action_plans }))
+    Ok(action_plans)
 }
 
 /// Get all reports for all organizations (DGRV admin view)
@@ -537,59 +1591,307 @@ pub async fn list_all_reports(
 pub async fn update_recommendation_status(
     State(app_state): State<AppState>,
     Path((report_id, recommendation_id)): Path<(Uuid, String)>,
-    Json(request): Json<UpdateRecommendationStatusRequest>,
+    StrictJson(request): StrictJson<UpdateRecommendationStatusRequest>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let valid_statuses = &["todo", "in_progress", "done", "approved"];
-    if !valid_statuses.contains(&request.status.as_str()) {
+    if !RECOMMENDATION_STATUSES.contains(&request.status.as_str()) {
         return Err(ApiError::BadRequest(format!("Invalid status: {}", request.status)));
     }
 
-    let report = app_state
+    let mut data_missing = false;
+    let mut recommendation_found_and_updated = false;
+    app_state
         .database
         .submission_reports
-        .get_report_by_id(report_id)
-        .await
-        .map_err(|e| ApiError::InternalServerError(format!("Database error: {e}")))?
-        .ok_or_else(|| ApiError::NotFound("Report not found".to_string()))?;
-
-    let mut data = report.data.ok_or_else(|| ApiError::InternalServerError("Report data is missing".to_string()))?;
-    let mut recommendation_found_and_updated = false;
-
-    if let Some(categories_map) = data.get_mut(0).and_then(|c| c.as_object_mut()) {
-        for (_category_name, category_data) in categories_map.iter_mut() {
-            if let Some(recs) = category_data.get_mut("recommendations").and_then(|r| r.as_array_mut()) {
-                for rec in recs {
-                    if rec.get("id").and_then(|id| id.as_str()) == Some(&recommendation_id) {
-                        if let Some(rec_obj) = rec.as_object_mut() {
-                            rec_obj.insert("status".to_string(), json!(request.status));
-                            recommendation_found_and_updated = true;
-                            break;
+        .update_report_locked(report_id, |report| {
+            let Some(data) = report.data.as_mut() else {
+                data_missing = true;
+                return false;
+            };
+            if let Some(categories_map) = data.get_mut(0).and_then(|c| c.as_object_mut()) {
+                for (_category_name, category_data) in categories_map.iter_mut() {
+                    if let Some(recs) = category_data.get_mut("recommendations").and_then(|r| r.as_array_mut()) {
+                        for rec in recs {
+                            if rec.get("id").and_then(|id| id.as_str()) == Some(&recommendation_id) {
+                                if let Some(rec_obj) = rec.as_object_mut() {
+                                    rec_obj.insert("status".to_string(), json!(request.status));
+                                    recommendation_found_and_updated = true;
+                                    break;
+                                }
+                            }
                         }
                     }
+                    if recommendation_found_and_updated {
+                        break;
+                    }
                 }
             }
-            if recommendation_found_and_updated {
-                break;
-            }
-        }
-    }
+            recommendation_found_and_updated
+        })
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to update report: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Report not found".to_string()))?;
 
-    if recommendation_found_and_updated {
-        app_state
-            .database
-            .submission_reports
-            .update_report_status(report_id, report.status, Some(data))
-            .await
-            .map_err(|e| ApiError::InternalServerError(format!("Failed to update report: {e}")))?;
+    if data_missing {
+        Err(ApiError::InternalServerError("Report data is missing".to_string()))
+    } else if recommendation_found_and_updated {
         Ok(StatusCode::OK)
     } else {
         Err(ApiError::NotFound("Recommendation not found in this report".to_string()))
     }
 }
 
+/// Add a reviewer's recommendation to an existing report
+/// POST /reports/{report_id}/recommendations
+///
+/// The recommendation gets the same id it would have had if given at generation time,
+/// so adding the same text to a category twice is refused. It replaces the category's
+/// generic placeholder and is kept when the report is regenerated.
+#[utoipa::path(
+    post,
+    path = "/reports/{report_id}/recommendations",
+    tag = "Report",
+    params(("report_id" = Uuid, Path, description = "Report ID")),
+    request_body = AddRecommendationRequest,
+    responses(
+        (status = 201, description = "Recommendation added", body = ReportRecommendation),
+        (status = 400, description = "Empty text or category not in the report"),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 404, description = "Report not found"),
+        (status = 409, description = "The category already has this recommendation")
+    )
+)]
+pub async fn add_report_recommendation(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(report_id): Path<Uuid>,
+    StrictJson(request): StrictJson<AddRecommendationRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only DGRV admins can add recommendations to reports".to_string()));
+    }
+    let text = request.text.trim();
+    if text.is_empty() {
+        return Err(ApiError::BadRequest("Recommendation text must not be empty".to_string()));
+    }
+
+    let recommendation_id = Uuid::new_v5(&Uuid::NAMESPACE_DNS, format!("{}-{}", request.category, text).as_bytes());
+    let mut outcome = Err(ApiError::BadRequest(format!("Category '{}' is not in this report", request.category)));
+    app_state
+        .database
+        .submission_reports
+        .update_report_locked(report_id, |report| {
+            let Some(recommendations) = report
+                .data
+                .as_mut()
+                .and_then(|data| data.get_mut(0))
+                .and_then(|categories| categories.get_mut(&request.category))
+                .and_then(|category| category.get_mut("recommendations"))
+                .and_then(|recommendations| recommendations.as_array_mut())
+            else {
+                return false;
+            };
+            if recommendations
+                .iter()
+                .any(|rec| rec.get("id").and_then(|id| id.as_str()) == Some(&recommendation_id.to_string()))
+            {
+                outcome = Err(ApiError::Conflict("The category already has this recommendation".to_string()));
+                return false;
+            }
+
+            recommendations.retain(|rec| !is_generic_placeholder(&request.category, rec));
+            recommendations.push(json!({
+                "id": recommendation_id.to_string(),
+                "text": text,
+                "status": "todo",
+                "added_by_reviewer": true,
+            }));
+            outcome = Ok(());
+            true
+        })
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to update report: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Report not found".to_string()))?;
+    outcome?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ReportRecommendation {
+            id: recommendation_id,
+            category: request.category,
+            text: text.to_string(),
+            status: "todo".to_string(),
+        }),
+    ))
+}
+
+/// Remove a recommendation from a report
+/// DELETE /reports/{report_id}/recommendations/{recommendation_id}
+///
+/// A category left without recommendations gets the placeholder a report is generated
+/// with, so every category keeps at least one.
+#[utoipa::path(
+    delete,
+    path = "/reports/{report_id}/recommendations/{recommendation_id}",
+    tag = "Report",
+    params(
+        ("report_id" = Uuid, Path, description = "Report ID"),
+        ("recommendation_id" = String, Path, description = "Recommendation ID")
+    ),
+    responses(
+        (status = 204, description = "Recommendation removed"),
+        (status = 403, description = "Caller is not an application admin"),
+        (status = 404, description = "Report or recommendation not found")
+    )
+)]
+pub async fn delete_report_recommendation(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((report_id, recommendation_id)): Path<(Uuid, String)>,
+) -> Result<StatusCode, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only DGRV admins can remove recommendations from reports".to_string()));
+    }
+
+    let has_recommendation = |category: &Value| {
+        category
+            .get("recommendations")
+            .and_then(|recs| recs.as_array())
+            .is_some_and(|recs| recs.iter().any(|rec| rec.get("id").and_then(|id| id.as_str()) == Some(&recommendation_id)))
+    };
+    let not_found = || ApiError::NotFound("Recommendation not found in this report".to_string());
+
+    let report = app_state
+        .database
+        .submission_reports
+        .get_report_by_id(report_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch report: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Report not found".to_string()))?;
+    let category = report
+        .data
+        .as_ref()
+        .and_then(|data| data.get(0))
+        .and_then(|categories| categories.as_object())
+        .and_then(|categories| categories.iter().find(|(_, category)| has_recommendation(category)))
+        .map(|(name, _)| name.clone())
+        .ok_or_else(not_found)?;
+
+    // The placeholder in the submission's language, as at generation time
+    let language = app_state
+        .database
+        .assessments_submission
+        .get_submission_by_assessment_id(report.submission_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
+        .and_then(|submission| submission.content["assessment"]["language"].as_str().map(str::to_string))
+        .unwrap_or_else(|| "en".to_string());
+    let default_text = app_state
+        .database
+        .category_catalog
+        .get_all_categories()
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch categories: {e}")))?
+        .into_iter()
+        .find(|model| model.name == category)
+        .and_then(|model| model.default_recommendation_for(&language, &app_state.locale_config.language_fallback));
+    let placeholder = placeholder_recommendation(&category, default_text);
+
+    let mut removed = false;
+    app_state
+        .database
+        .submission_reports
+        .update_report_locked(report_id, |report| {
+            let Some(recommendations) = report
+                .data
+                .as_mut()
+                .and_then(|data| data.get_mut(0))
+                .and_then(|categories| categories.get_mut(&category))
+                .and_then(|category| category.get_mut("recommendations"))
+                .and_then(|recs| recs.as_array_mut())
+            else {
+                return false;
+            };
+            let before = recommendations.len();
+            recommendations.retain(|rec| rec.get("id").and_then(|id| id.as_str()) != Some(&recommendation_id));
+            removed = recommendations.len() < before;
+            if recommendations.is_empty() {
+                recommendations.push(placeholder);
+            }
+            removed
+        })
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to update report: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Report not found".to_string()))?;
+
+    if removed {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found())
+    }
+}
+
+/// Regenerate an existing report in place
+/// PUT /submissions/{submission_id}/reports/{report_id}
+///
+/// The report keeps its id, and recommendations that already have a status keep it,
+/// matched by recommendation id. Recommendations reviewers added are kept.
+#[utoipa::path(
+    put,
+    path = "/submissions/{submission_id}/reports/{report_id}",
+    tag = "Report",
+    params(
+        ("submission_id" = uuid::Uuid, Path, description = "Submission ID"),
+        ("report_id" = uuid::Uuid, Path, description = "Report ID")
+    ),
+    request_body = Vec<GenerateReportRequest>,
+    responses((status = 200, description = "Report regenerated", body = ReportGenerationResponse), (status = 403, description = "Forbidden"), (status = 404, description = "Submission or report not found"))
+)]
+pub async fn regenerate_report(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path((submission_id, report_id)): Path<(Uuid, Uuid)>,
+    StrictJson(request): StrictJson<Vec<GenerateReportRequest>>,
+) -> Result<impl IntoResponse, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden("Only DGRV admins can regenerate reports".to_string()));
+    }
+
+    let report_content = generate_report_content(&request, submission_id, &app_state).await?;
+
+    let mut belongs_to_submission = false;
+    let report_model = app_state
+        .database
+        .submission_reports
+        .update_report_locked(report_id, |report| {
+            belongs_to_submission = report.submission_id == submission_id;
+            if !belongs_to_submission {
+                return false;
+            }
+            let mut data = report_content;
+            if let Some(previous) = &report.data {
+                carry_over_added_recommendations(&mut data, previous);
+                submission_reports::carry_over_recommendation_statuses(&mut data, previous);
+            }
+            report.data = Some(data);
+            report.status = "completed".to_string();
+            report.generated_at = chrono::Utc::now();
+            true
+        })
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to regenerate report: {e}")))?
+        .filter(|_| belongs_to_submission)
+        .ok_or_else(|| ApiError::NotFound("Report not found".to_string()))?;
+
+    Ok(Json(ReportGenerationResponse {
+        report_id: report_model.report_id,
+        status: report_model.status,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::{claims, org_claims, unreachable_keycloak};
     use serde_json::json;
 
     #[test]
@@ -736,4 +2038,1372 @@ mod tests {
         println!("Test passed! Generated report content with NEW format (single recommendation per category):");
         println!("{}", serde_json::to_string_pretty(&final_result).unwrap());
     }
+
+    use crate::common::database::entity::assessments_submission::{self, SubmissionStatus};
+    use axum::{body::Body, http::Request, routing::post, Router};
+    use sea_orm::{DatabaseConnection, EntityTrait, PaginatorTrait};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    /// One under-review submission answering a single Environmental question.
+    async fn setup_with_default(
+        default_recommendation: Option<Value>,
+    ) -> Result<(AppState, Arc<DatabaseConnection>, Uuid), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::{
+            category_catalog, questions, questions_revisions, submission_reports, submission_timeline,
+        };
+        use crate::common::state::AppDatabase;
+        use chrono::Utc;
+        use sea_orm::{ActiveModelTrait, ConnectionTrait, Database, Schema, Set};
+
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(submission_reports::Entity),
+            schema.create_table_from_entity(submission_timeline::Entity),
+            schema.create_table_from_entity(category_catalog::Entity),
+            schema.create_table_from_entity(questions::Entity),
+            schema.create_table_from_entity(questions_revisions::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        let category_id = Uuid::new_v4();
+        category_catalog::ActiveModel {
+            category_catalog_id: Set(category_id),
+            name: Set("Environmental".to_string()),
+            description: Set(None),
+            template_id: Set("sustainability_template_1".to_string()),
+            is_active: Set(true),
+            created_at: Set(Utc::now()),
+            updated_at: Set(Utc::now()),
+            default_recommendation: Set(default_recommendation),
+            deactivated_at: Set(None),
+            parent_category_catalog_id: Set(None),
+            scoring_mode: Set(Default::default()),
+            score_threshold: Set(None),
+            icon_name: Set(None),
+            color_hex: Set(None),
+            metadata: Set(serde_json::json!({})),
+        }
+        .insert(&db)
+        .await?;
+        let question_id = Uuid::new_v4();
+        questions::ActiveModel {
+            question_id: Set(question_id),
+            category_id: Set(category_id),
+            created_at: Set(Utc::now()),
+            external_key: Set(None),
+            sensitive: Set(false),
+            skip_rules: Set(serde_json::json!([])),
+        }
+        .insert(&db)
+        .await?;
+        let question_revision_id = Uuid::new_v4();
+        questions_revisions::ActiveModel {
+            question_revision_id: Set(question_revision_id),
+            question_id: Set(question_id),
+            text: Set(json!({ "en": "Do you have a sustainability policy?" })),
+            weight: Set(1.0),
+            created_at: Set(Utc::now()),
+        }
+        .insert(&db)
+        .await?;
+
+        let submission_id = Uuid::new_v4();
+        assessments_submission::ActiveModel {
+            submission_id: Set(submission_id),
+            org_id: Set("test-org".to_string()),
+            org_name: Set("Test Organization".to_string()),
+            content: Set(json!({
+                "responses": [{
+                    "question_revision_id": question_revision_id.to_string(),
+                    "response": "{\"yesNo\":true}",
+                }]
+            })),
+            submitted_at: Set(Utc::now()),
+            status: Set(SubmissionStatus::UnderReview),
+            reviewed_at: Set(None),
+            reopened_at: Set(None),
+            reopened_by: Set(None),
+        }
+        .insert(&db)
+        .await?;
+
+        let db = Arc::new(db);
+        let app_state = AppState::new(
+            unreachable_keycloak(),
+            AppDatabase::new(db.clone()).await,
+        )
+        .await;
+
+        Ok((app_state, db, submission_id))
+    }
+
+    async fn setup() -> Result<(AppState, Arc<DatabaseConnection>, Uuid), Box<dyn std::error::Error>> {
+        setup_with_default(None).await
+    }
+
+    async fn preview_as(
+        app_state: AppState,
+        claims: Claims,
+        submission_id: Uuid,
+        recommendations: Value,
+    ) -> Result<axum::response::Response, Box<dyn std::error::Error>> {
+        let app = Router::new()
+            .route("/submissions/:submission_id/reports/preview", post(preview_report))
+            .layer(Extension(claims))
+            .with_state(app_state);
+        Ok(app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/submissions/{submission_id}/reports/preview"))
+                    .header("content-type", "application/json")
+                    .body(Body::from(recommendations.to_string()))?,
+            )
+            .await?)
+    }
+
+    async fn preview(
+        app_state: AppState,
+        submission_id: Uuid,
+        recommendations: Value,
+    ) -> Result<ReportPreviewResponse, Box<dyn std::error::Error>> {
+        let response =
+            preview_as(app_state, claims("test-user-123", &["application_admin"]), submission_id, recommendations).await?;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    async fn submission_status(
+        db: &DatabaseConnection,
+        submission_id: Uuid,
+    ) -> Result<SubmissionStatus, Box<dyn std::error::Error>> {
+        let submission = assessments_submission::Entity::find_by_id(submission_id)
+            .one(db)
+            .await?
+            .unwrap();
+        Ok(submission.status)
+    }
+
+    fn report_request(uri: String) -> Result<Request<Body>, axum::http::Error> {
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(
+                json!([{ "category": "Environmental", "recommendation": "Publish the policy" }]).to_string(),
+            ))
+    }
+
+    async fn generate(uri_suffix: &str) -> Result<(Arc<DatabaseConnection>, Uuid), Box<dyn std::error::Error>> {
+        let (app_state, db, submission_id) = setup().await?;
+
+        let app = Router::new()
+            .route("/submissions/:submission_id/reports", post(generate_report))
+            .layer(Extension(claims("test-user-123", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+        let response = app
+            .oneshot(report_request(format!("/submissions/{submission_id}/reports{uri_suffix}"))?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        Ok((db, submission_id))
+    }
+
+    #[tokio::test]
+    async fn test_preview_report_does_not_persist() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+
+        let (app_state, db, submission_id) = setup().await?;
+
+        let preview = preview(
+            app_state,
+            submission_id,
+            json!([{ "category": "Environmental", "recommendation": "Publish the policy" }]),
+        )
+        .await?;
+        let environmental = &preview.data[0]["Environmental"];
+        assert_eq!(environmental["questions"][0]["answer"]["yesNo"], true);
+        assert_eq!(environmental["recommendations"][0]["text"], "Publish the policy");
+
+        assert_eq!(submission_reports::Entity::find().count(db.as_ref()).await?, 0);
+        assert_eq!(submission_status(&db, submission_id).await?, SubmissionStatus::UnderReview);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_preview_report_of_another_organization_is_forbidden() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, _db, submission_id) = setup().await?;
+
+        let response = preview_as(app_state, claims("test-user-123", &["org_admin"]), submission_id, json!([])).await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_report_uses_configured_default_recommendation() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, _db, submission_id) = setup_with_default(Some(json!({
+            "en": "Adopt a written sustainability policy",
+            "de": "Eine schriftliche Nachhaltigkeitsrichtlinie einführen",
+        })))
+        .await?;
+
+        let preview = preview(app_state, submission_id, json!([])).await?;
+        assert_eq!(
+            preview.data[0]["Environmental"]["recommendations"][0]["text"],
+            "Adopt a written sustainability policy"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_report_falls_back_without_default_recommendation() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, _db, submission_id) = setup().await?;
+
+        let preview = preview(app_state, submission_id, json!([])).await?;
+        assert_eq!(
+            preview.data[0]["Environmental"]["recommendations"][0]["text"],
+            "No recommendation provided"
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_generate_report_keeps_review_status() -> Result<(), Box<dyn std::error::Error>> {
+        let (db, submission_id) = generate("").await?;
+
+        assert_eq!(submission_status(&db, submission_id).await?, SubmissionStatus::UnderReview);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_generate_report_can_mark_reviewed() -> Result<(), Box<dyn std::error::Error>> {
+        let (db, submission_id) = generate("?mark_reviewed=true").await?;
+
+        assert_eq!(submission_status(&db, submission_id).await?, SubmissionStatus::Reviewed);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_generate_report_requires_access() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+
+        let member_of = |org_id| org_claims("test-user-123", &["org_admin"], "Test Organization", org_id);
+
+        for (claims, uri_suffix) in [(member_of("other-org"), ""), (member_of("test-org"), "?mark_reviewed=true")] {
+            let (app_state, db, submission_id) = setup().await?;
+            let app = Router::new()
+                .route("/submissions/:submission_id/reports", post(generate_report))
+                .layer(Extension(claims))
+                .layer(Extension("test-token".to_string()))
+                .with_state(app_state);
+            let response = app
+                .oneshot(report_request(format!("/submissions/{submission_id}/reports{uri_suffix}"))?)
+                .await?;
+
+            assert_eq!(response.status(), StatusCode::FORBIDDEN);
+            assert_eq!(submission_reports::Entity::find().count(db.as_ref()).await?, 0);
+            assert_eq!(submission_status(&db, submission_id).await?, SubmissionStatus::UnderReview);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_review_submission_requires_admin() -> Result<(), Box<dyn std::error::Error>> {
+        for (role, expected_status, expected_review) in [
+            ("org_admin", StatusCode::FORBIDDEN, SubmissionStatus::UnderReview),
+            ("application_admin", StatusCode::OK, SubmissionStatus::Reviewed),
+        ] {
+            let (app_state, db, submission_id) = setup().await?;
+
+            let app = Router::new()
+                .route("/submissions/:submission_id/review", post(review_submission))
+                .layer(Extension(claims("test-user-123", &[role])))
+                .with_state(app_state);
+            let response = app
+                .oneshot(
+                    Request::builder()
+                        .method("POST")
+                        .uri(format!("/submissions/{submission_id}/review"))
+                        .body(Body::empty())?,
+                )
+                .await?;
+
+            assert_eq!(response.status(), expected_status);
+            assert_eq!(submission_status(&db, submission_id).await?, expected_review);
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_review_unknown_submission_is_not_found() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, _db, _submission_id) = setup().await?;
+
+        let app = Router::new()
+            .route("/submissions/:submission_id/review", post(review_submission))
+            .layer(Extension(claims("test-user-123", &["application_admin"])))
+            .with_state(app_state);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/submissions/{}/review", Uuid::new_v4()))
+                    .body(Body::empty())?,
+            )
+            .await?;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_regenerate_report_preserves_recommendation_status() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+        use axum::routing::put;
+
+        let (app_state, db, submission_id) = setup().await?;
+        let app = Router::new()
+            .route("/submissions/:submission_id/reports", post(generate_report))
+            .route("/submissions/:submission_id/reports/:report_id", put(regenerate_report))
+            .route(
+                "/reports/:report_id/recommendations/:recommendation_id/status",
+                put(update_recommendation_status),
+            )
+            .layer(Extension(claims("test-user-123", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+
+        let response = app
+            .clone()
+            .oneshot(report_request(format!("/submissions/{submission_id}/reports"))?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let generated: Value = serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+        let report_id: Uuid = generated["report_id"].as_str().unwrap().parse()?;
+        let report = submission_reports::Entity::find_by_id(report_id).one(db.as_ref()).await?.unwrap();
+        let recommendation_id = report.data.unwrap()[0]["Environmental"]["recommendations"][0]["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/reports/{report_id}/recommendations/{recommendation_id}/status"))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!({
+                            "report_id": report_id,
+                            "recommendation_id": recommendation_id,
+                            "category": "Environmental",
+                            "status": "in_progress",
+                        })
+                        .to_string(),
+                    ))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/submissions/{submission_id}/reports/{report_id}"))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!([
+                            { "category": "Environmental", "recommendation": "Publish the policy" },
+                            { "category": "Environmental", "recommendation": "Track energy use" }
+                        ])
+                        .to_string(),
+                    ))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        assert_eq!(submission_reports::Entity::find().count(db.as_ref()).await?, 1);
+        let report = submission_reports::Entity::find_by_id(report_id).one(db.as_ref()).await?.unwrap();
+        let recommendations = report.data.unwrap()[0]["Environmental"]["recommendations"].clone();
+        assert_eq!(recommendations[0]["id"], recommendation_id);
+        assert_eq!(recommendations[0]["status"], "in_progress");
+        assert_eq!(recommendations[1]["text"], "Track energy use");
+        assert_eq!(recommendations[1]["status"], "todo");
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/submissions/{}/reports/{report_id}", Uuid::new_v4()))
+                    .header("content-type", "application/json")
+                    .body(Body::from("[]"))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_regenerate_report_requires_admin() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+        use axum::routing::put;
+
+        let (app_state, db, submission_id) = setup().await?;
+        let report = app_state
+            .database
+            .submission_reports
+            .create_report(submission_id, Some(json!([])))
+            .await?;
+        let app = Router::new()
+            .route("/submissions/:submission_id/reports/:report_id", put(regenerate_report))
+            .layer(Extension(claims("test-user-123", &["org_admin"])))
+            .with_state(app_state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/submissions/{submission_id}/reports/{}", report.report_id))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!([{ "category": "Environmental", "recommendation": "Publish the policy" }]).to_string(),
+                    ))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let unchanged = submission_reports::Entity::find_by_id(report.report_id).one(db.as_ref()).await?.unwrap();
+        assert_eq!(unchanged.data, Some(json!([])));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reviewer_adds_recommendation_to_report() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+
+        let (app_state, db, submission_id) = setup().await?;
+        let app = |role: &str| {
+            Router::new()
+                .route("/submissions/:submission_id/reports", post(generate_report))
+                .route("/reports/:report_id/recommendations", post(add_report_recommendation))
+                .layer(Extension(claims("test-user-123", &[role])))
+                .layer(Extension("test-token".to_string()))
+                .with_state(app_state.clone())
+        };
+        let add = |role: &str, report_id: Uuid, category: &str, text: &str| {
+            app(role).oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/reports/{report_id}/recommendations"))
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "category": category, "text": text }).to_string()))
+                    .unwrap(),
+            )
+        };
+
+        let response = app("application_admin")
+            .oneshot(report_request(format!("/submissions/{submission_id}/reports"))?)
+            .await?;
+        let generated: Value = serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+        let report_id: Uuid = generated["report_id"].as_str().unwrap().parse()?;
+
+        let response = add("application_admin", report_id, "Environmental", "Track energy use").await?;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let added: ReportRecommendation = serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+        assert_eq!(added.id, Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"Environmental-Track energy use"));
+        assert_eq!(added.status, "todo");
+
+        let report = submission_reports::Entity::find_by_id(report_id).one(db.as_ref()).await?.unwrap();
+        let recommendations = report.data.unwrap()[0]["Environmental"]["recommendations"].clone();
+        assert_eq!(recommendations.as_array().unwrap().len(), 2);
+        assert_eq!(recommendations[0]["text"], "Publish the policy");
+        assert_eq!(recommendations[1]["id"], added.id.to_string());
+        assert_eq!(recommendations[1]["text"], "Track energy use");
+
+        let response = add("application_admin", report_id, "Environmental", "Track energy use").await?;
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let response = add("org_admin", report_id, "Environmental", "Plant trees").await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        let response = add("application_admin", Uuid::new_v4(), "Environmental", "Plant trees").await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_added_recommendation_replaces_placeholder_and_survives_regeneration(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+        use axum::routing::put;
+
+        let (app_state, db, submission_id) = setup().await?;
+        let report = app_state
+            .database
+            .submission_reports
+            .create_report(
+                submission_id,
+                Some(json!([{
+                    "Environmental": {
+                        "questions": [],
+                        "recommendations": [placeholder_recommendation("Environmental", None)],
+                    }
+                }])),
+            )
+            .await?;
+        let app = Router::new()
+            .route("/submissions/:submission_id/reports/:report_id", put(regenerate_report))
+            .route("/reports/:report_id/recommendations", post(add_report_recommendation))
+            .layer(Extension(claims("test-user-123", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+        let recommendations = |report_id| {
+            let db = db.clone();
+            async move {
+                let report = submission_reports::Entity::find_by_id(report_id).one(db.as_ref()).await.unwrap().unwrap();
+                report.data.unwrap()[0]["Environmental"]["recommendations"].clone()
+            }
+        };
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/reports/{}/recommendations", report.report_id))
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "category": "Environmental", "text": "Track energy use" }).to_string()))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let added = recommendations(report.report_id).await;
+        assert_eq!(added.as_array().unwrap().len(), 1);
+        assert_eq!(added[0]["text"], "Track energy use");
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/submissions/{submission_id}/reports/{}", report.report_id))
+                    .header("content-type", "application/json")
+                    .body(Body::from("[]"))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(recommendations(report.report_id).await, added);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_recommendation_for_unknown_category_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+
+        let (app_state, db, submission_id) = setup().await?;
+        let app = Router::new()
+            .route("/submissions/:submission_id/reports", post(generate_report))
+            .route("/reports/:report_id/recommendations", post(add_report_recommendation))
+            .layer(Extension(claims("test-user-123", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+
+        let response = app
+            .clone()
+            .oneshot(report_request(format!("/submissions/{submission_id}/reports"))?)
+            .await?;
+        let generated: Value = serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+        let report_id: Uuid = generated["report_id"].as_str().unwrap().parse()?;
+        let before = submission_reports::Entity::find_by_id(report_id).one(db.as_ref()).await?.unwrap().data;
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/reports/{report_id}/recommendations"))
+                    .header("content-type", "application/json")
+                    .body(Body::from(json!({ "category": "Governance", "text": "Appoint a board" }).to_string()))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let after = submission_reports::Entity::find_by_id(report_id).one(db.as_ref()).await?.unwrap().data;
+        assert_eq!(after, before);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_report_recommendations() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+        use axum::routing::delete;
+
+        let (app_state, db, submission_id) = setup().await?;
+        let app = Router::new()
+            .route("/submissions/:submission_id/reports", post(generate_report))
+            .route("/reports/:report_id/recommendations/:recommendation_id", delete(delete_report_recommendation))
+            .layer(Extension(claims("test-user-123", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+        let remove = |report_id: Uuid, recommendation_id: String| {
+            app.clone().oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri(format!("/reports/{report_id}/recommendations/{recommendation_id}"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+        };
+        let recommendations = |report_id: Uuid| {
+            let db = db.clone();
+            async move {
+                let report = submission_reports::Entity::find_by_id(report_id).one(db.as_ref()).await.unwrap().unwrap();
+                report.data.unwrap()[0]["Environmental"]["recommendations"].as_array().unwrap().clone()
+            }
+        };
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/submissions/{submission_id}/reports"))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!([
+                            { "category": "Environmental", "recommendation": "Publish the policy" },
+                            { "category": "Environmental", "recommendation": "Track energy use" }
+                        ])
+                        .to_string(),
+                    ))?,
+            )
+            .await?;
+        let generated: Value = serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+        let report_id: Uuid = generated["report_id"].as_str().unwrap().parse()?;
+        let generated = recommendations(report_id).await;
+        let [first, second] = [0, 1].map(|i| generated[i]["id"].as_str().unwrap().to_string());
+
+        // One of several
+        assert_eq!(remove(report_id, first.clone()).await?.status(), StatusCode::NO_CONTENT);
+        let remaining = recommendations(report_id).await;
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0]["id"], second);
+        assert_eq!(remove(report_id, first).await?.status(), StatusCode::NOT_FOUND);
+
+        // The only one left is replaced by the placeholder
+        assert_eq!(remove(report_id, second).await?.status(), StatusCode::NO_CONTENT);
+        let remaining = recommendations(report_id).await;
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0]["text"], "No recommendation provided");
+        assert_eq!(remaining[0]["status"], "todo");
+        assert_eq!(
+            remaining[0]["id"],
+            Uuid::new_v5(&Uuid::NAMESPACE_DNS, b"Environmental-No recommendation provided").to_string()
+        );
+
+        assert_eq!(remove(Uuid::new_v4(), "missing".to_string()).await?.status(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_new_report_keeps_statuses_of_previous_report() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+        use chrono::{Duration, Utc};
+        use sea_orm::{ActiveModelTrait, QueryOrder, Set};
+
+        let (app_state, db, submission_id) = setup().await?;
+        let recommendation_id = |text: &str| Uuid::new_v5(&Uuid::NAMESPACE_DNS, format!("Environmental-{text}").as_bytes());
+        // An older report where the recommendation is still open, and the latest one where it is done
+        for (age, status) in [(2, "todo"), (1, "done")] {
+            submission_reports::ActiveModel {
+                report_id: Set(Uuid::new_v4()),
+                submission_id: Set(submission_id),
+                report_type: Set("default".to_string()),
+                status: Set("completed".to_string()),
+                generated_at: Set(Utc::now() - Duration::hours(age)),
+                data: Set(Some(json!([{ "Environmental": { "recommendations": [
+                    { "id": recommendation_id("Publish the policy"), "text": "Publish the policy", "status": status }
+                ] } }]))),
+            }
+            .insert(db.as_ref())
+            .await?;
+        }
+        // A newer generation that failed has nothing to carry over
+        submission_reports::ActiveModel {
+            report_id: Set(Uuid::new_v4()),
+            submission_id: Set(submission_id),
+            report_type: Set("default".to_string()),
+            status: Set("failed".to_string()),
+            generated_at: Set(Utc::now() - Duration::minutes(30)),
+            data: Set(None),
+        }
+        .insert(db.as_ref())
+        .await?;
+
+        let app = Router::new()
+            .route("/submissions/:submission_id/reports", post(generate_report))
+            .layer(Extension(claims("test-user-123", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri(format!("/submissions/{submission_id}/reports"))
+                    .header("content-type", "application/json")
+                    .body(Body::from(
+                        json!([
+                            { "category": "Environmental", "recommendation": "Publish the policy" },
+                            { "category": "Environmental", "recommendation": "Track energy use" }
+                        ])
+                        .to_string(),
+                    ))?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let latest = submission_reports::Entity::find()
+            .order_by_desc(submission_reports::Column::GeneratedAt)
+            .one(db.as_ref())
+            .await?
+            .unwrap();
+        let recommendations = latest.data.unwrap()[0]["Environmental"]["recommendations"].clone();
+        assert_eq!(recommendations[0]["id"], recommendation_id("Publish the policy").to_string());
+        assert_eq!(recommendations[0]["status"], "done");
+        assert_eq!(recommendations[1]["status"], "todo");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_poll_background_report_generation() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+        use axum::routing::get;
+
+        let (app_state, db, submission_id) = setup().await?;
+        let app = |claims: Claims| {
+            Router::new()
+                .route("/submissions/:submission_id/reports", post(generate_report))
+                .route("/reports/:report_id/generation-progress", get(get_report_generation_progress))
+                .layer(Extension(claims))
+                .layer(Extension("test-token".to_string()))
+                .with_state(app_state.clone())
+        };
+        let progress = |claims: Claims, report_id: Uuid| {
+            app(claims).oneshot(
+                Request::builder()
+                    .uri(format!("/reports/{report_id}/generation-progress"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+        };
+
+        let response = app(claims("test-user-123", &["application_admin"]))
+            .oneshot(report_request(format!("/submissions/{submission_id}/reports?background=true"))?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        let started: Value = serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+        assert_eq!(started["status"], "generating");
+        let report_id: Uuid = serde_json::from_value(started["report_id"].clone())?;
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        let finished = loop {
+            let response = progress(claims("test-user-123", &["application_admin"]), report_id).await?;
+            assert_eq!(response.status(), StatusCode::OK);
+            let body: Value = serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+            assert_eq!(body["total_steps"], 5);
+            if body["status"] != "pending" && body["status"] != "processing" {
+                break body;
+            }
+            assert!(std::time::Instant::now() < deadline, "report generation did not finish");
+            tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        };
+        assert_eq!(finished["status"], "completed");
+        assert_eq!(finished["steps_completed"], 5);
+        assert_eq!(finished["current_step"], "saving_report");
+        assert_eq!(finished["error"], Value::Null);
+        assert_eq!(finished["estimated_completion_secs"], Value::Null);
+
+        let report = submission_reports::Entity::find_by_id(report_id)
+            .one(db.as_ref())
+            .await?
+            .unwrap();
+        assert_eq!(report.status, "completed");
+        assert!(report.data.is_some());
+
+        // Members of other organizations cannot follow it, and unknown reports have no progress
+        let outsider = org_claims("test-user-123", &["Org_User"], "other-org", "other-org");
+        assert_eq!(progress(outsider, report_id).await?.status(), StatusCode::FORBIDDEN);
+        let response = progress(claims("test-user-123", &["application_admin"]), Uuid::new_v4()).await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    /// Org A gets three recommendations (todo, in_progress, done), Org B one todo
+    async fn seed_action_plans(db: &DatabaseConnection) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+        use chrono::Utc;
+        use sea_orm::{ActiveModelTrait, Set};
+
+        let recommendation = |text: &str, status: &str| {
+            json!({ "id": Uuid::new_v4().to_string(), "text": text, "status": status })
+        };
+        for (org_id, org_name, data) in [
+            (
+                "org-a",
+                "Org A",
+                json!([
+                    { "Environmental": { "recommendations": [recommendation("Publish the policy", "todo"), recommendation("Track energy use", "in_progress")] } },
+                    { "Social": { "recommendations": [recommendation("Train staff", "done")] } }
+                ]),
+            ),
+            (
+                "org-b",
+                "Org B, Ltd.",
+                json!([{ "Governance": { "recommendations": [recommendation("Adopt a \"code of conduct\"", "todo")] } }]),
+            ),
+        ] {
+            let submission_id = Uuid::new_v4();
+            assessments_submission::ActiveModel {
+                submission_id: Set(submission_id),
+                org_id: Set(org_id.to_string()),
+                org_name: Set(org_name.to_string()),
+                content: Set(json!({ "assessment_name": "Yearly check" })),
+                submitted_at: Set(Utc::now()),
+                status: Set(SubmissionStatus::Reviewed),
+                reviewed_at: Set(Some(Utc::now())),
+                reopened_at: Set(None),
+                reopened_by: Set(None),
+            }
+            .insert(db)
+            .await?;
+            submission_reports::ActiveModel {
+                report_id: Set(Uuid::new_v4()),
+                submission_id: Set(submission_id),
+                report_type: Set("sustainability".to_string()),
+                status: Set("generated".to_string()),
+                generated_at: Set(Utc::now()),
+                data: Set(Some(data)),
+            }
+            .insert(db)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_export_organization_reports_zip() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+        use axum::routing::get;
+        use chrono::Utc;
+        use sea_orm::{ActiveModelTrait, ColumnTrait, QueryFilter, Set};
+        use std::io::Read;
+
+        let (app_state, db, _) = setup().await?;
+        seed_action_plans(&db).await?;
+        // A second report of Org A's submission, generated the same day
+        let submission = assessments_submission::Entity::find()
+            .filter(assessments_submission::Column::OrgId.eq("org-a"))
+            .one(db.as_ref())
+            .await?
+            .unwrap();
+        submission_reports::ActiveModel {
+            report_id: Set(Uuid::new_v4()),
+            submission_id: Set(submission.submission_id),
+            report_type: Set("sustainability".to_string()),
+            status: Set("completed".to_string()),
+            generated_at: Set(Utc::now()),
+            data: Set(None),
+        }
+        .insert(db.as_ref())
+        .await?;
+
+        let member_of = |org_id| org_claims("test-user-123", &["Org_User"], org_id, org_id);
+        let export = |claims: Claims| {
+            Router::new()
+                .route("/organizations/:org_id/reports/export/zip", get(export_organization_reports_zip))
+                .layer(Extension(claims))
+                .with_state(app_state.clone())
+                .oneshot(Request::builder().uri("/organizations/org-a/reports/export/zip").body(Body::empty()).unwrap())
+        };
+
+        let response = export(member_of("org-b")).await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = export(member_of("org-a")).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/zip");
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        let mut archive = zip::ZipArchive::new(std::io::Cursor::new(body.to_vec()))?;
+        let date = Utc::now().format("%Y-%m-%d");
+        let mut names: Vec<String> = archive.file_names().map(str::to_string).collect();
+        names.sort();
+        assert_eq!(names, vec![format!("Yearly check {date} (2).pdf"), format!("Yearly check {date}.pdf")]);
+
+        let mut pdf = Vec::new();
+        archive.by_name(&format!("Yearly check {date}.pdf"))?.read_to_end(&mut pdf)?;
+        assert!(pdf.starts_with(b"%PDF-"));
+        assert!(String::from_utf8_lossy(&pdf).contains("(Recommendation: Publish the policy [todo]) Tj"));
+        Ok(())
+    }
+
+    async fn request_action_plans(
+        app_state: AppState,
+        claims: Claims,
+        uri: &str,
+    ) -> Result<axum::response::Response, Box<dyn std::error::Error>> {
+        use axum::routing::get;
+
+        let app = Router::new()
+            .route("/admin/action-plans", get(list_all_action_plans))
+            .layer(Extension(claims))
+            .with_state(app_state);
+        Ok(app.oneshot(Request::builder().uri(uri).body(Body::empty())?).await?)
+    }
+
+    async fn fetch_action_plans(app_state: AppState, uri: &str) -> Result<Value, Box<dyn std::error::Error>> {
+        let response = request_action_plans(app_state, claims("test-user-123", &["application_admin"]), uri).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        Ok(serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?)
+    }
+
+    #[tokio::test]
+    async fn test_action_plans_require_application_admin() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, db, _) = setup().await?;
+        seed_action_plans(&db).await?;
+
+        let response = request_action_plans(app_state, claims("test-user-123", &["org_admin"]), "/admin/action-plans").await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_action_plans_reject_unknown_status() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, _db, _) = setup().await?;
+
+        let response = request_action_plans(
+            app_state,
+            claims("test-user-123", &["application_admin"]),
+            "/admin/action-plans?status=finished",
+        )
+        .await?;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_action_plans_filter_by_status() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, db, _) = setup().await?;
+        seed_action_plans(&db).await?;
+
+        let plans = fetch_action_plans(app_state, "/admin/action-plans?status=done").await?;
+
+        let organizations = plans["organizations"].as_array().unwrap();
+        assert_eq!(organizations.len(), 1);
+        assert_eq!(organizations[0]["organization_name"], "Org A");
+        let recommendations = organizations[0]["recommendations"].as_array().unwrap();
+        assert_eq!(recommendations.len(), 1);
+        assert_eq!(recommendations[0]["recommendation"], "Train staff");
+        assert_eq!(recommendations[0]["status"], "done");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_action_plans_filter_without_matches_is_empty() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, db, _) = setup().await?;
+        seed_action_plans(&db).await?;
+
+        let plans = fetch_action_plans(app_state, "/admin/action-plans?status=approved").await?;
+
+        assert_eq!(plans["organizations"], json!([]));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_action_plan_summary_counts_statuses() -> Result<(), Box<dyn std::error::Error>> {
+        use axum::routing::get;
+
+        let (app_state, db, _) = setup().await?;
+        seed_action_plans(&db).await?;
+        let app = Router::new()
+            .route("/admin/action-plans/summary", get(summarize_action_plans))
+            .layer(Extension(claims("test-user-123", &["application_admin"])))
+            .with_state(app_state);
+
+        let response = app
+            .oneshot(Request::builder().uri("/admin/action-plans/summary").body(Body::empty())?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let summary: Value = serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+
+        let counts_for = |name: &str| {
+            summary["organizations"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|org| org["organization_name"] == name)
+                .map(|org| org["counts"].clone())
+        };
+        assert_eq!(
+            counts_for("Org A"),
+            Some(json!({ "todo": 1, "in_progress": 1, "done": 1, "approved": 0 }))
+        );
+        assert_eq!(
+            counts_for("Org B, Ltd."),
+            Some(json!({ "todo": 1, "in_progress": 0, "done": 0, "approved": 0 }))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_export_action_plans_csv_has_one_row_per_recommendation() -> Result<(), Box<dyn std::error::Error>> {
+        use axum::routing::get;
+
+        let (app_state, db, _) = setup().await?;
+        seed_action_plans(&db).await?;
+
+        let app = Router::new()
+            .route("/admin/action-plans", get(list_all_action_plans))
+            .route("/admin/action-plans/export/csv", get(export_action_plans_csv))
+            .layer(Extension(claims("test-user-123", &["application_admin"])))
+            .with_state(app_state);
+
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri("/admin/action-plans").body(Body::empty())?)
+            .await?;
+        let plans: Value = serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+        let total_recommendations: usize = plans["organizations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|org| org["recommendations"].as_array().unwrap().len())
+            .sum();
+        assert_eq!(total_recommendations, 4);
+
+        let response = app
+            .oneshot(Request::builder().uri("/admin/action-plans/export/csv").body(Body::empty())?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/csv; charset=utf-8");
+        let csv = String::from_utf8(axum::body::to_bytes(response.into_body(), usize::MAX).await?.to_vec())?;
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "org_name,assessment_name,category,recommendation,status,created_at");
+        assert_eq!(lines.len() - 1, total_recommendations);
+        assert!(lines.iter().any(|line| line.starts_with(
+            "\"Org B, Ltd.\",Yearly check,Governance,\"Adopt a \"\"code of conduct\"\"\",todo,"
+        )));
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_field_neutralizes_formulas() {
+        assert_eq!(csv_field("=HYPERLINK(\"http://evil\")"), "\"'=HYPERLINK(\"\"http://evil\"\")\"");
+        assert_eq!(csv_field("+1"), "'+1");
+        assert_eq!(csv_field("-1"), "'-1");
+        assert_eq!(csv_field("@SUM(A1)"), "'@SUM(A1)");
+        assert_eq!(csv_field("\tcmd"), "'\tcmd");
+        assert_eq!(csv_field("\rcmd"), "\"'\rcmd\"");
+        assert_eq!(csv_field("a-b=c"), "a-b=c");
+    }
+
+    #[tokio::test]
+    async fn test_export_action_plans_csv_requires_application_admin() -> Result<(), Box<dyn std::error::Error>> {
+        use axum::routing::get;
+
+        let (app_state, _, _) = setup().await?;
+        let app = Router::new()
+            .route("/admin/action-plans/export/csv", get(export_action_plans_csv))
+            .layer(Extension(claims("test-user-123", &["org_admin"])))
+            .with_state(app_state);
+
+        let response = app
+            .oneshot(Request::builder().uri("/admin/action-plans/export/csv").body(Body::empty())?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        Ok(())
+    }
+
+    /// A report of `org_id` with the given content, returning its id
+    async fn seed_report(
+        db: &DatabaseConnection,
+        org_id: &str,
+        status: &str,
+        generated_at: chrono::DateTime<chrono::Utc>,
+        data: Value,
+    ) -> Result<Uuid, Box<dyn std::error::Error>> {
+        use crate::common::database::entity::submission_reports;
+        use sea_orm::{ActiveModelTrait, Set};
+
+        let submission_id = Uuid::new_v4();
+        assessments_submission::ActiveModel {
+            submission_id: Set(submission_id),
+            org_id: Set(org_id.to_string()),
+            org_name: Set(org_id.to_string()),
+            content: Set(json!({})),
+            submitted_at: Set(generated_at),
+            status: Set(SubmissionStatus::Reviewed),
+            reviewed_at: Set(Some(generated_at)),
+            reopened_at: Set(None),
+            reopened_by: Set(None),
+        }
+        .insert(db)
+        .await?;
+        let report_id = Uuid::new_v4();
+        submission_reports::ActiveModel {
+            report_id: Set(report_id),
+            submission_id: Set(submission_id),
+            report_type: Set("sustainability".to_string()),
+            status: Set(status.to_string()),
+            generated_at: Set(generated_at),
+            data: Set(Some(data)),
+        }
+        .insert(db)
+        .await?;
+        Ok(report_id)
+    }
+
+    /// Single-question report data answered with `percentage`, one entry per category
+    fn scored_report_data(categories: &[(&str, f64)]) -> Value {
+        Value::Array(
+            categories
+                .iter()
+                .map(|(category, percentage)| {
+                    json!({ *category: { "questions": [
+                        { "question": "Policy?", "answer": { "yesNo": true, "percentage": percentage } }
+                    ] } })
+                })
+                .collect(),
+        )
+    }
+
+    /// One report per organization scoring `percentage` in Environmental, returning the report ids
+    async fn seed_benchmark_reports(
+        db: &DatabaseConnection,
+        scores: &[(&str, f64)],
+        generated_at: chrono::DateTime<chrono::Utc>,
+    ) -> Result<Vec<Uuid>, Box<dyn std::error::Error>> {
+        let mut report_ids = Vec::new();
+        for (org_id, percentage) in scores {
+            let data = scored_report_data(&[("Environmental", *percentage)]);
+            report_ids.push(seed_report(db, org_id, "generated", generated_at, data).await?);
+        }
+        Ok(report_ids)
+    }
+
+    async fn fetch_benchmark(
+        app_state: AppState,
+        claims: Claims,
+        report_id: Uuid,
+    ) -> Result<(StatusCode, Value), Box<dyn std::error::Error>> {
+        use axum::routing::get;
+
+        let app = Router::new()
+            .route("/reports/:report_id/benchmark", get(get_report_benchmark))
+            .layer(Extension(claims))
+            .with_state(app_state);
+        let response = app
+            .oneshot(Request::builder().uri(format!("/reports/{report_id}/benchmark")).body(Body::empty())?)
+            .await?;
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok((status, serde_json::from_slice(&body).unwrap_or(Value::Null)))
+    }
+
+    #[tokio::test]
+    async fn test_benchmark_below_privacy_floor_is_insufficient() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, db, _) = setup().await?;
+        let report_ids = seed_benchmark_reports(
+            &db,
+            &[("org-a", 20.0), ("org-b", 40.0), ("org-c", 60.0), ("org-d", 80.0)],
+            chrono::Utc::now(),
+        )
+        .await?;
+        // Reports older than a year are not part of the pool
+        seed_benchmark_reports(&db, &[("org-e", 50.0)], chrono::Utc::now() - chrono::Duration::days(400)).await?;
+
+        let (status, body) = fetch_benchmark(app_state, org_claims("test-user-123", &["Org_User"], &format!("{} name", "org-a"), "org-a"), report_ids[0]).await?;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body, json!({ "insufficient_data": true }));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_benchmark_percentiles() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, db, _) = setup().await?;
+        let now = chrono::Utc::now();
+        // org-c's older report is superseded by its latest one
+        seed_benchmark_reports(&db, &[("org-c", 100.0)], now - chrono::Duration::days(30)).await?;
+        let report_ids = seed_benchmark_reports(
+            &db,
+            &[("org-a", 20.0), ("org-b", 40.0), ("org-c", 60.0), ("org-d", 60.0), ("org-e", 80.0)],
+            now,
+        )
+        .await?;
+
+        let (status, body) = fetch_benchmark(app_state.clone(), org_claims("test-user-123", &["Org_User"], &format!("{} name", "org-c"), "org-c"), report_ids[2]).await?;
+        assert_eq!(status, StatusCode::OK);
+        let benchmark: BenchmarkResponse = serde_json::from_value(body)?;
+        assert_eq!(benchmark.org_scores.len(), 1);
+        assert!((benchmark.org_scores[0].score - 60.0).abs() < 1e-9);
+        assert_eq!(benchmark.sector_averages[0].category, "Environmental");
+        assert!((benchmark.sector_averages[0].score - 52.0).abs() < 1e-9);
+        // 2 organizations below, 2 (including itself) tied: (2 + 2 / 2) / 5
+        assert!((benchmark.percentile_ranks[0].percentile - 60.0).abs() < 1e-9);
+
+        let (_, body) = fetch_benchmark(app_state.clone(), org_claims("test-user-123", &["Org_User"], &format!("{} name", "org-a"), "org-a"), report_ids[0]).await?;
+        let benchmark: BenchmarkResponse = serde_json::from_value(body)?;
+        // Nobody below, only itself tied: (0 + 1 / 2) / 5
+        assert!((benchmark.percentile_ranks[0].percentile - 10.0).abs() < 1e-9);
+
+        let (status, _) = fetch_benchmark(app_state, org_claims("test-user-123", &["Org_User"], &format!("{} name", "org-b"), "org-b"), report_ids[0]).await?;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_organization_statistics_average_completed_reports() -> Result<(), Box<dyn std::error::Error>> {
+        use axum::routing::get;
+
+        let (app_state, db, _) = setup().await?;
+        let now = chrono::Utc::now();
+        let data = scored_report_data(&[("Environmental", 40.0), ("Social", 30.0)]);
+        seed_report(&db, "org-a", "completed", now, data).await?;
+        let data = scored_report_data(&[("Environmental", 80.0)]);
+        seed_report(&db, "org-a", "completed", now, data).await?;
+        // Neither a report still being generated nor another organization's report counts
+        let data = scored_report_data(&[("Environmental", 0.0)]);
+        seed_report(&db, "org-a", "generating", now, data).await?;
+        let data = scored_report_data(&[("Environmental", 100.0)]);
+        seed_report(&db, "org-b", "completed", now, data).await?;
+
+        let app = Router::new()
+            .route("/organizations/:org_id/statistics", get(get_organization_statistics))
+            .layer(Extension(org_claims("test-user-123", &["Org_User"], &format!("{} name", "org-a"), "org-a")))
+            .with_state(app_state);
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri("/organizations/org-a/statistics").body(Body::empty())?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let statistics: OrganizationStatisticsResponse =
+            serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+
+        assert_eq!(statistics.report_count, 2);
+        assert_eq!(statistics.categories.len(), 2);
+        assert_eq!(statistics.categories[0].category, "Environmental");
+        assert!((statistics.categories[0].average_score - 60.0).abs() < 1e-9);
+        assert_eq!(statistics.categories[0].report_count, 2);
+        assert_eq!(statistics.categories[1].category, "Social");
+        assert!((statistics.categories[1].average_score - 30.0).abs() < 1e-9);
+        assert_eq!(statistics.categories[1].report_count, 1);
+
+        let response = app
+            .oneshot(Request::builder().uri("/organizations/org-b/statistics").body(Body::empty())?)
+            .await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        Ok(())
+    }
+
+    /// Sharing organizations: one fully scored, one partly, one without a report; the
+    /// scores of `org-private` are there but it does not share them
+    fn matrix_fixture() -> (Vec<(String, String)>, std::collections::BTreeMap<String, CategoryScores>) {
+        let organizations = [("org-a", "Alpha"), ("org-b", "Beta"), ("org-c", "Gamma")]
+            .map(|(id, name)| (id.to_string(), name.to_string()))
+            .to_vec();
+        let scores = |pairs: &[(&str, f64)]| -> CategoryScores {
+            pairs.iter().map(|(category, score)| (category.to_string(), *score)).collect()
+        };
+        let scores_by_org = std::collections::BTreeMap::from([
+            ("org-a".to_string(), scores(&[("Environmental", 80.0), ("Social", 60.0)])),
+            ("org-b".to_string(), scores(&[("Environmental", 90.0)])),
+            ("org-private".to_string(), scores(&[("Governance", 100.0)])),
+        ]);
+        (organizations, scores_by_org)
+    }
+
+    #[test]
+    fn test_comparison_matrix_sorts_by_average_score() {
+        let (organizations, scores_by_org) = matrix_fixture();
+
+        let matrix = comparison_matrix(None, &organizations, &scores_by_org);
+
+        assert_eq!(matrix.categories, vec!["Environmental", "Social"]);
+        let rows: Vec<(&str, &[Option<f64>])> =
+            matrix.rows.iter().map(|row| (row.org_id.as_str(), row.scores.as_slice())).collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("org-b", &[Some(90.0), None][..]),
+                ("org-a", &[Some(80.0), Some(60.0)][..]),
+                ("org-c", &[None, None][..]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_comparison_matrix_with_chosen_categories() {
+        let (organizations, scores_by_org) = matrix_fixture();
+        let categories = vec!["Social".to_string(), "Governance".to_string()];
+
+        let matrix = comparison_matrix(Some(categories.clone()), &organizations, &scores_by_org);
+
+        assert_eq!(matrix.categories, categories);
+        // Beta and Gamma have no score in these categories and keep name order
+        let rows: Vec<(&str, &[Option<f64>])> =
+            matrix.rows.iter().map(|row| (row.org_name.as_str(), row.scores.as_slice())).collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("Alpha", &[Some(60.0), None][..]),
+                ("Beta", &[None, None][..]),
+                ("Gamma", &[None, None][..]),
+            ]
+        );
+
+        let matrix = comparison_matrix(None, &[], &scores_by_org);
+        assert!(matrix.categories.is_empty());
+        assert!(matrix.rows.is_empty());
+    }
+
+    #[test]
+    fn test_comparison_matrix_csv() {
+        let matrix = ComparisonMatrix {
+            categories: vec!["Environmental".to_string(), "Social, Community".to_string()],
+            rows: vec![
+                OrgRow {
+                    org_id: "org-a".to_string(),
+                    org_name: "Alpha \"Coop\"".to_string(),
+                    scores: vec![Some(80.0), Some(66.666)],
+                },
+                OrgRow {
+                    org_id: "org-c".to_string(),
+                    org_name: "Gamma".to_string(),
+                    scores: vec![None, None],
+                },
+                OrgRow {
+                    org_id: "org-d".to_string(),
+                    org_name: "=cmd|' /C calc'!A0".to_string(),
+                    scores: vec![Some(-5.0), None],
+                },
+            ],
+        };
+
+        assert_eq!(
+            comparison_matrix_csv(&matrix),
+            "org_id,org_name,Environmental,\"Social, Community\"\n\
+             org-a,\"Alpha \"\"Coop\"\"\",80.00,66.67\n\
+             org-c,Gamma,,\n\
+             org-d,'=cmd|' /C calc'!A0,-5.00,\n"
+        );
+    }
 }
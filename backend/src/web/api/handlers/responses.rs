@@ -1,15 +1,22 @@
 use axum::{
-    extract::{Extension, Path, State},
-    http::StatusCode,
+    extract::{Extension, Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
     response::IntoResponse,
     Json,
 };
+use sea_orm::DbErr;
 use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
 
+use crate::common::database::entity::assessments_response::{
+    self, assessment_responses_etag, VersionConflictError,
+};
 use crate::common::models::claims::Claims;
+use crate::web::api::handlers::assessments::expert_assigned_categories;
 use crate::web::routes::AppState;
 use crate::web::api::error::ApiError;
+use crate::web::api::strict_json::StrictJson;
 use crate::web::api::models::*;
 
 #[derive(Debug, Serialize)]
@@ -25,6 +32,21 @@ pub struct ResponseVersion {
     pub updated_at: String,
 }
 
+/// Reject edits to an assessment whose responses have been frozen by a submission
+pub(crate) async fn ensure_assessment_unlocked(app_state: &AppState, assessment_id: Uuid) -> Result<(), ApiError> {
+    let locked = app_state
+        .database
+        .assessments
+        .is_assessment_locked(assessment_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to check submission status: {e}")))?;
+
+    if locked {
+        return Err(ApiError::Conflict("Assessment is locked after submission".to_string()));
+    }
+    Ok(())
+}
+
 // Helper function to convert file::Model to FileMetadata
 async fn convert_file_model_to_metadata(
     file_model: crate::common::database::entity::file::Model,
@@ -88,6 +110,30 @@ async fn fetch_files_for_response(
     Ok(files)
 }
 
+/// API form of a stored response, with its answer decrypted and its files attached
+async fn to_api_response(
+    app_state: &AppState,
+    response_model: assessments_response::Model,
+) -> Result<Response, ApiError> {
+    let files = fetch_files_for_response(app_state, response_model.response_id).await?;
+    let response_model = app_state
+        .database
+        .assessments_response
+        .decrypt(response_model)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decrypt response: {e}")))?;
+
+    Ok(Response {
+        response_id: response_model.response_id,
+        assessment_id: response_model.assessment_id,
+        question_revision_id: response_model.question_revision_id,
+        // Store response as single string instead of array
+        response: vec![response_model.response],
+        version: response_model.version,
+        updated_at: response_model.updated_at.to_rfc3339(),
+        files,
+    })
+}
+
 /// List latest responses for an assessment
 #[utoipa::path(
     get,
@@ -95,7 +141,8 @@ async fn fetch_files_for_response(
     tag = "Response",
     params(("assessment_id" = uuid::Uuid, Path, description = "Assessment ID")),
     responses(
-        (status = 200, description = "Responses list", body = ResponseListResponse),
+        (status = 200, description = "Responses list", body = ResponseListResponse,
+            headers(("ETag" = String, description = "Version of the assessment's responses, send back as If-Match when updating"))),
         (status = 404, description = "Assessment not found")
     )
 )]
@@ -103,7 +150,7 @@ pub async fn list_responses(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path(assessment_id): Path<Uuid>,
-) -> Result<Json<ResponseListResponse>, ApiError> {
+) -> Result<impl IntoResponse, ApiError> {
     let org_id = claims.get_org_id()
         .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
 
@@ -143,25 +190,123 @@ pub async fn list_responses(
         .await
         .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch responses: {e}")))?;
 
+    let etag = assessment_responses_etag(&response_models);
+
     // Convert database models to API models
     let mut responses = Vec::new();
     for response_model in response_models {
-        let files = fetch_files_for_response(&app_state, response_model.response_id).await?;
-        // Store response as single string instead of array
-        let response_array = vec![response_model.response.clone()];
-
-        responses.push(Response {
-            response_id: response_model.response_id,
-            assessment_id: response_model.assessment_id,
-            question_revision_id: response_model.question_revision_id,
-            response: response_array,
-            version: response_model.version,
-            updated_at: response_model.updated_at.to_rfc3339(),
-            files,
-        });
+        responses.push(to_api_response(&app_state, response_model).await?);
+    }
+
+    Ok(([(header::ETAG, etag)], Json(ResponseListResponse { responses })))
+}
+
+/// Compare the answers as they stood at version `from` with those at version `to`.
+///
+/// A question's answer at a version is its latest row with a version up to that one,
+/// so questions left untouched in between carry their answer over. Questions answered
+/// identically at both are left out. When a question has several rows with the same
+/// version, the most recently written one counts.
+fn diff_response_versions(
+    rows: Vec<assessments_response::Model>,
+    from: i32,
+    to: i32,
+) -> Vec<ResponseChange> {
+    let mut by_question: BTreeMap<Uuid, (Option<assessments_response::Model>, Option<assessments_response::Model>)> =
+        BTreeMap::new();
+    for row in rows {
+        let entry = by_question.entry(row.question_revision_id).or_default();
+        for (version, slot) in [(from, &mut entry.0), (to, &mut entry.1)] {
+            if row.version > version {
+                continue;
+            }
+            let newer = slot
+                .as_ref()
+                .is_none_or(|current| (row.version, row.updated_at) > (current.version, current.updated_at));
+            if newer {
+                *slot = Some(row.clone());
+            }
+        }
     }
 
-    Ok(Json(ResponseListResponse { responses }))
+    by_question
+        .into_iter()
+        .filter_map(|(question_revision_id, (old, new))| {
+            let old_value = old.map(|row| row.response);
+            let new_value = new.map(|row| row.response);
+            let change = match (&old_value, &new_value) {
+                (None, Some(_)) => ResponseChangeKind::Added,
+                (Some(_), None) => ResponseChangeKind::Removed,
+                (Some(old), Some(new)) if old != new => ResponseChangeKind::Changed,
+                _ => return None,
+            };
+            Some(ResponseChange {
+                question_revision_id,
+                change,
+                old_value,
+                new_value,
+            })
+        })
+        .collect()
+}
+
+/// Show which answers differ between two response versions of an assessment
+#[utoipa::path(
+    get,
+    path = "/assessments/{assessment_id}/responses/diff",
+    tag = "Response",
+    params(
+        ("assessment_id" = uuid::Uuid, Path, description = "Assessment ID"),
+        ("from" = i32, Query, description = "Version to compare from"),
+        ("to" = i32, Query, description = "Version to compare to")
+    ),
+    responses(
+        (status = 200, description = "Answers added, removed or changed between the versions", body = ResponseDiffResponse),
+        (status = 400, description = "Invalid version numbers"),
+        (status = 404, description = "Assessment not found")
+    )
+)]
+pub async fn diff_responses(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(assessment_id): Path<Uuid>,
+    Query(query): Query<ResponseDiffQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    claims.get_org_id()
+        .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
+
+    if query.from < 1 || query.to < 1 || query.from == query.to {
+        return Err(ApiError::BadRequest(
+            "from and to must be two different versions, starting at 1".to_string(),
+        ));
+    }
+
+    // Same access rules as list_responses: the assessment ID is enough to read it
+    app_state
+        .database
+        .assessments
+        .get_assessment_by_id(assessment_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessment: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Assessment not found".to_string()))?;
+
+    let rows = app_state
+        .database
+        .assessments_response
+        .get_responses_up_to_version(assessment_id, query.from.max(query.to))
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch responses: {e}")))?
+        .into_iter()
+        .map(|row| app_state.database.assessments_response.decrypt(row))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decrypt response: {e}")))?;
+
+    Ok(Json(ResponseDiffResponse {
+        assessment_id,
+        from: query.from,
+        to: query.to,
+        changes: diff_response_versions(rows, query.from, query.to),
+    }))
 }
 
 /// Create or update responses for an assessment
@@ -174,18 +319,23 @@ pub async fn list_responses(
     responses(
         (status = 201, description = "Responses stored", body = ResponseListResponse),
         (status = 400, description = "Validation or permission error"),
-        (status = 404, description = "Assessment not found")
+        (status = 403, description = "Org expert answering outside their assigned categories"),
+        (status = 404, description = "Assessment not found"),
+        (status = 409, description = "Assessment is locked after submission")
     )
 )]
 pub async fn create_response(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
     Path(assessment_id): Path<Uuid>,
-    Json(requests): Json<Vec<CreateResponseRequest>>,
+    StrictJson(requests): StrictJson<Vec<CreateResponseRequest>>,
 ) -> Result<impl IntoResponse, ApiError> {
     let org_id = claims.get_org_id()
         .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
 
+    ensure_assessment_unlocked(&app_state, assessment_id).await?;
+
     // Validate requests
     if requests.is_empty() {
         return Err(ApiError::BadRequest(
@@ -204,7 +354,7 @@ pub async fn create_response(
     // Check if user has permission to answer assessments
     if !claims.can_answer_assessments() {
         return Err(ApiError::BadRequest(
-            "You don't have permission to answer assessments. Only Org_User and org_admin roles can answer assessments.".to_string(),
+            "You don't have permission to answer assessments. Only Org_User, Org_Expert and org_admin roles can answer assessments.".to_string(),
         ));
     }
 
@@ -234,9 +384,7 @@ pub async fn create_response(
         // The role check above ensures only authorized users can access this functionality
     }
 
-    // Allow multiple users to answer the same assessment even if it has been submitted
-    // This enables the use case where org_admin creates assessments and multiple Org_User users
-    // can answer and submit responses to the same assessment
+    ensure_assigned_categories(&app_state, &claims, &token, &requests).await?;
 
     // Get existing responses for this assessment
     let existing_responses = app_state
@@ -286,26 +434,55 @@ pub async fn create_response(
     // Convert database models to API models
     let mut responses = Vec::new();
     for response_model in updated_responses {
-        let files = fetch_files_for_response(&app_state, response_model.response_id).await?;
-
-        // Store response as single string instead of array
-        let response_array = vec![response_model.response.clone()];
-
-        let response = Response {
-            response_id: response_model.response_id,
-            assessment_id: response_model.assessment_id,
-            question_revision_id: response_model.question_revision_id,
-            response: response_array,
-            version: response_model.version,
-            updated_at: response_model.updated_at.to_rfc3339(),
-            files,
-        };
-        responses.push(response);
+        responses.push(to_api_response(&app_state, response_model).await?);
     }
 
     Ok((StatusCode::CREATED, Json(ResponseListResponse { responses })))
 }
 
+/// Org experts only answer the questions of the categories assigned to them
+async fn ensure_assigned_categories(
+    app_state: &AppState,
+    claims: &Claims,
+    token: &str,
+    requests: &[CreateResponseRequest],
+) -> Result<(), ApiError> {
+    use crate::common::database::entity::{questions, questions_revisions};
+    use sea_orm::{ColumnTrait, EntityTrait, JoinType, QueryFilter, QuerySelect, RelationTrait};
+
+    let Some(allowed) = expert_assigned_categories(app_state, claims, token).await? else {
+        return Ok(());
+    };
+
+    let question_categories: HashMap<Uuid, Uuid> = questions_revisions::Entity::find()
+        .select_only()
+        .column(questions_revisions::Column::QuestionRevisionId)
+        .column(questions::Column::CategoryId)
+        .join(JoinType::InnerJoin, questions_revisions::Relation::Question.def())
+        .filter(
+            questions_revisions::Column::QuestionRevisionId
+                .is_in(requests.iter().map(|request| request.question_revision_id)),
+        )
+        .into_tuple::<(Uuid, Uuid)>()
+        .all(app_state.database.get_connection())
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch question categories: {e}")))?
+        .into_iter()
+        .collect();
+
+    let outside_assignment = requests.iter().any(|request| {
+        !question_categories
+            .get(&request.question_revision_id)
+            .is_some_and(|category_id| allowed.contains(category_id))
+    });
+    if outside_assignment {
+        return Err(ApiError::Forbidden(
+            "Org experts can only answer questions of the categories assigned to them".to_string(),
+        ));
+    }
+    Ok(())
+}
+
 /// Get a response by ID
 #[utoipa::path(
     get,
@@ -377,20 +554,7 @@ pub async fn get_response(
     }
 
     // Convert database model to API model
-    let files = fetch_files_for_response(&app_state, response_model.response_id).await?;
-
-    // Store response as single string instead of array
-    let response_array = vec![response_model.response.clone()];
-
-    let response = Response {
-        response_id: response_model.response_id,
-        assessment_id: response_model.assessment_id,
-        question_revision_id: response_model.question_revision_id,
-        response: response_array,
-        version: response_model.version,
-        updated_at: response_model.updated_at.to_rfc3339(),
-        files,
-    };
+    let response = to_api_response(&app_state, response_model).await?;
 
     Ok(Json(ResponseResponse { response }))
 }
@@ -402,24 +566,39 @@ pub async fn get_response(
     tag = "Response",
     params(
         ("assessment_id" = uuid::Uuid, Path, description = "Assessment ID"),
-        ("response_id" = uuid::Uuid, Path, description = "Response ID")
+        ("response_id" = uuid::Uuid, Path, description = "Response ID"),
+        ("If-Match" = String, Header, description = "ETag returned by the responses listing")
     ),
     request_body = UpdateResponseRequest,
     responses(
         (status = 200, description = "Response updated", body = ResponseResponse),
         (status = 400, description = "Validation error"),
-        (status = 404, description = "Not found")
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Assessment is locked after submission"),
+        (status = 412, description = "Responses changed since the ETag was issued"),
+        (status = 428, description = "Missing If-Match header")
     )
 )]
 pub async fn update_response(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
     Path((assessment_id, response_id)): Path<(Uuid, Uuid)>,
-    Json(request): Json<UpdateResponseRequest>,
-) -> Result<Json<ResponseResponse>, ApiError> {
+    headers: HeaderMap,
+    StrictJson(request): StrictJson<UpdateResponseRequest>,
+) -> Result<impl IntoResponse, ApiError> {
     let org_id = claims.get_org_id()
         .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
 
+    ensure_assessment_unlocked(&app_state, assessment_id).await?;
+
+    let if_match = headers
+        .get(header::IF_MATCH)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_string())
+        .ok_or_else(|| {
+            ApiError::PreconditionRequired("If-Match header is required".to_string())
+        })?;
+
     // Validate request
     if request.response.is_empty() || request.response.iter().all(|s| s.trim().is_empty()) {
         return Err(ApiError::BadRequest(
@@ -455,23 +634,6 @@ pub async fn update_response(
         // ));
     }
 
-    // Verify that the assessment is in draft status (not submitted)
-    let has_submission = app_state
-        .database
-        .assessments_submission
-        .get_submission_by_assessment_id(assessment_id)
-        .await
-        .map_err(|e| {
-            ApiError::InternalServerError(format!("Failed to check submission status: {e}"))
-        })?
-        .is_some();
-
-    if has_submission {
-        return Err(ApiError::BadRequest(
-            "Cannot update response for a submitted assessment".to_string(),
-        ));
-    }
-
     // Fetch the existing response to get the question_revision_id
     let existing_response = app_state
         .database
@@ -502,131 +664,653 @@ pub async fn update_response(
     // Convert Vec<String> to single string for database storage (take first response)
     let response_json = request.response.first().unwrap_or(&String::new()).clone();
 
-    // Update the response in the database
+    // Update the response in the database, rejecting the save if someone else got there first
     let updated_response = app_state
         .database
         .assessments_response
-        .update_response(
-            assessment_id,
-            existing_response.question_revision_id,
-            response_json,
-        )
+        .update_response_with_version(response_id, &if_match, &response_json)
         .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to update response: {e}")))?;
+        .map_err(|e| match e {
+            VersionConflictError::NotFound => ApiError::NotFound("Response not found".to_string()),
+            VersionConflictError::Conflict { .. } => ApiError::PreconditionFailed(e.to_string()),
+            VersionConflictError::Database(e) => {
+                ApiError::InternalServerError(format!("Failed to update response: {e}"))
+            }
+        })?;
+
+    let etag = assessment_responses_etag(std::slice::from_ref(&updated_response));
 
     // Convert database model to API model
-    let files = fetch_files_for_response(&app_state, updated_response.response_id).await?;
-
-    // Store response as single string instead of array
-    let response_array = vec![updated_response.response.clone()];
-
-    let response = Response {
-        response_id: updated_response.response_id,
-        assessment_id: updated_response.assessment_id,
-        question_revision_id: updated_response.question_revision_id,
-        response: response_array,
-        version: updated_response.version,
-        updated_at: updated_response.updated_at.to_rfc3339(),
-        files,
-    };
+    let response = to_api_response(&app_state, updated_response).await?;
 
-    Ok(Json(ResponseResponse { response }))
+    Ok(([(header::ETAG, etag)], Json(ResponseResponse { response })))
 }
 
-/// Delete a response by ID
+/// Delete the answer to a single question
+///
+/// Removes every version of the response to the question revision along with its
+/// file links. Uploaded files are kept so they can be attached again.
 #[utoipa::path(
     delete,
-    path = "/assessments/{assessment_id}/responses/{response_id}",
+    path = "/assessments/{assessment_id}/responses/{question_revision_id}",
     tag = "Response",
     params(
         ("assessment_id" = uuid::Uuid, Path, description = "Assessment ID"),
-        ("response_id" = uuid::Uuid, Path, description = "Response ID")
+        ("question_revision_id" = uuid::Uuid, Path, description = "Question revision ID")
     ),
     responses(
         (status = 204, description = "Response deleted"),
-        (status = 404, description = "Not found")
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Assessment is locked after submission")
     )
 )]
 pub async fn delete_response(
     State(app_state): State<AppState>,
     Extension(claims): Extension<Claims>,
-    Path((assessment_id, response_id)): Path<(Uuid, Uuid)>,
+    Path((assessment_id, question_revision_id)): Path<(Uuid, Uuid)>,
 ) -> Result<StatusCode, ApiError> {
-    let org_id = claims.get_org_id()
+    ensure_draft_assessment(&app_state, &claims, assessment_id).await?;
+
+    app_state
+        .database
+        .assessments_response
+        .delete_response_by_assessment_and_question(assessment_id, question_revision_id)
+        .await
+        .map_err(|e| match e {
+            DbErr::RecordNotFound(message) => ApiError::NotFound(message),
+            e => ApiError::InternalServerError(format!("Failed to delete response: {e}")),
+        })?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Delete all answers of a draft assessment
+///
+/// Uploaded files are kept; only their links to the removed responses go away.
+#[utoipa::path(
+    delete,
+    path = "/assessments/{assessment_id}/responses",
+    tag = "Response",
+    params(("assessment_id" = uuid::Uuid, Path, description = "Assessment ID")),
+    responses(
+        (status = 204, description = "Responses deleted"),
+        (status = 404, description = "Assessment not found"),
+        (status = 409, description = "Assessment is locked after submission")
+    )
+)]
+pub async fn delete_all_responses(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(assessment_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    ensure_draft_assessment(&app_state, &claims, assessment_id).await?;
+
+    app_state
+        .database
+        .assessments_response
+        .delete_responses_by_assessment(assessment_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to delete responses: {e}")))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Responses can only be removed from an existing assessment that has not been submitted
+async fn ensure_draft_assessment(
+    app_state: &AppState,
+    claims: &Claims,
+    assessment_id: Uuid,
+) -> Result<(), ApiError> {
+    claims.get_org_id()
         .ok_or_else(|| ApiError::BadRequest("No organization ID found in token".to_string()))?;
 
-    // Verify that the current organization is the owner of the assessment
-    let assessment_model = app_state
+    ensure_assessment_unlocked(app_state, assessment_id).await?;
+
+    // Assessments are shared by id, so any organization may edit an existing one
+    app_state
         .database
         .assessments
         .get_assessment_by_id(assessment_id)
         .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessment: {e}")))?;
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch assessment: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Assessment not found".to_string()))?;
 
-    let assessment_model = match assessment_model {
-        Some(a) => a,
-        None => return Err(ApiError::NotFound("Assessment not found".to_string())),
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{keycloak_config, org_claims, unreachable_keycloak};
+    use crate::common::database::entity::{
+        assessments, assessments_response,
+        assessments_submission::{self, SubmissionStatus},
+        questions, questions_revisions,
     };
+    use crate::common::state::AppDatabase;
+    use axum::{body::Body, http::Request, routing::put, Router};
+    use chrono::Utc;
+    use sea_orm::{ActiveModelTrait, ConnectionTrait, Database, Schema, Set};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_update_response_after_submission_conflicts() -> Result<(), Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(questions::Entity),
+            schema.create_table_from_entity(questions_revisions::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
 
-    // Allow access to assessments in the following cases:
-    // 1. User owns the assessment (same org_id)
-    // 2. User is a super user (can access any assessment)
-    // 3. Any user can access any assessment if they have the assessment_id (shared assessments)
-    let is_owner = assessment_model.org_id == org_id;
-    let is_super_user = claims.is_super_user();
+        let assessment_id = Uuid::new_v4();
+        assessments::ActiveModel {
+            assessment_id: Set(assessment_id),
+            org_id: Set("test-org".to_string()),
+            language: Set("en".to_string()),
+            name: Set("Submitted assessment".to_string()),
+            created_at: Set(Utc::now()),
+        }
+        .insert(&db)
+        .await?;
+        let response = assessments_response::ActiveModel {
+            response_id: Set(Uuid::new_v4()),
+            assessment_id: Set(assessment_id),
+            question_revision_id: Set(Uuid::new_v4()),
+            response: Set("yes".to_string()),
+            version: Set(1),
+            updated_at: Set(Utc::now()),
+            key_version: Set(None),
+        }
+        .insert(&db)
+        .await?;
+        assessments_submission::ActiveModel {
+            submission_id: Set(assessment_id),
+            org_id: Set("test-org".to_string()),
+            org_name: Set("Test Organization".to_string()),
+            content: Set(serde_json::json!({})),
+            submitted_at: Set(Utc::now()),
+            status: Set(SubmissionStatus::UnderReview),
+            reviewed_at: Set(None),
+            reopened_at: Set(None),
+            reopened_by: Set(None),
+        }
+        .insert(&db)
+        .await?;
 
-    if !is_owner && !is_super_user {
-        // Allow access to any assessment - this enables the sharing use case
-        // Comment out the permission check to enable sharing
-        // return Err(ApiError::BadRequest(
-        //     "You don't have permission to access this assessment".to_string(),
-        // ));
+        let app_state = AppState::new(
+            keycloak_config("http://localhost:8080"),
+            AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+
+        let app = Router::new()
+            .route(
+                "/assessments/:assessment_id/responses/:response_id",
+                put(update_response),
+            )
+            .layer(Extension(org_claims("test-user-123", &["Org_User"], "Test Organization", "test-org")))
+            .with_state(app_state);
+        let http_response = app
+            .oneshot(
+                Request::builder()
+                    .method("PUT")
+                    .uri(format!("/assessments/{assessment_id}/responses/{}", response.response_id))
+                    .header("content-type", "application/json")
+                    .header(header::IF_MATCH, assessment_responses_etag(std::slice::from_ref(&response)))
+                    .body(Body::from(
+                        serde_json::json!({ "response": ["no"], "version": 1 }).to_string(),
+                    ))?,
+            )
+            .await?;
+
+        assert_eq!(http_response.status(), StatusCode::CONFLICT);
+        let body = axum::body::to_bytes(http_response.into_body(), usize::MAX).await?;
+        assert!(String::from_utf8(body.to_vec())?.contains("Assessment is locked after submission"));
+
+        Ok(())
     }
 
-    // Verify that the assessment is in draft status (not submitted)
-    let has_submission = app_state
-        .database
-        .assessments_submission
-        .get_submission_by_assessment_id(assessment_id)
-        .await
-        .map_err(|e| {
-            ApiError::InternalServerError(format!("Failed to check submission status: {e}"))
-        })?
-        .is_some();
+    #[tokio::test]
+    async fn test_answers_to_sensitive_questions_are_returned_decrypted() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::{assessments_response_file, file};
+        use crate::common::services::encryption::EncryptionService;
+        use axum::routing::{get, post};
+        use sea_orm::EntityTrait;
+
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(questions::Entity),
+            schema.create_table_from_entity(questions_revisions::Entity),
+            schema.create_table_from_entity(assessments_response_file::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(file::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
 
-    if has_submission {
-        return Err(ApiError::BadRequest(
-            "Cannot delete response for a submitted assessment".to_string(),
-        ));
+        let assessment_id = Uuid::new_v4();
+        assessments::ActiveModel {
+            assessment_id: Set(assessment_id),
+            org_id: Set("test-org".to_string()),
+            language: Set("en".to_string()),
+            name: Set("Draft assessment".to_string()),
+            created_at: Set(Utc::now()),
+        }
+        .insert(&db)
+        .await?;
+        let question_id = Uuid::new_v4();
+        questions::ActiveModel {
+            question_id: Set(question_id),
+            category_id: Set(Uuid::new_v4()),
+            created_at: Set(Utc::now()),
+            external_key: Set(None),
+            skip_rules: Set(serde_json::json!([])),
+            sensitive: Set(true),
+        }
+        .insert(&db)
+        .await?;
+        let question_revision_id = Uuid::new_v4();
+        questions_revisions::ActiveModel {
+            question_revision_id: Set(question_revision_id),
+            question_id: Set(question_id),
+            text: Set(serde_json::json!({ "en": "Annual revenue?" })),
+            weight: Set(1.0),
+            created_at: Set(Utc::now()),
+        }
+        .insert(&db)
+        .await?;
+
+        let app_database = AppDatabase::new(Arc::new(db))
+            .await
+            .with_encryption(Arc::new(EncryptionService::new([6; 32])));
+        let app_state = AppState::new(unreachable_keycloak(), app_database).await;
+        let app = Router::new()
+            .route("/assessments/:assessment_id/responses", post(create_response).get(list_responses))
+            .route("/assessments/:assessment_id/responses/diff", get(diff_responses))
+            .route(
+                "/assessments/:assessment_id/responses/:response_id",
+                get(get_response).put(update_response),
+            )
+            .layer(Extension(org_claims("test-user-123", &["Org_User"], "Test Organization", "test-org")))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state.clone());
+        let send = |method: &str, uri: String, headers: Vec<(header::HeaderName, String)>, body: Option<serde_json::Value>| {
+            let mut request = Request::builder().method(method).uri(uri);
+            for (name, value) in headers {
+                request = request.header(name, value);
+            }
+            let request = match body {
+                Some(body) => request
+                    .header(header::CONTENT_TYPE, "application/json")
+                    .body(Body::from(body.to_string())),
+                None => request.body(Body::empty()),
+            };
+            let app = app.clone();
+            async move {
+                let response = app.oneshot(request.unwrap()).await.unwrap();
+                let status = response.status();
+                let etag = response.headers().get(header::ETAG).map(|etag| etag.to_str().unwrap().to_string());
+                let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                (status, etag, serde_json::from_slice::<serde_json::Value>(&body).unwrap_or_default())
+            }
+        };
+
+        let base = format!("/assessments/{assessment_id}/responses");
+        let (status, _, created) = send(
+            "POST",
+            base.clone(),
+            vec![],
+            Some(serde_json::json!([{ "question_revision_id": question_revision_id, "response": "1.2M EUR" }])),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["responses"][0]["response"][0], "1.2M EUR");
+
+        // Stored encrypted
+        let stored = assessments_response::Entity::find().one(app_state.database.get_connection()).await?.unwrap();
+        assert!(stored.key_version.is_some());
+        assert_ne!(stored.response, "1.2M EUR");
+
+        let (status, etag, listed) = send("GET", base.clone(), vec![], None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(listed["responses"][0]["response"][0], "1.2M EUR");
+
+        let response_uri = format!("{base}/{}", stored.response_id);
+        let (status, _, fetched) = send("GET", response_uri.clone(), vec![], None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(fetched["response"]["response"][0], "1.2M EUR");
+
+        let (status, _, updated) = send(
+            "PUT",
+            response_uri,
+            vec![(header::IF_MATCH, etag.unwrap())],
+            Some(serde_json::json!({ "response": ["1.5M EUR"], "version": 1 })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(updated["response"]["response"][0], "1.5M EUR");
+
+        let (status, _, diff) = send("GET", format!("{base}/diff?from=1&to=2"), vec![], None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(diff["changes"][0]["old_value"], "1.2M EUR");
+        assert_eq!(diff["changes"][0]["new_value"], "1.5M EUR");
+        Ok(())
     }
 
-    // Check if the response exists and belongs to the specified assessment
-    let existing_response = app_state
-        .database
-        .assessments_response
-        .get_response_by_id(response_id)
-        .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch response: {e}")))?;
+    fn row(question_revision_id: Uuid, version: i32, response: &str) -> assessments_response::Model {
+        assessments_response::Model {
+            response_id: Uuid::new_v4(),
+            assessment_id: Uuid::nil(),
+            question_revision_id,
+            response: response.to_string(),
+            version,
+            updated_at: Utc::now(),
+            key_version: None,
+        }
+    }
 
-    let existing_response = match existing_response {
-        Some(r) => r,
-        None => return Err(ApiError::NotFound("Response not found".to_string())),
-    };
+    #[test]
+    fn test_diff_response_versions() {
+        let changed = Uuid::from_u128(1);
+        let unchanged = Uuid::from_u128(2);
+        let added = Uuid::from_u128(3);
+        let untouched = Uuid::from_u128(4);
+        let rows = vec![
+            row(changed, 1, "yes"),
+            row(changed, 2, "no"),
+            row(changed, 3, "later"),
+            row(unchanged, 1, "maybe"),
+            row(unchanged, 2, "maybe"),
+            row(added, 2, "new answer"),
+            row(untouched, 1, "old answer"),
+        ];
+
+        let summarize = |changes: Vec<ResponseChange>| -> Vec<_> {
+            changes
+                .into_iter()
+                .map(|c| (c.question_revision_id, c.change, c.old_value, c.new_value))
+                .collect()
+        };
+        let text = |value: &str| Some(value.to_string());
+
+        // An answer only saved at version 1 still stands at version 2
+        assert_eq!(
+            summarize(diff_response_versions(rows.clone(), 1, 2)),
+            vec![
+                (changed, ResponseChangeKind::Changed, text("yes"), text("no")),
+                (added, ResponseChangeKind::Added, None, text("new answer")),
+            ]
+        );
+        assert_eq!(
+            summarize(diff_response_versions(rows, 2, 1)),
+            vec![
+                (changed, ResponseChangeKind::Changed, text("no"), text("yes")),
+                (added, ResponseChangeKind::Removed, text("new answer"), None),
+            ]
+        );
+    }
 
-    // Verify that the response belongs to the specified assessment
-    if existing_response.assessment_id != assessment_id {
-        return Err(ApiError::BadRequest(
-            "Response does not belong to the specified assessment".to_string(),
-        ));
+    #[tokio::test]
+    async fn test_diff_responses_endpoint() -> Result<(), Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(questions::Entity),
+            schema.create_table_from_entity(questions_revisions::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        let assessment_id = Uuid::new_v4();
+        assessments::ActiveModel {
+            assessment_id: Set(assessment_id),
+            org_id: Set("test-org".to_string()),
+            language: Set("en".to_string()),
+            name: Set("Draft assessment".to_string()),
+            created_at: Set(Utc::now()),
+        }
+        .insert(&db)
+        .await?;
+
+        let app_database = AppDatabase::new(Arc::new(db)).await;
+        let question = Uuid::new_v4();
+        for answer in ["yes", "no", "partly"] {
+            app_database
+                .assessments_response
+                .update_response(assessment_id, question, answer.to_string())
+                .await?;
+        }
+
+        let app_state = AppState::new(
+            unreachable_keycloak(),
+            app_database,
+        )
+        .await;
+        let app = Router::new()
+            .route("/api/assessments/:assessment_id/responses/diff", axum::routing::get(diff_responses))
+            .layer(Extension(org_claims("test-user-123", &["Org_User"], "Test Organization", "test-org")))
+            .with_state(app_state);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/assessments/{assessment_id}/responses/diff?from=1&to=3"))
+                    .body(Body::empty())?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: serde_json::Value =
+            serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+        assert_eq!(body["changes"].as_array().unwrap().len(), 1);
+        assert_eq!(body["changes"][0]["change"], "changed");
+        assert_eq!(body["changes"][0]["old_value"], "yes");
+        assert_eq!(body["changes"][0]["new_value"], "partly");
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/assessments/{}/responses/diff?from=1&to=2", Uuid::new_v4()))
+                    .body(Body::empty())?,
+            )
+            .await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        Ok(())
     }
 
-    // Delete the response from the database
-    app_state
-        .database
-        .assessments_response
-        .delete_response(response_id)
-        .await
-        .map_err(|e| ApiError::InternalServerError(format!("Failed to delete response: {e}")))?;
+    #[tokio::test]
+    async fn test_delete_responses_keeps_files() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::database::entity::{assessments_response_file, file};
+        use axum::routing::delete;
+        use sea_orm::{EntityTrait, PaginatorTrait};
+
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(questions::Entity),
+            schema.create_table_from_entity(questions_revisions::Entity),
+            schema.create_table_from_entity(assessments_response_file::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(file::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
 
-    Ok(StatusCode::NO_CONTENT)
-}
\ No newline at end of file
+        let assessment_id = Uuid::new_v4();
+        assessments::ActiveModel {
+            assessment_id: Set(assessment_id),
+            org_id: Set("test-org".to_string()),
+            language: Set("en".to_string()),
+            name: Set("Draft assessment".to_string()),
+            created_at: Set(Utc::now()),
+        }
+        .insert(&db)
+        .await?;
+        let file_id = Uuid::new_v4();
+        file::ActiveModel {
+            id: Set(file_id),
+            content: Set(b"evidence".to_vec()),
+            metadata: Set(serde_json::json!({ "filename": "evidence.txt" })),
+            object_key: Set(None),
+        }
+        .insert(&db)
+        .await?;
+
+        let db = Arc::new(db);
+        let app_database = AppDatabase::new(db.clone()).await;
+        let answered = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        for (question, answer) in [(answered, "yes"), (answered, "no"), (other, "maybe")] {
+            let response = app_database
+                .assessments_response
+                .update_response(assessment_id, question, answer.to_string())
+                .await?;
+            if question == answered {
+                app_database
+                    .assessments_response_file
+                    .link_file_to_response(response.response_id, file_id)
+                    .await?;
+            }
+        }
+
+        let app_state = AppState::new(
+            unreachable_keycloak(),
+            app_database,
+        )
+        .await;
+        let app = Router::new()
+            .route("/assessments/:assessment_id/responses", delete(delete_all_responses))
+            .route("/assessments/:assessment_id/responses/:question_revision_id", delete(delete_response))
+            .layer(Extension(org_claims("test-user-123", &["Org_User"], "Test Organization", "test-org")))
+            .with_state(app_state);
+        let send = |uri: String| {
+            let app = app.clone();
+            async move {
+                app.oneshot(Request::builder().method("DELETE").uri(uri).body(Body::empty())?)
+                    .await
+                    .map_err(Box::<dyn std::error::Error>::from)
+            }
+        };
+
+        let response = send(format!("/assessments/{assessment_id}/responses/{answered}")).await?;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        let remaining = assessments_response::Entity::find().all(db.as_ref()).await?;
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].question_revision_id, other);
+        assert_eq!(assessments_response_file::Entity::find().count(db.as_ref()).await?, 0);
+        assert_eq!(file::Entity::find().count(db.as_ref()).await?, 1);
+
+        let response = send(format!("/assessments/{assessment_id}/responses/{answered}")).await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = send(format!("/assessments/{assessment_id}/responses")).await?;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(assessments_response::Entity::find().count(db.as_ref()).await?, 0);
+
+        let response = send(format!("/assessments/{}/responses", Uuid::new_v4())).await?;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_delete_responses_of_submitted_assessment_conflicts() -> Result<(), Box<dyn std::error::Error>> {
+        use axum::routing::delete;
+        use sea_orm::{EntityTrait, PaginatorTrait};
+
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(questions::Entity),
+            schema.create_table_from_entity(questions_revisions::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        let assessment_id = Uuid::new_v4();
+        assessments::ActiveModel {
+            assessment_id: Set(assessment_id),
+            org_id: Set("test-org".to_string()),
+            language: Set("en".to_string()),
+            name: Set("Submitted assessment".to_string()),
+            created_at: Set(Utc::now()),
+        }
+        .insert(&db)
+        .await?;
+        let question_revision_id = Uuid::new_v4();
+        assessments_response::ActiveModel {
+            response_id: Set(Uuid::new_v4()),
+            assessment_id: Set(assessment_id),
+            question_revision_id: Set(question_revision_id),
+            response: Set("yes".to_string()),
+            version: Set(1),
+            updated_at: Set(Utc::now()),
+            key_version: Set(None),
+        }
+        .insert(&db)
+        .await?;
+        assessments_submission::ActiveModel {
+            submission_id: Set(assessment_id),
+            org_id: Set("test-org".to_string()),
+            org_name: Set("Test Organization".to_string()),
+            content: Set(serde_json::json!({})),
+            submitted_at: Set(Utc::now()),
+            status: Set(SubmissionStatus::UnderReview),
+            reviewed_at: Set(None),
+            reopened_at: Set(None),
+            reopened_by: Set(None),
+        }
+        .insert(&db)
+        .await?;
+
+        let db = Arc::new(db);
+        let app_state = AppState::new(
+            unreachable_keycloak(),
+            AppDatabase::new(db.clone()).await,
+        )
+        .await;
+        let app = Router::new()
+            .route("/assessments/:assessment_id/responses", delete(delete_all_responses))
+            .route("/assessments/:assessment_id/responses/:question_revision_id", delete(delete_response))
+            .layer(Extension(org_claims("test-user-123", &["Org_User"], "Test Organization", "test-org")))
+            .with_state(app_state);
+
+        for uri in [
+            format!("/assessments/{assessment_id}/responses/{question_revision_id}"),
+            format!("/assessments/{assessment_id}/responses"),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().method("DELETE").uri(uri).body(Body::empty())?)
+                .await?;
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+        }
+        assert_eq!(assessments_response::Entity::find().count(db.as_ref()).await?, 1);
+        Ok(())
+    }
+}
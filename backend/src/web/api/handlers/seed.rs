@@ -0,0 +1,415 @@
+//! Test data endpoints, mounted by `create_app` in debug builds when
+//! `ENABLE_TEST_SEEDER=true`. See `services::seed`.
+//!
+//! Callers need a valid token of an application admin, which is also what the
+//! seeder uses to create and delete the data in Keycloak.
+
+use axum::{
+    extract::{FromRef, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::post,
+    Extension, Json, Router,
+};
+use std::sync::Arc;
+
+use crate::common::models::claims::Claims;
+use crate::common::services::seed::{SeedRequest, SeedService};
+use crate::web::api::error::ApiError;
+use crate::web::api::strict_json::{StrictJson, StrictRequestValidation};
+use crate::web::handlers::jwt_validator::JwtValidator;
+use crate::web::handlers::midlw::auth_middleware;
+
+pub fn seed_routes(
+    seed_service: SeedService,
+    jwt_validator: Arc<JwtValidator>,
+    strict_request_validation: bool,
+) -> Router {
+    Router::new()
+        .route("/internal/seed", post(seed).delete(rollback_seed))
+        .with_state(SeedState {
+            seed_service,
+            strict_request_validation: StrictRequestValidation(strict_request_validation),
+        })
+        .layer(middleware::from_fn_with_state(jwt_validator, auth_middleware))
+}
+
+/// State of the seed routes: the service, and how `StrictJson` treats unknown fields
+#[derive(Clone)]
+struct SeedState {
+    seed_service: SeedService,
+    strict_request_validation: StrictRequestValidation,
+}
+
+impl FromRef<SeedState> for SeedService {
+    fn from_ref(state: &SeedState) -> Self {
+        state.seed_service.clone()
+    }
+}
+
+impl FromRef<SeedState> for StrictRequestValidation {
+    fn from_ref(state: &SeedState) -> Self {
+        state.strict_request_validation
+    }
+}
+
+fn require_application_admin(claims: &Claims) -> Result<(), ApiError> {
+    if claims.is_application_admin() {
+        Ok(())
+    } else {
+        Err(ApiError::Forbidden("Only DGRV admins can seed test data".to_string()))
+    }
+}
+
+/// Create organizations, users and assessments, returning their IDs
+pub async fn seed(
+    State(seed_service): State<SeedService>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+    StrictJson(request): StrictJson<SeedRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_application_admin(&claims)?;
+    let seeded = seed_service
+        .seed(&token, request)
+        .await
+        .map_err(|e| ApiError::BadRequest(format!("Seeding failed: {e:#}")))?;
+
+    Ok((StatusCode::CREATED, Json(seeded)))
+}
+
+/// Delete everything seeded since startup or the last rollback
+pub async fn rollback_seed(
+    State(seed_service): State<SeedService>,
+    Extension(claims): Extension<Claims>,
+    Extension(token): Extension<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    require_application_admin(&claims)?;
+    Ok(Json(seed_service.rollback(&token).await))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{access_token, jwks_routes, keycloak_config, serve};
+    use crate::web::handlers::jwt_validator::JwtValidator;
+    use crate::common::database::entity::{
+        assessment_categories, assessments, assessments_response, assessments_response_file,
+        assessments_submission, category_catalog, file, organization_categories, temp_submission,
+    };
+    use crate::common::services::keycloak_service::KeycloakService;
+    use crate::common::state::AppDatabase;
+    use axum::{
+        body::Body,
+        extract::{Path, Query},
+        http::{header, Request},
+        routing::get,
+    };
+    use chrono::Utc;
+    use sea_orm::{
+        ActiveModelTrait, ColumnTrait, ConnectionTrait, Database, EntityTrait, PaginatorTrait,
+        QueryFilter, Schema, Set,
+    };
+    use serde_json::{json, Value};
+    use std::collections::{BTreeMap, HashMap};
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    /// What the fake Keycloak holds: organizations and users by ID, memberships
+    /// as (organization, user) and realm roles as (user, role)
+    #[derive(Default)]
+    struct Realm {
+        organizations: BTreeMap<String, String>,
+        users: BTreeMap<String, Value>,
+        members: Vec<(String, String)>,
+        roles: Vec<(String, String)>,
+    }
+
+    type SharedRealm = Arc<Mutex<Realm>>;
+
+    async fn fake_keycloak(realm: SharedRealm) -> String {
+        let base = "/admin/realms/test-realm";
+        let app = Router::new()
+            .route(
+                &format!("{base}/organizations"),
+                post(|State(realm): State<SharedRealm>, Json(org): Json<Value>| async move {
+                    let id = format!("org-{}", Uuid::new_v4());
+                    let name = org["name"].as_str().unwrap_or_default().to_string();
+                    realm.lock().unwrap().organizations.insert(id.clone(), name.clone());
+                    (StatusCode::CREATED, Json(json!({ "id": id, "name": name, "enabled": true })))
+                }),
+            )
+            .route(
+                &format!("{base}/organizations/:org_id"),
+                axum::routing::delete(|State(realm): State<SharedRealm>, Path(org_id): Path<String>| async move {
+                    realm.lock().unwrap().organizations.remove(&org_id);
+                    StatusCode::NO_CONTENT
+                }),
+            )
+            .route(
+                &format!("{base}/organizations/:org_id/members"),
+                post(
+                    |State(realm): State<SharedRealm>, Path(org_id): Path<String>, Json(user_id): Json<String>| async move {
+                        realm.lock().unwrap().members.push((org_id, user_id));
+                        StatusCode::CREATED
+                    },
+                ),
+            )
+            .route(
+                &format!("{base}/users"),
+                get(|State(realm): State<SharedRealm>, Query(query): Query<HashMap<String, String>>| async move {
+                    let search = query.get("search").cloned().unwrap_or_default();
+                    let users: Vec<Value> = realm
+                        .lock()
+                        .unwrap()
+                        .users
+                        .values()
+                        .filter(|user| user["email"] == search.as_str() || user["username"] == search.as_str())
+                        .cloned()
+                        .collect();
+                    Json(users)
+                })
+                .post(|State(realm): State<SharedRealm>, Json(mut user): Json<Value>| async move {
+                    let id = format!("user-{}", Uuid::new_v4());
+                    user["id"] = json!(id);
+                    realm.lock().unwrap().users.insert(id.clone(), user);
+                    (
+                        StatusCode::CREATED,
+                        [(header::LOCATION, format!("/admin/realms/test-realm/users/{id}"))],
+                    )
+                }),
+            )
+            .route(
+                &format!("{base}/users/:user_id"),
+                get(|State(realm): State<SharedRealm>, Path(user_id): Path<String>| async move {
+                    match realm.lock().unwrap().users.get(&user_id) {
+                        Some(user) => Json(user.clone()).into_response(),
+                        None => StatusCode::NOT_FOUND.into_response(),
+                    }
+                })
+                .delete(|State(realm): State<SharedRealm>, Path(user_id): Path<String>| async move {
+                    realm.lock().unwrap().users.remove(&user_id);
+                    StatusCode::NO_CONTENT
+                }),
+            )
+            .route(
+                &format!("{base}/users/:user_id/send-verify-email"),
+                post(|| async { StatusCode::NO_CONTENT }),
+            )
+            .route(
+                &format!("{base}/roles/:role"),
+                get(|Path(role): Path<String>| async move { Json(json!({ "name": role })) }),
+            )
+            .route(
+                &format!("{base}/users/:user_id/role-mappings/realm"),
+                post(
+                    |State(realm): State<SharedRealm>, Path(user_id): Path<String>, Json(roles): Json<Vec<Value>>| async move {
+                        let mut realm = realm.lock().unwrap();
+                        for role in roles {
+                            let name = role["name"].as_str().unwrap_or_default().to_string();
+                            realm.roles.push((user_id.clone(), name));
+                        }
+                        StatusCode::NO_CONTENT
+                    },
+                ),
+            )
+            .with_state(realm)
+            .merge(jwks_routes());
+
+        serve(app).await
+    }
+
+    /// Seed routes over a database with the Environment and Social catalog categories,
+    /// and the URL of the fake Keycloak, for `access_token`
+    async fn setup(realm: SharedRealm) -> Result<(Router, AppDatabase, String), Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(category_catalog::Entity),
+            schema.create_table_from_entity(organization_categories::Entity),
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessment_categories::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(assessments_response_file::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(temp_submission::Entity),
+            schema.create_table_from_entity(file::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        for name in ["Environment", "Social"] {
+            category_catalog::ActiveModel {
+                category_catalog_id: Set(Uuid::new_v4()),
+                name: Set(name.to_string()),
+                description: Set(None),
+                template_id: Set("sustainability_template_1".to_string()),
+                is_active: Set(true),
+                created_at: Set(Utc::now()),
+                updated_at: Set(Utc::now()),
+                default_recommendation: Set(None),
+                deactivated_at: Set(None),
+                parent_category_catalog_id: Set(None),
+                scoring_mode: Set(Default::default()),
+                score_threshold: Set(None),
+                icon_name: Set(None),
+                color_hex: Set(None),
+                metadata: Set(serde_json::json!({})),
+            }
+            .insert(&db)
+            .await?;
+        }
+
+        let url = fake_keycloak(realm).await;
+        let config = keycloak_config(&url);
+        let database = AppDatabase::new(Arc::new(db)).await;
+        let app = seed_routes(
+            SeedService::new(Arc::new(KeycloakService::new(config.clone())), database.clone()),
+            Arc::new(JwtValidator::new(&config)),
+            false,
+        );
+        Ok((app, database, url))
+    }
+
+    async fn send(
+        app: &Router,
+        token: &str,
+        method: &str,
+        body: Option<Value>,
+    ) -> Result<(StatusCode, Value), Box<dyn std::error::Error>> {
+        let request = Request::builder()
+            .method(method)
+            .uri("/internal/seed")
+            .header(header::AUTHORIZATION, format!("Bearer {token}"))
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))?;
+        let response = app.clone().oneshot(request).await?;
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok((status, serde_json::from_slice(&body).unwrap_or(Value::Null)))
+    }
+
+    #[tokio::test]
+    async fn test_seed_full_scenario_and_roll_it_back() -> Result<(), Box<dyn std::error::Error>> {
+        let realm = SharedRealm::default();
+        let (app, database, url) = setup(realm.clone()).await?;
+        let token = access_token(&url, "dgrv-admin", &["application_admin"]);
+
+        let (status, seeded) = send(
+            &app,
+            &token,
+            "POST",
+            Some(json!({
+                "organizations": [{ "name": "Green Coop", "domains": ["green.example"], "categories": ["Environment", "Social"] }],
+                "users": [
+                    { "email": "admin@green.example", "organization": "Green Coop", "roles": ["org_admin", "Org_User"], "categories": ["Environment"] },
+                    { "email": "dgrv@example.com", "roles": ["application_admin"] }
+                ],
+                "assessments": [{ "organization": "Green Coop", "name": "Baseline", "categories": ["Environment", "Social"] }]
+            })),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::CREATED, "{seeded}");
+
+        let org_id = seeded["organizations"]["Green Coop"].as_str().unwrap().to_string();
+        let admin_id = seeded["users"]["admin@green.example"].as_str().unwrap().to_string();
+        let dgrv_id = seeded["users"]["dgrv@example.com"].as_str().unwrap().to_string();
+        {
+            let realm = realm.lock().unwrap();
+            assert_eq!(realm.organizations.get(&org_id).map(String::as_str), Some("Green Coop"));
+            assert_eq!(realm.members, vec![(org_id.clone(), admin_id.clone())]);
+            assert_eq!(realm.users[&admin_id]["attributes"]["categories"], json!(["Environment"]));
+            assert_eq!(
+                realm.roles,
+                vec![
+                    (admin_id.clone(), "org_admin".to_string()),
+                    (admin_id.clone(), "Org_User".to_string()),
+                    (dgrv_id, "application_admin".to_string()),
+                ]
+            );
+        }
+
+        let org_categories = database
+            .organization_categories
+            .get_organization_categories_by_keycloak_organization_id(&org_id)
+            .await?;
+        assert_eq!(org_categories.iter().map(|c| c.weight).collect::<Vec<_>>(), vec![50, 50]);
+
+        let assessments = database.assessments.get_assessments_by_org(&org_id).await?;
+        assert_eq!(assessments.len(), 1);
+        assert_eq!(assessments[0].name, "Baseline");
+        assert_eq!(seeded["assessments"][0]["assessment_id"], json!(assessments[0].assessment_id));
+        let assessment_category_count = assessment_categories::Entity::find()
+            .filter(assessment_categories::Column::AssessmentId.eq(assessments[0].assessment_id))
+            .count(database.get_connection())
+            .await?;
+        assert_eq!(assessment_category_count, 2);
+
+        let (status, rollback) = send(&app, &token, "DELETE", None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(rollback["errors"], json!([]));
+        assert_eq!(rollback["deleted"], seeded);
+
+        {
+            let realm = realm.lock().unwrap();
+            assert!(realm.organizations.is_empty());
+            assert!(realm.users.is_empty());
+        }
+        assert!(database.assessments.get_assessments_by_org(&org_id).await?.is_empty());
+        assert!(database
+            .organization_categories
+            .get_organization_categories_by_keycloak_organization_id(&org_id)
+            .await?
+            .is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_failed_seed_keeps_what_it_created_for_rollback() -> Result<(), Box<dyn std::error::Error>> {
+        let realm = SharedRealm::default();
+        let (app, _, url) = setup(realm.clone()).await?;
+        let token = access_token(&url, "dgrv-admin", &["application_admin"]);
+
+        let (status, body) = send(
+            &app,
+            &token,
+            "POST",
+            Some(json!({
+                "organizations": [{ "name": "Green Coop" }],
+                "assessments": [{ "organization": "Green Coop", "name": "Baseline", "categories": ["Governance"] }]
+            })),
+        )
+        .await?;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(body["error"].as_str().unwrap_or_default().contains("Governance"), "{body}");
+        assert_eq!(realm.lock().unwrap().organizations.len(), 1);
+
+        let (status, rollback) = send(&app, &token, "DELETE", None).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(rollback["deleted"]["organizations"].as_object().map(|orgs| orgs.len()), Some(1));
+        assert!(realm.lock().unwrap().organizations.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_seed_requires_application_admin_token() -> Result<(), Box<dyn std::error::Error>> {
+        let realm = SharedRealm::default();
+        let (app, _, url) = setup(realm.clone()).await?;
+        let request = json!({ "organizations": [{ "name": "Green Coop" }] });
+
+        let (status, _) = send(&app, "not-a-token", "POST", Some(request.clone())).await?;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let org_admin = access_token(&url, "org-admin", &["org_admin"]);
+        let (status, _) = send(&app, &org_admin, "POST", Some(request)).await?;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = send(&app, &org_admin, "DELETE", None).await?;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        assert!(realm.lock().unwrap().organizations.is_empty());
+        Ok(())
+    }
+}
@@ -1,12 +1,26 @@
 use crate::common::models::claims::Claims;
 use crate::web::routes::AppState;
 use crate::web::api::error::ApiError;
-use crate::web::api::models::{Submission, SubmissionDetailResponse, SubmissionListResponse};
+use crate::web::api::strict_json::StrictJson;
+use crate::common::database::entity::{assessments, assessments_submission, submission_timeline};
+use crate::common::locale::select_localized_text;
+use crate::common::models::admin_event::AdminEvent;
+use crate::common::services::pdf::PdfDocument;
+use crate::web::api::handlers::reports::answer_summary;
+use crate::common::database::entity::submission_timeline::TimelineActor;
+use crate::common::database::entity::assessments_submission::RecallError;
+use crate::web::api::models::{
+    FileMetadata, ReopenSubmissionQuery, ReopenedSubmission, Submission, SubmissionDetailResponse,
+    SubmissionListResponse, SubmissionResponsesDetail, SubmittedResponse, TimelineCommentRequest, TimelineEvent,
+    TimelineResponse,
+};
 use axum::{
-    extract::{Extension, Path, State},
-    http::StatusCode,
+    extract::{Extension, Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
     Json,
 };
+use futures::future::join_all;
 use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
 use uuid::Uuid;
 
@@ -41,18 +55,14 @@ fn create_placeholder_question_json(question_revision_id: Uuid) -> serde_json::V
     })
 }
 
-/// Process a single response to replace question_revision_id with question text and category
-async fn process_response(
+/// Question text and category name for a question revision, with placeholders when
+/// the revision, its question or the category no longer exist
+async fn question_text_and_category(
     app_state: &AppState,
-    response_obj: &mut serde_json::Map<String, serde_json::Value>,
-) -> Result<(), ApiError> {
-    let question_revision_id = match extract_question_revision_id(response_obj) {
-        Some(id) => id,
-        None => return Ok(()), // Skip if no valid question_revision_id
-    };
-
+    question_revision_id: Uuid,
+) -> Result<(serde_json::Value, String), ApiError> {
     // Fetch question text and category using question_revision_id
-    let (question_text, question_category) = match app_state
+    match app_state
         .database
         .questions_revisions
         .get_revision_by_id(question_revision_id)
@@ -77,19 +87,31 @@ async fn process_response(
                         Ok(Some(c)) => c.name,
                         _ => "Unknown".to_string(),
                     };
-                    (revision.text, category_name)
+                    Ok((revision.text, category_name))
                 }
-                _ => (serde_json::json!({"en": "Question not found"}), "Unknown".to_string()),
+                _ => Ok((serde_json::json!({"en": "Question not found"}), "Unknown".to_string())),
             }
         }
-        Ok(None) => (serde_json::json!({"en": "Question not found"}), "Unknown".to_string()),
-        Err(e) => {
-            return Err(ApiError::InternalServerError(
-                format!("Failed to fetch question data: {e}")
-            ));
-        }
+        Ok(None) => Ok((serde_json::json!({"en": "Question not found"}), "Unknown".to_string())),
+        Err(e) => Err(ApiError::InternalServerError(
+            format!("Failed to fetch question data: {e}")
+        )),
+    }
+}
+
+/// Process a single response to replace question_revision_id with question text and category
+async fn process_response(
+    app_state: &AppState,
+    response_obj: &mut serde_json::Map<String, serde_json::Value>,
+) -> Result<(), ApiError> {
+    let question_revision_id = match extract_question_revision_id(response_obj) {
+        Some(id) => id,
+        None => return Ok(()), // Skip if no valid question_revision_id
     };
 
+    let (question_text, question_category) =
+        question_text_and_category(app_state, question_revision_id).await?;
+
     // Remove question_revision_id and replace with question text and category
     response_obj.remove("question_revision_id");
     response_obj.insert("question".to_string(), question_text);
@@ -103,6 +125,11 @@ async fn enhance_submission_content_with_questions(
     content: serde_json::Value,
 ) -> Result<serde_json::Value, ApiError> {
     let mut enhanced_content = content.clone();
+    app_state
+        .database
+        .assessments_response
+        .decrypt_content(&mut enhanced_content)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decrypt responses: {e}")))?;
 
     // Get responses array from content, return early if not found
     let responses_array = enhanced_content
@@ -269,6 +296,440 @@ pub async fn get_submission(
     Ok(Json(SubmissionDetailResponse { submission }))
 }
 
+/// Read-only view of the responses captured in a submission, with question text and
+/// category resolved and attached files listed
+#[utoipa::path(
+    get,
+    path = "/submissions/{submission_id}/responses",
+    tag = "Submission",
+    params(("submission_id" = uuid::Uuid, Path, description = "Submission ID")),
+    responses(
+        (status = 200, description = "Submitted responses", body = SubmissionResponsesDetail),
+        (status = 403, description = "Submission belongs to another organization"),
+        (status = 404, description = "Not found")
+    )
+)]
+pub async fn get_submission_responses(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(submission_id): Path<Uuid>,
+) -> Result<Json<SubmissionResponsesDetail>, ApiError> {
+    let submission_model = app_state
+        .database
+        .assessments_submission
+        .get_submission_by_assessment_id(submission_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Submission not found".to_string()))?;
+
+    if !is_member_of_org_by_id(&claims, &submission_model.org_id) {
+        return Err(ApiError::Forbidden(
+            "You don't have permission to access this submission".to_string(),
+        ));
+    }
+
+    Ok(Json(SubmissionResponsesDetail {
+        submission_id,
+        responses: submitted_responses(&app_state, &submission_model).await?,
+    }))
+}
+
+/// The responses stored in a submission, with question text and category resolved
+async fn submitted_responses(
+    app_state: &AppState,
+    submission_model: &assessments_submission::Model,
+) -> Result<Vec<SubmittedResponse>, ApiError> {
+    let mut content = submission_model.content.clone();
+    app_state
+        .database
+        .assessments_response
+        .decrypt_content(&mut content)
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to decrypt responses: {e}")))?;
+    let entries: Vec<(Uuid, &serde_json::Map<String, serde_json::Value>)> = content
+        .get("responses")
+        .and_then(|responses| responses.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|response| {
+            let response_obj = response.as_object()?;
+            Some((extract_question_revision_id(response_obj)?, response_obj))
+        })
+        .collect();
+
+    let questions = join_all(
+        entries
+            .iter()
+            .map(|(question_revision_id, _)| question_text_and_category(app_state, *question_revision_id)),
+    )
+    .await;
+
+    let mut responses = Vec::with_capacity(entries.len());
+    for ((question_revision_id, response_obj), question) in entries.into_iter().zip(questions) {
+        let (question_text, category) = question?;
+        let files = response_obj
+            .get("files")
+            .and_then(|files| files.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|file| serde_json::from_value::<FileMetadata>(file.clone()).ok())
+            .collect();
+
+        responses.push(SubmittedResponse {
+            question_revision_id,
+            question_text,
+            category,
+            response: response_obj.get("response").cloned().unwrap_or(serde_json::Value::Null),
+            files,
+        });
+    }
+
+    Ok(responses)
+}
+
+/// The submitted answers as a printable PDF, category by category, with questions in
+/// the assessment's language. Works before any report has been generated.
+#[utoipa::path(
+    get,
+    path = "/submissions/{submission_id}/pdf",
+    tag = "Submission",
+    params(("submission_id" = uuid::Uuid, Path, description = "Submission ID")),
+    responses(
+        (status = 200, description = "PDF of the submitted answers", content_type = "application/pdf"),
+        (status = 403, description = "Submission belongs to another organization"),
+        (status = 404, description = "Not found")
+    )
+)]
+pub async fn get_submission_pdf(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(submission_id): Path<Uuid>,
+) -> Result<Response, ApiError> {
+    let (submission_model, assessment_model) = assessments_submission::Entity::find_by_id(submission_id)
+        .left_join(assessments::Entity)
+        .select_also(assessments::Entity)
+        .one(app_state.database.get_connection())
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Submission not found".to_string()))?;
+
+    if !is_member_of_org_by_id(&claims, &submission_model.org_id) {
+        return Err(ApiError::Forbidden(
+            "You don't have permission to access this submission".to_string(),
+        ));
+    }
+
+    let responses = submitted_responses(&app_state, &submission_model).await?;
+
+    // The assessment may have been deleted since, the submission keeps a copy of both
+    let content = &submission_model.content;
+    let assessment_name = assessment_model
+        .as_ref()
+        .map(|assessment| assessment.name.clone())
+        .or_else(|| content.get("assessment_name").and_then(|v| v.as_str()).map(str::to_string))
+        .unwrap_or_else(|| "Unknown Assessment".to_string());
+    let language = assessment_model
+        .map(|assessment| assessment.language)
+        .or_else(|| content["assessment"]["language"].as_str().map(str::to_string))
+        .unwrap_or_else(|| "en".to_string());
+    let fallbacks = app_state.locale_config.language_fallback.0.clone();
+
+    let pdf = tokio::task::spawn_blocking(move || {
+        submission_pdf(&submission_model, &assessment_name, &language, &fallbacks, &responses)
+    })
+    .await
+    .map_err(|e| ApiError::InternalServerError(format!("Failed to render PDF: {e}")))?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/pdf".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"submission-{submission_id}.pdf\""),
+            ),
+        ],
+        pdf,
+    )
+        .into_response())
+}
+
+fn submission_pdf(
+    submission: &assessments_submission::Model,
+    assessment_name: &str,
+    language: &str,
+    fallbacks: &[String],
+    responses: &[SubmittedResponse],
+) -> Vec<u8> {
+    let mut document = PdfDocument::new(&format!("Submission: {assessment_name}"));
+    document
+        .text(&format!("Organization: {}", submission.org_name))
+        .text(&format!("Assessment: {assessment_name}"))
+        .text(&format!("Submitted: {}", submission.submitted_at.format("%Y-%m-%d")));
+
+    // Categories in the order their first question was answered
+    let mut categories: Vec<(&str, Vec<&SubmittedResponse>)> = Vec::new();
+    for response in responses {
+        match categories.iter_mut().find(|(category, _)| *category == response.category) {
+            Some((_, entries)) => entries.push(response),
+            None => categories.push((&response.category, vec![response])),
+        }
+    }
+
+    for (category, entries) in categories {
+        document.heading(category);
+        for response in entries {
+            let question = select_localized_text(&response.question_text, language, fallbacks)
+                .unwrap_or("Unknown question");
+            document.text(&format!("{question}: {}", response_summary(&response.response)));
+        }
+    }
+
+    document.render()
+}
+
+/// A stored response as one line. Responses are usually JSON encoded in a string,
+/// e.g. `["Yes"]` or `{"yesNo":true,"percentage":80}`.
+fn response_summary(response: &serde_json::Value) -> String {
+    let decoded = response
+        .as_str()
+        .and_then(|text| serde_json::from_str::<serde_json::Value>(text).ok());
+    match decoded.as_ref().unwrap_or(response) {
+        serde_json::Value::Null => "No answer".to_string(),
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .map(|item| item.as_str().map(str::to_string).unwrap_or_else(|| item.to_string()))
+            .collect::<Vec<_>>()
+            .join(", "),
+        answer @ serde_json::Value::Object(_) => answer_summary(Some(answer)),
+        other => other.to_string(),
+    }
+}
+
+/// DGRV admins, and admins of the organization the submission belongs to
+fn can_manage_submission(claims: &Claims, submission: &assessments_submission::Model) -> bool {
+    claims.is_application_admin()
+        || (claims.is_organization_admin() && is_member_of_org_by_id(claims, &submission.org_id))
+}
+
+async fn find_managed_submission(
+    app_state: &AppState,
+    claims: &Claims,
+    submission_id: Uuid,
+) -> Result<assessments_submission::Model, ApiError> {
+    let submission = app_state
+        .database
+        .assessments_submission
+        .get_submission_by_assessment_id(submission_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Submission not found".to_string()))?;
+
+    if !can_manage_submission(claims, &submission) {
+        return Err(ApiError::Forbidden(
+            "You don't have permission to access this submission".to_string(),
+        ));
+    }
+
+    Ok(submission)
+}
+
+fn to_api_timeline_event(model: submission_timeline::Model) -> TimelineEvent {
+    TimelineEvent {
+        event_id: model.event_id,
+        submission_id: model.submission_id,
+        from_status: model.from_status,
+        to_status: model.to_status,
+        actor_user_id: model.actor_user_id,
+        actor_role: model.actor_role,
+        comment: model.comment,
+        created_at: model.created_at.to_rfc3339(),
+    }
+}
+
+/// Status changes and review comments of a submission, oldest first
+#[utoipa::path(
+    get,
+    path = "/admin/submissions/{submission_id}/timeline",
+    tag = "Submission",
+    params(("submission_id" = uuid::Uuid, Path, description = "Submission ID")),
+    responses(
+        (status = 200, description = "Submission timeline", body = TimelineResponse),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found")
+    )
+)]
+pub async fn get_submission_timeline(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(submission_id): Path<Uuid>,
+) -> Result<Json<TimelineResponse>, ApiError> {
+    find_managed_submission(&app_state, &claims, submission_id).await?;
+
+    let events = app_state
+        .database
+        .submission_timeline
+        .get_timeline(submission_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission timeline: {e}")))?;
+
+    Ok(Json(TimelineResponse {
+        events: events.into_iter().map(to_api_timeline_event).collect(),
+    }))
+}
+
+/// Attach a review comment to a submission's timeline
+#[utoipa::path(
+    post,
+    path = "/submissions/{submission_id}/timeline/comment",
+    tag = "Submission",
+    params(("submission_id" = uuid::Uuid, Path, description = "Submission ID")),
+    request_body = TimelineCommentRequest,
+    responses(
+        (status = 201, description = "Comment added", body = TimelineEvent),
+        (status = 400, description = "Empty comment"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Not found")
+    )
+)]
+pub async fn add_submission_comment(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(submission_id): Path<Uuid>,
+    StrictJson(request): StrictJson<TimelineCommentRequest>,
+) -> Result<(StatusCode, Json<TimelineEvent>), ApiError> {
+    let comment = request.comment.trim();
+    if comment.is_empty() {
+        return Err(ApiError::BadRequest("Comment must not be empty".to_string()));
+    }
+
+    let submission = find_managed_submission(&app_state, &claims, submission_id).await?;
+
+    let event = app_state
+        .database
+        .submission_timeline
+        .add_comment(submission_id, &submission.status, &TimelineActor::from_claims(&claims), comment.to_string())
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to add comment: {e}")))?;
+
+    Ok((StatusCode::CREATED, Json(to_api_timeline_event(event))))
+}
+
+/// Reopen a submission for corrections (application admins)
+///
+/// Its assessment can be edited and submitted again, which replaces the submission.
+/// With `invalidate_report=true` the reports generated from it are marked invalidated.
+#[utoipa::path(
+    post,
+    path = "/submissions/{submission_id}/reopen",
+    tag = "Submission",
+    params(
+        ("submission_id" = uuid::Uuid, Path, description = "Submission ID"),
+        ("invalidate_report" = Option<bool>, Query, description = "Mark the submission's reports as invalidated")
+    ),
+    responses(
+        (status = 200, description = "Submission reopened", body = ReopenedSubmission),
+        (status = 403, description = "Not an application admin"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Submission is already reopened")
+    )
+)]
+pub async fn reopen_submission(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(submission_id): Path<Uuid>,
+    Query(query): Query<ReopenSubmissionQuery>,
+) -> Result<Json<ReopenedSubmission>, ApiError> {
+    if !claims.is_application_admin() {
+        return Err(ApiError::Forbidden(
+            "Only application admins can reopen submissions".to_string(),
+        ));
+    }
+
+    let submission = find_managed_submission(&app_state, &claims, submission_id).await?;
+    if submission.is_reopened() {
+        return Err(ApiError::Conflict("Submission is already reopened".to_string()));
+    }
+
+    let reopened = app_state
+        .database
+        .assessments_submission
+        .reopen_submission(submission_id, &TimelineActor::from_claims(&claims), query.invalidate_report)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to reopen submission: {e}")))?;
+
+    Ok(Json(ReopenedSubmission {
+        submission_id,
+        review_status: reopened.status.to_string(),
+        reopened_at: reopened.reopened_at.unwrap_or_default().to_rfc3339(),
+        reopened_by: reopened.reopened_by.unwrap_or_default(),
+        report_invalidated: query.invalidate_report,
+    }))
+}
+
+/// Take back a submission that was sent by mistake (organization admins)
+///
+/// Only possible while the submission waits for review and no report has been
+/// generated from it. The submission is reopened and its assessment is a draft
+/// again; the recall is recorded on its timeline. Application admins are told
+/// through the admin event stream.
+#[utoipa::path(
+    post,
+    path = "/submissions/{submission_id}/recall",
+    tag = "Submission",
+    params(("submission_id" = uuid::Uuid, Path, description = "Submission ID")),
+    responses(
+        (status = 204, description = "Submission recalled"),
+        (status = 403, description = "Not an admin of the submission's organization"),
+        (status = 404, description = "Not found"),
+        (status = 409, description = "Review of the submission has started or reports exist")
+    )
+)]
+pub async fn recall_submission(
+    State(app_state): State<AppState>,
+    Extension(claims): Extension<Claims>,
+    Path(submission_id): Path<Uuid>,
+) -> Result<StatusCode, ApiError> {
+    if !claims.is_organization_admin() {
+        return Err(ApiError::Forbidden(
+            "Only organization admins can recall submissions".to_string(),
+        ));
+    }
+
+    let submission = app_state
+        .database
+        .assessments_submission
+        .get_submission_by_assessment_id(submission_id)
+        .await
+        .map_err(|e| ApiError::InternalServerError(format!("Failed to fetch submission: {e}")))?
+        .ok_or_else(|| ApiError::NotFound("Submission not found".to_string()))?;
+
+    if !is_member_of_org_by_id(&claims, &submission.org_id) {
+        return Err(ApiError::Forbidden(
+            "You don't have permission to recall this submission".to_string(),
+        ));
+    }
+
+    app_state
+        .database
+        .assessments_submission
+        .recall_submission(submission_id, &TimelineActor::from_claims(&claims))
+        .await
+        .map_err(|e| match e {
+            RecallError::NotFound => ApiError::NotFound("Submission not found".to_string()),
+            RecallError::ReviewStarted | RecallError::HasReports => ApiError::Conflict(e.to_string()),
+            RecallError::Database(e) => ApiError::InternalServerError(format!("Failed to recall submission: {e}")),
+        })?;
+
+    app_state.session_cache.invalidate_user(&claims.sub);
+    app_state.publish_admin_event(AdminEvent::SubmissionRecalled {
+        submission_id,
+        org_id: submission.org_id,
+        org_name: submission.org_name,
+    });
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 /// Delete a submission by ID
 #[utoipa::path(
     delete,
@@ -323,3 +784,545 @@ pub async fn delete_submission(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{org_claims, unreachable_keycloak};
+    use crate::common::database::entity::{
+        category_catalog, questions, questions_revisions, submission_reports, temp_submission,
+    };
+    use crate::common::database::entity::assessments_submission::SubmissionStatus;
+    use crate::common::state::AppDatabase;
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use sea_orm::{ConnectionTrait, Database, Schema};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+
+    async fn setup() -> Result<AppState, Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(submission_timeline::Entity),
+            schema.create_table_from_entity(submission_reports::Entity),
+            schema.create_table_from_entity(temp_submission::Entity),
+            schema.create_table_from_entity(category_catalog::Entity),
+            schema.create_table_from_entity(questions::Entity),
+            schema.create_table_from_entity(questions_revisions::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        Ok(AppState::new(
+            unreachable_keycloak(),
+            AppDatabase::new(Arc::new(db)).await,
+        )
+        .await)
+    }
+
+    async fn fetch_responses(
+        app_state: &AppState,
+        claims: Claims,
+        submission_id: Uuid,
+    ) -> Result<(StatusCode, serde_json::Value), Box<dyn std::error::Error>> {
+        let app = Router::new()
+            .route("/api/submissions/:submission_id/responses", get(get_submission_responses))
+            .layer(Extension(claims))
+            .with_state(app_state.clone());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/submissions/{submission_id}/responses"))
+                    .body(Body::empty())?,
+            )
+            .await?;
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok((status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null)))
+    }
+
+    #[tokio::test]
+    async fn test_submission_without_responses_is_empty() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let submission_id = Uuid::new_v4();
+        app_state
+            .database
+            .assessments_submission
+            .create_submission(
+                submission_id,
+                "test-org".to_string(),
+                "Test Organization".to_string(),
+                serde_json::json!({ "responses": [] }),
+                None,
+            )
+            .await?;
+
+        let (status, body) = fetch_responses(&app_state, org_claims("user-123", &["org_user"], "Test Organization", "test-org"), submission_id).await?;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["submission_id"], submission_id.to_string());
+        assert_eq!(body["responses"], serde_json::json!([]));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_submission_responses_resolve_questions_and_files() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let category = app_state
+            .database
+            .category_catalog
+            .create_category_catalog(Uuid::new_v4(), "Environmental".to_string(), None, "sustainability_template_1".to_string(), true, None)
+            .await?;
+        let question = app_state
+            .database
+            .questions
+            .create_question(category.category_catalog_id)
+            .await?;
+        let revision = app_state
+            .database
+            .questions_revisions
+            .create_question_revision(question.question_id, serde_json::json!({ "en": "Do you recycle?" }), 1.0)
+            .await?;
+
+        let file_id = Uuid::new_v4();
+        let submission_id = Uuid::new_v4();
+        app_state
+            .database
+            .assessments_submission
+            .create_submission(
+                submission_id,
+                "test-org".to_string(),
+                "Test Organization".to_string(),
+                serde_json::json!({
+                    "responses": [{
+                        "question_revision_id": revision.question_revision_id,
+                        "response": "[\"Yes\"]",
+                        "version": 1,
+                        "files": [{
+                            "file_id": file_id,
+                            "filename": "policy.pdf",
+                            "size": 1024,
+                            "content_type": "application/pdf",
+                            "created_at": "2025-01-01T00:00:00+00:00",
+                            "metadata": { "filename": "policy.pdf" }
+                        }]
+                    }]
+                }),
+                None,
+            )
+            .await?;
+
+        let (status, body) = fetch_responses(&app_state, org_claims("user-123", &["org_user"], "Test Organization", "test-org"), submission_id).await?;
+
+        assert_eq!(status, StatusCode::OK);
+        let response = &body["responses"][0];
+        assert_eq!(response["question_revision_id"], revision.question_revision_id.to_string());
+        assert_eq!(response["question_text"]["en"], "Do you recycle?");
+        assert_eq!(response["category"], "Environmental");
+        assert_eq!(response["response"], "[\"Yes\"]");
+        assert_eq!(response["files"][0]["file_id"], file_id.to_string());
+        assert_eq!(response["files"][0]["filename"], "policy.pdf");
+
+        let (status, _) = fetch_responses(&app_state, org_claims("user-123", &["org_user"], "Test Organization", "other-org"), submission_id).await?;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_submission_pdf_without_report() -> Result<(), Box<dyn std::error::Error>> {
+        use sea_orm::{ActiveModelTrait, Set};
+
+        let app_state = setup().await?;
+        let category = app_state
+            .database
+            .category_catalog
+            .create_category_catalog(Uuid::new_v4(), "Environmental".to_string(), None, "sustainability_template_1".to_string(), true, None)
+            .await?;
+        let question = app_state.database.questions.create_question(category.category_catalog_id).await?;
+        let revision = app_state
+            .database
+            .questions_revisions
+            .create_question_revision(
+                question.question_id,
+                serde_json::json!({ "en": "Do you recycle?", "fr": "Recyclez-vous ?" }),
+                1.0,
+            )
+            .await?;
+
+        let submission_id = Uuid::new_v4();
+        assessments::ActiveModel {
+            assessment_id: Set(submission_id),
+            org_id: Set("test-org".to_string()),
+            language: Set("fr".to_string()),
+            name: Set("Bilan 2026".to_string()),
+            created_at: Set(chrono::Utc::now()),
+        }
+        .insert(app_state.database.get_connection())
+        .await?;
+        app_state
+            .database
+            .assessments_submission
+            .create_submission(
+                submission_id,
+                "test-org".to_string(),
+                "Test Organization".to_string(),
+                serde_json::json!({
+                    "responses": [{
+                        "question_revision_id": revision.question_revision_id,
+                        "response": "[\"Oui\"]",
+                        "version": 1,
+                        "files": []
+                    }]
+                }),
+                None,
+            )
+            .await?;
+
+        let app = Router::new()
+            .route("/api/submissions/:submission_id/pdf", get(get_submission_pdf))
+            .with_state(app_state.clone());
+        let request = |claims: Claims| {
+            let mut request = Request::builder()
+                .uri(format!("/api/submissions/{submission_id}/pdf"))
+                .body(Body::empty())
+                .unwrap();
+            request.extensions_mut().insert(claims);
+            request
+        };
+
+        let response = app.clone().oneshot(request(org_claims("user-123", &["org_user"], "Test Organization", "test-org"))).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/pdf");
+        let pdf = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        assert!(pdf.starts_with(b"%PDF-"));
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains("(Organization: Test Organization)"));
+        assert!(text.contains("(Submission: Bilan 2026)"));
+        assert!(text.contains("(Environmental)"));
+        assert!(text.contains("(Recyclez-vous ?: Oui)"));
+
+        let response = app.oneshot(request(org_claims("user-123", &["org_user"], "Test Organization", "other-org"))).await?;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        Ok(())
+    }
+
+    #[test]
+    fn test_response_summary() {
+        assert_eq!(response_summary(&serde_json::json!("[\"Yes\",\"Partly\"]")), "Yes, Partly");
+        assert_eq!(response_summary(&serde_json::json!("{\"yesNo\":true,\"percentage\":80}")), "Yes, 80%");
+        assert_eq!(response_summary(&serde_json::json!("We publish a report")), "We publish a report");
+        assert_eq!(response_summary(&serde_json::Value::Null), "No answer");
+    }
+
+    async fn timeline_request(
+        app_state: &AppState,
+        claims: Claims,
+        request: Request<Body>,
+    ) -> Result<(StatusCode, serde_json::Value), Box<dyn std::error::Error>> {
+        let app = Router::new()
+            .route("/api/admin/submissions/:submission_id/timeline", get(get_submission_timeline))
+            .route(
+                "/api/submissions/:submission_id/timeline/comment",
+                axum::routing::post(add_submission_comment),
+            )
+            .layer(Extension(claims))
+            .with_state(app_state.clone());
+
+        let response = app.oneshot(request).await?;
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok((status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null)))
+    }
+
+    async fn fetch_timeline(
+        app_state: &AppState,
+        claims: Claims,
+        submission_id: Uuid,
+    ) -> Result<(StatusCode, serde_json::Value), Box<dyn std::error::Error>> {
+        let request = Request::builder()
+            .uri(format!("/api/admin/submissions/{submission_id}/timeline"))
+            .body(Body::empty())?;
+        timeline_request(app_state, claims, request).await
+    }
+
+    async fn create_submission(app_state: &AppState) -> Result<Uuid, Box<dyn std::error::Error>> {
+        let submission_id = Uuid::new_v4();
+        app_state
+            .database
+            .assessments_submission
+            .create_submission(
+                submission_id,
+                "test-org".to_string(),
+                "Test Organization".to_string(),
+                serde_json::json!({ "responses": [] }),
+                None,
+            )
+            .await?;
+        Ok(submission_id)
+    }
+
+    #[tokio::test]
+    async fn test_timeline_grows_with_each_status_change() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let submission_id = create_submission(&app_state).await?;
+        let reviewer = org_claims("application_admin-user", &["application_admin"], "Test Organization", "other-org");
+        let actor = TimelineActor::from_claims(&reviewer);
+
+        let (status, body) = fetch_timeline(&app_state, reviewer.clone(), submission_id).await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["events"], serde_json::json!([]));
+
+        for (expected_len, new_status) in [(1, SubmissionStatus::Reviewed), (2, SubmissionStatus::Approved)] {
+            app_state
+                .database
+                .assessments_submission
+                .update_submission_status(submission_id, new_status, &actor)
+                .await?;
+            let (_, body) = fetch_timeline(&app_state, reviewer.clone(), submission_id).await?;
+            assert_eq!(body["events"].as_array().unwrap().len(), expected_len);
+        }
+
+        let (_, body) = fetch_timeline(&app_state, org_claims("org_admin-user", &["org_admin"], "Test Organization", "test-org"), submission_id).await?;
+        let events = body["events"].as_array().unwrap();
+        assert_eq!(events[0]["from_status"], "under_review");
+        assert_eq!(events[0]["to_status"], "reviewed");
+        assert_eq!(events[1]["from_status"], "reviewed");
+        assert_eq!(events[1]["to_status"], "approved");
+        assert_eq!(events[1]["actor_user_id"], "application_admin-user");
+        assert_eq!(events[1]["actor_role"], "application_admin");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_org_admin_comments_on_own_submission_only() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let submission_id = create_submission(&app_state).await?;
+        let comment = |claims: Claims| {
+            let app_state = app_state.clone();
+            async move {
+                let request = Request::builder()
+                    .method("POST")
+                    .uri(format!("/api/submissions/{submission_id}/timeline/comment"))
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::json!({ "comment": "Please attach the policy" }).to_string()))?;
+                timeline_request(&app_state, claims, request).await
+            }
+        };
+
+        let (status, _) = comment(org_claims("org_admin-user", &["org_admin"], "Test Organization", "other-org")).await?;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, _) = comment(org_claims("org_user-user", &["org_user"], "Test Organization", "test-org")).await?;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, event) = comment(org_claims("org_admin-user", &["org_admin"], "Test Organization", "test-org")).await?;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(event["comment"], "Please attach the policy");
+        assert_eq!(event["from_status"], "under_review");
+        assert_eq!(event["to_status"], "under_review");
+
+        let (_, body) = fetch_timeline(&app_state, org_claims("org_admin-user", &["org_admin"], "Test Organization", "test-org"), submission_id).await?;
+        assert_eq!(body["events"].as_array().unwrap().len(), 1);
+        Ok(())
+    }
+
+    async fn reopen(
+        app_state: &AppState,
+        claims: Claims,
+        submission_id: Uuid,
+        query: &str,
+    ) -> Result<(StatusCode, serde_json::Value), Box<dyn std::error::Error>> {
+        let app = Router::new()
+            .route("/api/submissions/:submission_id/reopen", axum::routing::post(reopen_submission))
+            .layer(Extension(claims))
+            .with_state(app_state.clone());
+
+        let request = Request::builder()
+            .method("POST")
+            .uri(format!("/api/submissions/{submission_id}/reopen{query}"))
+            .body(Body::empty())?;
+        let response = app.oneshot(request).await?;
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok((status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null)))
+    }
+
+    #[tokio::test]
+    async fn test_admin_reopens_submission() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let submission_id = create_submission(&app_state).await?;
+        let report = app_state.database.submission_reports.create_report(submission_id, None).await?;
+        let admin = org_claims("application_admin-user", &["application_admin"], "Test Organization", "other-org");
+        assert!(app_state.database.assessments.is_assessment_locked(submission_id).await?);
+
+        let (status, body) = reopen(&app_state, admin.clone(), submission_id, "?invalidate_report=true").await?;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["review_status"], "reopened");
+        assert_eq!(body["reopened_by"], "application_admin-user");
+        assert_eq!(body["report_invalidated"], true);
+
+        let submission = app_state
+            .database
+            .assessments_submission
+            .get_submission_by_assessment_id(submission_id)
+            .await?
+            .unwrap();
+        assert_eq!(submission.status, SubmissionStatus::Reopened);
+        assert_eq!(submission.reopened_by.as_deref(), Some("application_admin-user"));
+        assert!(submission.reopened_at.is_some());
+        assert!(!app_state.database.assessments.is_assessment_locked(submission_id).await?);
+
+        let report = app_state.database.submission_reports.get_report_by_id(report.report_id).await?.unwrap();
+        assert_eq!(report.status, "invalidated");
+
+        let (_, body) = fetch_timeline(&app_state, admin.clone(), submission_id).await?;
+        let events = body["events"].as_array().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0]["from_status"], "under_review");
+        assert_eq!(events[0]["to_status"], "reopened");
+        assert_eq!(events[0]["actor_user_id"], "application_admin-user");
+
+        let (status, _) = reopen(&app_state, admin, submission_id, "").await?;
+        assert_eq!(status, StatusCode::CONFLICT);
+        let (status, _) = reopen(&app_state, org_claims("application_admin-user", &["application_admin"], "Test Organization", "other-org"), Uuid::new_v4(), "").await?;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_only_admins_reopen_submissions() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let submission_id = create_submission(&app_state).await?;
+
+        for role in ["org_admin", "org_user"] {
+            let (status, _) = reopen(&app_state, org_claims(&format!("{role}-user"), &[role], "Test Organization", "test-org"), submission_id, "").await?;
+            assert_eq!(status, StatusCode::FORBIDDEN);
+        }
+
+        let submission = app_state
+            .database
+            .assessments_submission
+            .get_submission_by_assessment_id(submission_id)
+            .await?
+            .unwrap();
+        assert_eq!(submission.status, SubmissionStatus::UnderReview);
+        assert_eq!(submission.reopened_by, None);
+        Ok(())
+    }
+
+    async fn recall(
+        app_state: &AppState,
+        claims: Claims,
+        submission_id: Uuid,
+    ) -> Result<StatusCode, Box<dyn std::error::Error>> {
+        let app = Router::new()
+            .route("/api/submissions/:submission_id/recall", axum::routing::post(recall_submission))
+            .layer(Extension(claims))
+            .with_state(app_state.clone());
+
+        let request = Request::builder()
+            .method("POST")
+            .uri(format!("/api/submissions/{submission_id}/recall"))
+            .body(Body::empty())?;
+        Ok(app.oneshot(request).await?.status())
+    }
+
+    /// A submission of "test-org" whose assessment still exists
+    async fn create_assessment_submission(app_state: &AppState) -> Result<Uuid, Box<dyn std::error::Error>> {
+        use sea_orm::{ActiveModelTrait, Set};
+
+        let submission_id = create_submission(app_state).await?;
+        assessments::ActiveModel {
+            assessment_id: Set(submission_id),
+            org_id: Set("test-org".to_string()),
+            language: Set("en".to_string()),
+            name: Set("Annual Assessment".to_string()),
+            created_at: Set(chrono::Utc::now()),
+        }
+        .insert(app_state.database.get_connection())
+        .await?;
+        Ok(submission_id)
+    }
+
+    #[tokio::test]
+    async fn test_org_admin_recalls_submission() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let submission_id = create_assessment_submission(&app_state).await?;
+        let mut admin_events = app_state.admin_events.subscribe();
+
+        assert_eq!(recall(&app_state, org_claims("org_admin-user", &["org_admin"], "Test Organization", "other-org"), submission_id).await?, StatusCode::FORBIDDEN);
+        assert_eq!(recall(&app_state, org_claims("org_user-user", &["org_user"], "Test Organization", "test-org"), submission_id).await?, StatusCode::FORBIDDEN);
+
+        assert_eq!(recall(&app_state, org_claims("org_admin-user", &["org_admin"], "Test Organization", "test-org"), submission_id).await?, StatusCode::NO_CONTENT);
+        let submission = app_state
+            .database
+            .assessments_submission
+            .get_submission_by_assessment_id(submission_id)
+            .await?
+            .unwrap();
+        assert_eq!(submission.status, SubmissionStatus::Reopened);
+        assert_eq!(submission.reopened_by.as_deref(), Some("org_admin-user"));
+        assert_eq!(
+            admin_events.try_recv()?,
+            AdminEvent::SubmissionRecalled {
+                submission_id,
+                org_id: "test-org".to_string(),
+                org_name: "Test Organization".to_string(),
+            }
+        );
+
+        // The assessment is a draft again: still there, editable, not waiting for review
+        assert!(app_state.database.assessments.get_assessment_by_id(submission_id).await?.is_some());
+        assert!(!app_state.database.assessments.is_assessment_locked(submission_id).await?);
+        assert!(app_state
+            .database
+            .temp_submission
+            .get_temp_submission_by_assessment_id(submission_id)
+            .await?
+            .is_none());
+
+        // The recall is kept on the timeline
+        let events = app_state.database.submission_timeline.get_timeline(submission_id).await?;
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].from_status.as_deref(), Some("under_review"));
+        assert_eq!(events[0].to_status, "reopened");
+        assert_eq!(events[0].actor_user_id, "org_admin-user");
+        assert!(events[0].comment.is_some());
+
+        assert_eq!(recall(&app_state, org_claims("org_admin-user", &["org_admin"], "Test Organization", "test-org"), submission_id).await?, StatusCode::CONFLICT);
+        assert_eq!(recall(&app_state, org_claims("org_admin-user", &["org_admin"], "Test Organization", "test-org"), Uuid::new_v4()).await?, StatusCode::NOT_FOUND);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_submission_with_reports_cannot_be_recalled() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let submission_id = create_assessment_submission(&app_state).await?;
+        app_state.database.submission_reports.create_report(submission_id, None).await?;
+
+        assert_eq!(recall(&app_state, org_claims("org_admin-user", &["org_admin"], "Test Organization", "test-org"), submission_id).await?, StatusCode::CONFLICT);
+        assert!(app_state.database.assessments.is_assessment_locked(submission_id).await?);
+        assert!(app_state.database.submission_timeline.get_timeline(submission_id).await?.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reviewed_submission_cannot_be_recalled() -> Result<(), Box<dyn std::error::Error>> {
+        let app_state = setup().await?;
+        let submission_id = create_assessment_submission(&app_state).await?;
+        let reviewer = TimelineActor::from_claims(&org_claims("application_admin-user", &["application_admin"], "Test Organization", "other-org"));
+        app_state
+            .database
+            .assessments_submission
+            .update_submission_status(submission_id, SubmissionStatus::Reviewed, &reviewer)
+            .await?;
+
+        assert_eq!(recall(&app_state, org_claims("org_admin-user", &["org_admin"], "Test Organization", "test-org"), submission_id).await?, StatusCode::CONFLICT);
+        assert!(app_state.database.assessments.is_assessment_locked(submission_id).await?);
+        Ok(())
+    }
+}
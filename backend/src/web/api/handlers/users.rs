@@ -0,0 +1,138 @@
+use axum::{extract::Extension, Json};
+
+use crate::common::models::claims::Claims;
+use crate::web::api::models::{CurrentUserResponse, UserOrganization};
+
+/// Profile and capabilities of the current user, so clients need not decode the token
+#[utoipa::path(
+    get,
+    path = "/me",
+    tag = "User",
+    responses((status = 200, description = "Current user", body = CurrentUserResponse))
+)]
+pub async fn get_current_user(Extension(claims): Extension<Claims>) -> Json<CurrentUserResponse> {
+    let mut organizations: Vec<UserOrganization> = claims
+        .organizations
+        .iter()
+        .flat_map(|orgs| orgs.orgs.iter())
+        .map(|(name, info)| UserOrganization {
+            id: info.id.clone(),
+            name: name.clone(),
+            categories: info.categories.clone(),
+        })
+        .collect();
+    organizations.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Json(CurrentUserResponse {
+        is_application_admin: claims.is_application_admin(),
+        is_org_admin: claims.is_org_admin(),
+        can_create_assessments: claims.can_create_assessments(),
+        can_answer_assessments: claims.can_answer_assessments(),
+        roles: claims
+            .realm_access
+            .as_ref()
+            .map(|access| access.roles.clone())
+            .unwrap_or_default(),
+        organizations,
+        sub: claims.sub,
+        username: claims.preferred_username,
+        email: claims.email,
+        given_name: claims.given_name,
+        family_name: claims.family_name,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::claims;
+    use crate::common::models::claims::{OrganizationInfo, Organizations};
+    use axum::{body::Body, http::Request, routing::get, Router};
+    use std::collections::HashMap;
+    use tower::ServiceExt;
+
+    fn claims_with_roles(roles: &[&str], organizations: Option<Organizations>) -> Claims {
+        Claims {
+            organizations,
+            email: Some("test@example.com".to_string()),
+            preferred_username: "testuser".to_string(),
+            ..claims("user-123", roles)
+        }
+    }
+
+    async fn fetch_profile(claims: Claims) -> Result<CurrentUserResponse, Box<dyn std::error::Error>> {
+        let app = Router::new()
+            .route("/api/me", get(get_current_user))
+            .layer(Extension(claims));
+
+        let response = app
+            .oneshot(Request::builder().uri("/api/me").body(Body::empty())?)
+            .await?;
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+
+    #[tokio::test]
+    async fn test_org_user_can_only_answer() -> Result<(), Box<dyn std::error::Error>> {
+        let organizations = Organizations {
+            orgs: HashMap::from([(
+                "Test Organization".to_string(),
+                OrganizationInfo {
+                    id: Some("test-org".to_string()),
+                    categories: vec!["environment".to_string()],
+                },
+            )]),
+        };
+        let profile = fetch_profile(claims_with_roles(&["Org_User"], Some(organizations))).await?;
+
+        assert_eq!(profile.sub, "user-123");
+        assert_eq!(profile.email.as_deref(), Some("test@example.com"));
+        assert_eq!(profile.roles, vec!["Org_User"]);
+        assert_eq!(profile.organizations.len(), 1);
+        assert_eq!(profile.organizations[0].id.as_deref(), Some("test-org"));
+        assert_eq!(profile.organizations[0].categories, vec!["environment"]);
+        assert!(!profile.is_application_admin);
+        assert!(!profile.is_org_admin);
+        assert!(!profile.can_create_assessments);
+        assert!(profile.can_answer_assessments);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_org_admin_can_create_assessments() -> Result<(), Box<dyn std::error::Error>> {
+        let profile = fetch_profile(claims_with_roles(&["org_admin"], None)).await?;
+
+        assert!(!profile.is_application_admin);
+        assert!(profile.is_org_admin);
+        assert!(profile.can_create_assessments);
+        assert!(profile.can_answer_assessments);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_application_admin_without_organizations() -> Result<(), Box<dyn std::error::Error>> {
+        let profile = fetch_profile(claims_with_roles(&["application_admin"], None)).await?;
+
+        assert!(profile.organizations.is_empty());
+        assert!(profile.is_application_admin);
+        assert!(!profile.is_org_admin);
+        assert!(profile.can_create_assessments);
+        assert!(profile.can_answer_assessments);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_user_without_roles_has_no_capabilities() -> Result<(), Box<dyn std::error::Error>> {
+        let mut claims = claims_with_roles(&[], None);
+        claims.realm_access = None;
+        let profile = fetch_profile(claims).await?;
+
+        assert!(profile.roles.is_empty());
+        assert!(!profile.is_application_admin);
+        assert!(!profile.is_org_admin);
+        assert!(!profile.can_create_assessments);
+        assert!(!profile.can_answer_assessments);
+        Ok(())
+    }
+}
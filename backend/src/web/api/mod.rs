@@ -2,3 +2,4 @@ pub mod error;
 pub mod handlers;
 pub mod models;
 pub mod routes;
+pub mod strict_json;
@@ -25,6 +25,64 @@ pub struct SubmissionDetailResponse {
     pub submission: Submission,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubmittedResponse {
+    pub question_revision_id: Uuid,
+    /// Question text per language, as stored on the revision
+    pub question_text: serde_json::Value,
+    pub category: String,
+    pub response: serde_json::Value,
+    pub files: Vec<FileMetadata>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SubmissionResponsesDetail {
+    pub submission_id: Uuid,
+    pub responses: Vec<SubmittedResponse>,
+}
+
+/// A status change or review comment on a submission
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TimelineEvent {
+    pub event_id: Uuid,
+    pub submission_id: Uuid,
+    /// `None` for the initial submission
+    pub from_status: Option<String>,
+    pub to_status: String,
+    pub actor_user_id: String,
+    pub actor_role: String,
+    pub comment: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TimelineResponse {
+    pub events: Vec<TimelineEvent>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TimelineCommentRequest {
+    pub comment: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ReopenSubmissionQuery {
+    /// Mark the submission's reports as invalidated
+    #[serde(default)]
+    pub invalidate_report: bool,
+}
+
+/// A submission after it was reopened for corrections
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReopenedSubmission {
+    pub submission_id: Uuid,
+    pub review_status: String,
+    pub reopened_at: String,
+    pub reopened_by: String,
+    pub report_invalidated: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub enum UserInvitationStatus {
     Pending,
@@ -38,8 +96,10 @@ pub struct Organization {
     pub attributes: Option<serde_json::Value>,
 }
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
+use crate::common::services::report_progress::GenerationStatus;
+use crate::common::services::skip_logic::QuestionSkipRule;
 
 // Create wrapper type for Uuid to avoid orphan rules
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -59,10 +119,18 @@ impl From<crate::web::api::error::ApiError> for ApiErrorWrapper {
             crate::web::api::error::ApiError::NotFound(msg) => Self { error: msg },
             crate::web::api::error::ApiError::Forbidden(msg) => Self { error: msg },
             crate::web::api::error::ApiError::Conflict(msg) => Self { error: msg },
+            crate::web::api::error::ApiError::PreconditionFailed(msg) => Self { error: msg },
+            crate::web::api::error::ApiError::PreconditionRequired(msg) => Self { error: msg },
+            crate::web::api::error::ApiError::PayloadTooLarge(msg) => Self { error: msg },
+            crate::web::api::error::ApiError::UnsupportedMediaType(msg) => Self { error: msg },
+            crate::web::api::error::ApiError::TooManyRequests(msg) => Self { error: msg },
             crate::web::api::error::ApiError::InternalServerError(msg) => Self { error: msg },
             crate::web::api::error::ApiError::DatabaseError(msg) => Self {
                 error: format!("Database error: {msg}"),
             },
+            err @ crate::web::api::error::ApiError::Validation(_) => Self {
+                error: err.message().to_string(),
+            },
         }
     }
 }
@@ -93,6 +161,27 @@ pub struct HealthChecks {
     pub keycloak: String,
 }
 
+/// Outcome of the dependency checks behind `GET /health`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HealthStatus {
+    pub status: String, // "healthy", "degraded" or "unhealthy"
+    pub checks: HealthStatusChecks,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HealthStatusChecks {
+    pub database: CheckResult,
+    pub keycloak: CheckResult,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckResult {
+    pub status: String, // "ok" or "error"
+    pub latency_ms: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 pub struct MetricsResponse {
     pub uptime: f64,
@@ -126,6 +215,10 @@ pub struct Question {
     pub question_id: Uuid,
     pub category: String,
     pub created_at: String,
+    pub skip_rules: Vec<QuestionSkipRule>,
+    /// Answers are encrypted at rest
+    #[serde(default)]
+    pub sensitive: bool,
     pub latest_revision: QuestionRevision,
 }
 
@@ -139,18 +232,61 @@ pub struct QuestionRevision {
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct CreateQuestionRequest {
     pub category_id: Uuid,
     pub text: HashMap<String, String>, // Multilingual text
     pub weight: f64,
+    /// Encrypt answers to the question at rest
+    #[serde(default)]
+    pub sensitive: bool,
+}
+
+/// Outcome of a question import
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct QuestionImportResult {
+    pub created: usize,
+    pub updated: usize,
+    /// Existing questions left as they were, unchanged or skipped on purpose
+    pub skipped: usize,
 }
 
+/// Version of the question export document, raised when its shape changes
+pub const QUESTION_EXPORT_VERSION: u32 = 1;
+
+/// The question catalog as exported, accepted as is by the question import
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct QuestionExport {
+    pub version: u32,
+    pub exported_at: String,
+    pub questions: Vec<ExportedQuestion>,
+}
+
+/// A question with its latest revision
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ExportedQuestion {
+    /// The question's external key, or its ID when it has none
+    pub external_key: String,
+    /// Category catalog name
+    pub category: String,
+    pub text: HashMap<String, String>, // Multilingual text
+    pub weight: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct UpdateQuestionRequest {
     pub category_id: Uuid,
     pub category: String, // Keep this for the response
     pub text: HashMap<String, String>, // Multilingual text
     pub weight: f64,
+    /// Replaces the question's skip rules when given
+    #[serde(default)]
+    pub skip_rules: Option<Vec<QuestionSkipRule>>,
+    /// Changes whether answers are encrypted at rest when given; answers saved before
+    /// keep the form they were stored in
+    #[serde(default)]
+    pub sensitive: Option<bool>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -210,22 +346,101 @@ pub struct Assessment {
     pub name: String,
     pub categories: Vec<Uuid>,
     pub status: AssessmentStatus,
+    /// Percentage (0-100) of questions in the assigned categories that have a response
+    pub completion_percent: f64,
     pub created_at: String,
     pub updated_at: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct CreateAssessmentRequest {
     pub language: String,
     pub name: String,
     pub categories: Vec<Uuid>,
 }
 
+/// Reusable assessment setup, see `POST /assessments/from-template/{template_id}`
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateAssessmentTemplateRequest {
+    pub name: String,
+    pub language: String,
+    #[serde(default)]
+    pub categories: Vec<Uuid>,
+    /// Questions whose categories are added to assessments started from the template
+    #[serde(default)]
+    pub questions: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssessmentTemplate {
+    pub template_id: Uuid,
+    pub name: String,
+    pub language: String,
+    pub categories: Vec<Uuid>,
+    pub questions: Vec<Uuid>,
+    pub created_by: String,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssessmentTemplateResponse {
+    pub template: AssessmentTemplate,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssessmentTemplateListResponse {
+    pub templates: Vec<AssessmentTemplate>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssessmentFromTemplateResponse {
+    pub assessment: Assessment,
+    pub template_id: Uuid,
+    /// The template's questions that still exist
+    pub questions: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct UpdateAssessmentRequest {
     pub language: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateAssessmentStatusRequest {
+    pub status: AssessmentStatus,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssessmentStatusResponse {
+    pub assessment_id: Uuid,
+    pub previous_status: AssessmentStatus,
+    pub status: AssessmentStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AnsweredQuestion {
+    pub question_revision_id: Uuid,
+    pub value: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NextQuestionsRequest {
+    pub answered_so_far: Vec<AnsweredQuestion>,
+}
+
+/// The assessment's questions split by their skip rules, in the order they were created
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NextQuestionsResponse {
+    pub visible_questions: Vec<Uuid>,
+    pub skipped_questions: Vec<Uuid>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct AssessmentResponse {
     pub assessment: Assessment,
@@ -251,12 +466,14 @@ pub struct Response {
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct CreateResponseRequest {
     pub question_revision_id: Uuid,
     pub response: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct UpdateResponseRequest {
     pub response: Vec<String>,
     pub version: i32,
@@ -272,6 +489,57 @@ pub struct ResponseListResponse {
     pub responses: Vec<Response>,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssessmentUsageSummary {
+    pub assessment_id: Uuid,
+    pub org_id: String,
+    pub org_name: String,
+    pub response_count: u32,
+    pub last_responded_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct QuestionUsage {
+    pub question_id: Uuid,
+    pub total_responses: u64,
+    pub assessments_using: Vec<AssessmentUsageSummary>,
+    /// Some of the listed assessments are submitted and still waiting for review
+    pub has_pending_submissions: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResponseDiffQuery {
+    pub from: i32,
+    pub to: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseChangeKind {
+    /// Answered only in the `to` version
+    Added,
+    /// Answered only in the `from` version
+    Removed,
+    /// Answered in both versions with different values
+    Changed,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ResponseChange {
+    pub question_revision_id: Uuid,
+    pub change: ResponseChangeKind,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ResponseDiffResponse {
+    pub assessment_id: Uuid,
+    pub from: i32,
+    pub to: i32,
+    pub changes: Vec<ResponseChange>,
+}
+
 // =============== Submission Models ===============
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -335,6 +603,51 @@ pub struct AdminResponseDetail {
     pub files: Vec<FileMetadata>,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AssessmentSummary {
+    pub assessment_id: Uuid,
+    pub name: String,
+    /// `draft` for unsubmitted assessments, otherwise the review status
+    pub status: String,
+    pub submitted_at: Option<String>,
+    pub has_report: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminUserAssessmentsResponse {
+    pub user_id: String,
+    pub org_id: Option<String>,
+    pub org_name: Option<String>,
+    pub assessments: Vec<AssessmentSummary>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AppliedMigrationInfo {
+    pub name: String,
+    pub applied_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MigrationStatusResponse {
+    pub applied: Vec<AppliedMigrationInfo>,
+    pub pending: Vec<String>,
+    /// Applied migrations this build does not know about
+    pub unknown: Vec<String>,
+}
+
+/// Headline numbers for the admin dashboard
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminDashboardResponse {
+    pub total_organizations: u64,
+    pub total_users: u64,
+    pub total_submissions: u64,
+    /// Every submission status, with 0 for those no submission is in
+    pub submissions_by_status: std::collections::BTreeMap<String, u64>,
+    pub total_reports: u64,
+    /// Assessments not submitted within `ASSESSMENT_OVERDUE_DAYS` of their creation
+    pub overdue_assessments: u64,
+}
+
 // =============== Review Models ===============
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -351,12 +664,14 @@ pub struct Review {
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct CreateReviewRequest {
     pub decision: String,
     pub comments: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct UpdateReviewRequest {
     pub status: Option<String>,
     pub decision: Option<String>,
@@ -413,6 +728,7 @@ pub struct AdminReportListResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct AssignReviewerRequest {
     pub submission_id: Uuid,
     pub reviewer_id: String,
@@ -426,6 +742,27 @@ pub struct ReviewAssignmentResponse {
     pub assigned_at: String,
 }
 
+/// Organization as stored in the local cache of Keycloak organizations
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CachedOrganization {
+    pub id: String,
+    pub name: String,
+    pub domains: Vec<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizationSearchResponse {
+    pub organizations: Vec<CachedOrganization>,
+    /// When the cache was last refreshed from Keycloak
+    pub synced_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizationCacheRefreshResponse {
+    pub organizations: usize,
+    pub synced_at: Option<String>,
+}
+
 // =============== File Models ===============
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -449,6 +786,12 @@ pub struct FileMetadataResponse {
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct FileListResponse {
+    pub files: Vec<FileMetadata>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct AttachFileRequest {
     pub file_id: Uuid,
 }
@@ -467,6 +810,69 @@ pub struct Report {
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CategoryScore {
+    pub category: String,
+    pub score: f64, // 0-100
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CategoryPercentile {
+    pub category: String,
+    pub percentile: f64, // Share of the pool scoring lower, ties counting half
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BenchmarkResponse {
+    pub org_scores: Vec<CategoryScore>,
+    pub sector_averages: Vec<CategoryScore>,
+    pub percentile_ranks: Vec<CategoryPercentile>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ComparisonMatrixQuery {
+    /// Only reports generated at or after this time
+    pub from: Option<chrono::DateTime<chrono::Utc>>,
+    /// Only reports generated at or before this time
+    pub to: Option<chrono::DateTime<chrono::Utc>>,
+    /// Comma-separated category names, in column order; every scored category when absent
+    pub categories: Option<String>,
+    /// `csv` for a CSV download, JSON otherwise
+    pub format: Option<String>,
+}
+
+/// Category scores of each organization side by side
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ComparisonMatrix {
+    pub categories: Vec<String>,
+    /// Highest average score first
+    pub rows: Vec<OrgRow>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct OrgRow {
+    pub org_id: String,
+    pub org_name: String,
+    /// One per category, `null` where the organization's report does not score it or it
+    /// has no report in the period
+    pub scores: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CategoryStatistics {
+    pub category: String,
+    pub average_score: f64, // 0-100
+    pub report_count: usize, // Reports covering this category
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct OrganizationStatisticsResponse {
+    pub org_id: String,
+    pub report_count: usize,
+    pub categories: Vec<CategoryStatistics>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct GenerateReportRequest {
     pub category: String,
     pub recommendation: String,
@@ -474,6 +880,7 @@ pub struct GenerateReportRequest {
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct UpdateRecommendationStatusRequest {
     pub report_id: Uuid,
     pub recommendation_id: String,
@@ -481,6 +888,23 @@ pub struct UpdateRecommendationStatusRequest {
     pub status: String,
 }
 
+/// A recommendation a reviewer adds to an existing report
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AddRecommendationRequest {
+    pub category: String,
+    pub text: String,
+}
+
+/// A recommendation as stored in a report's data
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReportRecommendation {
+    pub id: Uuid,
+    pub category: String,
+    pub text: String,
+    pub status: String,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct OrganizationActionPlan {
     pub organization_id: Uuid,
@@ -505,12 +929,68 @@ pub struct ActionPlanListResponse {
     pub organizations: Vec<OrganizationActionPlan>,
 }
 
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct RecommendationStatusCounts {
+    pub todo: u32,
+    pub in_progress: u32,
+    pub done: u32,
+    pub approved: u32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizationActionPlanSummary {
+    pub organization_id: Uuid,
+    pub organization_name: String,
+    pub counts: RecommendationStatusCounts,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ActionPlanSummaryResponse {
+    pub organizations: Vec<OrganizationActionPlanSummary>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ActionPlanQuery {
+    /// Keep only recommendations with this status; organizations left without any are dropped
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ReportGenerationResponse {
     pub report_id: Uuid,
     pub status: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ReportPreviewResponse {
+    pub submission_id: Uuid,
+    pub data: serde_json::Value,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct GenerateReportQuery {
+    /// Also mark the submission as reviewed, as report generation used to do
+    #[serde(default)]
+    pub mark_reviewed: bool,
+    /// Answer right away and generate the report in the background
+    #[serde(default)]
+    pub background: bool,
+}
+
+/// How far the generation of a report has come
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct GenerationProgress {
+    pub report_id: Uuid,
+    pub status: GenerationStatus,
+    pub steps_completed: u32,
+    pub total_steps: u32,
+    pub current_step: String,
+    pub error: Option<String>,
+    /// From the average time of earlier generations, while still running
+    pub estimated_completion_secs: Option<u32>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ReportResponse {
     pub report: Report,
@@ -521,14 +1001,59 @@ pub struct ReportListResponse {
     pub reports: Vec<Report>,
 }
 
+// =============== Notification Models ===============
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct Notification {
+    pub notification_id: Uuid,
+    #[serde(rename = "type")]
+    pub notification_type: String,
+    pub title: String,
+    pub body: String,
+    pub read_at: Option<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct NotificationListResponse {
+    pub notifications: Vec<Notification>,
+}
+
+// =============== User Models ===============
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserOrganization {
+    pub id: Option<String>,
+    pub name: String,
+    pub categories: Vec<String>,
+}
+
+/// Who the caller is and what the backend lets them do, taken from their token
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CurrentUserResponse {
+    pub sub: String,
+    pub username: String,
+    pub email: Option<String>,
+    pub given_name: Option<String>,
+    pub family_name: Option<String>,
+    pub organizations: Vec<UserOrganization>,
+    pub roles: Vec<String>,
+    pub is_application_admin: bool,
+    pub is_org_admin: bool,
+    pub can_create_assessments: bool,
+    pub can_answer_assessments: bool,
+}
+
 // =============== Organization Models ===============
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct OrganizationDomainRequest {
     pub name: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct OrganizationCreateRequest {
     pub name: String,
     pub domains: Vec<OrganizationDomainRequest>,
@@ -538,19 +1063,128 @@ pub struct OrganizationCreateRequest {
     pub attributes: Option<HashMap<String, Vec<String>>>,
 }
 
+/// A row of a bulk import that failed validation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ImportValidationError {
+    /// Line in a CSV file, the header is row 1, or position in a JSON array starting at 1
+    pub row: usize,
+    pub field: String,
+    pub message: String,
+}
+
+/// A bulk import rejected because of invalid rows
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportRejectedResponse {
+    pub error: String,
+    pub validation_errors: Vec<ImportValidationError>,
+}
+
+/// A row of a bulk organization import that was not imported
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ImportRowError {
+    pub row: usize,
+    pub name: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportResult {
+    pub total: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub errors: Vec<ImportRowError>,
+}
+
+/// Outcome of updating one member's categories in a bulk update
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct MemberCategoryUpdateResult {
+    pub success: bool,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct BulkMemberCategoryUpdateResponse {
+    pub updated: usize,
+    pub failed: usize,
+    /// Member ID -> outcome
+    pub results: BTreeMap<String, MemberCategoryUpdateResult>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct MemberRequest {
     pub user_id: String,
     pub roles: Vec<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct InvitationRequest {
     pub email: String,
     pub roles: Vec<String>,
     pub expiration: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum InvitationResultStatus {
+    /// An existing user was added to the organization
+    Added,
+    /// A new account was created and an invitation email sent
+    Invited,
+    /// The user was already a member, nothing changed
+    AlreadyMember,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct InvitationResultResponse {
+    pub status: InvitationResultStatus,
+    pub user_id: String,
+}
+
+/// Move a user from one organization to another
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UserTransferRequest {
+    pub from_org: String,
+    pub to_org: String,
+    /// Roles in the target organization
+    pub roles: Vec<String>,
+    /// Category name in the source organization -> name in the target organization.
+    /// Categories without an entry are kept as they are.
+    #[serde(default)]
+    pub category_mapping: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferStepStatus {
+    Done,
+    Failed,
+}
+
+/// One step of a transfer, in the order they ran
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TransferStep {
+    /// `add_to_target_org`, `remove_from_source_org` or `map_categories`
+    pub step: String,
+    pub status: TransferStepStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct UserTransferResponse {
+    pub user_id: String,
+    pub from_org: String,
+    pub to_org: String,
+    /// False when a step after joining the target organization failed
+    pub completed: bool,
+    pub steps: Vec<TransferStep>,
+    /// Categories the user has after the transfer
+    pub categories: Vec<String>,
+}
+
 // =============== Category Models ===============
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -565,6 +1199,7 @@ pub struct Category {
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct CreateCategoryRequest {
     pub name: String,
     pub weight: i32,
@@ -573,6 +1208,7 @@ pub struct CreateCategoryRequest {
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct UpdateCategoryRequest {
     pub name: Option<String>,
     pub weight: Option<i32>,
@@ -600,21 +1236,89 @@ pub struct CategoryCatalog {
     pub is_active: bool,
     pub created_at: String,
     pub updated_at: String,
+    pub default_recommendation: Option<serde_json::Value>,
+    pub deactivated_at: Option<String>,
+    pub scoring_mode: crate::common::database::entity::category_catalog::ScoringMode,
+    /// Pass mark between 0 and 100 of threshold scoring
+    pub score_threshold: Option<f64>,
+    pub icon_name: Option<String>,
+    /// Card color as `#RRGGBB`
+    pub color_hex: Option<String>,
+    pub metadata: serde_json::Value,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct CreateCategoryCatalogRequest {
     pub name: String,
     pub description: Option<String>,
     pub template_id: String,
     pub is_active: Option<bool>,
+    /// Recommendation text per language code, e.g. `{"en": "..."}`
+    pub default_recommendation: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct UpdateCategoryCatalogRequest {
     pub name: Option<String>,
     pub description: Option<String>,
     pub is_active: Option<bool>,
+    /// Recommendation text per language code, e.g. `{"en": "..."}`
+    pub default_recommendation: Option<serde_json::Value>,
+    /// Given together with `score_threshold` when switching to threshold scoring
+    pub scoring_mode: Option<crate::common::database::entity::category_catalog::ScoringMode>,
+    pub score_threshold: Option<f64>,
+}
+
+/// How the frontend renders a category card; omitted fields are cleared
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CategoryMetadataRequest {
+    pub description: Option<String>,
+    pub icon_name: Option<String>,
+    /// Card color as `#RRGGBB`
+    pub color_hex: Option<String>,
+}
+
+/// Name and description of a catalog category; omitted fields are left unchanged
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateCategoryDetailsRequest {
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CategoryListQuery {
+    /// Leave out archived categories
+    #[serde(default)]
+    pub active_only: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CategoryTreeQuery {
+    /// Levels to return, 1 for top-level categories only
+    pub depth: Option<u32>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CategoryTreeResponse {
+    pub categories: Vec<crate::common::database::entity::category_catalog::CategoryNode>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CategoryWithQuestionCount {
+    pub category_catalog_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub template_id: String,
+    pub question_count: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct CategoryWithCountsListResponse {
+    pub categories: Vec<CategoryWithQuestionCount>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -642,6 +1346,7 @@ pub struct OrganizationCategory {
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct CreateOrganizationCategoryRequest {
     pub keycloak_organization_id: String,
     pub category_catalog_id: Uuid,
@@ -650,12 +1355,41 @@ pub struct CreateOrganizationCategoryRequest {
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct UpdateOrganizationCategoryRequest {
     pub weight: Option<i32>,
     pub order: Option<i32>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateCategoryWeightRequest {
+    pub weight: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CategoryWeight {
+    pub category_catalog_id: Uuid,
+    pub weight: i32,
+}
+
+/// New weight for every category assigned to the organization; weights must sum to 100
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateCategoryWeightsRequest {
+    pub weights: Vec<CategoryWeight>,
+}
+
+/// Category catalog IDs of every category assigned to the organization, in the desired order
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ReorderCategoriesRequest {
+    pub category_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct AssignCategoriesToOrganizationRequest {
     pub category_catalog_ids: Vec<Uuid>,
     pub weights: Option<Vec<i32>>, // If not provided, weights will be distributed equally
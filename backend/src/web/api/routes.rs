@@ -1,50 +1,89 @@
 
 use crate::web::api::handlers::{
-    admin::{list_all_submissions, list_temp_submissions_by_assessment, create_user_invitation, get_user_invitation_status, delete_user},
+    assessment_templates::{create_assessment_from_template, create_assessment_template, list_assessment_templates},
+    admin::{list_all_submissions, get_submission_by_id, list_temp_submissions_by_assessment, create_user_invitation, get_user_invitation_status, delete_user, get_user_assessments, resend_verification_email, transfer_user, get_admin_dashboard, stream_admin_events, unlock_assessment},
+    categories::{archive_category, create_category, get_category, get_category_tree, list_categories, update_category, update_category_metadata},
     assessments::{
         create_assessment, delete_assessment, get_assessment, list_assessments, submit_assessment,
-        update_assessment, user_submit_draft_assessment,
+        update_assessment, user_submit_draft_assessment, get_next_questions,
+        update_assessment_status, list_organization_assessments,
     },
-    files::{attach_file, delete_file, download_file, get_file_metadata, remove_file, upload_file},
+    export::export_organization,
+    files::{attach_file, delete_file, download_file, get_file_metadata, list_response_files, remove_file, remove_question_file, upload_file},
     health::{health_check, metrics},
+    notifications::{list_notifications, mark_all_notifications_read, mark_notification_read},
     organization_categories::{
         assign_categories_to_organization, create_category_catalog, delete_category_catalog, get_category_catalog,
-        get_category_catalogs, get_organization_categories, update_category_catalog, update_organization_category,
+        get_category_catalogs, get_categories_with_counts, get_organization_categories, update_category_catalog, update_category_weight,
+        reorder_organization_categories, update_organization_category, update_organization_category_weights,
     },
     organizations::{
         add_identity_provider, add_member, create_organization, delete_organization, get_identity_provider, get_identity_providers, 
         get_member, get_member_organizations, get_member_organizations_in_org, get_members, 
         get_members_count, get_organization_by_id, get_organizations, get_organizations_count,
         invite_existing_user, invite_user, remove_identity_provider, remove_member, 
-        update_organization, add_org_admin_member, get_org_admin_members, remove_org_admin_member,
-        update_org_admin_member_categories,
+        update_organization, add_org_admin_member, import_org_members, get_org_admin_members, remove_org_admin_member,
+        update_org_admin_member_categories, search_organizations, count_cached_organizations,
+        refresh_organizations_cache, bulk_import_organizations, bulk_update_member_categories,
+        get_invitation, resend_invitation,
     },
-    questions::{create_question, delete_question_revision_by_id, get_question, list_questions, update_question},
-    reports::{delete_report, generate_report, get_report, list_reports, list_user_reports, list_all_action_plans, update_recommendation_status, list_all_reports},
-    responses::{create_response, delete_response, get_response, list_responses, update_response},
-    submissions::{delete_submission, get_submission, list_user_submissions},
+    questions::{create_question, delete_question_revision_by_id, export_questions, get_question, get_question_usage, import_questions, list_questions, update_question},
+    reports::{delete_report, export_organization_reports_zip, generate_report, regenerate_report, get_report, get_report_generation_progress, get_report_benchmark, get_organization_statistics, preview_report, review_submission, list_reports, list_user_reports, list_all_action_plans, export_action_plans_csv, summarize_action_plans, update_recommendation_status, add_report_recommendation, delete_report_recommendation, list_all_reports, get_comparison_matrix},
+    responses::{create_response, delete_all_responses, delete_response, diff_responses, get_response, list_responses, update_response},
+    submissions::{
+        add_submission_comment, delete_submission, get_submission, get_submission_responses, get_submission_pdf,
+        get_submission_timeline, list_user_submissions, recall_submission, reopen_submission,
+    },
+    users::get_current_user,
 };
 
 use axum::{
-    routing::{delete, get, post, put},
+    extract::DefaultBodyLimit,
+    middleware,
+    routing::{delete, get, patch, post, put},
     Router,
 };
+use crate::web::handlers::etag::etag_middleware;
+use crate::web::handlers::idempotency::idempotency_middleware;
 use crate::web::routes::AppState;
 
+/// Room for multipart boundaries, part headers and the metadata field on top of the file itself
+const UPLOAD_BODY_OVERHEAD: usize = 64 * 1024;
+
 pub fn create_router(app_state: AppState) -> Router {
+    let upload_body_limit = DefaultBodyLimit::max(
+        app_state
+            .upload_config
+            .max_file_bytes
+            .saturating_add(UPLOAD_BODY_OVERHEAD),
+    );
+
+    // Lets clients retry creating requests without creating the resource twice
+    let idempotent = || {
+        middleware::from_fn_with_state(
+            app_state.database.idempotency_keys.clone(),
+            idempotency_middleware,
+        )
+    };
+
     Router::new()
         // Health endpoints
         .route("/api/health", get(health_check))
         .route("/api/metrics", get(metrics))
         // Organization endpoints matching OpenAPI specification
         .route("/api/admin/organizations", get(get_organizations))
-        .route("/api/admin/organizations", post(create_organization))
+        .route("/api/admin/organizations", post(create_organization).layer(idempotent()))
+        .route("/api/admin/organizations/bulk-import", post(bulk_import_organizations))
+        .route("/api/admin/organizations/search", get(search_organizations))
+        .route("/api/admin/organizations/count", get(count_cached_organizations))
+        .route("/api/admin/organizations/cache/refresh", post(refresh_organizations_cache))
         .route("/admin/realms/:realm/organizations/count", get(get_organizations_count))
         .route("/admin/realms/:member_id/organizations", get(get_member_organizations))
         .route("/api/admin/organizations/:org_id", get(get_organization_by_id))
         // The following endpoints expect only org_id as a path parameter
         .route("/api/admin/organizations/:org_id", put(update_organization))
         .route("/api/admin/organizations/:org_id", delete(delete_organization))
+        .route("/api/admin/organizations/:org_id/export", get(export_organization))
         .route("/admin/realms/:realm/organizations/:org_id/identity-providers", get(get_identity_providers))
         .route("/admin/realms/:realm/organizations/:org_id/identity-providers", post(add_identity_provider))
         .route("/admin/realms/:realm/organizations/:org_id/identity-providers/:alias", get(get_identity_provider))
@@ -63,6 +102,9 @@ pub fn create_router(app_state: AppState) -> Router {
         .route("/api/questions/:question_id", get(get_question))
         .route("/api/questions/:question_id", put(update_question))
         .route("/api/questions/revisions/:revision_id", delete(delete_question_revision_by_id))
+        .route("/api/admin/questions/:question_id/usage", get(get_question_usage))
+        .route("/api/admin/questions/import", post(import_questions))
+        .route("/api/admin/questions/export", get(export_questions))
         // Category endpoints
         // Category Catalog endpoints
         .route("/api/category-catalog", get(get_category_catalogs))
@@ -70,14 +112,27 @@ pub fn create_router(app_state: AppState) -> Router {
         .route("/api/category-catalog/:category_catalog_id", delete(delete_category_catalog))
         .route("/api/category-catalog/:category_catalog_id", get(get_category_catalog))
         .route("/api/category-catalog/:category_catalog_id", put(update_category_catalog))
+        .route("/api/categories/with-counts", get(get_categories_with_counts))
+        .route("/api/categories/tree", get(get_category_tree))
         // Organization Categories endpoints
         .route("/api/organizations/:keycloak_organization_id/categories", get(get_organization_categories))
         .route("/api/organizations/:keycloak_organization_id/categories/assign", post(assign_categories_to_organization))
+        .route("/api/organizations/:keycloak_organization_id/categories/order", put(reorder_organization_categories))
+        .route("/api/organizations/:keycloak_organization_id/categories/weights", put(update_organization_category_weights))
         .route("/api/organizations/:keycloak_organization_id/categories/:organization_category_id", put(update_organization_category))
+        // Same path as above so the router accepts it; the id here is a category catalog ID
+        .route("/api/organizations/:keycloak_organization_id/categories/:organization_category_id", patch(update_category_weight))
         // Assessment endpoints (org-scoped)
         .route("/api/assessments", get(list_assessments))
-        .route("/api/assessments", post(create_assessment))
-        .route("/api/assessments/:assessment_id", get(get_assessment))
+        .route("/api/assessments", post(create_assessment).layer(idempotent()))
+        .route(
+            "/api/assessments/from-template/:template_id",
+            post(create_assessment_from_template).layer(idempotent()),
+        )
+        .route(
+            "/api/assessments/:assessment_id",
+            get(get_assessment).layer(middleware::from_fn(etag_middleware)),
+        )
         .route("/api/assessments/:assessment_id", put(update_assessment)) 
         .route("/api/assessments/:assessment_id", delete(delete_assessment))
         .route(
@@ -88,6 +143,14 @@ pub fn create_router(app_state: AppState) -> Router {
             "/api/assessments/:assessment_id/draft",
             post(user_submit_draft_assessment),
         )
+        .route(
+            "/api/assessments/:assessment_id/status",
+            post(update_assessment_status),
+        )
+        .route(
+            "/api/assessments/:assessment_id/next-questions",
+            post(get_next_questions),
+        )
         // Response endpoints
         .route(
             "/api/assessments/:assessment_id/responses",
@@ -97,6 +160,14 @@ pub fn create_router(app_state: AppState) -> Router {
             "/api/assessments/:assessment_id/responses",
             post(create_response),
         )
+        .route(
+            "/api/assessments/:assessment_id/responses",
+            delete(delete_all_responses),
+        )
+        .route(
+            "/api/assessments/:assessment_id/responses/diff",
+            get(diff_responses),
+        )
         .route(
             "/api/assessments/:assessment_id/responses/:response_id",
             get(get_response),
@@ -105,12 +176,13 @@ pub fn create_router(app_state: AppState) -> Router {
             "/api/assessments/:assessment_id/responses/:response_id",
             put(update_response),
         )
+        // Same path as above so the router accepts it; the id here is a question revision ID
         .route(
             "/api/assessments/:assessment_id/responses/:response_id",
             delete(delete_response),
         )
         // File endpoints
-        .route("/api/files", post(upload_file))
+        .route("/api/files", post(upload_file).layer(upload_body_limit))
         .route("/api/files/:file_id", get(download_file))
         .route("/api/files/:file_id", delete(delete_file))
         .route("/api/files/:file_id/metadata", get(get_file_metadata))
@@ -118,18 +190,40 @@ pub fn create_router(app_state: AppState) -> Router {
             "/api/assessments/:assessment_id/responses/:response_id/files",
             post(attach_file),
         )
+        // Same path as above so the router accepts it; the id here is a question revision ID
+        .route(
+            "/api/assessments/:assessment_id/responses/:response_id/files",
+            get(list_response_files),
+        )
         .route(
             "/api/assessments/:assessment_id/responses/:response_id/files/:file_id",
             delete(remove_file),
         )
+        .route(
+            "/api/assessments/:assessment_id/questions/:question_revision_id/files/:file_id",
+            delete(remove_question_file),
+        )
         // Admin endpoints
         .route("/api/admin/submissions", get(list_all_submissions))
+        .route("/api/admin/submissions/:submission_id", get(get_submission_by_id))
         .route("/api/drafts", get(list_temp_submissions_by_assessment))
         // User submission endpoints
         .route("/api/submissions", get(list_user_submissions))
         .route("/api/org_admin/submissions", get(list_user_submissions))
         .route("/api/submissions/:submission_id", get(get_submission))
         .route("/api/submissions/:submission_id", delete(delete_submission))
+        .route("/api/submissions/:submission_id/responses", get(get_submission_responses))
+        .route("/api/submissions/:submission_id/pdf", get(get_submission_pdf))
+        .route("/api/submissions/:submission_id/timeline/comment", post(add_submission_comment))
+        .route("/api/submissions/:submission_id/reopen", post(reopen_submission))
+        .route("/api/submissions/:submission_id/recall", post(recall_submission))
+        .route("/api/admin/submissions/:submission_id/timeline", get(get_submission_timeline))
+        // Current user endpoints
+        .route("/api/me", get(get_current_user))
+        // Notification endpoints
+        .route("/api/notifications", get(list_notifications))
+        .route("/api/notifications/read-all", patch(mark_all_notifications_read))
+        .route("/api/notifications/:notification_id/read", patch(mark_notification_read))
         // User report endpoints
         .route("/api/user/reports", get(list_user_reports))
         // Report endpoints
@@ -141,22 +235,54 @@ pub fn create_router(app_state: AppState) -> Router {
             "/api/submissions/:submission_id/reports",
             post(generate_report),
         )
+        .route("/api/submissions/:submission_id/reports/:report_id", put(regenerate_report))
+        .route("/api/submissions/:submission_id/reports/preview", post(preview_report))
+        .route("/api/submissions/:submission_id/review", post(review_submission))
         .route("/api/reports/:report_id", get(get_report))
         .route("/api/reports/:report_id", delete(delete_report))
+        .route("/api/reports/:report_id/generation-progress", get(get_report_generation_progress))
+        .route("/api/reports/:report_id/benchmark", get(get_report_benchmark))
+        .route("/api/organizations/:org_id/statistics", get(get_organization_statistics))
+        .route("/api/organizations/:org_id/assessments", get(list_organization_assessments))
+        .route("/api/organizations/:org_id/reports/export/zip", get(export_organization_reports_zip))
         .route("/api/admin/action-plans", get(list_all_action_plans))
+        .route("/api/admin/action-plans/summary", get(summarize_action_plans))
+        .route("/api/admin/action-plans/export/csv", get(export_action_plans_csv))
         .route("/api/admin/reports", get(list_all_reports))
+        .route("/api/admin/reports/comparison-matrix", get(get_comparison_matrix))
         .route("/api/reports/:report_id/recommendations/:recommendation_id/status", put(update_recommendation_status))
+        .route("/api/reports/:report_id/recommendations", post(add_report_recommendation))
+        .route("/api/reports/:report_id/recommendations/:recommendation_id", delete(delete_report_recommendation))
         .route("/api/organizations/:org_id/org-admin/members", post(add_org_admin_member))
+        .route("/api/organizations/:org_id/members/import", post(import_org_members))
+        .route("/api/organizations/:org_id/members/categories/bulk", put(bulk_update_member_categories))
+        .route("/api/organizations/:org_id/invitations/:invitation_id", get(get_invitation))
+        .route("/api/organizations/:org_id/invitations/:invitation_id/resend", patch(resend_invitation))
         .route("/api/organizations/:org_id/org-admin/members", get(get_org_admin_members))
         .route("/api/organizations/:org_id/org-admin/members/:member_id", delete(remove_org_admin_member))
         .route("/api/organizations/:org_id/org-admin/members/:member_id/categories", put(update_org_admin_member_categories))
         // Org admin user invitation endpoints
 
         // User invitation endpoints
-        .route("/api/admin/user-invitations", post(create_user_invitation))
+        .route("/api/admin/user-invitations", post(create_user_invitation).layer(idempotent()))
         .route("/api/admin/user-invitations/:user_id/status", get(get_user_invitation_status))
         // User management endpoints
         .route("/api/admin/users/:user_id", delete(delete_user))
+        .route("/api/admin/users/:user_id/assessments", get(get_user_assessments))
+        .route("/api/admin/users/:user_id/resend-verification", post(resend_verification_email))
+        .route("/api/admin/users/:user_id/transfer", post(transfer_user))
+        .route("/api/admin/dashboard", get(get_admin_dashboard))
+        .route("/api/admin/events/stream", get(stream_admin_events))
+        .route("/api/admin/assessments/:assessment_id/unlock", post(unlock_assessment))
+        .route("/api/admin/assessment-templates", get(list_assessment_templates))
+        .route("/api/admin/assessment-templates", post(create_assessment_template).layer(idempotent()))
+        // Category catalog administration
+        .route("/api/admin/categories", get(list_categories))
+        .route("/api/admin/categories", post(create_category))
+        .route("/api/admin/categories/:category_catalog_id", get(get_category))
+        .route("/api/admin/categories/:category_catalog_id", put(update_category))
+        .route("/api/admin/categories/:category_catalog_id", delete(archive_category))
+        .route("/api/admin/categories/:category_catalog_id/metadata", put(update_category_metadata))
 
 
         .with_state(app_state)
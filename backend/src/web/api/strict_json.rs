@@ -0,0 +1,213 @@
+//! JSON request bodies with unknown fields.
+//!
+//! Request models in `web::api::models` are `deny_unknown_fields`. [`StrictJson`]
+//! extracts them like `Json` does, and what happens to a field the model does not know
+//! depends on `STRICT_REQUEST_VALIDATION`: when it is on the request is rejected with
+//! 422 and an `UNKNOWN_FIELD` error naming the field, otherwise the field is dropped
+//! and the rest of the body is used, as plain `Json` would. Every dropped field costs
+//! another pass over the body, so a body with more than [`MAX_DROPPED_FIELDS`] unknown
+//! fields is rejected even then.
+
+use axum::{
+    async_trait,
+    extract::{rejection::JsonRejection, FromRef, FromRequest, Request},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use serde_path_to_error::Segment;
+
+use crate::web::routes::AppState;
+
+/// Unknown fields dropped from a body in lenient mode before it is rejected
+const MAX_DROPPED_FIELDS: usize = 16;
+
+/// `Json` for request models, see the module documentation
+#[derive(Debug)]
+pub struct StrictJson<T>(pub T);
+
+/// Why a body could not be extracted
+#[derive(Debug)]
+pub enum StrictJsonRejection {
+    /// Not JSON at all, as `Json` rejects it
+    Json(JsonRejection),
+    /// A field the model does not know, in strict mode
+    UnknownField(String),
+    /// JSON that does not match the model
+    InvalidBody(String),
+}
+
+impl IntoResponse for StrictJsonRejection {
+    fn into_response(self) -> Response {
+        match self {
+            StrictJsonRejection::Json(rejection) => rejection.into_response(),
+            StrictJsonRejection::UnknownField(field) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(json!({ "error": { "code": "UNKNOWN_FIELD", "field": field } })),
+            )
+                .into_response(),
+            // Same rejection as `Json` gives
+            StrictJsonRejection::InvalidBody(message) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                format!("Failed to deserialize the JSON body into the target type: {message}"),
+            )
+                .into_response(),
+        }
+    }
+}
+
+/// Whether [`StrictJson`] rejects unknown fields, taken from the router state
+#[derive(Debug, Clone, Copy)]
+pub struct StrictRequestValidation(pub bool);
+
+impl FromRef<AppState> for StrictRequestValidation {
+    fn from_ref(state: &AppState) -> Self {
+        StrictRequestValidation(state.strict_request_validation)
+    }
+}
+
+#[async_trait]
+impl<S, T> FromRequest<S> for StrictJson<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned,
+    StrictRequestValidation: FromRef<S>,
+{
+    type Rejection = StrictJsonRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let StrictRequestValidation(strict) = StrictRequestValidation::from_ref(state);
+        let Json(body) = Json::<Value>::from_request(req, state)
+            .await
+            .map_err(StrictJsonRejection::Json)?;
+        from_value(body, strict).map(StrictJson)
+    }
+}
+
+/// Deserializes `body`, rejecting unknown fields when `strict` and dropping them otherwise
+fn from_value<T: DeserializeOwned>(mut body: Value, strict: bool) -> Result<T, StrictJsonRejection> {
+    let mut dropped = 0;
+    loop {
+        let error = match serde_path_to_error::deserialize::<_, T>(&body) {
+            Ok(value) => return Ok(value),
+            Err(error) => error,
+        };
+        if !error.inner().to_string().starts_with("unknown field `") {
+            return Err(StrictJsonRejection::InvalidBody(error.to_string()));
+        }
+
+        if strict || dropped == MAX_DROPPED_FIELDS {
+            return Err(StrictJsonRejection::UnknownField(error.path().to_string()));
+        }
+        // Each round removes one field, so this ends with the body's last unknown field
+        dropped += 1;
+        if !remove_field(&mut body, error.path()) {
+            return Err(StrictJsonRejection::InvalidBody(error.to_string()));
+        }
+    }
+}
+
+/// Removes the object field `path` points at, returning whether there was one
+fn remove_field(body: &mut Value, path: &serde_path_to_error::Path) -> bool {
+    let segments: Vec<&Segment> = path.iter().collect();
+    let Some((Segment::Map { key }, parents)) = segments.split_last() else {
+        return false;
+    };
+
+    let mut object = body;
+    for segment in parents {
+        object = match (segment, object) {
+            (Segment::Seq { index }, Value::Array(items)) => match items.get_mut(*index) {
+                Some(item) => item,
+                None => return false,
+            },
+            (Segment::Map { key }, Value::Object(fields)) => match fields.get_mut(key) {
+                Some(field) => field,
+                None => return false,
+            },
+            _ => return false,
+        };
+    }
+
+    object.as_object_mut().and_then(|fields| fields.remove(key)).is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::web::api::models::{OrganizationCreateRequest, UpdateCategoryWeightsRequest};
+
+    async fn body_json(response: Response) -> Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    fn organization_with_password() -> Value {
+        json!({
+            "name": "org",
+            "domains": [{ "name": "example.org" }],
+            "redirectUrl": "https://example.org",
+            "enabled": "true",
+            "password": "secret",
+        })
+    }
+
+    #[tokio::test]
+    async fn test_unknown_field_is_rejected_in_strict_mode() {
+        let response = from_value::<OrganizationCreateRequest>(organization_with_password(), true)
+            .unwrap_err()
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body_json(response).await,
+            json!({ "error": { "code": "UNKNOWN_FIELD", "field": "password" } })
+        );
+    }
+
+    #[test]
+    fn test_unknown_field_is_dropped_in_lenient_mode() {
+        let request = from_value::<OrganizationCreateRequest>(organization_with_password(), false).unwrap();
+
+        assert_eq!(request.name, "org");
+        assert_eq!(request.domains[0].name, "example.org");
+    }
+
+    #[tokio::test]
+    async fn test_nested_unknown_fields() {
+        let body = json!({
+            "weights": [
+                { "category_catalog_id": uuid::Uuid::nil(), "weight": 60 },
+                { "category_catalog_id": uuid::Uuid::nil(), "weight": 40, "note": "rest" },
+            ]
+        });
+
+        let rejection = from_value::<UpdateCategoryWeightsRequest>(body.clone(), true).unwrap_err();
+        assert_eq!(body_json(rejection.into_response()).await["error"]["field"], "weights[1].note");
+
+        let request = from_value::<UpdateCategoryWeightsRequest>(body, false).unwrap();
+        assert_eq!(request.weights[1].weight, 40);
+    }
+
+    #[test]
+    fn test_lenient_mode_drops_a_bounded_number_of_fields() {
+        let mut body = organization_with_password();
+        for i in 1..MAX_DROPPED_FIELDS {
+            body[format!("extra_{i}")] = json!(i);
+        }
+        assert!(from_value::<OrganizationCreateRequest>(body.clone(), false).is_ok());
+
+        body["one_too_many"] = json!(true);
+        let rejection = from_value::<OrganizationCreateRequest>(body, false).unwrap_err();
+        assert!(matches!(rejection, StrictJsonRejection::UnknownField(_)));
+    }
+
+    #[test]
+    fn test_other_errors_are_not_unknown_fields() {
+        let rejection = from_value::<OrganizationCreateRequest>(json!({ "domains": [] }), false).unwrap_err();
+
+        assert!(matches!(rejection, StrictJsonRejection::InvalidBody(_)));
+    }
+}
@@ -0,0 +1,226 @@
+//! Conditional GET Middleware
+//!
+//! Handlers that can describe their content with an `ETag` set it on the
+//! response; this layer compares it with the request's `If-None-Match` and
+//! swaps a matching 200 for a bodiless `304 Not Modified`, so clients can keep
+//! using their cached copy without the payload being sent again.
+
+use axum::{
+    body::Body,
+    extract::Request,
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+
+pub async fn etag_middleware(request: Request, next: Next) -> Response {
+    let if_none_match = request.headers().get(header::IF_NONE_MATCH).cloned();
+    let response = next.run(request).await;
+
+    let (Some(if_none_match), true) = (if_none_match, response.status() == StatusCode::OK) else {
+        return response;
+    };
+    let Some(etag) = response.headers().get(header::ETAG) else {
+        return response;
+    };
+    if !etag_matches(&if_none_match, etag) {
+        return response;
+    }
+
+    let mut headers = HeaderMap::new();
+    for name in [header::ETAG, header::CACHE_CONTROL] {
+        if let Some(value) = response.headers().get(&name) {
+            headers.insert(name, value.clone());
+        }
+    }
+
+    let mut not_modified = Response::new(Body::empty());
+    *not_modified.status_mut() = StatusCode::NOT_MODIFIED;
+    *not_modified.headers_mut() = headers;
+    not_modified
+}
+
+/// `If-None-Match` may list several tags or `*`; weak tags compare equal to strong ones
+fn etag_matches(if_none_match: &HeaderValue, etag: &HeaderValue) -> bool {
+    let (Ok(candidates), Ok(etag)) = (if_none_match.to_str(), etag.to_str()) else {
+        return false;
+    };
+    let etag = etag.trim_start_matches("W/");
+
+    candidates
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{org_claims, unreachable_keycloak};
+    use crate::common::database::entity::{
+        assessment_categories, assessments, assessments_response, assessments_response_file,
+        assessments_submission, file, questions, questions_revisions, temp_submission,
+    };
+    use crate::common::state::AppDatabase;
+    use crate::web::api::handlers::assessments::get_assessment;
+    use crate::web::routes::AppState;
+    use axum::{middleware, routing::get, Extension, Router};
+    use chrono::Utc;
+    use sea_orm::{ActiveModelTrait, ConnectionTrait, Database, Schema, Set};
+    use std::sync::Arc;
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    async fn setup() -> Result<(AppState, Uuid), Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessment_categories::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(questions::Entity),
+            schema.create_table_from_entity(questions_revisions::Entity),
+            schema.create_table_from_entity(assessments_response_file::Entity),
+            schema.create_table_from_entity(file::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(temp_submission::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        let assessment_id = Uuid::new_v4();
+        assessments::ActiveModel {
+            assessment_id: Set(assessment_id),
+            org_id: Set("test-org".to_string()),
+            language: Set("en".to_string()),
+            name: Set("Cached assessment".to_string()),
+            created_at: Set(Utc::now()),
+        }
+        .insert(&db)
+        .await?;
+
+        let app_state = AppState::new(
+            unreachable_keycloak(),
+            AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+
+        Ok((app_state, assessment_id))
+    }
+
+    async fn fetch(
+        app_state: &AppState,
+        assessment_id: Uuid,
+        if_none_match: Option<&HeaderValue>,
+    ) -> Result<Response, Box<dyn std::error::Error>> {
+        let app = Router::new()
+            .route("/api/assessments/:assessment_id", get(get_assessment))
+            .layer(middleware::from_fn(etag_middleware))
+            .layer(Extension(org_claims("test-user-123", &["Org_User"], "Test Organization", "test-org")))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state.clone());
+
+        let mut request = Request::builder().uri(format!("/api/assessments/{assessment_id}"));
+        if let Some(etag) = if_none_match {
+            request = request.header(header::IF_NONE_MATCH, etag);
+        }
+        Ok(app.oneshot(request.body(Body::empty())?).await?)
+    }
+
+    #[tokio::test]
+    async fn test_matching_etag_returns_not_modified() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, assessment_id) = setup().await?;
+
+        let first = fetch(&app_state, assessment_id, None).await?;
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(first.headers()[header::CACHE_CONTROL], "no-cache");
+        let etag = first.headers()[header::ETAG].clone();
+
+        let second = fetch(&app_state, assessment_id, Some(&etag)).await?;
+        assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(second.headers()[header::ETAG], etag);
+        assert_eq!(second.headers()[header::CACHE_CONTROL], "no-cache");
+        let body = axum::body::to_bytes(second.into_body(), usize::MAX).await?;
+        assert!(body.is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_saved_response_changes_etag() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, assessment_id) = setup().await?;
+        let question_revision_id = Uuid::new_v4();
+        app_state
+            .database
+            .assessments_response
+            .update_response(assessment_id, question_revision_id, "Yes".to_string())
+            .await?;
+
+        let first = fetch(&app_state, assessment_id, None).await?;
+        let etag = first.headers()[header::ETAG].clone();
+
+        app_state
+            .database
+            .assessments_response
+            .update_response(assessment_id, question_revision_id, "No".to_string())
+            .await?;
+
+        let after_update = fetch(&app_state, assessment_id, Some(&etag)).await?;
+        assert_eq!(after_update.status(), StatusCode::OK);
+        assert_ne!(after_update.headers()[header::ETAG], etag);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_renamed_assessment_and_attached_file_change_etag() -> Result<(), Box<dyn std::error::Error>> {
+        let (app_state, assessment_id) = setup().await?;
+        let response = app_state
+            .database
+            .assessments_response
+            .update_response(assessment_id, Uuid::new_v4(), "Yes".to_string())
+            .await?;
+
+        let etag = fetch(&app_state, assessment_id, None).await?.headers()[header::ETAG].clone();
+
+        let mut assessment: assessments::ActiveModel = app_state
+            .database
+            .assessments
+            .get_assessment_by_id(assessment_id)
+            .await?
+            .unwrap()
+            .into();
+        assessment.name = Set("Renamed assessment".to_string());
+        assessment.update(app_state.database.get_connection()).await?;
+
+        let after_rename = fetch(&app_state, assessment_id, Some(&etag)).await?;
+        assert_eq!(after_rename.status(), StatusCode::OK);
+        let etag = after_rename.headers()[header::ETAG].clone();
+
+        let file = app_state
+            .database
+            .file
+            .create_file(b"evidence".to_vec(), serde_json::json!({ "filename": "evidence.txt" }))
+            .await?;
+        app_state
+            .database
+            .assessments_response_file
+            .link_file_to_response(response.response_id, file.id)
+            .await?;
+
+        let after_attach = fetch(&app_state, assessment_id, Some(&etag)).await?;
+        assert_eq!(after_attach.status(), StatusCode::OK);
+        assert_ne!(after_attach.headers()[header::ETAG], etag);
+        Ok(())
+    }
+
+    #[test]
+    fn test_etag_matches_lists_and_weak_tags() {
+        let etag = HeaderValue::from_static("\"abc\"");
+
+        assert!(etag_matches(&HeaderValue::from_static("\"abc\""), &etag));
+        assert!(etag_matches(&HeaderValue::from_static("\"xyz\", W/\"abc\""), &etag));
+        assert!(etag_matches(&HeaderValue::from_static("*"), &etag));
+        assert!(!etag_matches(&HeaderValue::from_static("\"xyz\""), &etag));
+    }
+}
@@ -0,0 +1,197 @@
+//! Idempotency-Key Middleware
+//!
+//! POST endpoints that create resources can be retried by clients after a network
+//! error without creating the resource twice: a request carrying an
+//! `Idempotency-Key: <uuid>` header runs once per key and user, and repeats get
+//! the stored response for the next 24 hours. Requests without the header are
+//! passed through unchanged.
+
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{header, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use uuid::Uuid;
+
+use crate::common::database::entity::idempotency_keys::{IdempotencyService, StoredResponse};
+use crate::common::models::claims::Claims;
+use crate::web::api::error::ApiError;
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Must run after `auth_middleware`, keys are scoped to the caller from the claims
+pub async fn idempotency_middleware(
+    State(idempotency): State<IdempotencyService>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(key) = request.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return next.run(request).await;
+    };
+    let Some(key) = key.to_str().ok().and_then(|key| Uuid::parse_str(key.trim()).ok()) else {
+        return ApiError::BadRequest("Idempotency-Key must be a UUID".to_string()).into_response();
+    };
+    let Some(user_id) = request.extensions().get::<Claims>().map(|claims| claims.sub.clone()) else {
+        return next.run(request).await;
+    };
+
+    let handler = async move {
+        let (parts, body) = next.run(request).await.into_parts();
+        let body = axum::body::to_bytes(body, usize::MAX).await.unwrap_or_default();
+        StoredResponse {
+            status_code: parts.status.as_u16(),
+            body: body.to_vec(),
+        }
+    };
+
+    match idempotency.check_or_store(&key.to_string(), &user_id, handler).await {
+        Ok(Some(stored)) => {
+            let status = StatusCode::from_u16(stored.status_code).unwrap_or(StatusCode::OK);
+            let has_body = !stored.body.is_empty();
+            let mut response = Response::new(Body::from(stored.body));
+            *response.status_mut() = status;
+            if has_body {
+                response
+                    .headers_mut()
+                    .insert(header::CONTENT_TYPE, HeaderValue::from_static("application/json"));
+            }
+            response
+        }
+        Ok(None) => ApiError::Conflict(
+            "A request with this Idempotency-Key is still being processed".to_string(),
+        )
+        .into_response(),
+        Err(e) => {
+            tracing::error!(error = %e, "Idempotency key lookup failed");
+            ApiError::InternalServerError("Failed to process Idempotency-Key".to_string()).into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{claims, keycloak_config, serve};
+    use crate::common::database::entity::idempotency_keys;
+    use crate::common::state::AppDatabase;
+    use crate::web::api::handlers::organizations::create_organization;
+    use crate::web::routes::AppState;
+    use axum::{middleware, routing::post, Extension, Json, Router};
+    use sea_orm::{ConnectionTrait, Database, Schema};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tower::ServiceExt;
+
+    /// Keycloak stand-in that counts created organizations and answers slowly,
+    /// so that concurrent requests overlap
+    async fn fake_keycloak(created: Arc<AtomicUsize>) -> String {
+        let app = Router::new().route(
+            "/admin/realms/test-realm/organizations",
+            post(move |Json(org): Json<serde_json::Value>| async move {
+                let n = created.fetch_add(1, Ordering::SeqCst) + 1;
+                tokio::time::sleep(Duration::from_millis(200)).await;
+                (
+                    StatusCode::CREATED,
+                    Json(serde_json::json!({ "id": format!("org-{n}"), "name": org["name"], "enabled": true })),
+                )
+            }),
+        );
+
+        serve(app).await
+    }
+
+    async fn setup(created: Arc<AtomicUsize>) -> Result<Router, Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        let backend = db.get_database_backend();
+        db.execute(backend.build(&Schema::new(backend).create_table_from_entity(idempotency_keys::Entity)))
+            .await?;
+
+        let app_state = AppState::new(
+            keycloak_config(fake_keycloak(created).await),
+            AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+
+        Ok(Router::new()
+            .route(
+                "/api/admin/organizations",
+                post(create_organization).layer(middleware::from_fn_with_state(
+                    app_state.database.idempotency_keys.clone(),
+                    idempotency_middleware,
+                )),
+            )
+            .layer(Extension(claims("admin-user", &["application_admin"])))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state))
+    }
+
+    async fn create(app: &Router, key: Option<&str>) -> Result<(StatusCode, serde_json::Value), Box<dyn std::error::Error>> {
+        let mut request = Request::builder()
+            .method("POST")
+            .uri("/api/admin/organizations")
+            .header(header::CONTENT_TYPE, "application/json");
+        if let Some(key) = key {
+            request = request.header(IDEMPOTENCY_KEY_HEADER, key);
+        }
+        let body = serde_json::json!({
+            "name": "Green Coop",
+            "domains": [],
+            "redirectUrl": "https://greencoop.example",
+            "enabled": "true",
+        });
+
+        let response = app.clone().oneshot(request.body(Body::from(body.to_string()))?).await?;
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await?;
+        Ok((status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null)))
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_requests_with_same_key_create_once() -> Result<(), Box<dyn std::error::Error>> {
+        let created = Arc::new(AtomicUsize::new(0));
+        let app = setup(created.clone()).await?;
+        let key = Uuid::new_v4().to_string();
+
+        let (first, second) = tokio::join!(create(&app, Some(&key)), create(&app, Some(&key)));
+        let (first, second) = (first?, second?);
+
+        assert_eq!(created.load(Ordering::SeqCst), 1);
+        assert_eq!(first.0, StatusCode::CREATED);
+        assert_eq!(first, second);
+        assert_eq!(first.1["id"], "org-1");
+
+        // A later retry is answered from the stored response too
+        assert_eq!(create(&app, Some(&key)).await?, first);
+        assert_eq!(created.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_requests_without_or_with_new_keys_are_not_deduplicated() -> Result<(), Box<dyn std::error::Error>> {
+        let created = Arc::new(AtomicUsize::new(0));
+        let app = setup(created.clone()).await?;
+
+        create(&app, None).await?;
+        create(&app, None).await?;
+        create(&app, Some(&Uuid::new_v4().to_string())).await?;
+        create(&app, Some(&Uuid::new_v4().to_string())).await?;
+
+        assert_eq!(created.load(Ordering::SeqCst), 4);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_invalid_key_is_rejected() -> Result<(), Box<dyn std::error::Error>> {
+        let created = Arc::new(AtomicUsize::new(0));
+        let app = setup(created.clone()).await?;
+
+        let (status, _) = create(&app, Some("not-a-uuid")).await?;
+
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(created.load(Ordering::SeqCst), 0);
+        Ok(())
+    }
+}
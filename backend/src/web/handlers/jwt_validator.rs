@@ -1,10 +1,19 @@
+use crate::common::cache::JwksCache;
+use crate::common::config::KeycloakConfigs;
 use crate::common::models::claims::Claims;
 use jsonwebtoken::{decode, decode_header, Algorithm, DecodingKey, Validation};
 use reqwest::Client;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
-use tracing::{error};
+use tokio::sync::{Mutex, RwLock};
+use tracing::{info, warn};
+
+/// How long after a fetch a token with an unknown key ID is rejected without fetching
+/// the keys again, so a flood of made-up key IDs cannot hammer Keycloak
+const MIN_REFETCH_INTERVAL: Duration = Duration::from_secs(10);
 
 #[derive(Error, Debug)]
 pub enum JwtError {
@@ -18,28 +27,43 @@ pub enum JwtError {
     DecodeError(#[from] jsonwebtoken::errors::Error),
 }
 
-#[derive(Clone)]
 pub struct JwtValidator {
     client: Client,
     keycloak_url: String,
     realm: String,
-    keys_cache: HashMap<String, DecodingKey>,
+    /// Shared by all requests and the background refresh
+    jwks: Arc<RwLock<JwksCache>>,
+    /// Held while requests refetch the keys, so concurrent misses share one fetch
+    refetch: Mutex<()>,
+    min_refetch_interval: Duration,
+    refresh_interval: Duration,
+    composite_roles: HashMap<String, Vec<String>>,
+    issuers: Vec<String>,
+    audiences: Vec<String>,
 }
 
 impl JwtValidator {
-    pub fn new(keycloak_url: String, realm: String) -> Self {
+    pub fn new(config: &KeycloakConfigs) -> Self {
         Self {
             client: Client::builder()
                 .danger_accept_invalid_certs(true)
                 .build()
                 .expect("Client"),
-            keycloak_url,
-            realm,
-            keys_cache: HashMap::new(),
+            keycloak_url: config.url.clone(),
+            realm: config.realm.clone(),
+            jwks: Arc::new(RwLock::new(JwksCache::new(Duration::from_secs(
+                config.jwks_refresh_interval_secs,
+            )))),
+            refetch: Mutex::new(()),
+            min_refetch_interval: MIN_REFETCH_INTERVAL,
+            refresh_interval: Duration::from_secs(config.jwks_refresh_interval_secs),
+            composite_roles: config.composite_roles.0.clone(),
+            issuers: config.expected_issuers(),
+            audiences: config.audiences.0.clone(),
         }
     }
 
-    pub async fn validate_token(&mut self, token: &str) -> Result<Claims, JwtError> {
+    pub async fn validate_token(&self, token: &str) -> Result<Claims, JwtError> {
         // Decode header to get key ID
         let header = decode_header(token).map_err(|e| JwtError::InvalidToken(e.to_string()))?;
 
@@ -50,53 +74,115 @@ impl JwtValidator {
         // Get or fetch the decoding key
         let decoding_key = self.get_decoding_key(&kid).await?;
 
-        // Set up validation
+        // Set up validation; tokens for another realm or client are rejected here
         let mut validation = Validation::new(Algorithm::RS256);
-        // Accept the frontend/client audience and account
-        // NOTE: Align these with your Keycloak client-id(s)
-        validation.set_audience(&["sustainability-tool", "account"]);
-        // Accept the exact HTTPS issuer (matches well-known), and be lenient by also allowing HTTP if present upstream
-        let https_iss = format!("{}/realms/{}", self.keycloak_url, self.realm);
-        let http_iss = https_iss.replace("https://", "http://");
-        validation.set_issuer(&[&https_iss, &http_iss]);
+        validation.set_audience(&self.audiences);
+        validation.set_issuer(&self.issuers);
         // Decode and validate token
         let token_data =
             decode::<Claims>(token, &decoding_key, &validation).map_err(JwtError::DecodeError)?;
 
-        Ok(token_data.claims)
+        Ok(token_data.claims.expand_composite_roles(&self.composite_roles))
+    }
+
+    /// Use a different lifetime for fetched signing keys
+    pub fn with_jwks_ttl(mut self, ttl: Duration) -> Self {
+        self.jwks = Arc::new(RwLock::new(JwksCache::new(ttl)));
+        self
+    }
+
+    /// Use a different minimum time between fetches for unknown key IDs
+    pub fn with_min_refetch_interval(mut self, interval: Duration) -> Self {
+        self.min_refetch_interval = interval;
+        self
     }
 
-    async fn get_decoding_key(&mut self, kid: &str) -> Result<DecodingKey, JwtError> {
-        // Check cache first
-        if let Some(key) = self.keys_cache.get(kid) {
-            return Ok(key.clone());
+    /// Fetch the signing keys every `jwks_refresh_interval_secs`, starting right
+    /// away, so that rotated keys are usually known before the first token signed
+    /// with them arrives. Does nothing when the interval is 0.
+    pub fn spawn_key_refresh(self: &Arc<Self>) {
+        if self.refresh_interval.is_zero() {
+            return;
         }
+        let validator = self.clone();
 
-        // Fetch from Keycloak
-        let keys = self.fetch_public_keys().await?;
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(validator.refresh_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                match validator.refresh_keys().await {
+                    Ok(count) => info!(keys = count, "Refreshed token signing keys"),
+                    Err(e) => warn!(error = %e, "Failed to refresh token signing keys"),
+                }
+            }
+        });
+    }
 
-        // Find the key with matching kid
-        let key_data = keys
-            .iter()
-            .find(|key| key["kid"].as_str() == Some(kid))
-            .ok_or_else(|| JwtError::InvalidToken("Key not found".to_string()))?;
+    async fn get_decoding_key(&self, kid: &str) -> Result<DecodingKey, JwtError> {
+        {
+            let jwks = self.jwks.read().await;
+            if jwks.is_fresh() {
+                if let Some(key) = jwks.get(kid) {
+                    return Ok(key.clone());
+                }
+            }
+        }
 
-        // Create a decoding key
-        let n = key_data["n"]
-            .as_str()
-            .ok_or_else(|| JwtError::InvalidToken("Invalid key format".to_string()))?;
-        let e = key_data["e"]
-            .as_str()
-            .ok_or_else(|| JwtError::InvalidToken("Invalid key format".to_string()))?;
+        // The keys are stale, or the token is signed with a key we have not seen,
+        // e.g. because Keycloak rotated its keys: refetch once before rejecting
+        let _refetch = self.refetch.lock().await;
+        {
+            // Another request may have refetched the keys while we waited
+            let jwks = self.jwks.read().await;
+            if jwks.is_fresh() {
+                if let Some(key) = jwks.get(kid) {
+                    return Ok(key.clone());
+                }
+                if jwks.fetched_within(self.min_refetch_interval) {
+                    return Err(JwtError::InvalidToken("Key not found".to_string()));
+                }
+            }
+        }
+
+        if let Err(e) = self.refresh_keys().await {
+            if let Some(key) = self.jwks.read().await.get(kid) {
+                warn!("Failed to refresh signing keys, using cached key: {}", e);
+                return Ok(key.clone());
+            }
+            return Err(e);
+        }
 
-        let decoding_key = DecodingKey::from_rsa_components(n, e)
-            .map_err(|e| JwtError::InvalidToken(e.to_string()))?;
+        self.jwks
+            .read()
+            .await
+            .get(kid)
+            .cloned()
+            .ok_or_else(|| JwtError::InvalidToken("Key not found".to_string()))
+    }
 
-        // Cache the key
-        self.keys_cache
-            .insert(kid.to_string(), decoding_key.clone());
+    /// Replace the cached keys with the realm's current ones, returning how many there are
+    async fn refresh_keys(&self) -> Result<usize, JwtError> {
+        let mut keys = HashMap::new();
+        for key_data in self.fetch_public_keys().await? {
+            let (Some(kid), Some(n), Some(e)) = (
+                key_data["kid"].as_str(),
+                key_data["n"].as_str(),
+                key_data["e"].as_str(),
+            ) else {
+                continue;
+            };
+            match DecodingKey::from_rsa_components(n, e) {
+                Ok(decoding_key) => {
+                    keys.insert(kid.to_string(), decoding_key);
+                }
+                Err(e) => warn!("Skipping invalid signing key {}: {}", kid, e),
+            }
+        }
 
-        Ok(decoding_key)
+        let count = keys.len();
+        self.jwks.write().await.replace(keys);
+        Ok(count)
     }
 
     async fn fetch_public_keys(&self) -> Result<Vec<Value>, JwtError> {
@@ -115,3 +201,243 @@ impl JwtValidator {
         Ok(keys.clone())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{routing::get, Json, Router};
+    use crate::test_support::{serve, signed_token, TEST_KEY_E, TEST_KEY_N};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+
+    fn keycloak_config(issuer: Option<&str>) -> KeycloakConfigs {
+        KeycloakConfigs {
+            url: "https://auth.example.org".to_string(),
+            issuer: issuer.map(str::to_string),
+            ..Default::default()
+        }
+    }
+
+    fn validator(config: &KeycloakConfigs) -> JwtValidator {
+        let validator = JwtValidator::new(config);
+        validator.jwks.try_write().unwrap().replace(HashMap::from([(
+            "test-key".to_string(),
+            DecodingKey::from_rsa_components(TEST_KEY_N, TEST_KEY_E).unwrap(),
+        )]));
+        validator
+    }
+
+    fn token(iss: &str, aud: &str) -> String {
+        token_with_kid("test-key", iss, aud)
+    }
+
+    fn token_with_kid(kid: &str, iss: &str, aud: &str) -> String {
+        signed_token(
+            kid,
+            &serde_json::json!({
+                "sub": "user-123",
+                "preferred_username": "user",
+                "exp": 9999999999u64,
+                "iat": 1000000000u64,
+                "aud": aud,
+                "iss": iss,
+            }),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_accepts_token_from_realm_issuer() {
+        let validator = validator(&keycloak_config(None));
+
+        let claims = validator
+            .validate_token(&token("https://auth.example.org/realms/sustainability-realm", "sustainability-tool"))
+            .await
+            .unwrap();
+
+        assert_eq!(claims.sub, "user-123");
+    }
+
+    #[tokio::test]
+    async fn test_rejects_token_from_other_realm() {
+        let validator = validator(&keycloak_config(None));
+
+        let result = validator
+            .validate_token(&token("https://auth.example.org/realms/other-realm", "sustainability-tool"))
+            .await;
+
+        assert!(matches!(result, Err(JwtError::DecodeError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_token_for_other_client() {
+        let validator = validator(&keycloak_config(None));
+
+        let result = validator
+            .validate_token(&token("https://auth.example.org/realms/sustainability-realm", "other-client"))
+            .await;
+
+        assert!(matches!(result, Err(JwtError::DecodeError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_configured_issuer_replaces_realm_default() {
+        let validator = validator(&keycloak_config(Some("https://sso.example.org/realms/dgat")));
+
+        assert!(validator
+            .validate_token(&token("https://sso.example.org/realms/dgat", "account"))
+            .await
+            .is_ok());
+        assert!(validator
+            .validate_token(&token("https://auth.example.org/realms/sustainability-realm", "account"))
+            .await
+            .is_err());
+    }
+
+    /// Keycloak stand-in serving the JWKS with the given key ids, counting requests
+    async fn fake_jwks(kids: Arc<Mutex<Vec<String>>>, fetches: Arc<AtomicUsize>) -> String {
+        let app = Router::new().route(
+            "/realms/test-realm/protocol/openid-connect/certs",
+            get(move || async move {
+                fetches.fetch_add(1, Ordering::SeqCst);
+                let keys: Vec<Value> = kids
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .map(|kid| serde_json::json!({ "kid": kid, "kty": "RSA", "n": TEST_KEY_N, "e": TEST_KEY_E }))
+                    .collect();
+                Json(serde_json::json!({ "keys": keys }))
+            }),
+        );
+
+        serve(app).await
+    }
+
+    async fn jwks_validator(kids: &[&str]) -> (JwtValidator, Arc<Mutex<Vec<String>>>, Arc<AtomicUsize>, String) {
+        let kids = Arc::new(Mutex::new(kids.iter().map(|kid| kid.to_string()).collect()));
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let url = fake_jwks(kids.clone(), fetches.clone()).await;
+        let config = KeycloakConfigs {
+            url: url.clone(),
+            realm: "test-realm".to_string(),
+            ..keycloak_config(None)
+        };
+        (JwtValidator::new(&config), kids, fetches, format!("{url}/realms/test-realm"))
+    }
+
+    #[tokio::test]
+    async fn test_repeated_validations_reuse_cached_key() {
+        let (validator, _, fetches, issuer) = jwks_validator(&["key-1"]).await;
+
+        for _ in 0..3 {
+            validator
+                .validate_token(&token_with_kid("key-1", &issuer, "account"))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_rotated_key_triggers_single_refetch() {
+        let (validator, kids, fetches, issuer) = jwks_validator(&["key-1"]).await;
+        let validator = validator.with_min_refetch_interval(Duration::ZERO);
+        validator
+            .validate_token(&token_with_kid("key-1", &issuer, "account"))
+            .await
+            .unwrap();
+
+        *kids.lock().unwrap() = vec!["key-2".to_string()];
+        validator
+            .validate_token(&token_with_kid("key-2", &issuer, "account"))
+            .await
+            .unwrap();
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+
+        let unknown = validator
+            .validate_token(&token_with_kid("key-3", &issuer, "account"))
+            .await;
+        assert!(matches!(unknown, Err(JwtError::InvalidToken(_))));
+        assert_eq!(fetches.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_keys_are_not_refetched_right_away() {
+        let (validator, _, fetches, issuer) = jwks_validator(&["key-1"]).await;
+        validator
+            .validate_token(&token_with_kid("key-1", &issuer, "account"))
+            .await
+            .unwrap();
+
+        for kid in ["made-up-1", "made-up-2", "made-up-3"] {
+            let unknown = validator.validate_token(&token_with_kid(kid, &issuer, "account")).await;
+            assert!(matches!(unknown, Err(JwtError::InvalidToken(_))));
+        }
+
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_misses_share_one_fetch() {
+        let (validator, _, fetches, issuer) = jwks_validator(&["key-1"]).await;
+        let token = token_with_kid("key-1", &issuer, "account");
+
+        let results = futures::future::join_all((0..10).map(|_| validator.validate_token(&token))).await;
+
+        assert!(results.iter().all(Result::is_ok));
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_expired_keys_are_refetched() {
+        let (validator, _, fetches, issuer) = jwks_validator(&["key-1"]).await;
+        let validator = validator.with_jwks_ttl(Duration::ZERO);
+
+        for _ in 0..2 {
+            validator
+                .validate_token(&token_with_kid("key-1", &issuer, "account"))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(fetches.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_key_missing_from_cache_is_fetched() {
+        let (validator, _, fetches, issuer) = jwks_validator(&["key-1"]).await;
+        let validator = validator.with_min_refetch_interval(Duration::ZERO);
+        // Keys fetched before Keycloak started signing with key-1
+        validator.jwks.write().await.replace(HashMap::from([(
+            "key-0".to_string(),
+            DecodingKey::from_rsa_components(TEST_KEY_N, TEST_KEY_E).unwrap(),
+        )]));
+
+        let claims = validator
+            .validate_token(&token_with_kid("key-1", &issuer, "account"))
+            .await
+            .unwrap();
+
+        assert_eq!(claims.sub, "user-123");
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert!(validator.jwks.read().await.get("key-1").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_key_refresh_runs_in_background() {
+        let (validator, _, fetches, _) = jwks_validator(&["key-1"]).await;
+        let validator = Arc::new(validator);
+
+        validator.spawn_key_refresh();
+
+        for _ in 0..50 {
+            if fetches.load(Ordering::SeqCst) > 0 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert!(validator.jwks.read().await.get("key-1").is_some());
+    }
+}
@@ -13,7 +13,6 @@ use axum::{
     response::Response,
 };
 use std::sync::Arc;
-use tokio::sync::Mutex;
 
 /// Main authentication middleware that validates JWT tokens and injects claims
 ///
@@ -22,7 +21,7 @@ use tokio::sync::Mutex;
 /// 2. Validates the token using Keycloak public keys
 /// 3. Injects the validated claims into the request for downstream handlers
 pub async fn auth_middleware(
-    State(jwt_validator): State<Arc<Mutex<JwtValidator>>>,
+    State(jwt_validator): State<Arc<JwtValidator>>,
     headers: HeaderMap,
     mut request: Request,
     next: Next,
@@ -47,8 +46,7 @@ pub async fn auth_middleware(
         })?;
 
     // Validate token
-    let mut validator = jwt_validator.lock().await;
-    let claims = validator.validate_token(token).await.map_err(|e| {
+    let claims = jwt_validator.validate_token(token).await.map_err(|e| {
         tracing::error!("Token validation failed: {}", e);
         StatusCode::UNAUTHORIZED
     })?;
@@ -0,0 +1,168 @@
+//! Pending Migrations Gate
+//!
+//! With `MIGRATIONS_AUTO_RUN=false` the backend starts against a schema that may be
+//! older than the code, so API requests are answered with 503 `PENDING_MIGRATIONS`
+//! until the migrations have been applied by hand. The migration table is read again
+//! at most every few seconds while migrations are pending, and once none are left the
+//! gate stays open without touching the database.
+
+use crate::common::migrations::MigrationStatus;
+use crate::common::state::AppDatabase;
+use axum::{
+    extract::{Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// How often the migration table is read again while migrations are pending
+const RECHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Number of pending migrations, shared through `AppState`
+#[derive(Clone)]
+pub struct MigrationGate {
+    database: AppDatabase,
+    pending: Arc<AtomicUsize>,
+    last_check: Arc<Mutex<Instant>>,
+}
+
+impl MigrationGate {
+    /// An open gate; see [`MigrationGate::set_pending`]
+    pub fn new(database: AppDatabase) -> Self {
+        Self {
+            database,
+            pending: Arc::new(AtomicUsize::new(0)),
+            last_check: Arc::new(Mutex::new(Instant::now())),
+        }
+    }
+
+    pub fn set_pending(&self, pending: usize) {
+        self.pending.store(pending, Ordering::Relaxed);
+    }
+
+    /// Pending migrations, re-reading the migration table if it is due
+    pub async fn pending(&self) -> usize {
+        let pending = self.pending.load(Ordering::Relaxed);
+        if pending == 0 {
+            return 0;
+        }
+
+        {
+            let mut last_check = self.last_check.lock().unwrap();
+            if last_check.elapsed() < RECHECK_INTERVAL {
+                return pending;
+            }
+            *last_check = Instant::now();
+        }
+
+        match MigrationStatus::check_pending(self.database.get_connection()).await {
+            Ok(names) => {
+                if names.is_empty() {
+                    tracing::info!("Database migrations applied, accepting API requests");
+                }
+                self.set_pending(names.len());
+                names.len()
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to read migration status");
+                pending
+            }
+        }
+    }
+}
+
+/// Middleware answering 503 while migrations are pending
+pub async fn migration_gate_middleware(
+    State(gate): State<MigrationGate>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let pending = gate.pending().await;
+    if pending > 0 {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({ "error": { "code": "PENDING_MIGRATIONS", "count": pending } })),
+        )
+            .into_response();
+    }
+
+    next.run(request).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, middleware, routing::get, Router};
+    use sea_orm::{ActiveModelTrait, Database, Set};
+    use sea_orm_migration::seaql_migrations;
+    use tower::ServiceExt;
+
+    async fn test_app() -> (Router, MigrationGate) {
+        let conn = Database::connect("sqlite::memory:").await.unwrap();
+        let gate = MigrationGate::new(AppDatabase::new(Arc::new(conn)).await);
+
+        let app = Router::new()
+            .route("/api/assessments", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(gate.clone(), migration_gate_middleware));
+        (app, gate)
+    }
+
+    async fn send(app: &Router) -> Response {
+        app.clone()
+            .oneshot(Request::builder().uri("/api/assessments").body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_pending_migrations_answer_503() {
+        let (app, gate) = test_app().await;
+        let pending = MigrationStatus::check_pending(gate.database.get_connection()).await.unwrap();
+        assert!(!pending.is_empty());
+        gate.set_pending(pending.len());
+
+        let response = send(&app).await;
+
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(
+            serde_json::from_slice::<serde_json::Value>(&body).unwrap(),
+            json!({ "error": { "code": "PENDING_MIGRATIONS", "count": pending.len() } })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_gate_opens_once_migrations_are_applied() {
+        let (app, gate) = test_app().await;
+        let pending = MigrationStatus::check_pending(gate.database.get_connection()).await.unwrap();
+        gate.set_pending(pending.len());
+        assert_eq!(send(&app).await.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        // Recorded the way `Migrator::up` does, the migrations themselves need Postgres
+        for name in pending {
+            seaql_migrations::ActiveModel {
+                version: Set(name),
+                applied_at: Set(0),
+            }
+            .insert(gate.database.get_connection())
+            .await
+            .unwrap();
+        }
+        *gate.last_check.lock().unwrap() = Instant::now() - RECHECK_INTERVAL;
+
+        assert_eq!(send(&app).await.status(), StatusCode::OK);
+        assert_eq!(gate.pending.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn test_no_pending_migrations_pass_through() {
+        let (app, _gate) = test_app().await;
+
+        assert_eq!(send(&app).await.status(), StatusCode::OK);
+    }
+}
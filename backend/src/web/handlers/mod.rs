@@ -1,4 +1,9 @@
+pub mod etag;
+pub mod idempotency;
 pub mod jwt_validator;
 pub mod midlw;
-
-pub mod request_logging;
\ No newline at end of file
+pub mod migration_gate;
+pub mod rate_limit;
+pub mod request_id;
+pub mod request_logging;
+pub mod tenant_guard;
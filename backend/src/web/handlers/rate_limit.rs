@@ -0,0 +1,215 @@
+//! Rate Limiting Middleware
+//!
+//! This module provides a token-bucket rate limiter keyed by user and endpoint.
+//! Authenticated requests are keyed by the `sub` claim, anonymous requests by the
+//! client IP address. Buckets that have not been used for an hour are dropped so
+//! the map does not grow without bound.
+
+use crate::common::config::RateLimitConfig;
+use crate::common::models::claims::Claims;
+use axum::{
+    extract::{ConnectInfo, MatchedPath, Request, State},
+    http::{header, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use dashmap::DashMap;
+use governor::{
+    clock::{Clock, DefaultClock},
+    DefaultDirectRateLimiter, Quota,
+};
+use serde_json::json;
+use std::net::SocketAddr;
+use std::num::NonZeroU32;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Paths that are never rate limited
+const EXEMPT_PATHS: [&str; 2] = ["/health", "/webhooks/keycloak"];
+
+/// How long an unused bucket is kept around
+const BUCKET_EXPIRY: Duration = Duration::from_secs(60 * 60);
+
+/// How often idle buckets are swept
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+struct Bucket {
+    limiter: Arc<DefaultDirectRateLimiter>,
+    last_seen: Instant,
+}
+
+/// Per-user, per-endpoint token buckets shared through `AppState`
+pub struct RateLimiter {
+    quota: Quota,
+    buckets: DashMap<String, Bucket>,
+    last_sweep: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(config: &RateLimitConfig) -> Self {
+        let per_minute = NonZeroU32::new(config.requests_per_minute).unwrap_or(NonZeroU32::MIN);
+        let burst = NonZeroU32::new(config.burst_size).unwrap_or(per_minute);
+
+        Self {
+            quota: Quota::per_minute(per_minute).allow_burst(burst),
+            buckets: DashMap::new(),
+            last_sweep: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Take one token from the bucket for `key`.
+    ///
+    /// Returns the number of seconds to wait before retrying when the bucket is empty.
+    pub fn check(&self, key: &str) -> Result<(), u64> {
+        self.sweep_expired();
+
+        let limiter = {
+            let mut bucket = self.buckets.entry(key.to_string()).or_insert_with(|| Bucket {
+                limiter: Arc::new(DefaultDirectRateLimiter::direct(self.quota)),
+                last_seen: Instant::now(),
+            });
+            bucket.last_seen = Instant::now();
+            bucket.limiter.clone()
+        };
+
+        limiter.check().map_err(|not_until| {
+            let wait = not_until.wait_time_from(DefaultClock::default().now());
+            // Round up so clients never retry a fraction of a second too early
+            wait.as_secs() + u64::from(wait.subsec_nanos() > 0)
+        })
+    }
+
+    fn sweep_expired(&self) {
+        let mut last_sweep = match self.last_sweep.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+
+        if last_sweep.elapsed() < SWEEP_INTERVAL {
+            return;
+        }
+        *last_sweep = Instant::now();
+        drop(last_sweep);
+
+        self.buckets
+            .retain(|_, bucket| bucket.last_seen.elapsed() < BUCKET_EXPIRY);
+    }
+}
+
+/// Middleware rejecting requests with 429 once the caller's bucket is exhausted.
+///
+/// Must run after `auth_middleware` so that claims are available for authenticated
+/// routes; requests without claims fall back to the peer address.
+pub async fn rate_limit_middleware(
+    State(rate_limiter): State<Arc<RateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = request.uri().path();
+    if EXEMPT_PATHS.contains(&path) {
+        return next.run(request).await;
+    }
+
+    let caller = match request.extensions().get::<Claims>() {
+        Some(claims) => format!("user:{}", claims.sub),
+        None => match request.extensions().get::<ConnectInfo<SocketAddr>>() {
+            Some(ConnectInfo(addr)) => format!("ip:{}", addr.ip()),
+            None => "ip:unknown".to_string(),
+        },
+    };
+
+    let endpoint = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched| matched.as_str().to_string())
+        .unwrap_or_else(|| path.to_string());
+
+    let key = format!("{caller}|{} {endpoint}", request.method());
+
+    match rate_limiter.check(&key) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => {
+            tracing::warn!(endpoint = %endpoint, "Rate limit exceeded");
+
+            let mut response = (
+                StatusCode::TOO_MANY_REQUESTS,
+                Json(json!({ "error": "Too many requests" })),
+            )
+                .into_response();
+            response
+                .headers_mut()
+                .insert(header::RETRY_AFTER, HeaderValue::from(retry_after));
+            response
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::claims;
+    use axum::{body::Body, middleware, routing::get, Extension, Router};
+    use tower::ServiceExt;
+
+    fn test_app(sub: &str) -> Router {
+        let rate_limiter = Arc::new(RateLimiter::new(&RateLimitConfig {
+            requests_per_minute: 60,
+            burst_size: 60,
+        }));
+
+        Router::new()
+            .route("/api/assessments", get(|| async { "ok" }))
+            .route("/health", get(|| async { "ok" }))
+            .route("/api/reports/health", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(rate_limiter, rate_limit_middleware))
+            .layer(Extension(claims(sub, &[])))
+    }
+
+    async fn count_limited(app: &Router, uri: &str, requests: usize) -> usize {
+        let mut limited = 0;
+        for _ in 0..requests {
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+
+            if response.status() == StatusCode::TOO_MANY_REQUESTS {
+                assert!(response.headers().contains_key(header::RETRY_AFTER));
+                limited += 1;
+            }
+        }
+        limited
+    }
+
+    #[tokio::test]
+    async fn test_61_requests_trigger_one_429() {
+        let app = test_app("test-user-123");
+        assert_eq!(count_limited(&app, "/api/assessments", 61).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_health_is_exempt() {
+        let app = test_app("test-user-123");
+        assert_eq!(count_limited(&app, "/health", 61).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_paths_ending_in_an_exempt_path_are_limited() {
+        let app = test_app("test-user-123");
+        assert_eq!(count_limited(&app, "/api/reports/health", 61).await, 1);
+    }
+
+    #[test]
+    fn test_buckets_are_per_user() {
+        let rate_limiter = RateLimiter::new(&RateLimitConfig {
+            requests_per_minute: 1,
+            burst_size: 1,
+        });
+
+        assert!(rate_limiter.check("user:a|GET /api/assessments").is_ok());
+        assert!(rate_limiter.check("user:a|GET /api/assessments").is_err());
+        assert!(rate_limiter.check("user:b|GET /api/assessments").is_ok());
+    }
+}
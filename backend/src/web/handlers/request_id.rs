@@ -0,0 +1,116 @@
+//! Request ID Middleware
+//!
+//! Gives every request a fresh UUID, runs the rest of the stack inside a tracing
+//! span carrying it, and echoes it back in the `X-Request-ID` response header so
+//! a client-reported failure can be matched to its log lines.
+
+use axum::{
+    extract::Request,
+    http::{HeaderName, HeaderValue},
+    middleware::Next,
+    response::Response,
+};
+use tracing::{field::Empty, Instrument};
+use uuid::Uuid;
+
+use crate::common::logging::record_service_fields;
+
+pub static X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// The current request's ID, available to handlers as an extension
+#[derive(Debug, Clone)]
+pub struct RequestId(pub String);
+
+pub async fn request_id_middleware(mut request: Request, next: Next) -> Response {
+    let request_id = Uuid::new_v4().to_string();
+
+    let span = tracing::info_span!(
+        "request",
+        request_id = %request_id,
+        service.name = Empty,
+        service.version = Empty,
+    );
+    record_service_fields(&span);
+
+    request.extensions_mut().insert(RequestId(request_id.clone()));
+
+    let mut response = next.run(request).instrument(span).await;
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(X_REQUEST_ID.clone(), value);
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::logging::{json_layer, SERVICE_NAME};
+    use axum::{body::Body, middleware, routing::get, Router};
+    use std::io::Write;
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+
+    /// Log sink the JSON layer writes into
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_json_logs_carry_request_id() -> Result<(), Box<dyn std::error::Error>> {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::registry().with(json_layer(move || writer.clone()));
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let app = Router::new()
+            .route(
+                "/ping",
+                get(|| async {
+                    tracing::info!(answer = 42, "handled ping");
+                    "pong"
+                }),
+            )
+            .layer(middleware::from_fn(request_id_middleware));
+        let response = app
+            .oneshot(Request::builder().uri("/ping").body(Body::empty())?)
+            .await?;
+
+        let request_id = response
+            .headers()
+            .get(&X_REQUEST_ID)
+            .expect("X-Request-ID header")
+            .to_str()?
+            .to_string();
+        assert!(Uuid::parse_str(&request_id).is_ok());
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone())?;
+        let line = output
+            .lines()
+            .find(|line| line.contains("handled ping"))
+            .expect("handler log line");
+        let event: serde_json::Value = serde_json::from_str(line)?;
+
+        assert_eq!(event["level"], "INFO");
+        assert!(event["timestamp"].is_string());
+        assert!(event["target"].is_string());
+        assert_eq!(event["message"], "handled ping");
+        assert_eq!(event["answer"], 42);
+        assert_eq!(event["span"]["request_id"], request_id.as_str());
+        assert_eq!(event["span"]["service.name"], SERVICE_NAME);
+        assert_eq!(event["span"]["service.version"], env!("CARGO_PKG_VERSION"));
+
+        Ok(())
+    }
+}
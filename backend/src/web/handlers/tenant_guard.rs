@@ -0,0 +1,230 @@
+//! Tenant Isolation Guard
+//!
+//! Development-time safety net against cross-organization data leaks. In debug
+//! builds with the `assert-tenant-isolation` feature enabled, JSON responses are
+//! scanned for `org_id` fields and any value other than the caller's own
+//! organization is logged as an error. Application admins are exempt since they
+//! legitimately read every organization's data. Release builds pass responses
+//! through untouched.
+
+use axum::{extract::Request, middleware::Next, response::Response};
+
+#[cfg(all(debug_assertions, feature = "assert-tenant-isolation"))]
+use crate::common::models::claims::Claims;
+#[cfg(all(debug_assertions, feature = "assert-tenant-isolation"))]
+use axum::{body::Body, http::header};
+
+/// Middleware asserting that JSON responses only expose the caller's organization.
+///
+/// Must run after `auth_middleware` so that claims are available. Mismatches are
+/// reported with `tracing::error!` rather than failing the request, so a leak
+/// shows up in the logs without changing what the client receives.
+#[cfg(all(debug_assertions, feature = "assert-tenant-isolation"))]
+pub async fn tenant_guard_middleware(request: Request, next: Next) -> Response {
+    let claims = request.extensions().get::<Claims>().cloned();
+    let method = request.method().clone();
+    let path = request.uri().path().to_string();
+
+    let response = next.run(request).await;
+
+    let claims = match claims {
+        Some(claims) if !claims.is_application_admin() => claims,
+        _ => return response,
+    };
+
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.starts_with("application/json"))
+        .unwrap_or(false);
+    if !is_json {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::warn!(path = %path, error = %e, "Tenant guard could not read response body");
+            return Response::from_parts(parts, Body::empty());
+        }
+    };
+
+    if let Ok(value) = serde_json::from_slice::<serde_json::Value>(&bytes) {
+        let caller_org_id = claims.get_org_id();
+        let mut foreign_org_ids = Vec::new();
+        collect_foreign_org_ids(&value, caller_org_id.as_deref(), &mut foreign_org_ids);
+
+        if !foreign_org_ids.is_empty() {
+            tracing::error!(
+                method = %method,
+                path = %path,
+                user_id = %claims.sub,
+                caller_org_id = caller_org_id.as_deref().unwrap_or("<none>"),
+                foreign_org_ids = ?foreign_org_ids,
+                "Tenant isolation violation: response contains data from another organization"
+            );
+        }
+    }
+
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+/// Release builds (or builds without `assert-tenant-isolation`) skip the check entirely.
+#[cfg(not(all(debug_assertions, feature = "assert-tenant-isolation")))]
+pub async fn tenant_guard_middleware(request: Request, next: Next) -> Response {
+    next.run(request).await
+}
+
+/// Walk a JSON document and collect every string `org_id` that differs from the caller's.
+#[cfg(all(debug_assertions, feature = "assert-tenant-isolation"))]
+fn collect_foreign_org_ids(
+    value: &serde_json::Value,
+    caller_org_id: Option<&str>,
+    foreign: &mut Vec<String>,
+) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, field) in map {
+                if key == "org_id" {
+                    if let Some(org_id) = field.as_str() {
+                        if Some(org_id) != caller_org_id && !foreign.iter().any(|seen| seen == org_id) {
+                            foreign.push(org_id.to_string());
+                        }
+                    }
+                }
+                collect_foreign_org_ids(field, caller_org_id, foreign);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for item in items {
+                collect_foreign_org_ids(item, caller_org_id, foreign);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(all(test, debug_assertions, feature = "assert-tenant-isolation"))]
+mod tests {
+    use super::*;
+    use crate::test_support::{org_claims, unreachable_keycloak};
+    use crate::common::database::entity::{
+        assessment_categories, assessments, assessments_response, assessments_submission,
+        temp_submission,
+    };
+    use crate::common::state::AppDatabase;
+    use crate::web::api::handlers::assessments::get_assessment;
+    use crate::web::routes::AppState;
+    use axum::{middleware, routing::get, Extension, Router};
+    use chrono::Utc;
+    use sea_orm::{ActiveModelTrait, ConnectionTrait, Database, Schema, Set};
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+    use tracing::{field::Field, Event, Level, Subscriber};
+    use tracing_subscriber::{layer::Context, prelude::*, Layer};
+    use uuid::Uuid;
+
+    /// Records the message of every ERROR event emitted while installed
+    #[derive(Clone, Default)]
+    struct ErrorCapture(Arc<Mutex<Vec<String>>>);
+
+    impl<S: Subscriber> Layer<S> for ErrorCapture {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            if *event.metadata().level() != Level::ERROR {
+                return;
+            }
+
+            struct Message(String);
+            impl tracing::field::Visit for Message {
+                fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+                    if field.name() == "message" {
+                        self.0 = format!("{value:?}");
+                    }
+                }
+            }
+
+            let mut message = Message(String::new());
+            event.record(&mut message);
+            self.0.lock().unwrap().push(message.0);
+        }
+    }
+
+    async fn test_app(owner_org_id: &str) -> Result<(Router, Uuid), Box<dyn std::error::Error>> {
+        let db = Database::connect("sqlite::memory:").await?;
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await?;
+        let backend = db.get_database_backend();
+        let schema = Schema::new(backend);
+        for statement in [
+            schema.create_table_from_entity(assessments::Entity),
+            schema.create_table_from_entity(assessment_categories::Entity),
+            schema.create_table_from_entity(assessments_response::Entity),
+            schema.create_table_from_entity(assessments_submission::Entity),
+            schema.create_table_from_entity(temp_submission::Entity),
+        ] {
+            db.execute(backend.build(&statement)).await?;
+        }
+
+        let assessment_id = Uuid::new_v4();
+        assessments::ActiveModel {
+            assessment_id: Set(assessment_id),
+            org_id: Set(owner_org_id.to_string()),
+            language: Set("en".to_string()),
+            name: Set("Shared assessment".to_string()),
+            created_at: Set(Utc::now()),
+        }
+        .insert(&db)
+        .await?;
+
+        let app_state = AppState::new(
+            unreachable_keycloak(),
+            AppDatabase::new(Arc::new(db)).await,
+        )
+        .await;
+
+        let app = Router::new()
+            .route("/api/assessments/:assessment_id", get(get_assessment))
+            .layer(middleware::from_fn(tenant_guard_middleware))
+            .layer(Extension(org_claims("test-user-123", &["Org_User"], "Test Organization", "test-org")))
+            .layer(Extension("test-token".to_string()))
+            .with_state(app_state);
+
+        Ok((app, assessment_id))
+    }
+
+    async fn errors_for(owner_org_id: &str) -> Result<Vec<String>, Box<dyn std::error::Error>> {
+        let capture = ErrorCapture::default();
+        let _guard = tracing::subscriber::set_default(tracing_subscriber::registry().with(capture.clone()));
+
+        let (app, assessment_id) = test_app(owner_org_id).await?;
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(format!("/api/assessments/{assessment_id}"))
+                    .body(Body::empty())?,
+            )
+            .await?;
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+
+        let errors = capture.0.lock().unwrap().clone();
+        Ok(errors)
+    }
+
+    #[tokio::test]
+    async fn test_cross_org_assessment_is_reported() -> Result<(), Box<dyn std::error::Error>> {
+        let errors = errors_for("other-org").await?;
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("Tenant isolation violation"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_own_assessment_is_not_reported() -> Result<(), Box<dyn std::error::Error>> {
+        let errors = errors_for("test-org").await?;
+
+        assert!(errors.is_empty());
+        Ok(())
+    }
+}
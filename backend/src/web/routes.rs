@@ -13,64 +13,188 @@
 //! admin console or APIs directly.
 
 use axum::{
-    extract::Extension, http::StatusCode, middleware, response::Json, routing::get, Router,
+    extract::Extension,
+    http::{header, Extensions, HeaderMap, StatusCode, Version},
+    middleware,
+    response::Json,
+    routing::get,
+    Router,
 };
 use serde_json::json;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
 use axum::http::HeaderValue;
-use tokio::sync::Mutex;
+use tower_http::compression::predicate::{Predicate, SizeAbove};
+use tower_http::compression::CompressionLayer;
 use tower_http::cors::{CorsLayer, Any};
+use utoipa_swagger_ui::SwaggerUi;
 
-use crate::common::cache::SessionCache;
-use crate::common::config::{Configs, KeycloakConfigs};
+use crate::common::cache::{SectorPoolCache, SessionCache};
+use crate::common::config::{CompressionConfig, Configs, KeycloakConfigs, LimitsConfig, LocaleConfig, RateLimitConfig, UploadConfig};
+use crate::common::models::admin_event::{AdminEvent, ADMIN_EVENT_CAPACITY};
 use crate::common::models::claims::Claims;
+use crate::common::services::email::EmailService;
 use crate::common::services::keycloak_service::KeycloakService;
+use crate::common::services::report_progress::ReportProgress;
 use crate::common::state::AppDatabase;
 use crate::web::api::routes::create_router;
+use crate::web::api::handlers::admin::get_migration_status;
+use crate::web::api::handlers::health::{health_status, version};
 use crate::web::api::handlers::openapi::get_openapi_json;
-use crate::web::handlers::{jwt_validator::JwtValidator, midlw::auth_middleware, request_logging::request_logging_middleware};
+use crate::web::handlers::{
+    jwt_validator::JwtValidator,
+    midlw::auth_middleware,
+    migration_gate::{migration_gate_middleware, MigrationGate},
+    rate_limit::{rate_limit_middleware, RateLimiter},
+    request_id::{request_id_middleware, X_REQUEST_ID},
+    request_logging::request_logging_middleware,
+    tenant_guard::tenant_guard_middleware,
+};
 
 /// Application state containing shared services for JWT validation and database access
 #[derive(Clone)]
 pub struct AppState {
-    pub jwt_validator: Arc<Mutex<JwtValidator>>,
+    pub jwt_validator: Arc<JwtValidator>,
     pub database: AppDatabase,
     pub keycloak_service: Arc<KeycloakService>,
     pub session_cache: SessionCache,
+    pub rate_limiter: Arc<RateLimiter>,
+    pub upload_config: UploadConfig,
+    pub limits_config: LimitsConfig,
+    pub locale_config: LocaleConfig,
+    pub sector_pool_cache: SectorPoolCache,
+    /// Progress of reports generated in the background
+    pub report_progress: ReportProgress,
+    /// `None` without `SMTP_HOST`, leaving emails to Keycloak
+    pub email_service: Option<Arc<EmailService>>,
+    /// Streamed to admins by `stream_admin_events`
+    pub admin_events: broadcast::Sender<AdminEvent>,
+    /// See `ServerConfigs::strict_request_validation`
+    pub strict_request_validation: bool,
+    /// Holds back API requests while migrations are pending
+    pub migration_gate: MigrationGate,
 }
 
 impl AppState {
     pub async fn new(keycloak_config: KeycloakConfigs, database: AppDatabase) -> Self {
-        let jwt_validator = Arc::new(Mutex::new(JwtValidator::new(keycloak_config.url.clone(), keycloak_config.realm.clone())));
+        let jwt_validator = Arc::new(JwtValidator::new(&keycloak_config));
         let keycloak_service = Arc::new(KeycloakService::new(keycloak_config));
 
         Self {
             jwt_validator,
+            migration_gate: MigrationGate::new(database.clone()),
             database,
             keycloak_service,
             session_cache: SessionCache::new(),
+            rate_limiter: Arc::new(RateLimiter::new(&RateLimitConfig::default())),
+            upload_config: UploadConfig::default(),
+            limits_config: LimitsConfig::default(),
+            locale_config: LocaleConfig::default(),
+            sector_pool_cache: SectorPoolCache::new(Duration::from_secs(60 * 60)),
+            report_progress: ReportProgress::default(),
+            email_service: None,
+            admin_events: broadcast::channel(ADMIN_EVENT_CAPACITY).0,
+            strict_request_validation: false,
         }
     }
+
+    pub fn with_rate_limit(mut self, config: &RateLimitConfig) -> Self {
+        self.rate_limiter = Arc::new(RateLimiter::new(config));
+        self
+    }
+
+    pub fn with_upload_config(mut self, config: UploadConfig) -> Self {
+        self.upload_config = config;
+        self
+    }
+
+    pub fn with_limits_config(mut self, config: LimitsConfig) -> Self {
+        self.limits_config = config;
+        self
+    }
+
+    pub fn with_locale_config(mut self, config: LocaleConfig) -> Self {
+        self.locale_config = config;
+        self
+    }
+
+    pub fn with_strict_request_validation(mut self, enabled: bool) -> Self {
+        self.strict_request_validation = enabled;
+        self
+    }
+
+    /// Answer API requests with 503 until these migrations have been applied
+    pub fn with_pending_migrations(self, pending: &[String]) -> Self {
+        self.migration_gate.set_pending(pending.len());
+        self
+    }
+
+    pub fn with_email_service(mut self, service: Option<EmailService>) -> Self {
+        self.email_service = service.map(Arc::new);
+        self
+    }
+
+    /// Send `event` to every admin currently streaming events; nobody listening is fine
+    pub fn publish_admin_event(&self, event: AdminEvent) {
+        let _ = self.admin_events.send(event);
+    }
 }
 
 /// Create the main application router with protected routes
 pub fn routers(app_state: AppState) -> Router {
+    // Rate limiting and the tenant guard are layered inside auth so that they can read the user's claims
+    let rate_limit = middleware::from_fn_with_state(
+        app_state.rate_limiter.clone(),
+        rate_limit_middleware,
+    );
+
+    // Outermost, so requests are refused before anything else looks at them
+    let migration_gate = middleware::from_fn_with_state(
+        app_state.migration_gate.clone(),
+        migration_gate_middleware,
+    );
+
     // Scope auth middleware only to protected and API routers
-    let protected = protected_routes().layer(middleware::from_fn_with_state(
-        app_state.jwt_validator.clone(),
-        auth_middleware,
-    ));
+    let protected = protected_routes()
+        .layer(middleware::from_fn(tenant_guard_middleware))
+        .layer(rate_limit.clone())
+        .layer(middleware::from_fn_with_state(
+            app_state.jwt_validator.clone(),
+            auth_middleware,
+        ))
+        .layer(migration_gate.clone());
 
-    let api = create_router(app_state.clone()).layer(middleware::from_fn_with_state(
-        app_state.jwt_validator.clone(),
-        auth_middleware,
-    ));
+    let api = create_router(app_state.clone())
+        .layer(middleware::from_fn(tenant_guard_middleware))
+        .layer(rate_limit.clone())
+        .layer(middleware::from_fn_with_state(
+            app_state.jwt_validator.clone(),
+            auth_middleware,
+        ))
+        .layer(migration_gate);
+
+    // Authenticated but not gated, so operators can follow the migrations
+    let internal = Router::new()
+        .route("/api/admin/migrations/status", get(get_migration_status))
+        .with_state(app_state.clone())
+        .layer(rate_limit.clone())
+        .layer(middleware::from_fn_with_state(
+            app_state.jwt_validator.clone(),
+            auth_middleware,
+        ));
+
+    // Public routes (no auth), limited per client IP
+    let public = Router::new()
+        .route("/api/openapi.json", get(get_openapi_json))
+        .layer(rate_limit)
+        .with_state(app_state);
 
     Router::new()
         .merge(protected)
         .merge(api)
-        // Public routes (no auth)
-        .route("/api/openapi.json", get(get_openapi_json))
+        .merge(internal)
+        .merge(public)
 }
 
 /// Protected routes that require JWT authentication
@@ -143,17 +267,64 @@ async fn get_protected_resource(Extension(claims): Extension<Claims>) -> Json<se
 }
 
 /// Health check route (no authentication required)
-pub fn health_routes() -> Router {
-    async fn health_check() -> Json<serde_json::Value> {
-        Json(json!({
-            "status": "healthy",
-            "service": "sustainability-resource-server",
-            "timestamp": chrono::Utc::now().to_rfc3339(),
-            "version": env!("CARGO_PKG_VERSION")
-        }))
-    }
+pub fn health_routes(app_state: AppState) -> Router {
+    Router::new()
+        .route("/health", get(health_status))
+        .route("/version", get(version))
+        .with_state(app_state)
+}
+
+/// Swagger UI at `/docs`, logging in through Keycloak with the authorization code
+/// flow and PKCE so no client secret ever reaches the browser.
+///
+/// Keycloak needs a matching public client, named by `OAUTH2_CLIENT_ID`
+/// (`swagger-ui` by default), in the backend's realm:
+///
+/// 1. Clients > Create client, OpenID Connect, with the chosen client ID.
+/// 2. Leave "Client authentication" off so the client is public, and enable only
+///    "Standard flow".
+/// 3. Under Advanced > "Proof Key for Code Exchange Code Challenge Method" select `S256`.
+/// 4. Set "Valid redirect URIs" to `{backend origin}/docs/oauth2-redirect.html`, e.g.
+///    `http://localhost:3001/docs/oauth2-redirect.html`, and add the backend origin to
+///    "Web origins" so the token request passes CORS.
+/// 5. Issued tokens must carry the `account` or `sustainability-tool` audience for
+///    `auth_middleware` to accept them; Keycloak's default client scopes already add
+///    `account`, otherwise add an audience mapper.
+pub fn docs_routes(config: &Configs) -> Router {
+    let oauth = utoipa_swagger_ui::oauth::Config::new()
+        .client_id(&config.server.oauth2_client_id)
+        .scopes(vec!["openid".to_string(), "profile".to_string(), "email".to_string()])
+        .use_pkce_with_authorization_code_grant(true);
+
+    SwaggerUi::new("/docs")
+        .config(utoipa_swagger_ui::Config::new(["/api/openapi.json"]))
+        .oauth(oauth)
+        .into()
+}
+
+/// Content types worth compressing, binary files are mostly compressed already
+const COMPRESSIBLE_CONTENT_TYPES: [&str; 2] = ["application/json", "text/plain"];
+
+/// Brotli or gzip, whichever the client accepts, for JSON and plain text responses
+/// of at least `min_size_bytes`
+pub fn compression_layer(config: &CompressionConfig) -> CompressionLayer<impl Predicate> {
+    let compressible = |_: StatusCode, _: Version, headers: &HeaderMap, _: &Extensions| {
+        headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|content_type| content_type.to_str().ok())
+            .and_then(|content_type| content_type.split(';').next())
+            .is_some_and(|mime| {
+                COMPRESSIBLE_CONTENT_TYPES
+                    .iter()
+                    .any(|compressible| mime.trim().eq_ignore_ascii_case(compressible))
+            })
+    };
+    let min_size = u16::try_from(config.min_size_bytes).unwrap_or(u16::MAX);
 
-    Router::new().route("/health", get(health_check))
+    CompressionLayer::new()
+        .br(true)
+        .gzip(true)
+        .compress_when(SizeAbove::new(min_size).and(compressible))
 }
 
 /// Create the complete application with all routes
@@ -163,24 +334,55 @@ pub fn create_app(app_state: AppState, config: Configs) -> Router {
     let cors = CorsLayer::new()
         .allow_origin(origin)
         .allow_methods(Any)
-        .allow_headers(Any);
+        .allow_headers(Any)
+        .expose_headers([axum::http::header::ETAG, X_REQUEST_ID.clone()]);
 
-    Router::new()
+    let router = Router::new()
         .merge(routers(app_state.clone()))
-        .merge(health_routes())
+        .merge(health_routes(app_state.clone()))
+        .merge(docs_routes(&config));
+
+    // Release builds never mount the test data endpoints, whatever the configuration
+    #[cfg(debug_assertions)]
+    let router = if config.seeder.enabled {
+        tracing::warn!("ENABLE_TEST_SEEDER is set, POST/DELETE /internal/seed create and delete data");
+        router.merge(crate::web::api::handlers::seed::seed_routes(
+            crate::common::services::seed::SeedService::new(
+                app_state.keycloak_service.clone(),
+                app_state.database.clone(),
+            ),
+            app_state.jwt_validator.clone(),
+            app_state.strict_request_validation,
+        ))
+    } else {
+        router
+    };
+
+    let router = if config.server.compression.enabled {
+        router.layer(compression_layer(&config.server.compression))
+    } else {
+        router
+    };
+
+    router
         .layer(cors)
         .layer(middleware::from_fn(request_logging_middleware))
+        // Outermost so the request span covers everything logged for the request
+        .layer(middleware::from_fn(request_id_middleware))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_support::keycloak_config;
     use axum::http::StatusCode;
     use tower::ServiceExt;
 
     #[tokio::test]
     async fn test_health_endpoint() {
-        let app = health_routes();
+        let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
+        let app_database = AppDatabase::new(std::sync::Arc::new(db)).await;
+        let app = health_routes(AppState::new(keycloak_config("http://localhost:8080"), app_database).await);
 
         let response = app
             .oneshot(
@@ -195,18 +397,92 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_migration_status_answers_while_migrations_are_pending() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::common::migrations::MigrationStatus;
+        use crate::test_support::{access_token, jwks_routes, serve};
+
+        let url = serve(jwks_routes()).await;
+        let db = sea_orm::Database::connect("sqlite::memory:").await?;
+        let app_database = AppDatabase::new(std::sync::Arc::new(db)).await;
+        let pending = MigrationStatus::check_pending(app_database.get_connection()).await?;
+        let app = routers(
+            AppState::new(keycloak_config(url.clone()), app_database)
+                .await
+                .with_pending_migrations(&pending),
+        );
+        let token = access_token(&url, "admin", &["application_admin"]);
+        let get = |uri: &str| {
+            axum::http::Request::builder()
+                .uri(uri)
+                .header(axum::http::header::AUTHORIZATION, format!("Bearer {token}"))
+                .body(axum::body::Body::empty())
+        };
+
+        let response = app.clone().oneshot(get("/api/admin/dashboard")?).await?;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let response = app.oneshot(get("/api/admin/migrations/status")?).await?;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body: serde_json::Value =
+            serde_json::from_slice(&axum::body::to_bytes(response.into_body(), usize::MAX).await?)?;
+        assert_eq!(body["pending"].as_array().map(Vec::len), Some(pending.len()));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_docs_use_keycloak_pkce_login() {
+        let config = crate::common::config::Configs {
+            keycloak: keycloak_config("http://localhost:8080"),
+            server: crate::common::config::ServerConfigs {
+                host: "0.0.0.0".to_string(),
+                port: 3001,
+                oauth2_client_id: "docs-client".to_string(),
+                compression: CompressionConfig::default(),
+                strict_request_validation: false,
+            },
+            cors: crate::common::config::CorsConfigs {
+                origin: "http://localhost:3000".to_string(),
+            },
+            rate_limit: RateLimitConfig::default(),
+            upload: UploadConfig::default(),
+            limits: LimitsConfig::default(),
+            storage: crate::common::config::StorageConfig::default(),
+            secrets: crate::common::config::SecretsConfig::default(),
+            sync: crate::common::config::SyncConfig::default(),
+            locale: crate::common::config::LocaleConfig::default(),
+            email: crate::common::config::EmailConfig::default(),
+            seeder: crate::common::config::SeederConfig::default(),
+            database: crate::common::config::DatabaseConfig::default(),
+            migrations: crate::common::config::MigrationsConfig::default(),
+            encryption: crate::common::config::EncryptionConfig::default(),
+        };
+
+        let response = docs_routes(&config)
+            .oneshot(
+                axum::http::Request::builder()
+                    .uri("/docs/swagger-initializer.js")
+                    .body(axum::body::Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let initializer = String::from_utf8(body.to_vec()).unwrap();
+        assert!(initializer.contains("/api/openapi.json"));
+        assert!(initializer.contains("\"clientId\": \"docs-client\""));
+        assert!(initializer.contains("\"usePkceWithAuthorizationCodeGrant\": true"));
+    }
+
     #[tokio::test]
     async fn test_protected_route_without_auth() {
         // Create a mock database connection for testing
         let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
         let app_database = AppDatabase::new(std::sync::Arc::new(db)).await;
 
-        let app_state = AppState::new(
-            "http://localhost:8080".to_string(),
-            "test-realm".to_string(),
-            app_database,
-        )
-        .await;
+        let app_state = AppState::new(keycloak_config("http://localhost:8080"), app_database).await;
 
         let app = routers(app_state);
 
@@ -229,25 +505,32 @@ mod tests {
         let db = sea_orm::Database::connect("sqlite::memory:").await.unwrap();
         let app_database = AppDatabase::new(std::sync::Arc::new(db)).await;
 
-        let app_state = AppState::new(
-            "http://localhost:8080".to_string(),
-            "test-realm".to_string(),
-            app_database,
-        )
-        .await;
+        let app_state = AppState::new(keycloak_config("http://localhost:8080"), app_database).await;
 
         let config = crate::common::config::Configs {
-            keycloak: crate::common::config::KeycloakConfigs {
-                url: "http://localhost:8080".to_string(),
-                realm: "test-realm".to_string(),
-            },
+            keycloak: keycloak_config("http://localhost:8080"),
             server: crate::common::config::ServerConfigs {
                 host: "0.0.0.0".to_string(),
                 port: 3001,
+                oauth2_client_id: "swagger-ui".to_string(),
+                compression: CompressionConfig::default(),
+                strict_request_validation: false,
             },
             cors: crate::common::config::CorsConfigs {
                 origin: "http://localhost:3000".to_string(),
             },
+            rate_limit: RateLimitConfig::default(),
+            upload: UploadConfig::default(),
+            limits: LimitsConfig::default(),
+            storage: crate::common::config::StorageConfig::default(),
+            secrets: crate::common::config::SecretsConfig::default(),
+            sync: crate::common::config::SyncConfig::default(),
+            locale: crate::common::config::LocaleConfig::default(),
+            email: crate::common::config::EmailConfig::default(),
+            seeder: crate::common::config::SeederConfig::default(),
+            database: crate::common::config::DatabaseConfig::default(),
+            migrations: crate::common::config::MigrationsConfig::default(),
+            encryption: crate::common::config::EncryptionConfig::default(),
         };
 
         let app = create_app(app_state, config);
@@ -8,6 +8,7 @@ use sea_orm_migration::MigratorTrait;
 use serde_json::{json, Value};
 use std::sync::Arc;
 
+use sustainability_tool::common::config::KeycloakConfigs;
 use sustainability_tool::common::migrations::Migrator;
 use sustainability_tool::common::models::claims::Claims;
 use sustainability_tool::web::routes::AppState;
@@ -62,12 +63,12 @@ async fn create_test_app_state() -> AppState {
     // Create AppDatabase
     let app_database = AppDatabase::new(Arc::new(db)).await;
 
-    AppState::new(
-        "http://localhost:8080".to_string(),
-        "test-realm".to_string(),
-        app_database,
-    )
-    .await
+    let keycloak_config = KeycloakConfigs {
+        realm: "test-realm".to_string(),
+        ..Default::default()
+    };
+
+    AppState::new(keycloak_config, app_database).await
 }
 
 async fn create_test_app() -> Router {
@@ -381,7 +382,7 @@ async fn test_get_submission() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/submissions/{submission_id}"))
+                .uri(format!("/api/submissions/{submission_id}"))
                 .body(Body::empty())
                 .unwrap(),
         )
@@ -439,7 +440,7 @@ async fn test_get_question_success() {
     let get_response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/questions/{question_id}"))
+                .uri(format!("/api/questions/{question_id}"))
                 .body(Body::empty())
                 .unwrap(),
         )
@@ -487,7 +488,7 @@ async fn test_get_question_not_found() {
     let response = app
         .oneshot(
             Request::builder()
-                .uri(&format!("/api/questions/{non_existent_id}"))
+                .uri(format!("/api/questions/{non_existent_id}"))
                 .body(Body::empty())
                 .unwrap(),
         )
@@ -505,3 +506,64 @@ async fn test_get_question_not_found() {
         .unwrap()
         .contains("Question not found"));
 }
+
+#[tokio::test]
+async fn test_large_json_responses_are_brotli_compressed() {
+    use axum::http::header;
+    use std::io::Read;
+    use sustainability_tool::common::config::CompressionConfig;
+    use sustainability_tool::web::routes::compression_layer;
+
+    let questions: Vec<Value> = (0..100)
+        .map(|i| json!({ "id": i, "text": "Does the cooperative track its energy consumption?" }))
+        .collect();
+    let expected = json!({ "questions": questions });
+    let payload = expected.clone();
+    let app = Router::new()
+        .route(
+            "/api/questions",
+            axum::routing::get(move || async move { axum::Json(payload) }),
+        )
+        .route(
+            "/api/health",
+            axum::routing::get(|| async { axum::Json(json!({ "status": "healthy" })) }),
+        )
+        .layer(compression_layer(&CompressionConfig::default()));
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .uri("/api/questions")
+                .header(header::ACCEPT_ENCODING, "br")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::CONTENT_ENCODING], "br");
+
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let mut decompressed = Vec::new();
+    brotli::Decompressor::new(body.as_ref(), 4096)
+        .read_to_end(&mut decompressed)
+        .unwrap();
+    let questions_response: Value = serde_json::from_slice(&decompressed).unwrap();
+    assert_eq!(questions_response, expected);
+
+    // Below the 1 KB threshold the body goes out as is
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/health")
+                .header(header::ACCEPT_ENCODING, "br")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert!(response.headers().get(header::CONTENT_ENCODING).is_none());
+}